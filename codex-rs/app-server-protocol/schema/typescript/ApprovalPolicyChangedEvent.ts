@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AskForApproval } from "./AskForApproval";
+
+export type ApprovalPolicyChangedEvent = { 
+/**
+ * Approval policy now in effect for the session.
+ */
+approval_policy: AskForApproval, };
\ No newline at end of file
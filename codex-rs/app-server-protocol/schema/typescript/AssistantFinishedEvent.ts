@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+
+export type AssistantFinishedEvent = { turn_id: string, 
+/**
+ * The assistant message the turn ended with.
+ */
+message: string, };
\ No newline at end of file
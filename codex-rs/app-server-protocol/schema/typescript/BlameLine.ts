@@ -0,0 +1,21 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type BlameLine = { 
+/**
+ * 1-based line number in the working tree file.
+ */
+line_number: number, 
+/**
+ * SHA of the commit that last changed the line; all zeros when the line has uncommitted changes.
+ */
+commit: string, author: string, author_email: string, 
+/**
+ * Author time in seconds since the Unix epoch.
+ */
+timestamp: bigint, 
+/**
+ * Subject line of the commit.
+ */
+summary: string, content: string, };
\ No newline at end of file
@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlameLine } from "./BlameLine";
+
+export type BlameResultEvent = { path: string, 
+/**
+ * Blame for each requested line, in file order.
+ */
+lines: Array<BlameLine>, };
\ No newline at end of file
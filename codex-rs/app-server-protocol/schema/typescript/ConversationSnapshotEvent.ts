@@ -0,0 +1,16 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ResponseItem } from "./ResponseItem";
+import type { ToolOutputTokenUsage } from "./ToolOutputTokenUsage";
+
+export type ConversationSnapshotEvent = { 
+/**
+ * Items in the session's history, oldest first, serialized the same way
+ * they are recorded to the rollout.
+ */
+items: Array<ResponseItem>, 
+/**
+ * Approximate context cost of each tool output in `items`, oldest first.
+ */
+tool_output_tokens: Array<ToolOutputTokenUsage>, };
\ No newline at end of file
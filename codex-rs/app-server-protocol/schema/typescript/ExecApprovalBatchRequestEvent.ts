@@ -0,0 +1,23 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExecApprovalRequestEvent } from "./ExecApprovalRequestEvent";
+
+/**
+ * Exec approval requests that arrived within `approval_batch_window_ms` of
+ * each other. Answer them all with `Op::ExecApprovalBatch`, or each one with
+ * `Op::ExecApproval` using its effective approval id.
+ */
+export type ExecApprovalBatchRequestEvent = { 
+/**
+ * Identifier to pass to `Op::ExecApprovalBatch`.
+ */
+batch_id: string, 
+/**
+ * Turn ID that these commands belong to.
+ */
+turn_id: string, 
+/**
+ * The batched requests, in the order they arrived.
+ */
+requests: Array<ExecApprovalRequestEvent>, };
\ No newline at end of file
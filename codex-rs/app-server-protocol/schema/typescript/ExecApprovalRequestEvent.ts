@@ -64,4 +64,8 @@ skill_metadata?: ExecApprovalRequestSkillMetadata,
  * When absent, clients should derive the legacy default set from the
  * other fields on this request.
  */
-available_decisions?: Array<ReviewDecision>, parsed_cmd: Array<ParsedCommand>, };
+available_decisions?: Array<ReviewDecision>, 
+/**
+ * Input that will be written to the command's stdin, if any.
+ */
+stdin?: string, parsed_cmd: Array<ParsedCommand>, };
@@ -2,4 +2,4 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type FileChange = { "type": "add", content: string, } | { "type": "delete", content: string, } | { "type": "update", unified_diff: string, move_path: string | null, };
+export type FileChange = { "type": "add", content: string, } | { "type": "binary", base64_content: string, } | { "type": "symlink", target: string, } | { "type": "delete", content: string, } | { "type": "update", unified_diff: string, move_path: string | null, };
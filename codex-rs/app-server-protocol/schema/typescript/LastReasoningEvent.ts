@@ -0,0 +1,17 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LastReasoningEvent = { 
+/**
+ * Turn the reasoning came from; `None` when no turn has produced reasoning yet.
+ */
+turn_id: string | null, 
+/**
+ * Reasoning summary text, one entry per summary part.
+ */
+summary: Array<string>, 
+/**
+ * Raw reasoning text. Empty unless `show_raw_agent_reasoning` is enabled.
+ */
+raw_content: Array<string>, };
\ No newline at end of file
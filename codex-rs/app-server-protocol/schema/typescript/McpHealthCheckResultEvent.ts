@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { McpHealthStatus } from "./McpHealthStatus";
+
+export type McpHealthCheckResultEvent = { 
+/**
+ * Name of the checked server.
+ */
+server: string, status: McpHealthStatus, };
\ No newline at end of file
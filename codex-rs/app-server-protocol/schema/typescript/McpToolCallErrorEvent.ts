@@ -0,0 +1,15 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { McpInvocation } from "./McpInvocation";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+export type McpToolCallErrorEvent = { 
+/**
+ * Identifier for the corresponding McpToolCallEnd.
+ */
+call_id: string, invocation: McpInvocation, 
+/**
+ * Content blocks returned by the tool alongside the error flag.
+ */
+content: Array<JsonValue>, };
@@ -20,4 +20,8 @@ auto_approved: boolean,
 /**
  * The changes to be applied.
  */
-changes: { [key in string]?: FileChange }, };
+changes: { [key in string]?: FileChange }, 
+/**
+ * True when `dry_run` is enabled and the patch will not be written.
+ */
+dry_run: boolean, };
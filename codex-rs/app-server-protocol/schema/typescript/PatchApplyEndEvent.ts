@@ -33,4 +33,8 @@ changes: { [key in string]?: FileChange },
 /**
  * Completion status for this patch application.
  */
-status: PatchApplyStatus, };
+status: PatchApplyStatus, 
+/**
+ * True when `dry_run` is enabled and the patch was not written.
+ */
+dry_run: boolean, };
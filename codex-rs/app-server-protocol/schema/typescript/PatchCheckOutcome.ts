@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PatchConflict } from "./PatchConflict";
+
+/**
+ * Whether a patch checked via `Op::CheckPatchApplies` applies to the current tree.
+ */
+export type PatchCheckOutcome = { "status": "applies_cleanly" } | { "status": "conflicts", files: Array<PatchConflict>, } | { "status": "parse_error", message: string, };
\ No newline at end of file
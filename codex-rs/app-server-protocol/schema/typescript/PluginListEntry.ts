@@ -0,0 +1,21 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PluginListEntry = { 
+/**
+ * Plugin key from the `[plugins]` config table, e.g. `sample@marketplace`.
+ */
+config_name: string, 
+/**
+ * `name` from the plugin manifest, when it could be read.
+ */
+manifest_name: string | null, enabled: boolean, 
+/**
+ * Why the plugin failed to load, if it did.
+ */
+error: string | null, has_skills: boolean, has_mcp_servers: boolean, skill_count: number, mcp_server_count: number, 
+/**
+ * Tags from the `capabilities` array in the plugin manifest.
+ */
+declared_capabilities: Array<string>, };
\ No newline at end of file
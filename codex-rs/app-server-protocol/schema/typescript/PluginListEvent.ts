@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PluginListEntry } from "./PluginListEntry";
+
+export type PluginListEvent = { 
+/**
+ * Configured plugins sorted by config name, including disabled ones.
+ */
+plugins: Array<PluginListEntry>, 
+/**
+ * Human-readable listing with one line per plugin.
+ */
+summary: string, };
\ No newline at end of file
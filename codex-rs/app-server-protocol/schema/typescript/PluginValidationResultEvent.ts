@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PluginValidationErrorInfo } from "./PluginValidationErrorInfo";
+
+export type PluginValidationResultEvent = { 
+/**
+ * The plugin directory that was checked.
+ */
+path: string, 
+/**
+ * Problems found in the plugin; empty when it is valid.
+ */
+errors: Array<PluginValidationErrorInfo>, };
\ No newline at end of file
@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ProviderToolCallEvent = { call_id: string, 
+/**
+ * Name of the provider-side tool, e.g. `code_interpreter`.
+ */
+name: string, status?: string, arguments?: string, output?: string, };
\ No newline at end of file
@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ErrorEvent } from "./ErrorEvent";
+
+export type RecentErrorsEvent = { 
+/**
+ * Retained error events, oldest first.
+ */
+errors: Array<ErrorEvent>, };
\ No newline at end of file
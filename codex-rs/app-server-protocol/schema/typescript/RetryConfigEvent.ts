@@ -0,0 +1,27 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RetryConfigEvent = { 
+/**
+ * Retries for a request that fails before its response starts streaming.
+ */
+request_max_retries: bigint, 
+/**
+ * Reconnection attempts after a response stream drops mid-turn.
+ */
+stream_max_retries: bigint, 
+/**
+ * Delay before the first retry; each later retry multiplies it by
+ * `backoff_factor`.
+ */
+base_backoff_ms: bigint, backoff_factor: number, 
+/**
+ * Each delay is scaled by a random factor within `1 ± jitter_ratio`.
+ */
+jitter_ratio: number, 
+/**
+ * How long a response stream may stay silent before it is treated as
+ * disconnected.
+ */
+stream_idle_timeout_ms: bigint, };
\ No newline at end of file
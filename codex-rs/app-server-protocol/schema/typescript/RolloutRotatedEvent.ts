@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RolloutRotatedEvent = { 
+/**
+ * Rollout file the session now records into.
+ */
+rollout_path: string, 
+/**
+ * Rollout file that was closed by the rotation.
+ */
+previous_rollout_path: string, };
\ No newline at end of file
@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SearchHistoryResultsEvent = { 
+/**
+ * Positions of the matching items in the conversation history, as
+ * returned by `Op::GetConversationSnapshot`, oldest first.
+ */
+indices: Array<number>, 
+/**
+ * An excerpt of each matching item, parallel to `indices`.
+ */
+snippets: Array<string>, };
\ No newline at end of file
@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ThreadId } from "./ThreadId";
+
+export type SessionForkedEvent = { 
+/**
+ * Id of the new thread; look it up with `ThreadManager::get_thread`.
+ */
+session_id: ThreadId, from_submission_id: string, 
+/**
+ * Rollout file the fork records into.
+ */
+rollout_path: string | null, };
\ No newline at end of file
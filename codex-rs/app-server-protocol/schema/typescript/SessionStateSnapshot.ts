@@ -0,0 +1,33 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PermissionProfile } from "./PermissionProfile";
+import type { ResponseItem } from "./ResponseItem";
+import type { ReviewDecision } from "./ReviewDecision";
+import type { ThreadId } from "./ThreadId";
+import type { TurnContextItem } from "./TurnContextItem";
+
+/**
+ * Checkpoint of a session's in-memory state, suitable for restoring into a
+ * fresh thread (possibly in another process).
+ *
+ * Unlike rollout resume, which replays the append-only event log, a snapshot
+ * captures the current state directly. It includes the model-visible history,
+ * the context baseline used to diff settings on the next turn, the thread
+ * name, approvals cached for the session, and permissions granted via
+ * `request_permissions`.
+ *
+ * It does not include the rollout event log, in-flight turns or pending
+ * approval requests, background terminals, MCP connections, token usage, or
+ * any session configuration (model, cwd, sandbox and approval policies); those
+ * come from the `Config` used when restoring.
+ */
+export type SessionStateSnapshot = { 
+/**
+ * Thread the snapshot was exported from. Restoring creates a new thread id.
+ */
+thread_id: ThreadId, thread_name?: string, history: Array<ResponseItem>, reference_context_item?: TurnContextItem, 
+/**
+ * Session-scoped approval decisions, keyed by their opaque approval cache key.
+ */
+approvals: { [key in string]?: ReviewDecision }, granted_permissions?: PermissionProfile, };
@@ -0,0 +1,33 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SessionStatsEvent = { 
+/**
+ * Turns that ran to completion.
+ */
+turns_completed: bigint, 
+/**
+ * Input plus output tokens reported by the model provider.
+ */
+total_tokens: bigint, 
+/**
+ * Commands executed for tool calls.
+ */
+exec_calls: bigint, 
+/**
+ * Patches applied successfully, excluding dry runs.
+ */
+patches_applied: bigint, 
+/**
+ * Distinct files changed by patches.
+ */
+files_changed: number, 
+/**
+ * Wall-clock time since the session started.
+ */
+elapsed_ms: bigint, 
+/**
+ * Items currently in the conversation history.
+ */
+history_items: number, };
\ No newline at end of file
@@ -9,4 +9,8 @@ export type SkillMetadata = { name: string, description: string,
 /**
  * Legacy short_description from SKILL.md. Prefer SKILL.json interface.short_description.
  */
-short_description?: string, interface?: SkillInterface, dependencies?: SkillDependencies, path: string, scope: SkillScope, enabled: boolean, };
+short_description?: string, interface?: SkillInterface, dependencies?: SkillDependencies, 
+/**
+ * Name of the plugin that provides this skill, if any.
+ */
+plugin?: string, path: string, scope: SkillScope, enabled: boolean, };
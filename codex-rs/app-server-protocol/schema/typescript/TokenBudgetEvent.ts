@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TokenBudgetEvent = { 
+/**
+ * Cap on cumulative token usage, or `None` when the session is uncapped.
+ */
+max_total_tokens: bigint | null, 
+/**
+ * Cumulative `total_tokens` used by the session so far.
+ */
+total_tokens: bigint, };
\ No newline at end of file
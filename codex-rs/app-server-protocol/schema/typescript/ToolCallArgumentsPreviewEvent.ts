@@ -0,0 +1,12 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+export type ToolCallArgumentsPreviewEvent = { turn_id: string, call_id: string, tool_name: string, 
+/**
+ * The arguments received so far, with any unterminated strings, arrays,
+ * and objects closed. Each preview supersedes the previous one for the
+ * same `call_id`.
+ */
+arguments: JsonValue, };
\ No newline at end of file
@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ToolOutputTokenUsage = { 
+/**
+ * Identifier of the tool call that produced the output.
+ */
+call_id: string, 
+/**
+ * Estimated number of tokens the output occupies in the model context.
+ */
+tokens: number, };
\ No newline at end of file
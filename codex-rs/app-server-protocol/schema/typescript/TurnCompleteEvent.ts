@@ -2,4 +2,9 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type TurnCompleteEvent = { turn_id: string, last_agent_message: string | null, };
+export type TurnCompleteEvent = { turn_id: string, last_agent_message: string | null, 
+/**
+ * Estimated cost of the turn in USD, present when pricing is known for
+ * the turn's model.
+ */
+cost_usd?: number, };
@@ -0,0 +1,20 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AskForApproval } from "./AskForApproval";
+import type { CollaborationMode } from "./CollaborationMode";
+import type { Personality } from "./Personality";
+import type { ReasoningEffort } from "./ReasoningEffort";
+import type { ReasoningSummary } from "./ReasoningSummary";
+import type { SandboxPolicy } from "./SandboxPolicy";
+import type { TruncationPolicy } from "./TruncationPolicy";
+import type { TurnContextNetworkItem } from "./TurnContextNetworkItem";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Persist once per real user turn after computing that turn's model-visible
+ * context updates, and again after mid-turn compaction when replacement
+ * history re-establishes full context, so resume/fork replay can recover the
+ * latest durable baseline.
+ */
+export type TurnContextItem = { turn_id?: string | null, trace_id?: string | null, cwd: string, current_date?: string | null, timezone?: string | null, approval_policy: AskForApproval, sandbox_policy: SandboxPolicy, network: TurnContextNetworkItem | null, model: string, personality: Personality | null, collaboration_mode?: CollaborationMode | null, realtime_active?: boolean | null, effort: ReasoningEffort | null, summary: ReasoningSummary, user_instructions: string | null, developer_instructions: string | null, final_output_json_schema: JsonValue | null, truncation_policy: TruncationPolicy | null, };
@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WorkingDirectoryChangedEvent = { 
+/**
+ * Working directory now in effect for the session.
+ */
+new_cwd: string, };
\ No newline at end of file
@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WorkingTreeHashEvent = { 
+/**
+ * Hex-encoded SHA-256 over the relative path and content of every hashed
+ * file, in path order. Identical trees produce identical hashes.
+ */
+hash: string, 
+/**
+ * Number of files that went into the hash.
+ */
+file_count: number, };
\ No newline at end of file
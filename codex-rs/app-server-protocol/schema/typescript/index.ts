@@ -15,9 +15,14 @@ export type { AgentStatus } from "./AgentStatus";
 export type { ApplyPatchApprovalParams } from "./ApplyPatchApprovalParams";
 export type { ApplyPatchApprovalRequestEvent } from "./ApplyPatchApprovalRequestEvent";
 export type { ApplyPatchApprovalResponse } from "./ApplyPatchApprovalResponse";
+export type { ApprovalPolicyChangedEvent } from "./ApprovalPolicyChangedEvent";
 export type { AskForApproval } from "./AskForApproval";
+export type { AssistantFinishedEvent } from "./AssistantFinishedEvent";
 export type { AuthMode } from "./AuthMode";
 export type { BackgroundEventEvent } from "./BackgroundEventEvent";
+export type { BaseInstructionsUpdatedEvent } from "./BaseInstructionsUpdatedEvent";
+export type { BlameLine } from "./BlameLine";
+export type { BlameResultEvent } from "./BlameResultEvent";
 export type { ByteRange } from "./ByteRange";
 export type { CallToolResult } from "./CallToolResult";
 export type { ClientInfo } from "./ClientInfo";
@@ -41,6 +46,7 @@ export type { ContentItem } from "./ContentItem";
 export type { ContextCompactedEvent } from "./ContextCompactedEvent";
 export type { ContextCompactionItem } from "./ContextCompactionItem";
 export type { ConversationGitInfo } from "./ConversationGitInfo";
+export type { ConversationSnapshotEvent } from "./ConversationSnapshotEvent";
 export type { ConversationSummary } from "./ConversationSummary";
 export type { CreditsSnapshot } from "./CreditsSnapshot";
 export type { CustomPrompt } from "./CustomPrompt";
@@ -52,6 +58,7 @@ export type { ElicitationRequest } from "./ElicitationRequest";
 export type { ElicitationRequestEvent } from "./ElicitationRequestEvent";
 export type { ErrorEvent } from "./ErrorEvent";
 export type { EventMsg } from "./EventMsg";
+export type { ExecApprovalBatchRequestEvent } from "./ExecApprovalBatchRequestEvent";
 export type { ExecApprovalRequestEvent } from "./ExecApprovalRequestEvent";
 export type { ExecApprovalRequestSkillMetadata } from "./ExecApprovalRequestSkillMetadata";
 export type { ExecCommandApprovalParams } from "./ExecCommandApprovalParams";
@@ -64,6 +71,8 @@ export type { ExecCommandStatus } from "./ExecCommandStatus";
 export type { ExecOutputStream } from "./ExecOutputStream";
 export type { ExecPolicyAmendment } from "./ExecPolicyAmendment";
 export type { ExitedReviewModeEvent } from "./ExitedReviewModeEvent";
+export type { ExportCompleteEvent } from "./ExportCompleteEvent";
+export type { FeaturesEvent } from "./FeaturesEvent";
 export type { FileChange } from "./FileChange";
 export type { FileSystemPermissions } from "./FileSystemPermissions";
 export type { ForcedLoginMethod } from "./ForcedLoginMethod";
@@ -105,6 +114,7 @@ export type { InitializeResponse } from "./InitializeResponse";
 export type { InputModality } from "./InputModality";
 export type { ItemCompletedEvent } from "./ItemCompletedEvent";
 export type { ItemStartedEvent } from "./ItemStartedEvent";
+export type { LastReasoningEvent } from "./LastReasoningEvent";
 export type { ListCustomPromptsResponseEvent } from "./ListCustomPromptsResponseEvent";
 export type { ListRemoteSkillsResponseEvent } from "./ListRemoteSkillsResponseEvent";
 export type { ListSkillsResponseEvent } from "./ListSkillsResponseEvent";
@@ -116,6 +126,8 @@ export type { MacOsContactsPermission } from "./MacOsContactsPermission";
 export type { MacOsPreferencesPermission } from "./MacOsPreferencesPermission";
 export type { MacOsSeatbeltProfileExtensions } from "./MacOsSeatbeltProfileExtensions";
 export type { McpAuthStatus } from "./McpAuthStatus";
+export type { McpHealthCheckResultEvent } from "./McpHealthCheckResultEvent";
+export type { McpHealthStatus } from "./McpHealthStatus";
 export type { McpInvocation } from "./McpInvocation";
 export type { McpListToolsResponseEvent } from "./McpListToolsResponseEvent";
 export type { McpStartupCompleteEvent } from "./McpStartupCompleteEvent";
@@ -124,6 +136,7 @@ export type { McpStartupStatus } from "./McpStartupStatus";
 export type { McpStartupUpdateEvent } from "./McpStartupUpdateEvent";
 export type { McpToolCallBeginEvent } from "./McpToolCallBeginEvent";
 export type { McpToolCallEndEvent } from "./McpToolCallEndEvent";
+export type { McpToolCallErrorEvent } from "./McpToolCallErrorEvent";
 export type { MessagePhase } from "./MessagePhase";
 export type { ModeKind } from "./ModeKind";
 export type { ModelRerouteEvent } from "./ModelRerouteEvent";
@@ -138,12 +151,21 @@ export type { ParsedCommand } from "./ParsedCommand";
 export type { PatchApplyBeginEvent } from "./PatchApplyBeginEvent";
 export type { PatchApplyEndEvent } from "./PatchApplyEndEvent";
 export type { PatchApplyStatus } from "./PatchApplyStatus";
+export type { PatchCheckOutcome } from "./PatchCheckOutcome";
+export type { PatchCheckResultEvent } from "./PatchCheckResultEvent";
+export type { PatchConflict } from "./PatchConflict";
+export type { PauseStateChangedEvent } from "./PauseStateChangedEvent";
 export type { PermissionProfile } from "./PermissionProfile";
 export type { Personality } from "./Personality";
 export type { PlanDeltaEvent } from "./PlanDeltaEvent";
 export type { PlanItem } from "./PlanItem";
 export type { PlanItemArg } from "./PlanItemArg";
 export type { PlanType } from "./PlanType";
+export type { PluginListEntry } from "./PluginListEntry";
+export type { PluginListEvent } from "./PluginListEvent";
+export type { PluginValidationErrorInfo } from "./PluginValidationErrorInfo";
+export type { PluginValidationResultEvent } from "./PluginValidationResultEvent";
+export type { ProviderToolCallEvent } from "./ProviderToolCallEvent";
 export type { RateLimitSnapshot } from "./RateLimitSnapshot";
 export type { RateLimitWindow } from "./RateLimitWindow";
 export type { RawResponseItemEvent } from "./RawResponseItemEvent";
@@ -163,6 +185,7 @@ export type { ReasoningItemContent } from "./ReasoningItemContent";
 export type { ReasoningItemReasoningSummary } from "./ReasoningItemReasoningSummary";
 export type { ReasoningRawContentDeltaEvent } from "./ReasoningRawContentDeltaEvent";
 export type { ReasoningSummary } from "./ReasoningSummary";
+export type { RecentErrorsEvent } from "./RecentErrorsEvent";
 export type { RejectConfig } from "./RejectConfig";
 export type { RemoteSkillDownloadedEvent } from "./RemoteSkillDownloadedEvent";
 export type { RemoteSkillSummary } from "./RemoteSkillSummary";
@@ -174,6 +197,7 @@ export type { RequestUserInputQuestionOption } from "./RequestUserInputQuestionO
 export type { Resource } from "./Resource";
 export type { ResourceTemplate } from "./ResourceTemplate";
 export type { ResponseItem } from "./ResponseItem";
+export type { RetryConfigEvent } from "./RetryConfigEvent";
 export type { ReviewCodeLocation } from "./ReviewCodeLocation";
 export type { ReviewDecision } from "./ReviewDecision";
 export type { ReviewFinding } from "./ReviewFinding";
@@ -181,13 +205,19 @@ export type { ReviewLineRange } from "./ReviewLineRange";
 export type { ReviewOutputEvent } from "./ReviewOutputEvent";
 export type { ReviewRequest } from "./ReviewRequest";
 export type { ReviewTarget } from "./ReviewTarget";
+export type { RolloutRotatedEvent } from "./RolloutRotatedEvent";
 export type { SandboxPolicy } from "./SandboxPolicy";
+export type { SearchHistoryResultsEvent } from "./SearchHistoryResultsEvent";
 export type { ServerNotification } from "./ServerNotification";
 export type { ServerRequest } from "./ServerRequest";
 export type { ServiceTier } from "./ServiceTier";
 export type { SessionConfiguredEvent } from "./SessionConfiguredEvent";
+export type { SessionForkedEvent } from "./SessionForkedEvent";
 export type { SessionNetworkProxyRuntime } from "./SessionNetworkProxyRuntime";
 export type { SessionSource } from "./SessionSource";
+export type { SessionStateExportedEvent } from "./SessionStateExportedEvent";
+export type { SessionStateSnapshot } from "./SessionStateSnapshot";
+export type { SessionStatsEvent } from "./SessionStatsEvent";
 export type { Settings } from "./Settings";
 export type { SkillDependencies } from "./SkillDependencies";
 export type { SkillErrorInfo } from "./SkillErrorInfo";
@@ -204,13 +234,19 @@ export type { TextElement } from "./TextElement";
 export type { ThreadId } from "./ThreadId";
 export type { ThreadNameUpdatedEvent } from "./ThreadNameUpdatedEvent";
 export type { ThreadRolledBackEvent } from "./ThreadRolledBackEvent";
+export type { TokenBudgetEvent } from "./TokenBudgetEvent";
 export type { TokenCountEvent } from "./TokenCountEvent";
 export type { TokenUsage } from "./TokenUsage";
 export type { TokenUsageInfo } from "./TokenUsageInfo";
 export type { Tool } from "./Tool";
+export type { ToolCallArgumentsPreviewEvent } from "./ToolCallArgumentsPreviewEvent";
+export type { ToolOutputTokenUsage } from "./ToolOutputTokenUsage";
+export type { TruncationPolicy } from "./TruncationPolicy";
 export type { TurnAbortReason } from "./TurnAbortReason";
 export type { TurnAbortedEvent } from "./TurnAbortedEvent";
 export type { TurnCompleteEvent } from "./TurnCompleteEvent";
+export type { TurnContextItem } from "./TurnContextItem";
+export type { TurnContextNetworkItem } from "./TurnContextNetworkItem";
 export type { TurnDiffEvent } from "./TurnDiffEvent";
 export type { TurnItem } from "./TurnItem";
 export type { TurnStartedEvent } from "./TurnStartedEvent";
@@ -231,4 +267,6 @@ export type { WebSearchItem } from "./WebSearchItem";
 export type { WebSearchLocation } from "./WebSearchLocation";
 export type { WebSearchMode } from "./WebSearchMode";
 export type { WebSearchToolConfig } from "./WebSearchToolConfig";
+export type { WorkingDirectoryChangedEvent } from "./WorkingDirectoryChangedEvent";
+export type { WorkingTreeHashEvent } from "./WorkingTreeHashEvent";
 export * as v2 from "./v2";
@@ -1028,7 +1028,9 @@ fn convert_dynamic_tool_content_items(
 
 fn map_patch_change_kind(change: &codex_protocol::protocol::FileChange) -> PatchChangeKind {
     match change {
-        codex_protocol::protocol::FileChange::Add { .. } => PatchChangeKind::Add,
+        codex_protocol::protocol::FileChange::Add { .. }
+        | codex_protocol::protocol::FileChange::Binary { .. }
+        | codex_protocol::protocol::FileChange::Symlink { .. } => PatchChangeKind::Add,
         codex_protocol::protocol::FileChange::Delete { .. } => PatchChangeKind::Delete,
         codex_protocol::protocol::FileChange::Update { move_path, .. } => PatchChangeKind::Update {
             move_path: move_path.clone(),
@@ -1039,6 +1041,12 @@ fn map_patch_change_kind(change: &codex_protocol::protocol::FileChange) -> Patch
 fn format_file_change_diff(change: &codex_protocol::protocol::FileChange) -> String {
     match change {
         codex_protocol::protocol::FileChange::Add { content } => content.clone(),
+        codex_protocol::protocol::FileChange::Binary { .. } => {
+            change.binary_description().unwrap_or_default()
+        }
+        codex_protocol::protocol::FileChange::Symlink { target } => {
+            format!("-> {}", target.display())
+        }
         codex_protocol::protocol::FileChange::Delete { content } => content.clone(),
         codex_protocol::protocol::FileChange::Update {
             unified_diff,
@@ -1271,6 +1279,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: turn_id.to_string(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
         ];
 
@@ -1588,6 +1597,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-a".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
         ];
 
@@ -1828,6 +1838,7 @@ mod tests {
                 .into_iter()
                 .collect(),
                 status: CorePatchApplyStatus::Declined,
+                dry_run: false,
             }),
         ];
 
@@ -1885,6 +1896,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-a".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
             EventMsg::TurnStarted(TurnStartedEvent {
                 turn_id: "turn-b".into(),
@@ -1919,6 +1931,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-b".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
         ];
 
@@ -1967,6 +1980,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-a".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
             EventMsg::TurnStarted(TurnStartedEvent {
                 turn_id: "turn-b".into(),
@@ -2001,6 +2015,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-b".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
         ];
 
@@ -2054,6 +2069,7 @@ mod tests {
                 )]
                 .into_iter()
                 .collect(),
+                dry_run: false,
             }),
         ];
 
@@ -2170,6 +2186,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-a".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
             EventMsg::TurnStarted(TurnStartedEvent {
                 turn_id: "turn-b".into(),
@@ -2185,6 +2202,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-a".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
             EventMsg::AgentMessage(AgentMessageEvent {
                 message: "still in b".into(),
@@ -2193,6 +2211,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-b".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
         ];
 
@@ -2224,6 +2243,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-a".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
             EventMsg::TurnStarted(TurnStartedEvent {
                 turn_id: "turn-b".into(),
@@ -2273,6 +2293,7 @@ mod tests {
             RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-compact".into(),
                 last_agent_message: None,
+                cost_usd: None,
             })),
         ];
 
@@ -2384,6 +2405,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-a".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
             EventMsg::Error(ErrorEvent {
                 message: "request-level failure".into(),
@@ -2437,6 +2459,7 @@ mod tests {
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-a".into(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
         ];
 
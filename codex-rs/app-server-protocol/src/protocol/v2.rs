@@ -159,6 +159,16 @@ pub enum CodexErrorInfo {
         #[ts(rename = "httpStatusCode")]
         http_status_code: Option<u16>,
     },
+    /// The turn ran past its configured deadline and was aborted.
+    TurnDeadlineExceeded,
+    /// The account ran out of quota. `resetsAt` is the unix timestamp (in
+    /// seconds) at which it is expected to reset, when known.
+    QuotaExceeded {
+        #[serde(rename = "resetsAt")]
+        #[ts(rename = "resetsAt")]
+        #[ts(type = "number | null")]
+        resets_at: Option<i64>,
+    },
     Other,
 }
 
@@ -185,6 +195,10 @@ impl From<CoreCodexErrorInfo> for CodexErrorInfo {
             CoreCodexErrorInfo::ResponseTooManyFailedAttempts { http_status_code } => {
                 CodexErrorInfo::ResponseTooManyFailedAttempts { http_status_code }
             }
+            CoreCodexErrorInfo::TurnDeadlineExceeded => CodexErrorInfo::TurnDeadlineExceeded,
+            CoreCodexErrorInfo::QuotaExceeded { resets_at } => {
+                CodexErrorInfo::QuotaExceeded { resets_at }
+            }
             CoreCodexErrorInfo::Other => CodexErrorInfo::Other,
         }
     }
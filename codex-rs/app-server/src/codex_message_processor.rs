@@ -1674,6 +1674,7 @@ impl CodexMessageProcessor {
             windows_sandbox_level,
             justification: None,
             arg0: None,
+            stdin: None,
         };
 
         let requested_policy = sandbox_policy.map(|policy| policy.to_core());
@@ -5811,6 +5812,8 @@ impl CodexMessageProcessor {
             .submit(Op::UserInput {
                 items: mapped_items,
                 final_output_json_schema: params.output_schema,
+                effort: None,
+                tool_choice: None,
             })
             .await;
 
@@ -739,6 +739,7 @@ mod tests {
             network_sandbox_policy: NetworkSandboxPolicy::from(&sandbox_policy),
             justification: None,
             arg0: None,
+            stdin: None,
         }
     }
 
@@ -850,6 +851,7 @@ mod tests {
                     network_sandbox_policy: NetworkSandboxPolicy::from(&sandbox_policy),
                     justification: None,
                     arg0: None,
+                    stdin: None,
                 },
                 started_network_proxy: None,
                 tty: false,
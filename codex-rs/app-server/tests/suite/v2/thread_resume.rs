@@ -319,6 +319,7 @@ stream_max_retries = 0
         base_instructions: None,
         dynamic_tools: None,
         memory_mode: None,
+        previous_rollout_path: None,
     };
     std::fs::write(
         &rollout_path,
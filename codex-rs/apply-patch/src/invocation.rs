@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::sync::LazyLock;
 
+use base64::Engine;
+
 use tree_sitter::Parser;
 use tree_sitter::Query;
 use tree_sitter::QueryCursor;
@@ -167,6 +169,14 @@ pub fn maybe_parse_apply_patch_verified(argv: &[String], cwd: &Path) -> MaybeApp
                     Hunk::AddFile { contents, .. } => {
                         changes.insert(path, ApplyPatchFileChange::Add { content: contents });
                     }
+                    Hunk::AddBinaryFile { contents, .. } => {
+                        let base64_content =
+                            base64::engine::general_purpose::STANDARD.encode(contents);
+                        changes.insert(path, ApplyPatchFileChange::Binary { base64_content });
+                    }
+                    Hunk::AddSymlink { target, .. } => {
+                        changes.insert(path, ApplyPatchFileChange::Symlink { target });
+                    }
                     Hunk::DeleteFile { .. } => {
                         let content = match std::fs::read_to_string(&path) {
                             Ok(content) => content,
@@ -96,6 +96,16 @@ pub enum ApplyPatchFileChange {
     Add {
         content: String,
     },
+    /// A new binary file; `base64_content` is the standard base64 encoding of
+    /// its bytes.
+    Binary {
+        base64_content: String,
+    },
+    /// A symbolic link to `target`, created or replacing whatever is at the
+    /// path.
+    Symlink {
+        target: PathBuf,
+    },
     Delete {
         content: String,
     },
@@ -221,7 +231,7 @@ pub fn apply_hunks(
     let _existing_paths: Vec<&Path> = hunks
         .iter()
         .filter_map(|hunk| match hunk {
-            Hunk::AddFile { .. } => {
+            Hunk::AddFile { .. } | Hunk::AddBinaryFile { .. } | Hunk::AddSymlink { .. } => {
                 // The file is being added, so it doesn't exist yet.
                 None
             }
@@ -298,6 +308,35 @@ fn apply_hunks_to_files(hunks: &[Hunk]) -> anyhow::Result<AffectedPaths> {
                     .with_context(|| format!("Failed to write file {}", path.display()))?;
                 added.push(path.clone());
             }
+            Hunk::AddBinaryFile { path, contents } => {
+                if let Some(parent) = path.parent()
+                    && !parent.as_os_str().is_empty()
+                {
+                    std::fs::create_dir_all(parent).with_context(|| {
+                        format!("Failed to create parent directories for {}", path.display())
+                    })?;
+                }
+                std::fs::write(path, contents)
+                    .with_context(|| format!("Failed to write file {}", path.display()))?;
+                added.push(path.clone());
+            }
+            Hunk::AddSymlink { path, target } => {
+                if let Some(parent) = path.parent()
+                    && !parent.as_os_str().is_empty()
+                {
+                    std::fs::create_dir_all(parent).with_context(|| {
+                        format!("Failed to create parent directories for {}", path.display())
+                    })?;
+                }
+                if std::fs::symlink_metadata(path).is_ok_and(|meta| !meta.is_dir()) {
+                    std::fs::remove_file(path).with_context(|| {
+                        format!("Failed to replace existing {}", path.display())
+                    })?;
+                }
+                create_symlink(target, path)
+                    .with_context(|| format!("Failed to create symlink {}", path.display()))?;
+                added.push(path.clone());
+            }
             Hunk::DeleteFile { path } => {
                 std::fs::remove_file(path)
                     .with_context(|| format!("Failed to delete file {}", path.display()))?;
@@ -532,6 +571,19 @@ pub fn unified_diff_from_chunks_with_context(
     })
 }
 
+#[cfg(unix)]
+fn create_symlink(target: &Path, path: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, path)
+}
+
+#[cfg(not(unix))]
+fn create_symlink(_target: &Path, _path: &Path) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "symlinks are only supported on unix",
+    ))
+}
+
 /// Print the summary of changes in git-style format.
 /// Write a summary of changes to the given writer.
 pub fn print_summary(
@@ -590,6 +642,53 @@ mod tests {
         assert_eq!(contents, "ab\ncd\n");
     }
 
+    #[test]
+    fn test_add_binary_file_hunk_writes_decoded_bytes() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("assets/logo.png");
+        let patch = wrap_patch(&format!(
+            "*** Add File: {}\n@@binary@@\niVBORw0KGgo=",
+            path.display()
+        ));
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        apply_patch(&patch, &mut stdout, &mut stderr).unwrap();
+        let stdout_str = String::from_utf8(stdout).unwrap();
+        let expected_out = format!(
+            "Success. Updated the following files:\nA {}\n",
+            path.display()
+        );
+        assert_eq!(stdout_str, expected_out);
+        assert_eq!(fs::read(path).unwrap(), b"\x89PNG\r\n\x1a\n".to_vec());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_add_symlink_hunk_creates_and_replaces_link() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("current");
+        fs::write(dir.path().join("v1.txt"), "one\n").unwrap();
+        std::os::unix::fs::symlink("v1.txt", &path).unwrap();
+        let patch = wrap_patch(&format!(
+            "*** Add File: {}\n@@symlink@@\nv2.txt",
+            path.display()
+        ));
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        apply_patch(&patch, &mut stdout, &mut stderr).unwrap();
+        let stdout_str = String::from_utf8(stdout).unwrap();
+        let expected_out = format!(
+            "Success. Updated the following files:\nA {}\n",
+            path.display()
+        );
+        assert_eq!(stdout_str, expected_out);
+        assert_eq!(fs::read_link(&path).unwrap(), PathBuf::from("v2.txt"));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("v1.txt")).unwrap(),
+            "one\n"
+        );
+    }
+
     #[test]
     fn test_delete_file_hunk_removes_file() {
         let dir = tempdir().unwrap();
@@ -8,11 +8,15 @@
 //! end_patch: "*** End Patch" LF?
 //!
 //! hunk: add_hunk | delete_hunk | update_hunk
-//! add_hunk: "*** Add File: " filename LF add_line+
+//! add_hunk: "*** Add File: " filename LF (add_line+ | binary_content | symlink_content)
 //! delete_hunk: "*** Delete File: " filename LF
 //! update_hunk: "*** Update File: " filename LF change_move? change?
 //! filename: /(.+)/
 //! add_line: "+" /(.+)/ LF -> line
+//! binary_content: "@@binary@@" LF base64_line+
+//! base64_line: /[A-Za-z0-9+\/=]+/ LF
+//! symlink_content: "@@symlink@@" LF symlink_target LF
+//! symlink_target: /(.+)/
 //!
 //! change_move: "*** Move to: " filename LF
 //! change: (change_context | change_line)+ eof_line?
@@ -23,6 +27,7 @@
 //! The parser below is a little more lenient than the explicit spec and allows for
 //! leading/trailing whitespace around patch markers.
 use crate::ApplyPatchArgs;
+use base64::Engine;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -37,6 +42,8 @@ const MOVE_TO_MARKER: &str = "*** Move to: ";
 const EOF_MARKER: &str = "*** End of File";
 const CHANGE_CONTEXT_MARKER: &str = "@@ ";
 const EMPTY_CHANGE_CONTEXT_MARKER: &str = "@@";
+const BINARY_CONTENT_MARKER: &str = "@@binary@@";
+const SYMLINK_CONTENT_MARKER: &str = "@@symlink@@";
 
 /// Currently, the only OpenAI model that knowingly requires lenient parsing is
 /// gpt-4.1. While we could try to require everyone to pass in a strictness
@@ -62,6 +69,18 @@ pub enum Hunk {
         path: PathBuf,
         contents: String,
     },
+    /// Creates a file from a base64-encoded `@@binary@@` block. The blob is
+    /// decoded while parsing so malformed content is reported with its line.
+    AddBinaryFile {
+        path: PathBuf,
+        contents: Vec<u8>,
+    },
+    /// Creates `path` as a symbolic link to `target` from a `@@symlink@@`
+    /// block, replacing any file or link already there.
+    AddSymlink {
+        path: PathBuf,
+        target: PathBuf,
+    },
     DeleteFile {
         path: PathBuf,
     },
@@ -79,6 +98,8 @@ impl Hunk {
     pub fn resolve_path(&self, cwd: &Path) -> PathBuf {
         match self {
             Hunk::AddFile { path, .. } => cwd.join(path),
+            Hunk::AddBinaryFile { path, .. } => cwd.join(path),
+            Hunk::AddSymlink { path, .. } => cwd.join(path),
             Hunk::DeleteFile { path } => cwd.join(path),
             Hunk::UpdateFile { path, .. } => cwd.join(path),
         }
@@ -249,6 +270,12 @@ fn parse_one_hunk(lines: &[&str], line_number: usize) -> Result<(Hunk, usize), P
     // Be tolerant of case mismatches and extra padding around marker strings.
     let first_line = lines[0].trim();
     if let Some(path) = first_line.strip_prefix(ADD_FILE_MARKER) {
+        if lines.get(1).map(|line| line.trim()) == Some(BINARY_CONTENT_MARKER) {
+            return parse_add_binary_file(path, &lines[2..], line_number);
+        }
+        if lines.get(1).map(|line| line.trim()) == Some(SYMLINK_CONTENT_MARKER) {
+            return parse_add_symlink(path, lines.get(2).copied(), line_number);
+        }
         // Add File
         let mut contents = String::new();
         let mut parsed_lines = 1;
@@ -340,6 +367,63 @@ fn parse_one_hunk(lines: &[&str], line_number: usize) -> Result<(Hunk, usize), P
     })
 }
 
+/// Parses the base64 lines following a `@@binary@@` marker, up to the next
+/// `***` marker. Blank lines inside the blob are ignored.
+fn parse_add_binary_file(
+    path: &str,
+    lines: &[&str],
+    line_number: usize,
+) -> Result<(Hunk, usize), ParseError> {
+    let blob_lines: Vec<&str> = lines
+        .iter()
+        .map(|line| line.trim())
+        .take_while(|line| !line.starts_with("***"))
+        .collect();
+    let blob = blob_lines.concat();
+    if blob.is_empty() {
+        return Err(InvalidHunkError {
+            message: format!("Binary add file hunk for path '{path}' is empty"),
+            line_number: line_number + 1,
+        });
+    }
+    let contents = base64::engine::general_purpose::STANDARD
+        .decode(blob)
+        .map_err(|err| InvalidHunkError {
+            message: format!("Invalid base64 content for binary file '{path}': {err}"),
+            line_number: line_number + 2,
+        })?;
+    Ok((
+        AddBinaryFile {
+            path: PathBuf::from(path),
+            contents,
+        },
+        blob_lines.len() + 2,
+    ))
+}
+
+/// Parses the single target line following a `@@symlink@@` marker. The target
+/// is kept verbatim, so it may be relative to the link's directory.
+fn parse_add_symlink(
+    path: &str,
+    target: Option<&str>,
+    line_number: usize,
+) -> Result<(Hunk, usize), ParseError> {
+    let target = target.map(str::trim).unwrap_or_default();
+    if target.is_empty() || target.starts_with("***") {
+        return Err(InvalidHunkError {
+            message: format!("Symlink add file hunk for path '{path}' is missing a target"),
+            line_number: line_number + 1,
+        });
+    }
+    Ok((
+        AddSymlink {
+            path: PathBuf::from(path),
+            target: PathBuf::from(target),
+        },
+        3,
+    ))
+}
+
 fn parse_update_file_chunk(
     lines: &[&str],
     line_number: usize,
@@ -682,6 +766,79 @@ fn test_parse_one_hunk() {
     // Other edge cases are already covered by tests above/below.
 }
 
+#[test]
+fn test_parse_binary_add_file() {
+    let patch_text = "*** Begin Patch\n\
+*** Add File: logo.png\n\
+@@binary@@\n\
+iVBORw0K\n\
+Gg==\n\
+*** Delete File: old.png\n\
+*** End Patch";
+    assert_eq!(
+        parse_patch_text(patch_text, ParseMode::Strict).map(|args| args.hunks),
+        Ok(vec![
+            AddBinaryFile {
+                path: PathBuf::from("logo.png"),
+                contents: b"\x89PNG\r\n\x1a".to_vec(),
+            },
+            DeleteFile {
+                path: PathBuf::from("old.png"),
+            },
+        ])
+    );
+
+    assert_eq!(
+        parse_one_hunk(&["*** Add File: logo.png", "@@binary@@"], 3),
+        Err(InvalidHunkError {
+            message: "Binary add file hunk for path 'logo.png' is empty".to_string(),
+            line_number: 4,
+        })
+    );
+    assert_eq!(
+        parse_one_hunk(&["*** Add File: logo.png", "@@binary@@", "not*base"], 3),
+        Err(InvalidHunkError {
+            message:
+                "Invalid base64 content for binary file 'logo.png': Invalid symbol 42, offset 3."
+                    .to_string(),
+            line_number: 5,
+        })
+    );
+}
+
+#[test]
+fn test_parse_symlink_add_file() {
+    let patch_text = "*** Begin Patch\n\
+*** Add File: current\n\
+@@symlink@@\n\
+releases/v2\n\
+*** Delete File: old.txt\n\
+*** End Patch";
+    assert_eq!(
+        parse_patch_text(patch_text, ParseMode::Strict).map(|args| args.hunks),
+        Ok(vec![
+            AddSymlink {
+                path: PathBuf::from("current"),
+                target: PathBuf::from("releases/v2"),
+            },
+            DeleteFile {
+                path: PathBuf::from("old.txt"),
+            },
+        ])
+    );
+
+    assert_eq!(
+        parse_one_hunk(
+            &["*** Add File: current", "@@symlink@@", "*** End Patch"],
+            3
+        ),
+        Err(InvalidHunkError {
+            message: "Symlink add file hunk for path 'current' is missing a target".to_string(),
+            line_number: 4,
+        })
+    );
+}
+
 #[test]
 fn test_update_file_chunk() {
     assert_eq!(
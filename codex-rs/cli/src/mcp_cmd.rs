@@ -254,6 +254,8 @@ async fn run_add(config_overrides: &CliConfigOverrides, add_args: AddArgs) -> Re
         disabled_tools: None,
         scopes: None,
         oauth_resource: None,
+        merge_structured_content: false,
+        max_mcp_retries: 0,
     };
 
     servers.insert(name.clone(), new_entry);
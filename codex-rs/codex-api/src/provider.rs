@@ -89,6 +89,15 @@ impl Provider {
         is_azure_responses_wire_base_url(&self.name, Some(&self.base_url))
     }
 
+    /// Whether the Responses endpoint accepts `max_output_tokens`. The ChatGPT
+    /// backend rejects it, so the parameter is only sent elsewhere.
+    pub fn supports_max_output_tokens(&self) -> bool {
+        !self
+            .base_url
+            .to_ascii_lowercase()
+            .contains("chatgpt.com/backend-api")
+    }
+
     pub fn websocket_url_for_path(&self, path: &str) -> Result<Url, url::ParseError> {
         let mut url = Url::parse(&self.url_for_path(path))?;
 
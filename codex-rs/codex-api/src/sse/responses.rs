@@ -163,6 +163,7 @@ pub struct ResponsesStreamEvent {
     response: Option<Value>,
     item: Option<Value>,
     delta: Option<String>,
+    item_id: Option<String>,
     summary_index: Option<i64>,
     content_index: Option<i64>,
 }
@@ -253,6 +254,11 @@ pub fn process_responses_event(
                 }));
             }
         }
+        "response.function_call_arguments.delta" => {
+            if let (Some(delta), Some(item_id)) = (event.delta, event.item_id) {
+                return Ok(Some(ResponseEvent::ToolCallInputDelta { item_id, delta }));
+            }
+        }
         "response.reasoning_text.delta" => {
             if let (Some(delta), Some(content_index)) = (event.delta, event.content_index) {
                 return Ok(Some(ResponseEvent::ReasoningContentDelta {
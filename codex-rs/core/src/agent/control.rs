@@ -11,6 +11,7 @@ use crate::session_prefix::format_subagent_context_line;
 use crate::session_prefix::format_subagent_notification_message;
 use crate::shell_snapshot::ShellSnapshot;
 use crate::state_db;
+use crate::thread_manager::NewThread;
 use crate::thread_manager::ThreadManagerState;
 use codex_protocol::ThreadId;
 use codex_protocol::models::FunctionCallOutputPayload;
@@ -306,6 +307,8 @@ impl AgentControl {
                 Op::UserInput {
                     items,
                     final_output_json_schema: None,
+                    effort: None,
+                    tool_choice: None,
                 },
             )
             .await;
@@ -464,6 +467,30 @@ impl AgentControl {
         });
     }
 
+    /// Start a new thread seeded with `rollout_items` forked from an existing
+    /// thread. Unlike agent spawns, a fork takes no spawn slot and receives no
+    /// initial input.
+    pub(crate) async fn fork_thread(
+        &self,
+        config: crate::config::Config,
+        rollout_items: Vec<RolloutItem>,
+        session_source: SessionSource,
+    ) -> CodexResult<NewThread> {
+        let state = self.upgrade()?;
+        let new_thread = state
+            .fork_thread_with_source(
+                config,
+                InitialHistory::Forked(rollout_items),
+                self.clone(),
+                session_source,
+                false,
+                None,
+            )
+            .await?;
+        state.notify_thread_created(new_thread.thread_id);
+        Ok(new_thread)
+    }
+
     fn upgrade(&self) -> CodexResult<Arc<ThreadManagerState>> {
         self.manager
             .upgrade()
@@ -674,6 +701,7 @@ mod tests {
         let status = agent_status_from_event(&EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("done".to_string()),
+            cost_usd: None,
         }));
         let expected = AgentStatus::Completed(Some("done".to_string()));
         assert_eq!(status, Some(expected));
@@ -825,6 +853,8 @@ mod tests {
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             },
         );
         let captured = harness
@@ -856,6 +886,8 @@ mod tests {
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             },
         );
         let captured = harness
@@ -931,6 +963,8 @@ mod tests {
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             },
         );
         let captured = harness
@@ -1,4 +1,5 @@
 use chrono::DateTime;
+use chrono::TimeDelta;
 use chrono::Utc;
 use codex_api::AuthProvider as ApiAuthProvider;
 use codex_api::TransportError;
@@ -10,6 +11,7 @@ use serde::Deserialize;
 
 use crate::auth::CodexAuth;
 use crate::error::CodexErr;
+use crate::error::QuotaType;
 use crate::error::RetryLimitReachedError;
 use crate::error::UnexpectedResponseError;
 use crate::error::UsageLimitReachedError;
@@ -19,7 +21,10 @@ use crate::token_data::PlanType;
 pub(crate) fn map_api_error(err: ApiError) -> CodexErr {
     match err {
         ApiError::ContextWindowExceeded => CodexErr::ContextWindowExceeded,
-        ApiError::QuotaExceeded => CodexErr::QuotaExceeded,
+        ApiError::QuotaExceeded => CodexErr::QuotaExceeded {
+            reset_at: None,
+            quota_type: QuotaType::Unknown,
+        },
         ApiError::UsageNotIncluded => CodexErr::UsageNotIncluded,
         ApiError::Retryable { message, delay } => CodexErr::Stream(message, delay),
         ApiError::Stream(msg) => CodexErr::Stream(msg, None),
@@ -84,6 +89,10 @@ pub(crate) fn map_api_error(err: ApiError) -> CodexErr {
                             });
                         } else if err.error.error_type.as_deref() == Some("usage_not_included") {
                             return CodexErr::UsageNotIncluded;
+                        } else if err.error.error_type.as_deref() == Some(INSUFFICIENT_QUOTA)
+                            || err.error.code.as_deref() == Some(INSUFFICIENT_QUOTA)
+                        {
+                            return quota_exceeded_from_headers(headers.as_ref(), Utc::now());
                         }
                     }
 
@@ -115,6 +124,14 @@ pub(crate) fn map_api_error(err: ApiError) -> CodexErr {
 }
 
 const ACTIVE_LIMIT_HEADER: &str = "x-codex-active-limit";
+const RATELIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+const RATELIMIT_REMAINING_REQUESTS_HEADER: &str = "x-ratelimit-remaining-requests";
+const RATELIMIT_REMAINING_TOKENS_HEADER: &str = "x-ratelimit-remaining-tokens";
+const RETRY_AFTER_HEADER: &str = "retry-after";
+const INSUFFICIENT_QUOTA: &str = "insufficient_quota";
+/// `x-ratelimit-reset` values at or above this are unix timestamps; smaller
+/// values are a number of seconds from now.
+const MIN_UNIX_TIMESTAMP_SECONDS: i64 = 1_000_000_000;
 const REQUEST_ID_HEADER: &str = "x-request-id";
 const OAI_REQUEST_ID_HEADER: &str = "x-oai-request-id";
 const CF_RAY_HEADER: &str = "cf-ray";
@@ -217,6 +234,72 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn map_api_error_maps_insufficient_quota_with_reset_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RATELIMIT_RESET_HEADER,
+            http::HeaderValue::from_static("1767225600"),
+        );
+        headers.insert(
+            RATELIMIT_REMAINING_TOKENS_HEADER,
+            http::HeaderValue::from_static("0"),
+        );
+        let body = serde_json::json!({
+            "error": {
+                "type": "insufficient_quota",
+                "code": "insufficient_quota",
+            }
+        })
+        .to_string();
+        let err = map_api_error(ApiError::Transport(TransportError::Http {
+            status: http::StatusCode::TOO_MANY_REQUESTS,
+            url: Some("http://example.com/v1/responses".to_string()),
+            headers: Some(headers),
+            body: Some(body),
+        }));
+
+        let CodexErr::QuotaExceeded {
+            reset_at,
+            quota_type,
+        } = err
+        else {
+            panic!("expected CodexErr::QuotaExceeded, got {err:?}");
+        };
+        assert_eq!(reset_at, DateTime::<Utc>::from_timestamp(1_767_225_600, 0));
+        assert_eq!(quota_type, QuotaType::Tokens);
+    }
+
+    #[test]
+    fn parse_quota_reset_at_accepts_relative_and_http_date_values() {
+        let now = DateTime::<Utc>::from_timestamp(1_767_225_600, 0).unwrap();
+        let headers_with = |name, value| {
+            let mut headers = HeaderMap::new();
+            headers.insert(name, http::HeaderValue::from_static(value));
+            headers
+        };
+
+        assert_eq!(
+            parse_quota_reset_at(Some(&headers_with(RATELIMIT_RESET_HEADER, "60")), now),
+            DateTime::<Utc>::from_timestamp(1_767_225_660, 0)
+        );
+        assert_eq!(
+            parse_quota_reset_at(Some(&headers_with(RETRY_AFTER_HEADER, "120")), now),
+            DateTime::<Utc>::from_timestamp(1_767_225_720, 0)
+        );
+        assert_eq!(
+            parse_quota_reset_at(
+                Some(&headers_with(
+                    RETRY_AFTER_HEADER,
+                    "Thu, 01 Jan 2026 01:00:00 GMT"
+                )),
+                now
+            ),
+            DateTime::<Utc>::from_timestamp(1_767_229_200, 0)
+        );
+        assert_eq!(parse_quota_reset_at(None, now), None);
+    }
 }
 
 fn extract_request_tracking_id(headers: Option<&HeaderMap>) -> Option<String> {
@@ -228,6 +311,49 @@ fn extract_request_id(headers: Option<&HeaderMap>) -> Option<String> {
         .or_else(|| extract_header(headers, OAI_REQUEST_ID_HEADER))
 }
 
+fn quota_exceeded_from_headers(headers: Option<&HeaderMap>, now: DateTime<Utc>) -> CodexErr {
+    let header_is_zero = |name| {
+        extract_header(headers, name).is_some_and(|value| value.trim().parse::<u64>() == Ok(0))
+    };
+    let quota_type = if header_is_zero(RATELIMIT_REMAINING_REQUESTS_HEADER) {
+        QuotaType::Requests
+    } else if header_is_zero(RATELIMIT_REMAINING_TOKENS_HEADER) {
+        QuotaType::Tokens
+    } else {
+        QuotaType::Unknown
+    };
+    CodexErr::QuotaExceeded {
+        reset_at: parse_quota_reset_at(headers, now),
+        quota_type,
+    }
+}
+
+/// Reads when an exhausted quota resets from `x-ratelimit-reset` (a unix
+/// timestamp or seconds from now) or, failing that, `retry-after` (seconds or
+/// an HTTP date).
+fn parse_quota_reset_at(headers: Option<&HeaderMap>, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let seconds_from_now = |seconds: i64| {
+        TimeDelta::try_seconds(seconds).and_then(|delta| now.checked_add_signed(delta))
+    };
+    if let Some(seconds) = extract_header(headers, RATELIMIT_RESET_HEADER)
+        .and_then(|value| value.trim().parse::<i64>().ok())
+    {
+        return if seconds >= MIN_UNIX_TIMESTAMP_SECONDS {
+            DateTime::<Utc>::from_timestamp(seconds, 0)
+        } else {
+            seconds_from_now(seconds)
+        };
+    }
+    let retry_after = extract_header(headers, RETRY_AFTER_HEADER)?;
+    let retry_after = retry_after.trim();
+    match retry_after.parse::<i64>() {
+        Ok(seconds) => seconds_from_now(seconds),
+        Err(_) => DateTime::parse_from_rfc2822(retry_after)
+            .ok()
+            .map(|reset_at| reset_at.with_timezone(&Utc)),
+    }
+}
+
 fn extract_header(headers: Option<&HeaderMap>, name: &str) -> Option<String> {
     headers.and_then(|map| {
         map.get(name)
@@ -277,6 +403,7 @@ struct UsageErrorResponse {
 struct UsageErrorBody {
     #[serde(rename = "type")]
     error_type: Option<String>,
+    code: Option<String>,
     plan_type: Option<PlanType>,
     resets_at: Option<i64>,
 }
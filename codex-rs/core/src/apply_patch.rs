@@ -1,12 +1,18 @@
 use crate::codex::TurnContext;
 use crate::function_tool::FunctionCallError;
 use crate::protocol::FileChange;
+use crate::protocol::PatchCheckOutcome;
+use crate::protocol::PatchConflict;
 use crate::safety::SafetyCheck;
 use crate::safety::assess_patch_safety;
 use crate::tools::sandboxing::ExecApprovalRequirement;
 use codex_apply_patch::ApplyPatchAction;
+use codex_apply_patch::ApplyPatchError;
 use codex_apply_patch::ApplyPatchFileChange;
+use codex_apply_patch::Hunk;
+use codex_apply_patch::MaybeApplyPatchVerified;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 
 pub(crate) enum InternalApplyPatchInvocation {
@@ -84,6 +90,12 @@ pub(crate) fn convert_apply_patch_to_protocol(
             ApplyPatchFileChange::Add { content } => FileChange::Add {
                 content: content.clone(),
             },
+            ApplyPatchFileChange::Binary { base64_content } => FileChange::Binary {
+                base64_content: base64_content.clone(),
+            },
+            ApplyPatchFileChange::Symlink { target } => FileChange::Symlink {
+                target: target.clone(),
+            },
             ApplyPatchFileChange::Delete { content } => FileChange::Delete {
                 content: content.clone(),
             },
@@ -101,6 +113,61 @@ pub(crate) fn convert_apply_patch_to_protocol(
     result
 }
 
+/// Checks whether `patch` applies to the files under `cwd` without writing anything.
+pub(crate) fn check_patch_applies(patch: &str, cwd: &Path) -> PatchCheckOutcome {
+    let argv = vec!["apply_patch".to_string(), patch.to_string()];
+    match codex_apply_patch::maybe_parse_apply_patch_verified(&argv, cwd) {
+        MaybeApplyPatchVerified::Body(_) => PatchCheckOutcome::AppliesCleanly,
+        MaybeApplyPatchVerified::CorrectnessError(ApplyPatchError::ParseError(err)) => {
+            PatchCheckOutcome::ParseError {
+                message: err.to_string(),
+            }
+        }
+        MaybeApplyPatchVerified::CorrectnessError(err) => PatchCheckOutcome::Conflicts {
+            files: patch_conflicts(patch, cwd, &err),
+        },
+        MaybeApplyPatchVerified::ShellParseError(err) => PatchCheckOutcome::ParseError {
+            message: format!("{err:?}"),
+        },
+        MaybeApplyPatchVerified::NotApplyPatch => PatchCheckOutcome::ParseError {
+            message: "not an apply_patch patch".to_string(),
+        },
+    }
+}
+
+/// `maybe_parse_apply_patch_verified` stops at the first hunk that fails, so
+/// re-check each hunk on its own to report every conflicting file.
+fn patch_conflicts(patch: &str, cwd: &Path, first_error: &ApplyPatchError) -> Vec<PatchConflict> {
+    let hunks = codex_apply_patch::parse_patch(patch)
+        .map(|args| args.hunks)
+        .unwrap_or_default();
+    let mut conflicts = Vec::new();
+    for hunk in hunks {
+        let path = hunk.resolve_path(cwd);
+        let message = match &hunk {
+            Hunk::AddFile { .. } | Hunk::AddBinaryFile { .. } | Hunk::AddSymlink { .. } => None,
+            Hunk::DeleteFile { .. } => std::fs::read_to_string(&path)
+                .err()
+                .map(|err| format!("Failed to read {}: {err}", path.display())),
+            Hunk::UpdateFile { chunks, .. } => {
+                codex_apply_patch::unified_diff_from_chunks(&path, chunks)
+                    .err()
+                    .map(|err| err.to_string())
+            }
+        };
+        if let Some(message) = message {
+            conflicts.push(PatchConflict { path, message });
+        }
+    }
+    if conflicts.is_empty() {
+        conflicts.push(PatchConflict {
+            path: cwd.to_path_buf(),
+            message: first_error.to_string(),
+        });
+    }
+    conflicts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +191,44 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn check_patch_applies_reports_clean_patch() {
+        let tmp = tempdir().expect("tmp");
+        std::fs::write(tmp.path().join("a.txt"), "old\n").expect("write a.txt");
+        let patch = "*** Begin Patch\n*** Update File: a.txt\n@@\n-old\n+new\n*** End Patch";
+
+        assert_eq!(
+            check_patch_applies(patch, tmp.path()),
+            PatchCheckOutcome::AppliesCleanly
+        );
+        assert_eq!(
+            std::fs::read_to_string(tmp.path().join("a.txt")).expect("read a.txt"),
+            "old\n"
+        );
+    }
+
+    #[test]
+    fn check_patch_applies_lists_drifted_files() {
+        let tmp = tempdir().expect("tmp");
+        std::fs::write(tmp.path().join("a.txt"), "old\n").expect("write a.txt");
+        std::fs::write(tmp.path().join("b.txt"), "drifted\n").expect("write b.txt");
+        let patch = "*** Begin Patch\n*** Update File: a.txt\n@@\n-old\n+new\n*** Update File: b.txt\n@@\n-old\n+new\n*** End Patch";
+
+        let PatchCheckOutcome::Conflicts { files } = check_patch_applies(patch, tmp.path()) else {
+            panic!("expected conflicts");
+        };
+        let paths: Vec<PathBuf> = files.into_iter().map(|conflict| conflict.path).collect();
+        assert_eq!(paths, vec![tmp.path().join("b.txt")]);
+    }
+
+    #[test]
+    fn check_patch_applies_reports_parse_error() {
+        let tmp = tempdir().expect("tmp");
+
+        assert!(matches!(
+            check_patch_applies("not a patch", tmp.path()),
+            PatchCheckOutcome::ParseError { .. }
+        ));
+    }
 }
@@ -395,6 +395,7 @@ fn build_arc_monitor_message_item(
         | ResponseItem::FunctionCallOutput { .. }
         | ResponseItem::CustomToolCallOutput { .. }
         | ResponseItem::ImageGenerationCall { .. }
+        | ResponseItem::ProviderToolCall { .. }
         | ResponseItem::GhostSnapshot { .. }
         | ResponseItem::Compaction { .. }
         | ResponseItem::Other => None,
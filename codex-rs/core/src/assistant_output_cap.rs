@@ -0,0 +1,156 @@
+//! Caps how much assistant text a single turn may produce, so a runaway
+//! response cannot grow without bound.
+
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_utils_string::take_bytes_at_char_boundary;
+
+/// Appended to an assistant message that was cut at the cap.
+pub(crate) const ASSISTANT_OUTPUT_TRUNCATED_MARKER: &str = "\n\n[output truncated]";
+
+/// Tracks assistant output across every sampling request of a turn. Streamed
+/// deltas and completed messages are counted separately because the
+/// completed message repeats the text its deltas already carried.
+#[derive(Debug)]
+pub(crate) struct AssistantOutputCap {
+    /// Maximum bytes of assistant text per turn; `None` disables the cap.
+    limit: Option<usize>,
+    streamed: usize,
+    completed: usize,
+    exceeded: bool,
+    warning_pending: bool,
+}
+
+impl AssistantOutputCap {
+    pub(crate) fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            streamed: 0,
+            completed: 0,
+            exceeded: false,
+            warning_pending: false,
+        }
+    }
+
+    /// Returns the part of a streamed `delta` that still fits under the cap,
+    /// or `None` once the cap has been reached.
+    pub(crate) fn admit_delta(&mut self, delta: String) -> Option<String> {
+        let Some(limit) = self.limit else {
+            return Some(delta);
+        };
+        let remaining = limit.saturating_sub(self.streamed);
+        if delta.len() <= remaining {
+            self.streamed += delta.len();
+            return Some(delta);
+        }
+        self.mark_exceeded();
+        let kept = take_bytes_at_char_boundary(&delta, remaining);
+        self.streamed = limit;
+        (!kept.is_empty()).then(|| kept.to_string())
+    }
+
+    /// Cuts the text of a completed assistant message that goes past the cap
+    /// and appends [`ASSISTANT_OUTPUT_TRUNCATED_MARKER`].
+    pub(crate) fn truncate_item(&mut self, item: &mut ResponseItem) {
+        let Some(limit) = self.limit else {
+            return;
+        };
+        let ResponseItem::Message { role, content, .. } = item else {
+            return;
+        };
+        if role != "assistant" {
+            return;
+        }
+        let mut truncated = false;
+        for entry in content.iter_mut() {
+            let ContentItem::OutputText { text } = entry else {
+                continue;
+            };
+            let remaining = limit.saturating_sub(self.completed);
+            if text.len() <= remaining {
+                self.completed += text.len();
+                continue;
+            }
+            let kept = take_bytes_at_char_boundary(text, remaining).len();
+            text.truncate(kept);
+            if !truncated {
+                text.push_str(ASSISTANT_OUTPUT_TRUNCATED_MARKER);
+                truncated = true;
+            }
+            self.completed = limit;
+        }
+        if truncated {
+            self.mark_exceeded();
+        }
+    }
+
+    /// Returns the warning to show the first time the cap is hit in a turn.
+    pub(crate) fn take_warning(&mut self) -> Option<String> {
+        if !std::mem::take(&mut self.warning_pending) {
+            return None;
+        }
+        let limit = self.limit?;
+        Some(format!(
+            "The model's response exceeded the {limit}-byte output limit for this turn and was truncated."
+        ))
+    }
+
+    fn mark_exceeded(&mut self) {
+        if !self.exceeded {
+            self.exceeded = true;
+            self.warning_pending = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn assistant_message(text: &str) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: "assistant".to_string(),
+            content: vec![ContentItem::OutputText {
+                text: text.to_string(),
+            }],
+            end_turn: None,
+            phase: None,
+        }
+    }
+
+    #[test]
+    fn deltas_stop_at_the_cap_and_warn_once() {
+        let mut cap = AssistantOutputCap::new(Some(8));
+        assert_eq!(
+            cap.admit_delta("hello".to_string()),
+            Some("hello".to_string())
+        );
+        assert_eq!(cap.take_warning(), None);
+        assert_eq!(
+            cap.admit_delta(" world".to_string()),
+            Some(" wo".to_string())
+        );
+        assert_eq!(cap.admit_delta("!".to_string()), None);
+        assert!(cap.take_warning().is_some());
+        assert_eq!(cap.take_warning(), None);
+    }
+
+    #[test]
+    fn completed_message_is_truncated_with_marker() {
+        let mut cap = AssistantOutputCap::new(Some(5));
+        let mut item = assistant_message("hello world");
+        cap.truncate_item(&mut item);
+        assert_eq!(
+            item,
+            assistant_message(&format!("hello{ASSISTANT_OUTPUT_TRUNCATED_MARKER}"))
+        );
+
+        let mut unlimited = AssistantOutputCap::new(None);
+        let mut item = assistant_message("hello world");
+        unlimited.truncate_item(&mut item);
+        assert_eq!(item, assistant_message("hello world"));
+        assert_eq!(unlimited.take_warning(), None);
+    }
+}
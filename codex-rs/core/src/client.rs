@@ -71,6 +71,7 @@ use eventsource_stream::Event;
 use eventsource_stream::EventStreamError;
 use futures::StreamExt;
 use http::HeaderMap as ApiHeaderMap;
+use http::HeaderName;
 use http::HeaderValue;
 use http::StatusCode as HttpStatusCode;
 use reqwest::StatusCode;
@@ -90,7 +91,9 @@ use crate::client_common::Prompt;
 use crate::client_common::ResponseEvent;
 use crate::client_common::ResponseStream;
 use crate::config::Config;
+use crate::config::types::ClientMetadata;
 use crate::default_client::build_reqwest_client;
+use crate::default_client::get_codex_user_agent;
 use crate::error::CodexErr;
 use crate::error::Result;
 use crate::flags::CODEX_RS_SSE_FIXTURE;
@@ -103,6 +106,8 @@ pub const X_CODEX_TURN_STATE_HEADER: &str = "x-codex-turn-state";
 pub const X_CODEX_TURN_METADATA_HEADER: &str = "x-codex-turn-metadata";
 pub const X_RESPONSESAPI_INCLUDE_TIMING_METRICS_HEADER: &str =
     "x-responsesapi-include-timing-metrics";
+pub const X_CODEX_CLIENT_APP_NAME_HEADER: &str = "x-codex-client-app-name";
+pub const X_CODEX_CLIENT_APP_VERSION_HEADER: &str = "x-codex-client-app-version";
 const RESPONSES_WEBSOCKETS_V2_BETA_HEADER_VALUE: &str = "responses_websockets=2026-02-06";
 
 pub fn ws_version_from_features(config: &Config) -> bool {
@@ -129,6 +134,8 @@ struct ModelClientState {
     enable_request_compression: bool,
     include_timing_metrics: bool,
     beta_features_header: Option<String>,
+    /// Headers identifying the embedding application, sent on every request.
+    client_metadata_headers: ApiHeaderMap,
     disable_websockets: AtomicBool,
     cached_websocket_session: StdMutex<WebsocketSession>,
 }
@@ -223,6 +230,7 @@ impl ModelClient {
         enable_request_compression: bool,
         include_timing_metrics: bool,
         beta_features_header: Option<String>,
+        client_metadata: Option<ClientMetadata>,
     ) -> Self {
         Self {
             state: Arc::new(ModelClientState {
@@ -235,6 +243,7 @@ impl ModelClient {
                 enable_request_compression,
                 include_timing_metrics,
                 beta_features_header,
+                client_metadata_headers: build_client_metadata_headers(client_metadata.as_ref()),
                 disable_websockets: AtomicBool::new(false),
                 cached_websocket_session: StdMutex::new(WebsocketSession::default()),
             }),
@@ -325,6 +334,7 @@ impl ModelClient {
         extra_headers.extend(build_conversation_headers(Some(
             self.state.conversation_id.to_string(),
         )));
+        extra_headers.extend(self.state.client_metadata_headers.clone());
         client
             .compact_input(&payload, extra_headers)
             .await
@@ -364,8 +374,10 @@ impl ModelClient {
             }),
         };
 
+        let mut extra_headers = self.build_subagent_headers();
+        extra_headers.extend(self.state.client_metadata_headers.clone());
         client
-            .summarize_input(&payload, self.build_subagent_headers())
+            .summarize_input(&payload, extra_headers)
             .await
             .map_err(map_api_error)
     }
@@ -497,6 +509,7 @@ impl ModelClient {
             headers.insert("x-client-request-id", header_value);
         }
         headers.extend(build_conversation_headers(Some(conversation_id)));
+        headers.extend(self.state.client_metadata_headers.clone());
         headers.insert(
             OPENAI_BETA_HEADER,
             HeaderValue::from_static(RESPONSES_WEBSOCKETS_V2_BETA_HEADER_VALUE),
@@ -575,12 +588,15 @@ impl ModelClientSession {
         };
         let text = create_text_param_for_request(verbosity, &prompt.output_schema);
         let prompt_cache_key = Some(self.client.state.conversation_id.to_string());
+        let max_output_tokens = prompt
+            .max_output_tokens
+            .filter(|_| provider.supports_max_output_tokens());
         let request = ResponsesApiRequest {
             model: model_info.slug.clone(),
             instructions: instructions.clone(),
             input,
             tools,
-            tool_choice: "auto".to_string(),
+            tool_choice: prompt.tool_choice_param(),
             parallel_tool_calls: prompt.parallel_tool_calls,
             reasoning,
             store: provider.is_azure_responses_endpoint(),
@@ -593,6 +609,7 @@ impl ModelClientSession {
             },
             prompt_cache_key,
             text,
+            max_output_tokens,
         };
         Ok(request)
     }
@@ -609,14 +626,16 @@ impl ModelClientSession {
     ) -> ApiResponsesOptions {
         let turn_metadata_header = parse_turn_metadata_header(turn_metadata_header);
         let conversation_id = self.client.state.conversation_id.to_string();
+        let mut extra_headers = build_responses_headers(
+            self.client.state.beta_features_header.as_deref(),
+            Some(&self.turn_state),
+            turn_metadata_header.as_ref(),
+        );
+        extra_headers.extend(self.client.state.client_metadata_headers.clone());
         ApiResponsesOptions {
             conversation_id: Some(conversation_id),
             session_source: Some(self.client.state.session_source.clone()),
-            extra_headers: build_responses_headers(
-                self.client.state.beta_features_header.as_deref(),
-                Some(&self.turn_state),
-                turn_metadata_header.as_ref(),
-            ),
+            extra_headers,
             compression,
             turn_state: Some(Arc::clone(&self.turn_state)),
         }
@@ -1092,6 +1111,45 @@ impl ModelClientSession {
     }
 }
 
+/// Builds the headers configured via `client_metadata`.
+///
+/// The `User-Agent` keeps Codex's own identifier and appends the suffix in parentheses. Values
+/// that are not valid header values are dropped with a warning.
+fn build_client_metadata_headers(client_metadata: Option<&ClientMetadata>) -> ApiHeaderMap {
+    let mut headers = ApiHeaderMap::new();
+    let Some(client_metadata) = client_metadata else {
+        return headers;
+    };
+    let user_agent = client_metadata
+        .user_agent_suffix
+        .as_deref()
+        .map(str::trim)
+        .filter(|suffix| !suffix.is_empty())
+        .map(|suffix| format!("{} ({suffix})", get_codex_user_agent()));
+    for (name, value) in [
+        (http::header::USER_AGENT, user_agent.as_deref()),
+        (
+            HeaderName::from_static(X_CODEX_CLIENT_APP_NAME_HEADER),
+            client_metadata.app_name.as_deref(),
+        ),
+        (
+            HeaderName::from_static(X_CODEX_CLIENT_APP_VERSION_HEADER),
+            client_metadata.app_version.as_deref(),
+        ),
+    ] {
+        let Some(value) = value else {
+            continue;
+        };
+        match HeaderValue::from_str(value) {
+            Ok(header_value) => {
+                headers.insert(name, header_value);
+            }
+            Err(err) => warn!("ignoring invalid client metadata for `{name}` header: {err}"),
+        }
+    }
+    headers
+}
+
 /// Parses per-turn metadata into an HTTP header value.
 ///
 /// Invalid values are treated as absent so callers can compare and propagate
@@ -1326,6 +1384,7 @@ mod tests {
             false,
             false,
             None,
+            None,
         )
     }
 
@@ -2,12 +2,14 @@ use crate::client_common::tools::ToolSpec;
 use crate::config::types::Personality;
 use crate::error::Result;
 pub use codex_api::common::ResponseEvent;
+use codex_protocol::config_types::ToolChoice;
 use codex_protocol::models::BaseInstructions;
 use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::ResponseItem;
 use futures::Stream;
 use serde::Deserialize;
 use serde_json::Value;
+use serde_json::json;
 use std::collections::HashSet;
 use std::pin::Pin;
 use std::task::Context;
@@ -42,6 +44,12 @@ pub struct Prompt {
 
     /// Optional the output schema for the model's response.
     pub output_schema: Option<Value>,
+
+    /// Whether the model may, must not, or must call a specific tool.
+    pub(crate) tool_choice: ToolChoice,
+
+    /// Upper bound on the tokens the model may generate for this request.
+    pub(crate) max_output_tokens: Option<u32>,
 }
 
 impl Prompt {
@@ -62,6 +70,24 @@ impl Prompt {
 
         input
     }
+
+    /// The Responses API `tool_choice` value for this prompt. A named tool is
+    /// addressed by the type it is declared with in `tools`.
+    pub(crate) fn tool_choice_param(&self) -> Value {
+        match &self.tool_choice {
+            ToolChoice::Auto => Value::from("auto"),
+            ToolChoice::None => Value::from("none"),
+            ToolChoice::Tool(name) => match self.tools.iter().find(|tool| tool.name() == name) {
+                Some(ToolSpec::Freeform(_)) => json!({ "type": "custom", "name": name }),
+                Some(
+                    tool @ (ToolSpec::LocalShell {}
+                    | ToolSpec::ImageGeneration { .. }
+                    | ToolSpec::WebSearch { .. }),
+                ) => json!({ "type": tool.name() }),
+                Some(ToolSpec::Function(_)) | None => json!({ "type": "function", "name": name }),
+            },
+        }
+    }
 }
 
 fn reserialize_shell_outputs(items: &mut [ResponseItem]) {
@@ -288,6 +314,7 @@ impl Stream for ResponseStream {
 
 #[cfg(test)]
 mod tests {
+    use crate::tools::handlers::apply_patch::create_apply_patch_freeform_tool;
     use codex_api::ResponsesApiRequest;
     use codex_api::common::OpenAiVerbosity;
     use codex_api::common::TextControls;
@@ -307,7 +334,7 @@ mod tests {
             instructions: "i".to_string(),
             input,
             tools,
-            tool_choice: "auto".to_string(),
+            tool_choice: "auto".into(),
             parallel_tool_calls: true,
             reasoning: None,
             store: false,
@@ -319,6 +346,7 @@ mod tests {
                 verbosity: Some(OpenAiVerbosity::Low),
                 format: None,
             }),
+            max_output_tokens: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -349,7 +377,7 @@ mod tests {
             instructions: "i".to_string(),
             input,
             tools,
-            tool_choice: "auto".to_string(),
+            tool_choice: "auto".into(),
             parallel_tool_calls: true,
             reasoning: None,
             store: false,
@@ -358,6 +386,7 @@ mod tests {
             prompt_cache_key: None,
             service_tier: None,
             text: Some(text_controls),
+            max_output_tokens: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -386,7 +415,7 @@ mod tests {
             instructions: "i".to_string(),
             input,
             tools,
-            tool_choice: "auto".to_string(),
+            tool_choice: "auto".into(),
             parallel_tool_calls: true,
             reasoning: None,
             store: false,
@@ -395,6 +424,7 @@ mod tests {
             prompt_cache_key: None,
             service_tier: None,
             text: None,
+            max_output_tokens: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -408,7 +438,7 @@ mod tests {
             instructions: "i".to_string(),
             input: vec![],
             tools: vec![],
-            tool_choice: "auto".to_string(),
+            tool_choice: "auto".into(),
             parallel_tool_calls: true,
             reasoning: None,
             store: false,
@@ -417,6 +447,7 @@ mod tests {
             prompt_cache_key: None,
             service_tier: Some(ServiceTier::Flex.to_string()),
             text: None,
+            max_output_tokens: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -483,4 +514,40 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn tool_choice_param_addresses_tools_by_declared_type() {
+        let mut prompt = Prompt {
+            tools: vec![
+                create_apply_patch_freeform_tool(),
+                ToolSpec::WebSearch {
+                    external_web_access: None,
+                    filters: None,
+                    user_location: None,
+                    search_context_size: None,
+                    search_content_types: None,
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(prompt.tool_choice_param(), json!("auto"));
+
+        prompt.tool_choice = ToolChoice::None;
+        assert_eq!(prompt.tool_choice_param(), json!("none"));
+
+        prompt.tool_choice = ToolChoice::Tool("apply_patch".to_string());
+        assert_eq!(
+            prompt.tool_choice_param(),
+            json!({ "type": "custom", "name": "apply_patch" })
+        );
+
+        prompt.tool_choice = ToolChoice::Tool("web_search".to_string());
+        assert_eq!(prompt.tool_choice_param(), json!({ "type": "web_search" }));
+
+        prompt.tool_choice = ToolChoice::Tool("shell".to_string());
+        assert_eq!(
+            prompt.tool_choice_param(),
+            json!({ "type": "function", "name": "shell" })
+        );
+    }
 }
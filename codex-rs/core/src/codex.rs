@@ -5,6 +5,8 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::AuthManager;
 use crate::CodexAuth;
@@ -25,6 +27,7 @@ use crate::compact::should_use_remote_compact_task;
 use crate::compact_remote::run_inline_remote_auto_compact_task;
 use crate::config::ManagedFeatures;
 use crate::connectors;
+use crate::contextual_user_message::CONTEXT_FILE_FRAGMENT;
 use crate::exec_policy::ExecPolicyManager;
 use crate::features::FEATURES;
 use crate::features::Feature;
@@ -34,6 +37,7 @@ use crate::models_manager::collaboration_mode_presets::CollaborationModesConfig;
 use crate::models_manager::manager::ModelsManager;
 use crate::parse_command::parse_command;
 use crate::parse_turn_item;
+use crate::path_utils::canonicalize_existing_prefix;
 use crate::realtime_conversation::RealtimeConversationManager;
 use crate::realtime_conversation::handle_audio as handle_realtime_conversation_audio;
 use crate::realtime_conversation::handle_close as handle_realtime_conversation_close;
@@ -160,19 +164,29 @@ use crate::config::GhostSnapshotConfig;
 use crate::config::StartedNetworkProxy;
 use crate::config::resolve_web_search_mode_for_turn;
 use crate::config::types::McpServerConfig;
+use crate::config::types::PathOutsideRootsPolicy;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::context_manager::ContextManager;
 use crate::context_manager::TotalTokenUsageBreakdown;
 use crate::environment_context::EnvironmentContext;
+use crate::environment_context::PlatformContext;
 use crate::error::CodexErr;
 use crate::error::Result as CodexResult;
 #[cfg(test)]
 use crate::exec::StreamOutput;
+use crate::exec_env::missing_allow_listed_vars;
+use crate::function_tool::FunctionCallError;
 use codex_config::CONFIG_TOML_FILE;
 
+mod background_event_coalescer;
+#[cfg(test)]
+mod background_event_coalescer_tests;
 mod rollout_reconstruction;
 #[cfg(test)]
 mod rollout_reconstruction_tests;
+mod state_snapshot;
+
+use background_event_coalescer::BackgroundEventCoalescer;
 
 #[derive(Debug, PartialEq)]
 pub enum SteerInputError {
@@ -193,8 +207,11 @@ pub(crate) struct PreviousTurnSettings {
     pub(crate) realtime_active: Option<bool>,
 }
 
+use crate::assistant_output_cap::AssistantOutputCap;
 use crate::exec_policy::ExecPolicyUpdateError;
 use crate::feedback_tags;
+use crate::file_attachments::FileAttachments;
+use crate::file_attachments::build_file_attachments;
 use crate::file_watcher::FileWatcher;
 use crate::file_watcher::FileWatcherEvent;
 use crate::git_info::get_git_repo_root;
@@ -215,9 +232,13 @@ use crate::mentions::build_skill_name_counts;
 use crate::mentions::collect_explicit_app_ids;
 use crate::mentions::collect_explicit_plugin_mentions;
 use crate::mentions::collect_tool_mentions_from_messages;
+use crate::metrics::Metrics;
 use crate::network_policy_decision::execpolicy_network_rule_amendment;
+use crate::plugins::PluginTaskHookStage;
 use crate::plugins::PluginsManager;
 use crate::plugins::build_plugin_injections;
+use crate::plugins::plugin_namespace_for_skill_path;
+use crate::plugins::run_plugin_task_hooks;
 use crate::project_doc::get_user_instructions;
 use crate::protocol::AgentMessageContentDeltaEvent;
 use crate::protocol::AgentReasoningSectionBreakEvent;
@@ -229,6 +250,7 @@ use crate::protocol::DeprecationNoticeEvent;
 use crate::protocol::ErrorEvent;
 use crate::protocol::Event;
 use crate::protocol::EventMsg;
+use crate::protocol::ExecApprovalBatchRequestEvent;
 use crate::protocol::ExecApprovalRequestEvent;
 use crate::protocol::McpServerRefreshConfig;
 use crate::protocol::ModelRerouteEvent;
@@ -256,6 +278,8 @@ use crate::protocol::TokenUsage;
 use crate::protocol::TokenUsageInfo;
 use crate::protocol::TurnDiffEvent;
 use crate::protocol::WarningEvent;
+use crate::rate_limiter::RateLimitExceeded;
+use crate::recent_diffs::TurnDiffSummary;
 use crate::rollout::RolloutRecorder;
 use crate::rollout::RolloutRecorderParams;
 use crate::rollout::map_session_init_error;
@@ -276,6 +300,8 @@ use crate::skills::injection::app_id_from_path;
 use crate::skills::injection::tool_kind_for_path;
 use crate::skills::resolve_skill_dependencies_for_turn;
 use crate::state::ActiveTurn;
+use crate::state::DeniedCommand;
+use crate::state::ExecutedCommand;
 use crate::state::SessionServices;
 use crate::state::SessionState;
 use crate::state_db;
@@ -285,10 +311,13 @@ use crate::tasks::ReviewTask;
 use crate::tasks::SessionTask;
 use crate::tasks::SessionTaskContext;
 use crate::tools::ToolRouter;
+use crate::tools::approved_commands::ApprovedCommandsStore;
+use crate::tools::arguments_preview::ToolArgumentsPreviews;
 use crate::tools::context::SharedTurnDiffTracker;
 use crate::tools::handlers::SEARCH_TOOL_BM25_TOOL_NAME;
 use crate::tools::js_repl::JsReplHandle;
 use crate::tools::js_repl::resolve_compatible_node;
+use crate::tools::loop_detection::ToolCallLoopDetector;
 use crate::tools::network_approval::NetworkApprovalService;
 use crate::tools::network_approval::build_blocked_request_observer;
 use crate::tools::network_approval::build_network_policy_decider;
@@ -311,6 +340,7 @@ use codex_protocol::config_types::CollaborationMode;
 use codex_protocol::config_types::Personality;
 use codex_protocol::config_types::ReasoningSummary as ReasoningSummaryConfig;
 use codex_protocol::config_types::ServiceTier;
+use codex_protocol::config_types::ToolChoice;
 use codex_protocol::config_types::WindowsSandboxLevel;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::DeveloperInstructions;
@@ -329,6 +359,9 @@ use codex_utils_readiness::ReadinessFlag;
 pub struct Codex {
     pub(crate) tx_sub: Sender<Submission>,
     pub(crate) rx_event: Receiver<Event>,
+    /// Dedicated receiver for `ExecCommandOutputDelta` events, present when
+    /// `separate_exec_output_channel` is enabled.
+    pub(crate) rx_exec_output_event: Option<Receiver<Event>>,
     // Last known status of the agent.
     pub(crate) agent_status: watch::Receiver<AgentStatus>,
     pub(crate) session: Arc<Session>,
@@ -342,12 +375,17 @@ pub struct CodexSpawnOk {
     pub thread_id: ThreadId,
     #[deprecated(note = "use thread_id")]
     pub conversation_id: ThreadId,
+    /// Counters for this session, renderable via `Metrics::render_text`.
+    pub metrics: Arc<Metrics>,
 }
 
 pub(crate) const INITIAL_SUBMIT_ID: &str = "";
 pub(crate) const SUBMISSION_CHANNEL_CAPACITY: usize = 512;
 const CYBER_VERIFY_URL: &str = "https://chatgpt.com/cyber";
 const CYBER_SAFETY_URL: &str = "https://developers.openai.com/codex/concepts/cyber-safety";
+/// Sent when `request_final_message_after_tools` is enabled and the model
+/// stopped after tool calls without replying to the user.
+const FINAL_MESSAGE_REQUEST: &str = "You stopped after calling tools without replying to the user. Reply now with a brief summary of what you did and your final answer.";
 
 impl Codex {
     /// Spawn a new [`Codex`] and initialize the session.
@@ -370,6 +408,16 @@ impl Codex {
     ) -> CodexResult<CodexSpawnOk> {
         let (tx_sub, rx_sub) = async_channel::bounded(SUBMISSION_CHANNEL_CAPACITY);
         let (tx_event, rx_event) = async_channel::unbounded();
+        // Only the TUI reads the separate channel; other frontends keep
+        // receiving output deltas on the main stream.
+        let (tx_exec_output_event, rx_exec_output_event) = if config.separate_exec_output_channel
+            && matches!(session_source, SessionSource::Cli)
+        {
+            let (tx, rx) = async_channel::unbounded();
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
 
         let loaded_plugins = plugins_manager.plugins_for_config(&config);
         let loaded_skills = skills_manager.skills_for_config(&config);
@@ -456,7 +504,7 @@ impl Codex {
 
         // Resolve base instructions for the session. Priority order:
         // 1. config.base_instructions override
-        // 2. conversation history => session_meta.base_instructions
+        // 2. conversation history => last BaseInstructionsUpdated or session_meta.base_instructions
         // 3. base_instructions for current model
         let model_info = models_manager.get_model_info(model.as_str(), &config).await;
         let base_instructions = config
@@ -541,6 +589,7 @@ impl Codex {
             models_manager.clone(),
             exec_policy,
             tx_event.clone(),
+            tx_exec_output_event,
             agent_status_tx.clone(),
             conversation_history,
             session_source_clone,
@@ -557,6 +606,7 @@ impl Codex {
             map_session_init_error(&e, &config.codex_home)
         })?;
         let thread_id = session.conversation_id;
+        let metrics = Arc::clone(&session.services.metrics);
 
         // This task will run until Op::Shutdown is received.
         let session_loop_span = info_span!("session_loop", thread_id = %thread_id);
@@ -566,6 +616,7 @@ impl Codex {
         let codex = Codex {
             tx_sub,
             rx_event,
+            rx_exec_output_event,
             agent_status: agent_status_rx,
             session,
         };
@@ -575,6 +626,7 @@ impl Codex {
             codex,
             thread_id,
             conversation_id: thread_id,
+            metrics,
         })
     }
 
@@ -612,6 +664,21 @@ impl Codex {
         Ok(event)
     }
 
+    /// Like [`Codex::next_event`], but gives up after `timeout`. Returns
+    /// `Ok(None)` when no event arrived before the deadline.
+    pub async fn next_event_timeout(&self, timeout: Duration) -> CodexResult<Option<Event>> {
+        match tokio::time::timeout(timeout, self.rx_event.recv()).await {
+            Ok(event) => event.map(Some).map_err(|_| CodexErr::InternalAgentDied),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Receiver for exec output deltas when they are routed off the main event
+    /// stream. Returns `None` when `separate_exec_output_channel` is disabled.
+    pub fn exec_output_events(&self) -> Option<Receiver<Event>> {
+        self.rx_exec_output_event.clone()
+    }
+
     pub async fn steer_input(
         &self,
         input: Vec<UserInput>,
@@ -656,6 +723,12 @@ impl Codex {
 pub(crate) struct Session {
     pub(crate) conversation_id: ThreadId,
     tx_event: Sender<Event>,
+    /// Dedicated sender for `ExecCommandOutputDelta` events; falls back to
+    /// `tx_event` when unset.
+    tx_exec_output_event: Option<Sender<Event>>,
+    /// Merges repeated `BackgroundEvent`s when
+    /// `background_event_coalesce_window_ms` is configured.
+    background_event_coalescer: Option<BackgroundEventCoalescer>,
     agent_status: watch::Sender<AgentStatus>,
     out_of_band_elicitation_paused: watch::Sender<bool>,
     state: Mutex<SessionState>,
@@ -721,6 +794,7 @@ pub(crate) struct TurnContext {
     pub(crate) features: ManagedFeatures,
     pub(crate) ghost_snapshot: GhostSnapshotConfig,
     pub(crate) final_output_json_schema: Option<Value>,
+    pub(crate) tool_choice: ToolChoice,
     pub(crate) codex_linux_sandbox_exe: Option<PathBuf>,
     pub(crate) tool_call_gate: Arc<ReadinessFlag>,
     pub(crate) truncation_policy: TruncationPolicy,
@@ -738,6 +812,17 @@ impl TurnContext {
         })
     }
 
+    /// Total token usage at which the conversation is compacted automatically,
+    /// or `i64::MAX` when auto-compaction is disabled or no limit is known.
+    pub(crate) fn auto_compact_token_limit(&self) -> i64 {
+        if self.config.disable_auto_compact {
+            return i64::MAX;
+        }
+        self.model_info
+            .auto_compact_token_limit()
+            .unwrap_or(i64::MAX)
+    }
+
     pub(crate) fn apps_enabled(&self) -> bool {
         self.features
             .apps_enabled_cached(self.auth_manager.as_deref())
@@ -836,6 +921,49 @@ impl TurnContext {
             .map_or_else(|| self.cwd.clone(), |p| self.cwd.join(p))
     }
 
+    /// Resolves a path supplied in a tool call like `resolve_path`, then
+    /// applies the configured `path_outside_roots` policy when it escapes the
+    /// cwd and writable roots.
+    pub(crate) fn resolve_tool_path(
+        &self,
+        path: Option<String>,
+    ) -> Result<PathBuf, FunctionCallError> {
+        let resolved = self.resolve_path(path);
+        if self.is_within_roots(&resolved) {
+            return Ok(resolved);
+        }
+        match self.config.path_outside_roots {
+            PathOutsideRootsPolicy::Allow => Ok(resolved),
+            PathOutsideRootsPolicy::Warn => {
+                warn!(
+                    "tool path `{}` is outside the cwd `{}` and writable roots",
+                    resolved.display(),
+                    self.cwd.display()
+                );
+                Ok(resolved)
+            }
+            PathOutsideRootsPolicy::Reject => Err(FunctionCallError::RespondToModel(format!(
+                "path `{}` is outside the allowed roots; retry with a path under `{}`",
+                resolved.display(),
+                self.cwd.display()
+            ))),
+        }
+    }
+
+    /// Whether `path` lies under the cwd or one of the sandbox's writable
+    /// roots. Paths are compared canonically so `..` segments and symlinks
+    /// cannot escape a root, even when the tail of `path` does not exist yet.
+    pub(crate) fn is_within_roots(&self, path: &Path) -> bool {
+        let path = canonicalize_existing_prefix(path);
+        path.starts_with(canonicalize_existing_prefix(&self.cwd))
+            || self
+                .sandbox_policy
+                .get()
+                .get_writable_roots_with_cwd(&self.cwd)
+                .iter()
+                .any(|root| path.starts_with(canonicalize_existing_prefix(root.root.as_path())))
+    }
+
     pub(crate) fn compact_prompt(&self) -> &str {
         self.compact_prompt
             .as_deref()
@@ -889,6 +1017,31 @@ fn local_time_context() -> (String, String) {
     }
 }
 
+/// Maximum number of bytes injected from `initial_context_file`.
+const INITIAL_CONTEXT_FILE_MAX_BYTES: u64 = 32 * 1024;
+
+/// Read the configured initial context file, truncating it to
+/// [`INITIAL_CONTEXT_FILE_MAX_BYTES`]. Returns `Ok(None)` for blank files.
+async fn read_initial_context_file(path: &Path) -> std::io::Result<Option<String>> {
+    use tokio::io::AsyncReadExt;
+
+    let file = tokio::fs::File::open(path).await?;
+    let size = file.metadata().await?.len();
+    let mut data = Vec::new();
+    file.take(INITIAL_CONTEXT_FILE_MAX_BYTES)
+        .read_to_end(&mut data)
+        .await?;
+    if size > INITIAL_CONTEXT_FILE_MAX_BYTES {
+        warn!(
+            "Initial context file `{}` exceeds {INITIAL_CONTEXT_FILE_MAX_BYTES} bytes - truncating.",
+            path.display()
+        );
+    }
+
+    let text = String::from_utf8_lossy(&data).into_owned();
+    Ok((!text.trim().is_empty()).then_some(text))
+}
+
 #[derive(Clone)]
 pub(crate) struct SessionConfiguration {
     /// Provider identifier ("openai", "openrouter", ...).
@@ -1031,6 +1184,12 @@ pub(crate) struct SessionSettingsUpdate {
     pub(crate) final_output_json_schema: Option<Option<Value>>,
     pub(crate) personality: Option<Personality>,
     pub(crate) app_server_client_name: Option<String>,
+    /// Reasoning effort for the new turn only; not kept in the session
+    /// configuration.
+    pub(crate) reasoning_effort_override: Option<ReasoningEffortConfig>,
+    /// Tool choice for the new turn only; not kept in the session
+    /// configuration.
+    pub(crate) tool_choice_override: Option<ToolChoice>,
 }
 
 impl Session {
@@ -1235,6 +1394,7 @@ impl Session {
             features: per_turn_config.features.clone(),
             ghost_snapshot: per_turn_config.ghost_snapshot.clone(),
             final_output_json_schema: None,
+            tool_choice: ToolChoice::Auto,
             codex_linux_sandbox_exe: per_turn_config.codex_linux_sandbox_exe.clone(),
             tool_call_gate: Arc::new(ReadinessFlag::new()),
             truncation_policy: model_info.truncation_policy.into(),
@@ -1254,6 +1414,7 @@ impl Session {
         models_manager: Arc<ModelsManager>,
         exec_policy: ExecPolicyManager,
         tx_event: Sender<Event>,
+        tx_exec_output_event: Option<Sender<Event>>,
         agent_status: watch::Sender<AgentStatus>,
         initial_history: InitialHistory,
         session_source: SessionSource,
@@ -1411,6 +1572,16 @@ impl Session {
             });
         }
         maybe_push_unstable_features_warning(&config, &mut post_session_configured_events);
+        for name in missing_allow_listed_vars(&config.permissions.shell_environment_policy) {
+            post_session_configured_events.push(Event {
+                id: INITIAL_SUBMIT_ID.to_owned(),
+                msg: EventMsg::BackgroundEvent(BackgroundEventEvent {
+                    message: format!(
+                        "`{name}` is in `shell_environment_policy.allow_list` but is not set in the environment."
+                    ),
+                }),
+            });
+        }
         if config.permissions.approval_policy.value() == AskForApproval::OnFailure {
             post_session_configured_events.push(Event {
                 id: "".to_owned(),
@@ -1529,10 +1700,10 @@ impl Session {
             };
         session_configuration.thread_name = thread_name.clone();
         let state = SessionState::new(session_configuration.clone());
-        let managed_network_requirements_enabled = config.managed_network_requirements_enabled();
+        let managed_network_enforced = config.managed_network_enforced();
         let network_approval = Arc::new(NetworkApprovalService::default());
         // The managed proxy can call back into core for allowlist-miss decisions.
-        let network_policy_decider_session = if managed_network_requirements_enabled {
+        let network_policy_decider_session = if managed_network_enforced {
             config
                 .permissions
                 .network
@@ -1541,7 +1712,7 @@ impl Session {
         } else {
             None
         };
-        let blocked_request_observer = if managed_network_requirements_enabled {
+        let blocked_request_observer = if managed_network_enforced {
             config
                 .permissions
                 .network
@@ -1566,7 +1737,7 @@ impl Session {
                     config.permissions.sandbox_policy.get(),
                     network_policy_decider.as_ref().map(Arc::clone),
                     blocked_request_observer.as_ref().map(Arc::clone),
-                    managed_network_requirements_enabled,
+                    managed_network_enforced,
                     network_proxy_audit_metadata,
                 )
                 .await?;
@@ -1593,6 +1764,12 @@ impl Session {
                 }),
             });
         }
+        let tool_approvals = if config.persist_approved_commands {
+            ApprovalStore::with_approved_commands(ApprovedCommandsStore::new(&config.codex_home))
+                .await
+        } else {
+            ApprovalStore::default()
+        };
 
         let services = SessionServices {
             // Initialize the MCP connection manager with an uninitialized
@@ -1624,7 +1801,7 @@ impl Session {
             auth_manager: Arc::clone(&auth_manager),
             session_telemetry,
             models_manager: Arc::clone(&models_manager),
-            tool_approvals: Mutex::new(ApprovalStore::default()),
+            tool_approvals: Mutex::new(tool_approvals),
             execve_session_approvals: RwLock::new(HashMap::new()),
             skills_manager,
             plugins_manager: Arc::clone(&plugins_manager),
@@ -1644,8 +1821,10 @@ impl Session {
                 config.features.enabled(Feature::EnableRequestCompression),
                 config.features.enabled(Feature::RuntimeMetrics),
                 Self::build_model_client_beta_features_header(config.as_ref()),
+                config.client_metadata.clone(),
             ),
             code_mode_store: Default::default(),
+            metrics: Arc::new(Metrics::default()),
         };
         let js_repl = Arc::new(JsReplHandle::with_node_path(
             config.js_repl_node_path.clone(),
@@ -1657,6 +1836,15 @@ impl Session {
         let sess = Arc::new(Session {
             conversation_id,
             tx_event: tx_event.clone(),
+            tx_exec_output_event,
+            background_event_coalescer: config.background_event_coalesce_window_ms.map(
+                |window_ms| {
+                    BackgroundEventCoalescer::new(
+                        Duration::from_millis(window_ms),
+                        tx_event.clone(),
+                    )
+                },
+            ),
             agent_status,
             out_of_band_elicitation_paused,
             state: Mutex::new(state),
@@ -1725,6 +1913,7 @@ impl Session {
         let (mcp_connection_manager, cancel_token) = McpConnectionManager::new(
             &mcp_servers,
             config.mcp_oauth_credentials_store_mode,
+            config.default_tool_timeout_sec,
             auth_statuses.clone(),
             &session_configuration.approval_policy,
             tx_event.clone(),
@@ -1793,6 +1982,20 @@ impl Session {
         self.tx_event.clone()
     }
 
+    /// Sender to use for `ExecCommandOutputDelta` events.
+    pub(crate) fn get_tx_exec_output_event(&self) -> Sender<Event> {
+        self.tx_exec_output_event
+            .clone()
+            .unwrap_or_else(|| self.tx_event.clone())
+    }
+
+    fn event_sender_for(&self, msg: &EventMsg) -> &Sender<Event> {
+        match (&self.tx_exec_output_event, msg) {
+            (Some(tx), EventMsg::ExecCommandOutputDelta(_)) => tx,
+            _ => &self.tx_event,
+        }
+    }
+
     pub(crate) fn state_db(&self) -> Option<state_db::StateDbHandle> {
         self.services.state_db.clone()
     }
@@ -1830,6 +2033,12 @@ impl Session {
     }
 
     pub(crate) async fn route_realtime_text_input(self: &Arc<Self>, text: String) {
+        self.submit_text_input(text).await;
+    }
+
+    /// Submits `text` as if the user had typed it, for input that originates
+    /// inside the session rather than from a client `Op`.
+    async fn submit_text_input(self: &Arc<Self>, text: String) {
         handlers::user_input_or_turn(
             self,
             self.next_internal_sub_id(),
@@ -1839,6 +2048,8 @@ impl Session {
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             },
         )
         .await;
@@ -1874,6 +2085,30 @@ impl Session {
         }
     }
 
+    /// Replaces the base instructions used from the next model request on and
+    /// returns the new text. `None` restores the configured override, or the
+    /// current model's default instructions when there is none.
+    pub(crate) async fn set_base_instructions(&self, instructions: Option<String>) -> String {
+        let instructions = match instructions {
+            Some(instructions) => instructions,
+            None => {
+                let turn_context = self.new_default_turn().await;
+                turn_context
+                    .config
+                    .base_instructions
+                    .clone()
+                    .unwrap_or_else(|| {
+                        turn_context
+                            .model_info
+                            .get_model_instructions(turn_context.personality)
+                    })
+            }
+        };
+        let mut state = self.state.lock().await;
+        state.session_configuration.base_instructions = instructions.clone();
+        instructions
+    }
+
     pub(crate) async fn merge_mcp_tool_selection(&self, tool_names: Vec<String>) -> Vec<String> {
         let mut state = self.state.lock().await;
         state.merge_mcp_tool_selection(tool_names)
@@ -2193,6 +2428,29 @@ impl Session {
         }
     }
 
+    /// Moves the session to `cwd` for subsequent turns. Writable roots that
+    /// were derived from the old cwd are rederived from the new one.
+    pub(crate) async fn set_cwd(&self, cwd: PathBuf) -> Result<(), String> {
+        if !cwd.is_absolute() {
+            return Err(format!(
+                "working directory must be an absolute path: {}",
+                cwd.display()
+            ));
+        }
+        if !cwd.is_dir() {
+            return Err(format!(
+                "working directory does not exist or is not a directory: {}",
+                cwd.display()
+            ));
+        }
+        self.update_settings(SessionSettingsUpdate {
+            cwd: Some(cwd),
+            ..Default::default()
+        })
+        .await
+        .map_err(|err| err.to_string())
+    }
+
     pub(crate) async fn new_turn_with_sub_id(
         &self,
         sub_id: String,
@@ -2249,16 +2507,54 @@ impl Session {
                 sub_id,
                 session_configuration,
                 updates.final_output_json_schema,
+                updates.reasoning_effort_override,
+                updates.tool_choice_override,
                 sandbox_policy_changed,
             )
             .await)
     }
 
+    /// Checks `updates.reasoning_effort_override` against the reasoning levels
+    /// of the model the turn would run with, before any of `updates` is
+    /// applied, so a rejected turn leaves the session untouched. Returns the
+    /// error message to report when the effort is not supported.
+    async fn unsupported_reasoning_effort_override(
+        &self,
+        updates: &SessionSettingsUpdate,
+    ) -> Option<String> {
+        let effort = updates.reasoning_effort_override?;
+        // Invalid settings are reported by `new_turn_with_sub_id`.
+        let session_configuration = {
+            let state = self.state.lock().await;
+            state.session_configuration.clone().apply(updates).ok()?
+        };
+        let per_turn_config = Self::build_per_turn_config(&session_configuration);
+        let model_info = self
+            .services
+            .models_manager
+            .get_model_info(
+                session_configuration.collaboration_mode.model(),
+                &per_turn_config,
+            )
+            .await;
+        let supported = &model_info.supported_reasoning_levels;
+        (!supported.is_empty() && !supported.iter().any(|preset| preset.effort == effort)).then(
+            || {
+                format!(
+                    "Reasoning effort {effort} is not supported by model {}.",
+                    model_info.slug
+                )
+            },
+        )
+    }
+
     async fn new_turn_from_configuration(
         &self,
         sub_id: String,
         session_configuration: SessionConfiguration,
         final_output_json_schema: Option<Option<Value>>,
+        reasoning_effort_override: Option<ReasoningEffortConfig>,
+        tool_choice_override: Option<ToolChoice>,
         sandbox_policy_changed: bool,
     ) -> Arc<TurnContext> {
         let per_turn_config = Self::build_per_turn_config(&session_configuration);
@@ -2323,6 +2619,12 @@ impl Session {
         if let Some(final_schema) = final_output_json_schema {
             turn_context.final_output_json_schema = final_schema;
         }
+        if let Some(effort) = reasoning_effort_override {
+            turn_context.reasoning_effort = Some(effort);
+        }
+        if let Some(tool_choice) = tool_choice_override {
+            turn_context.tool_choice = tool_choice;
+        }
         let turn_context = Arc::new(turn_context);
         turn_context.turn_metadata_state.spawn_git_enrichment_task();
         turn_context
@@ -2343,6 +2645,34 @@ impl Session {
         }
     }
 
+    /// Warns once per session about each tool that lost its name to an
+    /// existing tool.
+    async fn warn_about_shadowed_tools(&self, turn_context: &TurnContext, shadowed: &[String]) {
+        if shadowed.is_empty() {
+            return;
+        }
+        let newly_shadowed = {
+            let mut state = self.state.lock().await;
+            shadowed
+                .iter()
+                .filter(|name| state.shadowed_tool_warnings_sent.insert((*name).clone()))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        for name in newly_shadowed {
+            warn!("tool {name} is shadowed by an existing tool with the same name");
+            self.send_event(
+                turn_context,
+                EventMsg::Warning(WarningEvent {
+                    message: format!(
+                        "Tool `{name}` is ignored because another tool already uses that name; calls to `{name}` go to the existing tool."
+                    ),
+                }),
+            )
+            .await;
+        }
+    }
+
     pub(crate) async fn new_default_turn(&self) -> Arc<TurnContext> {
         self.new_default_turn_with_sub_id(self.next_internal_sub_id())
             .await
@@ -2476,7 +2806,7 @@ impl Session {
             let state = self.state.lock().await;
             state.session_configuration.clone()
         };
-        self.new_turn_from_configuration(sub_id, session_configuration, None, false)
+        self.new_turn_from_configuration(sub_id, session_configuration, None, None, None, false)
             .await
     }
 
@@ -2549,15 +2879,45 @@ impl Session {
         self.conversation.clear_active_handoff().await;
     }
 
+    /// Updates session state derived from emitted events.
+    async fn record_event_in_state(&self, msg: &EventMsg) {
+        match msg {
+            EventMsg::Error(error) => {
+                self.services.metrics.record_error();
+                self.state.lock().await.record_recent_error(error.clone());
+            }
+            EventMsg::ExecCommandEnd(end) => {
+                self.state
+                    .lock()
+                    .await
+                    .record_executed_command(ExecutedCommand {
+                        command: end.command.clone(),
+                        cwd: end.cwd.clone(),
+                        exit_code: end.exit_code,
+                        output: end.aggregated_output.clone(),
+                    });
+            }
+            EventMsg::PatchApplyEnd(end) if end.success && !end.dry_run => {
+                self.state.lock().await.record_patch_applied();
+            }
+            EventMsg::TurnComplete(_) => {
+                self.state.lock().await.record_turn_completed();
+            }
+            _ => {}
+        }
+    }
+
     pub(crate) async fn send_event_raw(&self, event: Event) {
+        self.flush_background_events_before(&event.msg).await;
         // Record the last known agent status.
         if let Some(status) = agent_status_from_event(&event.msg) {
             self.agent_status.send_replace(status);
         }
+        self.record_event_in_state(&event.msg).await;
         // Persist the event into rollout (recorder filters as needed)
         let rollout_items = vec![RolloutItem::EventMsg(event.msg.clone())];
         self.persist_rollout_items(&rollout_items).await;
-        if let Err(e) = self.tx_event.send(event).await {
+        if let Err(e) = self.event_sender_for(&event.msg).send(event).await {
             debug!("dropping event because channel is closed: {e}");
         }
     }
@@ -2568,14 +2928,16 @@ impl Session {
     /// clients (e.g. app-server thread/rollback) re-read the rollout file synchronously on
     /// receipt of the event and depend on the marker already being visible on disk.
     pub(crate) async fn send_event_raw_flushed(&self, event: Event) {
+        self.flush_background_events_before(&event.msg).await;
         // Record the last known agent status.
         if let Some(status) = agent_status_from_event(&event.msg) {
             self.agent_status.send_replace(status);
         }
+        self.record_event_in_state(&event.msg).await;
         self.persist_rollout_items(&[RolloutItem::EventMsg(event.msg.clone())])
             .await;
         self.flush_rollout().await;
-        if let Err(e) = self.tx_event.send(event).await {
+        if let Err(e) = self.event_sender_for(&event.msg).send(event).await {
             debug!("dropping event because channel is closed: {e}");
         }
     }
@@ -2598,6 +2960,19 @@ impl Session {
         item: TurnItem,
     ) {
         record_turn_ttfm_metric(turn_context, &item).await;
+        if let TurnItem::Reasoning(reasoning) = &item {
+            // Raw reasoning is only kept when the user opted into seeing it.
+            let raw_content = if self.show_raw_agent_reasoning() {
+                reasoning.raw_content.clone()
+            } else {
+                Vec::new()
+            };
+            self.state.lock().await.record_reasoning(
+                &turn_context.sub_id,
+                reasoning.summary_text.clone(),
+                raw_content,
+            );
+        }
         self.send_event(
             turn_context,
             EventMsg::ItemCompleted(ItemCompletedEvent {
@@ -2795,6 +3170,7 @@ impl Session {
         approval_id: Option<String>,
         command: Vec<String>,
         cwd: PathBuf,
+        stdin: Option<String>,
         reason: Option<String>,
         network_approval_context: Option<NetworkApprovalContext>,
         proposed_execpolicy_amendment: Option<ExecPolicyAmendment>,
@@ -2842,7 +3218,7 @@ impl Session {
                 additional_permissions.as_ref(),
             )
         });
-        let event = EventMsg::ExecApprovalRequest(ExecApprovalRequestEvent {
+        let request = ExecApprovalRequestEvent {
             call_id,
             approval_id,
             turn_id: turn_context.sub_id.clone(),
@@ -2855,12 +3231,89 @@ impl Session {
             additional_permissions,
             skill_metadata,
             available_decisions: Some(available_decisions),
+            stdin,
             parsed_cmd,
-        });
-        self.send_event(turn_context, event).await;
+        };
+        self.send_exec_approval_request(turn_context, request).await;
         rx_approve.await.unwrap_or(ReviewDecision::Abort)
     }
 
+    /// Remembers a command the user denied so `Op::ReapproveLastDenied` can
+    /// re-run it.
+    pub(crate) async fn record_denied_command(&self, denied: DeniedCommand) {
+        self.state.lock().await.record_denied_command(denied);
+    }
+
+    /// Sends `request` right away, or, when `approval_batch_window_ms` is set,
+    /// queues it so requests arriving within the window go out together. The
+    /// request that opens a batch waits out the window and then sends
+    /// everything queued behind it.
+    ///
+    /// Only the TUI presents `ExecApprovalBatchRequest`, so sessions started
+    /// by other frontends, including sub-agents, always get single requests.
+    async fn send_exec_approval_request(
+        &self,
+        turn_context: &TurnContext,
+        request: ExecApprovalRequestEvent,
+    ) {
+        let turn_state = {
+            let active = self.active_turn.lock().await;
+            active.as_ref().map(|at| Arc::clone(&at.turn_state))
+        };
+        let window_ms = turn_context
+            .config
+            .approval_batch_window_ms
+            .filter(|_| matches!(turn_context.session_source, SessionSource::Cli));
+        let (Some(window_ms), Some(turn_state)) = (window_ms, turn_state) else {
+            self.send_event(turn_context, EventMsg::ExecApprovalRequest(request))
+                .await;
+            return;
+        };
+        if !turn_state.lock().await.queue_exec_approval(request) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(window_ms)).await;
+        let mut requests = turn_state.lock().await.take_queued_exec_approvals();
+        if requests.len() > 1 {
+            let approval_ids: Vec<String> = requests
+                .iter()
+                .map(ExecApprovalRequestEvent::effective_approval_id)
+                .collect();
+            let batch_id = format!("batch-{}", approval_ids[0]);
+            turn_state
+                .lock()
+                .await
+                .insert_approval_batch(batch_id.clone(), approval_ids);
+            let event = EventMsg::ExecApprovalBatchRequest(ExecApprovalBatchRequestEvent {
+                batch_id,
+                turn_id: turn_context.sub_id.clone(),
+                requests,
+            });
+            self.send_event(turn_context, event).await;
+        } else if let Some(request) = requests.pop() {
+            self.send_event(turn_context, EventMsg::ExecApprovalRequest(request))
+                .await;
+        }
+    }
+
+    /// Resolves every command of an approval batch that has not been answered
+    /// individually.
+    pub async fn notify_approval_batch(&self, batch_id: &str, decision: ReviewDecision) {
+        let approval_ids = {
+            let active = self.active_turn.lock().await;
+            match active.as_ref() {
+                Some(at) => at.turn_state.lock().await.take_approval_batch(batch_id),
+                None => Vec::new(),
+            }
+        };
+        if approval_ids.is_empty() {
+            warn!("No pending approvals found for batch_id: {batch_id}");
+        }
+        for approval_id in approval_ids {
+            self.notify_approval(&approval_id, decision.clone()).await;
+        }
+    }
+
     pub async fn request_patch_approval(
         &self,
         turn_context: &TurnContext,
@@ -3450,11 +3903,27 @@ impl Session {
             .agent_control
             .format_environment_context_subagents(self.conversation_id)
             .await;
-        contextual_user_sections.push(
+        let mut environment_context =
             EnvironmentContext::from_turn_context(turn_context, shell.as_ref())
-                .with_subagents(subagents)
-                .serialize_to_xml(),
-        );
+                .with_subagents(subagents);
+        if turn_context.config.include_platform_context {
+            environment_context =
+                environment_context.with_platform(PlatformContext::for_shell(shell.as_ref()));
+        }
+        contextual_user_sections.push(environment_context.serialize_to_xml());
+        // Part of the initial context so it is restated after compaction.
+        if let Some(path) = turn_context.config.initial_context_file.as_deref() {
+            match read_initial_context_file(path).await {
+                Ok(Some(contents)) => {
+                    contextual_user_sections.push(CONTEXT_FILE_FRAGMENT.wrap(contents));
+                }
+                Ok(None) => {}
+                Err(err) => warn!(
+                    "failed to read initial context file {}: {err}",
+                    path.display()
+                ),
+            }
+        }
 
         let mut items = Vec::with_capacity(2);
         if let Some(developer_message) =
@@ -3543,6 +4012,9 @@ impl Session {
         token_usage: Option<&TokenUsage>,
     ) {
         if let Some(token_usage) = token_usage {
+            self.services
+                .metrics
+                .record_tokens(token_usage.input_tokens, token_usage.output_tokens);
             let mut state = self.state.lock().await;
             state.update_token_info_from_usage(token_usage, turn_context.model_context_window());
         }
@@ -3617,6 +4089,24 @@ impl Session {
         state.set_dependency_env(values);
     }
 
+    pub(crate) async fn set_environment_variable(&self, name: String, value: String) {
+        let mut state = self.state.lock().await;
+        state.set_extra_env(name, value);
+    }
+
+    pub(crate) async fn unset_environment_variable(&self, name: String) {
+        let mut state = self.state.lock().await;
+        state.unset_extra_env(name);
+    }
+
+    /// Applies variables set or unset via `Op::SetEnvironmentVariable` and
+    /// `Op::UnsetEnvironmentVariable` to an environment built by `create_env`.
+    /// Returns the names of every variable it touched.
+    pub(crate) async fn apply_extra_env(&self, env: &mut HashMap<String, String>) -> Vec<String> {
+        let state = self.state.lock().await;
+        state.apply_extra_env(env)
+    }
+
     pub(crate) async fn set_server_reasoning_included(&self, included: bool) {
         let mut state = self.state.lock().await;
         state.set_server_reasoning_included(included);
@@ -3672,17 +4162,55 @@ impl Session {
         self.ensure_rollout_materialized().await;
     }
 
+    /// Releases a held background event so it is delivered ahead of `msg`.
+    async fn flush_background_events_before(&self, msg: &EventMsg) {
+        if let Some(coalescer) = &self.background_event_coalescer
+            && !matches!(msg, EventMsg::BackgroundEvent(_))
+        {
+            coalescer.flush().await;
+        }
+    }
+
     pub(crate) async fn notify_background_event(
         &self,
         turn_context: &TurnContext,
         message: impl Into<String>,
     ) {
+        if let Some(coalescer) = &self.background_event_coalescer {
+            coalescer
+                .push(turn_context.sub_id.clone(), message.into())
+                .await;
+            return;
+        }
         let event = EventMsg::BackgroundEvent(BackgroundEventEvent {
             message: message.into(),
         });
         self.send_event(turn_context, event).await;
     }
 
+    /// Emits a one-time background event when `max_output_tokens` is set but
+    /// the current provider drops it from requests.
+    async fn warn_if_max_output_tokens_unsupported(&self, turn_context: &TurnContext) {
+        let supported = turn_context
+            .provider
+            .to_api_provider(self.services.auth_manager.auth_mode())
+            .is_ok_and(|provider| provider.supports_max_output_tokens());
+        if supported {
+            return;
+        }
+        let already_warned = {
+            let mut state = self.state.lock().await;
+            std::mem::replace(&mut state.max_output_tokens_warning_sent, true)
+        };
+        if !already_warned {
+            self.notify_background_event(
+                turn_context,
+                "`max_output_tokens` is not supported by the current model provider and will be ignored.",
+            )
+            .await;
+        }
+    }
+
     pub(crate) async fn notify_stream_error(
         &self,
         turn_context: &TurnContext,
@@ -3845,6 +4373,7 @@ impl Session {
 
     pub async fn call_tool(
         &self,
+        turn_context: &TurnContext,
         server: &str,
         tool: &str,
         arguments: Option<serde_json::Value>,
@@ -3853,7 +4382,9 @@ impl Session {
             .mcp_connection_manager
             .read()
             .await
-            .call_tool(server, tool, arguments)
+            .call_tool(server, tool, arguments, |message| {
+                self.notify_background_event(turn_context, message)
+            })
             .await
     }
 
@@ -3876,6 +4407,13 @@ impl Session {
         }
     }
 
+    async fn cancel_file_watch(&self) {
+        let watch = self.state.lock().await.take_file_watch();
+        if let Some(cancel) = watch {
+            cancel.cancel();
+        }
+    }
+
     pub(crate) fn hooks(&self) -> &Hooks {
         &self.services.hooks
     }
@@ -3932,6 +4470,7 @@ impl Session {
         let (refreshed_manager, cancel_token) = McpConnectionManager::new(
             &mcp_servers,
             store_mode,
+            config.default_tool_timeout_sec,
             auth_statuses,
             &turn_context.config.permissions.approval_policy,
             self.get_tx_event(),
@@ -3953,6 +4492,106 @@ impl Session {
         *manager = refreshed_manager;
     }
 
+    /// Re-reads the user instructions from disk for later turns. Returns
+    /// whether any instructions were found.
+    async fn reload_user_instructions(&self) -> bool {
+        let mut config = (*self.get_config().await).clone();
+        config.user_instructions = Config::load_instructions(Some(&config.codex_home));
+        let loaded_plugins = self.services.plugins_manager.plugins_for_config(&config);
+        let loaded_skills = self.services.skills_manager.skills_for_config(&config);
+        let allowed_skills_for_implicit_invocation =
+            loaded_skills.allowed_skills_for_implicit_invocation();
+        let user_instructions = get_user_instructions(
+            &config,
+            Some(&allowed_skills_for_implicit_invocation),
+            Some(loaded_plugins.capability_summaries()),
+        )
+        .await;
+        let found = user_instructions.is_some();
+
+        let mut state = self.state.lock().await;
+        state.session_configuration.user_instructions = user_instructions;
+        state.session_configuration.original_config_do_not_use = Arc::new(config);
+        // The instructions are only sent with the full initial context, so
+        // have the next turn inject it again.
+        state.set_reference_context_item(None);
+        found
+    }
+
+    /// Reloads plugins from disk and reconnects the MCP servers whose effective
+    /// configuration changed. Returns the names of the servers that were
+    /// restarted or shut down.
+    async fn reload_plugins(&self) -> Vec<String> {
+        let auth = self.services.auth_manager.auth().await;
+        let config = self.get_config().await;
+        let previous_servers = self
+            .services
+            .mcp_manager
+            .effective_servers(config.as_ref(), auth.as_ref());
+        self.services
+            .plugins_manager
+            .reload(&config.cwd, &config.config_layer_stack);
+        let current_servers = self
+            .services
+            .mcp_manager
+            .effective_servers(config.as_ref(), auth.as_ref());
+
+        let removed: Vec<String> = previous_servers
+            .keys()
+            .filter(|name| !current_servers.contains_key(*name))
+            .cloned()
+            .collect();
+        let changed: HashMap<String, McpServerConfig> = current_servers
+            .into_iter()
+            .filter(|(name, server)| previous_servers.get(name) != Some(server))
+            .collect();
+        if changed.is_empty() && removed.is_empty() {
+            return Vec::new();
+        }
+
+        let turn_context = self.new_default_turn().await;
+        let store_mode = config.mcp_oauth_credentials_store_mode;
+        let auth_statuses = compute_auth_statuses(changed.iter(), store_mode).await;
+        let sandbox_state = SandboxState {
+            sandbox_policy: turn_context.sandbox_policy.get().clone(),
+            codex_linux_sandbox_exe: turn_context.codex_linux_sandbox_exe.clone(),
+            sandbox_cwd: turn_context.cwd.clone(),
+            use_linux_sandbox_bwrap: turn_context.features.enabled(Feature::UseLinuxSandboxBwrap),
+        };
+        let tool_plugin_provenance = self
+            .services
+            .mcp_manager
+            .tool_plugin_provenance(config.as_ref());
+        let cancel_token = self
+            .services
+            .mcp_startup_cancellation_token
+            .lock()
+            .await
+            .clone();
+        self.services
+            .mcp_connection_manager
+            .write()
+            .await
+            .reconnect_servers(
+                &changed,
+                &removed,
+                store_mode,
+                config.default_tool_timeout_sec,
+                auth_statuses,
+                self.get_tx_event(),
+                sandbox_state,
+                config.codex_home.clone(),
+                codex_apps_tools_cache_key(auth.as_ref()),
+                tool_plugin_provenance,
+                &cancel_token,
+            )
+            .await;
+
+        let mut reconnected: Vec<String> = changed.into_keys().chain(removed).collect();
+        reconnected.sort_unstable();
+        reconnected
+    }
+
     async fn refresh_mcp_servers_if_requested(&self, turn_context: &TurnContext) {
         let refresh_config = { self.pending_mcp_server_refresh_config.lock().await.take() };
         let Some(refresh_config) = refresh_config else {
@@ -4112,10 +4751,18 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                     handlers::exec_approval(&sess, approval_id, turn_id, decision).await;
                     false
                 }
+                Op::ExecApprovalBatch { batch_id, decision } => {
+                    handlers::exec_approval_batch(&sess, batch_id, decision).await;
+                    false
+                }
                 Op::PatchApproval { id, decision } => {
                     handlers::patch_approval(&sess, id, decision).await;
                     false
                 }
+                Op::CancelApproval { id } => {
+                    handlers::cancel_approval(&sess, id).await;
+                    false
+                }
                 Op::UserInputAnswer { id, response } => {
                     handlers::request_user_input_response(&sess, id, response).await;
                     false
@@ -4147,6 +4794,10 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                     handlers::list_mcp_tools(&sess, &config, sub.id.clone()).await;
                     false
                 }
+                Op::McpHealthCheck { server } => {
+                    handlers::mcp_health_check(&sess, sub.id.clone(), server).await;
+                    false
+                }
                 Op::RefreshMcpServers { config } => {
                     handlers::refresh_mcp_servers(&sess, config).await;
                     false
@@ -4155,6 +4806,14 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                     handlers::reload_user_config(&sess).await;
                     false
                 }
+                Op::ReloadPlugins => {
+                    handlers::reload_plugins(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::ReloadUserInstructions => {
+                    handlers::reload_user_instructions(&sess, sub.id.clone()).await;
+                    false
+                }
                 Op::ListCustomPrompts => {
                     handlers::list_custom_prompts(&sess, sub.id.clone()).await;
                     false
@@ -4208,20 +4867,171 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                     handlers::set_thread_name(&sess, sub.id.clone(), name).await;
                     false
                 }
-                Op::RunUserShellCommand { command } => {
-                    handlers::run_user_shell_command(&sess, sub.id.clone(), command).await;
+                Op::ExportState => {
+                    handlers::export_state(&sess, sub.id.clone()).await;
                     false
                 }
-                Op::ResolveElicitation {
-                    server_name,
-                    request_id,
-                    decision,
-                    content,
-                    meta,
-                } => {
-                    handlers::resolve_elicitation(
-                        &sess,
-                        server_name,
+                Op::RotateRollout => {
+                    handlers::rotate_rollout(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::ForkSession { from_submission_id } => {
+                    handlers::fork_session(&sess, sub.id.clone(), from_submission_id).await;
+                    false
+                }
+                Op::ReapproveLastDenied => {
+                    handlers::reapprove_last_denied(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::Pause => {
+                    handlers::pause(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::Resume => {
+                    handlers::resume(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::CheckPatchApplies { patch } => {
+                    handlers::check_patch_applies(&sess, sub.id.clone(), patch).await;
+                    false
+                }
+                Op::HashWorkingTree { paths } => {
+                    handlers::hash_working_tree(&sess, sub.id.clone(), paths).await;
+                    false
+                }
+                Op::GetRecentErrors { limit } => {
+                    handlers::get_recent_errors(&sess, sub.id.clone(), limit).await;
+                    false
+                }
+                Op::ClearRecentErrors => {
+                    handlers::clear_recent_errors(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::GetConversationSnapshot => {
+                    handlers::get_conversation_snapshot(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::GetRetryConfig => {
+                    handlers::get_retry_config(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::GetFeatures => {
+                    handlers::get_features(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::SetBudget { max_total_tokens } => {
+                    handlers::set_budget(&sess, sub.id.clone(), max_total_tokens).await;
+                    false
+                }
+                Op::GetBudget => {
+                    handlers::get_budget(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::GetSessionStats => {
+                    handlers::get_session_stats(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::ListPlugins => {
+                    handlers::list_plugins(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::WatchFiles {
+                    paths,
+                    prompt_template,
+                } => {
+                    handlers::watch_files(&sess, sub.id.clone(), paths, prompt_template).await;
+                    false
+                }
+                Op::UnwatchFiles => {
+                    sess.cancel_file_watch().await;
+                    false
+                }
+                Op::BlameFile { path, line_range } => {
+                    handlers::blame_file(&sess, sub.id.clone(), path, line_range).await;
+                    false
+                }
+                Op::GetLastReasoning => {
+                    handlers::get_last_reasoning(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::ValidatePlugin { path } => {
+                    handlers::validate_plugin(&sess, sub.id.clone(), path).await;
+                    false
+                }
+                Op::SetBaseInstructions { instructions } => {
+                    handlers::set_base_instructions(&sess, sub.id.clone(), instructions).await;
+                    false
+                }
+                Op::ResetTurnDiff => {
+                    handlers::reset_turn_diff(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::SearchHistory { query } => {
+                    handlers::search_history(&sess, sub.id.clone(), &query).await;
+                    false
+                }
+                Op::SetApprovalPolicy { policy } => {
+                    handlers::set_approval_policy(&sess, sub.id.clone(), policy).await;
+                    false
+                }
+                Op::ChangeWorkingDirectory { path } => {
+                    handlers::change_working_directory(&sess, sub.id.clone(), path).await;
+                    false
+                }
+                Op::ExportConversation { path, format } => {
+                    handlers::export_conversation(&sess, sub.id.clone(), path, format).await;
+                    false
+                }
+                Op::ExplainLastCommand => {
+                    handlers::explain_last_command(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::SetNotifyCommand { command } => {
+                    handlers::set_notify_command(&sess, sub.id.clone(), command).await;
+                    false
+                }
+                Op::SetDefaultOutputSchema { schema } => {
+                    handlers::set_default_output_schema(&sess, schema).await;
+                    false
+                }
+                Op::InjectSystemMessage { text } => {
+                    handlers::inject_system_message(&sess, text).await;
+                    false
+                }
+                Op::UndoLastPatch => {
+                    handlers::undo_last_patch(&sess, sub.id.clone()).await;
+                    false
+                }
+                Op::SetEnvironmentVariable { name, value } => {
+                    handlers::set_environment_variable(&sess, sub.id.clone(), name, value).await;
+                    false
+                }
+                Op::UnsetEnvironmentVariable { name } => {
+                    handlers::unset_environment_variable(&sess, sub.id.clone(), name).await;
+                    false
+                }
+                Op::RunUserShellCommand { command } => {
+                    handlers::run_user_shell_command(&sess, sub.id.clone(), command).await;
+                    false
+                }
+                Op::RunCommand {
+                    command,
+                    cwd,
+                    timeout_ms,
+                } => {
+                    handlers::run_command(&sess, sub.id.clone(), command, cwd, timeout_ms).await;
+                    false
+                }
+                Op::ResolveElicitation {
+                    server_name,
+                    request_id,
+                    decision,
+                    content,
+                    meta,
+                } => {
+                    handlers::resolve_elicitation(
+                        &sess,
+                        server_name,
                         request_id,
                         decision,
                         content,
@@ -4271,56 +5081,100 @@ mod handlers {
     use crate::codex::SessionSettingsUpdate;
     use crate::codex::SteerInputError;
 
+    use crate::codex::TurnContext;
+    use crate::codex::built_tools;
     use crate::codex::spawn_review_thread;
     use crate::config::Config;
+    use crate::conversation_export::render_conversation;
 
     use crate::mcp::auth::compute_auth_statuses;
     use crate::mcp::collect_mcp_snapshot_from_manager;
+    use crate::plugins::PluginsManager;
     use crate::review_prompts::resolve_review_request;
     use crate::rollout::RolloutRecorder;
     use crate::rollout::session_index;
+    use crate::rollout::truncation::truncate_rollout_after_turn;
     use crate::tasks::CompactTask;
+    use crate::tasks::ExplainCommandTask;
+    use crate::tasks::RunCommandTask;
+    use crate::tasks::UndoPatchTask;
     use crate::tasks::UndoTask;
     use crate::tasks::UserShellCommandMode;
     use crate::tasks::UserShellCommandTask;
     use crate::tasks::execute_user_shell_command;
+    use crate::thread_manager::NewThread;
+    use crate::tools::context::SharedTurnDiffTracker;
     use codex_protocol::custom_prompts::CustomPrompt;
+    use codex_protocol::protocol::ApprovalPolicyChangedEvent;
+    use codex_protocol::protocol::AskForApproval;
+    use codex_protocol::protocol::BackgroundEventEvent;
+    use codex_protocol::protocol::BaseInstructionsUpdatedEvent;
+    use codex_protocol::protocol::BlameLineRange;
+    use codex_protocol::protocol::BlameResultEvent;
     use codex_protocol::protocol::CodexErrorInfo;
+    use codex_protocol::protocol::ConversationSnapshotEvent;
     use codex_protocol::protocol::ErrorEvent;
     use codex_protocol::protocol::Event;
     use codex_protocol::protocol::EventMsg;
+    use codex_protocol::protocol::ExportCompleteEvent;
+    use codex_protocol::protocol::ExportFormat;
+    use codex_protocol::protocol::FeaturesEvent;
     use codex_protocol::protocol::ListCustomPromptsResponseEvent;
     use codex_protocol::protocol::ListRemoteSkillsResponseEvent;
     use codex_protocol::protocol::ListSkillsResponseEvent;
+    use codex_protocol::protocol::McpHealthCheckResultEvent;
     use codex_protocol::protocol::McpServerRefreshConfig;
     use codex_protocol::protocol::Op;
+    use codex_protocol::protocol::PatchCheckResultEvent;
+    use codex_protocol::protocol::PauseStateChangedEvent;
+    use codex_protocol::protocol::PluginListEntry;
+    use codex_protocol::protocol::PluginListEvent;
+    use codex_protocol::protocol::PluginValidationErrorInfo;
+    use codex_protocol::protocol::PluginValidationResultEvent;
+    use codex_protocol::protocol::RecentErrorsEvent;
     use codex_protocol::protocol::RemoteSkillDownloadedEvent;
     use codex_protocol::protocol::RemoteSkillHazelnutScope;
     use codex_protocol::protocol::RemoteSkillProductSurface;
     use codex_protocol::protocol::RemoteSkillSummary;
+    use codex_protocol::protocol::RetryConfigEvent;
     use codex_protocol::protocol::ReviewDecision;
     use codex_protocol::protocol::ReviewRequest;
     use codex_protocol::protocol::RolloutItem;
+    use codex_protocol::protocol::RolloutRotatedEvent;
+    use codex_protocol::protocol::SearchHistoryResultsEvent;
+    use codex_protocol::protocol::SessionForkedEvent;
+    use codex_protocol::protocol::SessionStateExportedEvent;
     use codex_protocol::protocol::SkillsListEntry;
     use codex_protocol::protocol::ThreadNameUpdatedEvent;
     use codex_protocol::protocol::ThreadRolledBackEvent;
+    use codex_protocol::protocol::TokenBudgetEvent;
     use codex_protocol::protocol::TurnAbortReason;
+    use codex_protocol::protocol::TurnDiffEvent;
     use codex_protocol::protocol::WarningEvent;
+    use codex_protocol::protocol::WorkingDirectoryChangedEvent;
+    use codex_protocol::protocol::WorkingTreeHashEvent;
     use codex_protocol::request_permissions::RequestPermissionsResponse;
     use codex_protocol::request_user_input::RequestUserInputResponse;
+    use std::path::Path;
 
     use crate::context_manager::is_user_turn_boundary;
+    use crate::git_info::WorkingTreeHash;
+    use crate::git_info::WorkingTreeHashError;
+    use crate::mentions::collect_explicit_app_ids;
     use codex_protocol::config_types::CollaborationMode;
     use codex_protocol::config_types::ModeKind;
     use codex_protocol::config_types::Settings;
+    use codex_protocol::config_types::ToolChoice;
     use codex_protocol::dynamic_tools::DynamicToolResponse;
     use codex_protocol::mcp::RequestId as ProtocolRequestId;
     use codex_protocol::user_input::UserInput;
     use codex_rmcp_client::ElicitationAction;
     use codex_rmcp_client::ElicitationResponse;
+    use futures::future::join_all;
     use serde_json::Value;
     use std::path::PathBuf;
     use std::sync::Arc;
+    use tokio_util::sync::CancellationToken;
     use tracing::info;
     use tracing::warn;
 
@@ -4350,6 +5204,29 @@ mod handlers {
     }
 
     pub async fn user_input_or_turn(sess: &Arc<Session>, sub_id: String, op: Op) {
+        let Some(op) = sess
+            .state
+            .lock()
+            .await
+            .queue_input_if_paused(sub_id.clone(), op)
+        else {
+            return;
+        };
+        let exceeded_budget = sess.state.lock().await.exceeded_token_budget();
+        if let Some(budget) = exceeded_budget {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message: format!(
+                        "Token budget of {budget} exceeded; raise it with `Op::SetBudget` to continue."
+                    ),
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            })
+            .await;
+            return;
+        }
+        let default_output_schema = sess.state.lock().await.default_output_schema.clone();
         let (items, updates) = match op {
             Op::UserTurn {
                 cwd,
@@ -4384,29 +5261,61 @@ mod handlers {
                         collaboration_mode,
                         reasoning_summary: summary,
                         service_tier,
-                        final_output_json_schema: Some(final_output_json_schema),
+                        final_output_json_schema: Some(
+                            final_output_json_schema.or(default_output_schema),
+                        ),
                         personality,
                         app_server_client_name: None,
+                        reasoning_effort_override: None,
+                        tool_choice_override: None,
                     },
                 )
             }
             Op::UserInput {
                 items,
                 final_output_json_schema,
+                effort,
+                tool_choice,
             } => (
                 items,
                 SessionSettingsUpdate {
-                    final_output_json_schema: Some(final_output_json_schema),
+                    final_output_json_schema: Some(
+                        final_output_json_schema.or(default_output_schema),
+                    ),
+                    reasoning_effort_override: effort,
+                    tool_choice_override: tool_choice,
                     ..Default::default()
                 },
             ),
             _ => unreachable!(),
         };
+        if let Some(message) = sess.unsupported_reasoning_effort_override(&updates).await {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message,
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            })
+            .await;
+            return;
+        }
 
-        let Ok(current_context) = sess.new_turn_with_sub_id(sub_id, updates).await else {
+        let Ok(current_context) = sess.new_turn_with_sub_id(sub_id.clone(), updates).await else {
             // new_turn_with_sub_id already emits the error event.
             return;
         };
+        if let Some(message) = unknown_tool_choice(sess, current_context.as_ref(), &items).await {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message,
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            })
+            .await;
+            return;
+        }
         sess.maybe_emit_unknown_model_warning_for_turn(current_context.as_ref())
             .await;
         current_context.session_telemetry.user_prompt(&items);
@@ -4421,114 +5330,873 @@ mod handlers {
         }
     }
 
-    pub async fn run_user_shell_command(sess: &Arc<Session>, sub_id: String, command: String) {
-        if let Some((turn_context, cancellation_token)) =
-            sess.active_turn_context_and_cancellation_token().await
-        {
-            let session = Arc::clone(sess);
-            tokio::spawn(async move {
-                execute_user_shell_command(
-                    session,
-                    turn_context,
-                    command,
-                    cancellation_token,
-                    UserShellCommandMode::ActiveTurnAuxiliary,
-                )
-                .await;
-            });
-            return;
+    /// Checks a forced `tool_choice` against the tools `turn_context` would
+    /// offer, so a request naming an unknown tool is rejected before a turn
+    /// starts. Returns the error message to report.
+    async fn unknown_tool_choice(
+        sess: &Arc<Session>,
+        turn_context: &TurnContext,
+        items: &[UserInput],
+    ) -> Option<String> {
+        let ToolChoice::Tool(name) = &turn_context.tool_choice else {
+            return None;
+        };
+        // Failures to list tools are reported by the turn itself.
+        let router = built_tools(
+            sess,
+            turn_context,
+            &[],
+            &collect_explicit_app_ids(items),
+            Some(turn_context.turn_skills.outcome.as_ref()),
+            &CancellationToken::new(),
+        )
+        .await
+        .ok()?;
+        (!router.specs().iter().any(|tool| tool.name() == name))
+            .then(|| format!("tool_choice names unknown tool `{name}`"))
+    }
+
+    pub async fn pause(sess: &Arc<Session>, sub_id: String) {
+        sess.state.lock().await.pause();
+        send_pause_state_changed(sess, sub_id, true).await;
+    }
+
+    pub async fn resume(sess: &Arc<Session>, sub_id: String) {
+        let queued = sess.state.lock().await.resume();
+        send_pause_state_changed(sess, sub_id, false).await;
+        for (queued_sub_id, op) in queued {
+            user_input_or_turn(sess, queued_sub_id, op).await;
         }
+    }
 
-        let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
-        sess.spawn_task(
-            Arc::clone(&turn_context),
-            Vec::new(),
-            UserShellCommandTask::new(command),
-        )
+    async fn send_pause_state_changed(sess: &Arc<Session>, sub_id: String, paused: bool) {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::PauseStateChanged(PauseStateChangedEvent { paused }),
+        })
         .await;
     }
 
-    pub async fn resolve_elicitation(
-        sess: &Arc<Session>,
-        server_name: String,
-        request_id: ProtocolRequestId,
-        decision: codex_protocol::approvals::ElicitationAction,
-        content: Option<Value>,
-        meta: Option<Value>,
-    ) {
-        let action = match decision {
-            codex_protocol::approvals::ElicitationAction::Accept => ElicitationAction::Accept,
-            codex_protocol::approvals::ElicitationAction::Decline => ElicitationAction::Decline,
-            codex_protocol::approvals::ElicitationAction::Cancel => ElicitationAction::Cancel,
-        };
-        let content = match action {
-            // Preserve the legacy fallback for clients that only send an action.
-            ElicitationAction::Accept => Some(content.unwrap_or_else(|| serde_json::json!({}))),
-            ElicitationAction::Decline | ElicitationAction::Cancel => None,
-        };
-        let response = ElicitationResponse {
-            action,
-            content,
-            meta,
-        };
-        let request_id = match request_id {
-            ProtocolRequestId::String(value) => {
-                rmcp::model::NumberOrString::String(std::sync::Arc::from(value))
-            }
-            ProtocolRequestId::Integer(value) => rmcp::model::NumberOrString::Number(value),
+    pub async fn check_patch_applies(sess: &Arc<Session>, sub_id: String, patch: String) {
+        let cwd = {
+            let state = sess.state.lock().await;
+            state.session_configuration.cwd.clone()
         };
-        if let Err(err) = sess
-            .resolve_elicitation(server_name, request_id, response)
-            .await
-        {
-            warn!(
-                error = %err,
-                "failed to resolve elicitation request in session"
-            );
-        }
+        let sess = Arc::clone(sess);
+
+        // Checking reads every file the patch touches, so keep it off the
+        // submission loop.
+        tokio::spawn(async move {
+            let check = tokio::task::spawn_blocking(move || {
+                crate::apply_patch::check_patch_applies(&patch, &cwd)
+            })
+            .await;
+            let msg = match check {
+                Ok(outcome) => EventMsg::PatchCheckResult(PatchCheckResultEvent { outcome }),
+                Err(err) => EventMsg::Error(ErrorEvent {
+                    message: format!("failed to check patch: {err}"),
+                    codex_error_info: Some(CodexErrorInfo::Other),
+                }),
+            };
+            sess.send_event_raw(Event { id: sub_id, msg }).await;
+        });
     }
 
-    /// Propagate a user's exec approval decision to the session.
-    /// Also optionally applies an execpolicy amendment.
-    pub async fn exec_approval(
+    pub async fn hash_working_tree(
         sess: &Arc<Session>,
-        approval_id: String,
-        turn_id: Option<String>,
-        decision: ReviewDecision,
+        sub_id: String,
+        paths: Option<Vec<PathBuf>>,
     ) {
-        let event_turn_id = turn_id.unwrap_or_else(|| approval_id.clone());
-        if let ReviewDecision::ApprovedExecpolicyAmendment {
-            proposed_execpolicy_amendment,
-        } = &decision
-        {
-            match sess
-                .persist_execpolicy_amendment(proposed_execpolicy_amendment)
-                .await
-            {
-                Ok(()) => {
-                    sess.record_execpolicy_amendment_message(
-                        &event_turn_id,
-                        proposed_execpolicy_amendment,
-                    )
-                    .await;
+        let cwd = {
+            let state = sess.state.lock().await;
+            state.session_configuration.cwd.clone()
+        };
+        let sess = Arc::clone(sess);
+
+        // Hashing reads every file in the tree, so keep it off the submission loop.
+        tokio::spawn(async move {
+            let msg = match crate::git_info::hash_working_tree(&cwd, paths.as_deref()).await {
+                Ok(WorkingTreeHash { hash, file_count }) => {
+                    EventMsg::WorkingTreeHash(WorkingTreeHashEvent { hash, file_count })
                 }
                 Err(err) => {
-                    let message = format!("Failed to apply execpolicy amendment: {err}");
-                    tracing::warn!("{message}");
-                    let warning = EventMsg::Warning(WarningEvent { message });
-                    sess.send_event_raw(Event {
-                        id: event_turn_id.clone(),
-                        msg: warning,
+                    let codex_error_info = match err {
+                        WorkingTreeHashError::NotARepository(_) => CodexErrorInfo::BadRequest,
+                        WorkingTreeHashError::Failed(_) => CodexErrorInfo::Other,
+                    };
+                    EventMsg::Error(ErrorEvent {
+                        message: format!("cannot hash working tree: {err}"),
+                        codex_error_info: Some(codex_error_info),
                     })
-                    .await;
                 }
+            };
+            sess.send_event_raw(Event { id: sub_id, msg }).await;
+        });
+    }
+
+    pub async fn get_recent_errors(sess: &Arc<Session>, sub_id: String, limit: Option<usize>) {
+        let errors = sess.state.lock().await.recent_errors(limit);
+        send_recent_errors(sess, sub_id, errors).await;
+    }
+
+    pub async fn clear_recent_errors(sess: &Arc<Session>, sub_id: String) {
+        let errors = sess.state.lock().await.take_recent_errors();
+        send_recent_errors(sess, sub_id, errors).await;
+    }
+
+    async fn send_recent_errors(sess: &Arc<Session>, sub_id: String, errors: Vec<ErrorEvent>) {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::RecentErrors(RecentErrorsEvent { errors }),
+        })
+        .await;
+    }
+
+    pub async fn get_conversation_snapshot(sess: &Arc<Session>, sub_id: String) {
+        let history = sess.clone_history().await;
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::ConversationSnapshot(ConversationSnapshotEvent {
+                items: history.raw_items().to_vec(),
+                tool_output_tokens: history.tool_output_token_usage(),
+            }),
+        })
+        .await;
+    }
+
+    pub async fn get_retry_config(sess: &Arc<Session>, sub_id: String) {
+        let provider = {
+            let state = sess.state.lock().await;
+            state.session_configuration.provider.clone()
+        };
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::RetryConfig(RetryConfigEvent {
+                request_max_retries: provider.request_max_retries(),
+                stream_max_retries: provider.stream_max_retries(),
+                base_backoff_ms: crate::util::INITIAL_DELAY_MS,
+                backoff_factor: crate::util::BACKOFF_FACTOR,
+                jitter_ratio: crate::util::BACKOFF_JITTER,
+                stream_idle_timeout_ms: provider.stream_idle_timeout().as_millis() as u64,
+            }),
+        })
+        .await;
+    }
+
+    pub async fn get_features(sess: &Arc<Session>, sub_id: String) {
+        let enabled = sess
+            .features
+            .enabled_features()
+            .into_iter()
+            .map(|feature| feature.key().to_string())
+            .collect();
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Features(FeaturesEvent { enabled }),
+        })
+        .await;
+    }
+
+    pub async fn set_budget(sess: &Arc<Session>, sub_id: String, max_total_tokens: Option<u64>) {
+        sess.state.lock().await.set_token_budget(max_total_tokens);
+        get_budget(sess, sub_id).await;
+    }
+
+    pub async fn get_budget(sess: &Arc<Session>, sub_id: String) {
+        let (max_total_tokens, total_tokens) = {
+            let state = sess.state.lock().await;
+            (state.token_budget(), state.session_total_tokens())
+        };
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::TokenBudget(TokenBudgetEvent {
+                max_total_tokens,
+                total_tokens,
+            }),
+        })
+        .await;
+    }
+
+    pub async fn get_session_stats(sess: &Arc<Session>, sub_id: String) {
+        let exec_calls = sess.services.metrics.exec_calls_total();
+        let stats = sess.state.lock().await.session_stats(exec_calls);
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::SessionStats(stats),
+        })
+        .await;
+    }
+
+    pub async fn get_last_reasoning(sess: &Arc<Session>, sub_id: String) {
+        let last_reasoning = sess.state.lock().await.last_reasoning();
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::LastReasoning(last_reasoning),
+        })
+        .await;
+    }
+
+    pub async fn set_base_instructions(
+        sess: &Arc<Session>,
+        sub_id: String,
+        instructions: Option<String>,
+    ) {
+        let instructions = sess.set_base_instructions(instructions).await;
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::BaseInstructionsUpdated(BaseInstructionsUpdatedEvent { instructions }),
+        })
+        .await;
+    }
+
+    pub async fn validate_plugin(sess: &Arc<Session>, sub_id: String, path: PathBuf) {
+        let path = if path.is_absolute() {
+            path
+        } else {
+            let state = sess.state.lock().await;
+            state.session_configuration.cwd.join(path)
+        };
+        let sess = Arc::clone(sess);
+        // Validation reads the manifest, MCP configs and skills of the
+        // plugin, so keep it off the submission loop.
+        tokio::spawn(async move {
+            let validate_path = path.clone();
+            let errors = tokio::task::spawn_blocking(move || {
+                PluginsManager::validate_plugin(&validate_path)
+                    .into_iter()
+                    .map(|error| PluginValidationErrorInfo {
+                        path: error.path().to_path_buf(),
+                        message: error.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .await;
+            let msg = match errors {
+                Ok(errors) => {
+                    EventMsg::PluginValidationResult(PluginValidationResultEvent { path, errors })
+                }
+                Err(err) => EventMsg::Error(ErrorEvent {
+                    message: format!("failed to validate plugin: {err}"),
+                    codex_error_info: Some(CodexErrorInfo::Other),
+                }),
+            };
+            sess.send_event_raw(Event { id: sub_id, msg }).await;
+        });
+    }
+
+    pub async fn list_plugins(sess: &Arc<Session>, sub_id: String) {
+        let config = sess.get_config().await;
+        let outcome = sess.services.plugins_manager.plugins_for_config(&config);
+        let plugins = outcome
+            .plugins()
+            .iter()
+            .map(|plugin| PluginListEntry {
+                config_name: plugin.config_name.clone(),
+                manifest_name: plugin.manifest_name.clone(),
+                enabled: plugin.enabled,
+                error: plugin.error.clone(),
+                has_skills: plugin.capabilities.has_skills,
+                has_mcp_servers: plugin.capabilities.has_mcp_servers,
+                skill_count: plugin.capabilities.skill_count,
+                mcp_server_count: plugin.capabilities.mcp_server_count,
+                declared_capabilities: plugin.capabilities.declared_capabilities.clone(),
+            })
+            .collect();
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::PluginList(PluginListEvent {
+                plugins,
+                summary: outcome.summary(),
+            }),
+        })
+        .await;
+    }
+
+    pub async fn watch_files(
+        sess: &Arc<Session>,
+        sub_id: String,
+        paths: Vec<PathBuf>,
+        prompt_template: String,
+    ) {
+        let error = if paths.is_empty() {
+            Some("WatchFiles requires at least one path".to_string())
+        } else {
+            let cwd = {
+                let state = sess.state.lock().await;
+                state.session_configuration.cwd.clone()
+            };
+            let paths: Vec<PathBuf> = paths.iter().map(|path| cwd.join(path)).collect();
+            let cancel = CancellationToken::new();
+            // Treat changes made during a turn as the agent's own edits.
+            let weak = Arc::downgrade(sess);
+            let is_turn_running = move || {
+                let weak = weak.clone();
+                async move {
+                    match weak.upgrade() {
+                        Some(sess) => sess.active_turn.lock().await.is_some(),
+                        None => false,
+                    }
+                }
+            };
+            match crate::watch_mode::spawn_file_watch(
+                &paths,
+                prompt_template,
+                is_turn_running,
+                cancel.clone(),
+            ) {
+                Ok(mut prompts) => {
+                    let previous = sess.state.lock().await.replace_file_watch(cancel.clone());
+                    if let Some(previous) = previous {
+                        previous.cancel();
+                    }
+                    // Hold the session weakly so a forgotten watch does not
+                    // keep it alive after shutdown.
+                    let weak = Arc::downgrade(sess);
+                    tokio::spawn(async move {
+                        loop {
+                            let prompt = tokio::select! {
+                                _ = cancel.cancelled() => break,
+                                prompt = prompts.recv() => prompt,
+                            };
+                            let (Some(prompt), Some(sess)) = (prompt, weak.upgrade()) else {
+                                break;
+                            };
+                            sess.submit_text_input(prompt).await;
+                        }
+                    });
+                    None
+                }
+                Err(err) => Some(format!("failed to watch files: {err}")),
+            }
+        };
+        if let Some(message) = error {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message,
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            })
+            .await;
+        }
+    }
+
+    pub async fn blame_file(
+        sess: &Arc<Session>,
+        sub_id: String,
+        path: PathBuf,
+        line_range: Option<BlameLineRange>,
+    ) {
+        let cwd = {
+            let state = sess.state.lock().await;
+            state.session_configuration.cwd.clone()
+        };
+        let msg = match crate::git_info::blame_file(&cwd, &path, line_range).await {
+            Ok(lines) => EventMsg::BlameResult(BlameResultEvent { path, lines }),
+            Err(err) => EventMsg::Error(ErrorEvent {
+                message: err.to_string(),
+                codex_error_info: Some(CodexErrorInfo::BadRequest),
+            }),
+        };
+        sess.send_event_raw(Event { id: sub_id, msg }).await;
+    }
+
+    pub async fn search_history(sess: &Arc<Session>, sub_id: String, query: &str) {
+        let history = sess.clone_history().await;
+        let indices = history.search(query);
+        let snippets = indices
+            .iter()
+            .filter_map(|&index| history.search_snippet(index, query))
+            .collect();
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::SearchHistoryResults(SearchHistoryResultsEvent { indices, snippets }),
+        })
+        .await;
+    }
+
+    pub async fn set_approval_policy(sess: &Arc<Session>, sub_id: String, policy: AskForApproval) {
+        let updates = SessionSettingsUpdate {
+            approval_policy: Some(policy),
+            ..Default::default()
+        };
+        if let Err(err) = sess.update_settings(updates).await {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message: err.to_string(),
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            })
+            .await;
+            return;
+        }
+
+        let approval_policy = {
+            let state = sess.state.lock().await;
+            state.session_configuration.approval_policy.value()
+        };
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::ApprovalPolicyChanged(ApprovalPolicyChangedEvent { approval_policy }),
+        })
+        .await;
+    }
+
+    pub async fn change_working_directory(sess: &Arc<Session>, sub_id: String, path: PathBuf) {
+        let msg = match sess.set_cwd(path.clone()).await {
+            Ok(()) => {
+                EventMsg::WorkingDirectoryChanged(WorkingDirectoryChangedEvent { new_cwd: path })
+            }
+            Err(message) => EventMsg::Error(ErrorEvent {
+                message,
+                codex_error_info: Some(CodexErrorInfo::BadRequest),
+            }),
+        };
+        sess.send_event_raw(Event { id: sub_id, msg }).await;
+    }
+
+    pub async fn export_conversation(
+        sess: &Arc<Session>,
+        sub_id: String,
+        path: PathBuf,
+        format: ExportFormat,
+    ) {
+        let (cwd, history) = {
+            let state = sess.state.lock().await;
+            (
+                state.session_configuration.cwd.clone(),
+                state.clone_history(),
+            )
+        };
+        let path = cwd.join(path);
+        let recorder = {
+            let guard = sess.services.rollout.lock().await;
+            guard.clone()
+        };
+        // Markdown is streamed from the rollout so the transcript covers the
+        // whole session, including turns compacted out of the history.
+        let rollout = match recorder {
+            Some(recorder) if format == ExportFormat::Markdown => {
+                tokio::fs::try_exists(recorder.rollout_path())
+                    .await
+                    .unwrap_or(false)
+                    .then_some(recorder)
+            }
+            Some(_) | None => None,
+        };
+        let result = match rollout {
+            Some(recorder) => export_rollout_markdown(&recorder, &path).await,
+            None => match render_conversation(history.raw_items(), format) {
+                Ok(contents) => tokio::fs::write(&path, contents)
+                    .await
+                    .map_err(anyhow::Error::from),
+                Err(err) => Err(err.into()),
+            },
+        };
+        let msg = match result {
+            Ok(()) => EventMsg::ExportComplete(ExportCompleteEvent { path }),
+            Err(err) => EventMsg::Error(ErrorEvent {
+                message: format!("Failed to export conversation to {}: {err}", path.display()),
+                codex_error_info: Some(CodexErrorInfo::Other),
+            }),
+        };
+        sess.send_event_raw(Event { id: sub_id, msg }).await;
+    }
+
+    async fn export_rollout_markdown(
+        recorder: &RolloutRecorder,
+        path: &Path,
+    ) -> anyhow::Result<()> {
+        let mut file = tokio::fs::File::create(path).await?;
+        recorder.export_markdown(&mut file).await
+    }
+
+    pub async fn set_environment_variable(
+        sess: &Arc<Session>,
+        sub_id: String,
+        name: String,
+        value: String,
+    ) {
+        if let Err(message) = validate_environment_variable(&name, Some(&value)) {
+            send_invalid_environment_variable(sess, sub_id, message).await;
+            return;
+        }
+        sess.set_environment_variable(name, value).await;
+    }
+
+    pub async fn unset_environment_variable(sess: &Arc<Session>, sub_id: String, name: String) {
+        if let Err(message) = validate_environment_variable(&name, None) {
+            send_invalid_environment_variable(sess, sub_id, message).await;
+            return;
+        }
+        sess.unset_environment_variable(name).await;
+    }
+
+    fn validate_environment_variable(name: &str, value: Option<&str>) -> Result<(), String> {
+        if name.is_empty() || name.contains('=') || name.contains('\0') {
+            return Err(format!("Invalid environment variable name: {name:?}"));
+        }
+        if value.is_some_and(|value| value.contains('\0')) {
+            return Err(format!(
+                "Value of environment variable {name} must not contain NUL bytes"
+            ));
+        }
+        Ok(())
+    }
+
+    async fn send_invalid_environment_variable(sess: &Session, sub_id: String, message: String) {
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Error(ErrorEvent {
+                message,
+                codex_error_info: Some(CodexErrorInfo::BadRequest),
+            }),
+        })
+        .await;
+    }
+
+    pub async fn run_user_shell_command(sess: &Arc<Session>, sub_id: String, command: String) {
+        if let Some((turn_context, cancellation_token)) =
+            sess.active_turn_context_and_cancellation_token().await
+        {
+            let session = Arc::clone(sess);
+            tokio::spawn(async move {
+                execute_user_shell_command(
+                    session,
+                    turn_context,
+                    command,
+                    cancellation_token,
+                    UserShellCommandMode::ActiveTurnAuxiliary,
+                )
+                .await;
+            });
+            return;
+        }
+
+        let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
+        sess.spawn_task(
+            Arc::clone(&turn_context),
+            Vec::new(),
+            UserShellCommandTask::new(command),
+        )
+        .await;
+    }
+
+    pub async fn run_command(
+        sess: &Arc<Session>,
+        sub_id: String,
+        command: Vec<String>,
+        cwd: Option<PathBuf>,
+        timeout_ms: Option<u64>,
+    ) {
+        let has_active_turn = { sess.active_turn.lock().await.is_some() };
+        let rejection = if command.is_empty() {
+            Some("Cannot run an empty command.")
+        } else if has_active_turn {
+            Some("Cannot run a command while a turn is in progress.")
+        } else {
+            None
+        };
+        if let Some(message) = rejection {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message: message.to_string(),
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            })
+            .await;
+            return;
+        }
+
+        let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
+        sess.spawn_task(
+            turn_context,
+            Vec::new(),
+            RunCommandTask::new(command, cwd, timeout_ms),
+        )
+        .await;
+    }
+
+    pub async fn reapprove_last_denied(sess: &Arc<Session>, sub_id: String) {
+        let has_active_turn = { sess.active_turn.lock().await.is_some() };
+        let denied = sess.state.lock().await.last_denied_command();
+        let denied = match denied {
+            Some(_) if has_active_turn => {
+                Err("Cannot re-approve a command while a turn is in progress.")
+            }
+            Some(denied) => Ok(denied),
+            None => Err("There is no denied command to re-approve."),
+        };
+        let denied = match denied {
+            Ok(denied) => denied,
+            Err(message) => {
+                sess.send_event_raw(Event {
+                    id: sub_id,
+                    msg: EventMsg::Error(ErrorEvent {
+                        message: message.to_string(),
+                        codex_error_info: Some(CodexErrorInfo::BadRequest),
+                    }),
+                })
+                .await;
+                return;
+            }
+        };
+
+        let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
+        sess.spawn_task(
+            turn_context,
+            Vec::new(),
+            RunCommandTask::for_denied_command(denied.clone()),
+        )
+        .await;
+        // Only forget the denial once the re-run is underway.
+        sess.state.lock().await.clear_last_denied_command(&denied);
+    }
+
+    pub async fn explain_last_command(sess: &Arc<Session>, sub_id: String) {
+        let has_active_turn = { sess.active_turn.lock().await.is_some() };
+        let command = sess.state.lock().await.last_executed_command();
+        let command = match command {
+            _ if has_active_turn => Err("Cannot explain a command while a turn is in progress."),
+            Some(command) => Ok(command),
+            None => Err("No command has been run yet."),
+        };
+        let command = match command {
+            Ok(command) => command,
+            Err(message) => {
+                sess.send_event_raw(Event {
+                    id: sub_id,
+                    msg: EventMsg::Error(ErrorEvent {
+                        message: message.to_string(),
+                        codex_error_info: Some(CodexErrorInfo::BadRequest),
+                    }),
+                })
+                .await;
+                return;
+            }
+        };
+
+        let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
+        sess.spawn_task(turn_context, Vec::new(), ExplainCommandTask::new(command))
+            .await;
+    }
+
+    pub async fn set_notify_command(
+        sess: &Arc<Session>,
+        sub_id: String,
+        command: Option<Vec<String>>,
+    ) {
+        if command
+            .as_ref()
+            .is_some_and(|argv| argv.first().is_none_or(String::is_empty))
+        {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message: "Notify command must not be empty.".to_string(),
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            })
+            .await;
+            return;
+        }
+        sess.hooks().set_legacy_notify_argv(command);
+    }
+
+    pub async fn set_default_output_schema(sess: &Arc<Session>, schema: Option<Value>) {
+        sess.state.lock().await.default_output_schema = schema;
+    }
+
+    pub async fn inject_system_message(sess: &Arc<Session>, text: String) {
+        sess.state.lock().await.push_injected_system_message(text);
+    }
+
+    pub async fn reset_turn_diff(sess: &Arc<Session>, sub_id: String) {
+        let has_active_turn = { sess.active_turn.lock().await.is_some() };
+        let Some(tracker) = sess.state.lock().await.last_turn_diff_tracker() else {
+            return;
+        };
+        let unified_diff = {
+            let mut tracker = tracker.lock().await;
+            if !has_active_turn {
+                tracker.abandon_patches_in_flight();
+            }
+            // A patch being applied finishes first; its end event reports
+            // the diff once the deferred reset ran.
+            if !tracker.reset_baseline() {
+                return;
+            }
+            tracker.get_unified_diff()
+        };
+        if let Ok(unified_diff) = unified_diff {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::TurnDiff(TurnDiffEvent {
+                    unified_diff: unified_diff.unwrap_or_default(),
+                }),
+            })
+            .await;
+        }
+    }
+
+    pub async fn undo_last_patch(sess: &Arc<Session>, sub_id: String) {
+        let has_active_turn = { sess.active_turn.lock().await.is_some() };
+        let tracker = sess.state.lock().await.last_turn_diff_tracker();
+        let tracker = match tracker {
+            _ if has_active_turn => {
+                Err("Cannot undo a patch while a turn is in progress.".to_string())
+            }
+            None => Err("No patch from the last turn to undo.".to_string()),
+            Some(tracker) => check_undoable(tracker).await,
+        };
+        let tracker = match tracker {
+            Ok(tracker) => tracker,
+            Err(message) => {
+                sess.send_event_raw(Event {
+                    id: sub_id,
+                    msg: EventMsg::Error(ErrorEvent {
+                        message,
+                        codex_error_info: Some(CodexErrorInfo::BadRequest),
+                    }),
+                })
+                .await;
+                return;
+            }
+        };
+
+        let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
+        sess.spawn_task(turn_context, Vec::new(), UndoPatchTask::new(tracker))
+            .await;
+    }
+
+    /// Returns `tracker` when it has changes to revert and none of its files
+    /// were edited after the patch that wrote them. Reading the files is
+    /// blocking work, so it runs on the blocking pool.
+    async fn check_undoable(
+        tracker: SharedTurnDiffTracker,
+    ) -> Result<SharedTurnDiffTracker, String> {
+        let check_tracker = Arc::clone(&tracker);
+        let check = tokio::task::spawn_blocking(move || {
+            let tracker = check_tracker.blocking_lock();
+            (
+                tracker.rollback_changes().is_empty(),
+                tracker.conflicting_paths(),
+            )
+        })
+        .await
+        .map_err(|err| format!("failed to inspect the last patch: {err}"))?;
+        match check {
+            (true, _) => Err("The last turn made no file changes to undo.".to_string()),
+            (false, conflicts) if !conflicts.is_empty() => {
+                let paths = conflicts
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>();
+                Err(format!(
+                    "Cannot undo the last patch because these files changed since it was applied:\n{}",
+                    paths.join("\n")
+                ))
+            }
+            (false, _) => Ok(tracker),
+        }
+    }
+
+    pub async fn resolve_elicitation(
+        sess: &Arc<Session>,
+        server_name: String,
+        request_id: ProtocolRequestId,
+        decision: codex_protocol::approvals::ElicitationAction,
+        content: Option<Value>,
+        meta: Option<Value>,
+    ) {
+        let action = match decision {
+            codex_protocol::approvals::ElicitationAction::Accept => ElicitationAction::Accept,
+            codex_protocol::approvals::ElicitationAction::Decline => ElicitationAction::Decline,
+            codex_protocol::approvals::ElicitationAction::Cancel => ElicitationAction::Cancel,
+        };
+        let content = match action {
+            // Preserve the legacy fallback for clients that only send an action.
+            ElicitationAction::Accept => Some(content.unwrap_or_else(|| serde_json::json!({}))),
+            ElicitationAction::Decline | ElicitationAction::Cancel => None,
+        };
+        let response = ElicitationResponse {
+            action,
+            content,
+            meta,
+        };
+        let request_id = match request_id {
+            ProtocolRequestId::String(value) => {
+                rmcp::model::NumberOrString::String(std::sync::Arc::from(value))
+            }
+            ProtocolRequestId::Integer(value) => rmcp::model::NumberOrString::Number(value),
+        };
+        if let Err(err) = sess
+            .resolve_elicitation(server_name, request_id, response)
+            .await
+        {
+            warn!(
+                error = %err,
+                "failed to resolve elicitation request in session"
+            );
+        }
+    }
+
+    /// Propagate a user's exec approval decision to the session.
+    /// Also optionally applies an execpolicy amendment.
+    pub async fn exec_approval(
+        sess: &Arc<Session>,
+        approval_id: String,
+        turn_id: Option<String>,
+        decision: ReviewDecision,
+    ) {
+        let event_turn_id = turn_id.unwrap_or_else(|| approval_id.clone());
+        if let ReviewDecision::ApprovedExecpolicyAmendment {
+            proposed_execpolicy_amendment,
+        } = &decision
+        {
+            match sess
+                .persist_execpolicy_amendment(proposed_execpolicy_amendment)
+                .await
+            {
+                Ok(()) => {
+                    sess.record_execpolicy_amendment_message(
+                        &event_turn_id,
+                        proposed_execpolicy_amendment,
+                    )
+                    .await;
+                }
+                Err(err) => {
+                    let message = format!("Failed to apply execpolicy amendment: {err}");
+                    tracing::warn!("{message}");
+                    let warning = EventMsg::Warning(WarningEvent { message });
+                    sess.send_event_raw(Event {
+                        id: event_turn_id.clone(),
+                        msg: warning,
+                    })
+                    .await;
+                }
+            }
+        }
+        match decision {
+            ReviewDecision::Abort => {
+                sess.interrupt_task().await;
             }
+            other => sess.notify_approval(&approval_id, other).await,
         }
+    }
+
+    pub async fn exec_approval_batch(
+        sess: &Arc<Session>,
+        batch_id: String,
+        decision: ReviewDecision,
+    ) {
         match decision {
             ReviewDecision::Abort => {
                 sess.interrupt_task().await;
             }
-            other => sess.notify_approval(&approval_id, other).await,
+            other => sess.notify_approval_batch(&batch_id, other).await,
         }
     }
 
@@ -4541,6 +6209,10 @@ mod handlers {
         }
     }
 
+    pub async fn cancel_approval(sess: &Arc<Session>, id: String) {
+        sess.notify_approval(&id, ReviewDecision::Denied).await;
+    }
+
     pub async fn request_user_input_response(
         sess: &Arc<Session>,
         id: String,
@@ -4622,6 +6294,38 @@ mod handlers {
         sess.reload_user_config_layer().await;
     }
 
+    pub async fn reload_plugins(sess: &Arc<Session>, sub_id: String) {
+        let reconnected = sess.reload_plugins().await;
+        if reconnected.is_empty() {
+            return;
+        }
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::Warning(WarningEvent {
+                message: format!(
+                    "Plugin MCP servers changed on reload; reconnecting: {}.",
+                    reconnected.join(", ")
+                ),
+            }),
+        })
+        .await;
+    }
+
+    pub async fn reload_user_instructions(sess: &Arc<Session>, sub_id: String) {
+        let message = if sess.reload_user_instructions().await {
+            "Reloaded user instructions; they apply from the next turn."
+        } else {
+            "No user instructions found; cleared them for the next turn."
+        };
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::BackgroundEvent(BackgroundEventEvent {
+                message: message.to_string(),
+            }),
+        })
+        .await;
+    }
+
     pub async fn list_mcp_tools(sess: &Session, config: &Arc<Config>, sub_id: String) {
         let mcp_connection_manager = sess.services.mcp_connection_manager.read().await;
         let auth = sess.services.auth_manager.auth().await;
@@ -4642,6 +6346,49 @@ mod handlers {
         sess.send_event_raw(event).await;
     }
 
+    /// Checks the servers concurrently in a background task so a slow server
+    /// does not hold up the submission loop.
+    pub async fn mcp_health_check(sess: &Arc<Session>, sub_id: String, server: Option<String>) {
+        let checks = {
+            let mcp_connection_manager = sess.services.mcp_connection_manager.read().await;
+            let servers = match server {
+                Some(server) => vec![server],
+                None => mcp_connection_manager.server_names(),
+            };
+            servers
+                .into_iter()
+                .map(|server| {
+                    let check = mcp_connection_manager.health_check(&server);
+                    (server, check)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let sess = Arc::clone(sess);
+        tokio::spawn(async move {
+            let results = join_all(checks.into_iter().map(|(server, check)| async move {
+                match check {
+                    Ok(check) => EventMsg::McpHealthCheckResult(McpHealthCheckResultEvent {
+                        server,
+                        status: check.await,
+                    }),
+                    Err(err) => EventMsg::Error(ErrorEvent {
+                        message: err.to_string(),
+                        codex_error_info: Some(CodexErrorInfo::BadRequest),
+                    }),
+                }
+            }))
+            .await;
+            for msg in results {
+                sess.send_event_raw(Event {
+                    id: sub_id.clone(),
+                    msg,
+                })
+                .await;
+            }
+        });
+    }
+
     pub async fn list_custom_prompts(sess: &Session, sub_id: String) {
         let custom_prompts: Vec<CustomPrompt> =
             if let Some(dir) = crate::custom_prompts::default_prompts_dir() {
@@ -4974,6 +6721,159 @@ mod handlers {
         .await;
     }
 
+    pub async fn export_state(sess: &Arc<Session>, sub_id: String) {
+        let has_active_turn = { sess.active_turn.lock().await.is_some() };
+        if has_active_turn {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message: "Cannot export session state while a turn is in progress."
+                        .to_string(),
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            })
+            .await;
+            return;
+        }
+
+        let snapshot = Box::new(sess.export_state_snapshot().await);
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::SessionStateExported(SessionStateExportedEvent { snapshot }),
+        })
+        .await;
+    }
+
+    /// Closes the current rollout file and continues recording into a new one
+    /// that points back at it. See `RolloutRecorder::rotate`.
+    pub async fn rotate_rollout(sess: &Arc<Session>, sub_id: String) {
+        let config = sess.get_config().await;
+        let mut guard = sess.services.rollout.lock().await;
+        let Some(recorder) = guard.as_ref() else {
+            drop(guard);
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::Error(ErrorEvent {
+                    message: "Session persistence is disabled; cannot rotate rollout.".to_string(),
+                    codex_error_info: Some(CodexErrorInfo::Other),
+                }),
+            })
+            .await;
+            return;
+        };
+
+        let previous_path = recorder.rollout_path().to_path_buf();
+        let rotated = match recorder.rotate(&config).await {
+            Ok(rotated) => rotated,
+            Err(err) => {
+                drop(guard);
+                warn!(
+                    "failed to rotate rollout {}: {err}",
+                    previous_path.display()
+                );
+                sess.send_event_raw(Event {
+                    id: sub_id,
+                    msg: EventMsg::Error(ErrorEvent {
+                        message: format!(
+                            "failed to rotate rollout `{}`: {err}",
+                            previous_path.display()
+                        ),
+                        codex_error_info: Some(CodexErrorInfo::Other),
+                    }),
+                })
+                .await;
+                return;
+            }
+        };
+        if let Err(err) = rotated.persist().await {
+            warn!("failed to materialize rotated rollout: {err}");
+        }
+        let rollout_path = rotated.rollout_path().to_path_buf();
+        info!(
+            "rotated rollout {} -> {}",
+            previous_path.display(),
+            rollout_path.display()
+        );
+        *guard = Some(rotated);
+        drop(guard);
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::RolloutRotated(RolloutRotatedEvent {
+                rollout_path,
+                previous_rollout_path: previous_path,
+            }),
+        })
+        .await;
+    }
+
+    /// Branches the session into a new thread whose history ends with the turn
+    /// started by `from_submission_id`, leaving this session untouched.
+    pub async fn fork_session(sess: &Arc<Session>, sub_id: String, from_submission_id: String) {
+        let sess = Arc::clone(sess);
+
+        // Forking reads the whole rollout and starts a new thread, so keep it
+        // off the submission loop.
+        tokio::spawn(async move {
+            let msg = match fork_session_thread(&sess, &from_submission_id).await {
+                Ok(new_thread) => EventMsg::SessionForked(SessionForkedEvent {
+                    session_id: new_thread.thread_id,
+                    from_submission_id,
+                    rollout_path: new_thread.session_configured.rollout_path,
+                }),
+                Err(error) => EventMsg::Error(error),
+            };
+            sess.send_event_raw(Event { id: sub_id, msg }).await;
+        });
+    }
+
+    async fn fork_session_thread(
+        sess: &Arc<Session>,
+        from_submission_id: &str,
+    ) -> Result<NewThread, ErrorEvent> {
+        // Rollout writes are queued; materialize and flush before reading the
+        // file back so the requested turn is on disk.
+        sess.ensure_rollout_materialized().await;
+        sess.flush_rollout().await;
+        let Some(rollout_path) = sess.current_rollout_path().await else {
+            return Err(ErrorEvent {
+                message: "Session persistence is disabled; cannot fork session.".to_string(),
+                codex_error_info: Some(CodexErrorInfo::BadRequest),
+            });
+        };
+        // After `Op::RotateRollout` the current file only holds the tail of
+        // the thread; loading it follows `previous_rollout_path` back through
+        // every earlier segment, so turns recorded before a rotation are found.
+        let items = RolloutRecorder::get_rollout_history(&rollout_path)
+            .await
+            .map_err(|err| ErrorEvent {
+                message: format!("failed to read rollout `{}`: {err}", rollout_path.display()),
+                codex_error_info: Some(CodexErrorInfo::Other),
+            })?
+            .get_rollout_items();
+        let Some(forked_items) = truncate_rollout_after_turn(&items, from_submission_id) else {
+            return Err(ErrorEvent {
+                message: format!(
+                    "No finished turn was started by submission `{from_submission_id}`."
+                ),
+                codex_error_info: Some(CodexErrorInfo::BadRequest),
+            });
+        };
+
+        let config = sess.get_config().await;
+        let session_source = {
+            let state = sess.state.lock().await;
+            state.session_configuration.session_source.clone()
+        };
+        sess.services
+            .agent_control
+            .fork_thread((*config).clone(), forked_items, session_source)
+            .await
+            .map_err(|err| ErrorEvent {
+                message: format!("failed to fork session: {err}"),
+                codex_error_info: Some(CodexErrorInfo::Other),
+            })
+    }
+
     /// Persists the thread name in the session index, updates in-memory state, and emits
     /// a `ThreadNameUpdated` event on success.
     ///
@@ -5042,6 +6942,7 @@ mod handlers {
 
     pub async fn shutdown(sess: &Arc<Session>, sub_id: String) -> bool {
         sess.abort_all_tasks(TurnAbortReason::Interrupted).await;
+        sess.cancel_file_watch().await;
         let _ = sess.conversation.shutdown().await;
         sess.services
             .unified_exec_manager
@@ -5231,6 +7132,7 @@ async fn spawn_review_thread(
         shell_environment_policy: parent_turn_context.shell_environment_policy.clone(),
         cwd: parent_turn_context.cwd.clone(),
         final_output_json_schema: None,
+        tool_choice: ToolChoice::Auto,
         codex_linux_sandbox_exe: parent_turn_context.codex_linux_sandbox_exe.clone(),
         tool_call_gate: Arc::new(ReadinessFlag::new()),
         js_repl: Arc::clone(&sess.js_repl),
@@ -5300,6 +7202,7 @@ fn skills_to_info(
                         .collect(),
                 }
             }),
+            plugin: plugin_namespace_for_skill_path(&skill.path_to_skills_md),
             path: skill.path_to_skills_md.clone(),
             scope: skill.scope,
             enabled: !disabled_paths.contains(&skill.path_to_skills_md),
@@ -5342,15 +7245,25 @@ pub(crate) async fn run_turn(
         return None;
     }
 
-    let model_info = turn_context.model_info.clone();
-    let auto_compact_limit = model_info.auto_compact_token_limit().unwrap_or(i64::MAX);
+    let auto_compact_limit = turn_context.auto_compact_token_limit();
 
+    sess.services.metrics.record_turn();
     let event = EventMsg::TurnStarted(TurnStartedEvent {
         turn_id: turn_context.sub_id.clone(),
         model_context_window: turn_context.model_context_window(),
         collaboration_mode_kind: turn_context.collaboration_mode.mode,
     });
     sess.send_event(&turn_context, event).await;
+    run_plugin_task_hooks(
+        &sess,
+        &turn_context,
+        PluginTaskHookStage::PreTask,
+        &cancellation_token,
+    )
+    .await;
+    if cancellation_token.is_cancelled() {
+        return None;
+    }
     // TODO(ccunningham): Pre-turn compaction runs before context updates and the
     // new user message are recorded. Estimate pending incoming items (context
     // diffs/full reinjection + user input) and trigger compaction preemptively
@@ -5363,6 +7276,13 @@ pub(crate) async fn run_turn(
         return None;
     }
 
+    if let Some(budget) = turn_context.config.history_token_budget {
+        sess.state
+            .lock()
+            .await
+            .keep_history_within_token_budget(budget);
+    }
+
     let skills_outcome = Some(turn_context.turn_skills.outcome.as_ref());
 
     sess.record_context_updates_and_set_reference_context_item(turn_context.as_ref())
@@ -5462,6 +7382,15 @@ pub(crate) async fn run_turn(
     let plugin_items =
         build_plugin_injections(&mentioned_plugins, &mcp_tools, &available_connectors);
 
+    let FileAttachments {
+        items: attached_file_items,
+        warnings: attachment_warnings,
+    } = build_file_attachments(&input, turn_context.as_ref()).await;
+    for message in attachment_warnings {
+        sess.send_event(&turn_context, EventMsg::Warning(WarningEvent { message }))
+            .await;
+    }
+
     let mut explicitly_enabled_connectors = collect_explicit_app_ids(&input);
     explicitly_enabled_connectors.extend(collect_explicit_app_ids_from_skill_items(
         &skill_items,
@@ -5523,16 +7452,52 @@ pub(crate) async fn run_turn(
         sess.record_conversation_items(&turn_context, &plugin_items)
             .await;
     }
+    if !attached_file_items.is_empty() {
+        sess.record_conversation_items(&turn_context, &attached_file_items)
+            .await;
+    }
 
     sess.maybe_start_ghost_snapshot(Arc::clone(&turn_context), cancellation_token.child_token())
         .await;
     let mut last_agent_message: Option<String> = None;
     let mut stop_hook_active = false;
     let mut pending_stop_hook_message: Option<String> = None;
+    let mut sampled_tool_calls = false;
+    let mut final_message_requested = false;
+    let mut pending_final_message_request = false;
     // Although from the perspective of codex.rs, TurnDiffTracker has the lifecycle of a Task which contains
     // many turns, from the perspective of the user, it is a single turn.
     let turn_diff_tracker = Arc::new(tokio::sync::Mutex::new(TurnDiffTracker::new()));
+    sess.state
+        .lock()
+        .await
+        .set_last_turn_diff_tracker(Arc::clone(&turn_diff_tracker));
     let mut server_model_warning_emitted_for_turn = false;
+    let mut tool_call_loop =
+        ToolCallLoopDetector::new(turn_context.config.tool_call_loop_threshold);
+    let mut assistant_output_cap =
+        AssistantOutputCap::new(turn_context.config.max_assistant_output_bytes);
+    let recent_diffs_context = if turn_context.config.include_recent_diffs_in_context {
+        sess.state.lock().await.recent_turn_diffs.render_context()
+    } else {
+        None
+    };
+    // One-shot instructions from `Op::InjectSystemMessage`. They are added to
+    // every sampling request of this turn but never recorded in history.
+    let injected_system_messages = sess
+        .state
+        .lock()
+        .await
+        .take_injected_system_messages()
+        .into_iter()
+        .map(|text| ResponseItem::Message {
+            id: None,
+            role: "system".to_string(),
+            content: vec![ContentItem::InputText { text }],
+            end_turn: None,
+            phase: None,
+        })
+        .collect::<Vec<_>>();
 
     // `ModelClientSession` is turn-scoped and caches WebSocket + sticky routing state, so we reuse
     // one instance across retries within this turn.
@@ -5589,6 +7554,10 @@ pub(crate) async fn run_turn(
             }
         }
 
+        if !wait_for_turn_rate_limit(&sess, &turn_context, &cancellation_token).await {
+            return None;
+        }
+
         // Note that pending_input would be something like a message the user
         // submitted through the UI while the model was running. Though the UI
         // may support this, the model might not.
@@ -5625,9 +7594,16 @@ pub(crate) async fn run_turn(
                 .await
                 .for_prompt(&turn_context.model_info.input_modalities)
         };
+        if let Some(recent_diffs) = recent_diffs_context.as_ref() {
+            sampling_request_input.push(DeveloperInstructions::new(recent_diffs.clone()).into());
+        }
+        sampling_request_input.extend(injected_system_messages.iter().cloned());
         if let Some(stop_hook_message) = pending_stop_hook_message.take() {
             sampling_request_input.push(DeveloperInstructions::new(stop_hook_message).into());
         }
+        if std::mem::take(&mut pending_final_message_request) {
+            sampling_request_input.push(DeveloperInstructions::new(FINAL_MESSAGE_REQUEST).into());
+        }
 
         let sampling_request_input_messages = sampling_request_input
             .iter()
@@ -5648,6 +7624,8 @@ pub(crate) async fn run_turn(
             &turn_enabled_connectors,
             skills_outcome,
             &mut server_model_warning_emitted_for_turn,
+            &mut tool_call_loop,
+            &mut assistant_output_cap,
             cancellation_token.child_token(),
         )
         .await
@@ -5657,6 +7635,7 @@ pub(crate) async fn run_turn(
                     needs_follow_up,
                     last_agent_message: sampling_request_last_agent_message,
                 } = sampling_request_output;
+                sampled_tool_calls |= needs_follow_up;
                 let total_usage_tokens = sess.get_total_token_usage().await;
                 let token_limit_reached = total_usage_tokens >= auto_compact_limit;
 
@@ -5673,6 +7652,32 @@ pub(crate) async fn run_turn(
                     "post sampling token usage"
                 );
 
+                if needs_follow_up && let Some(tool_name) = tool_call_loop.take_detected() {
+                    stop_tool_call_loop(
+                        &sess,
+                        &turn_context,
+                        &tool_name,
+                        tool_call_loop.threshold(),
+                    )
+                    .await;
+                    break;
+                }
+
+                let exceeded_budget = sess.state.lock().await.exceeded_token_budget();
+                if needs_follow_up && let Some(budget) = exceeded_budget {
+                    sess.send_event(
+                        &turn_context,
+                        EventMsg::Error(ErrorEvent {
+                            message: format!(
+                                "Stopped the turn because the session exceeded its token budget of {budget}."
+                            ),
+                            codex_error_info: Some(CodexErrorInfo::Other),
+                        }),
+                    )
+                    .await;
+                    break;
+                }
+
                 // as long as compaction works well in getting us way below the token limit, we shouldn't worry about being in an infinite loop.
                 if token_limit_reached && needs_follow_up {
                     if run_auto_compact(
@@ -5688,6 +7693,19 @@ pub(crate) async fn run_turn(
                     continue;
                 }
 
+                if !needs_follow_up
+                    && sampling_request_last_agent_message.is_none()
+                    && sampled_tool_calls
+                    && !final_message_requested
+                    && turn_context.config.request_final_message_after_tools
+                {
+                    // The model stopped after tool calls without replying; ask
+                    // once for a closing message so the turn has a final answer.
+                    final_message_requested = true;
+                    pending_final_message_request = true;
+                    continue;
+                }
+
                 if !needs_follow_up {
                     last_agent_message = sampling_request_last_agent_message;
                     let stop_hook_permission_mode = match turn_context.approval_policy.value() {
@@ -5833,6 +7851,26 @@ pub(crate) async fn run_turn(
         }
     }
 
+    let (unified_diff, changed_paths) = {
+        let mut tracker = turn_diff_tracker.lock().await;
+        // Rendering the diff reads every changed file, so skip it unless the
+        // summaries are used.
+        let unified_diff = if turn_context.config.include_recent_diffs_in_context {
+            tracker.get_unified_diff()
+        } else {
+            Ok(None)
+        };
+        (unified_diff, tracker.changed_paths())
+    };
+    sess.state.lock().await.record_changed_files(changed_paths);
+    if let Ok(Some(unified_diff)) = unified_diff {
+        sess.state
+            .lock()
+            .await
+            .recent_turn_diffs
+            .record(TurnDiffSummary::from_unified_diff(&unified_diff));
+    }
+
     last_agent_message
 }
 
@@ -5848,10 +7886,7 @@ async fn run_pre_sampling_compact(
     )
     .await?;
     let total_usage_tokens = sess.get_total_token_usage().await;
-    let auto_compact_limit = turn_context
-        .model_info
-        .auto_compact_token_limit()
-        .unwrap_or(i64::MAX);
+    let auto_compact_limit = turn_context.auto_compact_token_limit();
     // Compact if the total usage tokens are greater than the auto compact limit
     if total_usage_tokens >= auto_compact_limit {
         run_auto_compact(sess, turn_context, InitialContextInjection::DoNotInject).await?;
@@ -5885,10 +7920,7 @@ async fn maybe_run_previous_model_inline_compact(
     let Some(new_context_window) = turn_context.model_context_window() else {
         return Ok(false);
     };
-    let new_auto_compact_limit = turn_context
-        .model_info
-        .auto_compact_token_limit()
-        .unwrap_or(i64::MAX);
+    let new_auto_compact_limit = turn_context.auto_compact_token_limit();
     let should_run = total_usage_tokens > new_auto_compact_limit
         && previous_model_turn_context.model_info.slug != turn_context.model_info.slug
         && old_context_window > new_context_window;
@@ -5909,6 +7941,11 @@ async fn run_auto_compact(
     turn_context: &Arc<TurnContext>,
     initial_context_injection: InitialContextInjection,
 ) -> CodexResult<()> {
+    sess.notify_background_event(
+        turn_context.as_ref(),
+        "Context window is nearly full; compacting the conversation automatically.",
+    )
+    .await;
     if should_use_remote_compact_task(&turn_context.provider) {
         run_inline_remote_auto_compact_task(
             Arc::clone(sess),
@@ -6089,6 +8126,12 @@ fn build_prompt(
     turn_context: &TurnContext,
     base_instructions: BaseInstructions,
 ) -> Prompt {
+    // A forced tool only applies until the model has called a tool in this
+    // turn; forcing it on every follow-up request would loop forever.
+    let tool_choice = match &turn_context.tool_choice {
+        ToolChoice::Tool(_) if has_tool_output_since_last_user_message(&input) => ToolChoice::Auto,
+        tool_choice => tool_choice.clone(),
+    };
     Prompt {
         input,
         tools: router.specs(),
@@ -6096,8 +8139,92 @@ fn build_prompt(
         base_instructions,
         personality: turn_context.personality,
         output_schema: turn_context.final_output_json_schema.clone(),
+        tool_choice,
+        max_output_tokens: turn_context.config.max_output_tokens,
     }
 }
+
+fn has_tool_output_since_last_user_message(input: &[ResponseItem]) -> bool {
+    input
+        .iter()
+        .rev()
+        .take_while(|item| !matches!(item, ResponseItem::Message { role, .. } if role == "user"))
+        .any(|item| {
+            matches!(
+                item,
+                ResponseItem::FunctionCallOutput { .. } | ResponseItem::CustomToolCallOutput { .. }
+            )
+        })
+}
+
+/// Ends a turn in which the model kept repeating the same tool call: leaves
+/// the model a note for the next turn and reports the loop to the client.
+async fn stop_tool_call_loop(
+    sess: &Session,
+    turn_context: &TurnContext,
+    tool_name: &str,
+    repeats: usize,
+) {
+    let note: ResponseItem = DeveloperInstructions::new(format!(
+        "You called `{tool_name}` with identical arguments {repeats} times in a row, so the \
+         turn was stopped. Do not repeat the same call again; try a different approach or ask \
+         the user for help."
+    ))
+    .into();
+    sess.record_conversation_items(turn_context, std::slice::from_ref(&note))
+        .await;
+    sess.send_event(
+        turn_context,
+        EventMsg::Error(ErrorEvent {
+            message: format!(
+                "Stopped the turn because the model called `{tool_name}` with identical \
+                 arguments {repeats} times in a row."
+            ),
+            codex_error_info: Some(CodexErrorInfo::Other),
+        }),
+    )
+    .await;
+}
+
+/// Blocks until `max_turns_per_minute` allows another model request,
+/// telling the client while it waits. Returns `false` if the turn was
+/// cancelled meanwhile.
+async fn wait_for_turn_rate_limit(
+    sess: &Session,
+    turn_context: &TurnContext,
+    cancellation_token: &CancellationToken,
+) -> bool {
+    let Some(max_turns) = turn_context.config.max_turns_per_minute else {
+        return true;
+    };
+    loop {
+        let acquired = sess
+            .state
+            .lock()
+            .await
+            .turn_rate_limiter
+            .try_acquire(max_turns, Instant::now());
+        let Err(RateLimitExceeded { retry_after }) = acquired else {
+            return true;
+        };
+        sess.notify_background_event(
+            turn_context,
+            format!(
+                "Reached the limit of {max_turns} model requests per minute; waiting {}s before continuing.",
+                retry_after.as_secs().max(1)
+            ),
+        )
+        .await;
+        if tokio::time::sleep(retry_after)
+            .or_cancel(cancellation_token)
+            .await
+            .is_err()
+        {
+            return false;
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(level = "trace",
     skip_all,
@@ -6117,6 +8244,8 @@ async fn run_sampling_request(
     explicitly_enabled_connectors: &HashSet<String>,
     skills_outcome: Option<&SkillLoadOutcome>,
     server_model_warning_emitted_for_turn: &mut bool,
+    tool_call_loop: &mut ToolCallLoopDetector,
+    assistant_output_cap: &mut AssistantOutputCap,
     cancellation_token: CancellationToken,
 ) -> CodexResult<SamplingRequestResult> {
     let router = built_tools(
@@ -6137,6 +8266,17 @@ async fn run_sampling_request(
         turn_context.as_ref(),
         base_instructions,
     );
+    if let ToolChoice::Tool(name) = &prompt.tool_choice
+        && !prompt.tools.iter().any(|tool| tool.name() == name)
+    {
+        return Err(CodexErr::InvalidRequest(format!(
+            "tool_choice names unknown tool `{name}`"
+        )));
+    }
+    if prompt.max_output_tokens.is_some() {
+        sess.warn_if_max_output_tokens_unsupported(turn_context.as_ref())
+            .await;
+    }
     let mut retries = 0;
     loop {
         let err = match try_run_sampling_request(
@@ -6147,6 +8287,8 @@ async fn run_sampling_request(
             turn_metadata_header,
             Arc::clone(&turn_diff_tracker),
             server_model_warning_emitted_for_turn,
+            tool_call_loop,
+            assistant_output_cap,
             &prompt,
             cancellation_token.child_token(),
         )
@@ -6298,7 +8440,7 @@ pub(crate) async fn built_tools(
             connectors::filter_codex_apps_tools_by_policy(selected_mcp_tools, &turn_context.config);
     }
 
-    Ok(Arc::new(ToolRouter::from_config(
+    let router = ToolRouter::from_config(
         &turn_context.tools_config,
         has_mcp_servers.then(|| {
             mcp_tools
@@ -6308,7 +8450,10 @@ pub(crate) async fn built_tools(
         }),
         app_tools,
         turn_context.dynamic_tools.as_slice(),
-    )))
+    );
+    sess.warn_about_shadowed_tools(turn_context, router.shadowed_tools())
+        .await;
+    Ok(Arc::new(router))
 }
 
 #[derive(Debug)]
@@ -6517,6 +8662,7 @@ fn realtime_text_for_event(msg: &EventMsg) -> Option<String> {
         | EventMsg::ThreadNameUpdated(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
+        | EventMsg::McpHealthCheckResult(_)
         | EventMsg::McpToolCallBegin(_)
         | EventMsg::McpToolCallEnd(_)
         | EventMsg::WebSearchBegin(_)
@@ -6530,7 +8676,9 @@ fn realtime_text_for_event(msg: &EventMsg) -> Option<String> {
         | EventMsg::ViewImageToolCall(_)
         | EventMsg::ImageGenerationBegin(_)
         | EventMsg::ImageGenerationEnd(_)
+        | EventMsg::ProviderToolCall(_)
         | EventMsg::ExecApprovalRequest(_)
+        | EventMsg::ExecApprovalBatchRequest(_)
         | EventMsg::RequestPermissions(_)
         | EventMsg::RequestUserInput(_)
         | EventMsg::DynamicToolCallRequest(_)
@@ -6549,6 +8697,30 @@ fn realtime_text_for_event(msg: &EventMsg) -> Option<String> {
         | EventMsg::ListSkillsResponse(_)
         | EventMsg::ListRemoteSkillsResponse(_)
         | EventMsg::RemoteSkillDownloaded(_)
+        | EventMsg::McpToolCallError(_)
+        | EventMsg::PauseStateChanged(_)
+        | EventMsg::PatchCheckResult(_)
+        | EventMsg::RecentErrors(_)
+        | EventMsg::ConversationSnapshot(_)
+        | EventMsg::ApprovalPolicyChanged(_)
+        | EventMsg::WorkingDirectoryChanged(_)
+        | EventMsg::ExportComplete(_)
+        | EventMsg::AssistantFinished(_)
+        | EventMsg::ToolCallArgumentsPreview(_)
+        | EventMsg::SessionForked(_)
+        | EventMsg::RolloutRotated(_)
+        | EventMsg::RetryConfig(_)
+        | EventMsg::SearchHistoryResults(_)
+        | EventMsg::Features(_)
+        | EventMsg::TokenBudget(_)
+        | EventMsg::SessionStats(_)
+        | EventMsg::PluginList(_)
+        | EventMsg::BlameResult(_)
+        | EventMsg::LastReasoning(_)
+        | EventMsg::PluginValidationResult(_)
+        | EventMsg::BaseInstructionsUpdated(_)
+        | EventMsg::WorkingTreeHash(_)
+        | EventMsg::SessionStateExported(_)
         | EventMsg::SkillsUpdateAvailable
         | EventMsg::PlanUpdate(_)
         | EventMsg::TurnAborted(_)
@@ -6676,6 +8848,15 @@ async fn emit_streamed_assistant_text_delta(
         .await;
 }
 
+fn agent_message_text_is_blank(item: &TurnItem) -> bool {
+    let TurnItem::AgentMessage(message) = item else {
+        return false;
+    };
+    message.content.iter().all(|content| match content {
+        codex_protocol::items::AgentMessageContent::Text { text } => text.trim().is_empty(),
+    })
+}
+
 /// Flush buffered assistant text parser state when an assistant message item ends.
 async fn flush_assistant_text_segments_for_item(
     sess: &Session,
@@ -6869,6 +9050,8 @@ async fn try_run_sampling_request(
     turn_metadata_header: Option<&str>,
     turn_diff_tracker: SharedTurnDiffTracker,
     server_model_warning_emitted_for_turn: &mut bool,
+    tool_call_loop: &mut ToolCallLoopDetector,
+    assistant_output_cap: &mut AssistantOutputCap,
     prompt: &Prompt,
     cancellation_token: CancellationToken,
 ) -> CodexResult<SamplingRequestResult> {
@@ -6905,10 +9088,15 @@ async fn try_run_sampling_request(
     let mut needs_follow_up = false;
     let mut last_agent_message: Option<String> = None;
     let mut active_item: Option<TurnItem> = None;
+    // Id of an agent message whose start is held back because it has no
+    // visible text yet, and the whitespace deltas received for it so far.
+    let mut deferred_agent_message_start: Option<String> = None;
+    let mut deferred_agent_message_whitespace = String::new();
     let mut should_emit_turn_diff = false;
     let plan_mode = turn_context.collaboration_mode.mode == ModeKind::Plan;
     let mut assistant_message_stream_parsers = AssistantMessageStreamParsers::new(plan_mode);
     let mut plan_mode_state = plan_mode.then(|| PlanModeStreamState::new(&turn_context.sub_id));
+    let mut tool_arguments_previews = ToolArgumentsPreviews::default();
     let receiving_span = trace_span!("receiving_stream");
     let outcome: CodexResult<SamplingRequestResult> = loop {
         let handle_responses = trace_span!(
@@ -6946,9 +9134,20 @@ async fn try_run_sampling_request(
 
         match event {
             ResponseEvent::Created => {}
-            ResponseEvent::OutputItemDone(item) => {
-                let previously_active_item = active_item.take();
-                if let Some(previous) = previously_active_item.as_ref()
+            ResponseEvent::OutputItemDone(mut item) => {
+                assistant_output_cap.truncate_item(&mut item);
+                if let Some(message) = assistant_output_cap.take_warning() {
+                    sess.send_event(&turn_context, EventMsg::Warning(WarningEvent { message }))
+                        .await;
+                }
+                let mut previously_active_item = active_item.take();
+                if let Some(item_id) = deferred_agent_message_start.take() {
+                    // Nothing was emitted for this message yet; the completion
+                    // carries its full text and starts it unless it is suppressed.
+                    assistant_message_stream_parsers.finish_item(&item_id);
+                    deferred_agent_message_whitespace.clear();
+                    previously_active_item = None;
+                } else if let Some(previous) = previously_active_item.as_ref()
                     && matches!(previous, TurnItem::AgentMessage(_))
                 {
                     let item_id = previous.id();
@@ -6975,6 +9174,8 @@ async fn try_run_sampling_request(
                     continue;
                 }
 
+                tool_arguments_previews.finish(&item);
+                tool_call_loop.record(&item);
                 let mut ctx = HandleOutputCtx {
                     sess: sess.clone(),
                     turn_context: turn_context.clone(),
@@ -6994,6 +9195,7 @@ async fn try_run_sampling_request(
                 needs_follow_up |= output_result.needs_follow_up;
             }
             ResponseEvent::OutputItemAdded(item) => {
+                tool_arguments_previews.start(&item);
                 if let Some(turn_item) = handle_non_tool_response_item(
                     sess.as_ref(),
                     turn_context.as_ref(),
@@ -7031,6 +9233,12 @@ async fn try_run_sampling_request(
                         state
                             .pending_agent_message_items
                             .insert(item_id, turn_item.clone());
+                    } else if turn_context.config.suppress_whitespace_only_agent_messages
+                        && agent_message_text_is_blank(&turn_item)
+                    {
+                        // Hold the start until non-whitespace text arrives so a
+                        // suppressed message emits neither start nor completion.
+                        deferred_agent_message_start = Some(turn_item.id());
                     } else {
                         sess.emit_turn_item_started(&turn_context, &turn_item).await;
                     }
@@ -7095,11 +9303,31 @@ async fn try_run_sampling_request(
                 });
             }
             ResponseEvent::OutputTextDelta(delta) => {
+                let admitted = assistant_output_cap.admit_delta(delta);
+                if let Some(message) = assistant_output_cap.take_warning() {
+                    sess.send_event(&turn_context, EventMsg::Warning(WarningEvent { message }))
+                        .await;
+                }
+                let Some(delta) = admitted else {
+                    continue;
+                };
                 // In review child threads, suppress assistant text deltas; the
                 // UI will show a selection popup from the final ReviewOutput.
                 if let Some(active) = active_item.as_ref() {
                     let item_id = active.id();
                     if matches!(active, TurnItem::AgentMessage(_)) {
+                        let delta =
+                            if deferred_agent_message_start.as_deref() == Some(item_id.as_str()) {
+                                deferred_agent_message_whitespace.push_str(&delta);
+                                if deferred_agent_message_whitespace.trim().is_empty() {
+                                    continue;
+                                }
+                                deferred_agent_message_start = None;
+                                sess.emit_turn_item_started(&turn_context, active).await;
+                                std::mem::take(&mut deferred_agent_message_whitespace)
+                            } else {
+                                delta
+                            };
                         let parsed = assistant_message_stream_parsers.parse_delta(&item_id, &delta);
                         emit_streamed_assistant_text_delta(
                             &sess,
@@ -7141,6 +9369,14 @@ async fn try_run_sampling_request(
                     error_or_panic("ReasoningSummaryDelta without active item".to_string());
                 }
             }
+            ResponseEvent::ToolCallInputDelta { item_id, delta } => {
+                if let Some(preview) =
+                    tool_arguments_previews.push_delta(&turn_context.sub_id, &item_id, &delta)
+                {
+                    sess.send_event(&turn_context, EventMsg::ToolCallArgumentsPreview(preview))
+                        .await;
+                }
+            }
             ResponseEvent::ReasoningSummaryPartAdded { summary_index } => {
                 if let Some(active) = active_item.as_ref() {
                     let event =
@@ -0,0 +1,123 @@
+//! Collapses bursts of identical `BackgroundEvent`s (e.g. during retry storms)
+//! into a single event carrying a repeat count.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use async_channel::Sender;
+use codex_protocol::protocol::BackgroundEventEvent;
+use codex_protocol::protocol::Event;
+use codex_protocol::protocol::EventMsg;
+use tracing::debug;
+
+/// Holds back the most recent background message for `window` so consecutive
+/// duplicates can be merged. The held message is delivered when the window
+/// elapses without a repeat, or earlier via [`BackgroundEventCoalescer::flush`]
+/// so it never reorders with other events.
+pub(crate) struct BackgroundEventCoalescer {
+    window: Duration,
+    tx_event: Sender<Event>,
+    pending: Arc<Mutex<Option<PendingBackgroundEvent>>>,
+    next_generation: AtomicU64,
+}
+
+struct PendingBackgroundEvent {
+    sub_id: String,
+    message: String,
+    count: u32,
+    /// Identifies the timer allowed to deliver this entry; bumped on every
+    /// repeat so only the most recent timer fires.
+    generation: u64,
+}
+
+impl PendingBackgroundEvent {
+    fn into_event(self) -> Event {
+        let message = if self.count > 1 {
+            format!("{} (x{})", self.message, self.count)
+        } else {
+            self.message
+        };
+        Event {
+            id: self.sub_id,
+            msg: EventMsg::BackgroundEvent(BackgroundEventEvent { message }),
+        }
+    }
+}
+
+impl BackgroundEventCoalescer {
+    pub(crate) fn new(window: Duration, tx_event: Sender<Event>) -> Self {
+        Self {
+            window,
+            tx_event,
+            pending: Arc::new(Mutex::new(None)),
+            next_generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Queues `message`, merging it into the held event when it repeats the
+    /// previous message for the same submission.
+    pub(crate) async fn push(&self, sub_id: String, message: String) {
+        let generation = self.next_generation.fetch_add(1, Ordering::Relaxed);
+        let displaced = {
+            let mut pending = lock(&self.pending);
+            match pending.as_mut() {
+                Some(held) if held.sub_id == sub_id && held.message == message => {
+                    held.count += 1;
+                    held.generation = generation;
+                    None
+                }
+                _ => pending.replace(PendingBackgroundEvent {
+                    sub_id,
+                    message,
+                    count: 1,
+                    generation,
+                }),
+            }
+        };
+        if let Some(displaced) = displaced {
+            send(&self.tx_event, displaced.into_event()).await;
+        }
+
+        let pending = Arc::clone(&self.pending);
+        let tx_event = self.tx_event.clone();
+        let window = self.window;
+        tokio::spawn(async move {
+            tokio::time::sleep(window).await;
+            let expired = {
+                let mut pending = lock(&pending);
+                match pending.as_ref() {
+                    Some(held) if held.generation == generation => pending.take(),
+                    _ => None,
+                }
+            };
+            if let Some(expired) = expired {
+                send(&tx_event, expired.into_event()).await;
+            }
+        });
+    }
+
+    /// Delivers the held event, if any, immediately.
+    pub(crate) async fn flush(&self) {
+        let held = lock(&self.pending).take();
+        if let Some(held) = held {
+            send(&self.tx_event, held.into_event()).await;
+        }
+    }
+}
+
+fn lock(
+    pending: &Mutex<Option<PendingBackgroundEvent>>,
+) -> std::sync::MutexGuard<'_, Option<PendingBackgroundEvent>> {
+    pending
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+async fn send(tx_event: &Sender<Event>, event: Event) {
+    if let Err(e) = tx_event.send(event).await {
+        debug!("dropping event because channel is closed: {e}");
+    }
+}
@@ -0,0 +1,58 @@
+use super::background_event_coalescer::BackgroundEventCoalescer;
+use codex_protocol::protocol::EventMsg;
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+fn background_message(msg: EventMsg) -> String {
+    let EventMsg::BackgroundEvent(event) = msg else {
+        panic!("expected BackgroundEvent, got {msg:?}");
+    };
+    event.message
+}
+
+#[tokio::test]
+async fn consecutive_identical_messages_coalesce_with_repeat_count() {
+    let (tx_event, rx_event) = async_channel::unbounded();
+    let coalescer = BackgroundEventCoalescer::new(Duration::from_millis(50), tx_event);
+
+    for _ in 0..3 {
+        coalescer
+            .push("sub-1".to_string(), "Reconnecting...".to_string())
+            .await;
+    }
+
+    let event = tokio::time::timeout(Duration::from_secs(2), rx_event.recv())
+        .await
+        .expect("timeout waiting for coalesced event")
+        .expect("event");
+    assert_eq!(event.id, "sub-1");
+    assert_eq!(background_message(event.msg), "Reconnecting... (x3)");
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(rx_event.is_empty(), "expected a single coalesced event");
+}
+
+#[tokio::test]
+async fn different_message_releases_held_event_in_order() {
+    let (tx_event, rx_event) = async_channel::unbounded();
+    let coalescer = BackgroundEventCoalescer::new(Duration::from_secs(60), tx_event);
+
+    coalescer
+        .push("sub-1".to_string(), "first".to_string())
+        .await;
+    coalescer
+        .push("sub-1".to_string(), "first".to_string())
+        .await;
+    coalescer
+        .push("sub-1".to_string(), "second".to_string())
+        .await;
+    coalescer.flush().await;
+
+    let messages: Vec<String> = std::iter::from_fn(|| rx_event.try_recv().ok())
+        .map(|event| background_message(event.msg))
+        .collect();
+    assert_eq!(
+        messages,
+        vec!["first (x2)".to_string(), "second".to_string()]
+    );
+}
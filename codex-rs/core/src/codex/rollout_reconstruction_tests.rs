@@ -124,6 +124,7 @@ async fn record_initial_history_resumed_hydrates_previous_turn_settings_from_lif
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
     ];
@@ -188,6 +189,7 @@ async fn reconstruct_history_rollback_keeps_history_and_metadata_in_sync_for_com
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: first_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -212,6 +214,7 @@ async fn reconstruct_history_rollback_keeps_history_and_metadata_in_sync_for_com
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: rolled_back_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -278,6 +281,7 @@ async fn reconstruct_history_rollback_keeps_history_and_metadata_in_sync_for_inc
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: first_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -363,6 +367,7 @@ async fn reconstruct_history_rollback_skips_non_user_turns_for_history_and_metad
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: first_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -386,6 +391,7 @@ async fn reconstruct_history_rollback_skips_non_user_turns_for_history_and_metad
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: second_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -400,6 +406,7 @@ async fn reconstruct_history_rollback_skips_non_user_turns_for_history_and_metad
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: standalone_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -461,6 +468,7 @@ async fn reconstruct_history_rollback_clears_history_and_metadata_when_exceeding
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: only_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -507,6 +515,7 @@ async fn record_initial_history_resumed_rollback_skips_only_user_turns() {
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: user_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         // Standalone task turn (no UserMessage) should not consume rollback skips.
@@ -521,6 +530,7 @@ async fn record_initial_history_resumed_rollback_skips_only_user_turns() {
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: standalone_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -571,6 +581,7 @@ async fn record_initial_history_resumed_rollback_drops_incomplete_user_turn_comp
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: previous_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -723,6 +734,7 @@ async fn reconstruct_history_legacy_compaction_without_replacement_history_clear
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: current_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
     ];
@@ -789,6 +801,7 @@ async fn record_initial_history_resumed_turn_context_after_compaction_reestablis
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: previous_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
     ];
@@ -887,6 +900,7 @@ async fn record_initial_history_resumed_aborted_turn_without_id_clears_active_tu
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: previous_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -988,6 +1002,7 @@ async fn record_initial_history_resumed_unmatched_abort_preserves_active_turn_fo
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: previous_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -1016,6 +1031,7 @@ async fn record_initial_history_resumed_unmatched_abort_preserves_active_turn_fo
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: current_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
     ];
@@ -1095,6 +1111,7 @@ async fn record_initial_history_resumed_trailing_incomplete_turn_compaction_clea
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: previous_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -1240,6 +1257,7 @@ async fn record_initial_history_resumed_replaced_incomplete_compacted_turn_clear
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id: previous_turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -0,0 +1,61 @@
+use super::*;
+use codex_protocol::protocol::SessionStateSnapshot;
+
+impl Session {
+    /// Captures the in-memory state described by [`SessionStateSnapshot`].
+    pub(crate) async fn export_state_snapshot(&self) -> SessionStateSnapshot {
+        let (thread_name, history, reference_context_item, granted_permissions) = {
+            let state = self.state.lock().await;
+            (
+                state.session_configuration.thread_name.clone(),
+                state.clone_history().raw_items().to_vec(),
+                state.reference_context_item(),
+                state.granted_permissions(),
+            )
+        };
+        let approvals = self.services.tool_approvals.lock().await.entries();
+
+        SessionStateSnapshot {
+            thread_id: self.conversation_id,
+            thread_name,
+            history,
+            reference_context_item,
+            approvals,
+            granted_permissions,
+        }
+    }
+
+    /// Rebuilds state from a snapshot produced by [`Session::export_state_snapshot`].
+    ///
+    /// The restored history is also written to this session's rollout so the
+    /// new thread can later be resumed like any other.
+    pub(crate) async fn restore_state_snapshot(&self, snapshot: SessionStateSnapshot) {
+        let SessionStateSnapshot {
+            thread_id: _,
+            thread_name,
+            history,
+            reference_context_item,
+            approvals,
+            granted_permissions,
+        } = snapshot;
+
+        let rollout_items = history
+            .iter()
+            .cloned()
+            .map(RolloutItem::ResponseItem)
+            .collect::<Vec<_>>();
+        self.persist_rollout_items(&rollout_items).await;
+        self.replace_history(history, reference_context_item).await;
+        {
+            let mut state = self.state.lock().await;
+            state.session_configuration.thread_name = thread_name;
+            if let Some(granted_permissions) = granted_permissions {
+                state.record_granted_permissions(granted_permissions);
+            }
+        }
+        self.services.tool_approvals.lock().await.extend(approvals);
+
+        let turn_context = self.new_default_turn().await;
+        self.recompute_token_usage(turn_context.as_ref()).await;
+    }
+}
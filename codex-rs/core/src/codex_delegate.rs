@@ -103,6 +103,7 @@ pub(crate) async fn run_codex_thread_interactive(
     Ok(Codex {
         tx_sub: tx_ops,
         rx_event: rx_sub,
+        rx_exec_output_event: codex.exec_output_events(),
         agent_status: codex.agent_status.clone(),
         session: Arc::clone(&codex.session),
     })
@@ -143,6 +144,8 @@ pub(crate) async fn run_codex_thread_one_shot(
     io.submit(Op::UserInput {
         items: input,
         final_output_json_schema,
+        effort: None,
+        tool_choice: None,
     })
     .await?;
 
@@ -151,6 +154,7 @@ pub(crate) async fn run_codex_thread_one_shot(
     let ops_tx = io.tx_sub.clone();
     let agent_status = io.agent_status.clone();
     let session = Arc::clone(&io.session);
+    let rx_exec_output_event = io.exec_output_events();
     let io_for_bridge = io;
     tokio::spawn(async move {
         while let Ok(event) = io_for_bridge.next_event().await {
@@ -181,6 +185,7 @@ pub(crate) async fn run_codex_thread_one_shot(
 
     Ok(Codex {
         rx_event: rx_bridge,
+        rx_exec_output_event,
         tx_sub: tx_closed,
         agent_status,
         session,
@@ -351,6 +356,7 @@ async fn handle_exec_approval(
         additional_permissions,
         skill_metadata,
         available_decisions,
+        stdin,
         ..
     } = event;
     // Race approval with cancellation and timeout to avoid hangs.
@@ -360,6 +366,7 @@ async fn handle_exec_approval(
         approval_id,
         command,
         cwd,
+        stdin,
         reason,
         network_approval_context,
         proposed_execpolicy_amendment,
@@ -570,6 +577,7 @@ mod tests {
         let codex = Arc::new(Codex {
             tx_sub,
             rx_event: rx_events,
+            rx_exec_output_event: None,
             agent_status,
             session: Arc::clone(&session),
         });
@@ -643,6 +651,7 @@ mod tests {
         let codex = Arc::new(Codex {
             tx_sub,
             rx_event: rx_events,
+            rx_exec_output_event: None,
             agent_status,
             session,
         });
@@ -689,6 +698,7 @@ mod tests {
         let codex = Arc::new(Codex {
             tx_sub,
             rx_event: rx_events_child,
+            rx_exec_output_event: None,
             agent_status,
             session: Arc::clone(&parent_session),
         });
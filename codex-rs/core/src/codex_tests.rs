@@ -2,6 +2,7 @@ use super::*;
 use crate::CodexAuth;
 use crate::config::ConfigBuilder;
 use crate::config::test_config;
+use crate::config::types::PathOutsideRootsPolicy;
 use crate::config_loader::ConfigLayerStack;
 use crate::config_loader::ConfigLayerStackOrdering;
 use crate::config_loader::NetworkConstraints;
@@ -62,6 +63,7 @@ use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::openai_models::ModelsResponse;
 use codex_protocol::protocol::ConversationAudioParams;
+use codex_protocol::protocol::ExportFormat;
 use codex_protocol::protocol::RealtimeAudioFrame;
 use codex_protocol::protocol::Submission;
 use codex_protocol::protocol::W3cTraceContext;
@@ -1048,6 +1050,7 @@ async fn record_initial_history_forked_hydrates_previous_turn_settings() {
             codex_protocol::protocol::TurnCompleteEvent {
                 turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             },
         )),
     ];
@@ -1222,6 +1225,7 @@ async fn thread_rollback_recomputes_previous_turn_settings_and_reference_context
         RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: first_turn_id,
             last_agent_message: None,
+            cost_usd: None,
         })),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
             codex_protocol::protocol::TurnStartedEvent {
@@ -1244,6 +1248,7 @@ async fn thread_rollback_recomputes_previous_turn_settings_and_reference_context
         RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: rolled_back_turn_id,
             last_agent_message: None,
+            cost_usd: None,
         })),
     ])
     .await;
@@ -1307,6 +1312,7 @@ async fn thread_rollback_persists_marker_and_replays_cumulatively() {
         RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         })),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
             codex_protocol::protocol::TurnStartedEvent {
@@ -1327,6 +1333,7 @@ async fn thread_rollback_persists_marker_and_replays_cumulatively() {
         RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-2".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         })),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
             codex_protocol::protocol::TurnStartedEvent {
@@ -1347,6 +1354,7 @@ async fn thread_rollback_persists_marker_and_replays_cumulatively() {
         RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-3".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         })),
     ])
     .await;
@@ -1422,6 +1430,294 @@ async fn thread_rollback_fails_when_num_turns_is_zero() {
     assert_eq!(initial_context, history.raw_items());
 }
 
+#[tokio::test]
+async fn export_state_restores_history_and_approvals_into_new_session() {
+    let (sess, tc, rx) = make_session_and_context_with_rx().await;
+
+    let mut history = sess.build_initial_context(tc.as_ref()).await;
+    history.extend(vec![
+        user_message("checkpoint me"),
+        assistant_message("checkpointed"),
+    ]);
+    sess.replace_history(history.clone(), Some(tc.to_turn_context_item()))
+        .await;
+    let approval_key = vec!["git".to_string(), "status".to_string()];
+    sess.services
+        .tool_approvals
+        .lock()
+        .await
+        .put(approval_key.clone(), ReviewDecision::ApprovedForSession);
+
+    handlers::export_state(&sess, "sub-1".to_string()).await;
+    let snapshot = loop {
+        let evt = tokio::time::timeout(StdDuration::from_secs(2), rx.recv())
+            .await
+            .expect("timeout waiting for event")
+            .expect("event");
+        if let EventMsg::SessionStateExported(event) = evt.msg {
+            break event.snapshot;
+        }
+    };
+    assert_eq!(snapshot.thread_id, sess.conversation_id);
+
+    // Round-trip through JSON, as a client persisting the checkpoint would.
+    let serialized = serde_json::to_string(&snapshot).expect("serialize snapshot");
+    let snapshot = serde_json::from_str(&serialized).expect("deserialize snapshot");
+
+    let (restored, _restored_tc) = make_session_and_context().await;
+    restored.restore_state_snapshot(snapshot).await;
+
+    assert_eq!(history, restored.clone_history().await.raw_items());
+    let exported_approvals = sess.services.tool_approvals.lock().await.entries();
+    let restored_approvals = restored.services.tool_approvals.lock().await.entries();
+    assert_eq!(exported_approvals, restored_approvals);
+    assert_eq!(
+        restored
+            .services
+            .tool_approvals
+            .lock()
+            .await
+            .get(&approval_key),
+        Some(ReviewDecision::ApprovedForSession)
+    );
+}
+
+#[tokio::test]
+async fn export_state_fails_when_turn_in_progress() {
+    let (sess, _tc, rx) = make_session_and_context_with_rx().await;
+
+    *sess.active_turn.lock().await = Some(crate::state::ActiveTurn::default());
+    handlers::export_state(&sess, "sub-1".to_string()).await;
+
+    let evt = tokio::time::timeout(StdDuration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::Error(error_event) = evt.msg else {
+        panic!("expected error event, got {:?}", evt.msg);
+    };
+    assert_eq!(
+        error_event.codex_error_info,
+        Some(CodexErrorInfo::BadRequest)
+    );
+}
+
+#[tokio::test]
+async fn conversation_snapshot_returns_history_items() {
+    let (sess, tc, rx) = make_session_and_context_with_rx().await;
+
+    let history = vec![
+        user_message("snapshot me"),
+        assistant_message("snapshotted"),
+    ];
+    sess.replace_history(history.clone(), Some(tc.to_turn_context_item()))
+        .await;
+
+    handlers::get_conversation_snapshot(&sess, "sub-1".to_string()).await;
+    let evt = tokio::time::timeout(StdDuration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::ConversationSnapshot(event) = evt.msg else {
+        panic!("expected conversation snapshot, got {:?}", evt.msg);
+    };
+    assert_eq!(evt.id, "sub-1");
+    assert_eq!(history, event.items);
+}
+
+#[tokio::test]
+async fn set_approval_policy_updates_next_turn_and_acknowledges() {
+    let (sess, tc, rx) = make_session_and_context_with_rx().await;
+    let policy = if tc.approval_policy.value() == AskForApproval::Never {
+        AskForApproval::OnRequest
+    } else {
+        AskForApproval::Never
+    };
+
+    handlers::set_approval_policy(&sess, "sub-1".to_string(), policy).await;
+    let evt = tokio::time::timeout(StdDuration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::ApprovalPolicyChanged(event) = evt.msg else {
+        panic!("expected approval policy change, got {:?}", evt.msg);
+    };
+    assert_eq!(evt.id, "sub-1");
+    assert_eq!(event.approval_policy, policy);
+
+    let next_turn = sess.new_default_turn().await;
+    assert_eq!(next_turn.approval_policy.value(), policy);
+}
+
+#[tokio::test]
+async fn change_working_directory_moves_next_turn_and_rejects_relative_paths() {
+    let (sess, _tc, rx) = make_session_and_context_with_rx().await;
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let new_cwd = dir.path().to_path_buf();
+
+    handlers::change_working_directory(&sess, "sub-1".to_string(), new_cwd.clone()).await;
+    let evt = tokio::time::timeout(StdDuration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::WorkingDirectoryChanged(event) = evt.msg else {
+        panic!("expected working directory change, got {:?}", evt.msg);
+    };
+    assert_eq!(evt.id, "sub-1");
+    assert_eq!(event.new_cwd, new_cwd);
+
+    let next_turn = sess.new_default_turn().await;
+    assert_eq!(next_turn.cwd, new_cwd);
+    assert_eq!(
+        next_turn.resolve_path(Some("src".to_string())),
+        new_cwd.join("src")
+    );
+
+    handlers::change_working_directory(&sess, "sub-2".to_string(), PathBuf::from("relative")).await;
+    let evt = tokio::time::timeout(StdDuration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::Error(error) = evt.msg else {
+        panic!("expected error, got {:?}", evt.msg);
+    };
+    assert_eq!(
+        error.message,
+        "working directory must be an absolute path: relative"
+    );
+    assert_eq!(sess.new_default_turn().await.cwd, new_cwd);
+}
+
+#[tokio::test]
+async fn export_conversation_writes_markdown_and_reports_path() {
+    let (sess, tc, rx) = make_session_and_context_with_rx().await;
+    sess.replace_history(
+        vec![user_message("export me"), assistant_message("exported")],
+        Some(tc.to_turn_context_item()),
+    )
+    .await;
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("conversation.md");
+
+    handlers::export_conversation(
+        &sess,
+        "sub-1".to_string(),
+        path.clone(),
+        ExportFormat::Markdown,
+    )
+    .await;
+    let evt = tokio::time::timeout(StdDuration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::ExportComplete(event) = evt.msg else {
+        panic!("expected export complete, got {:?}", evt.msg);
+    };
+    assert_eq!(evt.id, "sub-1");
+    assert_eq!(event.path, path);
+    assert_eq!(
+        std::fs::read_to_string(&path).expect("read export"),
+        "### User\n\nexport me\n\n### Assistant\n\nexported\n"
+    );
+}
+
+#[tokio::test]
+async fn export_conversation_reports_write_failure() {
+    let (sess, _tc, rx) = make_session_and_context_with_rx().await;
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let path = dir.path().join("missing").join("conversation.json");
+
+    handlers::export_conversation(&sess, "sub-1".to_string(), path, ExportFormat::Json).await;
+    let evt = tokio::time::timeout(StdDuration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::Error(error_event) = evt.msg else {
+        panic!("expected error event, got {:?}", evt.msg);
+    };
+    assert!(
+        error_event
+            .message
+            .starts_with("Failed to export conversation to "),
+        "{}",
+        error_event.message
+    );
+}
+
+#[tokio::test]
+async fn resolve_tool_path_applies_path_outside_roots_policy() {
+    let (_session, mut turn_context) = make_session_and_context().await;
+    let workspace = tempfile::tempdir().expect("create temp dir");
+    let cwd = workspace.path().join("project");
+    std::fs::create_dir(&cwd).expect("create cwd");
+    turn_context.cwd = cwd.clone();
+    turn_context.sandbox_policy =
+        codex_config::Constrained::allow_any(SandboxPolicy::new_read_only_policy());
+
+    assert_eq!(
+        turn_context.resolve_tool_path(Some("src/../lib.rs".to_string())),
+        Ok(cwd.join("src/../lib.rs"))
+    );
+
+    let traversal = "missing/../../outside.txt".to_string();
+    let absolute = workspace
+        .path()
+        .join("elsewhere")
+        .to_string_lossy()
+        .into_owned();
+    for policy in [
+        PathOutsideRootsPolicy::Allow,
+        PathOutsideRootsPolicy::Warn,
+        PathOutsideRootsPolicy::Reject,
+    ] {
+        let mut config = (*turn_context.config).clone();
+        config.path_outside_roots = policy;
+        turn_context.config = Arc::new(config);
+
+        for path in [&traversal, &absolute] {
+            let result = turn_context.resolve_tool_path(Some(path.clone()));
+            if policy == PathOutsideRootsPolicy::Reject {
+                assert!(
+                    matches!(result, Err(FunctionCallError::RespondToModel(ref message))
+                        if message.contains("outside the allowed roots")),
+                    "{policy:?} should reject {path}: {result:?}"
+                );
+            } else {
+                assert_eq!(result, Ok(cwd.join(path)), "{policy:?} should allow {path}");
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn session_metrics_count_errors_and_token_usage() {
+    let (sess, tc, _rx) = make_session_and_context_with_rx().await;
+
+    sess.send_event_raw(Event {
+        id: "sub-1".to_string(),
+        msg: EventMsg::Error(ErrorEvent {
+            message: "boom".to_string(),
+            codex_error_info: None,
+        }),
+    })
+    .await;
+    sess.update_token_usage_info(
+        tc.as_ref(),
+        Some(&TokenUsage {
+            input_tokens: 100,
+            output_tokens: 25,
+            ..Default::default()
+        }),
+    )
+    .await;
+
+    let metrics = &sess.services.metrics;
+    assert_eq!(metrics.errors_total(), 1);
+    assert_eq!(metrics.tokens_input_total(), 100);
+    assert_eq!(metrics.tokens_output_total(), 25);
+    assert_eq!(metrics.turns_total(), 0);
+}
+
 #[tokio::test]
 async fn set_rate_limits_retains_previous_credits() {
     let codex_home = tempfile::tempdir().expect("create temp dir");
@@ -2087,6 +2383,7 @@ async fn session_new_fails_when_zsh_fork_enabled_without_zsh_path() {
         models_manager,
         ExecPolicyManager::default(),
         tx_event,
+        None,
         agent_status_tx,
         InitialHistory::New,
         SessionSource::Exec,
@@ -2234,8 +2531,10 @@ pub(crate) async fn make_session_and_context() -> (Session, TurnContext) {
             config.features.enabled(Feature::EnableRequestCompression),
             config.features.enabled(Feature::RuntimeMetrics),
             Session::build_model_client_beta_features_header(config.as_ref()),
+            None,
         ),
         code_mode_store: Default::default(),
+        metrics: Arc::new(Metrics::default()),
     };
     let js_repl = Arc::new(JsReplHandle::with_node_path(
         config.js_repl_node_path.clone(),
@@ -2259,6 +2558,8 @@ pub(crate) async fn make_session_and_context() -> (Session, TurnContext) {
     let session = Session {
         conversation_id,
         tx_event,
+        tx_exec_output_event: None,
+        background_event_coalescer: None,
         agent_status: agent_status_tx,
         out_of_band_elicitation_paused: watch::channel(false).0,
         state: Mutex::new(state),
@@ -2423,6 +2724,47 @@ async fn request_permissions_returns_empty_grant_when_reject_policy_blocks_reque
     );
 }
 
+#[tokio::test]
+async fn next_event_timeout_returns_none_then_event() {
+    let (session, _turn_context) = make_session_and_context().await;
+    let (tx_sub, _rx_sub) = async_channel::bounded(1);
+    let (tx_event, rx_event) = async_channel::unbounded();
+    let (_agent_status_tx, agent_status) = watch::channel(AgentStatus::PendingInit);
+    let codex = Codex {
+        tx_sub,
+        rx_event,
+        rx_exec_output_event: None,
+        agent_status,
+        session: Arc::new(session),
+    };
+
+    let idle = codex
+        .next_event_timeout(StdDuration::from_millis(10))
+        .await
+        .expect("channel should be open");
+    assert!(idle.is_none());
+
+    tx_event
+        .send(Event {
+            id: "sub-1".into(),
+            msg: EventMsg::ShutdownComplete,
+        })
+        .await
+        .expect("send event");
+    let event = codex
+        .next_event_timeout(StdDuration::from_secs(1))
+        .await
+        .expect("channel should be open")
+        .expect("event should arrive before the deadline");
+    assert_eq!(event.id, "sub-1");
+
+    drop(tx_event);
+    assert!(matches!(
+        codex.next_event_timeout(StdDuration::from_secs(1)).await,
+        Err(CodexErr::InternalAgentDied)
+    ));
+}
+
 #[tokio::test]
 async fn submit_with_id_captures_current_span_trace_context() {
     let (session, _turn_context) = make_session_and_context().await;
@@ -2432,6 +2774,7 @@ async fn submit_with_id_captures_current_span_trace_context() {
     let codex = Codex {
         tx_sub,
         rx_event,
+        rx_exec_output_event: None,
         agent_status,
         session: Arc::new(session),
     };
@@ -2794,8 +3137,10 @@ pub(crate) async fn make_session_and_context_with_dynamic_tools_and_rx(
             config.features.enabled(Feature::EnableRequestCompression),
             config.features.enabled(Feature::RuntimeMetrics),
             Session::build_model_client_beta_features_header(config.as_ref()),
+            None,
         ),
         code_mode_store: Default::default(),
+        metrics: Arc::new(Metrics::default()),
     };
     let js_repl = Arc::new(JsReplHandle::with_node_path(
         config.js_repl_node_path.clone(),
@@ -2819,6 +3164,8 @@ pub(crate) async fn make_session_and_context_with_dynamic_tools_and_rx(
     let session = Arc::new(Session {
         conversation_id,
         tx_event,
+        tx_exec_output_event: None,
+        background_event_coalescer: None,
         agent_status: agent_status_tx,
         out_of_band_elicitation_paused: watch::channel(false).0,
         state: Mutex::new(state),
@@ -3772,6 +4119,7 @@ async fn task_finish_emits_turn_item_lifecycle_for_leftover_pending_user_input()
         EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id,
             last_agent_message: None,
+            ..
         }) if turn_id == tc.sub_id
     ));
 }
@@ -4197,6 +4545,7 @@ async fn rejects_escalated_permissions_when_policy_not_on_request() {
         windows_sandbox_level: turn_context.windows_sandbox_level,
         justification: Some("test".to_string()),
         arg0: None,
+        stdin: None,
     };
 
     let params2 = ExecParams {
@@ -4209,6 +4558,7 @@ async fn rejects_escalated_permissions_when_policy_not_on_request() {
         windows_sandbox_level: turn_context.windows_sandbox_level,
         justification: params.justification.clone(),
         arg0: None,
+        stdin: None,
     };
 
     let turn_diff_tracker = Arc::new(tokio::sync::Mutex::new(TurnDiffTracker::new()));
@@ -4344,3 +4694,137 @@ async fn unified_exec_rejects_escalated_permissions_when_policy_not_on_request()
 
     pretty_assertions::assert_eq!(output, expected);
 }
+
+#[tokio::test]
+async fn shadowed_dynamic_tool_warns_once_per_session() {
+    let (session, turn_context, rx) =
+        make_session_and_context_with_dynamic_tools_and_rx(vec![DynamicToolSpec {
+            name: "update_plan".to_string(),
+            description: "Impostor plan tool".to_string(),
+            input_schema: serde_json::json!({ "type": "object" }),
+        }])
+        .await;
+    let explicitly_enabled_connectors = HashSet::new();
+    let cancellation_token = CancellationToken::new();
+
+    for _ in 0..2 {
+        let router = built_tools(
+            &session,
+            &turn_context,
+            &[],
+            &explicitly_enabled_connectors,
+            None,
+            &cancellation_token,
+        )
+        .await
+        .expect("tools should build");
+        assert_eq!(router.shadowed_tools(), ["update_plan".to_string()]);
+    }
+
+    let mut warnings = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        if let EventMsg::Warning(WarningEvent { message }) = event.msg {
+            warnings.push(message);
+        }
+    }
+    assert_eq!(
+        warnings,
+        vec![
+            "Tool `update_plan` is ignored because another tool already uses that name; calls to `update_plan` go to the existing tool."
+                .to_string()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn user_input_rejects_unsupported_effort_before_starting_turn() {
+    let (sess, _tc, rx) = make_session_and_context_with_rx().await;
+    // The mini models only support medium and high effort.
+    let model = "gpt-5.1-codex-mini";
+    let effort = ReasoningEffortConfig::Low;
+    {
+        let mut state = sess.state.lock().await;
+        let collaboration_mode = &mut state.session_configuration.collaboration_mode;
+        *collaboration_mode = collaboration_mode.with_updates(Some(model.to_string()), None, None);
+    }
+
+    handlers::user_input_or_turn(
+        &sess,
+        "sub-1".to_string(),
+        Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "hello".to_string(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: Some(effort),
+            tool_choice: None,
+        },
+    )
+    .await;
+
+    let event = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::Error(error) = event.msg else {
+        panic!("expected error event, got {:?}", event.msg);
+    };
+    assert_eq!(
+        error.message,
+        format!("Reasoning effort {effort} is not supported by model {model}.")
+    );
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::BadRequest));
+    assert!(sess.active_turn.lock().await.is_none());
+}
+
+#[tokio::test]
+async fn explain_last_command_is_rejected_during_an_active_turn() {
+    let (sess, _tc, rx) = make_session_and_context_with_rx().await;
+    *sess.active_turn.lock().await = Some(crate::state::ActiveTurn::default());
+
+    handlers::explain_last_command(&sess, "sub-1".to_string()).await;
+
+    let event = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::Error(error) = event.msg else {
+        panic!("expected error event, got {:?}", event.msg);
+    };
+    assert_eq!(
+        error.message,
+        "Cannot explain a command while a turn is in progress."
+    );
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::BadRequest));
+}
+
+#[tokio::test]
+async fn reapprove_last_denied_is_rejected_during_an_active_turn() {
+    let (sess, tc, rx) = make_session_and_context_with_rx().await;
+    let denied = DeniedCommand {
+        command: vec!["echo".to_string(), "hi".to_string()],
+        cwd: tc.cwd.clone(),
+        sandbox_permissions: crate::sandboxing::SandboxPermissions::UseDefault,
+        additional_permissions: None,
+        justification: None,
+    };
+    sess.record_denied_command(denied.clone()).await;
+    *sess.active_turn.lock().await = Some(crate::state::ActiveTurn::default());
+
+    handlers::reapprove_last_denied(&sess, "sub-1".to_string()).await;
+
+    let event = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+        .await
+        .expect("timeout waiting for event")
+        .expect("event");
+    let EventMsg::Error(error) = event.msg else {
+        panic!("expected error event, got {:?}", event.msg);
+    };
+    assert_eq!(
+        error.message,
+        "Cannot re-approve a command while a turn is in progress."
+    );
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::BadRequest));
+    assert_eq!(sess.state.lock().await.last_denied_command(), Some(denied));
+}
@@ -127,6 +127,7 @@ async fn guardian_allows_shell_additional_permissions_requests_past_policy_valid
         windows_sandbox_level: turn_context.windows_sandbox_level,
         justification: Some("test".to_string()),
         arg0: None,
+        stdin: None,
     };
 
     let handler = ShellHandler;
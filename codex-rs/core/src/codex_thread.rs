@@ -9,6 +9,7 @@ use crate::file_watcher::WatchRegistration;
 use crate::protocol::Event;
 use crate::protocol::Op;
 use crate::protocol::Submission;
+use async_channel::Receiver;
 use codex_protocol::config_types::Personality;
 use codex_protocol::config_types::ServiceTier;
 use codex_protocol::models::ContentItem;
@@ -21,6 +22,7 @@ use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::TokenUsage;
 use codex_protocol::user_input::UserInput;
 use std::path::PathBuf;
+use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio::sync::watch;
 
@@ -93,6 +95,17 @@ impl CodexThread {
         self.codex.next_event().await
     }
 
+    pub async fn next_event_timeout(&self, timeout: Duration) -> CodexResult<Option<Event>> {
+        self.codex.next_event_timeout(timeout).await
+    }
+
+    /// Receiver for exec output deltas when `separate_exec_output_channel` is
+    /// enabled for a TUI session; those events are then no longer delivered
+    /// via `next_event`.
+    pub fn exec_output_events(&self) -> Option<Receiver<Event>> {
+        self.codex.exec_output_events()
+    }
+
     pub async fn agent_status(&self) -> AgentStatus {
         self.codex.agent_status().await
     }
@@ -99,6 +99,11 @@ async fn run_compact_task_inner(
     let initial_input_for_turn: ResponseInputItem = ResponseInputItem::from(input);
 
     let mut history = sess.clone_history().await;
+    let pending_tool_call = if turn_context.config.compact_preserve_pending_tool_call {
+        pending_tool_call_items(history.raw_items())
+    } else {
+        Vec::new()
+    };
     history.record_items(
         &[initial_input_for_turn.into()],
         turn_context.truncation_policy,
@@ -195,6 +200,9 @@ async fn run_compact_task_inner(
     let user_messages = collect_user_messages(history_items);
 
     let mut new_history = build_compacted_history(Vec::new(), &user_messages, &summary_text);
+    // Keep the summary last; the retained call goes right before it.
+    let summary_index = new_history.len() - 1;
+    new_history.splice(summary_index..summary_index, pending_tool_call);
 
     if matches!(
         initial_context_injection,
@@ -266,6 +274,51 @@ pub(crate) fn collect_user_messages(items: &[ResponseItem]) -> Vec<String> {
         .collect()
 }
 
+/// Returns the most recent tool call the model has not followed up on with an
+/// assistant message yet, plus its output when one has been recorded. Empty
+/// when every call has been answered.
+fn pending_tool_call_items(items: &[ResponseItem]) -> Vec<ResponseItem> {
+    let Some((index, call_id)) = items.iter().enumerate().rev().find_map(|(index, item)| {
+        let call_id = match item {
+            ResponseItem::FunctionCall { call_id, .. }
+            | ResponseItem::CustomToolCall { call_id, .. } => Some(call_id.as_str()),
+            ResponseItem::LocalShellCall { call_id, id, .. } => {
+                call_id.as_deref().or(id.as_deref())
+            }
+            _ => return None,
+        };
+        Some((index, call_id))
+    }) else {
+        return Vec::new();
+    };
+    let later_items = &items[index + 1..];
+    if later_items
+        .iter()
+        .any(|item| matches!(item, ResponseItem::Message { role, .. } if role == "assistant"))
+    {
+        return Vec::new();
+    }
+
+    let mut retained = vec![items[index].clone()];
+    retained.extend(
+        later_items
+            .iter()
+            .filter(|item| match item {
+                ResponseItem::FunctionCallOutput {
+                    call_id: output_call_id,
+                    ..
+                }
+                | ResponseItem::CustomToolCallOutput {
+                    call_id: output_call_id,
+                    ..
+                } => call_id == Some(output_call_id.as_str()),
+                _ => false,
+            })
+            .cloned(),
+    );
+    retained
+}
+
 pub(crate) fn is_summary_message(message: &str) -> bool {
     message.starts_with(format!("{SUMMARY_PREFIX}\n").as_str())
 }
@@ -441,6 +494,7 @@ async fn drain_to_completed(
 mod tests {
 
     use super::*;
+    use codex_protocol::models::FunctionCallOutputPayload;
     use pretty_assertions::assert_eq;
 
     async fn process_compacted_history_with_test_session(
@@ -492,6 +546,64 @@ mod tests {
         assert_eq!(None, joined);
     }
 
+    #[test]
+    fn pending_tool_call_items_keeps_unanswered_call_and_output() {
+        let call = ResponseItem::FunctionCall {
+            id: None,
+            name: "shell".to_string(),
+            arguments: "{}".to_string(),
+            call_id: "call-2".to_string(),
+        };
+        let output = ResponseItem::FunctionCallOutput {
+            call_id: "call-2".to_string(),
+            output: FunctionCallOutputPayload::from_text("ok".to_string()),
+        };
+        let earlier_output = ResponseItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text("earlier".to_string()),
+        };
+        let items = vec![
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: "{}".to_string(),
+                call_id: "call-1".to_string(),
+            },
+            call.clone(),
+            earlier_output,
+            output.clone(),
+        ];
+
+        assert_eq!(pending_tool_call_items(&items), vec![call, output]);
+    }
+
+    #[test]
+    fn pending_tool_call_items_skips_answered_calls() {
+        let items = vec![
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: "{}".to_string(),
+                call_id: "call-1".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload::from_text("ok".to_string()),
+            },
+            ResponseItem::Message {
+                id: None,
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText {
+                    text: "done".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            },
+        ];
+
+        assert_eq!(pending_tool_call_items(&items), Vec::<ResponseItem>::new());
+    }
+
     #[test]
     fn collect_user_messages_extracts_user_text_only() {
         let items = vec![
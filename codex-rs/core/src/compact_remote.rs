@@ -16,6 +16,7 @@ use crate::error::Result as CodexResult;
 use crate::protocol::CompactedItem;
 use crate::protocol::EventMsg;
 use crate::protocol::TurnStartedEvent;
+use codex_protocol::config_types::ToolChoice;
 use codex_protocol::items::ContextCompactionItem;
 use codex_protocol::items::TurnItem;
 use codex_protocol::models::BaseInstructions;
@@ -112,6 +113,8 @@ async fn run_remote_compact_task_inner_impl(
         base_instructions,
         personality: turn_context.personality,
         output_schema: None,
+        tool_choice: ToolChoice::Auto,
+        max_output_tokens: None,
     };
 
     let mut new_history = sess
@@ -222,6 +225,7 @@ fn should_keep_compacted_history_item(item: &ResponseItem) -> bool {
         | ResponseItem::CustomToolCallOutput { .. }
         | ResponseItem::WebSearchCall { .. }
         | ResponseItem::ImageGenerationCall { .. }
+        | ResponseItem::ProviderToolCall { .. }
         | ResponseItem::GhostSnapshot { .. }
         | ResponseItem::Other => false,
     }
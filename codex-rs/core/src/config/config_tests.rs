@@ -10,6 +10,7 @@ use crate::config::types::MemoriesToml;
 use crate::config::types::ModelAvailabilityNuxConfig;
 use crate::config::types::NotificationMethod;
 use crate::config::types::Notifications;
+use crate::config::types::SandboxTier;
 use crate::config_loader::RequirementSource;
 use crate::features::Feature;
 use assert_matches::assert_matches;
@@ -48,6 +49,8 @@ fn stdio_mcp(command: &str) -> McpServerConfig {
         disabled_tools: None,
         scopes: None,
         oauth_resource: None,
+        merge_structured_content: false,
+        max_mcp_retries: 0,
     }
 }
 
@@ -68,6 +71,8 @@ fn http_mcp(url: &str) -> McpServerConfig {
         disabled_tools: None,
         scopes: None,
         oauth_resource: None,
+        merge_structured_content: false,
+        max_mcp_retries: 0,
     }
 }
 
@@ -205,6 +210,62 @@ fn config_toml_deserializes_model_availability_nux() {
     );
 }
 
+#[test]
+fn pricing_overrides_merge_with_bundled_defaults() {
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+[pricing."gpt-5"]
+input_per_million = 3.0
+output_per_million = 12.0
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        tempdir().expect("tempdir").path().to_path_buf(),
+    )
+    .expect("load config");
+
+    assert_eq!(
+        config.pricing.get("gpt-5"),
+        Some(&ModelPricing {
+            input_per_million: 3.0,
+            output_per_million: 12.0,
+        })
+    );
+    assert_eq!(
+        config.pricing.get("gpt-5.1-codex"),
+        resolve_model_pricing(None).get("gpt-5.1-codex")
+    );
+}
+
+#[test]
+fn sandbox_selection_fills_unset_categories_with_defaults() {
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+[sandbox_selection]
+read_only = "full"
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        tempdir().expect("tempdir").path().to_path_buf(),
+    )
+    .expect("load config");
+
+    assert_eq!(
+        config.sandbox_selection,
+        Some(SandboxSelection {
+            read_only: SandboxTier::Full,
+            mutating: SandboxTier::Full,
+            network: SandboxTier::Network,
+        })
+    );
+}
+
 #[test]
 fn runtime_config_defaults_model_availability_nux() {
     let cfg = Config::load_from_base_config_with_overrides(
@@ -278,6 +339,7 @@ allowed_domains = ["openai.com"]
                         denied_domains: None,
                         allow_unix_sockets: None,
                         allow_local_binding: None,
+                        confirm_before_access: None,
                     }),
                 },
             )]),
@@ -329,6 +391,49 @@ fn permissions_profiles_network_populates_runtime_network_proxy_spec() -> std::i
 
     assert_eq!(network.proxy_host_and_port(), "127.0.0.1:43128");
     assert!(!network.socks_enabled());
+    assert!(!config.managed_network_enforced());
+    Ok(())
+}
+
+#[test]
+fn permissions_profiles_network_confirm_before_access_enforces_managed_network()
+-> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let cwd = TempDir::new()?;
+    std::fs::write(cwd.path().join(".git"), "gitdir: nowhere")?;
+
+    let config = Config::load_from_base_config_with_overrides(
+        ConfigToml {
+            default_permissions: Some("workspace".to_string()),
+            permissions: Some(PermissionsToml {
+                entries: BTreeMap::from([(
+                    "workspace".to_string(),
+                    PermissionProfileToml {
+                        filesystem: Some(FilesystemPermissionsToml {
+                            entries: BTreeMap::from([(
+                                ":minimal".to_string(),
+                                FilesystemPermissionToml::Access(FileSystemAccessMode::Read),
+                            )]),
+                        }),
+                        network: Some(NetworkToml {
+                            enabled: Some(true),
+                            confirm_before_access: Some(true),
+                            ..Default::default()
+                        }),
+                    },
+                )]),
+            }),
+            ..Default::default()
+        },
+        ConfigOverrides {
+            cwd: Some(cwd.path().to_path_buf()),
+            ..Default::default()
+        },
+        codex_home.path().to_path_buf(),
+    )?;
+
+    assert!(!config.managed_network_requirements_enabled());
+    assert!(config.managed_network_enforced());
     Ok(())
 }
 
@@ -1719,6 +1824,8 @@ async fn replace_mcp_servers_round_trips_entries() -> anyhow::Result<()> {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     );
 
@@ -1875,6 +1982,8 @@ async fn replace_mcp_servers_serializes_env_sorted() -> anyhow::Result<()> {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -1947,6 +2056,8 @@ async fn replace_mcp_servers_serializes_env_vars() -> anyhow::Result<()> {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -1999,6 +2110,8 @@ async fn replace_mcp_servers_serializes_cwd() -> anyhow::Result<()> {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -2049,6 +2162,8 @@ async fn replace_mcp_servers_streamable_http_serializes_bearer_token() -> anyhow
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -2115,6 +2230,8 @@ async fn replace_mcp_servers_streamable_http_serializes_custom_headers() -> anyh
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
     apply_blocking(
@@ -2193,6 +2310,8 @@ async fn replace_mcp_servers_streamable_http_removes_optional_sections() -> anyh
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -2224,6 +2343,8 @@ async fn replace_mcp_servers_streamable_http_removes_optional_sections() -> anyh
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     );
     apply_blocking(
@@ -2290,6 +2411,8 @@ async fn replace_mcp_servers_streamable_http_isolates_headers_between_servers()
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         ),
         (
@@ -2311,6 +2434,8 @@ async fn replace_mcp_servers_streamable_http_isolates_headers_between_servers()
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         ),
     ]);
@@ -2395,6 +2520,8 @@ async fn replace_mcp_servers_serializes_disabled_flag() -> anyhow::Result<()> {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -2441,6 +2568,8 @@ async fn replace_mcp_servers_serializes_required_flag() -> anyhow::Result<()> {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -2487,6 +2616,8 @@ async fn replace_mcp_servers_serializes_tool_filters() -> anyhow::Result<()> {
             disabled_tools: Some(vec!["blocked".to_string()]),
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -2537,6 +2668,8 @@ async fn replace_mcp_servers_streamable_http_serializes_oauth_resource() -> anyh
             disabled_tools: None,
             scopes: None,
             oauth_resource: Some("https://resource.example.com".to_string()),
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         },
     )]);
 
@@ -3620,6 +3753,26 @@ fn load_config_normalizes_agent_role_nickname_candidates() -> std::io::Result<()
     Ok(())
 }
 
+#[test]
+fn load_config_rejects_zero_max_turns_per_minute() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let cfg = ConfigToml {
+        max_turns_per_minute: Some(0),
+        ..Default::default()
+    };
+
+    let result = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.path().to_path_buf(),
+    );
+    let err = result.expect_err("a zero request limit should be rejected");
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(err.to_string().contains("max_turns_per_minute"));
+
+    Ok(())
+}
+
 #[test]
 fn load_config_rejects_empty_agent_role_nickname_candidates() -> std::io::Result<()> {
     let codex_home = TempDir::new()?;
@@ -3981,6 +4134,38 @@ fn test_precedence_fixture_with_o3_profile() -> std::io::Result<()> {
             web_search_config: None,
             use_experimental_unified_exec_tool: !cfg!(windows),
             background_terminal_max_timeout: DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS,
+            turn_deadline_seconds: None,
+            turn_running_long_seconds: None,
+            separate_exec_output_channel: false,
+            background_event_coalesce_window_ms: None,
+            suppress_whitespace_only_agent_messages: false,
+            client_metadata: None,
+            rollout_retention: None,
+            initial_context_file: None,
+            acceptable_exit_codes: HashMap::new(),
+            default_tool_timeout_sec: None,
+            compact_threshold_ratio: None,
+            disable_auto_compact: false,
+            tool_call_loop_threshold: DEFAULT_TOOL_CALL_LOOP_THRESHOLD,
+            max_turns_per_minute: None,
+            include_recent_diffs_in_context: false,
+            history_token_budget: None,
+            path_outside_roots: PathOutsideRootsPolicy::Allow,
+            dry_run: false,
+            sandbox_bypass_audit_log: None,
+            max_output_tokens: None,
+            plugin_cache_max_entries: None,
+            request_final_message_after_tools: false,
+            persist_approved_commands: false,
+            warn_on_gitignored_patch_paths: false,
+            compact_preserve_pending_tool_call: false,
+            pricing: resolve_model_pricing(None),
+            exec_command_display_max_arg_len: DEFAULT_EXEC_COMMAND_DISPLAY_MAX_ARG_LEN,
+            sandbox_selection: None,
+            approval_batch_window_ms: None,
+            include_platform_context: false,
+            max_assistant_output_bytes: None,
+            auto_attach_files: None,
             ghost_snapshot: GhostSnapshotConfig::default(),
             features: Features::with_defaults().into(),
             suppress_unstable_features_warning: false,
@@ -4117,6 +4302,38 @@ fn test_precedence_fixture_with_gpt3_profile() -> std::io::Result<()> {
         web_search_config: None,
         use_experimental_unified_exec_tool: !cfg!(windows),
         background_terminal_max_timeout: DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS,
+        turn_deadline_seconds: None,
+        turn_running_long_seconds: None,
+        separate_exec_output_channel: false,
+        background_event_coalesce_window_ms: None,
+        suppress_whitespace_only_agent_messages: false,
+        client_metadata: None,
+        rollout_retention: None,
+        initial_context_file: None,
+        acceptable_exit_codes: HashMap::new(),
+        default_tool_timeout_sec: None,
+        compact_threshold_ratio: None,
+        disable_auto_compact: false,
+        tool_call_loop_threshold: DEFAULT_TOOL_CALL_LOOP_THRESHOLD,
+        max_turns_per_minute: None,
+        include_recent_diffs_in_context: false,
+        history_token_budget: None,
+        path_outside_roots: PathOutsideRootsPolicy::Allow,
+        dry_run: false,
+        sandbox_bypass_audit_log: None,
+        max_output_tokens: None,
+        plugin_cache_max_entries: None,
+        request_final_message_after_tools: false,
+        persist_approved_commands: false,
+        warn_on_gitignored_patch_paths: false,
+        compact_preserve_pending_tool_call: false,
+        pricing: resolve_model_pricing(None),
+        exec_command_display_max_arg_len: DEFAULT_EXEC_COMMAND_DISPLAY_MAX_ARG_LEN,
+        sandbox_selection: None,
+        approval_batch_window_ms: None,
+        include_platform_context: false,
+        max_assistant_output_bytes: None,
+        auto_attach_files: None,
         ghost_snapshot: GhostSnapshotConfig::default(),
         features: Features::with_defaults().into(),
         suppress_unstable_features_warning: false,
@@ -4251,6 +4468,38 @@ fn test_precedence_fixture_with_zdr_profile() -> std::io::Result<()> {
         web_search_config: None,
         use_experimental_unified_exec_tool: !cfg!(windows),
         background_terminal_max_timeout: DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS,
+        turn_deadline_seconds: None,
+        turn_running_long_seconds: None,
+        separate_exec_output_channel: false,
+        background_event_coalesce_window_ms: None,
+        suppress_whitespace_only_agent_messages: false,
+        client_metadata: None,
+        rollout_retention: None,
+        initial_context_file: None,
+        acceptable_exit_codes: HashMap::new(),
+        default_tool_timeout_sec: None,
+        compact_threshold_ratio: None,
+        disable_auto_compact: false,
+        tool_call_loop_threshold: DEFAULT_TOOL_CALL_LOOP_THRESHOLD,
+        max_turns_per_minute: None,
+        include_recent_diffs_in_context: false,
+        history_token_budget: None,
+        path_outside_roots: PathOutsideRootsPolicy::Allow,
+        dry_run: false,
+        sandbox_bypass_audit_log: None,
+        max_output_tokens: None,
+        plugin_cache_max_entries: None,
+        request_final_message_after_tools: false,
+        persist_approved_commands: false,
+        warn_on_gitignored_patch_paths: false,
+        compact_preserve_pending_tool_call: false,
+        pricing: resolve_model_pricing(None),
+        exec_command_display_max_arg_len: DEFAULT_EXEC_COMMAND_DISPLAY_MAX_ARG_LEN,
+        sandbox_selection: None,
+        approval_batch_window_ms: None,
+        include_platform_context: false,
+        max_assistant_output_bytes: None,
+        auto_attach_files: None,
         ghost_snapshot: GhostSnapshotConfig::default(),
         features: Features::with_defaults().into(),
         suppress_unstable_features_warning: false,
@@ -4371,6 +4620,38 @@ fn test_precedence_fixture_with_gpt5_profile() -> std::io::Result<()> {
         web_search_config: None,
         use_experimental_unified_exec_tool: !cfg!(windows),
         background_terminal_max_timeout: DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS,
+        turn_deadline_seconds: None,
+        turn_running_long_seconds: None,
+        separate_exec_output_channel: false,
+        background_event_coalesce_window_ms: None,
+        suppress_whitespace_only_agent_messages: false,
+        client_metadata: None,
+        rollout_retention: None,
+        initial_context_file: None,
+        acceptable_exit_codes: HashMap::new(),
+        default_tool_timeout_sec: None,
+        compact_threshold_ratio: None,
+        disable_auto_compact: false,
+        tool_call_loop_threshold: DEFAULT_TOOL_CALL_LOOP_THRESHOLD,
+        max_turns_per_minute: None,
+        include_recent_diffs_in_context: false,
+        history_token_budget: None,
+        path_outside_roots: PathOutsideRootsPolicy::Allow,
+        dry_run: false,
+        sandbox_bypass_audit_log: None,
+        max_output_tokens: None,
+        plugin_cache_max_entries: None,
+        request_final_message_after_tools: false,
+        persist_approved_commands: false,
+        warn_on_gitignored_patch_paths: false,
+        compact_preserve_pending_tool_call: false,
+        pricing: resolve_model_pricing(None),
+        exec_command_display_max_arg_len: DEFAULT_EXEC_COMMAND_DISPLAY_MAX_ARG_LEN,
+        sandbox_selection: None,
+        approval_batch_window_ms: None,
+        include_platform_context: false,
+        max_assistant_output_bytes: None,
+        auto_attach_files: None,
         ghost_snapshot: GhostSnapshotConfig::default(),
         features: Features::with_defaults().into(),
         suppress_unstable_features_warning: false,
@@ -225,6 +225,12 @@ mod document_helpers {
         {
             entry["oauth_resource"] = value(resource.clone());
         }
+        if config.merge_structured_content {
+            entry["merge_structured_content"] = value(true);
+        }
+        if config.max_mcp_retries > 0 {
+            entry["max_mcp_retries"] = value(i64::from(config.max_mcp_retries));
+        }
 
         entry
     }
@@ -1530,6 +1536,8 @@ gpt-5 = "gpt-5.1"
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         );
 
@@ -1555,6 +1563,8 @@ gpt-5 = "gpt-5.1"
                 disabled_tools: Some(vec!["forbidden".to_string()]),
                 scopes: None,
                 oauth_resource: Some("https://resource.example.com".to_string()),
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         );
 
@@ -1624,6 +1634,8 @@ foo = { command = "cmd" }
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         );
 
@@ -1671,6 +1683,8 @@ foo = { command = "cmd" } # keep me
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         );
 
@@ -1717,6 +1731,8 @@ foo = { command = "cmd", args = ["--flag"] } # keep me
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         );
 
@@ -1764,6 +1780,8 @@ foo = { command = "cmd" }
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         );
 
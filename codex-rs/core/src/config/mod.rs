@@ -2,6 +2,9 @@ use crate::auth::AuthCredentialsStoreMode;
 use crate::config::edit::ConfigEdit;
 use crate::config::edit::ConfigEditsBuilder;
 use crate::config::types::AppsConfigToml;
+use crate::config::types::AutoAttachFiles;
+use crate::config::types::AutoAttachFilesToml;
+use crate::config::types::ClientMetadata;
 use crate::config::types::DEFAULT_OTEL_ENVIRONMENT;
 use crate::config::types::History;
 use crate::config::types::McpServerConfig;
@@ -10,13 +13,18 @@ use crate::config::types::McpServerTransportConfig;
 use crate::config::types::MemoriesConfig;
 use crate::config::types::MemoriesToml;
 use crate::config::types::ModelAvailabilityNuxConfig;
+use crate::config::types::ModelPricing;
 use crate::config::types::Notice;
 use crate::config::types::NotificationMethod;
 use crate::config::types::Notifications;
 use crate::config::types::OtelConfig;
 use crate::config::types::OtelConfigToml;
 use crate::config::types::OtelExporterKind;
+use crate::config::types::PathOutsideRootsPolicy;
 use crate::config::types::PluginConfig;
+use crate::config::types::RolloutRetention;
+use crate::config::types::SandboxSelection;
+use crate::config::types::SandboxSelectionToml;
 use crate::config::types::SandboxWorkspaceWrite;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::config::types::ShellEnvironmentPolicyToml;
@@ -49,6 +57,7 @@ use crate::model_provider_info::OLLAMA_CHAT_PROVIDER_REMOVED_ERROR;
 use crate::model_provider_info::OLLAMA_OSS_PROVIDER_ID;
 use crate::model_provider_info::built_in_model_providers;
 use crate::path_utils::normalize_for_native_workdir;
+use crate::pricing::resolve_model_pricing;
 use crate::project_doc::DEFAULT_PROJECT_DOC_FILENAME;
 use crate::project_doc::LOCAL_PROJECT_DOC_FILENAME;
 use crate::protocol::AskForApproval;
@@ -89,6 +98,7 @@ use std::collections::HashMap;
 use std::io::ErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::config::permissions::compile_permission_profile;
 use crate::config::permissions::network_proxy_config_from_profile_network;
@@ -132,6 +142,8 @@ pub(crate) const PROJECT_DOC_MAX_BYTES: usize = 32 * 1024; // 32 KiB
 pub(crate) const DEFAULT_AGENT_MAX_THREADS: Option<usize> = Some(6);
 pub(crate) const DEFAULT_AGENT_MAX_DEPTH: i32 = 1;
 pub(crate) const DEFAULT_AGENT_JOB_MAX_RUNTIME_SECONDS: Option<u64> = None;
+pub(crate) const DEFAULT_TOOL_CALL_LOOP_THRESHOLD: usize = 0;
+pub(crate) const DEFAULT_EXEC_COMMAND_DISPLAY_MAX_ARG_LEN: usize = 512;
 
 pub const CONFIG_TOML_FILE: &str = "config.toml";
 
@@ -498,6 +510,134 @@ pub struct Config {
     /// Default: `300000` (5 minutes).
     pub background_terminal_max_timeout: u64,
 
+    /// Hard wall-clock limit for a single turn, in seconds. When reached the turn is
+    /// aborted and any running commands are terminated. `None` means no deadline.
+    pub turn_deadline_seconds: Option<u64>,
+
+    /// Soft wall-clock limit for a single turn, in seconds. When reached the user
+    /// is notified that the turn is running long, but the turn keeps going. `None`
+    /// disables the notification.
+    pub turn_running_long_seconds: Option<u64>,
+
+    /// When `true`, `ExecCommandOutputDelta` events are delivered on a dedicated
+    /// channel (see `CodexThread::exec_output_events`) instead of the main event
+    /// stream, so clients can drain high-volume output independently. Only
+    /// honored for TUI sessions.
+    pub separate_exec_output_channel: bool,
+
+    /// When set, consecutive identical background events arriving within this
+    /// many milliseconds of each other are delivered as one event with a repeat
+    /// count. `None` delivers every background event as-is.
+    pub background_event_coalesce_window_ms: Option<u64>,
+
+    /// When `true`, assistant messages whose text is empty or whitespace-only are
+    /// neither emitted nor recorded in history; the turn ends as if the model
+    /// returned no message.
+    pub suppress_whitespace_only_agent_messages: bool,
+
+    /// Identifies the embedding application on model requests via a
+    /// `User-Agent` suffix and `x-codex-client-app-*` headers.
+    pub client_metadata: Option<ClientMetadata>,
+
+    /// Pruning policy for old rollout files under `codex_home/sessions`.
+    /// `None` (the default) never deletes anything.
+    pub rollout_retention: Option<RolloutRetention>,
+
+    /// File whose contents are part of the initial context, so they seed new
+    /// sessions and are restated after compaction.
+    pub initial_context_file: Option<PathBuf>,
+
+    /// Non-zero exit codes treated as success, keyed by program name, with
+    /// `"*"` applying to every command.
+    pub acceptable_exit_codes: HashMap<String, Vec<i32>>,
+
+    /// Timeout for MCP tool calls on servers without their own
+    /// `tool_timeout_sec`.
+    pub default_tool_timeout_sec: Option<Duration>,
+
+    /// Fraction of the context window at which to auto-compact.
+    pub compact_threshold_ratio: Option<f32>,
+
+    /// When `true`, the conversation is only compacted on request.
+    pub disable_auto_compact: bool,
+
+    /// Number of identical consecutive tool calls after which a turn is stopped
+    /// as a loop. `0` (the default) disables loop detection.
+    pub tool_call_loop_threshold: usize,
+
+    /// Maximum number of model requests per minute; `None` means unlimited.
+    pub max_turns_per_minute: Option<u32>,
+
+    /// When `true`, a summary of files changed in recent turns is added to each
+    /// model request.
+    pub include_recent_diffs_in_context: bool,
+
+    /// Estimated token budget for conversation history; the oldest items are
+    /// dropped at the start of each turn until the history fits.
+    pub history_token_budget: Option<usize>,
+
+    /// How tool paths that resolve outside the cwd and writable roots are handled.
+    pub path_outside_roots: PathOutsideRootsPolicy,
+
+    /// When true, exec, JavaScript and apply_patch tool calls, user shell
+    /// commands and plugin task hooks are reported but not executed.
+    pub dry_run: bool,
+
+    /// Append-only JSONL file recording every command run without a sandbox.
+    pub sandbox_bypass_audit_log: Option<PathBuf>,
+
+    /// Upper bound on the tokens the model may generate per response.
+    pub max_output_tokens: Option<u32>,
+
+    /// Maximum number of cwds whose loaded plugins are cached.
+    pub plugin_cache_max_entries: Option<usize>,
+
+    /// When `true`, a turn whose last model response carries no assistant message
+    /// gets one extra request asking the model for a closing answer.
+    pub request_final_message_after_tools: bool,
+
+    /// When `true`, commands approved for the session are saved to
+    /// `approved_commands.jsonl` in `codex_home` and pre-approved in new sessions.
+    pub persist_approved_commands: bool,
+
+    /// When `true`, a warning event is emitted after `apply_patch` creates or
+    /// modifies files that git ignores, since those changes do not show up in
+    /// `git status`.
+    pub warn_on_gitignored_patch_paths: bool,
+
+    /// When `true`, local compaction keeps the most recent tool call the model has
+    /// not answered yet, together with its output, in the compacted history.
+    pub compact_preserve_pending_tool_call: bool,
+
+    /// Token prices keyed by model slug: the bundled defaults merged with the
+    /// `pricing` entries from config.toml.
+    pub pricing: HashMap<String, ModelPricing>,
+
+    /// Command arguments longer than this many bytes are shortened in exec command
+    /// events.
+    pub exec_command_display_max_arg_len: usize,
+
+    /// Sandbox tier per command category, or `None` to let the sandbox policy
+    /// alone choose.
+    pub sandbox_selection: Option<SandboxSelection>,
+
+    /// Exec approval requests that arrive within this many milliseconds of each
+    /// other are sent as one batch. `None` sends each request on its own.
+    /// Only honored for TUI sessions.
+    pub approval_batch_window_ms: Option<u64>,
+
+    /// When `true`, the initial environment context describes the host OS,
+    /// architecture, default shell and path separator.
+    pub include_platform_context: bool,
+
+    /// Maximum bytes of assistant text a turn may produce before the rest is
+    /// dropped and the message is truncated. `None` means unlimited.
+    pub max_assistant_output_bytes: Option<usize>,
+
+    /// When set, text files mentioned by path in a user message are attached to
+    /// the turn. `None` disables attachment.
+    pub auto_attach_files: Option<AutoAttachFiles>,
+
     /// Settings for ghost snapshots (used for undo).
     pub ghost_snapshot: GhostSnapshotConfig,
 
@@ -1160,6 +1300,160 @@ pub struct ConfigToml {
     /// Default: `300000` (5 minutes).
     pub background_terminal_max_timeout: Option<u64>,
 
+    /// Hard wall-clock limit for a single turn, in seconds. When reached the turn is
+    /// aborted and any running commands are terminated.
+    pub turn_deadline_seconds: Option<u64>,
+
+    /// Notify (via `notify` and a background event) when a single turn has been
+    /// running for this many seconds. Unlike `turn_deadline_seconds` the turn is
+    /// not aborted.
+    pub turn_running_long_seconds: Option<u64>,
+
+    /// When set to `true`, `ExecCommandOutputDelta` events are delivered on a
+    /// dedicated channel instead of the main event stream. Defaults to `false`.
+    /// Only the TUI reads that channel; other clients ignore this setting.
+    pub separate_exec_output_channel: Option<bool>,
+
+    /// When set, consecutive identical background events arriving within this
+    /// many milliseconds of each other are delivered as one event with a repeat
+    /// count.
+    pub background_event_coalesce_window_ms: Option<u64>,
+
+    /// When set to `true`, empty or whitespace-only assistant messages are
+    /// neither emitted nor recorded. Defaults to `false`.
+    pub suppress_whitespace_only_agent_messages: Option<bool>,
+
+    /// Identifies the embedding application on model requests. Codex's own
+    /// `User-Agent` is kept and the suffix is appended to it.
+    pub client_metadata: Option<ClientMetadata>,
+
+    /// Prune old rollout files under `codex_home/sessions` when a session
+    /// starts. Disabled unless at least one limit is set.
+    pub rollout_retention: Option<RolloutRetention>,
+
+    /// Optional path to a Markdown or JSON file whose contents are injected
+    /// as context at the start of every new session and again after the
+    /// conversation is compacted. Capped at 32 KiB.
+    pub initial_context_file: Option<AbsolutePathBuf>,
+
+    /// Non-zero exit codes that count as success for shell commands, keyed by
+    /// program name (e.g. `diff = [1]`). The `"*"` key applies to every command.
+    /// Matching commands are reported to the model as successful and are not
+    /// escalated as likely sandbox denials.
+    pub acceptable_exit_codes: Option<HashMap<String, Vec<i32>>>,
+
+    /// Default timeout, in seconds, for MCP tool calls on servers that do not
+    /// set their own `tool_timeout_sec`.
+    #[serde(default, with = "crate::config::types::option_duration_secs")]
+    #[schemars(with = "Option<f64>")]
+    pub default_tool_timeout_sec: Option<Duration>,
+
+    /// Fraction of the model context window (between 0 and 1) at which the
+    /// conversation is compacted automatically. Compaction always triggers by 90%
+    /// of the window, so larger values have no effect.
+    pub compact_threshold_ratio: Option<f32>,
+
+    /// Never compact the conversation automatically; compaction then only runs
+    /// when requested (e.g. `/compact`).
+    pub disable_auto_compact: Option<bool>,
+
+    /// Stop a turn once the model makes this many identical tool calls (same
+    /// tool, same arguments) in a row. Polling tools such as `write_stdin` are
+    /// exempt. Defaults to `0`, which disables the check.
+    pub tool_call_loop_threshold: Option<usize>,
+
+    /// Maximum number of model requests a session may make per minute. When the
+    /// limit is hit, the turn waits until the one-minute window clears. Unset
+    /// means unlimited; `0` is rejected.
+    pub max_turns_per_minute: Option<u32>,
+
+    /// Remind the model which files it changed in recent turns by adding a compact
+    /// summary (paths and line counts, not full diffs) to each request. Defaults to
+    /// `false`.
+    pub include_recent_diffs_in_context: Option<bool>,
+
+    /// Approximate token budget for the conversation history. When set, the oldest
+    /// history items are dropped at the start of each turn until the estimated total
+    /// fits. Unset by default.
+    pub history_token_budget: Option<usize>,
+
+    /// How a path passed to a tool that resolves outside the cwd and writable roots
+    /// (for example via `..` segments or an absolute path) is handled: `allow`
+    /// (default), `warn`, or `reject`.
+    pub path_outside_roots: Option<PathOutsideRootsPolicy>,
+
+    /// When true, commands and patches requested by the model are logged and
+    /// reported as successful without being executed. Defaults to false.
+    pub dry_run: Option<bool>,
+
+    /// Optional path to an append-only JSONL file that records every command run
+    /// without a sandbox: command, cwd, approval decision, and timestamp.
+    /// Secrets in the command are redacted.
+    pub sandbox_bypass_audit_log: Option<AbsolutePathBuf>,
+
+    /// Upper bound on the number of tokens the model may generate for each
+    /// response. Ignored (with a warning) by providers that do not support it.
+    pub max_output_tokens: Option<u32>,
+
+    /// Maximum number of working directories whose loaded plugins are kept in
+    /// memory. Least recently used entries are evicted and reloaded on their next
+    /// use. Defaults to 64.
+    pub plugin_cache_max_entries: Option<usize>,
+
+    /// Ask the model for a closing message when a turn would otherwise end after
+    /// tool calls without one, so clients always have a final answer to show.
+    /// Defaults to `false`.
+    pub request_final_message_after_tools: Option<bool>,
+
+    /// Remember commands approved "for this session" across restarts by saving
+    /// them to `approved_commands.jsonl` in `CODEX_HOME`. Defaults to `false`.
+    pub persist_approved_commands: Option<bool>,
+
+    /// Emit a warning when `apply_patch` creates or modifies a path ignored by the
+    /// repository's `.gitignore`. The patch is still applied. Defaults to `false`.
+    pub warn_on_gitignored_patch_paths: Option<bool>,
+
+    /// Keep the most recent tool call that the model has not responded to yet (and
+    /// its output, if recorded) when compacting the conversation locally, so the
+    /// next sample can still refer to it. Defaults to `false`.
+    pub compact_preserve_pending_tool_call: Option<bool>,
+
+    /// Token prices used to estimate the cost of each turn, keyed by model slug
+    /// (e.g. `[pricing."gpt-5.1-codex"]`). Entries replace the bundled price of the
+    /// same model; models without a price get no estimate.
+    pub pricing: Option<HashMap<String, ModelPricing>>,
+
+    /// Maximum length in bytes of a single command argument as shown in exec command
+    /// events. Longer arguments have embedded `apply_patch` bodies summarized and are
+    /// then truncated; the command that runs is unchanged. Defaults to 512.
+    pub exec_command_display_max_arg_len: Option<usize>,
+
+    /// Picks the sandbox for each shell command from what the command does:
+    /// read-only, mutating, or network. Never weaker than the sandbox policy
+    /// requires. Unset leaves the choice to the sandbox policy alone.
+    pub sandbox_selection: Option<SandboxSelectionToml>,
+
+    /// Collect exec approval requests that arrive within this many milliseconds
+    /// of the first one and present them together as a single
+    /// `ExecApprovalBatchRequest`. Unset or 0 presents each request on its own.
+    /// Only the TUI supports batches; other clients always get single requests.
+    pub approval_batch_window_ms: Option<u64>,
+
+    /// Tell the model which OS, CPU architecture, default shell and path separator
+    /// the host uses, so it can tailor commands and paths to it. Defaults to
+    /// `false`.
+    pub include_platform_context: Option<bool>,
+
+    /// Stop streaming assistant text once a turn has produced this many bytes, and
+    /// truncate the final message with a marker. The turn still completes. Unset
+    /// or 0 means unlimited.
+    pub max_assistant_output_bytes: Option<usize>,
+
+    /// Attach the text files a user message mentions by path to the turn, so the
+    /// model sees them without reading them itself. Only existing files inside the
+    /// cwd or writable roots are attached. Unset disables attachment.
+    pub auto_attach_files: Option<AutoAttachFilesToml>,
+
     /// Optional absolute path to the Node runtime used by `js_repl`.
     pub js_repl_node_path: Option<AbsolutePathBuf>,
 
@@ -1961,6 +2255,7 @@ impl Config {
             && has_permission_profiles);
         let (
             configured_network_proxy_config,
+            network_confirm_before_access,
             sandbox_policy,
             file_system_sandbox_policy,
             network_sandbox_policy,
@@ -1980,6 +2275,11 @@ impl Config {
             let profile = resolve_permission_profile(permissions, default_permissions)?;
             let configured_network_proxy_config =
                 network_proxy_config_from_profile_network(profile.network.as_ref());
+            let network_confirm_before_access = profile
+                .network
+                .as_ref()
+                .and_then(|network| network.confirm_before_access)
+                .unwrap_or(false);
             let (mut file_system_sandbox_policy, network_sandbox_policy) =
                 compile_permission_profile(
                     permissions,
@@ -1998,12 +2298,14 @@ impl Config {
             }
             (
                 configured_network_proxy_config,
+                network_confirm_before_access,
                 sandbox_policy,
                 file_system_sandbox_policy,
                 network_sandbox_policy,
             )
         } else {
             let configured_network_proxy_config = NetworkProxyConfig::default();
+            let network_confirm_before_access = false;
             let mut sandbox_policy = cfg.derive_sandbox_policy(
                 sandbox_mode,
                 config_profile.sandbox_mode,
@@ -2023,6 +2325,7 @@ impl Config {
             let network_sandbox_policy = NetworkSandboxPolicy::from(&sandbox_policy);
             (
                 configured_network_proxy_config,
+                network_confirm_before_access,
                 sandbox_policy,
                 file_system_sandbox_policy,
                 network_sandbox_policy,
@@ -2126,10 +2429,28 @@ impl Config {
                 "agents.job_max_runtime_seconds must fit within a 64-bit signed integer",
             ));
         }
+        if cfg.max_turns_per_minute == Some(0) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "max_turns_per_minute must be at least 1; leave it unset for no limit",
+            ));
+        }
         let background_terminal_max_timeout = cfg
             .background_terminal_max_timeout
             .unwrap_or(DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS)
             .max(MIN_EMPTY_YIELD_TIME_MS);
+        if cfg.turn_deadline_seconds == Some(0) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "turn_deadline_seconds must be at least 1",
+            ));
+        }
+        if cfg.turn_running_long_seconds == Some(0) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "turn_running_long_seconds must be at least 1",
+            ));
+        }
 
         let ghost_snapshot = {
             let mut config = GhostSnapshotConfig::default();
@@ -2308,7 +2629,8 @@ impl Config {
             } else {
                 err
             }
-        })?;
+        })?
+        .with_confirm_before_access(network_confirm_before_access);
         let network = if has_network_requirements {
             Some(network)
         } else {
@@ -2442,6 +2764,50 @@ impl Config {
             web_search_config,
             use_experimental_unified_exec_tool,
             background_terminal_max_timeout,
+            turn_deadline_seconds: cfg.turn_deadline_seconds,
+            turn_running_long_seconds: cfg.turn_running_long_seconds,
+            separate_exec_output_channel: cfg.separate_exec_output_channel.unwrap_or(false),
+            background_event_coalesce_window_ms: cfg.background_event_coalesce_window_ms,
+            suppress_whitespace_only_agent_messages: cfg
+                .suppress_whitespace_only_agent_messages
+                .unwrap_or(false),
+            client_metadata: cfg.client_metadata,
+            rollout_retention: cfg.rollout_retention,
+            initial_context_file: cfg.initial_context_file.map(Into::into),
+            acceptable_exit_codes: cfg.acceptable_exit_codes.unwrap_or_default(),
+            default_tool_timeout_sec: cfg.default_tool_timeout_sec,
+            compact_threshold_ratio: cfg.compact_threshold_ratio,
+            disable_auto_compact: cfg.disable_auto_compact.unwrap_or(false),
+            tool_call_loop_threshold: cfg
+                .tool_call_loop_threshold
+                .unwrap_or(DEFAULT_TOOL_CALL_LOOP_THRESHOLD),
+            max_turns_per_minute: cfg.max_turns_per_minute,
+            include_recent_diffs_in_context: cfg.include_recent_diffs_in_context.unwrap_or(false),
+            history_token_budget: cfg.history_token_budget,
+            path_outside_roots: cfg.path_outside_roots.unwrap_or_default(),
+            dry_run: cfg.dry_run.unwrap_or(false),
+            sandbox_bypass_audit_log: cfg.sandbox_bypass_audit_log.map(Into::into),
+            max_output_tokens: cfg.max_output_tokens,
+            plugin_cache_max_entries: cfg.plugin_cache_max_entries,
+            request_final_message_after_tools: cfg
+                .request_final_message_after_tools
+                .unwrap_or(false),
+            persist_approved_commands: cfg.persist_approved_commands.unwrap_or(false),
+            warn_on_gitignored_patch_paths: cfg.warn_on_gitignored_patch_paths.unwrap_or(false),
+            compact_preserve_pending_tool_call: cfg
+                .compact_preserve_pending_tool_call
+                .unwrap_or(false),
+            pricing: resolve_model_pricing(cfg.pricing),
+            exec_command_display_max_arg_len: cfg
+                .exec_command_display_max_arg_len
+                .unwrap_or(DEFAULT_EXEC_COMMAND_DISPLAY_MAX_ARG_LEN),
+            sandbox_selection: cfg.sandbox_selection.map(SandboxSelection::from),
+            approval_batch_window_ms: cfg
+                .approval_batch_window_ms
+                .filter(|window_ms| *window_ms > 0),
+            include_platform_context: cfg.include_platform_context.unwrap_or(false),
+            max_assistant_output_bytes: cfg.max_assistant_output_bytes.filter(|limit| *limit > 0),
+            auto_attach_files: cfg.auto_attach_files.map(AutoAttachFiles::from),
             ghost_snapshot,
             features,
             suppress_unstable_features_warning: cfg
@@ -2508,7 +2874,7 @@ impl Config {
         Ok(config)
     }
 
-    fn load_instructions(codex_dir: Option<&Path>) -> Option<String> {
+    pub(crate) fn load_instructions(codex_dir: Option<&Path>) -> Option<String> {
         let base = codex_dir?;
         for candidate in [LOCAL_PROJECT_DOC_FILENAME, DEFAULT_PROJECT_DOC_FILENAME] {
             let mut path = base.to_path_buf();
@@ -2585,6 +2951,19 @@ impl Config {
             .is_some()
     }
 
+    /// Whether commands must reach the network through the managed proxy, with
+    /// allowlist misses routed to the approval flow. This is the case under
+    /// managed network requirements, or when the active permissions profile
+    /// sets `confirm_before_access` on its network table.
+    pub fn managed_network_enforced(&self) -> bool {
+        self.managed_network_requirements_enabled()
+            || self
+                .permissions
+                .network
+                .as_ref()
+                .is_some_and(NetworkProxySpec::confirm_before_access)
+    }
+
     pub fn bundled_skills_enabled(&self) -> bool {
         crate::skills::manager::bundled_skills_enabled_from_stack(&self.config_layer_stack)
     }
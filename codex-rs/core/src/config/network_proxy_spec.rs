@@ -22,6 +22,7 @@ pub struct NetworkProxySpec {
     config: NetworkProxyConfig,
     constraints: NetworkProxyConstraints,
     hard_deny_allowlist_misses: bool,
+    confirm_before_access: bool,
 }
 
 pub struct StartedNetworkProxy {
@@ -81,6 +82,17 @@ impl NetworkProxySpec {
         self.config.network.enable_socks5
     }
 
+    /// Whether requests outside the allowlist should prompt for approval even
+    /// without managed network requirements.
+    pub(crate) fn confirm_before_access(&self) -> bool {
+        self.confirm_before_access
+    }
+
+    pub(crate) fn with_confirm_before_access(mut self, confirm_before_access: bool) -> Self {
+        self.confirm_before_access = confirm_before_access;
+        self
+    }
+
     pub(crate) fn from_config_and_constraints(
         config: NetworkProxyConfig,
         requirements: Option<NetworkConstraints>,
@@ -109,6 +121,7 @@ impl NetworkProxySpec {
             config,
             constraints,
             hard_deny_allowlist_misses,
+            confirm_before_access: false,
         })
     }
 
@@ -290,6 +303,7 @@ mod tests {
             config: NetworkProxyConfig::default(),
             constraints: NetworkProxyConstraints::default(),
             hard_deny_allowlist_misses: false,
+            confirm_before_access: false,
         };
         let metadata = NetworkProxyAuditMetadata {
             conversation_id: Some("conversation-1".to_string()),
@@ -104,6 +104,14 @@ pub struct McpServerConfig {
     /// Optional OAuth resource parameter to include during MCP login (RFC 8707).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub oauth_resource: Option<String>,
+    /// When `true`, a tool's `structured_content` is appended to its `content`
+    /// blocks in the output sent to the model instead of replacing them.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub merge_structured_content: bool,
+    /// How many times a tool call is retried after a transport failure, such as
+    /// a dropped connection or an HTTP 429. `0` disables retries.
+    #[serde(default)]
+    pub max_mcp_retries: u32,
 }
 
 // Raw MCP config shape used for deserialization and JSON Schema generation.
@@ -150,6 +158,10 @@ pub(crate) struct RawMcpServerConfig {
     pub scopes: Option<Vec<String>>,
     #[serde(default)]
     pub oauth_resource: Option<String>,
+    #[serde(default)]
+    pub merge_structured_content: Option<bool>,
+    #[serde(default)]
+    pub max_mcp_retries: Option<u32>,
 }
 
 impl<'de> Deserialize<'de> for McpServerConfig {
@@ -174,6 +186,8 @@ impl<'de> Deserialize<'de> for McpServerConfig {
         let disabled_tools = raw.disabled_tools.clone();
         let scopes = raw.scopes.clone();
         let oauth_resource = raw.oauth_resource.clone();
+        let merge_structured_content = raw.merge_structured_content.unwrap_or_default();
+        let max_mcp_retries = raw.max_mcp_retries.unwrap_or_default();
 
         fn throw_if_set<E, T>(transport: &str, field: &str, value: Option<&T>) -> Result<(), E>
         where
@@ -232,6 +246,8 @@ impl<'de> Deserialize<'de> for McpServerConfig {
             disabled_tools,
             scopes,
             oauth_resource,
+            merge_structured_content,
+            max_mcp_retries,
         })
     }
 }
@@ -272,7 +288,7 @@ pub enum McpServerTransportConfig {
     },
 }
 
-mod option_duration_secs {
+pub(crate) mod option_duration_secs {
     use serde::Deserialize;
     use serde::Deserializer;
     use serde::Serializer;
@@ -351,6 +367,110 @@ pub enum HistoryPersistence {
     None,
 }
 
+/// How a model-supplied tool path that resolves outside the session roots
+/// (the cwd plus any writable roots) is handled.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PathOutsideRootsPolicy {
+    /// Use the path as given.
+    #[default]
+    Allow,
+    /// Use the path as given, but log a warning.
+    Warn,
+    /// Fail the tool call.
+    Reject,
+}
+
+/// Sandbox applied to a category of shell commands by `[sandbox_selection]`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SandboxTier {
+    /// Run under the sandbox policy as configured.
+    Light,
+    /// Run under the platform sandbox with at most workspace writes and no
+    /// network access.
+    Full,
+    /// Like `full`, but keeps the network access the sandbox policy grants.
+    Network,
+}
+
+/// Per-category sandbox tiers loaded from the `[sandbox_selection]` table.
+/// Unset categories fall back to the defaults of [`SandboxSelection`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct SandboxSelectionToml {
+    /// Commands known to only read, such as `ls` or `git status`. Defaults to
+    /// `light`.
+    pub read_only: Option<SandboxTier>,
+    /// Commands that may change files. Defaults to `full`.
+    pub mutating: Option<SandboxTier>,
+    /// Commands that reach the network, such as `curl` or `git fetch`.
+    /// Defaults to `network`.
+    pub network: Option<SandboxTier>,
+}
+
+/// Sandbox tier for each command category. A tier can only make the sandbox
+/// stronger than the sandbox policy alone would; `light` keeps the policy
+/// as is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SandboxSelection {
+    pub read_only: SandboxTier,
+    pub mutating: SandboxTier,
+    pub network: SandboxTier,
+}
+
+impl From<SandboxSelectionToml> for SandboxSelection {
+    fn from(toml: SandboxSelectionToml) -> Self {
+        Self {
+            read_only: toml.read_only.unwrap_or(SandboxTier::Light),
+            mutating: toml.mutating.unwrap_or(SandboxTier::Full),
+            network: toml.network.unwrap_or(SandboxTier::Network),
+        }
+    }
+}
+
+/// Settings loaded from the `[auto_attach_files]` table. Unset fields fall back
+/// to the defaults of [`AutoAttachFiles`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct AutoAttachFilesToml {
+    /// Regular expressions that find file paths in user messages. The first
+    /// capture group, or the whole match when there is none, is the path.
+    /// Defaults to `@path` mentions and paths in backticks.
+    pub patterns: Option<Vec<String>>,
+    /// Maximum bytes attached from each file; longer files are truncated.
+    /// Defaults to 32 KiB.
+    pub max_bytes: Option<usize>,
+}
+
+/// Attaches the text files a user message mentions by path to the turn, so
+/// the model sees their contents without reading them itself. Only existing
+/// files inside the cwd or writable roots are attached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoAttachFiles {
+    pub patterns: Vec<String>,
+    pub max_bytes: usize,
+}
+
+impl AutoAttachFiles {
+    pub const DEFAULT_PATTERNS: [&str; 2] = [r"(?:^|\s)@([^\s`]+)", r"`([^`\s]+)`"];
+    pub const DEFAULT_MAX_BYTES: usize = 32 * 1024;
+}
+
+impl From<AutoAttachFilesToml> for AutoAttachFiles {
+    fn from(toml: AutoAttachFilesToml) -> Self {
+        Self {
+            patterns: toml.patterns.unwrap_or_else(|| {
+                Self::DEFAULT_PATTERNS
+                    .iter()
+                    .map(|pattern| (*pattern).to_string())
+                    .collect()
+            }),
+            max_bytes: toml.max_bytes.unwrap_or(Self::DEFAULT_MAX_BYTES),
+        }
+    }
+}
+
 // ===== Analytics configuration =====
 
 /// Analytics settings loaded from config.toml. Fields are optional so we can apply defaults.
@@ -368,6 +488,41 @@ pub struct FeedbackConfigToml {
     pub enabled: Option<bool>,
 }
 
+/// Identifies the application embedding Codex on model requests.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ClientMetadata {
+    /// Appended in parentheses to Codex's own `User-Agent`, e.g. `my-app/1.2`.
+    pub user_agent_suffix: Option<String>,
+    /// Sent as the `x-codex-client-app-name` header.
+    pub app_name: Option<String>,
+    /// Sent as the `x-codex-client-app-version` header.
+    pub app_version: Option<String>,
+}
+
+/// Pruning policy for rollout files under `codex_home/sessions`, applied when a
+/// session starts. Both limits are optional; when neither is set nothing is
+/// deleted. Rollouts modified within the last day are always kept.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct RolloutRetention {
+    /// Keep at most this many rollout files, most recently modified first.
+    pub max_count: Option<usize>,
+    /// Delete rollout files not modified within this many days.
+    pub max_age_days: Option<u64>,
+}
+
+/// Per-model token prices used to estimate the cost of a turn, in USD per
+/// million tokens.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ModelPricing {
+    /// Price of one million input tokens, cached or not.
+    pub input_per_million: f64,
+    /// Price of one million output tokens, including reasoning tokens.
+    pub output_per_million: f64,
+}
+
 /// Memories settings loaded from config.toml.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
 #[schemars(deny_unknown_fields)]
@@ -842,6 +997,11 @@ pub enum ShellEnvironmentPolicyInherit {
 
     /// Do not inherit any environment variables from the parent process.
     None,
+
+    /// Inherit only the named variables from the parent process. Configured
+    /// through `allow_list` rather than `inherit`.
+    #[serde(skip)]
+    AllowList { vars: Vec<String> },
 }
 
 /// Policy for building the `env` when spawning a process via either the
@@ -851,6 +1011,10 @@ pub enum ShellEnvironmentPolicyInherit {
 pub struct ShellEnvironmentPolicyToml {
     pub inherit: Option<ShellEnvironmentPolicyInherit>,
 
+    /// Exact names of the only variables to inherit from the parent process.
+    /// Takes precedence over `inherit`.
+    pub allow_list: Option<Vec<String>>,
+
     pub ignore_default_excludes: Option<bool>,
 
     /// List of regular expressions.
@@ -898,7 +1062,10 @@ pub struct ShellEnvironmentPolicy {
 impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
     fn from(toml: ShellEnvironmentPolicyToml) -> Self {
         // Default to inheriting the full environment when not specified.
-        let inherit = toml.inherit.unwrap_or(ShellEnvironmentPolicyInherit::All);
+        let inherit = match toml.allow_list {
+            Some(vars) => ShellEnvironmentPolicyInherit::AllowList { vars },
+            None => toml.inherit.unwrap_or(ShellEnvironmentPolicyInherit::All),
+        };
         let ignore_default_excludes = toml.ignore_default_excludes.unwrap_or(true);
         let exclude = toml
             .exclude
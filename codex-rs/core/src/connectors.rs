@@ -162,6 +162,7 @@ pub async fn list_accessible_connectors_from_mcp_tools_with_options_and_status(
     let (mcp_connection_manager, cancel_token) = McpConnectionManager::new(
         &mcp_servers,
         config.mcp_oauth_credentials_store_mode,
+        config.default_tool_timeout_sec,
         auth_status_entries,
         &config.permissions.approval_policy,
         tx_event,
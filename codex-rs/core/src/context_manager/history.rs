@@ -19,6 +19,7 @@ use codex_protocol::models::ResponseItem;
 use codex_protocol::openai_models::InputModality;
 use codex_protocol::protocol::TokenUsage;
 use codex_protocol::protocol::TokenUsageInfo;
+use codex_protocol::protocol::ToolOutputTokenUsage;
 use codex_protocol::protocol::TurnContextItem;
 use codex_utils_cache::BlockingLruCache;
 use codex_utils_cache::sha1_digest;
@@ -26,6 +27,9 @@ use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::sync::LazyLock;
 
+/// Longest excerpt returned by `ContextManager::search_snippet`, in characters.
+const SEARCH_SNIPPET_MAX_CHARS: usize = 160;
+
 /// Transcript of thread history
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ContextManager {
@@ -119,6 +123,43 @@ impl ContextManager {
         &self.items
     }
 
+    /// Returns the indices (into `raw_items`) of the messages and tool outputs
+    /// whose text contains `query`, ignoring case. An empty query matches
+    /// nothing.
+    pub(crate) fn search(&self, query: &str) -> Vec<usize> {
+        let query = query.to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                searchable_text(item).is_some_and(|text| text.to_lowercase().contains(&query))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns a short excerpt of the item at `index` for a `search` hit: the
+    /// first line mentioning `query`, or the first line when the match spans
+    /// several lines.
+    pub(crate) fn search_snippet(&self, index: usize, query: &str) -> Option<String> {
+        let text = searchable_text(self.items.get(index)?)?;
+        let query = query.to_lowercase();
+        let line = text
+            .lines()
+            .find(|line| line.to_lowercase().contains(&query))
+            .or_else(|| text.lines().next())
+            .unwrap_or_default()
+            .trim();
+        let mut snippet: String = line.chars().take(SEARCH_SNIPPET_MAX_CHARS).collect();
+        if snippet.len() < line.len() {
+            snippet.push('…');
+        }
+        Some(snippet)
+    }
+
     // Estimate token usage using byte-based heuristics from the truncation helpers.
     // This is a coarse lower bound, not a tokenizer-accurate count.
     pub(crate) fn estimate_token_count(&self, turn_context: &TurnContext) -> Option<i64> {
@@ -146,6 +187,23 @@ impl ContextManager {
         Some(base_tokens.saturating_add(items_tokens))
     }
 
+    /// Estimated context cost of each tool output in the history, oldest first.
+    pub(crate) fn tool_output_token_usage(&self) -> Vec<ToolOutputTokenUsage> {
+        self.items
+            .iter()
+            .filter_map(|item| match item {
+                ResponseItem::FunctionCallOutput { call_id, .. }
+                | ResponseItem::CustomToolCallOutput { call_id, .. } => {
+                    Some(ToolOutputTokenUsage {
+                        call_id: call_id.clone(),
+                        tokens: estimate_item_token_count(item),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
     pub(crate) fn remove_first_item(&mut self) {
         if !self.items.is_empty() {
             // Remove the oldest item (front of the list). Items are ordered from
@@ -158,6 +216,37 @@ impl ContextManager {
         }
     }
 
+    /// Drops the oldest items until the total reported by `count_fn` is at
+    /// most `max_tokens`. Call/output pairs are removed together. Returns
+    /// whether any item was dropped.
+    pub(crate) fn keep_within_token_budget(
+        &mut self,
+        max_tokens: usize,
+        count_fn: impl Fn(&ResponseItem) -> usize,
+    ) -> bool {
+        let total = |items: &[ResponseItem]| {
+            items
+                .iter()
+                .map(&count_fn)
+                .fold(0usize, usize::saturating_add)
+        };
+        let mut total_tokens = total(&self.items);
+        let mut trimmed = false;
+        while total_tokens > max_tokens && !self.items.is_empty() {
+            let len_before = self.items.len();
+            let first_tokens = count_fn(&self.items[0]);
+            self.remove_first_item();
+            trimmed = true;
+            total_tokens = if self.items.len() + 1 == len_before {
+                total_tokens.saturating_sub(first_tokens)
+            } else {
+                // A paired call/output elsewhere in the history went too.
+                total(&self.items)
+            };
+        }
+        trimmed
+    }
+
     pub(crate) fn remove_last_item(&mut self) -> bool {
         if let Some(removed) = self.items.pop() {
             normalize::remove_corresponding_for(&mut self.items, &removed);
@@ -378,6 +467,7 @@ impl ContextManager {
             | ResponseItem::FunctionCall { .. }
             | ResponseItem::WebSearchCall { .. }
             | ResponseItem::ImageGenerationCall { .. }
+            | ResponseItem::ProviderToolCall { .. }
             | ResponseItem::CustomToolCall { .. }
             | ResponseItem::Compaction { .. }
             | ResponseItem::GhostSnapshot { .. }
@@ -419,6 +509,7 @@ fn is_api_message(message: &ResponseItem) -> bool {
         | ResponseItem::Reasoning { .. }
         | ResponseItem::WebSearchCall { .. }
         | ResponseItem::ImageGenerationCall { .. }
+        | ResponseItem::ProviderToolCall { .. }
         | ResponseItem::Compaction { .. } => true,
         ResponseItem::GhostSnapshot { .. } => false,
         ResponseItem::Other => false,
@@ -438,6 +529,12 @@ fn estimate_item_token_count(item: &ResponseItem) -> i64 {
     approx_tokens_from_byte_count_i64(model_visible_bytes)
 }
 
+/// Default `count_fn` for [`ContextManager::keep_within_token_budget`]: about
+/// four model-visible bytes per token.
+pub(crate) fn approx_item_token_count(item: &ResponseItem) -> usize {
+    usize::try_from(estimate_item_token_count(item)).unwrap_or(usize::MAX)
+}
+
 /// Approximate model-visible byte cost for one image input.
 ///
 /// The estimator later converts bytes to tokens using a 4-bytes/token heuristic
@@ -607,6 +704,7 @@ fn is_model_generated_item(item: &ResponseItem) -> bool {
         | ResponseItem::FunctionCall { .. }
         | ResponseItem::WebSearchCall { .. }
         | ResponseItem::ImageGenerationCall { .. }
+        | ResponseItem::ProviderToolCall { .. }
         | ResponseItem::CustomToolCall { .. }
         | ResponseItem::LocalShellCall { .. }
         | ResponseItem::Compaction { .. } => true,
@@ -632,6 +730,29 @@ pub(crate) fn is_user_turn_boundary(item: &ResponseItem) -> bool {
     role == "user" && !is_contextual_user_message_content(content)
 }
 
+/// Text of the items `ContextManager::search` looks at: message text and
+/// function or custom tool call output.
+fn searchable_text(item: &ResponseItem) -> Option<String> {
+    match item {
+        ResponseItem::Message { content, .. } => {
+            let text = content
+                .iter()
+                .filter_map(|content| match content {
+                    ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                        Some(text.as_str())
+                    }
+                    ContentItem::InputImage { .. } => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            Some(text)
+        }
+        ResponseItem::FunctionCallOutput { output, .. }
+        | ResponseItem::CustomToolCallOutput { output, .. } => output.body.to_text(),
+        _ => None,
+    }
+}
+
 fn user_message_positions(items: &[ResponseItem]) -> Vec<usize> {
     let mut positions = Vec::new();
     for (idx, item) in items.iter().enumerate() {
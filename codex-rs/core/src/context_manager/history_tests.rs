@@ -210,6 +210,28 @@ fn items_after_last_model_generated_tokens_include_user_and_tool_output() {
     );
 }
 
+#[test]
+fn tool_output_token_usage_attributes_larger_outputs_more_tokens() {
+    let history = create_history_with_items(vec![
+        user_msg("run both tools"),
+        custom_tool_call_output("call-small", "ok"),
+        assistant_msg("looking at the big one"),
+        custom_tool_call_output("call-large", &"line of output\n".repeat(500)),
+    ]);
+
+    let usage = history.tool_output_token_usage();
+    let call_ids: Vec<&str> = usage.iter().map(|entry| entry.call_id.as_str()).collect();
+    assert_eq!(call_ids, vec!["call-small", "call-large"]);
+    assert!(
+        usage[1].tokens > usage[0].tokens,
+        "large output should cost more tokens: {usage:?}"
+    );
+    assert_eq!(
+        usage[1].tokens,
+        estimate_item_token_count(&history.raw_items()[3])
+    );
+}
+
 #[test]
 fn items_after_last_model_generated_tokens_are_zero_without_model_generated_items() {
     let history = create_history_with_items(vec![user_msg("no model output yet")]);
@@ -1682,3 +1704,94 @@ fn text_only_items_unchanged() {
 
     assert_eq!(estimated, raw_len);
 }
+
+#[test]
+fn keep_within_token_budget_drops_oldest_items() {
+    let mut h = create_history_with_items(vec![
+        user_msg("one"),
+        assistant_msg("two"),
+        user_msg("three"),
+        assistant_msg("four"),
+    ]);
+
+    assert!(!h.keep_within_token_budget(4, |_| 1));
+    assert!(h.keep_within_token_budget(2, |_| 1));
+    assert_eq!(
+        h.raw_items(),
+        vec![user_msg("three"), assistant_msg("four")]
+    );
+}
+
+#[test]
+fn keep_within_token_budget_removes_call_output_pairs_together() {
+    let mut h = create_history_with_items(vec![
+        ResponseItem::FunctionCall {
+            id: None,
+            name: "do_it".to_string(),
+            arguments: "{}".to_string(),
+            call_id: "call-1".to_string(),
+        },
+        user_msg("between"),
+        ResponseItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text("ok".to_string()),
+        },
+        assistant_msg("latest"),
+    ]);
+
+    assert!(h.keep_within_token_budget(2, |_| 1));
+    assert_eq!(
+        h.raw_items(),
+        vec![user_msg("between"), assistant_msg("latest")]
+    );
+}
+
+#[test]
+fn search_matches_message_text_and_tool_output_ignoring_case() {
+    let history = create_history_with_items(vec![
+        user_input_text_msg("Where is the Parser defined?"),
+        ResponseItem::FunctionCall {
+            id: None,
+            name: "shell".to_string(),
+            arguments: "{\"command\":[\"rg\",\"parser\"]}".to_string(),
+            call_id: "call-1".to_string(),
+        },
+        ResponseItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text("src/lib.rs\nsrc/parser.rs".to_string()),
+        },
+        custom_tool_call_output("call-2", "no matches"),
+        assistant_msg("It lives in PARSER.rs."),
+    ]);
+
+    // Function call arguments are not searched.
+    assert_eq!(history.search("parser"), vec![0, 2, 4]);
+    assert_eq!(history.search("no MATCHES"), vec![3]);
+    assert_eq!(history.search("missing"), Vec::<usize>::new());
+    assert_eq!(history.search(""), Vec::<usize>::new());
+}
+
+#[test]
+fn search_snippet_returns_matching_line() {
+    let long_line = "parser ".repeat(40);
+    let history = create_history_with_items(vec![
+        ResponseItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text("src/lib.rs\n  src/parser.rs".to_string()),
+        },
+        assistant_msg(&long_line),
+    ]);
+
+    assert_eq!(
+        history.search_snippet(0, "Parser"),
+        Some("src/parser.rs".to_string())
+    );
+    assert_eq!(
+        history.search_snippet(0, "lib.rs\n  src"),
+        Some("src/lib.rs".to_string())
+    );
+    let snippet = history.search_snippet(1, "parser").expect("snippet");
+    assert_eq!(snippet.chars().count(), SEARCH_SNIPPET_MAX_CHARS + 1);
+    assert!(snippet.ends_with('…'));
+    assert_eq!(history.search_snippet(2, "parser"), None);
+}
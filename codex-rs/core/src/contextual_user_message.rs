@@ -13,6 +13,10 @@ pub(crate) const TURN_ABORTED_OPEN_TAG: &str = "<turn_aborted>";
 pub(crate) const TURN_ABORTED_CLOSE_TAG: &str = "</turn_aborted>";
 pub(crate) const SUBAGENT_NOTIFICATION_OPEN_TAG: &str = "<subagent_notification>";
 pub(crate) const SUBAGENT_NOTIFICATION_CLOSE_TAG: &str = "</subagent_notification>";
+pub(crate) const CONTEXT_FILE_OPEN_TAG: &str = "<context_file>";
+pub(crate) const CONTEXT_FILE_CLOSE_TAG: &str = "</context_file>";
+pub(crate) const ATTACHED_FILE_OPEN_TAG: &str = "<attached_file>";
+pub(crate) const ATTACHED_FILE_CLOSE_TAG: &str = "</attached_file>";
 
 #[derive(Clone, Copy)]
 pub(crate) struct ContextualUserFragmentDefinition {
@@ -84,6 +88,10 @@ pub(crate) const SUBAGENT_NOTIFICATION_FRAGMENT: ContextualUserFragmentDefinitio
         SUBAGENT_NOTIFICATION_OPEN_TAG,
         SUBAGENT_NOTIFICATION_CLOSE_TAG,
     );
+pub(crate) const CONTEXT_FILE_FRAGMENT: ContextualUserFragmentDefinition =
+    ContextualUserFragmentDefinition::new(CONTEXT_FILE_OPEN_TAG, CONTEXT_FILE_CLOSE_TAG);
+pub(crate) const ATTACHED_FILE_FRAGMENT: ContextualUserFragmentDefinition =
+    ContextualUserFragmentDefinition::new(ATTACHED_FILE_OPEN_TAG, ATTACHED_FILE_CLOSE_TAG);
 
 const CONTEXTUAL_USER_FRAGMENTS: &[ContextualUserFragmentDefinition] = &[
     AGENTS_MD_FRAGMENT,
@@ -92,6 +100,8 @@ const CONTEXTUAL_USER_FRAGMENTS: &[ContextualUserFragmentDefinition] = &[
     USER_SHELL_COMMAND_FRAGMENT,
     TURN_ABORTED_FRAGMENT,
     SUBAGENT_NOTIFICATION_FRAGMENT,
+    CONTEXT_FILE_FRAGMENT,
+    ATTACHED_FILE_FRAGMENT,
 ];
 
 pub(crate) fn is_contextual_user_fragment(content_item: &ContentItem) -> bool {
@@ -0,0 +1,221 @@
+//! Rendering of conversation history for `Op::ExportConversation`.
+
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ReasoningItemReasoningSummary;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::ExportFormat;
+
+use crate::event_mapping::is_contextual_user_message_content;
+
+const APPLY_PATCH_TOOL: &str = "apply_patch";
+
+/// Renders `items` in the requested format.
+///
+/// JSON exports keep every item, serialized the same way it is recorded to the
+/// rollout. Markdown exports only keep what a reader cares about: user and
+/// assistant messages plus tool calls and their outputs.
+pub(crate) fn render_conversation(
+    items: &[ResponseItem],
+    format: ExportFormat,
+) -> serde_json::Result<String> {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(items),
+        ExportFormat::Markdown => Ok(render_markdown(items)),
+    }
+}
+
+fn render_markdown(items: &[ResponseItem]) -> String {
+    let mut markdown = items
+        .iter()
+        .filter_map(markdown_section)
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Renders one history item as a Markdown section, or `None` for items a
+/// reader does not care about. Sections are meant to be joined with a blank
+/// line.
+///
+/// Messages become headed paragraphs, reasoning summaries blockquotes, and
+/// `apply_patch` calls `diff` code blocks. Other tool calls and their outputs
+/// are folded into `<details>` blocks.
+pub(crate) fn markdown_section(item: &ResponseItem) -> Option<String> {
+    match item {
+        ResponseItem::Message { role, content, .. } => {
+            let heading = match role.as_str() {
+                "user" if !is_contextual_user_message_content(content) => "User",
+                "assistant" => "Assistant",
+                _ => return None,
+            };
+            let text = message_text(content);
+            (!text.trim().is_empty()).then(|| format!("### {heading}\n\n{}", text.trim_end()))
+        }
+        ResponseItem::Reasoning {
+            summary, content, ..
+        } => {
+            let mut texts = summary
+                .iter()
+                .map(|ReasoningItemReasoningSummary::SummaryText { text }| text.as_str())
+                .collect::<Vec<_>>();
+            if texts.is_empty() {
+                texts = content
+                    .iter()
+                    .flatten()
+                    .map(|item| match item {
+                        ReasoningItemContent::ReasoningText { text }
+                        | ReasoningItemContent::Text { text } => text.as_str(),
+                    })
+                    .collect();
+            }
+            let text = texts.join("\n\n");
+            (!text.trim().is_empty()).then(|| blockquote(text.trim_end()))
+        }
+        ResponseItem::FunctionCall {
+            name, arguments, ..
+        } => Some(match apply_patch_input(name, arguments) {
+            Some(patch) => patch_section(&patch),
+            None => tool_call_section(name, "json", arguments),
+        }),
+        ResponseItem::CustomToolCall { name, input, .. } => Some(if name == APPLY_PATCH_TOOL {
+            patch_section(input)
+        } else {
+            tool_call_section(name, "", input)
+        }),
+        ResponseItem::LocalShellCall {
+            action: LocalShellAction::Exec(exec),
+            ..
+        } => Some(tool_call_section("shell", "sh", &exec.command.join(" "))),
+        ResponseItem::FunctionCallOutput { output, .. }
+        | ResponseItem::CustomToolCallOutput { output, .. } => Some(details_section(
+            "Tool output",
+            &fenced_block("", &tool_output_text(output)),
+        )),
+        ResponseItem::WebSearchCall { .. }
+        | ResponseItem::ImageGenerationCall { .. }
+        | ResponseItem::ProviderToolCall { .. }
+        | ResponseItem::GhostSnapshot { .. }
+        | ResponseItem::Compaction { .. }
+        | ResponseItem::Other => None,
+    }
+}
+
+fn message_text(content: &[ContentItem]) -> String {
+    content
+        .iter()
+        .filter_map(|item| match item {
+            ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                Some(text.as_str())
+            }
+            ContentItem::InputImage { .. } => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn tool_output_text(output: &FunctionCallOutputPayload) -> String {
+    output.body.to_text().unwrap_or_default()
+}
+
+fn tool_call_section(name: &str, info: &str, body: &str) -> String {
+    details_section(
+        &format!("Tool call: <code>{name}</code>"),
+        &fenced_block(info, body),
+    )
+}
+
+fn details_section(summary: &str, body: &str) -> String {
+    format!("<details>\n<summary>{summary}</summary>\n\n{body}\n\n</details>")
+}
+
+fn patch_section(patch: &str) -> String {
+    format!("**Patch:**\n\n{}", fenced_block("diff", patch))
+}
+
+fn blockquote(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The patch passed to a function-style `apply_patch` call.
+fn apply_patch_input(name: &str, arguments: &str) -> Option<String> {
+    if name != APPLY_PATCH_TOOL {
+        return None;
+    }
+    let arguments: serde_json::Value = serde_json::from_str(arguments).ok()?;
+    arguments.get("input")?.as_str().map(str::to_string)
+}
+
+/// Wraps `body` in a code fence longer than any backtick run it contains.
+fn fenced_block(info: &str, body: &str) -> String {
+    let longest_run = body.split(|ch| ch != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    format!("{fence}{info}\n{}\n{fence}", body.trim_end_matches('\n'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn message(role: &str, text: &str) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: role.to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+            end_turn: None,
+            phase: None,
+        }
+    }
+
+    #[test]
+    fn markdown_renders_messages_and_tool_calls() {
+        let items = vec![
+            message("developer", "system instructions"),
+            message("user", "list the files"),
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: r#"{"command":["ls"]}"#.to_string(),
+                call_id: "call-1".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload::from_text("a.txt\n```\n".to_string()),
+            },
+            message("assistant", "There is one file."),
+        ];
+
+        assert_eq!(
+            render_conversation(&items, ExportFormat::Markdown).expect("markdown export"),
+            "### User\n\nlist the files\n\n\
+<details>\n<summary>Tool call: <code>shell</code></summary>\n\n\
+```json\n{\"command\":[\"ls\"]}\n```\n\n</details>\n\n\
+<details>\n<summary>Tool output</summary>\n\n````\na.txt\n```\n````\n\n</details>\n\n\
+### Assistant\n\nThere is one file.\n"
+        );
+    }
+
+    #[test]
+    fn json_round_trips_items() {
+        let items = vec![message("user", "hi"), message("assistant", "hello")];
+
+        let json = render_conversation(&items, ExportFormat::Json).expect("json export");
+        let parsed: Vec<ResponseItem> = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(parsed, items);
+    }
+}
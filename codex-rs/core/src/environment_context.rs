@@ -7,6 +7,7 @@ use codex_protocol::protocol::TurnContextNetworkItem;
 use serde::Deserialize;
 use serde::Serialize;
 use std::path::PathBuf;
+use std::sync::LazyLock;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename = "environment_context", rename_all = "snake_case")]
@@ -17,6 +18,7 @@ pub(crate) struct EnvironmentContext {
     pub timezone: Option<String>,
     pub network: Option<NetworkContext>,
     pub subagents: Option<String>,
+    pub platform: Option<PlatformContext>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -25,6 +27,39 @@ pub(crate) struct NetworkContext {
     denied_domains: Vec<String>,
 }
 
+/// Host details that shape which commands and paths work, included when
+/// `include_platform_context` is enabled.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct PlatformContext {
+    os: String,
+    arch: String,
+    default_shell: String,
+    path_separator: String,
+}
+
+/// OS description and architecture, looked up once per process.
+static HOST_PLATFORM: LazyLock<(String, String)> = LazyLock::new(|| {
+    let info = os_info::get();
+    let os = format!("{} {}", info.os_type(), info.version());
+    let arch = info
+        .architecture()
+        .unwrap_or(std::env::consts::ARCH)
+        .to_string();
+    (os, arch)
+});
+
+impl PlatformContext {
+    pub fn for_shell(shell: &Shell) -> Self {
+        let (os, arch) = HOST_PLATFORM.clone();
+        Self {
+            os,
+            arch,
+            default_shell: shell.shell_path.to_string_lossy().into_owned(),
+            path_separator: std::path::MAIN_SEPARATOR.to_string(),
+        }
+    }
+}
+
 impl EnvironmentContext {
     pub fn new(
         cwd: Option<PathBuf>,
@@ -41,6 +76,7 @@ impl EnvironmentContext {
             timezone,
             network,
             subagents,
+            platform: None,
         }
     }
 
@@ -54,6 +90,7 @@ impl EnvironmentContext {
             timezone,
             network,
             subagents,
+            platform,
             shell: _,
         } = other;
         self.cwd == *cwd
@@ -61,6 +98,7 @@ impl EnvironmentContext {
             && self.timezone == *timezone
             && self.network == *network
             && self.subagents == *subagents
+            && self.platform == *platform
     }
 
     pub fn diff_from_turn_context_item(
@@ -114,6 +152,11 @@ impl EnvironmentContext {
         self
     }
 
+    pub fn with_platform(mut self, platform: PlatformContext) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+
     fn network_from_turn_context(turn_context: &TurnContext) -> Option<NetworkContext> {
         let network = turn_context
             .config
@@ -167,6 +210,20 @@ impl EnvironmentContext {
         if let Some(timezone) = self.timezone {
             lines.push(format!("  <timezone>{timezone}</timezone>"));
         }
+        if let Some(platform) = self.platform {
+            lines.push("  <platform>".to_string());
+            lines.push(format!("    <os>{}</os>", platform.os));
+            lines.push(format!("    <arch>{}</arch>", platform.arch));
+            lines.push(format!(
+                "    <default_shell>{}</default_shell>",
+                platform.default_shell
+            ));
+            lines.push(format!(
+                "    <path_separator>{}</path_separator>",
+                platform.path_separator
+            ));
+            lines.push("  </platform>".to_string());
+        }
         match self.network {
             Some(ref network) => {
                 lines.push("  <network enabled=\"true\">".to_string());
@@ -239,6 +296,43 @@ mod tests {
         assert_eq!(context.serialize_to_xml(), expected);
     }
 
+    #[test]
+    fn serialize_environment_context_with_platform() {
+        let shell = fake_shell();
+        let platform = PlatformContext {
+            os: "Ubuntu 24.04".to_string(),
+            arch: "x86_64".to_string(),
+            default_shell: "/bin/bash".to_string(),
+            path_separator: "/".to_string(),
+        };
+        let context = EnvironmentContext::new(
+            None,
+            shell.clone(),
+            Some("2026-02-26".to_string()),
+            None,
+            None,
+            None,
+        )
+        .with_platform(platform);
+
+        let expected = r#"<environment_context>
+  <shell>bash</shell>
+  <current_date>2026-02-26</current_date>
+  <platform>
+    <os>Ubuntu 24.04</os>
+    <arch>x86_64</arch>
+    <default_shell>/bin/bash</default_shell>
+    <path_separator>/</path_separator>
+  </platform>
+</environment_context>"#;
+
+        assert_eq!(context.serialize_to_xml(), expected);
+        assert_eq!(
+            PlatformContext::for_shell(&shell).default_shell,
+            "/bin/bash".to_string()
+        );
+    }
+
     #[test]
     fn serialize_environment_context_with_network() {
         let network = NetworkContext {
@@ -126,8 +126,14 @@ pub enum CodexErr {
     #[error("{0}")]
     ConnectionFailed(ConnectionFailedError),
 
-    #[error("Quota exceeded. Check your plan and billing details.")]
-    QuotaExceeded,
+    #[error(
+        "Quota exceeded. Check your plan and billing details.{}",
+        quota_reset_suffix(.reset_at)
+    )]
+    QuotaExceeded {
+        reset_at: Option<DateTime<Utc>>,
+        quota_type: QuotaType,
+    },
 
     #[error(
         "To use Codex with your ChatGPT plan, upgrade to Plus: https://chatgpt.com/explore/plus."
@@ -155,6 +161,16 @@ pub enum CodexErr {
     #[error("unsupported operation: {0}")]
     UnsupportedOperation(String),
 
+    /// Tool call arguments that do not match the tool's parameters. `field` is
+    /// the path of the offending value, or `.` when the arguments as a whole
+    /// could not be parsed.
+    #[error("failed to parse function arguments: `{field}` expected {expected}, got {got}")]
+    ParseError {
+        field: String,
+        expected: String,
+        got: String,
+    },
+
     #[error("{0}")]
     RefreshTokenFailed(RefreshTokenFailedError),
 
@@ -199,11 +215,12 @@ impl CodexErr {
             | CodexErr::EnvVar(_)
             | CodexErr::Fatal(_)
             | CodexErr::UsageNotIncluded
-            | CodexErr::QuotaExceeded
+            | CodexErr::QuotaExceeded { .. }
             | CodexErr::InvalidImageRequest()
             | CodexErr::InvalidRequest(_)
             | CodexErr::RefreshTokenFailed(_)
             | CodexErr::UnsupportedOperation(_)
+            | CodexErr::ParseError { .. }
             | CodexErr::Sandbox(_)
             | CodexErr::LandlockSandboxExecutableNotProvided
             | CodexErr::RetryLimit(_)
@@ -409,6 +426,15 @@ impl std::fmt::Display for RetryLimitReachedError {
     }
 }
 
+/// The quota a `CodexErr::QuotaExceeded` ran out of, as far as the rejected
+/// response tells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaType {
+    Requests,
+    Tokens,
+    Unknown,
+}
+
 #[derive(Debug)]
 pub struct UsageLimitReachedError {
     pub(crate) plan_type: Option<PlanType>,
@@ -487,6 +513,13 @@ fn retry_suffix(resets_at: Option<&DateTime<Utc>>) -> String {
     }
 }
 
+fn quota_reset_suffix(reset_at: &Option<DateTime<Utc>>) -> String {
+    reset_at
+        .as_ref()
+        .map(|reset_at| retry_suffix(Some(reset_at)))
+        .unwrap_or_default()
+}
+
 fn retry_suffix_after_or(resets_at: Option<&DateTime<Utc>>) -> String {
     if let Some(resets_at) = resets_at {
         let formatted = format_retry_timestamp(resets_at);
@@ -565,13 +598,80 @@ impl CodexErr {
         (self as &dyn std::any::Any).downcast_ref::<T>()
     }
 
+    /// Builds a `ParseError` from JSON tool arguments that failed to
+    /// deserialize at `path` (as formatted by `serde_path_to_error`), splitting
+    /// serde's message into what was expected and what was found.
+    pub fn from_json_arguments_error(path: &str, err: &serde_json::Error) -> Self {
+        let message = err.to_string();
+        let message = message
+            .strip_suffix(&format!(" at line {} column {}", err.line(), err.column()))
+            .unwrap_or(&message);
+        let child_field = |name: &str| {
+            if path == "." {
+                name.to_string()
+            } else {
+                format!("{path}.{name}")
+            }
+        };
+
+        let (field, expected, got) = if !err.is_data() {
+            (
+                path.to_string(),
+                "valid JSON".to_string(),
+                message.to_string(),
+            )
+        } else if let Some(name) = message
+            .strip_prefix("missing field `")
+            .and_then(|rest| rest.strip_suffix('`'))
+        {
+            (
+                child_field(name),
+                "a value".to_string(),
+                "nothing".to_string(),
+            )
+        } else if let Some((found, expected)) = message.split_once(", expected ") {
+            if let Some(name) = found
+                .strip_prefix("unknown field `")
+                .and_then(|rest| rest.strip_suffix('`'))
+            {
+                (
+                    child_field(name),
+                    expected.to_string(),
+                    "an unknown field".to_string(),
+                )
+            } else {
+                let found = found
+                    .strip_prefix("invalid type: ")
+                    .or_else(|| found.strip_prefix("invalid value: "))
+                    .or_else(|| found.strip_prefix("invalid "))
+                    .or_else(|| found.strip_prefix("unknown "))
+                    .unwrap_or(found);
+                (path.to_string(), expected.to_string(), found.to_string())
+            }
+        } else {
+            (
+                path.to_string(),
+                "a valid value".to_string(),
+                message.to_string(),
+            )
+        };
+        CodexErr::ParseError {
+            field,
+            expected,
+            got,
+        }
+    }
+
     /// Translate core error to client-facing protocol error.
     pub fn to_codex_protocol_error(&self) -> CodexErrorInfo {
         match self {
             CodexErr::ContextWindowExceeded => CodexErrorInfo::ContextWindowExceeded,
-            CodexErr::UsageLimitReached(_)
-            | CodexErr::QuotaExceeded
-            | CodexErr::UsageNotIncluded => CodexErrorInfo::UsageLimitExceeded,
+            CodexErr::UsageLimitReached(_) | CodexErr::UsageNotIncluded => {
+                CodexErrorInfo::UsageLimitExceeded
+            }
+            CodexErr::QuotaExceeded { reset_at, .. } => CodexErrorInfo::QuotaExceeded {
+                resets_at: reset_at.map(|reset_at| reset_at.timestamp()),
+            },
             CodexErr::ServerOverloaded => CodexErrorInfo::ServerOverloaded,
             CodexErr::RetryLimit(_) => CodexErrorInfo::ResponseTooManyFailedAttempts {
                 http_status_code: self.http_status_code_value(),
@@ -587,6 +687,7 @@ impl CodexErr {
             | CodexErr::InternalServerError
             | CodexErr::InternalAgentDied => CodexErrorInfo::InternalServerError,
             CodexErr::UnsupportedOperation(_)
+            | CodexErr::ParseError { .. }
             | CodexErr::ThreadNotFound(_)
             | CodexErr::AgentLimitReached { .. } => CodexErrorInfo::BadRequest,
             CodexErr::Sandbox(_) => CodexErrorInfo::SandboxError,
@@ -1143,4 +1244,64 @@ mod tests {
             assert_eq!(err.to_string(), expected);
         });
     }
+
+    fn parse_error(arguments: &str) -> CodexErr {
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Args {
+            command: Vec<String>,
+            timeout_ms: Option<u64>,
+        }
+
+        let mut deserializer = serde_json::Deserializer::from_str(arguments);
+        let err = serde_path_to_error::deserialize::<_, Args>(&mut deserializer)
+            .expect_err("arguments should not parse");
+        CodexErr::from_json_arguments_error(&err.path().to_string(), err.inner())
+    }
+
+    fn parse_error_fields(err: CodexErr) -> (String, String, String) {
+        match err {
+            CodexErr::ParseError {
+                field,
+                expected,
+                got,
+            } => (field, expected, got),
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_error_reports_path_expected_and_got() {
+        assert_eq!(
+            parse_error_fields(parse_error(r#"{"command": ["ls", 1]}"#)),
+            (
+                "command[1]".to_string(),
+                "a string".to_string(),
+                "integer `1`".to_string(),
+            )
+        );
+        assert_eq!(
+            parse_error_fields(parse_error(r#"{"command": ["ls"], "timeout_ms": "soon"}"#)),
+            (
+                "timeout_ms".to_string(),
+                "u64".to_string(),
+                r#"string "soon""#.to_string(),
+            )
+        );
+    }
+
+    #[test]
+    fn parse_error_reports_missing_field_and_malformed_json() {
+        assert_eq!(
+            parse_error_fields(parse_error("{}")),
+            (
+                "command".to_string(),
+                "a value".to_string(),
+                "nothing".to_string(),
+            )
+        );
+
+        let (field, expected, _) = parse_error_fields(parse_error("not json"));
+        assert_eq!((field.as_str(), expected.as_str()), (".", "valid JSON"));
+    }
 }
@@ -16,6 +16,7 @@ use tokio::io::BufReader;
 use tokio::process::Child;
 use tokio_util::sync::CancellationToken;
 
+use crate::command_canonicalization::canonicalize_command_for_approval;
 use crate::error::CodexErr;
 use crate::error::Result;
 use crate::error::SandboxErr;
@@ -63,6 +64,9 @@ const EXEC_OUTPUT_MAX_BYTES: usize = DEFAULT_OUTPUT_BYTES_CAP;
 /// Aggregation still collects full output; only the live event stream is capped.
 pub(crate) const MAX_EXEC_OUTPUT_DELTAS_PER_CALL: usize = 10_000;
 
+/// Largest `ExecParams::stdin` payload accepted for a single exec call.
+pub const EXEC_STDIN_MAX_BYTES: usize = 1024 * 1024;
+
 // Wait for the stdout/stderr collection tasks but guard against them
 // hanging forever. In the normal case, both pipes are closed once the child
 // terminates so the tasks exit quickly. However, if the child process
@@ -83,6 +87,9 @@ pub struct ExecParams {
     pub windows_sandbox_level: codex_protocol::config_types::WindowsSandboxLevel,
     pub justification: Option<String>,
     pub arg0: Option<String>,
+    /// Text written to the child's stdin before it is closed. Limited to
+    /// `EXEC_STDIN_MAX_BYTES`.
+    pub stdin: Option<String>,
 }
 
 fn select_process_exec_tool_sandbox_type(
@@ -233,6 +240,7 @@ pub fn build_exec_request(
         windows_sandbox_level,
         justification,
         arg0: _,
+        stdin,
     } = params;
     if let Some(network) = network.as_ref() {
         network.apply_to_env(&mut env);
@@ -256,7 +264,7 @@ pub fn build_exec_request(
     };
 
     let manager = SandboxManager::new();
-    let exec_req = manager
+    let mut exec_req = manager
         .transform(crate::sandboxing::SandboxTransformRequest {
             spec,
             policy: sandbox_policy,
@@ -273,6 +281,7 @@ pub fn build_exec_request(
             windows_sandbox_level,
         })
         .map_err(CodexErr::from)?;
+    exec_req.stdin = stdin;
     Ok(exec_req)
 }
 
@@ -296,6 +305,7 @@ pub(crate) async fn execute_exec_request(
         network_sandbox_policy,
         justification,
         arg0,
+        stdin,
     } = exec_request;
     let _ = _sandbox_policy_from_env;
 
@@ -309,6 +319,7 @@ pub(crate) async fn execute_exec_request(
         windows_sandbox_level,
         justification,
         arg0,
+        stdin,
     };
 
     let start = Instant::now();
@@ -646,6 +657,48 @@ pub(crate) fn is_likely_sandbox_denied(
     false
 }
 
+/// Key in `acceptable_exit_codes` whose codes apply to every command.
+pub(crate) const ANY_COMMAND_EXIT_CODES_KEY: &str = "*";
+
+/// Whether `exit_code` counts as success for `command` given the configured
+/// `acceptable_exit_codes`. Zero always does. Per-program entries match the
+/// program's file name for plain commands, including a single plain command
+/// wrapped in `bash -lc`; more complex scripts only honor the `"*"` entry.
+pub(crate) fn is_acceptable_exit_code(
+    acceptable_exit_codes: &HashMap<String, Vec<i32>>,
+    command: &[String],
+    exit_code: i32,
+) -> bool {
+    exit_code == 0
+        || acceptable_exit_codes
+            .get(ANY_COMMAND_EXIT_CODES_KEY)
+            .is_some_and(|codes| codes.contains(&exit_code))
+        || is_acceptable_program_exit_code(acceptable_exit_codes, command, exit_code)
+}
+
+/// Like [`is_acceptable_exit_code`], but only honors the entry for the
+/// command's own program and ignores the `"*"` entry. Used for likely sandbox
+/// denials, where a catch-all entry must not mask the denial.
+pub(crate) fn is_acceptable_program_exit_code(
+    acceptable_exit_codes: &HashMap<String, Vec<i32>>,
+    command: &[String],
+    exit_code: i32,
+) -> bool {
+    if exit_code == 0 {
+        return true;
+    }
+    let accepts = |key: &str| {
+        acceptable_exit_codes
+            .get(key)
+            .is_some_and(|codes| codes.contains(&exit_code))
+    };
+    canonicalize_command_for_approval(command)
+        .first()
+        .and_then(|program| Path::new(program).file_name())
+        .and_then(|name| name.to_str())
+        .is_some_and(accepts)
+}
+
 #[derive(Debug, Clone)]
 pub struct StreamOutput<T: Clone> {
     pub text: T,
@@ -755,6 +808,17 @@ async fn exec(
     stdout_stream: Option<StdoutStream>,
     after_spawn: Option<Box<dyn FnOnce() + Send>>,
 ) -> Result<RawExecToolCallOutput> {
+    if let Some(stdin) = params.stdin.as_ref()
+        && stdin.len() > EXEC_STDIN_MAX_BYTES
+    {
+        return Err(CodexErr::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "stdin is {} bytes, which exceeds the {EXEC_STDIN_MAX_BYTES} byte limit",
+                stdin.len()
+            ),
+        )));
+    }
     #[cfg(target_os = "windows")]
     if sandbox == SandboxType::WindowsRestrictedToken {
         if let Some(reason) = unsupported_windows_restricted_token_sandbox_reason(
@@ -765,6 +829,11 @@ async fn exec(
         ) {
             return Err(CodexErr::Io(io::Error::other(reason)));
         }
+        if params.stdin.is_some() {
+            return Err(CodexErr::Io(io::Error::other(
+                "windows sandbox does not support stdin input",
+            )));
+        }
         return exec_windows_sandbox(params, sandbox_policy).await;
     }
     let ExecParams {
@@ -775,6 +844,7 @@ async fn exec(
         arg0,
         expiration,
         windows_sandbox_level: _,
+        stdin,
         ..
     } = params;
     if let Some(network) = network.as_ref() {
@@ -788,7 +858,7 @@ async fn exec(
         ))
     })?;
     let arg0_ref = arg0.as_deref();
-    let child = spawn_child_async(SpawnChildRequest {
+    let mut child = spawn_child_async(SpawnChildRequest {
         program: PathBuf::from(program),
         args: args.into(),
         arg0: arg0_ref,
@@ -798,13 +868,31 @@ async fn exec(
         // apply_to_env_for_attempt above. Passing network here would reapply
         // non-attempt proxy vars and drop attempt correlation metadata.
         network: None,
-        stdio_policy: StdioPolicy::RedirectForShellTool,
+        stdio_policy: if stdin.is_some() {
+            StdioPolicy::RedirectForShellToolWithStdin
+        } else {
+            StdioPolicy::RedirectForShellTool
+        },
         env,
     })
     .await?;
     if let Some(after_spawn) = after_spawn {
         after_spawn();
     }
+    if let Some(input) = stdin
+        && let Some(mut child_stdin) = child.stdin.take()
+    {
+        // Write from a separate task so a child that fills its stdout pipe
+        // before draining stdin cannot deadlock us; dropping the handle
+        // closes stdin so the child sees EOF.
+        tokio::spawn(async move {
+            use tokio::io::AsyncWriteExt;
+
+            if let Err(err) = child_stdin.write_all(input.as_bytes()).await {
+                tracing::debug!("failed to write exec stdin: {err}");
+            }
+        });
+    }
     consume_truncated_output(child, expiration, stdout_stream).await
 }
 
@@ -1025,6 +1113,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn acceptable_exit_codes_match_program_name() {
+        let acceptable = HashMap::from([("diff".to_string(), vec![1])]);
+        let argv = |args: &[&str]| args.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert!(is_acceptable_exit_code(&acceptable, &argv(&["ls"]), 0));
+        assert!(is_acceptable_exit_code(
+            &acceptable,
+            &argv(&["/usr/bin/diff", "a", "b"]),
+            1
+        ));
+        assert!(is_acceptable_exit_code(
+            &acceptable,
+            &argv(&["bash", "-lc", "diff a b"]),
+            1
+        ));
+        assert!(!is_acceptable_exit_code(
+            &acceptable,
+            &argv(&["bash", "-lc", "diff a b"]),
+            2
+        ));
+        assert!(!is_acceptable_exit_code(
+            &acceptable,
+            &argv(&["grep", "x", "file"]),
+            1
+        ));
+
+        let any = HashMap::from([(ANY_COMMAND_EXIT_CODES_KEY.to_string(), vec![3])]);
+        assert!(is_acceptable_exit_code(
+            &any,
+            &argv(&["bash", "-lc", "make || true"]),
+            3
+        ));
+        assert!(!is_acceptable_program_exit_code(
+            &any,
+            &argv(&["bash", "-lc", "make || true"]),
+            3
+        ));
+    }
+
     #[test]
     fn sandbox_detection_requires_keywords() {
         let output = make_exec_output(1, "", "", "");
@@ -1327,6 +1455,7 @@ mod tests {
             windows_sandbox_level: codex_protocol::config_types::WindowsSandboxLevel::Disabled,
             justification: None,
             arg0: None,
+            stdin: None,
         };
 
         let output = exec(
@@ -1383,6 +1512,7 @@ mod tests {
             windows_sandbox_level: codex_protocol::config_types::WindowsSandboxLevel::Disabled,
             justification: None,
             arg0: None,
+            stdin: None,
         };
         tokio::spawn(async move {
             tokio::time::sleep(Duration::from_millis(1_000)).await;
@@ -1408,6 +1538,51 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn process_exec_tool_call_writes_stdin_to_child() -> Result<()> {
+        let cwd = std::env::current_dir()?;
+        let stdin_params = |stdin: String| ExecParams {
+            command: vec!["cat".to_string()],
+            cwd: cwd.clone(),
+            expiration: 10_000.into(),
+            env: std::env::vars().collect(),
+            network: None,
+            sandbox_permissions: SandboxPermissions::UseDefault,
+            windows_sandbox_level: codex_protocol::config_types::WindowsSandboxLevel::Disabled,
+            justification: None,
+            arg0: None,
+            stdin: Some(stdin),
+        };
+        let run = |params: ExecParams| {
+            let cwd = cwd.clone();
+            async move {
+                process_exec_tool_call(
+                    params,
+                    &SandboxPolicy::DangerFullAccess,
+                    &FileSystemSandboxPolicy::from(&SandboxPolicy::DangerFullAccess),
+                    NetworkSandboxPolicy::Enabled,
+                    cwd.as_path(),
+                    &None,
+                    false,
+                    None,
+                )
+                .await
+            }
+        };
+
+        let output = run(stdin_params("hello from stdin\n".to_string())).await?;
+        assert_eq!(output.exit_code, 0);
+        assert_eq!(output.stdout.text, "hello from stdin\n");
+
+        let oversized = "x".repeat(EXEC_STDIN_MAX_BYTES + 1);
+        let Err(CodexErr::Io(err)) = run(stdin_params(oversized)).await else {
+            panic!("expected oversized stdin to be rejected");
+        };
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        Ok(())
+    }
+
     #[cfg(unix)]
     fn long_running_command() -> Vec<String> {
         vec![
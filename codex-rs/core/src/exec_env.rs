@@ -3,7 +3,6 @@ use crate::config::types::ShellEnvironmentPolicy;
 use crate::config::types::ShellEnvironmentPolicyInherit;
 use codex_protocol::ThreadId;
 use std::collections::HashMap;
-use std::collections::HashSet;
 
 pub const CODEX_THREAD_ID_ENV_VAR: &str = "CODEX_THREAD_ID";
 
@@ -34,25 +33,21 @@ where
 {
     // Step 1 – determine the starting set of variables based on the
     // `inherit` strategy.
-    let mut env_map: HashMap<String, String> = match policy.inherit {
+    let mut env_map: HashMap<String, String> = match &policy.inherit {
         ShellEnvironmentPolicyInherit::All => vars.into_iter().collect(),
         ShellEnvironmentPolicyInherit::None => HashMap::new(),
         ShellEnvironmentPolicyInherit::Core => {
             const CORE_VARS: &[&str] = &[
                 "HOME", "LOGNAME", "PATH", "SHELL", "USER", "USERNAME", "TMPDIR", "TEMP", "TMP",
             ];
-            let allow: HashSet<&str> = CORE_VARS.iter().copied().collect();
-            let is_core_var = |name: &str| {
-                if cfg!(target_os = "windows") {
-                    CORE_VARS
-                        .iter()
-                        .any(|allowed| allowed.eq_ignore_ascii_case(name))
-                } else {
-                    allow.contains(name)
-                }
-            };
-            vars.into_iter().filter(|(k, _)| is_core_var(k)).collect()
+            vars.into_iter()
+                .filter(|(k, _)| is_listed_var(CORE_VARS.iter().copied(), k))
+                .collect()
         }
+        ShellEnvironmentPolicyInherit::AllowList { vars: allowed } => vars
+            .into_iter()
+            .filter(|(k, _)| is_listed_var(allowed.iter().map(String::as_str), k))
+            .collect(),
     };
 
     // Internal helper – does `name` match **any** pattern in `patterns`?
@@ -93,6 +88,35 @@ where
     env_map
 }
 
+/// Names in the `allow_list` of `policy` that are not set in this process's
+/// environment, so they would silently be missing from spawned commands.
+pub(crate) fn missing_allow_listed_vars(policy: &ShellEnvironmentPolicy) -> Vec<String> {
+    let ShellEnvironmentPolicyInherit::AllowList { vars } = &policy.inherit else {
+        return Vec::new();
+    };
+    let present: Vec<String> = std::env::vars_os()
+        .filter_map(|(name, _)| name.into_string().ok())
+        .collect();
+    vars.iter()
+        .filter(|name| !present.iter().any(|present| names_match(present, name)))
+        .cloned()
+        .collect()
+}
+
+/// Whether `name` is one of `listed`. Windows variable names are
+/// case-insensitive.
+fn is_listed_var<'a>(mut listed: impl Iterator<Item = &'a str>, name: &str) -> bool {
+    listed.any(|listed| names_match(listed, name))
+}
+
+fn names_match(a: &str, b: &str) -> bool {
+    if cfg!(target_os = "windows") {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,4 +334,47 @@ mod tests {
         expected.insert(CODEX_THREAD_ID_ENV_VAR.to_string(), thread_id.to_string());
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_allow_list_inherits_only_listed_vars() {
+        let vars = make_vars(&[
+            ("PATH", "/usr/bin"),
+            ("HOME", "/home/user"),
+            ("EDITOR", "vim"),
+        ]);
+
+        let policy = ShellEnvironmentPolicy {
+            inherit: ShellEnvironmentPolicyInherit::AllowList {
+                vars: vec![
+                    "PATH".to_string(),
+                    "HOME".to_string(),
+                    "MISSING".to_string(),
+                ],
+            },
+            ..Default::default()
+        };
+
+        let result = populate_env(vars, &policy, None);
+        let expected: HashMap<String, String> = hashmap! {
+            "PATH".to_string() => "/usr/bin".to_string(),
+            "HOME".to_string() => "/home/user".to_string(),
+        };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn allow_list_parses_from_toml() {
+        let policy: ShellEnvironmentPolicy = toml::from_str::<
+            crate::config::types::ShellEnvironmentPolicyToml,
+        >(r#"allow_list = ["PATH", "HOME"]"#)
+        .expect("allow_list should parse")
+        .into();
+
+        assert_eq!(
+            policy.inherit,
+            ShellEnvironmentPolicyInherit::AllowList {
+                vars: vec!["PATH".to_string(), "HOME".to_string()],
+            }
+        );
+    }
 }
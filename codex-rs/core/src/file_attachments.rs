@@ -0,0 +1,193 @@
+//! Attaches the local files a user message mentions by path to the turn when
+//! `[auto_attach_files]` is configured, so the model sees their contents
+//! without shelling out to read them.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use codex_protocol::models::ResponseItem;
+use codex_protocol::user_input::UserInput;
+use regex_lite::Regex;
+use tokio::io::AsyncReadExt;
+use tracing::warn;
+
+use crate::codex::TurnContext;
+use crate::config::types::AutoAttachFiles;
+use crate::contextual_user_message::ATTACHED_FILE_FRAGMENT;
+
+/// Files attached to a single turn at most; further mentions are ignored.
+const MAX_ATTACHED_FILES_PER_TURN: usize = 8;
+
+/// Appended to a file's contents when they were cut at `max_bytes`.
+const TRUNCATED_MARKER: &str = "\n[truncated]";
+
+#[derive(Debug, Default)]
+pub(crate) struct FileAttachments {
+    pub(crate) items: Vec<ResponseItem>,
+    pub(crate) warnings: Vec<String>,
+}
+
+/// Builds one `<attached_file>` item for every existing text file inside the
+/// session roots that `input` mentions. Returns nothing unless
+/// `auto_attach_files` is configured.
+pub(crate) async fn build_file_attachments(
+    input: &[UserInput],
+    turn_context: &TurnContext,
+) -> FileAttachments {
+    let mut attachments = FileAttachments::default();
+    let Some(config) = turn_context.config.auto_attach_files.as_ref() else {
+        return attachments;
+    };
+
+    let patterns = compile_patterns(config, &mut attachments.warnings);
+    let mut seen = HashSet::new();
+    let mentions = input.iter().flat_map(|item| match item {
+        UserInput::Text { text, .. } => mentioned_paths(text, &patterns),
+        _ => Vec::new(),
+    });
+    for mention in mentions {
+        let path = turn_context.resolve_path(Some(mention));
+        if !path.is_file() || !turn_context.is_within_roots(&path) || !seen.insert(path.clone()) {
+            continue;
+        }
+        if attachments.items.len() == MAX_ATTACHED_FILES_PER_TURN {
+            attachments.warnings.push(format!(
+                "Only the first {MAX_ATTACHED_FILES_PER_TURN} mentioned files were attached to this turn."
+            ));
+            break;
+        }
+        match read_text_file(&path, config.max_bytes).await {
+            Ok(Some(contents)) => attachments.items.push(attached_file_item(&path, &contents)),
+            Ok(None) => {}
+            Err(err) => warn!("failed to attach {}: {err}", path.display()),
+        }
+    }
+    attachments
+}
+
+fn compile_patterns(config: &AutoAttachFiles, warnings: &mut Vec<String>) -> Vec<Regex> {
+    config
+        .patterns
+        .iter()
+        .filter_map(|pattern| match Regex::new(pattern) {
+            Ok(regex) => Some(regex),
+            Err(err) => {
+                warnings.push(format!(
+                    "Ignoring invalid auto_attach_files pattern `{pattern}`: {err}"
+                ));
+                None
+            }
+        })
+        .collect()
+}
+
+/// Paths mentioned in `text`, in the order the patterns find them. Trailing
+/// sentence punctuation is not part of a path.
+fn mentioned_paths(text: &str, patterns: &[Regex]) -> Vec<String> {
+    patterns
+        .iter()
+        .flat_map(|pattern| pattern.captures_iter(text))
+        .filter_map(|captures| captures.get(1).or_else(|| captures.get(0)))
+        .map(|mention| {
+            mention
+                .as_str()
+                .trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '"', '\''])
+                .to_string()
+        })
+        .filter(|mention| !mention.is_empty())
+        .collect()
+}
+
+/// Reads at most `max_bytes` of `path`. Returns `Ok(None)` for files that are
+/// not UTF-8 text.
+async fn read_text_file(path: &Path, max_bytes: usize) -> std::io::Result<Option<String>> {
+    let file = tokio::fs::File::open(path).await?;
+    let size = file.metadata().await?.len();
+    let mut data = Vec::new();
+    file.take(max_bytes as u64).read_to_end(&mut data).await?;
+    let truncated = size > max_bytes as u64;
+
+    let valid_len = match std::str::from_utf8(&data) {
+        Ok(_) => data.len(),
+        // The cut may land inside a multi-byte character.
+        Err(err) if truncated && err.error_len().is_none() => err.valid_up_to(),
+        Err(_) => return Ok(None),
+    };
+    data.truncate(valid_len);
+    if data.contains(&0) {
+        return Ok(None);
+    }
+    Ok(String::from_utf8(data).ok().map(|mut text| {
+        if truncated {
+            text.push_str(TRUNCATED_MARKER);
+        }
+        text
+    }))
+}
+
+fn attached_file_item(path: &Path, contents: &str) -> ResponseItem {
+    ATTACHED_FILE_FRAGMENT.into_message(
+        ATTACHED_FILE_FRAGMENT.wrap(format!("path: {}\n\n{contents}", path.display())),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::AutoAttachFilesToml;
+    use pretty_assertions::assert_eq;
+
+    fn default_patterns() -> Vec<Regex> {
+        let config = AutoAttachFiles::from(AutoAttachFilesToml::default());
+        compile_patterns(&config, &mut Vec::new())
+    }
+
+    #[test]
+    fn finds_at_mentions_and_backticked_paths() {
+        assert_eq!(
+            mentioned_paths(
+                "Compare @src/lib.rs with `docs/notes.md`, then email me@.",
+                &default_patterns()
+            ),
+            vec!["src/lib.rs".to_string(), "docs/notes.md".to_string()]
+        );
+    }
+
+    #[test]
+    fn invalid_patterns_are_reported() {
+        let config = AutoAttachFiles {
+            patterns: vec!["(".to_string(), r"see (\S+)".to_string()],
+            max_bytes: 10,
+        };
+        let mut warnings = Vec::new();
+        let patterns = compile_patterns(&config, &mut warnings);
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            mentioned_paths("see Cargo.toml.", &patterns),
+            vec!["Cargo.toml".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_text_files_up_to_the_cap() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let text = dir.path().join("notes.txt");
+        std::fs::write(&text, "hello world").expect("write text");
+        let binary = dir.path().join("blob.bin");
+        std::fs::write(&binary, [0xff, 0x00, 0x01]).expect("write binary");
+
+        assert_eq!(
+            read_text_file(&text, 5).await.expect("read text"),
+            Some(format!("hello{TRUNCATED_MARKER}"))
+        );
+        assert_eq!(
+            read_text_file(&text, 64).await.expect("read text"),
+            Some("hello world".to_string())
+        );
+        assert_eq!(
+            read_text_file(&binary, 64).await.expect("read binary"),
+            None
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::path::Path;
@@ -6,10 +8,14 @@ use std::path::PathBuf;
 
 use crate::util::resolve_path;
 use codex_app_server_protocol::GitSha;
+use codex_protocol::protocol::BlameLine;
+use codex_protocol::protocol::BlameLineRange;
 use codex_protocol::protocol::GitInfo;
 use futures::future::join_all;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
 use tokio::process::Command;
 use tokio::time::Duration as TokioDuration;
 use tokio::time::timeout;
@@ -151,6 +157,114 @@ pub async fn get_has_changes(cwd: &Path) -> Option<bool> {
     Some(!output.stdout.is_empty())
 }
 
+/// Returns the entries of `paths` that git ignores in the repository containing
+/// `cwd`. Tracked files are not reported even when a pattern matches them, as
+/// their changes still show up in `git status`. Returns an empty list outside a
+/// git repository or when git fails.
+pub async fn git_ignored_paths(cwd: &Path, paths: &[PathBuf]) -> Vec<PathBuf> {
+    if paths.is_empty() {
+        return Vec::new();
+    }
+    let path_args: Vec<String> = paths
+        .iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+    let mut args = vec!["check-ignore", "--"];
+    args.extend(path_args.iter().map(String::as_str));
+    let Some(output) = run_git_command_with_timeout(&args, cwd).await else {
+        return Vec::new();
+    };
+    // `check-ignore` exits with 1 when none of the paths are ignored.
+    if !output.status.success() {
+        return Vec::new();
+    }
+    let Ok(stdout) = String::from_utf8(output.stdout) else {
+        return Vec::new();
+    };
+    let ignored: HashSet<&str> = stdout.lines().collect();
+    paths
+        .iter()
+        .zip(&path_args)
+        .filter(|(_, arg)| ignored.contains(arg.as_str()))
+        .map(|(path, _)| path.clone())
+        .collect()
+}
+
+/// Content hash of the files git considers part of a working tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingTreeHash {
+    pub hash: String,
+    pub file_count: usize,
+}
+
+/// Why `hash_working_tree` could not hash the working tree.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum WorkingTreeHashError {
+    #[error("{} is not inside a git repository", .0.display())]
+    NotARepository(PathBuf),
+    #[error("git ls-files failed: {0}")]
+    Failed(String),
+}
+
+/// Hashes the tracked files and the untracked, non-ignored files under `cwd`,
+/// limited to `paths` when given. Each file contributes its path relative to
+/// `cwd`, its length, and its content, in path order, so the result only
+/// depends on what is on disk.
+pub async fn hash_working_tree(
+    cwd: &Path,
+    paths: Option<&[PathBuf]>,
+) -> Result<WorkingTreeHash, WorkingTreeHashError> {
+    if get_git_repo_root(cwd).is_none() {
+        return Err(WorkingTreeHashError::NotARepository(cwd.to_path_buf()));
+    }
+    let path_args: Vec<String> = paths
+        .unwrap_or_default()
+        .iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+    let mut args = vec![
+        "ls-files",
+        "-z",
+        "--cached",
+        "--others",
+        "--exclude-standard",
+        "--",
+    ];
+    args.extend(path_args.iter().map(String::as_str));
+    let Some(output) = run_git_command_with_timeout(&args, cwd).await else {
+        return Err(WorkingTreeHashError::Failed(
+            "git ls-files timed out".to_string(),
+        ));
+    };
+    if !output.status.success() {
+        return Err(WorkingTreeHashError::Failed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    let stdout = String::from_utf8(output.stdout).map_err(|_| {
+        WorkingTreeHashError::Failed("git ls-files listed a non-UTF-8 path".to_string())
+    })?;
+    let files: BTreeSet<&str> = stdout.split('\0').filter(|file| !file.is_empty()).collect();
+
+    let mut hasher = Sha256::new();
+    let mut file_count = 0;
+    for file in files {
+        // Tracked files deleted from the working tree are still listed.
+        let Ok(contents) = tokio::fs::read(cwd.join(file)).await else {
+            continue;
+        };
+        hasher.update(file.as_bytes());
+        hasher.update([0]);
+        hasher.update((contents.len() as u64).to_le_bytes());
+        hasher.update(&contents);
+        file_count += 1;
+    }
+    Ok(WorkingTreeHash {
+        hash: format!("{:x}", hasher.finalize()),
+        file_count,
+    })
+}
+
 fn parse_git_remote_urls(stdout: &str) -> Option<BTreeMap<String, String>> {
     let mut remotes = BTreeMap::new();
     for line in stdout.lines() {
@@ -237,6 +351,116 @@ pub async fn recent_commits(cwd: &Path, limit: usize) -> Vec<CommitLogEntry> {
     entries
 }
 
+/// Why `blame_file` could not produce blame for a file.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BlameError {
+    #[error("{} is not inside a git repository", .0.display())]
+    NotARepository(PathBuf),
+    #[error("{} is not tracked by git", .0.display())]
+    Untracked(PathBuf),
+    #[error("git blame failed: {0}")]
+    Failed(String),
+}
+
+/// Run `git blame` on `path` (relative to `cwd`), limited to the inclusive
+/// 1-based `line_range` when given.
+pub async fn blame_file(
+    cwd: &Path,
+    path: &PathBuf,
+    line_range: Option<BlameLineRange>,
+) -> Result<Vec<BlameLine>, BlameError> {
+    let path = resolve_path(cwd, path);
+    // Run git from the file's directory so files in nested repositories
+    // resolve against their own repository.
+    let dir = path.parent().unwrap_or(cwd);
+    if get_git_repo_root(dir).is_none() {
+        return Err(BlameError::NotARepository(path));
+    }
+    let path_arg = path.to_string_lossy().into_owned();
+    let tracked = run_git_command_with_timeout(
+        &["ls-files", "--error-unmatch", "--", path_arg.as_str()],
+        dir,
+    )
+    .await
+    .is_some_and(|out| out.status.success());
+    if !tracked {
+        return Err(BlameError::Untracked(path));
+    }
+
+    let range_arg = line_range.map(|range| format!("{},{}", range.start, range.end));
+    let mut args = vec!["blame", "--porcelain"];
+    if let Some(range) = &range_arg {
+        args.extend(["-L", range.as_str()]);
+    }
+    args.extend(["--", path_arg.as_str()]);
+    let Some(out) = run_git_command_with_timeout(&args, dir).await else {
+        return Err(BlameError::Failed("git blame timed out".to_string()));
+    };
+    if !out.status.success() {
+        return Err(BlameError::Failed(
+            String::from_utf8_lossy(&out.stderr).trim().to_string(),
+        ));
+    }
+    Ok(parse_blame_porcelain(&String::from_utf8_lossy(&out.stdout)))
+}
+
+/// Parse `git blame --porcelain` output. Commit details are only printed the
+/// first time a commit appears, so they are remembered per SHA.
+fn parse_blame_porcelain(output: &str) -> Vec<BlameLine> {
+    let mut commits: HashMap<String, BlameLine> = HashMap::new();
+    let mut current: Option<(String, u32)> = None;
+    let mut lines = Vec::new();
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            if let Some((commit, line_number)) = current.take()
+                && let Some(details) = commits.get(&commit)
+            {
+                lines.push(BlameLine {
+                    line_number,
+                    content: content.to_string(),
+                    ..details.clone()
+                });
+            }
+            continue;
+        }
+        let mut parts = line.split(' ');
+        let first = parts.next().unwrap_or_default();
+        if first.len() >= 40 && first.chars().all(|c| c.is_ascii_hexdigit()) {
+            let line_number = parts.nth(1).and_then(|n| n.parse().ok()).unwrap_or(0);
+            commits
+                .entry(first.to_string())
+                .or_insert_with(|| BlameLine {
+                    line_number: 0,
+                    commit: first.to_string(),
+                    author: String::new(),
+                    author_email: String::new(),
+                    timestamp: 0,
+                    summary: String::new(),
+                    content: String::new(),
+                });
+            current = Some((first.to_string(), line_number));
+            continue;
+        }
+        let Some(details) = current
+            .as_ref()
+            .and_then(|(commit, _)| commits.get_mut(commit))
+        else {
+            continue;
+        };
+        let value = line.split_once(' ').map_or("", |(_, value)| value);
+        match first {
+            "author" => details.author = value.to_string(),
+            "author-mail" => {
+                details.author_email = value.trim_matches(['<', '>']).to_string();
+            }
+            "author-time" => details.timestamp = value.parse().unwrap_or(0),
+            "summary" => details.summary = value.to_string(),
+            _ => {}
+        }
+    }
+    lines
+}
+
 /// Returns the closest git sha to HEAD that is on a remote as well as the diff to that sha.
 pub async fn git_diff_to_remote(cwd: &Path) -> Option<GitDiffToRemote> {
     get_git_repo_root(cwd)?;
@@ -990,6 +1214,142 @@ mod tests {
         assert_eq!(git_info.branch, Some("feature-branch".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_git_ignored_paths_reports_only_untracked_ignored_paths() {
+        skip_if_sandbox!();
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repo_path = create_test_git_repo(&temp_dir).await;
+        fs::write(repo_path.join(".gitignore"), "build/\n*.txt\n").unwrap();
+
+        let ignored = git_ignored_paths(
+            &repo_path,
+            &[
+                repo_path.join("build/out.js"),
+                repo_path.join("src/main.rs"),
+                // Committed before `.gitignore` existed, so still tracked.
+                repo_path.join("test.txt"),
+                repo_path.join("notes.txt"),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            ignored,
+            vec![repo_path.join("build/out.js"), repo_path.join("notes.txt")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_git_ignored_paths_non_git_directory_returns_empty() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let ignored = git_ignored_paths(temp_dir.path(), &[temp_dir.path().join("a.txt")]).await;
+        assert!(ignored.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hash_working_tree_tracks_content_and_skips_ignored_files() {
+        skip_if_sandbox!();
+        let first_dir = TempDir::new().expect("Failed to create temp dir");
+        let second_dir = TempDir::new().expect("Failed to create temp dir");
+        let first = create_test_git_repo(&first_dir).await;
+        let second = create_test_git_repo(&second_dir).await;
+        for repo in [&first, &second] {
+            fs::write(repo.join(".gitignore"), "*.log\n").unwrap();
+            fs::write(repo.join("notes.md"), "untracked\n").unwrap();
+        }
+
+        let hash = hash_working_tree(&first, None).await.expect("hash");
+        assert_eq!(hash.file_count, 3);
+        assert_eq!(hash_working_tree(&second, None).await, Ok(hash.clone()));
+
+        fs::write(first.join("debug.log"), "ignored\n").unwrap();
+        assert_eq!(hash_working_tree(&first, None).await, Ok(hash.clone()));
+
+        fs::write(first.join("notes.md"), "edited\n").unwrap();
+        let edited = hash_working_tree(&first, None).await.expect("hash");
+        assert_ne!(edited.hash, hash.hash);
+
+        let scoped = hash_working_tree(&first, Some(&[PathBuf::from("test.txt")]))
+            .await
+            .expect("hash");
+        assert_eq!(scoped.file_count, 1);
+        assert_eq!(
+            hash_working_tree(&second, Some(&[PathBuf::from("test.txt")])).await,
+            Ok(scoped)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hash_working_tree_non_git_directory_is_not_a_repository() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        assert_eq!(
+            hash_working_tree(temp_dir.path(), None).await,
+            Err(WorkingTreeHashError::NotARepository(
+                temp_dir.path().to_path_buf()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_blame_file_reports_commit_details_for_a_line() {
+        skip_if_sandbox!();
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repo_path = create_test_git_repo(&temp_dir).await;
+        fs::write(repo_path.join("lib.rs"), "one\ntwo\nthree\n").unwrap();
+        for args in [&["add", "lib.rs"][..], &["commit", "-m", "Add lib"][..]] {
+            Command::new("git")
+                .args(args)
+                .current_dir(&repo_path)
+                .output()
+                .await
+                .expect("git command");
+        }
+        let head = get_head_commit_hash(&repo_path).await.expect("head");
+
+        let lines = blame_file(
+            &repo_path,
+            &PathBuf::from("lib.rs"),
+            Some(BlameLineRange { start: 2, end: 2 }),
+        )
+        .await
+        .expect("blame");
+
+        assert_eq!(lines.len(), 1);
+        let line = &lines[0];
+        assert!(line.timestamp > 0);
+        assert_eq!(
+            line,
+            &BlameLine {
+                line_number: 2,
+                commit: head,
+                author: "Test User".to_string(),
+                author_email: "test@example.com".to_string(),
+                timestamp: line.timestamp,
+                summary: "Add lib".to_string(),
+                content: "two".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_blame_file_rejects_untracked_and_non_repo_files() {
+        skip_if_sandbox!();
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repo_path = create_test_git_repo(&temp_dir).await;
+        fs::write(repo_path.join("scratch.txt"), "draft\n").unwrap();
+        assert_eq!(
+            blame_file(&repo_path, &PathBuf::from("scratch.txt"), None).await,
+            Err(BlameError::Untracked(repo_path.join("scratch.txt")))
+        );
+
+        let outside = TempDir::new().expect("Failed to create temp dir");
+        fs::write(outside.path().join("notes.txt"), "hi\n").unwrap();
+        assert_eq!(
+            blame_file(outside.path(), &PathBuf::from("notes.txt"), None).await,
+            Err(BlameError::NotARepository(outside.path().join("notes.txt")))
+        );
+    }
+
     #[tokio::test]
     async fn test_get_has_changes_non_git_directory_returns_none() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
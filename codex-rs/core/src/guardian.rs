@@ -128,6 +128,7 @@ pub(crate) enum GuardianApprovalRequest {
         sandbox_permissions: crate::sandboxing::SandboxPermissions,
         additional_permissions: Option<PermissionProfile>,
         justification: Option<String>,
+        stdin: Option<String>,
     },
     ExecCommand {
         command: Vec<String>,
@@ -629,6 +630,8 @@ async fn run_guardian_subagent(
         .submit(Op::UserInput {
             items: prompt_items,
             final_output_json_schema: Some(schema),
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -742,6 +745,7 @@ pub(crate) fn guardian_approval_request_to_json(action: &GuardianApprovalRequest
             sandbox_permissions,
             additional_permissions,
             justification,
+            stdin,
         } => {
             let mut action = serde_json::json!({
                 "tool": "shell",
@@ -750,6 +754,7 @@ pub(crate) fn guardian_approval_request_to_json(action: &GuardianApprovalRequest
                 "sandbox_permissions": sandbox_permissions,
                 "additional_permissions": additional_permissions,
                 "justification": justification,
+                "stdin": stdin,
             });
             if let Some(action) = action.as_object_mut() {
                 if additional_permissions.is_none() {
@@ -758,6 +763,9 @@ pub(crate) fn guardian_approval_request_to_json(action: &GuardianApprovalRequest
                 if justification.is_none() {
                     action.remove("justification");
                 }
+                if stdin.is_none() {
+                    action.remove("stdin");
+                }
             }
             action
         }
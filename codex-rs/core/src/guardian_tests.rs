@@ -359,6 +359,7 @@ async fn guardian_review_request_layout_matches_model_visible_request_snapshot()
             justification: Some(
                 "Need to push the reviewed docs fix to the repo remote.".to_string(),
             ),
+            stdin: None,
         },
     )
     .await;
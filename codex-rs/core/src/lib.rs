@@ -10,6 +10,7 @@ pub mod api_bridge;
 mod apply_patch;
 mod apps;
 mod arc_monitor;
+mod assistant_output_cap;
 pub mod auth;
 mod client;
 mod client_common;
@@ -30,6 +31,7 @@ pub mod config_loader;
 pub mod connectors;
 mod context_manager;
 mod contextual_user_message;
+mod conversation_export;
 pub mod custom_prompts;
 pub mod env;
 mod environment_context;
@@ -39,6 +41,7 @@ pub mod exec_env;
 mod exec_policy;
 pub mod external_agent_config;
 pub mod features;
+mod file_attachments;
 mod file_watcher;
 mod flags;
 pub mod git_info;
@@ -59,10 +62,12 @@ mod memories;
 pub mod mention_syntax;
 mod mentions;
 mod message_history;
+pub mod metrics;
 mod model_provider_info;
 pub mod path_utils;
 pub mod personality_migration;
 pub mod plugins;
+mod pricing;
 mod sandbox_tags;
 pub mod sandboxing;
 mod session_prefix;
@@ -103,11 +108,15 @@ pub use auth::AuthManager;
 pub use auth::CodexAuth;
 pub mod default_client;
 pub mod project_doc;
+mod rate_limiter;
+mod recent_diffs;
+pub mod replay;
 mod rollout;
 pub(crate) mod safety;
 pub mod seatbelt;
 pub mod shell;
 pub mod shell_snapshot;
+pub mod signals;
 pub mod skills;
 pub mod spawn;
 pub mod state_db;
@@ -116,6 +125,7 @@ mod tools;
 pub mod turn_diff_tracker;
 mod turn_metadata;
 mod turn_timing;
+mod watch_mode;
 pub use rollout::ARCHIVED_SESSIONS_SUBDIR;
 pub use rollout::INTERACTIVE_SESSION_SOURCES;
 pub use rollout::RolloutRecorder;
@@ -201,6 +201,8 @@ fn codex_apps_mcp_server_config(config: &Config, auth: Option<&CodexAuth>) -> Mc
         disabled_tools: None,
         scopes: None,
         oauth_resource: None,
+        merge_structured_content: false,
+        max_mcp_retries: 0,
     }
 }
 
@@ -310,6 +312,7 @@ pub async fn collect_mcp_snapshot(config: &Config) -> McpListToolsResponseEvent
     let (mcp_connection_manager, cancel_token) = McpConnectionManager::new(
         &mcp_servers,
         config.mcp_oauth_credentials_store_mode,
+        config.default_tool_timeout_sec,
         auth_status_entries.clone(),
         &config.permissions.approval_policy,
         tx_event,
@@ -783,6 +786,8 @@ mod tests {
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         );
         config
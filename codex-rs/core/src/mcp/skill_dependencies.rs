@@ -394,6 +394,8 @@ fn mcp_dependency_to_server_config(
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         });
     }
 
@@ -419,6 +421,8 @@ fn mcp_dependency_to_server_config(
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         });
     }
 
@@ -476,6 +480,8 @@ mod tests {
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         )]);
 
@@ -525,6 +531,8 @@ mod tests {
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
         )]);
 
@@ -36,6 +36,7 @@ use codex_protocol::mcp::RequestId as ProtocolRequestId;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::McpHealthStatus;
 use codex_protocol::protocol::McpStartupCompleteEvent;
 use codex_protocol::protocol::McpStartupFailure;
 use codex_protocol::protocol::McpStartupStatus;
@@ -45,6 +46,7 @@ use codex_rmcp_client::ElicitationResponse;
 use codex_rmcp_client::OAuthCredentialsStoreMode;
 use codex_rmcp_client::RmcpClient;
 use codex_rmcp_client::SendElicitation;
+use codex_rmcp_client::is_transport_error;
 use futures::future::BoxFuture;
 use futures::future::FutureExt;
 use futures::future::Shared;
@@ -82,6 +84,7 @@ use crate::codex::INITIAL_SUBMIT_ID;
 use crate::config::types::McpServerConfig;
 use crate::config::types::McpServerTransportConfig;
 use crate::connectors::is_connector_id_allowed;
+use crate::util::backoff;
 /// Delimiter used to separate the server name from the tool name in a fully
 /// qualified tool name.
 ///
@@ -96,6 +99,9 @@ pub const DEFAULT_STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
 /// Default timeout for individual tool calls.
 const DEFAULT_TOOL_TIMEOUT: Duration = Duration::from_secs(120);
 
+/// How long a health check waits for a server to start and answer.
+const MCP_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
 const CODEX_APPS_TOOLS_CACHE_SCHEMA_VERSION: u8 = 1;
 const CODEX_APPS_TOOLS_CACHE_DIR: &str = "cache/codex_apps_tools";
 const MCP_TOOLS_LIST_DURATION_METRIC: &str = "codex.mcp.tools.list.duration_ms";
@@ -365,6 +371,8 @@ struct ManagedClient {
     tools: Vec<ToolInfo>,
     tool_filter: ToolFilter,
     tool_timeout: Option<Duration>,
+    merge_structured_content: bool,
+    max_retries: u32,
     server_supports_sandbox_state_capability: bool,
     codex_apps_tools_cache_context: Option<CodexAppsToolsCacheContext>,
 }
@@ -428,6 +436,7 @@ impl AsyncManagedClient {
         server_name: String,
         config: McpServerConfig,
         store_mode: OAuthCredentialsStoreMode,
+        default_tool_timeout: Option<Duration>,
         cancel_token: CancellationToken,
         tx_event: Sender<Event>,
         elicitation_requests: ElicitationRequestManager,
@@ -458,7 +467,12 @@ impl AsyncManagedClient {
                         startup_timeout: config
                             .startup_timeout_sec
                             .or(Some(DEFAULT_STARTUP_TIMEOUT)),
-                        tool_timeout: config.tool_timeout_sec.unwrap_or(DEFAULT_TOOL_TIMEOUT),
+                        tool_timeout: config
+                            .tool_timeout_sec
+                            .or(default_tool_timeout)
+                            .unwrap_or(DEFAULT_TOOL_TIMEOUT),
+                        merge_structured_content: config.merge_structured_content,
+                        max_retries: config.max_mcp_retries,
                         tool_filter: startup_tool_filter,
                         tx_event,
                         elicitation_requests,
@@ -627,6 +641,7 @@ impl McpConnectionManager {
     pub async fn new(
         mcp_servers: &HashMap<String, McpServerConfig>,
         store_mode: OAuthCredentialsStoreMode,
+        default_tool_timeout: Option<Duration>,
         auth_entries: HashMap<String, McpAuthStatusEntry>,
         approval_policy: &Constrained<AskForApproval>,
         tx_event: Sender<Event>,
@@ -636,15 +651,81 @@ impl McpConnectionManager {
         tool_plugin_provenance: ToolPluginProvenance,
     ) -> (Self, CancellationToken) {
         let cancel_token = CancellationToken::new();
-        let mut clients = HashMap::new();
-        let mut server_origins = HashMap::new();
+        let mut manager = Self::new_uninitialized(approval_policy);
+        manager
+            .start_servers(
+                mcp_servers,
+                store_mode,
+                default_tool_timeout,
+                auth_entries,
+                tx_event,
+                initial_sandbox_state,
+                codex_home,
+                codex_apps_tools_cache_key,
+                tool_plugin_provenance,
+                &cancel_token,
+            )
+            .await;
+        (manager, cancel_token)
+    }
+
+    /// Restarts only the given servers, leaving every other running client
+    /// untouched. Servers in `changed` are (re)started with their new
+    /// configuration and servers in `removed` are shut down.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn reconnect_servers(
+        &mut self,
+        changed: &HashMap<String, McpServerConfig>,
+        removed: &[String],
+        store_mode: OAuthCredentialsStoreMode,
+        default_tool_timeout: Option<Duration>,
+        auth_entries: HashMap<String, McpAuthStatusEntry>,
+        tx_event: Sender<Event>,
+        sandbox_state: SandboxState,
+        codex_home: PathBuf,
+        codex_apps_tools_cache_key: CodexAppsToolsCacheKey,
+        tool_plugin_provenance: ToolPluginProvenance,
+        cancel_token: &CancellationToken,
+    ) {
+        for server_name in removed.iter().chain(changed.keys()) {
+            self.clients.remove(server_name);
+            self.server_origins.remove(server_name);
+        }
+        self.start_servers(
+            changed,
+            store_mode,
+            default_tool_timeout,
+            auth_entries,
+            tx_event,
+            sandbox_state,
+            codex_home,
+            codex_apps_tools_cache_key,
+            tool_plugin_provenance,
+            cancel_token,
+        )
+        .await;
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn start_servers(
+        &mut self,
+        mcp_servers: &HashMap<String, McpServerConfig>,
+        store_mode: OAuthCredentialsStoreMode,
+        default_tool_timeout: Option<Duration>,
+        auth_entries: HashMap<String, McpAuthStatusEntry>,
+        tx_event: Sender<Event>,
+        initial_sandbox_state: SandboxState,
+        codex_home: PathBuf,
+        codex_apps_tools_cache_key: CodexAppsToolsCacheKey,
+        tool_plugin_provenance: ToolPluginProvenance,
+        cancel_token: &CancellationToken,
+    ) {
         let mut join_set = JoinSet::new();
-        let elicitation_requests = ElicitationRequestManager::new(approval_policy.value());
         let tool_plugin_provenance = Arc::new(tool_plugin_provenance);
         let mcp_servers = mcp_servers.clone();
         for (server_name, cfg) in mcp_servers.into_iter().filter(|(_, cfg)| cfg.enabled) {
             if let Some(origin) = transport_origin(&cfg.transport) {
-                server_origins.insert(server_name.clone(), origin);
+                self.server_origins.insert(server_name.clone(), origin);
             }
             let cancel_token = cancel_token.child_token();
             let _ = emit_update(
@@ -667,13 +748,15 @@ impl McpConnectionManager {
                 server_name.clone(),
                 cfg,
                 store_mode,
+                default_tool_timeout,
                 cancel_token.clone(),
                 tx_event.clone(),
-                elicitation_requests.clone(),
+                self.elicitation_requests.clone(),
                 codex_apps_tools_cache_context,
                 Arc::clone(&tool_plugin_provenance),
             );
-            clients.insert(server_name.clone(), async_managed_client.clone());
+            self.clients
+                .insert(server_name.clone(), async_managed_client.clone());
             let tx_event = tx_event.clone();
             let auth_entry = auth_entries.get(&server_name).cloned();
             let sandbox_state = initial_sandbox_state.clone();
@@ -717,11 +800,6 @@ impl McpConnectionManager {
                 (server_name, outcome)
             });
         }
-        let manager = Self {
-            clients,
-            server_origins,
-            elicitation_requests: elicitation_requests.clone(),
-        };
         tokio::spawn(async move {
             let outcomes = join_set.join_all().await;
             let mut summary = McpStartupCompleteEvent::default();
@@ -744,7 +822,6 @@ impl McpConnectionManager {
                 })
                 .await;
         });
-        (manager, cancel_token)
     }
 
     async fn client_by_name(&self, name: &str) -> Result<ManagedClient> {
@@ -997,12 +1074,22 @@ impl McpConnectionManager {
     }
 
     /// Invoke the tool indicated by the (server, tool) pair.
-    pub async fn call_tool(
+    ///
+    /// Transport failures are retried with backoff up to the server's
+    /// `max_mcp_retries`, calling `on_retry` with a short notice before each
+    /// attempt. Results the tool itself flags with `is_error` are returned
+    /// as-is.
+    pub async fn call_tool<F, Fut>(
         &self,
         server: &str,
         tool: &str,
         arguments: Option<serde_json::Value>,
-    ) -> Result<CallToolResult> {
+        on_retry: F,
+    ) -> Result<CallToolResult>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = ()>,
+    {
         let client = self.client_by_name(server).await?;
         if !client.tool_filter.allows(tool) {
             return Err(anyhow!(
@@ -1010,11 +1097,32 @@ impl McpConnectionManager {
             ));
         }
 
-        let result: rmcp::model::CallToolResult = client
-            .client
-            .call_tool(tool.to_string(), arguments, client.tool_timeout)
-            .await
-            .with_context(|| format!("tool call failed for `{server}/{tool}`"))?;
+        let mut attempt = 0;
+        let result: rmcp::model::CallToolResult = loop {
+            match client
+                .client
+                .call_tool(tool.to_string(), arguments.clone(), client.tool_timeout)
+                .await
+            {
+                Ok(result) => break result,
+                Err(err) if attempt < client.max_retries && is_transport_error(&err) => {
+                    attempt += 1;
+                    let delay = backoff(u64::from(attempt));
+                    warn!(
+                        "MCP tool call `{server}/{tool}` failed ({err:#}); retrying in {delay:?}"
+                    );
+                    on_retry(format!(
+                        "Retrying MCP tool `{server}/{tool}` after a transport error ({attempt}/{})",
+                        client.max_retries
+                    ))
+                    .await;
+                    tokio::time::sleep(delay).await;
+                }
+                Err(err) => {
+                    return Err(err.context(format!("tool call failed for `{server}/{tool}`")));
+                }
+            }
+        };
 
         let content = result
             .content
@@ -1033,6 +1141,13 @@ impl McpConnectionManager {
         })
     }
 
+    /// Whether `server` is configured with `merge_structured_content`.
+    pub async fn merges_structured_content(&self, server: &str) -> bool {
+        self.client_by_name(server)
+            .await
+            .is_ok_and(|client| client.merge_structured_content)
+    }
+
     /// List resources from the specified server.
     pub async fn list_resources(
         &self,
@@ -1082,6 +1197,58 @@ impl McpConnectionManager {
             .with_context(|| format!("resources/read failed for `{server}` ({uri})"))
     }
 
+    /// Names of every server this manager was started with, sorted.
+    pub(crate) fn server_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.clients.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Checks that `server` still responds by sending it a `tools/list`
+    /// request. Servers that failed to start or do not answer within
+    /// [`MCP_HEALTH_CHECK_TIMEOUT`] are reported as unreachable; only an
+    /// unknown server name is an error.
+    ///
+    /// The returned future does not borrow the manager, so callers can release
+    /// their lock before awaiting it.
+    pub fn health_check(
+        &self,
+        server: &str,
+    ) -> Result<impl Future<Output = McpHealthStatus> + Send + 'static> {
+        let async_managed_client = self
+            .clients
+            .get(server)
+            .cloned()
+            .ok_or_else(|| anyhow!("unknown MCP server '{server}'"))?;
+        Ok(async move {
+            let check = async {
+                let managed = match async_managed_client.client().await {
+                    Ok(managed) => managed,
+                    Err(error) => {
+                        return McpHealthStatus::Unreachable {
+                            error: error.to_string(),
+                        };
+                    }
+                };
+                match managed
+                    .client
+                    .list_tools(None, Some(MCP_HEALTH_CHECK_TIMEOUT))
+                    .await
+                {
+                    Ok(_) => McpHealthStatus::Healthy,
+                    Err(error) => McpHealthStatus::Unreachable {
+                        error: format!("{error:#}"),
+                    },
+                }
+            };
+            tokio::time::timeout(MCP_HEALTH_CHECK_TIMEOUT, check)
+                .await
+                .unwrap_or_else(|_| McpHealthStatus::Unreachable {
+                    error: format!("no response within {}s", MCP_HEALTH_CHECK_TIMEOUT.as_secs()),
+                })
+        })
+    }
+
     pub async fn parse_tool_name(&self, tool_name: &str) -> Option<(String, String)> {
         self.list_all_tools()
             .await
@@ -1313,6 +1480,8 @@ async fn start_server_task(
     let StartServerTaskParams {
         startup_timeout,
         tool_timeout,
+        merge_structured_content,
+        max_retries,
         tool_filter,
         tx_event,
         elicitation_requests,
@@ -1381,6 +1550,8 @@ async fn start_server_task(
         client: Arc::clone(&client),
         tools,
         tool_timeout: Some(tool_timeout),
+        merge_structured_content,
+        max_retries,
         tool_filter,
         server_supports_sandbox_state_capability,
         codex_apps_tools_cache_context,
@@ -1392,6 +1563,8 @@ async fn start_server_task(
 struct StartServerTaskParams {
     startup_timeout: Option<Duration>, // TODO: cancel_token should handle this.
     tool_timeout: Duration,
+    merge_structured_content: bool,
+    max_retries: u32,
     tool_filter: ToolFilter,
     tx_event: Sender<Event>,
     elicitation_requests: ElicitationRequestManager,
@@ -2209,6 +2382,8 @@ mod tests {
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
             auth_status: McpAuthStatus::Unsupported,
         };
@@ -2257,6 +2432,8 @@ mod tests {
                 disabled_tools: None,
                 scopes: None,
                 oauth_resource: None,
+                merge_structured_content: false,
+                max_mcp_retries: 0,
             },
             auth_status: McpAuthStatus::Unsupported,
         };
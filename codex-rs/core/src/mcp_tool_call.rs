@@ -30,6 +30,7 @@ use crate::protocol::EventMsg;
 use crate::protocol::McpInvocation;
 use crate::protocol::McpToolCallBeginEvent;
 use crate::protocol::McpToolCallEndEvent;
+use crate::protocol::McpToolCallErrorEvent;
 use crate::state_db;
 use codex_protocol::mcp::CallToolResult;
 use codex_protocol::openai_models::InputModality;
@@ -143,7 +144,12 @@ pub(crate) async fn handle_mcp_tool_call(
 
                 let start = Instant::now();
                 let result = sess
-                    .call_tool(&server, &tool_name, arguments_value.clone())
+                    .call_tool(
+                        turn_context.as_ref(),
+                        &server,
+                        &tool_name,
+                        arguments_value.clone(),
+                    )
                     .await
                     .map_err(|e| format!("tool call error: {e:?}"));
                 let result = sanitize_mcp_tool_result_for_model(
@@ -156,18 +162,14 @@ pub(crate) async fn handle_mcp_tool_call(
                 if let Err(e) = &result {
                     tracing::warn!("MCP tool call error: {e:?}");
                 }
-                let tool_call_end_event = EventMsg::McpToolCallEnd(McpToolCallEndEvent {
+                let tool_call_end_event = McpToolCallEndEvent {
                     call_id: call_id.clone(),
                     invocation,
                     duration: start.elapsed(),
                     result: result.clone(),
-                });
-                notify_mcp_tool_call_event(
-                    sess.as_ref(),
-                    turn_context.as_ref(),
-                    tool_call_end_event.clone(),
-                )
-                .await;
+                };
+                notify_mcp_tool_call_end(sess.as_ref(), turn_context.as_ref(), tool_call_end_event)
+                    .await;
                 maybe_track_codex_app_used(
                     sess.as_ref(),
                     turn_context.as_ref(),
@@ -216,6 +218,7 @@ pub(crate) async fn handle_mcp_tool_call(
             .session_telemetry
             .counter("codex.mcp.call", 1, &[("status", status)]);
 
+        let result = result_for_model(sess.as_ref(), &server, result).await;
         return CallToolResult::from_result(result);
     }
 
@@ -229,7 +232,12 @@ pub(crate) async fn handle_mcp_tool_call(
     let start = Instant::now();
     // Perform the tool call.
     let result = sess
-        .call_tool(&server, &tool_name, arguments_value.clone())
+        .call_tool(
+            turn_context.as_ref(),
+            &server,
+            &tool_name,
+            arguments_value.clone(),
+        )
         .await
         .map_err(|e| format!("tool call error: {e:?}"));
     let result = sanitize_mcp_tool_result_for_model(
@@ -242,19 +250,13 @@ pub(crate) async fn handle_mcp_tool_call(
     if let Err(e) = &result {
         tracing::warn!("MCP tool call error: {e:?}");
     }
-    let tool_call_end_event = EventMsg::McpToolCallEnd(McpToolCallEndEvent {
+    let tool_call_end_event = McpToolCallEndEvent {
         call_id: call_id.clone(),
         invocation,
         duration: start.elapsed(),
         result: result.clone(),
-    });
-
-    notify_mcp_tool_call_event(
-        sess.as_ref(),
-        turn_context.as_ref(),
-        tool_call_end_event.clone(),
-    )
-    .await;
+    };
+    notify_mcp_tool_call_end(sess.as_ref(), turn_context.as_ref(), tool_call_end_event).await;
     maybe_track_codex_app_used(sess.as_ref(), turn_context.as_ref(), &server, &tool_name).await;
 
     let status = if result.is_ok() { "ok" } else { "error" };
@@ -262,9 +264,49 @@ pub(crate) async fn handle_mcp_tool_call(
         .session_telemetry
         .counter("codex.mcp.call", 1, &[("status", status)]);
 
+    let result = result_for_model(sess.as_ref(), &server, result).await;
     CallToolResult::from_result(result)
 }
 
+/// Applies the server's `merge_structured_content` setting to the output the
+/// model sees. The `McpToolCallEnd` event keeps the result as returned.
+async fn result_for_model(
+    sess: &Session,
+    server: &str,
+    result: Result<CallToolResult, String>,
+) -> Result<CallToolResult, String> {
+    let merge = sess
+        .services
+        .mcp_connection_manager
+        .read()
+        .await
+        .merges_structured_content(server)
+        .await;
+    if merge {
+        result.map(merge_structured_content_into_content)
+    } else {
+        result
+    }
+}
+
+/// Appends `structured_content` to `content` as a serialized text block so the
+/// model sees both, rather than the structured payload replacing the content.
+fn merge_structured_content_into_content(mut result: CallToolResult) -> CallToolResult {
+    let Some(structured_content) = result.structured_content.take() else {
+        return result;
+    };
+    if structured_content.is_null() {
+        return result;
+    }
+    let text = serde_json::to_string(&structured_content)
+        .unwrap_or_else(|err| format!("<structured content: {err}>"));
+    result.content.push(serde_json::json!({
+        "type": "text",
+        "text": text,
+    }));
+    result
+}
+
 async fn maybe_mark_thread_memory_mode_polluted(sess: &Session, turn_context: &TurnContext) {
     if !turn_context
         .config
@@ -316,6 +358,29 @@ async fn notify_mcp_tool_call_event(sess: &Session, turn_context: &TurnContext,
     sess.send_event(turn_context, event).await;
 }
 
+/// Emits `McpToolCallEnd`, followed by `McpToolCallError` when the server flagged
+/// the result with `is_error` so UIs can badge the failed call. The result is
+/// returned to the model unchanged either way.
+async fn notify_mcp_tool_call_end(
+    sess: &Session,
+    turn_context: &TurnContext,
+    end_event: McpToolCallEndEvent,
+) {
+    let error_event = match &end_event.result {
+        Ok(result) if result.is_error == Some(true) => Some(McpToolCallErrorEvent {
+            call_id: end_event.call_id.clone(),
+            invocation: end_event.invocation.clone(),
+            content: result.content.clone(),
+        }),
+        Ok(_) | Err(_) => None,
+    };
+    notify_mcp_tool_call_event(sess, turn_context, EventMsg::McpToolCallEnd(end_event)).await;
+    if let Some(error_event) = error_event {
+        notify_mcp_tool_call_event(sess, turn_context, EventMsg::McpToolCallError(error_event))
+            .await;
+    }
+}
+
 struct McpAppUsageMetadata {
     connector_id: Option<String>,
     app_name: Option<String>,
@@ -15,6 +15,7 @@ use codex_api::ResponseEvent;
 use codex_otel::SessionTelemetry;
 use codex_protocol::config_types::ReasoningSummary as ReasoningSummaryConfig;
 use codex_protocol::config_types::ServiceTier;
+use codex_protocol::config_types::ToolChoice;
 use codex_protocol::models::BaseInstructions;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::ResponseItem;
@@ -340,6 +341,8 @@ mod job {
             },
             personality: None,
             output_schema: Some(output_schema()),
+            tool_choice: ToolChoice::Auto,
+            max_output_tokens: None,
         };
 
         let mut client_session = session.services.model_client.new_session();
@@ -0,0 +1,146 @@
+//! Session-scoped counters that can be scraped in the OpenMetrics text format.
+
+use std::fmt::Write as _;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// Monotonic counters for a single session, shared with callers via
+/// `CodexSpawnOk::metrics`.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    turns_total: AtomicU64,
+    tokens_input_total: AtomicU64,
+    tokens_output_total: AtomicU64,
+    exec_calls_total: AtomicU64,
+    patch_calls_total: AtomicU64,
+    errors_total: AtomicU64,
+}
+
+impl Metrics {
+    pub(crate) fn record_turn(&self) {
+        self.turns_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_tokens(&self, input: i64, output: i64) {
+        let to_u64 = |tokens: i64| u64::try_from(tokens).unwrap_or(0);
+        self.tokens_input_total
+            .fetch_add(to_u64(input), Ordering::Relaxed);
+        self.tokens_output_total
+            .fetch_add(to_u64(output), Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_exec_call(&self) {
+        self.exec_calls_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_patch_call(&self) {
+        self.patch_calls_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_error(&self) {
+        self.errors_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn turns_total(&self) -> u64 {
+        self.turns_total.load(Ordering::Relaxed)
+    }
+
+    pub fn tokens_input_total(&self) -> u64 {
+        self.tokens_input_total.load(Ordering::Relaxed)
+    }
+
+    pub fn tokens_output_total(&self) -> u64 {
+        self.tokens_output_total.load(Ordering::Relaxed)
+    }
+
+    pub fn exec_calls_total(&self) -> u64 {
+        self.exec_calls_total.load(Ordering::Relaxed)
+    }
+
+    pub fn patch_calls_total(&self) -> u64 {
+        self.patch_calls_total.load(Ordering::Relaxed)
+    }
+
+    pub fn errors_total(&self) -> u64 {
+        self.errors_total.load(Ordering::Relaxed)
+    }
+
+    /// Renders every counter in the OpenMetrics text exposition format,
+    /// terminated by `# EOF`.
+    pub fn render_text(&self) -> String {
+        let counters = [
+            ("codex_turns", "Turns started.", self.turns_total()),
+            (
+                "codex_tokens_input",
+                "Input tokens reported by the model provider.",
+                self.tokens_input_total(),
+            ),
+            (
+                "codex_tokens_output",
+                "Output tokens reported by the model provider.",
+                self.tokens_output_total(),
+            ),
+            (
+                "codex_exec_calls",
+                "Commands executed for tool calls.",
+                self.exec_calls_total(),
+            ),
+            (
+                "codex_patch_calls",
+                "Patches applied for tool calls.",
+                self.patch_calls_total(),
+            ),
+            ("codex_errors", "Error events emitted.", self.errors_total()),
+        ];
+
+        let mut text = String::new();
+        for (name, help, value) in counters {
+            let _ = writeln!(text, "# TYPE {name} counter");
+            let _ = writeln!(text, "# HELP {name} {help}");
+            let _ = writeln!(text, "{name}_total {value}");
+        }
+        text.push_str("# EOF\n");
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn render_text_reports_recorded_counters() {
+        let metrics = Metrics::default();
+        metrics.record_turn();
+        metrics.record_turn();
+        metrics.record_tokens(120, 30);
+        metrics.record_tokens(-5, 10);
+        metrics.record_exec_call();
+        metrics.record_patch_call();
+        metrics.record_error();
+
+        assert_eq!(
+            metrics.render_text(),
+            "# TYPE codex_turns counter\n\
+# HELP codex_turns Turns started.\n\
+codex_turns_total 2\n\
+# TYPE codex_tokens_input counter\n\
+# HELP codex_tokens_input Input tokens reported by the model provider.\n\
+codex_tokens_input_total 120\n\
+# TYPE codex_tokens_output counter\n\
+# HELP codex_tokens_output Output tokens reported by the model provider.\n\
+codex_tokens_output_total 40\n\
+# TYPE codex_exec_calls counter\n\
+# HELP codex_exec_calls Commands executed for tool calls.\n\
+codex_exec_calls_total 1\n\
+# TYPE codex_patch_calls counter\n\
+# HELP codex_patch_calls Patches applied for tool calls.\n\
+codex_patch_calls_total 1\n\
+# TYPE codex_errors counter\n\
+# HELP codex_errors Error events emitted.\n\
+codex_errors_total 1\n\
+# EOF\n"
+        );
+    }
+}
@@ -158,7 +158,7 @@ impl ModelProviderInfo {
         let headers = self.build_header_map()?;
         let retry = ApiRetryConfig {
             max_attempts: self.request_max_retries(),
-            base_delay: Duration::from_millis(200),
+            base_delay: Duration::from_millis(crate::util::INITIAL_DELAY_MS),
             retry_429: false,
             retry_5xx: true,
             retry_transport: true,
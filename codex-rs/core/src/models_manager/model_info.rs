@@ -33,6 +33,18 @@ pub(crate) fn with_config_overrides(mut model: ModelInfo, config: &Config) -> Mo
     if let Some(auto_compact_token_limit) = config.model_auto_compact_token_limit {
         model.auto_compact_token_limit = Some(auto_compact_token_limit);
     }
+    if let Some(ratio) = config
+        .compact_threshold_ratio
+        .filter(|ratio| ratio.is_finite() && *ratio > 0.0)
+        && let Some(context_window) = model.context_window
+    {
+        let ratio_limit = (context_window as f64 * f64::from(ratio)) as i64;
+        model.auto_compact_token_limit = Some(
+            model
+                .auto_compact_token_limit
+                .map_or(ratio_limit, |limit| limit.min(ratio_limit)),
+        );
+    }
     if let Some(token_limit) = config.tool_output_token_limit {
         model.truncation_policy = match model.truncation_policy.mode {
             TruncationMode::Bytes => {
@@ -150,4 +162,19 @@ mod tests {
 
         assert_eq!(updated, model);
     }
+
+    #[test]
+    fn compact_threshold_ratio_lowers_auto_compact_limit() {
+        let model = model_info_from_slug("unknown-model");
+        let mut config = test_config();
+        config.model_context_window = Some(100_000);
+        config.compact_threshold_ratio = Some(0.5);
+
+        let updated = with_config_overrides(model.clone(), &config);
+        assert_eq!(updated.auto_compact_token_limit(), Some(50_000));
+
+        config.model_auto_compact_token_limit = Some(30_000);
+        let updated = with_config_overrides(model, &config);
+        assert_eq!(updated.auto_compact_token_limit(), Some(30_000));
+    }
 }
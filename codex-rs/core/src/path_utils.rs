@@ -1,6 +1,7 @@
 use codex_utils_absolute_path::AbsolutePathBuf;
 use std::collections::HashSet;
 use std::io;
+use std::path::Component;
 use std::path::Path;
 use std::path::PathBuf;
 use tempfile::NamedTempFile;
@@ -12,6 +13,38 @@ pub fn normalize_for_path_comparison(path: impl AsRef<Path>) -> std::io::Result<
     Ok(normalize_for_wsl(canonical))
 }
 
+/// Canonicalizes the longest existing ancestor of `path` and re-appends the
+/// remaining components, resolving `.` and `..` in that tail lexically.
+///
+/// Unlike `canonicalize`, this also works for paths that do not exist yet, so
+/// `root/missing/../../outside` still resolves outside of `root`.
+pub fn canonicalize_existing_prefix(path: &Path) -> PathBuf {
+    let mut existing = path;
+    let mut tail = Vec::new();
+    let mut resolved = loop {
+        if let Ok(canonical) = dunce::canonicalize(existing) {
+            break canonical;
+        }
+        match (existing.parent(), existing.components().next_back()) {
+            (Some(parent), Some(component)) => {
+                tail.push(component);
+                existing = parent;
+            }
+            _ => break existing.to_path_buf(),
+        }
+    };
+    for component in tail.into_iter().rev() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            component => resolved.push(component),
+        }
+    }
+    resolved
+}
+
 pub fn normalize_for_native_workdir(path: impl AsRef<Path>) -> PathBuf {
     normalize_for_native_workdir_with_flag(path.as_ref().to_path_buf(), cfg!(windows))
 }
@@ -253,6 +286,27 @@ mod tests {
         }
     }
 
+    mod existing_prefix {
+        use super::super::canonicalize_existing_prefix;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn missing_tail_is_resolved_lexically() -> std::io::Result<()> {
+            let dir = tempfile::tempdir()?;
+            let root = dunce::canonicalize(dir.path())?;
+
+            assert_eq!(
+                canonicalize_existing_prefix(&dir.path().join("missing/./file.txt")),
+                root.join("missing/file.txt")
+            );
+            assert_eq!(
+                canonicalize_existing_prefix(&dir.path().join("missing/../../outside")),
+                root.parent().expect("tempdir has a parent").join("outside")
+            );
+            Ok(())
+        }
+    }
+
     mod native_workdir {
         use super::super::normalize_for_native_workdir_with_flag;
         use pretty_assertions::assert_eq;
@@ -1,6 +1,8 @@
 use super::PluginManifestPaths;
+use super::PluginTaskHooks;
 use super::curated_plugins_repo_path;
 use super::load_plugin_manifest;
+use super::manifest::PluginManifest;
 use super::manifest::PluginManifestInterfaceSummary;
 use super::marketplace::MarketplaceError;
 use super::marketplace::MarketplacePluginSourceSummary;
@@ -16,6 +18,8 @@ use super::store::PluginInstallResult;
 use super::store::PluginStore;
 use super::store::PluginStoreError;
 use super::sync_openai_plugins_repo;
+use super::validation::PluginValidationError;
+use super::validation::validate_plugin;
 use crate::auth::CodexAuth;
 use crate::config::Config;
 use crate::config::ConfigService;
@@ -31,9 +35,14 @@ use crate::default_client::build_reqwest_client;
 use crate::features::Feature;
 use crate::features::FeatureOverrides;
 use crate::features::Features;
+use crate::skills::loader::SkillRoot;
+use crate::skills::loader::load_skills_from_roots;
 use codex_app_server_protocol::ConfigValueWriteParams;
 use codex_app_server_protocol::MergeStrategy;
+use codex_protocol::protocol::SkillScope;
 use codex_utils_absolute_path::AbsolutePathBuf;
+use lru::LruCache;
+use semver::Version;
 use serde::Deserialize;
 use serde_json::Map as JsonMap;
 use serde_json::Value as JsonValue;
@@ -41,6 +50,7 @@ use serde_json::json;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fs;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::RwLock;
@@ -56,6 +66,9 @@ const DEFAULT_MCP_CONFIG_FILE: &str = ".mcp.json";
 const DEFAULT_APP_CONFIG_FILE: &str = ".app.json";
 const DISABLE_CURATED_PLUGIN_SYNC_ENV_VAR: &str = "CODEX_DISABLE_CURATED_PLUGIN_SYNC";
 const OPENAI_CURATED_MARKETPLACE_NAME: &str = "openai-curated";
+/// Number of cwds whose plugin load outcome is kept when
+/// `plugin_cache_max_entries` is unset.
+const DEFAULT_PLUGIN_CACHE_MAX_ENTRIES: usize = 64;
 const REMOTE_PLUGIN_SYNC_TIMEOUT: Duration = Duration::from_secs(30);
 static CURATED_REPO_SYNC_STARTED: AtomicBool = AtomicBool::new(false);
 
@@ -90,12 +103,17 @@ pub struct LoadedPlugin {
     pub config_name: String,
     pub manifest_name: Option<String>,
     pub manifest_description: Option<String>,
+    pub manifest_version: Option<String>,
     pub root: AbsolutePathBuf,
     pub enabled: bool,
     pub skill_roots: Vec<PathBuf>,
     pub mcp_servers: HashMap<String, McpServerConfig>,
     pub apps: Vec<AppConnectorId>,
+    pub task_hooks: PluginTaskHooks,
+    pub capabilities: PluginCapabilities,
     pub error: Option<String>,
+    /// Set when the plugin was rejected because it requires a newer Codex.
+    pub version_incompatible: bool,
 }
 
 impl LoadedPlugin {
@@ -104,6 +122,17 @@ impl LoadedPlugin {
     }
 }
 
+/// What a loaded plugin provides, counted when the plugin is loaded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginCapabilities {
+    pub has_skills: bool,
+    pub has_mcp_servers: bool,
+    pub skill_count: usize,
+    pub mcp_server_count: usize,
+    /// Tags from the optional top-level `capabilities` array in `plugin.json`.
+    pub declared_capabilities: Vec<String>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct PluginCapabilitySummary {
     pub config_name: String,
@@ -205,6 +234,16 @@ impl PluginLoadOutcome {
         apps
     }
 
+    /// Task hooks of the active plugins, paired with each plugin's config name.
+    pub fn effective_task_hooks(&self) -> Vec<(String, PluginTaskHooks)> {
+        self.plugins
+            .iter()
+            .filter(|plugin| plugin.is_active())
+            .filter(|plugin| plugin.task_hooks != PluginTaskHooks::default())
+            .map(|plugin| (plugin.config_name.clone(), plugin.task_hooks.clone()))
+            .collect()
+    }
+
     pub fn capability_summaries(&self) -> &[PluginCapabilitySummary] {
         &self.capability_summaries
     }
@@ -212,6 +251,55 @@ impl PluginLoadOutcome {
     pub fn plugins(&self) -> &[LoadedPlugin] {
         &self.plugins
     }
+
+    /// One line per configured plugin with its state and what it provides,
+    /// e.g. `sample@test: enabled, 2 skills, 1 MCP server`.
+    pub fn summary(&self) -> String {
+        if self.plugins.is_empty() {
+            return "No plugins configured.".to_string();
+        }
+        self.plugins
+            .iter()
+            .map(|plugin| {
+                let mut parts = Vec::new();
+                if !plugin.enabled {
+                    parts.push("disabled".to_string());
+                } else if let Some(error) = &plugin.error {
+                    parts.push(format!("error: {error}"));
+                } else {
+                    let capabilities = &plugin.capabilities;
+                    parts.push("enabled".to_string());
+                    parts.push(count_label(capabilities.skill_count, "skill", "skills"));
+                    parts.push(count_label(
+                        capabilities.mcp_server_count,
+                        "MCP server",
+                        "MCP servers",
+                    ));
+                    if !capabilities.declared_capabilities.is_empty() {
+                        parts.push(format!(
+                            "capabilities: {}",
+                            capabilities.declared_capabilities.join(", ")
+                        ));
+                    }
+                }
+                format!("{}: {}", plugin.config_name, parts.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Plugins that were not loaded because their `minCodexVersion` is newer
+    /// than the running Codex.
+    pub fn incompatible_plugins(&self) -> Vec<&LoadedPlugin> {
+        self.plugins
+            .iter()
+            .filter(|plugin| plugin.version_incompatible)
+            .collect()
+    }
+}
+
+fn count_label(count: usize, singular: &str, plural: &str) -> String {
+    format!("{count} {}", if count == 1 { singular } else { plural })
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -322,18 +410,33 @@ fn default_remote_marketplace_name() -> String {
 pub struct PluginsManager {
     codex_home: PathBuf,
     store: PluginStore,
-    cache_by_cwd: RwLock<HashMap<PathBuf, PluginLoadOutcome>>,
+    /// Load outcomes keyed by cwd. Least recently used entries are evicted
+    /// once the cache is full and recomputed on their next access.
+    cache_by_cwd: RwLock<LruCache<PathBuf, PluginLoadOutcome>>,
 }
 
 impl PluginsManager {
     pub fn new(codex_home: PathBuf) -> Self {
+        Self::with_cache_max_entries(codex_home, DEFAULT_PLUGIN_CACHE_MAX_ENTRIES)
+    }
+
+    /// Like `new`, but caches the plugin load outcome of at most
+    /// `max_entries` cwds (at least one).
+    pub fn with_cache_max_entries(codex_home: PathBuf, max_entries: usize) -> Self {
+        let capacity = NonZeroUsize::new(max_entries).unwrap_or(NonZeroUsize::MIN);
         Self {
             codex_home: codex_home.clone(),
             store: PluginStore::new(codex_home),
-            cache_by_cwd: RwLock::new(HashMap::new()),
+            cache_by_cwd: RwLock::new(LruCache::new(capacity)),
         }
     }
 
+    /// Checks the plugin directory at `plugin_root` before it is added to the
+    /// config. Returns every problem found; an empty list means it is valid.
+    pub fn validate_plugin(plugin_root: &Path) -> Vec<PluginValidationError> {
+        validate_plugin(plugin_root)
+    }
+
     pub fn plugins_for_config(&self, config: &Config) -> PluginLoadOutcome {
         self.plugins_for_layer_stack(&config.cwd, &config.config_layer_stack, false)
     }
@@ -358,7 +461,35 @@ impl PluginsManager {
             Ok(cache) => cache,
             Err(err) => err.into_inner(),
         };
-        cache.insert(cwd.to_path_buf(), outcome.clone());
+        cache.put(cwd.to_path_buf(), outcome.clone());
+        outcome
+    }
+
+    /// Re-reads plugins for `cwd` from disk, bypassing the cache, and replaces
+    /// the cached outcome. Plugins whose MCP server definitions differ from the
+    /// previously cached outcome are logged so the caller can reconnect them.
+    pub fn reload(&self, cwd: &Path, config_layer_stack: &ConfigLayerStack) -> PluginLoadOutcome {
+        let outcome = if plugins_feature_enabled_from_stack(config_layer_stack) {
+            let outcome = load_plugins_from_layer_stack(config_layer_stack, &self.store);
+            log_plugin_load_errors(&outcome);
+            outcome
+        } else {
+            PluginLoadOutcome::default()
+        };
+
+        let mut cache = match self.cache_by_cwd.write() {
+            Ok(cache) => cache,
+            Err(err) => err.into_inner(),
+        };
+        let previous = cache.put(cwd.to_path_buf(), outcome.clone());
+        if let Some(previous) = previous.as_ref() {
+            for plugin in plugins_with_changed_mcp_servers(previous, &outcome) {
+                warn!(
+                    plugin = plugin,
+                    "plugin MCP server definitions changed on reload"
+                );
+            }
+        }
         outcome
     }
 
@@ -371,10 +502,12 @@ impl PluginsManager {
     }
 
     fn cached_outcome_for_cwd(&self, cwd: &Path) -> Option<PluginLoadOutcome> {
-        match self.cache_by_cwd.read() {
-            Ok(cache) => cache.get(cwd).cloned(),
-            Err(err) => err.into_inner().get(cwd).cloned(),
-        }
+        // A hit refreshes the entry's recency, so lookups need the write lock.
+        let mut cache = match self.cache_by_cwd.write() {
+            Ok(cache) => cache,
+            Err(err) => err.into_inner(),
+        };
+        cache.get(cwd).cloned()
     }
 
     pub async fn install_plugin(
@@ -825,11 +958,44 @@ fn log_plugin_load_errors(outcome: &PluginLoadOutcome) {
     }
 }
 
+/// Returns the config names of plugins whose active MCP servers differ between
+/// two load outcomes, including plugins that were added, removed, enabled, or
+/// disabled.
+fn plugins_with_changed_mcp_servers(
+    previous: &PluginLoadOutcome,
+    current: &PluginLoadOutcome,
+) -> Vec<String> {
+    let active_servers = |outcome: &PluginLoadOutcome| {
+        outcome
+            .plugins
+            .iter()
+            .filter(|plugin| plugin.is_active())
+            .map(|plugin| (plugin.config_name.clone(), plugin.mcp_servers.clone()))
+            .collect::<HashMap<_, _>>()
+    };
+    let previous = active_servers(previous);
+    let current = active_servers(current);
+
+    let mut changed = previous
+        .keys()
+        .chain(current.keys())
+        .filter(|name| {
+            let before = previous.get(*name).filter(|servers| !servers.is_empty());
+            let after = current.get(*name).filter(|servers| !servers.is_empty());
+            before != after
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    changed.sort_unstable();
+    changed.dedup();
+    changed
+}
+
 #[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct PluginMcpFile {
+pub(super) struct PluginMcpFile {
     #[serde(default)]
-    mcp_servers: HashMap<String, JsonValue>,
+    pub(super) mcp_servers: HashMap<String, JsonValue>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -916,12 +1082,16 @@ fn load_plugin(config_name: String, plugin: &PluginConfig, store: &PluginStore)
         config_name,
         manifest_name: None,
         manifest_description: None,
+        manifest_version: None,
         root,
         enabled: plugin.enabled,
         skill_roots: Vec::new(),
         mcp_servers: HashMap::new(),
         apps: Vec::new(),
+        task_hooks: PluginTaskHooks::default(),
+        capabilities: PluginCapabilities::default(),
         error: None,
+        version_incompatible: false,
     };
 
     if !plugin.enabled {
@@ -946,8 +1116,16 @@ fn load_plugin(config_name: String, plugin: &PluginConfig, store: &PluginStore)
         return loaded_plugin;
     };
 
-    let manifest_paths = plugin_manifest_paths(&manifest, plugin_root.as_path());
     loaded_plugin.manifest_name = Some(plugin_manifest_name(&manifest, plugin_root.as_path()));
+    if let Err(err) = check_plugin_versions(&manifest, env!("CARGO_PKG_VERSION")) {
+        loaded_plugin.version_incompatible =
+            matches!(err, PluginVersionError::RequiresNewerCodex { .. });
+        loaded_plugin.error = Some(err.to_string());
+        return loaded_plugin;
+    }
+    loaded_plugin.manifest_version = manifest.version.clone();
+
+    let manifest_paths = plugin_manifest_paths(&manifest, plugin_root.as_path());
     loaded_plugin.manifest_description = manifest.description;
     loaded_plugin.skill_roots = plugin_skill_roots(plugin_root.as_path(), &manifest_paths);
     let mut mcp_servers = HashMap::new();
@@ -966,10 +1144,82 @@ fn load_plugin(config_name: String, plugin: &PluginConfig, store: &PluginStore)
     }
     loaded_plugin.mcp_servers = mcp_servers;
     loaded_plugin.apps = load_plugin_apps(plugin_root.as_path());
+    loaded_plugin.task_hooks = manifest_paths.task_hooks;
+    loaded_plugin.capabilities = plugin_capabilities(&loaded_plugin, manifest.capabilities);
     loaded_plugin
 }
 
-fn plugin_skill_roots(plugin_root: &Path, manifest_paths: &PluginManifestPaths) -> Vec<PathBuf> {
+fn plugin_capabilities(
+    plugin: &LoadedPlugin,
+    declared_capabilities: Vec<String>,
+) -> PluginCapabilities {
+    let skill_count = load_skills_from_roots(plugin.skill_roots.iter().map(|path| SkillRoot {
+        path: path.clone(),
+        scope: SkillScope::User,
+    }))
+    .skills
+    .len();
+    PluginCapabilities {
+        has_skills: skill_count > 0,
+        has_mcp_servers: !plugin.mcp_servers.is_empty(),
+        skill_count,
+        mcp_server_count: plugin.mcp_servers.len(),
+        declared_capabilities,
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum PluginVersionError {
+    #[error("invalid plugin version `{version}`: {source}")]
+    InvalidVersion {
+        version: String,
+        source: semver::Error,
+    },
+
+    #[error("invalid minCodexVersion `{version}`: {source}")]
+    InvalidMinCodexVersion {
+        version: String,
+        source: semver::Error,
+    },
+
+    #[error("plugin requires Codex {required} or newer, but this is Codex {running}")]
+    RequiresNewerCodex { required: Version, running: Version },
+}
+
+/// Validates the manifest's semver fields and checks `minCodexVersion` against
+/// `codex_version`.
+fn check_plugin_versions(
+    manifest: &PluginManifest,
+    codex_version: &str,
+) -> Result<(), PluginVersionError> {
+    if let Some(version) = &manifest.version {
+        Version::parse(version).map_err(|source| PluginVersionError::InvalidVersion {
+            version: version.clone(),
+            source,
+        })?;
+    }
+    let Some(min_codex_version) = &manifest.min_codex_version else {
+        return Ok(());
+    };
+    let required = Version::parse(min_codex_version).map_err(|source| {
+        PluginVersionError::InvalidMinCodexVersion {
+            version: min_codex_version.clone(),
+            source,
+        }
+    })?;
+    let Ok(running) = Version::parse(codex_version) else {
+        return Ok(());
+    };
+    if running < required {
+        return Err(PluginVersionError::RequiresNewerCodex { required, running });
+    }
+    Ok(())
+}
+
+pub(super) fn plugin_skill_roots(
+    plugin_root: &Path,
+    manifest_paths: &PluginManifestPaths,
+) -> Vec<PathBuf> {
     let mut paths = default_skill_roots(plugin_root);
     if let Some(path) = &manifest_paths.skills {
         paths.push(path.to_path_buf());
@@ -988,7 +1238,7 @@ fn default_skill_roots(plugin_root: &Path) -> Vec<PathBuf> {
     }
 }
 
-fn plugin_mcp_config_paths(
+pub(super) fn plugin_mcp_config_paths(
     plugin_root: &Path,
     manifest_paths: &PluginManifestPaths,
 ) -> Vec<AbsolutePathBuf> {
@@ -1134,7 +1384,7 @@ fn normalize_plugin_mcp_servers(
     PluginMcpDiscovery { mcp_servers }
 }
 
-fn normalize_plugin_mcp_server_value(
+pub(super) fn normalize_plugin_mcp_server_value(
     plugin_root: &Path,
     value: JsonValue,
 ) -> JsonMap<String, JsonValue> {
@@ -1360,6 +1610,7 @@ mod tests {
                 manifest_description: Some(
                     "Plugin that includes the sample MCP server and Skills".to_string(),
                 ),
+                manifest_version: None,
                 root: AbsolutePathBuf::try_from(plugin_root.clone()).unwrap(),
                 enabled: true,
                 skill_roots: vec![plugin_root.join("skills")],
@@ -1381,10 +1632,21 @@ mod tests {
                         disabled_tools: None,
                         scopes: None,
                         oauth_resource: None,
+                        merge_structured_content: false,
+                        max_mcp_retries: 0,
                     },
                 )]),
                 apps: vec![AppConnectorId("connector_example".to_string())],
+                task_hooks: PluginTaskHooks::default(),
+                capabilities: PluginCapabilities {
+                    has_skills: true,
+                    has_mcp_servers: true,
+                    skill_count: 1,
+                    mcp_server_count: 1,
+                    declared_capabilities: Vec::new(),
+                },
                 error: None,
+                version_incompatible: false,
             }]
         );
         assert_eq!(
@@ -1411,6 +1673,154 @@ mod tests {
         );
     }
 
+    #[test]
+    fn load_plugins_reads_declared_capabilities_and_summarizes_outcome() {
+        let codex_home = TempDir::new().unwrap();
+        let plugin_root = codex_home
+            .path()
+            .join("plugins/cache")
+            .join("test/sample/local");
+        write_file(
+            &plugin_root.join(".codex-plugin/plugin.json"),
+            r#"{"name":"sample","capabilities":["search","review"]}"#,
+        );
+        for skill in ["alpha", "beta"] {
+            write_file(
+                &plugin_root.join(format!("skills/{skill}/SKILL.md")),
+                &format!("---\nname: {skill}\ndescription: {skill} skill\n---\n"),
+            );
+        }
+
+        let outcome = load_plugins_from_config(&plugin_config_toml(true, true), codex_home.path());
+
+        assert_eq!(
+            outcome.plugins[0].capabilities,
+            PluginCapabilities {
+                has_skills: true,
+                has_mcp_servers: false,
+                skill_count: 2,
+                mcp_server_count: 0,
+                declared_capabilities: vec!["search".to_string(), "review".to_string()],
+            }
+        );
+        assert_eq!(
+            outcome.summary(),
+            "sample@test: enabled, 2 skills, 0 MCP servers, capabilities: search, review"
+        );
+
+        let disabled =
+            load_plugins_from_config(&plugin_config_toml(false, true), codex_home.path());
+        assert_eq!(disabled.summary(), "sample@test: disabled");
+        assert_eq!(
+            PluginLoadOutcome::default().summary(),
+            "No plugins configured."
+        );
+    }
+
+    #[test]
+    fn reload_replaces_cached_outcome_with_changed_mcp_servers() {
+        let codex_home = TempDir::new().unwrap();
+        let plugin_root = codex_home
+            .path()
+            .join("plugins/cache")
+            .join("test/sample/local");
+        let write_mcp_config = |url: &str| {
+            write_file(
+                &plugin_root.join(".mcp.json"),
+                &format!(r#"{{"mcpServers":{{"sample":{{"type":"http","url":"{url}"}}}}}}"#),
+            );
+        };
+        write_file(
+            &plugin_root.join(".codex-plugin/plugin.json"),
+            r#"{"name":"sample"}"#,
+        );
+        write_mcp_config("https://old.example/mcp");
+
+        let config_toml = plugin_config_toml(true, true);
+        write_file(&codex_home.path().join(CONFIG_TOML_FILE), &config_toml);
+        let stack = ConfigLayerStack::new(
+            vec![ConfigLayerEntry::new(
+                ConfigLayerSource::User {
+                    file: AbsolutePathBuf::try_from(codex_home.path().join(CONFIG_TOML_FILE))
+                        .unwrap(),
+                },
+                toml::from_str(&config_toml).expect("plugin test config should parse"),
+            )],
+            ConfigRequirements::default(),
+            ConfigRequirementsToml::default(),
+        )
+        .expect("config layer stack should build");
+        let manager = PluginsManager::new(codex_home.path().to_path_buf());
+        let before = manager.plugins_for_layer_stack(codex_home.path(), &stack, false);
+
+        write_mcp_config("https://new.example/mcp");
+        assert_eq!(
+            manager.plugins_for_layer_stack(codex_home.path(), &stack, false),
+            before
+        );
+
+        let reloaded = manager.reload(codex_home.path(), &stack);
+        let server_url = |outcome: &PluginLoadOutcome| match outcome
+            .effective_mcp_servers()
+            .remove("sample")
+            .map(|cfg| cfg.transport)
+        {
+            Some(McpServerTransportConfig::StreamableHttp { url, .. }) => Some(url),
+            _ => None,
+        };
+        assert_eq!(
+            server_url(&reloaded),
+            Some("https://new.example/mcp".to_string())
+        );
+        assert_eq!(
+            plugins_with_changed_mcp_servers(&before, &reloaded),
+            vec!["sample@test".to_string()]
+        );
+        assert_eq!(
+            manager.plugins_for_layer_stack(codex_home.path(), &stack, false),
+            reloaded
+        );
+        assert_eq!(
+            plugins_with_changed_mcp_servers(&reloaded, &reloaded),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn plugin_cache_evicts_least_recently_used_cwd() {
+        let codex_home = TempDir::new().unwrap();
+        let config_toml = plugin_config_toml(true, true);
+        write_file(&codex_home.path().join(CONFIG_TOML_FILE), &config_toml);
+        let stack = ConfigLayerStack::new(
+            vec![ConfigLayerEntry::new(
+                ConfigLayerSource::User {
+                    file: AbsolutePathBuf::try_from(codex_home.path().join(CONFIG_TOML_FILE))
+                        .unwrap(),
+                },
+                toml::from_str(&config_toml).expect("plugin test config should parse"),
+            )],
+            ConfigRequirements::default(),
+            ConfigRequirementsToml::default(),
+        )
+        .expect("config layer stack should build");
+        let manager = PluginsManager::with_cache_max_entries(codex_home.path().to_path_buf(), 2);
+        let cwd = |name: &str| codex_home.path().join(name);
+
+        manager.plugins_for_layer_stack(&cwd("a"), &stack, false);
+        manager.plugins_for_layer_stack(&cwd("b"), &stack, false);
+        // Touch `a` so `b` becomes the least recently used entry.
+        manager.plugins_for_layer_stack(&cwd("a"), &stack, false);
+        manager.plugins_for_layer_stack(&cwd("c"), &stack, false);
+
+        assert!(manager.cached_outcome_for_cwd(&cwd("a")).is_some());
+        assert!(manager.cached_outcome_for_cwd(&cwd("b")).is_none());
+        assert!(manager.cached_outcome_for_cwd(&cwd("c")).is_some());
+
+        manager.plugins_for_layer_stack(&cwd("b"), &stack, false);
+        assert!(manager.cached_outcome_for_cwd(&cwd("b")).is_some());
+        assert_eq!(manager.cache_by_cwd.read().unwrap().len(), 2);
+    }
+
     #[test]
     fn load_plugins_uses_manifest_configured_component_paths() {
         let codex_home = TempDir::new().unwrap();
@@ -1508,6 +1918,8 @@ mod tests {
                     disabled_tools: None,
                     scopes: None,
                     oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
                 },
             )])
         );
@@ -1611,6 +2023,8 @@ mod tests {
                     disabled_tools: None,
                     scopes: None,
                     oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
                 },
             )])
         );
@@ -1620,6 +2034,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn load_plugins_rejects_plugins_requiring_newer_codex() {
+        let codex_home = TempDir::new().unwrap();
+        let plugin_root = codex_home
+            .path()
+            .join("plugins/cache")
+            .join("test/sample/local");
+
+        write_file(
+            &plugin_root.join(".codex-plugin/plugin.json"),
+            r#"{
+  "name": "sample",
+  "version": "1.2.0",
+  "minCodexVersion": "999.0.0"
+}"#,
+        );
+        write_file(&plugin_root.join("skills/SKILL.md"), "skill");
+
+        let outcome = load_plugins_from_config(&plugin_config_toml(true, true), codex_home.path());
+
+        let plugin = &outcome.plugins()[0];
+        assert_eq!(
+            plugin.error,
+            Some(format!(
+                "plugin requires Codex 999.0.0 or newer, but this is Codex {}",
+                env!("CARGO_PKG_VERSION")
+            ))
+        );
+        assert!(plugin.version_incompatible);
+        assert_eq!(outcome.incompatible_plugins(), vec![plugin]);
+        assert!(outcome.effective_skill_roots().is_empty());
+        assert!(outcome.capability_summaries().is_empty());
+    }
+
+    #[test]
+    fn load_plugins_resolves_manifest_task_hooks() {
+        let codex_home = TempDir::new().unwrap();
+        let plugin_root = codex_home
+            .path()
+            .join("plugins/cache")
+            .join("test/sample/local");
+
+        write_file(
+            &plugin_root.join(".codex-plugin/plugin.json"),
+            r#"{
+  "name": "sample",
+  "hooks": {
+    "preTask": "./hooks/setup.sh",
+    "postTask": "../teardown.sh"
+  }
+}"#,
+        );
+
+        let outcome = load_plugins_from_config(&plugin_config_toml(true, true), codex_home.path());
+
+        let expected_hooks = PluginTaskHooks {
+            pre_task: Some(AbsolutePathBuf::try_from(plugin_root.join("hooks/setup.sh")).unwrap()),
+            // Paths escaping the plugin root are ignored.
+            post_task: None,
+        };
+        assert_eq!(outcome.plugins()[0].task_hooks, expected_hooks);
+        assert_eq!(
+            outcome.effective_task_hooks(),
+            vec![("sample@test".to_string(), expected_hooks)]
+        );
+    }
+
+    #[test]
+    fn check_plugin_versions_validates_semver_fields() {
+        let manifest = |version: Option<&str>, min_codex_version: Option<&str>| PluginManifest {
+            version: version.map(str::to_string),
+            min_codex_version: min_codex_version.map(str::to_string),
+            ..Default::default()
+        };
+
+        assert!(check_plugin_versions(&manifest(Some("1.0.0"), Some("0.40.0")), "0.41.2").is_ok());
+        assert!(check_plugin_versions(&manifest(None, Some("0.41.2")), "0.41.2").is_ok());
+        assert!(matches!(
+            check_plugin_versions(&manifest(None, Some("0.42.0")), "0.41.2"),
+            Err(PluginVersionError::RequiresNewerCodex { .. })
+        ));
+        assert!(matches!(
+            check_plugin_versions(&manifest(Some("latest"), None), "0.41.2"),
+            Err(PluginVersionError::InvalidVersion { .. })
+        ));
+        assert!(matches!(
+            check_plugin_versions(&manifest(None, Some(">=0.40")), "0.41.2"),
+            Err(PluginVersionError::InvalidMinCodexVersion { .. })
+        ));
+    }
+
     #[test]
     fn load_plugins_preserves_disabled_plugins_without_effective_contributions() {
         let codex_home = TempDir::new().unwrap();
@@ -1652,12 +2157,16 @@ mod tests {
                 config_name: "sample@test".to_string(),
                 manifest_name: None,
                 manifest_description: None,
+                manifest_version: None,
                 root: AbsolutePathBuf::try_from(plugin_root).unwrap(),
                 enabled: false,
                 skill_roots: Vec::new(),
                 mcp_servers: HashMap::new(),
                 apps: Vec::new(),
+                task_hooks: PluginTaskHooks::default(),
+                capabilities: PluginCapabilities::default(),
                 error: None,
+                version_incompatible: false,
             }]
         );
         assert!(outcome.effective_skill_roots().is_empty());
@@ -1758,17 +2267,23 @@ mod tests {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         };
         let plugin = |config_name: &str, dir_name: &str, manifest_name: &str| LoadedPlugin {
             config_name: config_name.to_string(),
             manifest_name: Some(manifest_name.to_string()),
             manifest_description: None,
+            manifest_version: None,
             root: AbsolutePathBuf::try_from(codex_home.path().join(dir_name)).unwrap(),
             enabled: true,
             skill_roots: Vec::new(),
             mcp_servers: HashMap::new(),
             apps: Vec::new(),
+            task_hooks: PluginTaskHooks::default(),
+            capabilities: PluginCapabilities::default(),
             error: None,
+            version_incompatible: false,
         };
         let summary = |config_name: &str, display_name: &str| PluginCapabilitySummary {
             config_name: config_name.to_string(),
@@ -13,6 +13,15 @@ pub(crate) struct PluginManifest {
     pub(crate) name: String,
     #[serde(default)]
     pub(crate) description: Option<String>,
+    /// Semver version of the plugin itself.
+    #[serde(default)]
+    pub(crate) version: Option<String>,
+    /// Oldest Codex release (semver) the plugin works with.
+    #[serde(default)]
+    pub(crate) min_codex_version: Option<String>,
+    /// Free-form capability tags the plugin declares about itself.
+    #[serde(default)]
+    pub(crate) capabilities: Vec<String>,
     // Keep manifest paths as raw strings so we can validate the required `./...` syntax before
     // resolving them under the plugin root.
     #[serde(default)]
@@ -23,6 +32,8 @@ pub(crate) struct PluginManifest {
     apps: Option<String>,
     #[serde(default)]
     interface: Option<PluginManifestInterface>,
+    #[serde(default)]
+    hooks: Option<PluginManifestHooks>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,6 +41,16 @@ pub struct PluginManifestPaths {
     pub skills: Option<AbsolutePathBuf>,
     pub mcp_servers: Option<AbsolutePathBuf>,
     pub apps: Option<AbsolutePathBuf>,
+    pub task_hooks: PluginTaskHooks,
+}
+
+/// Scripts a plugin runs around every task, resolved under the plugin root.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginTaskHooks {
+    /// Runs after `TurnStarted`, before the first model request.
+    pub pre_task: Option<AbsolutePathBuf>,
+    /// Runs once the task is done, before `TurnComplete`.
+    pub post_task: Option<AbsolutePathBuf>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -86,6 +107,15 @@ struct PluginManifestInterface {
     screenshots: Vec<String>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PluginManifestHooks {
+    #[serde(default)]
+    pre_task: Option<String>,
+    #[serde(default)]
+    post_task: Option<String>,
+}
+
 pub(crate) fn load_plugin_manifest(plugin_root: &Path) -> Option<PluginManifest> {
     let manifest_path = plugin_root.join(PLUGIN_MANIFEST_PATH);
     if !manifest_path.is_file() {
@@ -179,6 +209,22 @@ pub(crate) fn plugin_manifest_paths(
             manifest.mcp_servers.as_deref(),
         ),
         apps: resolve_manifest_path(plugin_root, "apps", manifest.apps.as_deref()),
+        task_hooks: manifest
+            .hooks
+            .as_ref()
+            .map(|hooks| PluginTaskHooks {
+                pre_task: resolve_manifest_path(
+                    plugin_root,
+                    "hooks.preTask",
+                    hooks.pre_task.as_deref(),
+                ),
+                post_task: resolve_manifest_path(
+                    plugin_root,
+                    "hooks.postTask",
+                    hooks.post_task.as_deref(),
+                ),
+            })
+            .unwrap_or_default(),
     }
 }
 
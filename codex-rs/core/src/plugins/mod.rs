@@ -5,6 +5,8 @@ mod manifest;
 mod marketplace;
 mod render;
 mod store;
+mod task_hooks;
+mod validation;
 
 pub(crate) use curated_repo::curated_plugins_repo_path;
 pub(crate) use curated_repo::sync_openai_plugins_repo;
@@ -13,6 +15,7 @@ pub use manager::AppConnectorId;
 pub use manager::ConfiguredMarketplacePluginSummary;
 pub use manager::ConfiguredMarketplaceSummary;
 pub use manager::LoadedPlugin;
+pub use manager::PluginCapabilities;
 pub use manager::PluginCapabilitySummary;
 pub use manager::PluginInstallError;
 pub use manager::PluginInstallRequest;
@@ -25,6 +28,7 @@ pub use manager::load_plugin_apps;
 pub(crate) use manager::plugin_namespace_for_skill_path;
 pub use manifest::PluginManifestInterfaceSummary;
 pub(crate) use manifest::PluginManifestPaths;
+pub use manifest::PluginTaskHooks;
 pub(crate) use manifest::load_plugin_manifest;
 pub(crate) use manifest::plugin_manifest_interface;
 pub(crate) use manifest::plugin_manifest_name;
@@ -35,3 +39,6 @@ pub(crate) use render::render_explicit_plugin_instructions;
 pub(crate) use render::render_plugins_section;
 pub use store::PluginId;
 pub use store::PluginInstallResult;
+pub(crate) use task_hooks::PluginTaskHookStage;
+pub(crate) use task_hooks::run_plugin_task_hooks;
+pub use validation::PluginValidationError;
@@ -0,0 +1,164 @@
+//! Runs the `hooks.preTask` / `hooks.postTask` scripts declared by enabled
+//! plugins around each regular task.
+//!
+//! Scripts run one after another in the turn's working directory, under the
+//! turn's sandbox policy, and are killed when the task is interrupted. Their
+//! output is surfaced as background events; a failing script is reported the
+//! same way and never stops the task. Scripts that run without a sandbox are
+//! recorded in the sandbox bypass audit log.
+
+use codex_async_utils::OrCancelExt;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use tokio_util::sync::CancellationToken;
+
+use crate::codex::Session;
+use crate::codex::TurnContext;
+use crate::exec::ExecParams;
+use crate::exec::SandboxType;
+use crate::exec::build_exec_request;
+use crate::exec_env::create_env;
+use crate::features::Feature;
+use crate::sandboxing::SandboxPermissions;
+use crate::sandboxing::execute_env;
+use crate::tools::runtimes::dry_run_output;
+use crate::tools::sandbox_audit::record_sandbox_bypass;
+
+const PLUGIN_TASK_HOOK_TIMEOUT_MS: u64 = 60_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PluginTaskHookStage {
+    PreTask,
+    PostTask,
+}
+
+impl PluginTaskHookStage {
+    fn label(self) -> &'static str {
+        match self {
+            PluginTaskHookStage::PreTask => "preTask",
+            PluginTaskHookStage::PostTask => "postTask",
+        }
+    }
+}
+
+/// Runs every hook for `stage`, stopping early once `cancellation_token` is
+/// cancelled.
+pub(crate) async fn run_plugin_task_hooks(
+    sess: &Session,
+    turn_context: &TurnContext,
+    stage: PluginTaskHookStage,
+    cancellation_token: &CancellationToken,
+) {
+    let scripts = sess
+        .services
+        .plugins_manager
+        .plugins_for_config(&turn_context.config)
+        .effective_task_hooks()
+        .into_iter()
+        .filter_map(|(plugin, hooks)| {
+            let script = match stage {
+                PluginTaskHookStage::PreTask => hooks.pre_task,
+                PluginTaskHookStage::PostTask => hooks.post_task,
+            };
+            script.map(|script| (plugin, script))
+        })
+        .collect::<Vec<_>>();
+    for (plugin, script) in scripts {
+        if cancellation_token.is_cancelled() {
+            return;
+        }
+        run_plugin_task_hook(
+            sess,
+            turn_context,
+            stage,
+            &plugin,
+            &script,
+            cancellation_token,
+        )
+        .await;
+    }
+}
+
+async fn run_plugin_task_hook(
+    sess: &Session,
+    turn_context: &TurnContext,
+    stage: PluginTaskHookStage,
+    plugin: &str,
+    script: &AbsolutePathBuf,
+    cancellation_token: &CancellationToken,
+) {
+    let label = format!("Plugin {plugin} {} hook", stage.label());
+    if turn_context.config.dry_run {
+        let output = dry_run_output(format!("{label} not executed: {}", script.display()));
+        sess.notify_background_event(turn_context, output.aggregated_output.text)
+            .await;
+        return;
+    }
+    let params = ExecParams {
+        command: vec![script.to_string_lossy().into_owned()],
+        cwd: turn_context.cwd.clone(),
+        expiration: PLUGIN_TASK_HOOK_TIMEOUT_MS.into(),
+        env: create_env(
+            &turn_context.shell_environment_policy,
+            Some(sess.conversation_id),
+        ),
+        network: None,
+        sandbox_permissions: SandboxPermissions::UseDefault,
+        windows_sandbox_level: turn_context.windows_sandbox_level,
+        justification: None,
+        arg0: None,
+        stdin: None,
+    };
+    let exec_req = match build_exec_request(
+        params,
+        turn_context.sandbox_policy.get(),
+        &turn_context.file_system_sandbox_policy,
+        turn_context.network_sandbox_policy,
+        turn_context.cwd.as_path(),
+        &turn_context.codex_linux_sandbox_exe,
+        turn_context.features.enabled(Feature::UseLinuxSandboxBwrap),
+    ) {
+        Ok(exec_req) => exec_req,
+        Err(err) => {
+            sess.notify_background_event(turn_context, format!("{label} failed: {err}"))
+                .await;
+            return;
+        }
+    };
+    if exec_req.sandbox == SandboxType::None
+        && let Some(log_path) = turn_context.config.sandbox_bypass_audit_log.as_deref()
+    {
+        let call_id = format!("plugin-hook:{plugin}:{}", stage.label());
+        record_sandbox_bypass(log_path, &call_id, &exec_req.command, &exec_req.cwd, None).await;
+    }
+    // Dropping the exec future on cancellation kills the script.
+    let result = execute_env(exec_req, None)
+        .or_cancel(cancellation_token)
+        .await;
+
+    let output = match result {
+        Err(_) => return,
+        Ok(Ok(output)) => output,
+        Ok(Err(err)) => {
+            sess.notify_background_event(turn_context, format!("{label} failed: {err}"))
+                .await;
+            return;
+        }
+    };
+    for (stream, text) in [
+        ("stdout", &output.stdout.text),
+        ("stderr", &output.stderr.text),
+    ] {
+        let text = text.trim_end();
+        if !text.is_empty() {
+            sess.notify_background_event(turn_context, format!("{label} {stream}:\n{text}"))
+                .await;
+        }
+    }
+    if output.exit_code != 0 {
+        sess.notify_background_event(
+            turn_context,
+            format!("{label} exited with code {}", output.exit_code),
+        )
+        .await;
+    }
+}
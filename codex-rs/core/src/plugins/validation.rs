@@ -0,0 +1,250 @@
+//! Checks a plugin directory for the problems that would stop it from loading,
+//! so users can fix them before adding the plugin to their config.
+
+use super::manager::PluginMcpFile;
+use super::manager::normalize_plugin_mcp_server_value;
+use super::manager::plugin_mcp_config_paths;
+use super::manager::plugin_skill_roots;
+use super::manifest::PLUGIN_MANIFEST_PATH;
+use super::manifest::PluginManifest;
+use super::plugin_manifest_paths;
+use crate::config::types::McpServerConfig;
+use crate::skills::loader::SkillRoot;
+use crate::skills::loader::load_skills_from_roots;
+use codex_protocol::protocol::SkillScope;
+use serde_json::Value as JsonValue;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// A problem found by [`validate_plugin`], pointing at the file to fix.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PluginValidationError {
+    #[error("{} does not exist or is not a directory", path.display())]
+    NotADirectory { path: PathBuf },
+
+    #[error("missing plugin manifest {}; create it with at least a `name` field", path.display())]
+    MissingManifest { path: PathBuf },
+
+    #[error("failed to parse plugin manifest {}: {message}", path.display())]
+    InvalidManifest { path: PathBuf, message: String },
+
+    #[error("`name` in {} must not be empty", path.display())]
+    EmptyName { path: PathBuf },
+
+    #[error("failed to read MCP config {}: {message}", path.display())]
+    InvalidMcpConfig { path: PathBuf, message: String },
+
+    #[error("invalid MCP server `{server}` in {}: {message}", path.display())]
+    InvalidMcpServer {
+        path: PathBuf,
+        server: String,
+        message: String,
+    },
+
+    #[error("invalid skill {}: {message}", path.display())]
+    InvalidSkill { path: PathBuf, message: String },
+}
+
+impl PluginValidationError {
+    /// The file or directory the problem was found in.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::NotADirectory { path }
+            | Self::MissingManifest { path }
+            | Self::InvalidManifest { path, .. }
+            | Self::EmptyName { path }
+            | Self::InvalidMcpConfig { path, .. }
+            | Self::InvalidMcpServer { path, .. }
+            | Self::InvalidSkill { path, .. } => path,
+        }
+    }
+}
+
+/// Returns every problem found in the plugin at `plugin_root`; an empty list
+/// means the plugin is ready to be added to the config.
+pub(crate) fn validate_plugin(plugin_root: &Path) -> Vec<PluginValidationError> {
+    if !plugin_root.is_dir() {
+        return vec![PluginValidationError::NotADirectory {
+            path: plugin_root.to_path_buf(),
+        }];
+    }
+
+    let manifest_path = plugin_root.join(PLUGIN_MANIFEST_PATH);
+    if !manifest_path.is_file() {
+        return vec![PluginValidationError::MissingManifest {
+            path: manifest_path,
+        }];
+    }
+    let manifest = match fs::read_to_string(&manifest_path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| {
+            serde_json::from_str::<PluginManifest>(&contents).map_err(|err| err.to_string())
+        }) {
+        Ok(manifest) => manifest,
+        Err(message) => {
+            return vec![PluginValidationError::InvalidManifest {
+                path: manifest_path,
+                message,
+            }];
+        }
+    };
+
+    let mut errors = Vec::new();
+    if manifest.name.trim().is_empty() {
+        errors.push(PluginValidationError::EmptyName {
+            path: manifest_path,
+        });
+    }
+
+    let manifest_paths = plugin_manifest_paths(&manifest, plugin_root);
+    for mcp_config_path in plugin_mcp_config_paths(plugin_root, &manifest_paths) {
+        errors.extend(validate_mcp_config(plugin_root, mcp_config_path.as_path()));
+    }
+
+    let skill_roots = plugin_skill_roots(plugin_root, &manifest_paths);
+    let skills = load_skills_from_roots(skill_roots.into_iter().map(|path| SkillRoot {
+        path,
+        scope: SkillScope::User,
+    }));
+    errors.extend(
+        skills
+            .errors
+            .into_iter()
+            .map(|error| PluginValidationError::InvalidSkill {
+                path: error.path,
+                message: error.message,
+            }),
+    );
+
+    errors
+}
+
+fn validate_mcp_config(plugin_root: &Path, path: &Path) -> Vec<PluginValidationError> {
+    let parsed = match fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| {
+            serde_json::from_str::<PluginMcpFile>(&contents).map_err(|err| err.to_string())
+        }) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            return vec![PluginValidationError::InvalidMcpConfig {
+                path: path.to_path_buf(),
+                message,
+            }];
+        }
+    };
+
+    let mut servers = parsed.mcp_servers.into_iter().collect::<Vec<_>>();
+    servers.sort_unstable_by(|(left, _), (right, _)| left.cmp(right));
+    servers
+        .into_iter()
+        .filter_map(|(server, value)| {
+            let normalized = normalize_plugin_mcp_server_value(plugin_root, value);
+            serde_json::from_value::<McpServerConfig>(JsonValue::Object(normalized))
+                .err()
+                .map(|err| PluginValidationError::InvalidMcpServer {
+                    path: path.to_path_buf(),
+                    server,
+                    message: err.to_string(),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn write_file(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().expect("file should have a parent")).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn valid_plugin_has_no_errors() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write_file(&root.join(PLUGIN_MANIFEST_PATH), r#"{"name":"sample"}"#);
+        write_file(
+            &root.join("skills/review/SKILL.md"),
+            "---\nname: review\ndescription: Review code\n---\n\n# Review\n",
+        );
+        write_file(
+            &root.join(".mcp.json"),
+            r#"{"mcpServers":{"docs":{"command":"docs-server"}}}"#,
+        );
+
+        assert_eq!(validate_plugin(root), Vec::new());
+    }
+
+    #[test]
+    fn missing_directory_and_manifest_are_reported() {
+        let tmp = TempDir::new().unwrap();
+        let missing = tmp.path().join("missing");
+        assert_eq!(
+            validate_plugin(&missing),
+            vec![PluginValidationError::NotADirectory { path: missing }]
+        );
+
+        assert_eq!(
+            validate_plugin(tmp.path()),
+            vec![PluginValidationError::MissingManifest {
+                path: tmp.path().join(PLUGIN_MANIFEST_PATH),
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_every_problem_in_a_broken_plugin() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let manifest_path = root.join(PLUGIN_MANIFEST_PATH);
+        let mcp_path = root.join(".mcp.json");
+        let skill_path = root.join("skills/broken/SKILL.md");
+        write_file(&manifest_path, r#"{"name":"  "}"#);
+        write_file(&mcp_path, r#"{"mcpServers":{"bad":{"command":42}}}"#);
+        write_file(&skill_path, "no frontmatter here");
+
+        let errors = validate_plugin(root);
+        let summary = errors
+            .iter()
+            .map(|error| match error {
+                PluginValidationError::InvalidMcpServer { path, server, .. } => {
+                    format!("mcp {server} {}", path.display())
+                }
+                PluginValidationError::InvalidSkill { .. } => "skill".to_string(),
+                other => other.to_string(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                PluginValidationError::EmptyName {
+                    path: manifest_path,
+                }
+                .to_string(),
+                format!("mcp bad {}", mcp_path.display()),
+                "skill".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn unparseable_manifest_is_reported() {
+        let tmp = TempDir::new().unwrap();
+        let manifest_path = tmp.path().join(PLUGIN_MANIFEST_PATH);
+        write_file(&manifest_path, "{not json");
+
+        let errors = validate_plugin(tmp.path());
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [PluginValidationError::InvalidManifest { path, .. }] if *path == manifest_path
+            ),
+            "unexpected errors: {errors:?}"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Turn cost estimates from per-model token prices.
+//!
+//! A price table for well-known models is bundled with the binary; entries
+//! under `pricing` in config.toml replace the bundled price of the same model.
+
+use std::collections::HashMap;
+
+use codex_protocol::protocol::TokenUsage;
+
+use crate::config::types::ModelPricing;
+
+const DEFAULT_MODEL_PRICING_TOML: &str = include_str!("model_pricing.toml");
+
+const TOKENS_PER_MILLION: f64 = 1_000_000.0;
+
+/// Returns the bundled price table with `overrides` applied on top.
+pub(crate) fn resolve_model_pricing(
+    overrides: Option<HashMap<String, ModelPricing>>,
+) -> HashMap<String, ModelPricing> {
+    let mut pricing = default_model_pricing();
+    pricing.extend(overrides.unwrap_or_default());
+    pricing
+}
+
+#[expect(clippy::expect_used)]
+fn default_model_pricing() -> HashMap<String, ModelPricing> {
+    toml::from_str(DEFAULT_MODEL_PRICING_TOML).expect("bundled model pricing should parse")
+}
+
+/// Estimated cost of the tokens a single turn consumed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TurnCostEstimate {
+    pub(crate) input_tokens: i64,
+    pub(crate) output_tokens: i64,
+    pub(crate) cost_usd: f64,
+}
+
+impl TurnCostEstimate {
+    /// Prices `usage` for `model`, or returns `None` when the model has no
+    /// entry in `pricing`. Cached input is billed at the regular input rate,
+    /// so the estimate errs on the high side.
+    pub(crate) fn for_model(
+        pricing: &HashMap<String, ModelPricing>,
+        model: &str,
+        usage: &TokenUsage,
+    ) -> Option<Self> {
+        let pricing = pricing.get(model)?;
+        let input_tokens = usage.input_tokens.max(0);
+        let output_tokens = usage.output_tokens.max(0);
+        let cost_usd = (input_tokens as f64 * pricing.input_per_million
+            + output_tokens as f64 * pricing.output_per_million)
+            / TOKENS_PER_MILLION;
+        Some(Self {
+            input_tokens,
+            output_tokens,
+            cost_usd,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn bundled_pricing_parses_and_overrides_replace_entries() {
+        let defaults = resolve_model_pricing(None);
+        assert!(defaults.contains_key("gpt-5.1-codex"));
+
+        let custom = ModelPricing {
+            input_per_million: 3.0,
+            output_per_million: 15.0,
+        };
+        let pricing = resolve_model_pricing(Some(HashMap::from([
+            ("gpt-5.1-codex".to_string(), custom),
+            ("my-model".to_string(), custom),
+        ])));
+        assert_eq!(pricing.get("gpt-5.1-codex"), Some(&custom));
+        assert_eq!(pricing.get("my-model"), Some(&custom));
+        assert_eq!(pricing.get("gpt-5"), defaults.get("gpt-5"));
+    }
+
+    #[test]
+    fn estimate_multiplies_tokens_by_model_price() {
+        let pricing = HashMap::from([(
+            "my-model".to_string(),
+            ModelPricing {
+                input_per_million: 2.0,
+                output_per_million: 8.0,
+            },
+        )]);
+        let usage = TokenUsage {
+            input_tokens: 500_000,
+            cached_input_tokens: 100_000,
+            output_tokens: 250_000,
+            reasoning_output_tokens: 50_000,
+            total_tokens: 750_000,
+        };
+
+        assert_eq!(
+            TurnCostEstimate::for_model(&pricing, "my-model", &usage),
+            Some(TurnCostEstimate {
+                input_tokens: 500_000,
+                output_tokens: 250_000,
+                cost_usd: 3.0,
+            })
+        );
+        assert_eq!(
+            TurnCostEstimate::for_model(&pricing, "unknown-model", &usage),
+            None
+        );
+    }
+}
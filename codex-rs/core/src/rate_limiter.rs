@@ -0,0 +1,102 @@
+//! Caps how many model requests a session makes per minute, so a runaway
+//! agent cannot burn tokens in a tight loop.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Length of the sliding window used by [`TurnRateLimiter`].
+pub(crate) const TURN_RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Returned by [`TurnRateLimiter::try_acquire`] when the window is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RateLimitExceeded {
+    /// Time until the oldest request leaves the window.
+    pub(crate) retry_after: Duration,
+}
+
+/// Counts model requests (the inner turns of a task) in a sliding window.
+#[derive(Debug)]
+pub(crate) struct TurnRateLimiter {
+    window: Duration,
+    started_at: VecDeque<Instant>,
+}
+
+impl Default for TurnRateLimiter {
+    fn default() -> Self {
+        Self::new(TURN_RATE_LIMIT_WINDOW)
+    }
+}
+
+impl TurnRateLimiter {
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            started_at: VecDeque::new(),
+        }
+    }
+
+    /// Records a turn starting at `now` if fewer than `max_turns` started
+    /// within the window; otherwise reports how long to wait.
+    pub(crate) fn try_acquire(
+        &mut self,
+        max_turns: u32,
+        now: Instant,
+    ) -> Result<(), RateLimitExceeded> {
+        while self
+            .started_at
+            .front()
+            .is_some_and(|started| now.saturating_duration_since(*started) >= self.window)
+        {
+            self.started_at.pop_front();
+        }
+        if self.started_at.len() < max_turns as usize {
+            self.started_at.push_back(now);
+            return Ok(());
+        }
+        let retry_after = self.started_at.front().map_or(self.window, |oldest| {
+            self.window
+                .saturating_sub(now.saturating_duration_since(*oldest))
+        });
+        Err(RateLimitExceeded { retry_after })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn allows_up_to_max_turns_per_window() {
+        let mut limiter = TurnRateLimiter::default();
+        let start = Instant::now();
+        assert_eq!(limiter.try_acquire(2, start), Ok(()));
+        assert_eq!(
+            limiter.try_acquire(2, start + Duration::from_secs(10)),
+            Ok(())
+        );
+        assert_eq!(
+            limiter.try_acquire(2, start + Duration::from_secs(20)),
+            Err(RateLimitExceeded {
+                retry_after: Duration::from_secs(40),
+            })
+        );
+    }
+
+    #[test]
+    fn turns_leave_the_window_after_it_elapses() {
+        let mut limiter = TurnRateLimiter::default();
+        let start = Instant::now();
+        assert_eq!(limiter.try_acquire(1, start), Ok(()));
+        assert!(
+            limiter
+                .try_acquire(1, start + Duration::from_secs(59))
+                .is_err()
+        );
+        assert_eq!(
+            limiter.try_acquire(1, start + Duration::from_secs(60)),
+            Ok(())
+        );
+    }
+}
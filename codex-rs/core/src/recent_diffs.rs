@@ -0,0 +1,207 @@
+//! Compact summaries of the file changes made in recent turns, used to remind
+//! the model what it already changed when `include_recent_diffs_in_context`
+//! is enabled.
+
+use std::collections::VecDeque;
+
+/// Number of turns whose diff summaries are retained.
+const RECENT_TURN_DIFFS_CAPACITY: usize = 5;
+
+/// Upper bound on the size of the rendered context note.
+const RECENT_DIFFS_CONTEXT_MAX_BYTES: usize = 2_000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct FileDiffStat {
+    pub(crate) path: String,
+    pub(crate) added: usize,
+    pub(crate) removed: usize,
+}
+
+/// Per-file line stats for the changes made in one turn.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct TurnDiffSummary {
+    pub(crate) files: Vec<FileDiffStat>,
+}
+
+impl TurnDiffSummary {
+    /// Summarizes a unified diff as produced by `TurnDiffTracker`.
+    pub(crate) fn from_unified_diff(unified_diff: &str) -> Self {
+        let mut files: Vec<FileDiffStat> = Vec::new();
+        // `---`/`+++` are file headers only before the first hunk; inside a
+        // hunk they are removed or added lines that start with `--`/`++`.
+        let mut in_hunk = false;
+        for line in unified_diff.lines() {
+            if let Some(header) = line.strip_prefix("diff --git ") {
+                in_hunk = false;
+                let path = header
+                    .rsplit_once(" b/")
+                    .map_or(header, |(_, path)| path)
+                    .to_string();
+                files.push(FileDiffStat {
+                    path,
+                    added: 0,
+                    removed: 0,
+                });
+                continue;
+            }
+            let Some(file) = files.last_mut() else {
+                continue;
+            };
+            if line.starts_with("@@") {
+                in_hunk = true;
+                continue;
+            }
+            if !in_hunk && (line.starts_with("+++") || line.starts_with("---")) {
+                continue;
+            }
+            if line.starts_with('+') {
+                file.added += 1;
+            } else if line.starts_with('-') {
+                file.removed += 1;
+            }
+        }
+        Self { files }
+    }
+}
+
+/// Diff summaries for the most recent turns that changed files, oldest first.
+#[derive(Debug, Default)]
+pub(crate) struct RecentTurnDiffs {
+    turns: VecDeque<TurnDiffSummary>,
+}
+
+impl RecentTurnDiffs {
+    pub(crate) fn record(&mut self, summary: TurnDiffSummary) {
+        if summary.files.is_empty() {
+            return;
+        }
+        if self.turns.len() == RECENT_TURN_DIFFS_CAPACITY {
+            self.turns.pop_front();
+        }
+        self.turns.push_back(summary);
+    }
+
+    /// Renders the retained summaries, most recent turn first, dropping older
+    /// entries once the note would exceed `RECENT_DIFFS_CONTEXT_MAX_BYTES`.
+    pub(crate) fn render_context(&self) -> Option<String> {
+        if self.turns.is_empty() {
+            return None;
+        }
+        let mut lines = Vec::new();
+        for (index, turn) in self.turns.iter().rev().enumerate() {
+            lines.push(format!("- {} turn(s) ago:", index + 1));
+            for file in &turn.files {
+                lines.push(format!(
+                    "  - {} (+{}/-{})",
+                    file.path, file.added, file.removed
+                ));
+            }
+        }
+
+        let mut note =
+            "Files you changed in recent turns (most recent first; +added/-removed lines):\n"
+                .to_string();
+        for line in lines {
+            if note.len() + line.len() + 1 > RECENT_DIFFS_CONTEXT_MAX_BYTES {
+                note.push_str("(older changes omitted)\n");
+                break;
+            }
+            note.push_str(&line);
+            note.push('\n');
+        }
+        Some(note)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs\n\
+index 1111111..2222222 100644\n\
+--- a/src/lib.rs\n\
++++ b/src/lib.rs\n\
+@@ -1,2 +1,3 @@\n\
+-old\n\
++new\n\
++more\n\
+ same\n\
+diff --git a/README.md b/README.md\n\
+new file mode 100644\n\
+--- /dev/null\n\
++++ b/README.md\n\
+@@ -0,0 +1 @@\n\
++hello\n";
+
+    #[test]
+    fn summarizes_unified_diff_per_file() {
+        assert_eq!(
+            TurnDiffSummary::from_unified_diff(DIFF),
+            TurnDiffSummary {
+                files: vec![
+                    FileDiffStat {
+                        path: "src/lib.rs".to_string(),
+                        added: 2,
+                        removed: 1,
+                    },
+                    FileDiffStat {
+                        path: "README.md".to_string(),
+                        added: 1,
+                        removed: 0,
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn counts_hunk_lines_that_look_like_file_headers() {
+        let diff = "diff --git a/notes.md b/notes.md\n\
+index 1111111..2222222 100644\n\
+--- a/notes.md\n\
++++ b/notes.md\n\
+@@ -1,2 +1,2 @@\n\
+---- old rule\n\
+++++ new rule\n\
+ same\n";
+        assert_eq!(
+            TurnDiffSummary::from_unified_diff(diff),
+            TurnDiffSummary {
+                files: vec![FileDiffStat {
+                    path: "notes.md".to_string(),
+                    added: 1,
+                    removed: 1,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn renders_most_recent_turn_first_within_cap() {
+        let mut recent = RecentTurnDiffs::default();
+        assert_eq!(recent.render_context(), None);
+
+        recent.record(TurnDiffSummary::from_unified_diff(DIFF));
+        recent.record(TurnDiffSummary::from_unified_diff(
+            "diff --git a/notes.txt b/notes.txt\n+line\n",
+        ));
+        assert_eq!(
+            recent.render_context(),
+            Some(
+                "Files you changed in recent turns (most recent first; +added/-removed lines):\n\
+- 1 turn(s) ago:\n  - notes.txt (+1/-0)\n\
+- 2 turn(s) ago:\n  - src/lib.rs (+2/-1)\n  - README.md (+1/-0)\n"
+                    .to_string()
+            )
+        );
+
+        let huge = (0..500)
+            .map(|index| format!("diff --git a/file{index}.rs b/file{index}.rs\n+x\n"))
+            .collect::<String>();
+        recent.record(TurnDiffSummary::from_unified_diff(&huge));
+        let rendered = recent.render_context().expect("note should render");
+        assert!(rendered.len() <= RECENT_DIFFS_CONTEXT_MAX_BYTES + 64);
+        assert!(rendered.ends_with("(older changes omitted)\n"));
+    }
+}
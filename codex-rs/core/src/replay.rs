@@ -0,0 +1,168 @@
+//! Plays a saved rollout back to a frontend without contacting the model.
+//!
+//! Only the events the rollout recorded are replayed, in file order, paced by
+//! the gaps between their original timestamps (capped at a few seconds).
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Context;
+use async_channel::Sender;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use codex_protocol::protocol::Event;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::BufReader;
+use tracing::trace;
+
+use crate::codex::INITIAL_SUBMIT_ID;
+
+/// Longest pause between two replayed events, so idle time in the original
+/// session (e.g. a user away overnight) does not stall the replay.
+const MAX_REPLAY_DELAY: Duration = Duration::from_secs(3);
+
+/// Reads the rollout at `path` and sends its recorded events to `tx_event`.
+///
+/// Before each event, sleeps for the time that separated it from the
+/// previous event in the original session divided by `speed`, so `2.0`
+/// replays twice as fast, capped at [`MAX_REPLAY_DELAY`]. Events get the id
+/// of the turn they belong to, taken from the preceding `TurnStarted` event.
+/// Returns early, without error, when the receiver is dropped.
+pub async fn replay(path: &Path, tx_event: Sender<Event>, speed: f32) -> anyhow::Result<()> {
+    if !speed.is_finite() || speed <= 0.0 {
+        anyhow::bail!("replay speed must be a positive number, got {speed}");
+    }
+
+    let file = tokio::fs::File::open(path)
+        .await
+        .with_context(|| format!("failed to open rollout {}", path.display()))?;
+    let mut lines = BufReader::new(file).lines();
+    let mut turn_id = INITIAL_SUBMIT_ID.to_string();
+    let mut previous_timestamp: Option<DateTime<FixedOffset>> = None;
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let rollout_line = match serde_json::from_str::<RolloutLine>(&line) {
+            Ok(rollout_line) => rollout_line,
+            Err(e) => {
+                trace!("failed to parse rollout line: {e}");
+                continue;
+            }
+        };
+        let RolloutItem::EventMsg(msg) = rollout_line.item else {
+            continue;
+        };
+
+        let timestamp = DateTime::parse_from_rfc3339(&rollout_line.timestamp).ok();
+        if let (Some(previous), Some(current)) = (previous_timestamp, timestamp) {
+            tokio::time::sleep(replay_delay(previous, current, speed)).await;
+        }
+        previous_timestamp = timestamp.or(previous_timestamp);
+
+        if let EventMsg::TurnStarted(event) = &msg {
+            turn_id = event.turn_id.clone();
+        }
+        let event = Event {
+            id: turn_id.clone(),
+            msg,
+        };
+        if tx_event.send(event).await.is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// The original gap between two events scaled by `speed` and capped at
+/// [`MAX_REPLAY_DELAY`]; out-of-order timestamps replay without a delay.
+fn replay_delay(
+    previous: DateTime<FixedOffset>,
+    current: DateTime<FixedOffset>,
+    speed: f32,
+) -> Duration {
+    (current - previous)
+        .to_std()
+        .map(|gap| {
+            // A tiny `speed` can overflow `Duration`; that is longer than the
+            // cap anyway.
+            Duration::try_from_secs_f32(gap.as_secs_f32() / speed)
+                .map_or(MAX_REPLAY_DELAY, |delay| delay.min(MAX_REPLAY_DELAY))
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::TurnStartedEvent;
+    use pretty_assertions::assert_eq;
+
+    fn rollout_line(timestamp: &str, msg: EventMsg) -> String {
+        serde_json::to_string(&RolloutLine {
+            timestamp: timestamp.to_string(),
+            item: RolloutItem::EventMsg(msg),
+        })
+        .expect("serialize rollout line")
+    }
+
+    #[tokio::test]
+    async fn replays_events_in_order_with_turn_ids() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("rollout.jsonl");
+        let lines = [
+            rollout_line(
+                "2026-01-01T00:00:00.000Z",
+                EventMsg::TurnStarted(TurnStartedEvent {
+                    turn_id: "turn-1".to_string(),
+                    model_context_window: None,
+                    collaboration_mode_kind: Default::default(),
+                }),
+            ),
+            "not json".to_string(),
+            rollout_line(
+                "2026-01-01T00:00:01.000Z",
+                EventMsg::AgentMessage(AgentMessageEvent {
+                    message: "hello".to_string(),
+                    phase: None,
+                }),
+            ),
+        ];
+        std::fs::write(&path, lines.join("\n")).expect("write rollout");
+
+        let (tx, rx) = async_channel::unbounded();
+        replay(&path, tx, 1000.0).await.expect("replay");
+
+        let events = std::iter::from_fn(|| rx.try_recv().ok()).collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0].msg, EventMsg::TurnStarted(_)));
+        let EventMsg::AgentMessage(message) = &events[1].msg else {
+            panic!("expected agent message, got {:?}", events[1].msg);
+        };
+        assert_eq!(message.message, "hello");
+        assert_eq!(events[1].id, "turn-1");
+    }
+
+    #[test]
+    fn delay_scales_with_speed() {
+        let previous = DateTime::parse_from_rfc3339("2026-01-01T00:00:00.000Z").expect("ts");
+        let current = DateTime::parse_from_rfc3339("2026-01-01T00:00:02.000Z").expect("ts");
+        assert_eq!(
+            replay_delay(previous, current, 4.0),
+            Duration::from_millis(500)
+        );
+        assert_eq!(replay_delay(current, previous, 1.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn delay_is_capped() {
+        let previous = DateTime::parse_from_rfc3339("2026-01-01T00:00:00.000Z").expect("ts");
+        let current = DateTime::parse_from_rfc3339("2026-01-01T08:00:00.000Z").expect("ts");
+        assert_eq!(replay_delay(previous, current, 1.0), MAX_REPLAY_DELAY);
+        assert_eq!(replay_delay(previous, current, 1e-30), MAX_REPLAY_DELAY);
+    }
+}
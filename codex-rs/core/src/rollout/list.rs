@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use std::cmp::Reverse;
+use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::io::{self};
 use std::num::NonZero;
@@ -97,12 +98,16 @@ struct HeadTailSummary {
     cli_version: Option<String>,
     created_at: Option<String>,
     updated_at: Option<String>,
+    /// File this rotated rollout continues from.
+    previous_rollout_path: Option<PathBuf>,
 }
 
 /// Hard cap to bound worst‑case work per request.
 const MAX_SCAN_FILES: usize = 10000;
 const HEAD_RECORD_LIMIT: usize = 10;
 const USER_EVENT_SCAN_LIMIT: usize = 200;
+/// Upper bound on the rollout files the id lookup considers for one thread.
+const MAX_ROLLOUT_SEGMENT_MATCHES: usize = 256;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ThreadSortKey {
@@ -197,6 +202,7 @@ struct FilesByCreatedAtVisitor<'a> {
     items: &'a mut Vec<ThreadItem>,
     page_size: usize,
     anchor_state: AnchorState,
+    seen_ids: HashSet<Uuid>,
     more_matches_available: bool,
     allowed_sources: &'a [SessionSource],
     provider_matcher: Option<&'a ProviderMatcher<'a>>,
@@ -215,6 +221,12 @@ impl<'a> RolloutFileVisitor for FilesByCreatedAtVisitor<'a> {
             self.more_matches_available = true;
             return ControlFlow::Break(());
         }
+        // Rotated rollouts share their thread's id; only the newest file is
+        // listed. Ids are tracked before the anchor so later pages skip the
+        // older files too.
+        if !self.seen_ids.insert(id) {
+            return ControlFlow::Continue(());
+        }
         if self.anchor_state.should_skip(ts, id) {
             return ControlFlow::Continue(());
         }
@@ -450,6 +462,7 @@ async fn traverse_directories_for_paths_created(
         items: &mut items,
         page_size,
         anchor_state: AnchorState::new(anchor),
+        seen_ids: HashSet::new(),
         more_matches_available,
         allowed_sources,
         provider_matcher,
@@ -497,13 +510,23 @@ async fn traverse_directories_for_paths_updated(
 
     let candidates = collect_files_by_updated_at(&root, &mut scanned_files).await?;
     let mut candidates = candidates;
-    candidates.sort_by_key(|candidate| {
-        let ts = candidate.updated_at.unwrap_or(OffsetDateTime::UNIX_EPOCH);
-        (Reverse(ts), Reverse(candidate.id))
+    // Ties on a rotated thread's files go to the newest file, which sorts last.
+    candidates.sort_by(|left, right| {
+        let key = |candidate: &ThreadCandidate| {
+            let ts = candidate.updated_at.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+            (Reverse(ts), Reverse(candidate.id))
+        };
+        key(left)
+            .cmp(&key(right))
+            .then_with(|| right.path.cmp(&left.path))
     });
 
+    let mut seen_ids = HashSet::new();
     for candidate in candidates.into_iter() {
         let ts = candidate.updated_at.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+        if !seen_ids.insert(candidate.id) {
+            continue;
+        }
         if anchor_state.should_skip(ts, candidate.id) {
             continue;
         }
@@ -556,7 +579,11 @@ async fn traverse_flat_paths_created(
     let mut more_matches_available = false;
 
     let files = collect_flat_rollout_files(&root, &mut scanned_files).await?;
+    let mut seen_ids = HashSet::new();
     for (ts, id, path) in files.into_iter() {
+        if !seen_ids.insert(id) {
+            continue;
+        }
         if anchor_state.should_skip(ts, id) {
             continue;
         }
@@ -607,13 +634,23 @@ async fn traverse_flat_paths_updated(
 
     let candidates = collect_flat_files_by_updated_at(&root, &mut scanned_files).await?;
     let mut candidates = candidates;
-    candidates.sort_by_key(|candidate| {
-        let ts = candidate.updated_at.unwrap_or(OffsetDateTime::UNIX_EPOCH);
-        (Reverse(ts), Reverse(candidate.id))
+    // Ties on a rotated thread's files go to the newest file, which sorts last.
+    candidates.sort_by(|left, right| {
+        let key = |candidate: &ThreadCandidate| {
+            let ts = candidate.updated_at.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+            (Reverse(ts), Reverse(candidate.id))
+        };
+        key(left)
+            .cmp(&key(right))
+            .then_with(|| right.path.cmp(&left.path))
     });
 
+    let mut seen_ids = HashSet::new();
     for candidate in candidates.into_iter() {
         let ts = candidate.updated_at.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+        if !seen_ids.insert(candidate.id) {
+            continue;
+        }
         if anchor_state.should_skip(ts, candidate.id) {
             continue;
         }
@@ -695,9 +732,12 @@ async fn build_thread_item(
     updated_at: Option<String>,
 ) -> Option<ThreadItem> {
     // Read head and detect message events; stop once meta + user are found.
-    let summary = read_head_summary(&path, HEAD_RECORD_LIMIT)
+    let mut summary = read_head_summary(&path, HEAD_RECORD_LIMIT)
         .await
         .unwrap_or_default();
+    if !summary.saw_user_event {
+        fill_user_message_from_previous_rollouts(&path, &mut summary).await;
+    }
     if !allowed_sources.is_empty()
         && !summary
             .source
@@ -752,6 +792,27 @@ async fn build_thread_item(
     None
 }
 
+/// A rotated rollout may not have a user message yet; take the first one from
+/// the files it continues from so the thread stays listed.
+async fn fill_user_message_from_previous_rollouts(path: &Path, summary: &mut HeadTailSummary) {
+    let mut visited = HashSet::from([path.to_path_buf()]);
+    let mut previous_rollout_path = summary.previous_rollout_path.clone();
+    while let Some(previous) = previous_rollout_path.take() {
+        if !visited.insert(previous.clone()) {
+            break;
+        }
+        let Ok(previous_summary) = read_head_summary(&previous, HEAD_RECORD_LIMIT).await else {
+            break;
+        };
+        if previous_summary.saw_user_event {
+            summary.saw_user_event = true;
+            summary.first_user_message = previous_summary.first_user_message;
+            break;
+        }
+        previous_rollout_path = previous_summary.previous_rollout_path;
+    }
+}
+
 /// Collects immediate subdirectories of `parent`, parses their (string) names with `parse`,
 /// and returns them sorted descending by the parsed key.
 async fn collect_dirs_desc<T, F>(parent: &Path, parse: F) -> io::Result<Vec<(T, PathBuf)>>
@@ -1046,6 +1107,8 @@ async fn read_head_summary(path: &Path, head_limit: usize) -> io::Result<HeadTai
                         .and_then(|git| git.repository_url.clone());
                     summary.cli_version = Some(session_meta_line.meta.cli_version);
                     summary.created_at = Some(session_meta_line.meta.timestamp.clone());
+                    summary.previous_rollout_path =
+                        session_meta_line.meta.previous_rollout_path.clone();
                     summary.saw_session_meta = true;
                 }
             }
@@ -1213,9 +1276,10 @@ async fn find_thread_path_by_id_str_in_subdir(
     if !root.exists() {
         return Ok(None);
     }
-    // This is safe because we know the values are valid.
+    // A rotated thread has one file per segment; ask for enough matches to
+    // see all of them. This is safe because we know the values are valid.
     #[allow(clippy::unwrap_used)]
-    let limit = NonZero::new(1).unwrap();
+    let limit = NonZero::new(MAX_ROLLOUT_SEGMENT_MATCHES).unwrap();
     let options = file_search::FileSearchOptions {
         limit,
         compute_indices: false,
@@ -1226,7 +1290,18 @@ async fn find_thread_path_by_id_str_in_subdir(
     let results = file_search::run(id_str, vec![root], options, None)
         .map_err(|e| io::Error::other(format!("file search failed: {e}")))?;
 
-    let found = results.matches.into_iter().next().map(|m| m.full_path());
+    // Rollout filenames start with their creation timestamp, so the newest
+    // segment of a rotated thread has the greatest name.
+    let found = results
+        .matches
+        .into_iter()
+        .map(|m| m.full_path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(OsStr::to_str)
+                .is_some_and(|name| name.contains(id_str))
+        })
+        .max_by(|left, right| left.file_name().cmp(&right.file_name()));
     if let Some(found_path) = found.as_ref() {
         tracing::debug!("state db missing rollout path for thread {id_str}");
         tracing::warn!(
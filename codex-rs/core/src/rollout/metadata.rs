@@ -484,6 +484,7 @@ mod tests {
             base_instructions: None,
             dynamic_tools: None,
             memory_mode: None,
+            previous_rollout_path: None,
         };
         let session_meta_line = SessionMetaLine {
             meta: session_meta,
@@ -535,6 +536,7 @@ mod tests {
             base_instructions: None,
             dynamic_tools: None,
             memory_mode: None,
+            previous_rollout_path: None,
         };
         let polluted_meta = SessionMeta {
             memory_mode: Some("polluted".to_string()),
@@ -806,6 +808,7 @@ mod tests {
             base_instructions: None,
             dynamic_tools: None,
             memory_mode: None,
+            previous_rollout_path: None,
         };
         let session_meta_line = SessionMetaLine {
             meta: session_meta,
@@ -12,6 +12,7 @@ pub mod list;
 pub(crate) mod metadata;
 pub(crate) mod policy;
 pub mod recorder;
+pub(crate) mod retention;
 pub(crate) mod session_index;
 pub(crate) mod truncation;
 
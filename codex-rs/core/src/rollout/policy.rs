@@ -36,6 +36,7 @@ pub(crate) fn should_persist_response_item(item: &ResponseItem) -> bool {
         | ResponseItem::CustomToolCallOutput { .. }
         | ResponseItem::WebSearchCall { .. }
         | ResponseItem::ImageGenerationCall { .. }
+        | ResponseItem::ProviderToolCall { .. }
         | ResponseItem::GhostSnapshot { .. }
         | ResponseItem::Compaction { .. } => true,
         ResponseItem::Other => false,
@@ -55,6 +56,7 @@ pub(crate) fn should_persist_response_item_for_memories(item: &ResponseItem) ->
         | ResponseItem::WebSearchCall { .. } => true,
         ResponseItem::Reasoning { .. }
         | ResponseItem::ImageGenerationCall { .. }
+        | ResponseItem::ProviderToolCall { .. }
         | ResponseItem::GhostSnapshot { .. }
         | ResponseItem::Compaction { .. }
         | ResponseItem::Other => false,
@@ -99,6 +101,7 @@ fn event_msg_persistence_mode(ev: &EventMsg) -> Option<EventPersistenceMode> {
         | EventMsg::ExitedReviewMode(_)
         | EventMsg::ThreadRolledBack(_)
         | EventMsg::UndoCompleted(_)
+        | EventMsg::BaseInstructionsUpdated(_)
         | EventMsg::TurnAborted(_)
         | EventMsg::TurnStarted(_)
         | EventMsg::TurnComplete(_) => Some(EventPersistenceMode::Limited),
@@ -119,6 +122,7 @@ fn event_msg_persistence_mode(ev: &EventMsg) -> Option<EventPersistenceMode> {
         | EventMsg::McpToolCallEnd(_)
         | EventMsg::ViewImageToolCall(_)
         | EventMsg::ImageGenerationEnd(_)
+        | EventMsg::ProviderToolCall(_)
         | EventMsg::CollabAgentSpawnEnd(_)
         | EventMsg::CollabAgentInteractionEnd(_)
         | EventMsg::CollabWaitingEnd(_)
@@ -144,6 +148,7 @@ fn event_msg_persistence_mode(ev: &EventMsg) -> Option<EventPersistenceMode> {
         | EventMsg::TerminalInteraction(_)
         | EventMsg::ExecCommandOutputDelta(_)
         | EventMsg::ExecApprovalRequest(_)
+        | EventMsg::ExecApprovalBatchRequest(_)
         | EventMsg::RequestPermissions(_)
         | EventMsg::RequestUserInput(_)
         | EventMsg::ElicitationRequest(_)
@@ -157,10 +162,34 @@ fn event_msg_persistence_mode(ev: &EventMsg) -> Option<EventPersistenceMode> {
         | EventMsg::McpListToolsResponse(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
+        | EventMsg::McpHealthCheckResult(_)
         | EventMsg::ListCustomPromptsResponse(_)
         | EventMsg::ListSkillsResponse(_)
         | EventMsg::ListRemoteSkillsResponse(_)
         | EventMsg::RemoteSkillDownloaded(_)
+        | EventMsg::McpToolCallError(_)
+        | EventMsg::PauseStateChanged(_)
+        | EventMsg::PatchCheckResult(_)
+        | EventMsg::RecentErrors(_)
+        | EventMsg::ConversationSnapshot(_)
+        | EventMsg::ApprovalPolicyChanged(_)
+        | EventMsg::WorkingDirectoryChanged(_)
+        | EventMsg::ExportComplete(_)
+        | EventMsg::AssistantFinished(_)
+        | EventMsg::ToolCallArgumentsPreview(_)
+        | EventMsg::SessionForked(_)
+        | EventMsg::RolloutRotated(_)
+        | EventMsg::RetryConfig(_)
+        | EventMsg::SearchHistoryResults(_)
+        | EventMsg::Features(_)
+        | EventMsg::TokenBudget(_)
+        | EventMsg::SessionStats(_)
+        | EventMsg::PluginList(_)
+        | EventMsg::BlameResult(_)
+        | EventMsg::LastReasoning(_)
+        | EventMsg::PluginValidationResult(_)
+        | EventMsg::WorkingTreeHash(_)
+        | EventMsg::SessionStateExported(_)
         | EventMsg::PlanUpdate(_)
         | EventMsg::ShutdownComplete
         | EventMsg::DeprecationNotice(_)
@@ -1,5 +1,6 @@
 //! Persist Codex session rollouts (.jsonl) so sessions can be replayed or inspected later.
 
+use std::collections::HashSet;
 use std::fs::File;
 use std::fs::{self};
 use std::io::Error as IoError;
@@ -16,7 +17,10 @@ use time::OffsetDateTime;
 use time::format_description::FormatItem;
 use time::format_description::well_known::Rfc3339;
 use time::macros::format_description;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncWrite;
 use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
 use tokio::sync::mpsc::Sender;
 use tokio::sync::mpsc::{self};
 use tokio::sync::oneshot;
@@ -36,10 +40,14 @@ use super::list::get_threads;
 use super::list::get_threads_in_root;
 use super::list::parse_cursor;
 use super::list::parse_timestamp_uuid_from_filename;
+use super::list::read_session_meta_line;
 use super::metadata;
 use super::policy::EventPersistenceMode;
 use super::policy::is_persisted_response_item;
+use super::retention;
 use crate::config::Config;
+use crate::context_manager::is_user_turn_boundary;
+use crate::conversation_export::markdown_section;
 use crate::default_client::originator;
 use crate::git_info::collect_git_info;
 use crate::path_utils;
@@ -415,6 +423,7 @@ impl RolloutRecorder {
                         },
                         memory_mode: (!config.memories.generate_memories)
                             .then_some("disabled".to_string()),
+                        previous_rollout_path: None,
                     };
 
                     (
@@ -442,6 +451,22 @@ impl RolloutRecorder {
                 ),
             };
 
+        if let Some(retention) = config.rollout_retention {
+            // Pruning walks the whole sessions tree, so keep it off the
+            // session startup path.
+            let codex_home = config.codex_home.clone();
+            let keep = rollout_path.clone();
+            let state_db = state_db_ctx.clone();
+            tokio::spawn(async move {
+                if let Err(err) =
+                    retention::prune_rollouts(&codex_home, retention, &keep, state_db.as_deref())
+                        .await
+                {
+                    warn!("failed to prune old rollout files: {err}");
+                }
+            });
+        }
+
         // Clone the cwd for the spawned task to collect git info asynchronously
         let cwd = config.cwd.clone();
 
@@ -527,10 +552,67 @@ impl RolloutRecorder {
             .map_err(|e| IoError::other(format!("failed waiting for rollout flush: {e}")))
     }
 
+    /// Close this rollout and start a fresh file for the same thread.
+    ///
+    /// The current file is materialized and flushed first. The new file starts
+    /// with a copy of the current `SessionMeta` whose `previous_rollout_path`
+    /// points back at this file, so resuming from it replays the whole chain.
+    /// Like [`RolloutRecorder::new`], the new file is only created on `persist()`.
+    pub(crate) async fn rotate(&self, config: &Config) -> std::io::Result<Self> {
+        self.persist().await?;
+        self.flush().await?;
+        self.shutdown().await?;
+
+        let previous_path = self.rollout_path.clone();
+        let mut meta = read_session_meta_line(previous_path.as_path()).await?.meta;
+        let log_file_info = precompute_rotated_log_file_info(config, meta.id)?;
+        let timestamp_format: &[FormatItem] = format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z"
+        );
+        meta.timestamp = log_file_info
+            .timestamp
+            .to_offset(time::UtcOffset::UTC)
+            .format(timestamp_format)
+            .map_err(|e| IoError::other(format!("failed to format timestamp: {e}")))?;
+        meta.previous_rollout_path = Some(previous_path);
+
+        let rollout_path = log_file_info.path.clone();
+        let (tx, rx) = mpsc::channel::<RolloutCmd>(256);
+        tokio::task::spawn(rollout_writer(
+            None,
+            Some(log_file_info),
+            rx,
+            Some(meta),
+            config.cwd.clone(),
+            rollout_path.clone(),
+            self.state_db.clone(),
+            None,
+            config.model_provider_id.clone(),
+            config.memories.generate_memories,
+        ));
+
+        Ok(Self {
+            tx,
+            rollout_path,
+            state_db: self.state_db.clone(),
+            event_persistence_mode: self.event_persistence_mode,
+        })
+    }
+
     pub(crate) async fn load_rollout_items(
         path: &Path,
+    ) -> std::io::Result<(Vec<RolloutItem>, Option<ThreadId>, usize)> {
+        Self::load_rollout_chain(path, &mut HashSet::new()).await
+    }
+
+    /// Loads `path` after the files it continues from. `visited` holds the
+    /// files already loaded so a cyclic chain cannot recurse forever.
+    async fn load_rollout_chain(
+        path: &Path,
+        visited: &mut HashSet<PathBuf>,
     ) -> std::io::Result<(Vec<RolloutItem>, Option<ThreadId>, usize)> {
         trace!("Resuming rollout from {path:?}");
+        visited.insert(path.to_path_buf());
         let text = tokio::fs::read_to_string(path).await?;
         if text.trim().is_empty() {
             return Err(IoError::other("empty session file"));
@@ -583,6 +665,27 @@ impl RolloutRecorder {
             }
         }
 
+        // Rotated rollouts only hold the tail of the thread; prepend the files
+        // they continue from so callers always see the full history.
+        let previous_rollout_path = items.iter().find_map(|item| match item {
+            RolloutItem::SessionMeta(session_meta_line) => {
+                Some(session_meta_line.meta.previous_rollout_path.clone())
+            }
+            _ => None,
+        });
+        if let Some(Some(previous_rollout_path)) = previous_rollout_path
+            && previous_rollout_is_loadable(previous_rollout_path.as_path(), visited).await
+        {
+            let (mut previous_items, previous_thread_id, previous_parse_errors) = Box::pin(
+                Self::load_rollout_chain(previous_rollout_path.as_path(), visited),
+            )
+            .await?;
+            previous_items.append(&mut items);
+            items = previous_items;
+            thread_id = previous_thread_id.or(thread_id);
+            parse_errors = parse_errors.saturating_add(previous_parse_errors);
+        }
+
         tracing::debug!(
             "Resumed rollout with {} items, thread ID: {:?}, parse errors: {}",
             items.len(),
@@ -609,6 +712,23 @@ impl RolloutRecorder {
         }))
     }
 
+    /// Writes this rollout to `out` as a Markdown transcript.
+    ///
+    /// The file is read one line at a time, so large sessions are never held
+    /// in memory. Rotated rollouts are exported from their first file on, and
+    /// turns removed by a rollback are left out. Sections are rendered like
+    /// the in-memory Markdown export; lines that fail to parse are skipped.
+    pub async fn export_markdown(
+        &self,
+        out: &mut (dyn AsyncWrite + Unpin + Send),
+    ) -> anyhow::Result<()> {
+        self.flush().await?;
+        write_markdown_sections(self.rollout_path.clone(), out).await?;
+        out.write_all(b"\n").await?;
+        out.flush().await?;
+        Ok(())
+    }
+
     pub async fn shutdown(&self) -> std::io::Result<()> {
         let (tx_done, rx_done) = oneshot::channel();
         match self.tx.send(RolloutCmd::Shutdown { ack: tx_done }).await {
@@ -626,6 +746,164 @@ impl RolloutRecorder {
     }
 }
 
+/// Whether the file a rotated rollout continues from can be loaded. A file
+/// that was deleted (for example by retention) is treated as the start of the
+/// thread, and a file already loaded means the chain loops back on itself.
+async fn previous_rollout_is_loadable(path: &Path, visited: &HashSet<PathBuf>) -> bool {
+    if visited.contains(path) {
+        warn!(
+            "rollout chain loops back to {}; ignoring the earlier link",
+            path.display()
+        );
+        return false;
+    }
+    if !tokio::fs::try_exists(path).await.unwrap_or(false) {
+        warn!(
+            "previous rollout {} is missing; history starts at the next file",
+            path.display()
+        );
+        return false;
+    }
+    true
+}
+
+/// Files of the rollout chain that ends at `path`, oldest first. The chain
+/// stops at a missing file or a file it already contains.
+async fn rollout_chain_paths(path: PathBuf) -> Vec<PathBuf> {
+    let mut visited = HashSet::from([path.clone()]);
+    let mut chain = vec![path];
+    while let Some(current) = chain.last()
+        && let Ok(meta_line) = read_session_meta_line(current).await
+        && let Some(previous) = meta_line.meta.previous_rollout_path
+        && previous_rollout_is_loadable(previous.as_path(), &visited).await
+    {
+        visited.insert(previous.clone());
+        chain.push(previous);
+    }
+    chain.reverse();
+    chain
+}
+
+/// Reads the items of a rollout chain in order, one line at a time. Lines
+/// that fail to parse are skipped.
+struct RolloutItemReader {
+    paths: std::vec::IntoIter<PathBuf>,
+    lines: Option<tokio::io::Lines<BufReader<tokio::fs::File>>>,
+}
+
+impl RolloutItemReader {
+    fn new(paths: Vec<PathBuf>) -> Self {
+        Self {
+            paths: paths.into_iter(),
+            lines: None,
+        }
+    }
+
+    async fn next_item(&mut self) -> std::io::Result<Option<RolloutItem>> {
+        loop {
+            let Some(lines) = self.lines.as_mut() else {
+                let Some(path) = self.paths.next() else {
+                    return Ok(None);
+                };
+                let file = tokio::fs::File::open(&path).await?;
+                self.lines = Some(BufReader::new(file).lines());
+                continue;
+            };
+            let Some(line) = lines.next_line().await? else {
+                self.lines = None;
+                continue;
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<RolloutLine>(&line) {
+                Ok(rollout_line) => return Ok(Some(rollout_line.item)),
+                Err(e) => trace!("failed to parse rollout line: {e}"),
+            }
+        }
+    }
+}
+
+/// Item index ranges that `ThreadRolledBack` events removed, sorted and
+/// merged. Like history reconstruction, a rollback drops the newest user turns
+/// from their user message up to the rollback itself. Undo only removes a
+/// ghost snapshot, which the export does not render.
+async fn rolled_back_item_ranges(
+    paths: Vec<PathBuf>,
+) -> std::io::Result<Vec<std::ops::Range<usize>>> {
+    let mut reader = RolloutItemReader::new(paths);
+    let mut user_turn_starts = Vec::new();
+    let mut ranges: Vec<std::ops::Range<usize>> = Vec::new();
+    let mut index = 0usize;
+    while let Some(item) = reader.next_item().await? {
+        match item {
+            RolloutItem::ResponseItem(item) if is_user_turn_boundary(&item) => {
+                user_turn_starts.push(index);
+            }
+            RolloutItem::EventMsg(EventMsg::ThreadRolledBack(rollback)) => {
+                let num_turns = usize::try_from(rollback.num_turns).unwrap_or(usize::MAX);
+                let kept = user_turn_starts.len().saturating_sub(num_turns);
+                if let Some(&start) = user_turn_starts.get(kept) {
+                    ranges.push(start..index);
+                }
+                user_turn_starts.truncate(kept);
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<std::ops::Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    Ok(merged)
+}
+
+async fn write_markdown_sections(
+    path: PathBuf,
+    out: &mut (dyn AsyncWrite + Unpin + Send),
+) -> anyhow::Result<()> {
+    let paths = rollout_chain_paths(path).await;
+    let rolled_back = rolled_back_item_ranges(paths.clone()).await?;
+    let mut rolled_back = rolled_back.iter().peekable();
+    let mut reader = RolloutItemReader::new(paths);
+    let mut wrote_section = false;
+    let mut index = 0usize;
+    while let Some(item) = reader.next_item().await? {
+        let item_index = index;
+        index += 1;
+        while rolled_back
+            .peek()
+            .is_some_and(|range| range.end <= item_index)
+        {
+            rolled_back.next();
+        }
+        if rolled_back
+            .peek()
+            .is_some_and(|range| range.contains(&item_index))
+        {
+            continue;
+        }
+        let RolloutItem::ResponseItem(item) = item else {
+            continue;
+        };
+        let Some(section) = markdown_section(&item) else {
+            continue;
+        };
+        if wrote_section {
+            out.write_all(b"\n\n").await?;
+        }
+        out.write_all(section.as_bytes()).await?;
+        wrote_section = true;
+    }
+    Ok(())
+}
+
 fn truncate_fs_page(
     mut page: ThreadsPage,
     page_size: usize,
@@ -690,6 +968,29 @@ fn precompute_log_file_info(
     })
 }
 
+/// Like [`precompute_log_file_info`], but never returns the path of an
+/// existing file. Rollout filenames only have second precision, so a rotation
+/// within the same second as the previous file moves the timestamp forward.
+fn precompute_rotated_log_file_info(
+    config: &Config,
+    conversation_id: ThreadId,
+) -> std::io::Result<LogFileInfo> {
+    let mut log_file_info = precompute_log_file_info(config, conversation_id)?;
+    while log_file_info.path.exists() {
+        let timestamp = log_file_info.timestamp + time::Duration::SECOND;
+        let format: &[FormatItem] =
+            format_description!("[year]-[month]-[day]T[hour]-[minute]-[second]");
+        let date_str = timestamp
+            .format(format)
+            .map_err(|e| IoError::other(format!("failed to format timestamp: {e}")))?;
+        log_file_info.path = log_file_info
+            .path
+            .with_file_name(format!("rollout-{date_str}-{conversation_id}.jsonl"));
+        log_file_info.timestamp = timestamp;
+    }
+    Ok(log_file_info)
+}
+
 fn open_log_file(path: &Path) -> std::io::Result<File> {
     let Some(parent) = path.parent() else {
         return Err(IoError::other(format!(
@@ -717,6 +1018,7 @@ async fn rollout_writer(
     default_provider: String,
     generate_memories: bool,
 ) -> std::io::Result<()> {
+    let _open_rollout = retention::OpenRolloutGuard::new(&rollout_path);
     let mut writer = file.map(|file| JsonlWriter { file });
     let mut buffered_items = Vec::<RolloutItem>::new();
     if let Some(builder) = state_builder.as_mut() {
@@ -1108,10 +1410,15 @@ mod tests {
     use crate::features::Feature;
     use chrono::TimeZone;
     use codex_protocol::config_types::ReasoningSummary as ReasoningSummaryConfig;
+    use codex_protocol::models::ContentItem;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use codex_protocol::models::ReasoningItemReasoningSummary;
+    use codex_protocol::models::ResponseItem;
     use codex_protocol::protocol::AgentMessageEvent;
     use codex_protocol::protocol::AskForApproval;
     use codex_protocol::protocol::EventMsg;
     use codex_protocol::protocol::SandboxPolicy;
+    use codex_protocol::protocol::ThreadRolledBackEvent;
     use codex_protocol::protocol::TurnContextItem;
     use codex_protocol::protocol::UserMessageEvent;
     use pretty_assertions::assert_eq;
@@ -1242,6 +1549,160 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn export_markdown_renders_rollout_as_transcript() -> anyhow::Result<()> {
+        let home = TempDir::new().expect("temp dir");
+        let config = ConfigBuilder::default()
+            .codex_home(home.path().to_path_buf())
+            .build()
+            .await?;
+        let recorder = RolloutRecorder::new(
+            &config,
+            RolloutRecorderParams::new(
+                ThreadId::new(),
+                None,
+                SessionSource::Exec,
+                BaseInstructions::default(),
+                Vec::new(),
+                EventPersistenceMode::Limited,
+            ),
+            None,
+            None,
+        )
+        .await?;
+        recorder.persist().await?;
+
+        let message = |role: &str, text: &str| {
+            RolloutItem::ResponseItem(ResponseItem::Message {
+                id: None,
+                role: role.to_string(),
+                content: vec![ContentItem::InputText {
+                    text: text.to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            })
+        };
+        let patch = "*** Begin Patch\n*** Add File: a.txt\n+hello\n*** End Patch";
+        recorder
+            .record_items(&[
+                message("user", "add a file"),
+                RolloutItem::ResponseItem(ResponseItem::Reasoning {
+                    id: String::new(),
+                    summary: vec![ReasoningItemReasoningSummary::SummaryText {
+                        text: "Creating a.txt.".to_string(),
+                    }],
+                    content: None,
+                    encrypted_content: None,
+                }),
+                RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                    id: None,
+                    name: "shell".to_string(),
+                    arguments: r#"{"command":["ls"]}"#.to_string(),
+                    call_id: "call-1".to_string(),
+                }),
+                RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput {
+                    call_id: "call-1".to_string(),
+                    output: FunctionCallOutputPayload::from_text("b.txt".to_string()),
+                }),
+                RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                    id: None,
+                    status: None,
+                    call_id: "call-2".to_string(),
+                    name: "apply_patch".to_string(),
+                    input: patch.to_string(),
+                }),
+                message("assistant", "Added a.txt."),
+            ])
+            .await?;
+
+        let mut out = Vec::new();
+        recorder.export_markdown(&mut out).await?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            format!(
+                "### User\n\nadd a file\n\n\
+> Creating a.txt.\n\n\
+<details>\n<summary>Tool call: <code>shell</code></summary>\n\n\
+```json\n{{\"command\":[\"ls\"]}}\n```\n\n</details>\n\n\
+<details>\n<summary>Tool output</summary>\n\n```\nb.txt\n```\n\n</details>\n\n\
+**Patch:**\n\n```diff\n{patch}\n```\n\n\
+### Assistant\n\nAdded a.txt.\n"
+            )
+        );
+
+        recorder.shutdown().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn export_markdown_leaves_out_rolled_back_turns() -> anyhow::Result<()> {
+        let home = TempDir::new().expect("temp dir");
+        let config = ConfigBuilder::default()
+            .codex_home(home.path().to_path_buf())
+            .build()
+            .await?;
+        let recorder = RolloutRecorder::new(
+            &config,
+            RolloutRecorderParams::new(
+                ThreadId::new(),
+                None,
+                SessionSource::Exec,
+                BaseInstructions::default(),
+                Vec::new(),
+                EventPersistenceMode::Limited,
+            ),
+            None,
+            None,
+        )
+        .await?;
+        recorder.persist().await?;
+
+        let message = |role: &str, text: &str| {
+            RolloutItem::ResponseItem(ResponseItem::Message {
+                id: None,
+                role: role.to_string(),
+                content: vec![ContentItem::InputText {
+                    text: text.to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            })
+        };
+        let rollback = |num_turns| {
+            RolloutItem::EventMsg(EventMsg::ThreadRolledBack(ThreadRolledBackEvent {
+                num_turns,
+            }))
+        };
+        recorder
+            .record_items(&[
+                message("user", "first"),
+                message("assistant", "one"),
+                message("user", "second"),
+                message("assistant", "two"),
+                rollback(1),
+                message("user", "third"),
+                message("assistant", "three"),
+                message("user", "fourth"),
+                message("assistant", "four"),
+                rollback(1),
+            ])
+            .await?;
+
+        let mut out = Vec::new();
+        recorder.export_markdown(&mut out).await?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "### User\n\nfirst\n\n### Assistant\n\none\n\n\
+### User\n\nthird\n\n### Assistant\n\nthree\n"
+        );
+
+        recorder.shutdown().await?;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn metadata_irrelevant_events_touch_state_db_updated_at() -> std::io::Result<()> {
         let home = TempDir::new().expect("temp dir");
@@ -0,0 +1,372 @@
+//! Pruning of old rollout files according to [`RolloutRetention`].
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::sync::Mutex as StdMutex;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use codex_protocol::ThreadId;
+use tracing::warn;
+
+use super::SESSIONS_SUBDIR;
+use super::list::parse_timestamp_uuid_from_filename;
+use super::list::read_session_meta_line;
+use crate::config::types::RolloutRetention;
+use crate::state_db;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Rollouts modified this recently are never pruned: another Codex process may
+/// still be writing to them, and [`OPEN_ROLLOUTS`] only covers this process.
+const RECENTLY_MODIFIED_GRACE: Duration = Duration::from_secs(SECONDS_PER_DAY);
+
+/// File names of the rollouts that running threads in this process write to.
+static OPEN_ROLLOUTS: LazyLock<StdMutex<HashSet<OsString>>> =
+    LazyLock::new(|| StdMutex::new(HashSet::new()));
+
+/// Registers a rollout as open for writing until dropped, so pruning started
+/// by another thread never deletes it.
+pub(crate) struct OpenRolloutGuard {
+    file_name: Option<OsString>,
+}
+
+impl OpenRolloutGuard {
+    pub(crate) fn new(path: &Path) -> Self {
+        let file_name = path.file_name().map(ToOwned::to_owned);
+        if let Some(file_name) = file_name.as_ref()
+            && let Ok(mut open) = OPEN_ROLLOUTS.lock()
+        {
+            open.insert(file_name.clone());
+        }
+        Self { file_name }
+    }
+}
+
+impl Drop for OpenRolloutGuard {
+    fn drop(&mut self) {
+        if let Some(file_name) = self.file_name.as_ref()
+            && let Ok(mut open) = OPEN_ROLLOUTS.lock()
+        {
+            open.remove(file_name);
+        }
+    }
+}
+
+/// Delete rollout files under `codex_home/sessions` that fall outside
+/// `retention`, along with their state DB rows. The file named like `keep`
+/// (the rollout being resumed or written), files of running threads, files
+/// modified within the last day, and the earlier files of a rotated rollout
+/// that is kept are never deleted. Returns the paths that were removed.
+pub(crate) async fn prune_rollouts(
+    codex_home: &Path,
+    retention: RolloutRetention,
+    keep: &Path,
+    state_db: Option<&codex_state::StateRuntime>,
+) -> io::Result<Vec<PathBuf>> {
+    if retention.max_count.is_none() && retention.max_age_days.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let mut rollouts = collect_rollouts(&codex_home.join(SESSIONS_SUBDIR)).await?;
+    rollouts.sort_by_key(|(modified, _path)| Reverse(*modified));
+
+    let now = SystemTime::now();
+    let cutoff = retention.max_age_days.and_then(|days| {
+        now.checked_sub(Duration::from_secs(days.saturating_mul(SECONDS_PER_DAY)))
+    });
+    let recently_modified = now.checked_sub(RECENTLY_MODIFIED_GRACE);
+
+    let mut protected = OPEN_ROLLOUTS
+        .lock()
+        .map(|open| open.clone())
+        .unwrap_or_default();
+    protected.extend(keep.file_name().map(ToOwned::to_owned));
+
+    // Newest first, so a kept rotated rollout is seen before the files it
+    // continues from and can protect them.
+    let mut removed = Vec::new();
+    for (index, (modified, path)) in rollouts.into_iter().enumerate() {
+        let over_count = retention.max_count.is_some_and(|max| index >= max);
+        let too_old = cutoff.is_some_and(|cutoff| modified < cutoff);
+        let is_protected = path
+            .file_name()
+            .is_some_and(|file_name| protected.contains(file_name))
+            || recently_modified.is_none_or(|recently_modified| modified >= recently_modified);
+        if is_protected || (!over_count && !too_old) {
+            if let Ok(meta_line) = read_session_meta_line(&path).await
+                && let Some(previous) = meta_line.meta.previous_rollout_path
+            {
+                protected.extend(previous.file_name().map(ToOwned::to_owned));
+            }
+            continue;
+        }
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => {
+                if let Some(state_db) = state_db {
+                    forget_pruned_thread(state_db, &path).await;
+                }
+                removed.push(path);
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => warn!("failed to prune rollout {}: {err}", path.display()),
+        }
+    }
+    Ok(removed)
+}
+
+/// Deletes the state DB row of the thread stored in the pruned rollout `path`.
+/// Rows that point at another rollout (e.g. a later file of a rotated thread)
+/// are left alone.
+async fn forget_pruned_thread(state_db: &codex_state::StateRuntime, path: &Path) {
+    let Some(thread_id) = path
+        .file_name()
+        .and_then(|file_name| file_name.to_str())
+        .and_then(parse_timestamp_uuid_from_filename)
+        .and_then(|(_, uuid)| ThreadId::from_string(&uuid.to_string()).ok())
+    else {
+        return;
+    };
+    let indexed_path =
+        state_db::find_rollout_path_by_id(Some(state_db), thread_id, None, "prune_rollouts").await;
+    if indexed_path.as_deref() != Some(path) {
+        return;
+    }
+    if let Err(err) = state_db.delete_thread(thread_id).await {
+        warn!("failed to delete state db row for pruned rollout {thread_id}: {err}");
+    }
+}
+
+/// Recursively collect `rollout-*.jsonl` files with their modification time.
+async fn collect_rollouts(root: &Path) -> io::Result<Vec<(SystemTime, PathBuf)>> {
+    let mut rollouts = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+                continue;
+            }
+            if !file_type.is_file() {
+                continue;
+            }
+            let file_name = entry.file_name();
+            let Some(name_str) = file_name.to_str() else {
+                continue;
+            };
+            if !name_str.starts_with("rollout-") || !name_str.ends_with(".jsonl") {
+                continue;
+            }
+            let modified = entry.metadata().await?.modified()?;
+            rollouts.push((modified, entry.path()));
+        }
+    }
+    Ok(rollouts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::SessionMeta;
+    use codex_protocol::protocol::SessionMetaLine;
+    use codex_protocol::protocol::SessionSource;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn write_rollout(codex_home: &Path, day: u32, age_days: u64) -> io::Result<PathBuf> {
+        let dir = codex_home
+            .join(SESSIONS_SUBDIR)
+            .join("2025")
+            .join("01")
+            .join(format!("{day:02}"));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!(
+            "rollout-2025-01-{day:02}T00-00-00-00000000-0000-0000-0000-0000000000{day:02}.jsonl"
+        ));
+        let file = std::fs::File::create(&path)?;
+        file.set_modified(SystemTime::now() - Duration::from_secs(age_days * SECONDS_PER_DAY))?;
+        Ok(path)
+    }
+
+    #[tokio::test]
+    async fn max_count_keeps_most_recent_and_protected_rollout() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        let paths = (1..=5)
+            .map(|day| write_rollout(temp.path(), day, u64::from(10 - day)))
+            .collect::<io::Result<Vec<_>>>()?;
+        let protected = paths[0].clone();
+
+        let retention = RolloutRetention {
+            max_count: Some(2),
+            max_age_days: None,
+        };
+        let mut removed = prune_rollouts(temp.path(), retention, &protected, None).await?;
+        removed.sort();
+
+        assert_eq!(removed, vec![paths[1].clone(), paths[2].clone()]);
+        let remaining = paths.iter().filter(|path| path.exists()).cloned();
+        assert_eq!(
+            remaining.collect::<Vec<_>>(),
+            vec![protected, paths[3].clone(), paths[4].clone()]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn keeps_open_rollouts_and_files_continued_by_kept_rollouts() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        // Days unused by other tests: the open-rollout registry is process-wide.
+        let paths = (11..=14)
+            .map(|day| write_rollout(temp.path(), day, u64::from(20 - day)))
+            .collect::<io::Result<Vec<_>>>()?;
+        // The newest rollout was rotated out of the oldest one.
+        let meta_line = SessionMetaLine {
+            meta: SessionMeta {
+                previous_rollout_path: Some(paths[0].clone()),
+                ..SessionMeta::default()
+            },
+            git: None,
+        };
+        let line = serde_json::json!({
+            "timestamp": "2025-01-14T00:00:00.000Z",
+            "type": "session_meta",
+            "payload": meta_line,
+        });
+        std::fs::write(&paths[3], format!("{line}\n"))?;
+        let file = std::fs::File::options().write(true).open(&paths[3])?;
+        file.set_modified(SystemTime::now() - Duration::from_secs(6 * SECONDS_PER_DAY))?;
+        let _running = OpenRolloutGuard::new(&paths[1]);
+
+        let retention = RolloutRetention {
+            max_count: Some(1),
+            max_age_days: None,
+        };
+        let removed =
+            prune_rollouts(temp.path(), retention, Path::new("unused.jsonl"), None).await?;
+
+        assert_eq!(removed, vec![paths[2].clone()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn max_age_removes_stale_rollouts() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        let stale = write_rollout(temp.path(), 1, 30)?;
+        let fresh = write_rollout(temp.path(), 2, 1)?;
+
+        let retention = RolloutRetention {
+            max_count: None,
+            max_age_days: Some(7),
+        };
+        let removed =
+            prune_rollouts(temp.path(), retention, Path::new("unused.jsonl"), None).await?;
+
+        assert_eq!(removed, vec![stale.clone()]);
+        assert!(!stale.exists());
+        assert!(fresh.exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn recently_modified_rollouts_are_kept() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        let paths = (21..=23)
+            .map(|day| write_rollout(temp.path(), day, 0))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let retention = RolloutRetention {
+            max_count: Some(1),
+            max_age_days: None,
+        };
+        let removed =
+            prune_rollouts(temp.path(), retention, Path::new("unused.jsonl"), None).await?;
+
+        assert!(removed.is_empty());
+        assert!(paths.iter().all(|path| path.exists()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pruning_deletes_state_db_rows_of_removed_rollouts() -> anyhow::Result<()> {
+        let temp = TempDir::new()?;
+        let stale = write_rollout(temp.path(), 24, 30)?;
+        // A rotated thread whose row already points at a later rollout.
+        let rotated = write_rollout(temp.path(), 25, 30)?;
+        let state_db =
+            codex_state::StateRuntime::init(temp.path().to_path_buf(), "test-provider".into())
+                .await?;
+        let thread_id = |day: u32| {
+            ThreadId::from_string(&format!("00000000-0000-0000-0000-0000000000{day:02}"))
+        };
+        for (id, rollout_path) in [
+            (thread_id(24)?, stale.clone()),
+            (thread_id(25)?, temp.path().join("rollout-later.jsonl")),
+        ] {
+            let metadata = codex_state::ThreadMetadataBuilder::new(
+                id,
+                rollout_path,
+                chrono::Utc::now(),
+                SessionSource::Cli,
+            )
+            .build("test-provider");
+            state_db.upsert_thread(&metadata).await?;
+        }
+
+        let retention = RolloutRetention {
+            max_count: None,
+            max_age_days: Some(7),
+        };
+        let mut removed = prune_rollouts(
+            temp.path(),
+            retention,
+            Path::new("unused.jsonl"),
+            Some(&state_db),
+        )
+        .await?;
+        removed.sort();
+
+        assert_eq!(removed, vec![stale, rotated]);
+        assert_eq!(
+            state_db
+                .find_rollout_path_by_id(thread_id(24)?, None)
+                .await?,
+            None
+        );
+        assert_eq!(
+            state_db
+                .find_rollout_path_by_id(thread_id(25)?, None)
+                .await?,
+            Some(temp.path().join("rollout-later.jsonl"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn default_retention_deletes_nothing() -> io::Result<()> {
+        let temp = TempDir::new()?;
+        let old = write_rollout(temp.path(), 1, 365)?;
+
+        let removed = prune_rollouts(
+            temp.path(),
+            RolloutRetention::default(),
+            Path::new("unused.jsonl"),
+            None,
+        )
+        .await?;
+
+        assert!(removed.is_empty());
+        assert!(old.exists());
+        Ok(())
+    }
+}
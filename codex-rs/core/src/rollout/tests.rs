@@ -6,6 +6,7 @@ use std::fs::FileTimes;
 use std::fs::{self};
 use std::io::Write;
 use std::path::Path;
+use std::path::PathBuf;
 
 use chrono::TimeZone;
 use pretty_assertions::assert_eq;
@@ -18,6 +19,7 @@ use time::macros::format_description;
 use uuid::Uuid;
 
 use crate::rollout::INTERACTIVE_SESSION_SOURCES;
+use crate::rollout::RolloutRecorder;
 use crate::rollout::list::Cursor;
 use crate::rollout::list::ThreadItem;
 use crate::rollout::list::ThreadSortKey;
@@ -1107,6 +1109,7 @@ async fn test_updated_at_uses_file_mtime() -> Result<()> {
                 base_instructions: None,
                 dynamic_tools: None,
                 memory_mode: None,
+                previous_rollout_path: None,
             },
             git: None,
         }),
@@ -1469,3 +1472,148 @@ async fn test_model_provider_filter_selects_only_matching_sessions() -> Result<(
 
     Ok(())
 }
+
+fn write_rollout_segment(
+    root: &Path,
+    day: &str,
+    ts_str: &str,
+    uuid: Uuid,
+    previous_rollout_path: Option<PathBuf>,
+    user_message: Option<&str>,
+) -> Result<PathBuf> {
+    let dir = root.join("sessions").join(day);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("rollout-{ts_str}-{uuid}.jsonl"));
+    let mut file = File::create(&path)?;
+
+    let meta_line = RolloutLine {
+        timestamp: ts_str.to_string(),
+        item: RolloutItem::SessionMeta(SessionMetaLine {
+            meta: SessionMeta {
+                id: thread_id_from_uuid(uuid),
+                timestamp: ts_str.to_string(),
+                cwd: ".".into(),
+                source: SessionSource::VSCode,
+                model_provider: Some(TEST_PROVIDER.into()),
+                previous_rollout_path,
+                ..SessionMeta::default()
+            },
+            git: None,
+        }),
+    };
+    writeln!(file, "{}", serde_json::to_string(&meta_line)?)?;
+
+    if let Some(message) = user_message {
+        let user_event_line = RolloutLine {
+            timestamp: ts_str.to_string(),
+            item: RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: message.into(),
+                images: None,
+                text_elements: Vec::new(),
+                local_images: Vec::new(),
+            })),
+        };
+        writeln!(file, "{}", serde_json::to_string(&user_event_line)?)?;
+    }
+    Ok(path)
+}
+
+#[tokio::test]
+async fn rotated_rollouts_are_listed_once_from_the_newest_file() -> Result<()> {
+    let temp = TempDir::new()?;
+    let home = temp.path();
+    let uuid = Uuid::from_u128(4242);
+    let first = write_rollout_segment(
+        home,
+        "2025/03/01",
+        "2025-03-01T09-00-00",
+        uuid,
+        None,
+        Some("first question"),
+    )?;
+    let rotated = write_rollout_segment(
+        home,
+        "2025/03/02",
+        "2025-03-02T09-00-00",
+        uuid,
+        Some(first),
+        None,
+    )?;
+
+    for sort_key in [ThreadSortKey::CreatedAt, ThreadSortKey::UpdatedAt] {
+        let page = get_threads(
+            home,
+            10,
+            None,
+            sort_key,
+            NO_SOURCE_FILTER,
+            None,
+            TEST_PROVIDER,
+        )
+        .await?;
+        let listed = page
+            .items
+            .iter()
+            .map(|item| (item.path.clone(), item.first_user_message.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            listed,
+            vec![(rotated.clone(), Some("first question".to_string()))]
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rollout_chain_stops_at_missing_or_repeated_files() -> Result<()> {
+    let temp = TempDir::new()?;
+    let home = temp.path();
+
+    let deleted = home.join("sessions/2025/03/01/rollout-deleted.jsonl");
+    let orphan = write_rollout_segment(
+        home,
+        "2025/03/02",
+        "2025-03-02T09-00-00",
+        Uuid::from_u128(1),
+        Some(deleted),
+        Some("after retention"),
+    )?;
+    let (items, thread_id, _) = RolloutRecorder::load_rollout_items(&orphan).await?;
+    assert_eq!(thread_id, Some(thread_id_from_uuid(Uuid::from_u128(1))));
+    assert_eq!(items.len(), 2);
+
+    // Two files that name each other as their predecessor.
+    let uuid = Uuid::from_u128(2);
+    let second_path = home.join(format!(
+        "sessions/2025/03/04/rollout-2025-03-04T09-00-00-{uuid}.jsonl"
+    ));
+    let first = write_rollout_segment(
+        home,
+        "2025/03/03",
+        "2025-03-03T09-00-00",
+        uuid,
+        Some(second_path.clone()),
+        Some("one"),
+    )?;
+    let second = write_rollout_segment(
+        home,
+        "2025/03/04",
+        "2025-03-04T09-00-00",
+        uuid,
+        Some(first),
+        Some("two"),
+    )?;
+    assert_eq!(second, second_path);
+    let (items, _, _) = RolloutRecorder::load_rollout_items(&second).await?;
+    let user_messages = items
+        .iter()
+        .filter_map(|item| match item {
+            RolloutItem::EventMsg(EventMsg::UserMessage(user)) => Some(user.message.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(user_messages, vec!["one", "two"]);
+
+    Ok(())
+}
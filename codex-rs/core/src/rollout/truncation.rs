@@ -68,6 +68,24 @@ pub(crate) fn truncate_rollout_before_nth_user_message_from_start(
     items[..cut_idx].to_vec()
 }
 
+/// Return a prefix of `items` that ends with the turn identified by `turn_id`,
+/// i.e. through its `TurnComplete` (or `TurnAborted`) event.
+///
+/// Returns `None` when the rollout has no finished turn with that id.
+pub(crate) fn truncate_rollout_after_turn(
+    items: &[RolloutItem],
+    turn_id: &str,
+) -> Option<Vec<RolloutItem>> {
+    let end_idx = items.iter().rposition(|item| match item {
+        RolloutItem::EventMsg(EventMsg::TurnComplete(event)) => event.turn_id == turn_id,
+        RolloutItem::EventMsg(EventMsg::TurnAborted(event)) => {
+            event.turn_id.as_deref() == Some(turn_id)
+        }
+        _ => false,
+    })?;
+    Some(items[..=end_idx].to_vec())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,6 +94,7 @@ mod tests {
     use codex_protocol::models::ContentItem;
     use codex_protocol::models::ReasoningItemReasoningSummary;
     use codex_protocol::protocol::ThreadRolledBackEvent;
+    use codex_protocol::protocol::TurnCompleteEvent;
     use pretty_assertions::assert_eq;
 
     fn user_msg(text: &str) -> ResponseItem {
@@ -218,4 +237,30 @@ mod tests {
             serde_json::to_value(&expected).unwrap()
         );
     }
+
+    #[test]
+    fn truncates_rollout_after_finished_turn() {
+        let turn_complete = |turn_id: &str| {
+            RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
+                turn_id: turn_id.to_string(),
+                last_agent_message: None,
+                cost_usd: None,
+            }))
+        };
+        let rollout_items = vec![
+            RolloutItem::ResponseItem(user_msg("u1")),
+            RolloutItem::ResponseItem(assistant_msg("a1")),
+            turn_complete("sub-1"),
+            RolloutItem::ResponseItem(user_msg("u2")),
+            RolloutItem::ResponseItem(assistant_msg("a2")),
+            turn_complete("sub-2"),
+        ];
+
+        let truncated = truncate_rollout_after_turn(&rollout_items, "sub-1");
+        assert_eq!(
+            serde_json::to_value(&truncated).unwrap(),
+            serde_json::to_value(Some(&rollout_items[..3])).unwrap()
+        );
+        assert!(truncate_rollout_after_turn(&rollout_items, "sub-3").is_none());
+    }
 }
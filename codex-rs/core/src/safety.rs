@@ -1,3 +1,4 @@
+use std::ffi::OsString;
 use std::path::Component;
 use std::path::Path;
 use std::path::PathBuf;
@@ -11,7 +12,9 @@ use crate::util::resolve_path;
 use crate::protocol::AskForApproval;
 use crate::protocol::FileSystemSandboxPolicy;
 use crate::protocol::SandboxPolicy;
+use crate::protocol::WritableRoot;
 use codex_protocol::config_types::WindowsSandboxLevel;
+use codex_utils_absolute_path::AbsolutePathBuf;
 
 #[derive(Debug, PartialEq)]
 pub enum SafetyCheck {
@@ -143,48 +146,88 @@ fn is_write_patch_constrained_to_writable_paths(
         Some(out)
     }
 
-    let unreadable_roots = file_system_sandbox_policy.get_unreadable_roots_with_cwd(cwd);
+    let unreadable_roots: Vec<PathBuf> = file_system_sandbox_policy
+        .get_unreadable_roots_with_cwd(cwd)
+        .into_iter()
+        .map(AbsolutePathBuf::into_path_buf)
+        .collect();
     let writable_roots = file_system_sandbox_policy.get_writable_roots_with_cwd(cwd);
-
-    // Determine whether `path` is inside **any** writable root. Both `path`
-    // and roots are converted to absolute, normalized forms before the
-    // prefix check.
-    let is_path_writable = |p: &PathBuf| {
-        let abs = resolve_path(cwd, p);
-        let abs = match normalize(&abs) {
-            Some(v) => v,
-            None => return false,
+    let full_disk_write_access = file_system_sandbox_policy.has_full_disk_write_access();
+
+    // The same roots with symlinks already on disk followed, to compare
+    // against paths whose symlinks have been followed as well.
+    let resolved_unreadable_roots: Vec<PathBuf> = unreadable_roots
+        .iter()
+        .map(|root| resolve_existing_symlinks(root).unwrap_or_else(|| root.clone()))
+        .collect();
+    let resolved_writable_roots: Vec<WritableRoot> = writable_roots
+        .iter()
+        .map(|writable_root| WritableRoot {
+            root: resolve_absolute_path(&writable_root.root),
+            read_only_subpaths: writable_root
+                .read_only_subpaths
+                .iter()
+                .map(resolve_absolute_path)
+                .collect(),
+        })
+        .collect();
+
+    let is_allowed =
+        |path: &Path, unreadable_roots: &[PathBuf], writable_roots: &[WritableRoot]| {
+            if unreadable_roots.iter().any(|root| path.starts_with(root)) {
+                return false;
+            }
+            full_disk_write_access
+                || writable_roots
+                    .iter()
+                    .any(|writable_root| writable_root.is_path_writable(path))
         };
 
-        if unreadable_roots
-            .iter()
-            .any(|root| abs.starts_with(root.as_path()))
-        {
-            return false;
-        }
-
-        if file_system_sandbox_policy.has_full_disk_write_access() {
-            return true;
-        }
-
-        writable_roots
-            .iter()
-            .any(|writable_root| writable_root.is_path_writable(&abs))
+    // Determine whether the absolute, normalized `path` is inside **any**
+    // writable root, both as written and after following the symlinks on
+    // disk, so a link cannot redirect the write outside the roots or into a
+    // read-only subpath.
+    let is_path_writable = |path: &Path| {
+        is_allowed(path, &unreadable_roots, &writable_roots)
+            && resolve_existing_symlinks(path).is_some_and(|resolved| {
+                is_allowed(
+                    &resolved,
+                    &resolved_unreadable_roots,
+                    &resolved_writable_roots,
+                )
+            })
     };
+    let absolute = |path: &PathBuf| normalize(&resolve_path(cwd, path));
 
     for (path, change) in action.changes() {
+        let Some(path) = absolute(path) else {
+            return false;
+        };
         match change {
-            ApplyPatchFileChange::Add { .. } | ApplyPatchFileChange::Delete { .. } => {
-                if !is_path_writable(path) {
+            ApplyPatchFileChange::Add { .. }
+            | ApplyPatchFileChange::Binary { .. }
+            | ApplyPatchFileChange::Delete { .. } => {
+                if !is_path_writable(&path) {
+                    return false;
+                }
+            }
+            ApplyPatchFileChange::Symlink { target } => {
+                // Later writes through the link land on its target, so the
+                // target must be writable too. Relative targets resolve
+                // against the link's directory.
+                let target = path.parent().unwrap_or(cwd).join(target);
+                if !is_path_writable(&path)
+                    || !normalize(&target).is_some_and(|target| is_path_writable(&target))
+                {
                     return false;
                 }
             }
             ApplyPatchFileChange::Update { move_path, .. } => {
-                if !is_path_writable(path) {
+                if !is_path_writable(&path) {
                     return false;
                 }
                 if let Some(dest) = move_path
-                    && !is_path_writable(dest)
+                    && !absolute(dest).is_some_and(|dest| is_path_writable(&dest))
                 {
                     return false;
                 }
@@ -195,6 +238,62 @@ fn is_write_patch_constrained_to_writable_paths(
     true
 }
 
+/// Follows every symlink that exists along the absolute `path`, including
+/// dangling ones, and returns where a write to `path` would land. Components
+/// that do not exist yet are kept as written. Returns `None` when a link
+/// cannot be read or links form a loop.
+fn resolve_existing_symlinks(path: &Path) -> Option<PathBuf> {
+    const MAX_SYMLINKS: usize = 40;
+
+    let mut resolved = PathBuf::new();
+    let mut pending = Vec::new();
+    push_components(path, &mut resolved, &mut pending);
+    let mut symlinks = 0;
+    while let Some(name) = pending.pop() {
+        if name == Component::ParentDir.as_os_str() {
+            resolved.pop();
+            continue;
+        }
+        resolved.push(name);
+        let is_symlink = std::fs::symlink_metadata(&resolved)
+            .is_ok_and(|metadata| metadata.file_type().is_symlink());
+        if !is_symlink {
+            continue;
+        }
+        symlinks += 1;
+        if symlinks > MAX_SYMLINKS {
+            return None;
+        }
+        let target = std::fs::read_link(&resolved).ok()?;
+        resolved.pop();
+        push_components(&target, &mut resolved, &mut pending);
+    }
+    Some(resolved)
+}
+
+/// Queues the `..` and normal components of `path` on `pending` so they are
+/// popped in order. Absolute paths restart `resolved` at their root.
+fn push_components(path: &Path, resolved: &mut PathBuf, pending: &mut Vec<OsString>) {
+    if path.has_root() {
+        *resolved = path
+            .components()
+            .take_while(|component| matches!(component, Component::Prefix(_) | Component::RootDir))
+            .collect();
+    }
+    pending.extend(
+        path.components()
+            .rev()
+            .filter(|component| matches!(component, Component::Normal(_) | Component::ParentDir))
+            .map(|component| component.as_os_str().to_os_string()),
+    );
+}
+
+fn resolve_absolute_path(path: &AbsolutePathBuf) -> AbsolutePathBuf {
+    resolve_existing_symlinks(path.as_path())
+        .and_then(|resolved| AbsolutePathBuf::from_absolute_path(resolved).ok())
+        .unwrap_or_else(|| path.clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -451,4 +550,109 @@ mod tests {
             SafetyCheck::AskUser,
         );
     }
+
+    #[cfg(unix)]
+    fn parse_patch_for_test(cwd: &Path, body: &str) -> ApplyPatchAction {
+        let argv = vec![
+            "apply_patch".to_string(),
+            format!("*** Begin Patch\n{body}\n*** End Patch"),
+        ];
+        match codex_apply_patch::maybe_parse_apply_patch_verified(&argv, cwd) {
+            codex_apply_patch::MaybeApplyPatchVerified::Body(action) => action,
+            other => panic!("expected a parsed patch, got {other:?}"),
+        }
+    }
+
+    #[cfg(unix)]
+    fn workspace_only_policy() -> FileSystemSandboxPolicy {
+        FileSystemSandboxPolicy::from(&SandboxPolicy::WorkspaceWrite {
+            writable_roots: vec![],
+            read_only_access: Default::default(),
+            network_access: false,
+            exclude_tmpdir_env_var: true,
+            exclude_slash_tmp: true,
+        })
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_targets_must_stay_in_writable_roots() {
+        let tmp = TempDir::new().unwrap();
+        let cwd = tmp.path().join("workspace");
+        std::fs::create_dir_all(cwd.join(".git").join("hooks")).unwrap();
+        let policy = workspace_only_policy();
+
+        let inside =
+            parse_patch_for_test(&cwd, "*** Add File: docs/link\n@@symlink@@\n../README.md");
+        assert!(is_write_patch_constrained_to_writable_paths(
+            &inside, &policy, &cwd,
+        ));
+
+        let outside = parse_patch_for_test(
+            &cwd,
+            "*** Add File: keys\n@@symlink@@\n../.ssh/authorized_keys",
+        );
+        assert!(!is_write_patch_constrained_to_writable_paths(
+            &outside, &policy, &cwd,
+        ));
+
+        let read_only = parse_patch_for_test(
+            &cwd,
+            "*** Add File: hook\n@@symlink@@\n.git/hooks/pre-commit",
+        );
+        assert!(!is_write_patch_constrained_to_writable_paths(
+            &read_only, &policy, &cwd,
+        ));
+        assert_eq!(
+            assess_patch_safety(
+                &read_only,
+                AskForApproval::OnRequest,
+                &SandboxPolicy::new_workspace_write_policy(),
+                &policy,
+                &cwd,
+                WindowsSandboxLevel::Disabled,
+            ),
+            SafetyCheck::AskUser,
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn writes_through_existing_symlinks_must_stay_in_writable_roots() {
+        let tmp = TempDir::new().unwrap();
+        let cwd = tmp.path().join("workspace");
+        let outside = tmp.path().join("outside");
+        std::fs::create_dir_all(cwd.join(".git")).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("target.txt"), "old\n").unwrap();
+        std::fs::write(cwd.join(".git").join("config"), "old\n").unwrap();
+        std::fs::write(cwd.join("real.txt"), "old\n").unwrap();
+        std::os::unix::fs::symlink(outside.join("target.txt"), cwd.join("escape.txt")).unwrap();
+        std::os::unix::fs::symlink(".git/config", cwd.join("config.txt")).unwrap();
+        std::os::unix::fs::symlink("real.txt", cwd.join("alias.txt")).unwrap();
+        std::os::unix::fs::symlink(&outside, cwd.join("linked_dir")).unwrap();
+        let policy = workspace_only_policy();
+        let update = |path: &str| {
+            parse_patch_for_test(&cwd, &format!("*** Update File: {path}\n@@\n-old\n+new"))
+        };
+
+        assert!(is_write_patch_constrained_to_writable_paths(
+            &update("alias.txt"),
+            &policy,
+            &cwd,
+        ));
+        for path in ["escape.txt", "config.txt", "linked_dir/target.txt"] {
+            assert!(
+                !is_write_patch_constrained_to_writable_paths(&update(path), &policy, &cwd),
+                "{path} should need approval"
+            );
+        }
+
+        // A dangling link still redirects a newly added file.
+        std::os::unix::fs::symlink(outside.join("new.txt"), cwd.join("dangling.txt")).unwrap();
+        let add = parse_patch_for_test(&cwd, "*** Add File: dangling.txt\n+new");
+        assert!(!is_write_patch_constrained_to_writable_paths(
+            &add, &policy, &cwd,
+        ));
+    }
 }
@@ -7,6 +7,7 @@ ready‑to‑spawn environment.
 */
 
 pub(crate) mod macos_permissions;
+pub(crate) mod selection;
 
 use crate::exec::ExecExpiration;
 use crate::exec::ExecToolCallOutput;
@@ -75,6 +76,7 @@ pub struct ExecRequest {
     pub network_sandbox_policy: NetworkSandboxPolicy,
     pub justification: Option<String>,
     pub arg0: Option<String>,
+    pub stdin: Option<String>,
 }
 
 /// Bundled arguments for sandbox transformation.
@@ -691,6 +693,7 @@ impl SandboxManager {
             network_sandbox_policy: effective_network_policy,
             justification: spec.justification,
             arg0: arg0_override,
+            stdin: None,
         })
     }
 
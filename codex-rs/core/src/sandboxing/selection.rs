@@ -0,0 +1,337 @@
+//! Per-command sandbox selection for `[sandbox_selection]`.
+//!
+//! Shell commands are sorted into read-only, mutating and network categories,
+//! and each category maps to a [`SandboxTier`]. `light` runs the command under
+//! the turn's sandbox policy. `full` and `network` run it under the platform
+//! sandbox with a policy derived from the turn's: full disk access becomes
+//! workspace writes, and `full` also cuts off the network. A tier never grants
+//! more than the turn's policy does.
+
+use std::path::Path;
+
+use codex_protocol::permissions::FileSystemSandboxPolicy;
+use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::protocol::ReadOnlyAccess;
+use codex_protocol::protocol::SandboxPolicy;
+
+use crate::bash::parse_shell_lc_plain_commands;
+use crate::config::types::SandboxSelection;
+use crate::config::types::SandboxTier;
+use crate::is_safe_command::is_known_safe_command;
+use crate::tools::sandboxing::SandboxablePreference;
+
+/// Programs that always talk to the network.
+const NETWORK_PROGRAMS: &[&str] = &[
+    "curl", "ftp", "nc", "rsync", "scp", "sftp", "ssh", "telnet", "wget",
+];
+
+/// Programs whose listed subcommands talk to the network.
+const NETWORK_SUBCOMMANDS: &[(&str, &[&str])] = &[
+    ("cargo", &["fetch", "install", "update"]),
+    ("git", &["clone", "fetch", "ls-remote", "pull", "push"]),
+    ("npm", &["ci", "install", "update"]),
+    ("pip", &["download", "install"]),
+    ("pip3", &["download", "install"]),
+    ("pnpm", &["add", "install", "update"]),
+    ("yarn", &["add", "install"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CommandCategory {
+    ReadOnly,
+    Mutating,
+    Network,
+}
+
+pub(crate) fn categorize_command(command: &[String]) -> CommandCategory {
+    let commands = parse_shell_lc_plain_commands(command).unwrap_or_else(|| vec![command.to_vec()]);
+    if commands.iter().any(|command| reaches_network(command)) {
+        CommandCategory::Network
+    } else if is_known_safe_command(command) {
+        CommandCategory::ReadOnly
+    } else {
+        CommandCategory::Mutating
+    }
+}
+
+fn reaches_network(command: &[String]) -> bool {
+    let Some(program) = command.first() else {
+        return false;
+    };
+    let program = Path::new(program)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(program);
+    if NETWORK_PROGRAMS.contains(&program) {
+        return true;
+    }
+    NETWORK_SUBCOMMANDS
+        .iter()
+        .find(|(name, _)| *name == program)
+        .is_some_and(|(_, subcommands)| {
+            command
+                .iter()
+                .skip(1)
+                .find(|arg| !arg.starts_with('-'))
+                .is_some_and(|subcommand| subcommands.contains(&subcommand.as_str()))
+        })
+}
+
+/// The sandbox preference and policies a command's first attempt runs with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CommandSandbox {
+    pub(crate) preference: SandboxablePreference,
+    pub(crate) sandbox_policy: SandboxPolicy,
+    pub(crate) file_system_policy: FileSystemSandboxPolicy,
+    pub(crate) network_policy: NetworkSandboxPolicy,
+}
+
+impl CommandSandbox {
+    /// The tool's preference under the turn's own policies.
+    pub(crate) fn new(
+        preference: SandboxablePreference,
+        sandbox_policy: &SandboxPolicy,
+        file_system_policy: &FileSystemSandboxPolicy,
+        network_policy: NetworkSandboxPolicy,
+    ) -> Self {
+        Self {
+            preference,
+            sandbox_policy: sandbox_policy.clone(),
+            file_system_policy: file_system_policy.clone(),
+            network_policy,
+        }
+    }
+
+    /// Applies the tier `selection` assigns to `command`. Tools without a
+    /// shell command keep the turn's sandbox.
+    pub(crate) fn for_command(
+        self,
+        selection: Option<&SandboxSelection>,
+        command: Option<&[String]>,
+        cwd: &Path,
+    ) -> Self {
+        let (Some(selection), Some(command)) = (selection, command) else {
+            return self;
+        };
+        match categorize_command(command) {
+            CommandCategory::ReadOnly => self.with_tier(selection.read_only, cwd),
+            CommandCategory::Mutating => self.with_tier(selection.mutating, cwd),
+            CommandCategory::Network => self.with_tier(selection.network, cwd),
+        }
+    }
+
+    fn with_tier(self, tier: SandboxTier, cwd: &Path) -> Self {
+        // An external sandbox enforces its own rules; layering the platform
+        // sandbox or cutting off the network on top of it is not ours to do.
+        if matches!(self.sandbox_policy, SandboxPolicy::ExternalSandbox { .. }) {
+            return self;
+        }
+        let allow_network = match tier {
+            SandboxTier::Light => return self,
+            SandboxTier::Full => false,
+            SandboxTier::Network => true,
+        };
+        let preference = match self.preference {
+            SandboxablePreference::Auto => SandboxablePreference::Require,
+            preference => preference,
+        };
+        let sandbox_policy = restrict_sandbox_policy(&self.sandbox_policy, allow_network);
+        // Only full disk access changes what the file system policy allows.
+        let file_system_policy = if matches!(self.sandbox_policy, SandboxPolicy::DangerFullAccess) {
+            FileSystemSandboxPolicy::from_legacy_sandbox_policy(&sandbox_policy, cwd)
+        } else {
+            self.file_system_policy
+        };
+        let network_policy = if allow_network {
+            self.network_policy
+        } else {
+            NetworkSandboxPolicy::Restricted
+        };
+        Self {
+            preference,
+            sandbox_policy,
+            file_system_policy,
+            network_policy,
+        }
+    }
+}
+
+/// `policy` with full disk access narrowed to workspace writes and, unless
+/// `allow_network`, without network access.
+fn restrict_sandbox_policy(policy: &SandboxPolicy, allow_network: bool) -> SandboxPolicy {
+    match policy {
+        SandboxPolicy::DangerFullAccess => SandboxPolicy::WorkspaceWrite {
+            writable_roots: Vec::new(),
+            read_only_access: ReadOnlyAccess::FullAccess,
+            network_access: allow_network,
+            exclude_tmpdir_env_var: false,
+            exclude_slash_tmp: false,
+        },
+        SandboxPolicy::ReadOnly {
+            access,
+            network_access,
+        } => SandboxPolicy::ReadOnly {
+            access: access.clone(),
+            network_access: *network_access && allow_network,
+        },
+        SandboxPolicy::WorkspaceWrite {
+            writable_roots,
+            read_only_access,
+            network_access,
+            exclude_tmpdir_env_var,
+            exclude_slash_tmp,
+        } => SandboxPolicy::WorkspaceWrite {
+            writable_roots: writable_roots.clone(),
+            read_only_access: read_only_access.clone(),
+            network_access: *network_access && allow_network,
+            exclude_tmpdir_env_var: *exclude_tmpdir_env_var,
+            exclude_slash_tmp: *exclude_slash_tmp,
+        },
+        SandboxPolicy::ExternalSandbox { .. } => policy.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::SandboxSelectionToml;
+    use crate::exec::SandboxType;
+    use crate::sandboxing::SandboxManager;
+    use codex_protocol::config_types::WindowsSandboxLevel;
+    use codex_protocol::protocol::NetworkAccess;
+    use pretty_assertions::assert_eq;
+
+    fn vec_str(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    fn command_sandbox(policy: &SandboxPolicy, command: &[&str]) -> CommandSandbox {
+        let cwd = std::env::temp_dir();
+        let selection = SandboxSelection::from(SandboxSelectionToml::default());
+        CommandSandbox::new(
+            SandboxablePreference::Auto,
+            policy,
+            &FileSystemSandboxPolicy::from_legacy_sandbox_policy(policy, &cwd),
+            NetworkSandboxPolicy::from(policy),
+        )
+        .for_command(Some(&selection), Some(&vec_str(command)), &cwd)
+    }
+
+    fn select(policy: &SandboxPolicy, command: &[&str]) -> SandboxType {
+        let sandbox = command_sandbox(policy, command);
+        SandboxManager::new().select_initial(
+            &sandbox.file_system_policy,
+            sandbox.network_policy,
+            sandbox.preference,
+            WindowsSandboxLevel::Disabled,
+            false,
+        )
+    }
+
+    #[test]
+    fn categorizes_commands_by_what_they_do() {
+        assert_eq!(
+            categorize_command(&vec_str(&["ls", "-la"])),
+            CommandCategory::ReadOnly
+        );
+        assert_eq!(
+            categorize_command(&vec_str(&["rm", "-rf", "build"])),
+            CommandCategory::Mutating
+        );
+        assert_eq!(
+            categorize_command(&vec_str(&["bash", "-lc", "git fetch origin && ls"])),
+            CommandCategory::Network
+        );
+        assert_eq!(
+            categorize_command(&vec_str(&["/usr/bin/curl", "https://example.com"])),
+            CommandCategory::Network
+        );
+    }
+
+    #[test]
+    fn read_only_commands_get_the_lighter_sandbox_and_rm_gets_full() {
+        let platform_sandbox =
+            crate::safety::get_platform_sandbox(false).unwrap_or(SandboxType::None);
+        let full_access = SandboxPolicy::DangerFullAccess;
+
+        assert_eq!(select(&full_access, &["ls", "-la"]), SandboxType::None);
+        assert_eq!(
+            select(&full_access, &["rm", "-rf", "build"]),
+            platform_sandbox
+        );
+    }
+
+    #[test]
+    fn tiers_derive_restricted_policies_from_full_access() {
+        let full_access = SandboxPolicy::DangerFullAccess;
+
+        let rm = command_sandbox(&full_access, &["rm", "-rf", "build"]);
+        assert_eq!(
+            rm.sandbox_policy,
+            SandboxPolicy::new_workspace_write_policy()
+        );
+        assert!(!rm.file_system_policy.has_full_disk_write_access());
+        assert_eq!(rm.network_policy, NetworkSandboxPolicy::Restricted);
+
+        let curl = command_sandbox(&full_access, &["curl", "https://example.com"]);
+        assert_eq!(
+            curl.sandbox_policy,
+            SandboxPolicy::WorkspaceWrite {
+                writable_roots: Vec::new(),
+                read_only_access: ReadOnlyAccess::FullAccess,
+                network_access: true,
+                exclude_tmpdir_env_var: false,
+                exclude_slash_tmp: false,
+            }
+        );
+        assert!(!curl.file_system_policy.has_full_disk_write_access());
+        assert_eq!(curl.network_policy, NetworkSandboxPolicy::Enabled);
+    }
+
+    #[test]
+    fn selection_never_weakens_the_policy_or_tool_preference() {
+        let platform_sandbox =
+            crate::safety::get_platform_sandbox(false).unwrap_or(SandboxType::None);
+        let read_only = SandboxPolicy::new_read_only_policy();
+
+        assert_eq!(select(&read_only, &["ls", "-la"]), platform_sandbox);
+        let curl = command_sandbox(&read_only, &["curl", "https://example.com"]);
+        assert_eq!(curl.sandbox_policy, read_only);
+        assert_eq!(curl.network_policy, NetworkSandboxPolicy::Restricted);
+
+        let forbidden = CommandSandbox::new(
+            SandboxablePreference::Forbid,
+            &read_only,
+            &FileSystemSandboxPolicy::from(&read_only),
+            NetworkSandboxPolicy::Restricted,
+        )
+        .for_command(
+            Some(&SandboxSelection::from(SandboxSelectionToml::default())),
+            Some(&vec_str(&["rm", "-rf", "build"])),
+            Path::new("/"),
+        );
+        assert_eq!(forbidden.preference, SandboxablePreference::Forbid);
+    }
+
+    #[test]
+    fn external_sandbox_is_left_unchanged() {
+        let external = SandboxPolicy::ExternalSandbox {
+            network_access: NetworkAccess::Enabled,
+        };
+        let cwd = std::env::temp_dir();
+        let turn_sandbox = CommandSandbox::new(
+            SandboxablePreference::Auto,
+            &external,
+            &FileSystemSandboxPolicy::from_legacy_sandbox_policy(&external, &cwd),
+            NetworkSandboxPolicy::from(&external),
+        );
+
+        for command in [
+            &["ls", "-la"][..],
+            &["rm", "-rf", "build"],
+            &["curl", "https://example.com"],
+        ] {
+            assert_eq!(command_sandbox(&external, command), turn_sandbox);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Opt-in wiring of OS signals to a thread's interrupt handling.
+//!
+//! Nothing here is installed by default: embedders that manage signals
+//! themselves can keep doing so and simply never call these helpers.
+
+use std::io::Result as IoResult;
+use std::sync::Arc;
+
+use codex_protocol::protocol::Op;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use crate::codex_thread::CodexThread;
+use crate::error::Result as CodexResult;
+
+/// Spawns a task that calls `notify_one` on `ctrl_c` every time the process
+/// receives SIGINT (and SIGTERM on Unix). Abort the returned handle to stop
+/// forwarding signals.
+pub fn forward_os_signals(ctrl_c: Arc<Notify>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            if let Err(err) = next_signal().await {
+                warn!("failed to listen for shutdown signals: {err}");
+                return;
+            }
+            ctrl_c.notify_one();
+        }
+    })
+}
+
+/// Translates `ctrl_c` notifications into ops on `thread`.
+///
+/// The first notification submits `Op::Interrupt`, which aborts the running
+/// turn (if any) with a `TurnAborted` event. The second submits `Op::Shutdown`,
+/// which ends with `ShutdownComplete`, and then returns so the caller can exit
+/// the process.
+pub async fn escalate_ctrl_c(thread: &CodexThread, ctrl_c: &Notify) -> CodexResult<()> {
+    ctrl_c.notified().await;
+    thread.submit(Op::Interrupt).await?;
+    ctrl_c.notified().await;
+    thread.submit(Op::Shutdown).await?;
+    Ok(())
+}
+
+async fn next_signal() -> IoResult<()> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::SignalKind;
+        use tokio::signal::unix::signal;
+
+        let mut term = signal(SignalKind::terminate())?;
+        tokio::select! {
+            ctrl_c_result = tokio::signal::ctrl_c() => ctrl_c_result,
+            _ = term.recv() => Ok(()),
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await
+    }
+}
@@ -2,21 +2,38 @@
 
 use codex_protocol::models::PermissionProfile;
 use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::ErrorEvent;
+use codex_protocol::protocol::LastReasoningEvent;
+use codex_protocol::protocol::Op;
+use codex_protocol::protocol::SessionStatsEvent;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::Instant;
 use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 
 use crate::codex::PreviousTurnSettings;
 use crate::codex::SessionConfiguration;
 use crate::context_manager::ContextManager;
+use crate::context_manager::approx_item_token_count;
 use crate::error::Result as CodexResult;
 use crate::protocol::RateLimitSnapshot;
 use crate::protocol::TokenUsage;
 use crate::protocol::TokenUsageInfo;
+use crate::rate_limiter::TurnRateLimiter;
+use crate::recent_diffs::RecentTurnDiffs;
+use crate::sandboxing::SandboxPermissions;
 use crate::tasks::RegularTask;
+use crate::tools::context::SharedTurnDiffTracker;
 use crate::truncate::TruncationPolicy;
 use codex_protocol::protocol::TurnContextItem;
 
+/// Number of error events retained for `Op::GetRecentErrors`.
+const RECENT_ERRORS_CAPACITY: usize = 32;
+
 /// Persistent, session-scoped state previously stored directly on `Session`.
 pub(crate) struct SessionState {
     pub(crate) session_configuration: SessionConfiguration,
@@ -25,6 +42,18 @@ pub(crate) struct SessionState {
     pub(crate) server_reasoning_included: bool,
     pub(crate) dependency_env: HashMap<String, String>,
     pub(crate) mcp_dependency_prompted: HashSet<String>,
+    /// Set once the "`max_output_tokens` unsupported" warning has been shown.
+    pub(crate) max_output_tokens_warning_sent: bool,
+    /// Tools whose "shadowed by an existing tool" warning has been shown.
+    pub(crate) shadowed_tool_warnings_sent: HashSet<String>,
+    /// Output schema set via `Op::SetDefaultOutputSchema`, used for turns that
+    /// do not carry their own.
+    pub(crate) default_output_schema: Option<Value>,
+    /// Variables set via `Op::SetEnvironmentVariable`, applied on top of the
+    /// shell environment policy for every command.
+    extra_env: HashMap<String, String>,
+    /// Variables removed via `Op::UnsetEnvironmentVariable`.
+    unset_env: HashSet<String>,
     /// Settings used by the latest regular user turn, used for turn-to-turn
     /// model/realtime handling on subsequent regular turns (including full-context
     /// reinjection after resume or `/compact`).
@@ -35,6 +64,60 @@ pub(crate) struct SessionState {
     pub(crate) active_connector_selection: HashSet<String>,
     pub(crate) pending_session_start_source: Option<codex_hooks::SessionStartSource>,
     granted_permissions: Option<PermissionProfile>,
+    /// Most recent command the user denied, kept so it can be re-approved.
+    last_denied_command: Option<DeniedCommand>,
+    /// Most recent command that finished running, kept for `Op::ExplainLastCommand`.
+    last_executed_command: Option<ExecutedCommand>,
+    /// File changes tracked by the most recent regular turn, for `Op::UndoLastPatch`.
+    last_turn_diff_tracker: Option<SharedTurnDiffTracker>,
+    /// Per-file change stats for recent turns, for `include_recent_diffs_in_context`.
+    pub(crate) recent_turn_diffs: RecentTurnDiffs,
+    /// Enforces `max_turns_per_minute` across all turns of the session.
+    pub(crate) turn_rate_limiter: TurnRateLimiter,
+    /// Set by `Op::Pause`; user input is queued instead of starting a task.
+    paused: bool,
+    /// User input submitted while paused, as `(sub_id, op)` in arrival order.
+    paused_inputs: VecDeque<(String, Op)>,
+    /// Most recent error events, oldest first, capped at `RECENT_ERRORS_CAPACITY`.
+    recent_errors: VecDeque<ErrorEvent>,
+    /// Text from `Op::InjectSystemMessage`, consumed by the next turn.
+    injected_system_messages: Vec<String>,
+    /// Cap on cumulative `total_tokens` set by `Op::SetBudget`.
+    token_budget: Option<u64>,
+    /// When the session started, for `Op::GetSessionStats`.
+    started_at: Instant,
+    /// Turns that emitted `TurnComplete`.
+    turns_completed: u64,
+    /// Patches that applied successfully outside of dry runs.
+    patches_applied: u64,
+    /// Files changed by patches in any turn of the session.
+    changed_files: HashSet<PathBuf>,
+    /// Cancels the watcher started by `Op::WatchFiles`, if one is running.
+    file_watch: Option<CancellationToken>,
+    /// Reasoning from the most recent turn that produced any, for
+    /// `Op::GetLastReasoning`.
+    last_reasoning: LastReasoningEvent,
+}
+
+/// A command the user declined to run when asked for approval.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct DeniedCommand {
+    pub(crate) command: Vec<String>,
+    pub(crate) cwd: PathBuf,
+    /// Sandbox permissions the command was originally requested with, so a
+    /// re-run is sandboxed the same way.
+    pub(crate) sandbox_permissions: SandboxPermissions,
+    pub(crate) additional_permissions: Option<PermissionProfile>,
+    pub(crate) justification: Option<String>,
+}
+
+/// A command that ran to completion, with the output it produced.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ExecutedCommand {
+    pub(crate) command: Vec<String>,
+    pub(crate) cwd: PathBuf,
+    pub(crate) exit_code: i32,
+    pub(crate) output: String,
 }
 
 impl SessionState {
@@ -48,12 +131,33 @@ impl SessionState {
             server_reasoning_included: false,
             dependency_env: HashMap::new(),
             mcp_dependency_prompted: HashSet::new(),
+            max_output_tokens_warning_sent: false,
+            shadowed_tool_warnings_sent: HashSet::new(),
+            default_output_schema: None,
+            extra_env: HashMap::new(),
+            unset_env: HashSet::new(),
             previous_turn_settings: None,
             startup_regular_task: None,
             active_mcp_tool_selection: None,
             active_connector_selection: HashSet::new(),
             pending_session_start_source: None,
             granted_permissions: None,
+            last_denied_command: None,
+            last_executed_command: None,
+            last_turn_diff_tracker: None,
+            recent_turn_diffs: RecentTurnDiffs::default(),
+            turn_rate_limiter: TurnRateLimiter::default(),
+            paused: false,
+            paused_inputs: VecDeque::new(),
+            recent_errors: VecDeque::new(),
+            injected_system_messages: Vec::new(),
+            token_budget: None,
+            started_at: Instant::now(),
+            turns_completed: 0,
+            patches_applied: 0,
+            changed_files: HashSet::new(),
+            file_watch: None,
+            last_reasoning: LastReasoningEvent::default(),
         }
     }
 
@@ -94,6 +198,18 @@ impl SessionState {
         self.history.set_token_info(info);
     }
 
+    /// Trims the oldest history items to fit `max_tokens`. Trimming drops the
+    /// initial context first, so the reference context item is cleared to make
+    /// the next context update reinject it in full.
+    pub(crate) fn keep_history_within_token_budget(&mut self, max_tokens: usize) {
+        if self
+            .history
+            .keep_within_token_budget(max_tokens, approx_item_token_count)
+        {
+            self.history.set_reference_context_item(None);
+        }
+    }
+
     pub(crate) fn set_reference_context_item(&mut self, item: Option<TurnContextItem>) {
         self.history.set_reference_context_item(item);
     }
@@ -166,6 +282,30 @@ impl SessionState {
         self.dependency_env.clone()
     }
 
+    pub(crate) fn set_extra_env(&mut self, name: String, value: String) {
+        self.unset_env.remove(&name);
+        self.extra_env.insert(name, value);
+    }
+
+    pub(crate) fn unset_extra_env(&mut self, name: String) {
+        self.extra_env.remove(&name);
+        self.unset_env.insert(name);
+    }
+
+    pub(crate) fn apply_extra_env(&self, env: &mut HashMap<String, String>) -> Vec<String> {
+        for name in &self.unset_env {
+            env.remove(name);
+        }
+        for (name, value) in &self.extra_env {
+            env.insert(name.clone(), value.clone());
+        }
+        self.unset_env
+            .iter()
+            .chain(self.extra_env.keys())
+            .cloned()
+            .collect()
+    }
+
     pub(crate) fn set_startup_regular_task(&mut self, task: JoinHandle<CodexResult<RegularTask>>) {
         self.startup_regular_task = Some(task);
     }
@@ -253,6 +393,173 @@ impl SessionState {
         self.active_connector_selection.clear();
     }
 
+    pub(crate) fn record_denied_command(&mut self, denied: DeniedCommand) {
+        self.last_denied_command = Some(denied);
+    }
+
+    pub(crate) fn last_denied_command(&self) -> Option<DeniedCommand> {
+        self.last_denied_command.clone()
+    }
+
+    /// Forgets `denied` once it has been re-run, unless a newer denial has
+    /// replaced it in the meantime.
+    pub(crate) fn clear_last_denied_command(&mut self, denied: &DeniedCommand) {
+        if self.last_denied_command.as_ref() == Some(denied) {
+            self.last_denied_command = None;
+        }
+    }
+
+    pub(crate) fn record_executed_command(&mut self, executed: ExecutedCommand) {
+        self.last_executed_command = Some(executed);
+    }
+
+    pub(crate) fn last_executed_command(&self) -> Option<ExecutedCommand> {
+        self.last_executed_command.clone()
+    }
+
+    pub(crate) fn set_last_turn_diff_tracker(&mut self, tracker: SharedTurnDiffTracker) {
+        self.last_turn_diff_tracker = Some(tracker);
+    }
+
+    pub(crate) fn last_turn_diff_tracker(&self) -> Option<SharedTurnDiffTracker> {
+        self.last_turn_diff_tracker.clone()
+    }
+
+    pub(crate) fn take_last_turn_diff_tracker(&mut self) -> Option<SharedTurnDiffTracker> {
+        self.last_turn_diff_tracker.take()
+    }
+
+    pub(crate) fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Leaves the paused state and returns the input queued while paused.
+    pub(crate) fn resume(&mut self) -> Vec<(String, Op)> {
+        self.paused = false;
+        self.paused_inputs.drain(..).collect()
+    }
+
+    /// Queues `op` when paused, otherwise hands it back to the caller.
+    pub(crate) fn queue_input_if_paused(&mut self, sub_id: String, op: Op) -> Option<Op> {
+        if !self.paused {
+            return Some(op);
+        }
+        self.paused_inputs.push_back((sub_id, op));
+        None
+    }
+
+    pub(crate) fn record_recent_error(&mut self, error: ErrorEvent) {
+        if self.recent_errors.len() == RECENT_ERRORS_CAPACITY {
+            self.recent_errors.pop_front();
+        }
+        self.recent_errors.push_back(error);
+    }
+
+    /// Returns up to `limit` of the most recent errors, oldest first.
+    pub(crate) fn recent_errors(&self, limit: Option<usize>) -> Vec<ErrorEvent> {
+        let skip = limit.map_or(0, |limit| self.recent_errors.len().saturating_sub(limit));
+        self.recent_errors.iter().skip(skip).cloned().collect()
+    }
+
+    pub(crate) fn take_recent_errors(&mut self) -> Vec<ErrorEvent> {
+        self.recent_errors.drain(..).collect()
+    }
+
+    pub(crate) fn push_injected_system_message(&mut self, text: String) {
+        self.injected_system_messages.push(text);
+    }
+
+    /// Returns the injected system messages for the turn that is starting and
+    /// clears them so later turns do not see them.
+    pub(crate) fn take_injected_system_messages(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.injected_system_messages)
+    }
+
+    pub(crate) fn token_budget(&self) -> Option<u64> {
+        self.token_budget
+    }
+
+    pub(crate) fn set_token_budget(&mut self, max_total_tokens: Option<u64>) {
+        self.token_budget = max_total_tokens;
+    }
+
+    /// Cumulative `total_tokens` reported by the model so far this session.
+    pub(crate) fn session_total_tokens(&self) -> i64 {
+        self.token_info()
+            .map_or(0, |info| info.total_token_usage.total_tokens)
+    }
+
+    /// Returns the budget when the session has used more tokens than it allows.
+    pub(crate) fn exceeded_token_budget(&self) -> Option<u64> {
+        let budget = self.token_budget?;
+        let used = u64::try_from(self.session_total_tokens()).unwrap_or(0);
+        (used > budget).then_some(budget)
+    }
+
+    pub(crate) fn record_turn_completed(&mut self) {
+        self.turns_completed += 1;
+    }
+
+    pub(crate) fn record_patch_applied(&mut self) {
+        self.patches_applied += 1;
+    }
+
+    pub(crate) fn record_changed_files(&mut self, paths: impl IntoIterator<Item = PathBuf>) {
+        self.changed_files.extend(paths);
+    }
+
+    /// Installs a new file watch and returns the one it replaces so the
+    /// caller can cancel it.
+    pub(crate) fn replace_file_watch(
+        &mut self,
+        cancel: CancellationToken,
+    ) -> Option<CancellationToken> {
+        self.file_watch.replace(cancel)
+    }
+
+    pub(crate) fn take_file_watch(&mut self) -> Option<CancellationToken> {
+        self.file_watch.take()
+    }
+
+    /// Aggregate statistics for `Op::GetSessionStats`. Exec calls are counted
+    /// by the session metrics, so the caller supplies them.
+    pub(crate) fn session_stats(&self, exec_calls: u64) -> SessionStatsEvent {
+        let total_tokens = self.token_info().map_or(0, |info| {
+            info.total_token_usage.input_tokens + info.total_token_usage.output_tokens
+        });
+        SessionStatsEvent {
+            turns_completed: self.turns_completed,
+            total_tokens,
+            exec_calls,
+            patches_applied: self.patches_applied,
+            files_changed: self.changed_files.len(),
+            elapsed_ms: u64::try_from(self.started_at.elapsed().as_millis()).unwrap_or(u64::MAX),
+            history_items: self.history.raw_items().len(),
+        }
+    }
+
+    /// Adds a completed reasoning item from `turn_id`, replacing whatever an
+    /// earlier turn left behind.
+    pub(crate) fn record_reasoning(
+        &mut self,
+        turn_id: &str,
+        summary: Vec<String>,
+        raw_content: Vec<String>,
+    ) {
+        if self.last_reasoning.turn_id.as_deref() != Some(turn_id) {
+            self.last_reasoning = LastReasoningEvent {
+                turn_id: Some(turn_id.to_string()),
+                ..Default::default()
+            };
+        }
+        self.last_reasoning.summary.extend(summary);
+        self.last_reasoning.raw_content.extend(raw_content);
+    }
+
+    pub(crate) fn last_reasoning(&self) -> LastReasoningEvent {
+        self.last_reasoning.clone()
+    }
+
     pub(crate) fn set_pending_session_start_source(
         &mut self,
         value: Option<codex_hooks::SessionStartSource>,
@@ -293,6 +600,30 @@ mod tests {
     use crate::protocol::RateLimitWindow;
     use pretty_assertions::assert_eq;
 
+    #[tokio::test]
+    async fn session_stats_counts_distinct_changed_files() {
+        let session_configuration = make_session_configuration_for_tests().await;
+        let mut state = SessionState::new(session_configuration);
+        state.record_turn_completed();
+        state.record_patch_applied();
+        state.record_changed_files([PathBuf::from("a.txt"), PathBuf::from("b.txt")]);
+        state.record_changed_files([PathBuf::from("a.txt")]);
+
+        let stats = state.session_stats(3);
+        assert_eq!(
+            stats,
+            SessionStatsEvent {
+                turns_completed: 1,
+                total_tokens: 0,
+                exec_calls: 3,
+                patches_applied: 1,
+                files_changed: 2,
+                elapsed_ms: stats.elapsed_ms,
+                history_items: 0,
+            }
+        );
+    }
+
     #[tokio::test]
     async fn merge_mcp_tool_selection_deduplicates_and_preserves_order() {
         let session_configuration = make_session_configuration_for_tests().await;
@@ -546,4 +877,72 @@ mod tests {
             })
         );
     }
+
+    #[tokio::test]
+    async fn recent_errors_keep_newest_up_to_capacity() {
+        let session_configuration = make_session_configuration_for_tests().await;
+        let mut state = SessionState::new(session_configuration);
+        for index in 0..RECENT_ERRORS_CAPACITY + 2 {
+            state.record_recent_error(ErrorEvent {
+                message: format!("error {index}"),
+                codex_error_info: None,
+            });
+        }
+
+        let messages = |errors: Vec<ErrorEvent>| {
+            errors
+                .into_iter()
+                .map(|error| error.message)
+                .collect::<Vec<_>>()
+        };
+        let all = messages(state.recent_errors(None));
+        assert_eq!(all.len(), RECENT_ERRORS_CAPACITY);
+        assert_eq!(all.first().map(String::as_str), Some("error 2"));
+        assert_eq!(
+            messages(state.recent_errors(Some(2))),
+            vec![
+                format!("error {}", RECENT_ERRORS_CAPACITY),
+                format!("error {}", RECENT_ERRORS_CAPACITY + 1),
+            ]
+        );
+
+        assert_eq!(state.take_recent_errors().len(), RECENT_ERRORS_CAPACITY);
+        assert!(state.recent_errors(None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn extra_env_overrides_and_unsets_policy_values() {
+        let session_configuration = make_session_configuration_for_tests().await;
+        let mut state = SessionState::new(session_configuration);
+        state.set_extra_env("API_TOKEN".to_string(), "secret".to_string());
+        state.set_extra_env("PATH".to_string(), "/custom/bin".to_string());
+        state.unset_extra_env("HOME".to_string());
+        state.unset_extra_env("PATH".to_string());
+        state.set_extra_env("HOME".to_string(), "/tmp/home".to_string());
+
+        let mut env = HashMap::from([
+            ("HOME".to_string(), "/home/user".to_string()),
+            ("PATH".to_string(), "/usr/bin".to_string()),
+            ("USER".to_string(), "user".to_string()),
+        ]);
+        let mut touched = state.apply_extra_env(&mut env);
+        touched.sort();
+
+        assert_eq!(
+            env,
+            HashMap::from([
+                ("API_TOKEN".to_string(), "secret".to_string()),
+                ("HOME".to_string(), "/tmp/home".to_string()),
+                ("USER".to_string(), "user".to_string()),
+            ])
+        );
+        assert_eq!(
+            touched,
+            vec![
+                "API_TOKEN".to_string(),
+                "HOME".to_string(),
+                "PATH".to_string(),
+            ]
+        );
+    }
 }
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use tokio::sync::Notify;
 use tokio_util::sync::CancellationToken;
+use tokio_util::sync::DropGuard;
 use tokio_util::task::AbortOnDropHandle;
 
 use codex_protocol::dynamic_tools::DynamicToolResponse;
@@ -17,6 +18,7 @@ use rmcp::model::RequestId;
 use tokio::sync::oneshot;
 
 use crate::codex::TurnContext;
+use crate::protocol::ExecApprovalRequestEvent;
 use crate::protocol::ReviewDecision;
 use crate::protocol::TokenUsage;
 use crate::sandboxing::merge_permission_profiles;
@@ -54,6 +56,10 @@ pub(crate) struct RunningTask {
     pub(crate) turn_context: Arc<TurnContext>,
     // Timer recorded when the task drops to capture the full turn duration.
     pub(crate) _timer: Option<codex_otel::Timer>,
+    // Stops the turn deadline watchdog once the task is finished or aborted.
+    pub(crate) _deadline_guard: Option<DropGuard>,
+    // Stops the long-running turn notification once the task is finished or aborted.
+    pub(crate) _running_long_guard: Option<DropGuard>,
 }
 
 impl ActiveTurn {
@@ -76,6 +82,11 @@ impl ActiveTurn {
 #[derive(Default)]
 pub(crate) struct TurnState {
     pending_approvals: HashMap<String, oneshot::Sender<ReviewDecision>>,
+    /// Exec approval requests waiting for the `approval_batch_window_ms`
+    /// window to close.
+    queued_exec_approvals: Vec<ExecApprovalRequestEvent>,
+    /// Approval ids of each batch sent as `ExecApprovalBatchRequest`.
+    approval_batches: HashMap<String, Vec<String>>,
     pending_request_permissions: HashMap<String, oneshot::Sender<RequestPermissionsResponse>>,
     pending_user_input: HashMap<String, oneshot::Sender<RequestUserInputResponse>>,
     pending_elicitations: HashMap<(String, RequestId), oneshot::Sender<ElicitationResponse>>,
@@ -102,8 +113,36 @@ impl TurnState {
         self.pending_approvals.remove(key)
     }
 
+    /// Queues `request` for the current approval batch. Returns `true` when
+    /// it opened a new batch, making the caller responsible for sending it.
+    pub(crate) fn queue_exec_approval(&mut self, request: ExecApprovalRequestEvent) -> bool {
+        self.queued_exec_approvals.push(request);
+        self.queued_exec_approvals.len() == 1
+    }
+
+    pub(crate) fn take_queued_exec_approvals(&mut self) -> Vec<ExecApprovalRequestEvent> {
+        std::mem::take(&mut self.queued_exec_approvals)
+    }
+
+    pub(crate) fn insert_approval_batch(&mut self, batch_id: String, approval_ids: Vec<String>) {
+        self.approval_batches.insert(batch_id, approval_ids);
+    }
+
+    /// Removes the batch and returns the approval ids in it that have not
+    /// already been answered one by one.
+    pub(crate) fn take_approval_batch(&mut self, batch_id: &str) -> Vec<String> {
+        self.approval_batches
+            .remove(batch_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|approval_id| self.pending_approvals.contains_key(approval_id))
+            .collect()
+    }
+
     pub(crate) fn clear_pending(&mut self) {
         self.pending_approvals.clear();
+        self.queued_exec_approvals.clear();
+        self.approval_batches.clear();
         self.pending_request_permissions.clear();
         self.pending_user_input.clear();
         self.pending_elicitations.clear();
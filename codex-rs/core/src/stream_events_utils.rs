@@ -24,6 +24,8 @@ use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::FunctionCallOutputPayload;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::ProviderToolCallEvent;
 use codex_utils_stream_parser::strip_proposed_plan_blocks;
 use futures::Future;
 use tracing::debug;
@@ -189,6 +191,16 @@ pub(crate) async fn handle_output_item_done(
         }
         // No tool call: convert messages/reasoning into turn items and mark them as complete.
         Ok(None) => {
+            if ctx
+                .turn_context
+                .config
+                .suppress_whitespace_only_agent_messages
+                && raw_assistant_output_text_from_item(&item)
+                    .is_some_and(|text| text.trim().is_empty())
+            {
+                tracing::debug!("suppressing whitespace-only assistant message");
+                return Ok(output);
+            }
             if let Some(turn_item) = handle_non_tool_response_item(
                 ctx.sess.as_ref(),
                 ctx.turn_context.as_ref(),
@@ -335,6 +347,26 @@ pub(crate) async fn handle_non_tool_response_item(
             }
             Some(turn_item)
         }
+        ResponseItem::ProviderToolCall {
+            id,
+            name,
+            status,
+            arguments,
+            output,
+        } => {
+            sess.send_event(
+                turn_context,
+                EventMsg::ProviderToolCall(ProviderToolCallEvent {
+                    call_id: id.clone().unwrap_or_default(),
+                    name: name.clone(),
+                    status: status.clone(),
+                    arguments: arguments.clone(),
+                    output: output.clone(),
+                }),
+            )
+            .await;
+            None
+        }
         ResponseItem::FunctionCallOutput { .. } | ResponseItem::CustomToolCallOutput { .. } => {
             debug!("unexpected tool output from stream");
             None
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use codex_async_utils::OrCancelExt;
+use codex_protocol::config_types::ToolChoice;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::user_input::UserInput;
+use futures::prelude::*;
+use tokio_util::sync::CancellationToken;
+
+use super::SessionTask;
+use super::SessionTaskContext;
+use crate::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::codex::Session;
+use crate::codex::TurnContext;
+use crate::compact::content_items_to_text;
+use crate::error::CodexErr;
+use crate::error::Result as CodexResult;
+use crate::event_mapping::parse_turn_item;
+use crate::parse_command::shlex_join;
+use crate::protocol::EventMsg;
+use crate::protocol::TurnStartedEvent;
+use crate::state::ExecutedCommand;
+use crate::state::TaskKind;
+use crate::truncate::TruncationPolicy;
+use crate::truncate::truncate_text;
+
+/// Token budget for the command output quoted in the explanation request.
+const EXPLAINED_OUTPUT_MAX_TOKENS: usize = 2_000;
+
+/// Asks the model to explain a command that already ran, without offering it
+/// any tools. The explanation is emitted as an agent message but is not added
+/// to the conversation history.
+pub(crate) struct ExplainCommandTask {
+    command: ExecutedCommand,
+}
+
+impl ExplainCommandTask {
+    pub(crate) fn new(command: ExecutedCommand) -> Self {
+        Self { command }
+    }
+}
+
+#[async_trait]
+impl SessionTask for ExplainCommandTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::Regular
+    }
+
+    fn span_name(&self) -> &'static str {
+        "session_task.explain_command"
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        session: Arc<SessionTaskContext>,
+        ctx: Arc<TurnContext>,
+        _input: Vec<UserInput>,
+        cancellation_token: CancellationToken,
+    ) -> Option<String> {
+        let sess = session.clone_session();
+        sess.send_event(
+            ctx.as_ref(),
+            EventMsg::TurnStarted(TurnStartedEvent {
+                turn_id: ctx.sub_id.clone(),
+                model_context_window: ctx.model_context_window(),
+                collaboration_mode_kind: ctx.collaboration_mode.mode,
+            }),
+        )
+        .await;
+
+        let item = match explain(&sess, &ctx, &self.command)
+            .or_cancel(&cancellation_token)
+            .await
+        {
+            Ok(Ok(item)) => item,
+            Ok(Err(err)) => {
+                sess.send_event(ctx.as_ref(), EventMsg::Error(err.to_error_event(None)))
+                    .await;
+                return None;
+            }
+            Err(_) => return None,
+        };
+
+        let ResponseItem::Message { content, .. } = &item else {
+            return None;
+        };
+        let explanation = content_items_to_text(content);
+        if let Some(turn_item) = parse_turn_item(&item) {
+            sess.emit_turn_item_started(ctx.as_ref(), &turn_item).await;
+            sess.emit_turn_item_completed(ctx.as_ref(), turn_item).await;
+        }
+        explanation
+    }
+}
+
+async fn explain(
+    sess: &Session,
+    ctx: &TurnContext,
+    command: &ExecutedCommand,
+) -> CodexResult<ResponseItem> {
+    let mut input = sess
+        .clone_history()
+        .await
+        .for_prompt(&ctx.model_info.input_modalities);
+    input.push(ResponseItem::Message {
+        id: None,
+        role: "user".to_string(),
+        content: vec![ContentItem::InputText {
+            text: explanation_request(command),
+        }],
+        end_turn: None,
+        phase: None,
+    });
+    let prompt = Prompt {
+        input,
+        tools: Vec::new(),
+        parallel_tool_calls: false,
+        base_instructions: sess.get_base_instructions().await,
+        personality: ctx.personality,
+        output_schema: None,
+        tool_choice: ToolChoice::Auto,
+        max_output_tokens: None,
+    };
+
+    let mut client_session = sess.services.model_client.new_session();
+    let turn_metadata_header = ctx.turn_metadata_state.current_header_value();
+    let mut stream = client_session
+        .stream(
+            &prompt,
+            &ctx.model_info,
+            &ctx.session_telemetry,
+            ctx.reasoning_effort,
+            ctx.reasoning_summary,
+            ctx.config.service_tier,
+            turn_metadata_header.as_deref(),
+        )
+        .await?;
+
+    let mut explanation = None;
+    while let Some(event) = stream.next().await.transpose()? {
+        match event {
+            ResponseEvent::OutputItemDone(item @ ResponseItem::Message { .. }) => {
+                explanation = Some(item);
+            }
+            ResponseEvent::Completed { token_usage, .. } => {
+                sess.update_token_usage_info(ctx, token_usage.as_ref())
+                    .await;
+                return explanation
+                    .ok_or_else(|| CodexErr::Stream("model returned no explanation".into(), None));
+            }
+            _ => {}
+        }
+    }
+    Err(CodexErr::Stream(
+        "stream closed before response.completed".into(),
+        None,
+    ))
+}
+
+fn explanation_request(command: &ExecutedCommand) -> String {
+    let output = truncate_text(
+        &command.output,
+        TruncationPolicy::Tokens(EXPLAINED_OUTPUT_MAX_TOKENS),
+    );
+    format!(
+        "Explain in plain English what the following command did and what its result means. \
+         Do not run any commands.\n\n\
+         Command: {}\nWorking directory: {}\nExit code: {}\nOutput:\n{output}",
+        shlex_join(&command.command),
+        command.cwd.display(),
+        command.exit_code,
+    )
+}
@@ -1,8 +1,11 @@
 mod compact;
+mod explain_command;
 mod ghost_snapshot;
 mod regular;
 mod review;
+mod run_command;
 mod undo;
+mod undo_patch;
 mod user_shell;
 
 use std::sync::Arc;
@@ -10,11 +13,16 @@ use std::time::Duration;
 use std::time::Instant;
 
 use async_trait::async_trait;
+use codex_hooks::HookEvent;
+use codex_hooks::HookEventTurnRunningLong;
+use codex_hooks::HookPayload;
+use codex_hooks::HookResult;
 use tokio::select;
 use tokio::sync::Notify;
 use tokio_util::sync::CancellationToken;
 use tokio_util::task::AbortOnDropHandle;
 use tracing::Instrument;
+use tracing::debug;
 use tracing::info_span;
 use tracing::trace;
 use tracing::warn;
@@ -25,6 +33,12 @@ use crate::codex::TurnContext;
 use crate::contextual_user_message::TURN_ABORTED_OPEN_TAG;
 use crate::event_mapping::parse_turn_item;
 use crate::models_manager::manager::ModelsManager;
+use crate::plugins::PluginTaskHookStage;
+use crate::plugins::run_plugin_task_hooks;
+use crate::pricing::TurnCostEstimate;
+use crate::protocol::AssistantFinishedEvent;
+use crate::protocol::CodexErrorInfo;
+use crate::protocol::ErrorEvent;
 use crate::protocol::EventMsg;
 use crate::protocol::TokenUsage;
 use crate::protocol::TurnAbortReason;
@@ -45,10 +59,13 @@ use codex_protocol::user_input::UserInput;
 
 use crate::features::Feature;
 pub(crate) use compact::CompactTask;
+pub(crate) use explain_command::ExplainCommandTask;
 pub(crate) use ghost_snapshot::GhostSnapshotTask;
 pub(crate) use regular::RegularTask;
 pub(crate) use review::ReviewTask;
+pub(crate) use run_command::RunCommandTask;
 pub(crate) use undo::UndoTask;
+pub(crate) use undo_patch::UndoPatchTask;
 pub(crate) use user_shell::UserShellCommandMode;
 pub(crate) use user_shell::UserShellCommandTask;
 pub(crate) use user_shell::execute_user_shell_command;
@@ -97,6 +114,13 @@ pub(crate) trait SessionTask: Send + Sync + 'static {
     /// Returns the tracing name for a spawned task span.
     fn span_name(&self) -> &'static str;
 
+    /// Whether enabled plugins' `hooks.postTask` scripts run once this task
+    /// has emitted `TurnComplete`. Tasks that run plugins' `hooks.preTask`
+    /// scripts should return `true`.
+    fn runs_plugin_task_hooks(&self) -> bool {
+        false
+    }
+
     /// Executes the task until completion or cancellation.
     ///
     /// Implementations typically stream protocol events using `session` and
@@ -156,6 +180,7 @@ impl Session {
             let session_ctx = Arc::new(SessionTaskContext::new(Arc::clone(self)));
             let ctx = Arc::clone(&turn_context);
             let task_for_run = Arc::clone(&task);
+            let runs_plugin_task_hooks = task.runs_plugin_task_hooks();
             let task_cancellation_token = cancellation_token.child_token();
             // Task-owned turn spans keep a core-owned span open for the
             // full task lifecycle after the submission dispatch span ends.
@@ -179,6 +204,17 @@ impl Session {
                         .await;
                     let sess = session_ctx.clone_session();
                     sess.flush_rollout().await;
+                    // postTask hooks run while the turn is still active, so
+                    // their output belongs to it and an interrupt stops them.
+                    if runs_plugin_task_hooks && !task_cancellation_token.is_cancelled() {
+                        run_plugin_task_hooks(
+                            &sess,
+                            &ctx_for_finish,
+                            PluginTaskHookStage::PostTask,
+                            &task_cancellation_token,
+                        )
+                        .await;
+                    }
                     if !task_cancellation_token.is_cancelled() {
                         // Emit completion uniformly from spawn site so all tasks share the same lifecycle.
                         sess.on_task_finished(Arc::clone(&ctx_for_finish), last_agent_message)
@@ -190,6 +226,43 @@ impl Session {
             )
         };
 
+        let deadline_guard = turn_context.config.turn_deadline_seconds.map(|seconds| {
+            let deadline = Duration::from_secs(seconds);
+            let deadline_token = CancellationToken::new();
+            let sess = Arc::clone(self);
+            let ctx = Arc::clone(&turn_context);
+            let watchdog_token = deadline_token.clone();
+            tokio::spawn(async move {
+                select! {
+                    _ = watchdog_token.cancelled() => {}
+                    _ = tokio::time::sleep(deadline) => {
+                        sess.abort_turn_past_deadline(ctx, deadline).await;
+                    }
+                }
+            });
+            deadline_token.drop_guard()
+        });
+
+        let running_long_guard = turn_context
+            .config
+            .turn_running_long_seconds
+            .map(|seconds| {
+                let threshold = Duration::from_secs(seconds);
+                let running_long_token = CancellationToken::new();
+                let sess = Arc::clone(self);
+                let ctx = Arc::clone(&turn_context);
+                let watchdog_token = running_long_token.clone();
+                tokio::spawn(async move {
+                    select! {
+                        _ = watchdog_token.cancelled() => {}
+                        _ = tokio::time::sleep(threshold) => {
+                            sess.notify_turn_running_long(ctx, threshold).await;
+                        }
+                    }
+                });
+                running_long_token.drop_guard()
+            });
+
         let running_task = RunningTask {
             done,
             handle: Arc::new(AbortOnDropHandle::new(handle)),
@@ -198,6 +271,8 @@ impl Session {
             cancellation_token,
             turn_context: Arc::clone(&turn_context),
             _timer: timer,
+            _deadline_guard: deadline_guard,
+            _running_long_guard: running_long_guard,
         };
         self.register_new_active_task(running_task, token_usage_at_turn_start)
             .await;
@@ -217,6 +292,98 @@ impl Session {
         }
     }
 
+    /// Aborts the turn identified by `turn_context` once it has run past its
+    /// configured deadline, surfacing a `TurnDeadlineExceeded` error first.
+    async fn abort_turn_past_deadline(
+        self: &Arc<Self>,
+        turn_context: Arc<TurnContext>,
+        deadline: Duration,
+    ) {
+        let still_running = self
+            .active_turn
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|turn| turn.tasks.contains_key(&turn_context.sub_id));
+        if !still_running {
+            return;
+        }
+
+        warn!(
+            turn_id = %turn_context.sub_id,
+            "turn exceeded its {}s deadline; aborting",
+            deadline.as_secs()
+        );
+        self.send_event(
+            turn_context.as_ref(),
+            EventMsg::Error(ErrorEvent {
+                message: format!(
+                    "Turn exceeded its {}s deadline and was aborted.",
+                    deadline.as_secs()
+                ),
+                codex_error_info: Some(CodexErrorInfo::TurnDeadlineExceeded),
+            }),
+        )
+        .await;
+        // Treat the deadline like an interrupt so running exec processes are
+        // terminated along with the turn.
+        self.abort_all_tasks(TurnAbortReason::Interrupted).await;
+    }
+
+    /// Warns that the turn identified by `turn_context` has been running for
+    /// `threshold` without aborting it: a background event for clients and a
+    /// `TurnRunningLong` hook for the legacy `notify` command.
+    async fn notify_turn_running_long(
+        self: &Arc<Self>,
+        turn_context: Arc<TurnContext>,
+        threshold: Duration,
+    ) {
+        let still_running = self
+            .active_turn
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|turn| turn.tasks.contains_key(&turn_context.sub_id));
+        if !still_running {
+            return;
+        }
+
+        let elapsed_seconds = threshold.as_secs();
+        self.notify_background_event(
+            turn_context.as_ref(),
+            format!("Turn has been running for over {elapsed_seconds}s."),
+        )
+        .await;
+        let hook_outcomes = self
+            .hooks()
+            .dispatch(HookPayload {
+                session_id: self.conversation_id,
+                cwd: turn_context.cwd.clone(),
+                client: turn_context.app_server_client_name.clone(),
+                triggered_at: chrono::Utc::now(),
+                hook_event: HookEvent::TurnRunningLong {
+                    event: HookEventTurnRunningLong {
+                        thread_id: self.conversation_id,
+                        turn_id: turn_context.sub_id.clone(),
+                        elapsed_seconds,
+                    },
+                },
+            })
+            .await;
+        for hook_outcome in hook_outcomes {
+            if let HookResult::FailedContinue(error) | HookResult::FailedAbort(error) =
+                hook_outcome.result
+            {
+                warn!(
+                    turn_id = %turn_context.sub_id,
+                    hook_name = %hook_outcome.hook_name,
+                    error = %error,
+                    "turn_running_long hook failed"
+                );
+            }
+        }
+    }
+
     pub async fn on_task_finished(
         self: &Arc<Self>,
         turn_context: Arc<TurnContext>,
@@ -269,6 +436,7 @@ impl Session {
                 }
             }
         }
+        let mut cost_usd = None;
         // Emit token usage metrics.
         if let Some(token_usage_at_turn_start) = token_usage_at_turn_start {
             // TODO(jif): drop this
@@ -328,10 +496,31 @@ impl Session {
                 turn_token_usage.reasoning_output_tokens,
                 &[("token_type", "reasoning_output"), tmp_mem],
             );
+            if let Some(estimate) = TurnCostEstimate::for_model(
+                &turn_context.config.pricing,
+                &turn_context.model_info.slug,
+                &turn_token_usage,
+            ) {
+                debug!(
+                    input_tokens = estimate.input_tokens,
+                    output_tokens = estimate.output_tokens,
+                    cost_usd = estimate.cost_usd,
+                    "estimated turn cost"
+                );
+                cost_usd = Some(estimate.cost_usd);
+            }
+        }
+        if let Some(message) = last_agent_message.clone() {
+            let event = EventMsg::AssistantFinished(AssistantFinishedEvent {
+                turn_id: turn_context.sub_id.clone(),
+                message,
+            });
+            self.send_event(turn_context.as_ref(), event).await;
         }
         let event = EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: turn_context.sub_id.clone(),
             last_agent_message,
+            cost_usd,
         });
         self.send_event(turn_context.as_ref(), event).await;
     }
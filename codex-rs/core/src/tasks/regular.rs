@@ -72,6 +72,10 @@ impl SessionTask for RegularTask {
         "session_task.turn"
     }
 
+    fn runs_plugin_task_hooks(&self) -> bool {
+        true
+    }
+
     async fn run(
         self: Arc<Self>,
         session: Arc<SessionTaskContext>,
@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use codex_async_utils::OrCancelExt;
+use codex_protocol::models::PermissionProfile;
+use codex_protocol::user_input::UserInput;
+use tokio_util::sync::CancellationToken;
+
+use super::SessionTask;
+use super::SessionTaskContext;
+use crate::codex::TurnContext;
+use crate::exec::ExecParams;
+use crate::exec_env::create_env;
+use crate::protocol::CodexErrorInfo;
+use crate::protocol::ErrorEvent;
+use crate::protocol::EventMsg;
+use crate::sandboxing::SandboxPermissions;
+use crate::state::DeniedCommand;
+use crate::state::TaskKind;
+use crate::tools::handlers::ShellHandler;
+
+/// Runs a single command on behalf of a client (`Op::RunCommand`) with the
+/// same approval and sandbox handling as the `shell` tool, but without a model
+/// request and without recording anything to the conversation history.
+pub(crate) struct RunCommandTask {
+    command: Vec<String>,
+    cwd: Option<PathBuf>,
+    timeout_ms: Option<u64>,
+    sandbox_permissions: SandboxPermissions,
+    additional_permissions: Option<PermissionProfile>,
+    justification: Option<String>,
+}
+
+impl RunCommandTask {
+    pub(crate) fn new(command: Vec<String>, cwd: Option<PathBuf>, timeout_ms: Option<u64>) -> Self {
+        Self {
+            command,
+            cwd,
+            timeout_ms,
+            sandbox_permissions: SandboxPermissions::UseDefault,
+            additional_permissions: None,
+            justification: None,
+        }
+    }
+
+    /// Re-runs a command the user denied (`Op::ReapproveLastDenied`) with the
+    /// sandbox permissions it was originally requested with. The command is
+    /// put through approval again like any other request.
+    pub(crate) fn for_denied_command(denied: DeniedCommand) -> Self {
+        Self {
+            command: denied.command,
+            cwd: Some(denied.cwd),
+            timeout_ms: None,
+            sandbox_permissions: denied.sandbox_permissions,
+            additional_permissions: denied.additional_permissions,
+            justification: denied.justification,
+        }
+    }
+}
+
+#[async_trait]
+impl SessionTask for RunCommandTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::Regular
+    }
+
+    fn span_name(&self) -> &'static str {
+        "session_task.run_command"
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        session: Arc<SessionTaskContext>,
+        ctx: Arc<TurnContext>,
+        _input: Vec<UserInput>,
+        cancellation_token: CancellationToken,
+    ) -> Option<String> {
+        let sess = session.clone_session();
+        let cwd = self
+            .cwd
+            .as_ref()
+            .map_or_else(|| ctx.cwd.clone(), |cwd| ctx.cwd.join(cwd));
+        let exec_params = ExecParams {
+            command: self.command.clone(),
+            cwd,
+            expiration: self.timeout_ms.into(),
+            env: create_env(&ctx.shell_environment_policy, Some(sess.conversation_id)),
+            network: ctx.network.clone(),
+            sandbox_permissions: self.sandbox_permissions,
+            windows_sandbox_level: ctx.windows_sandbox_level,
+            justification: self.justification.clone(),
+            arg0: None,
+            stdin: None,
+        };
+        let call_id = format!("run-command-{}", ctx.sub_id);
+
+        let result = ShellHandler::run_command(
+            Arc::clone(&sess),
+            Arc::clone(&ctx),
+            call_id,
+            exec_params,
+            self.additional_permissions.clone(),
+        )
+        .or_cancel(&cancellation_token)
+        .await;
+        // The command's output is reported through the exec events; surface
+        // failures such as a rejected approval as an error as well.
+        if let Ok(Err(err)) = result {
+            sess.send_event(
+                ctx.as_ref(),
+                EventMsg::Error(ErrorEvent {
+                    message: format!("Command failed: {err}"),
+                    codex_error_info: Some(CodexErrorInfo::BadRequest),
+                }),
+            )
+            .await;
+        }
+        None
+    }
+}
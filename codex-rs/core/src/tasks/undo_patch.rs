@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use codex_protocol::models::DeveloperInstructions;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::user_input::UserInput;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use super::SessionTask;
+use super::SessionTaskContext;
+use crate::codex::TurnContext;
+use crate::protocol::EventMsg;
+use crate::protocol::PatchApplyBeginEvent;
+use crate::protocol::PatchApplyEndEvent;
+use crate::protocol::PatchApplyStatus;
+use crate::state::TaskKind;
+use crate::tools::context::SharedTurnDiffTracker;
+
+/// Reverts the file changes tracked during the most recent turn, reporting
+/// the revert as an auto-approved patch.
+pub(crate) struct UndoPatchTask {
+    tracker: SharedTurnDiffTracker,
+}
+
+impl UndoPatchTask {
+    pub(crate) fn new(tracker: SharedTurnDiffTracker) -> Self {
+        Self { tracker }
+    }
+}
+
+#[async_trait]
+impl SessionTask for UndoPatchTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::Regular
+    }
+
+    fn span_name(&self) -> &'static str {
+        "session_task.undo_patch"
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        session: Arc<SessionTaskContext>,
+        ctx: Arc<TurnContext>,
+        _input: Vec<UserInput>,
+        cancellation_token: CancellationToken,
+    ) -> Option<String> {
+        if cancellation_token.is_cancelled() {
+            return None;
+        }
+        let sess = session.clone_session();
+        // Rollback reads and rewrites files; run it on the blocking pool.
+        let tracker = Arc::clone(&self.tracker);
+        let changes =
+            tokio::task::spawn_blocking(move || tracker.blocking_lock().rollback_changes())
+                .await
+                .unwrap_or_default();
+        let call_id = format!("undo-patch-{}", ctx.sub_id);
+        sess.send_event(
+            ctx.as_ref(),
+            EventMsg::PatchApplyBegin(PatchApplyBeginEvent {
+                call_id: call_id.clone(),
+                turn_id: ctx.sub_id.clone(),
+                auto_approved: true,
+                changes: changes.clone(),
+                dry_run: false,
+            }),
+        )
+        .await;
+
+        let mut paths = changes
+            .keys()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>();
+        paths.sort();
+        let tracker = Arc::clone(&self.tracker);
+        let rollback = tokio::task::spawn_blocking(move || tracker.blocking_lock().rollback())
+            .await
+            .map_err(anyhow::Error::from)
+            .and_then(|result| result);
+        let (stdout, stderr, status) = match rollback {
+            Ok(()) => (
+                format!("Reverted changes to:\n{}", paths.join("\n")),
+                String::new(),
+                PatchApplyStatus::Completed,
+            ),
+            Err(err) => {
+                warn!("failed to undo last patch: {err:#}");
+                (String::new(), format!("{err:#}"), PatchApplyStatus::Failed)
+            }
+        };
+        let success = status == PatchApplyStatus::Completed;
+        sess.send_event(
+            ctx.as_ref(),
+            EventMsg::PatchApplyEnd(PatchApplyEndEvent {
+                call_id,
+                turn_id: ctx.sub_id.clone(),
+                stdout,
+                stderr,
+                success,
+                changes,
+                status,
+                dry_run: false,
+            }),
+        )
+        .await;
+
+        if success {
+            let note: ResponseItem = DeveloperInstructions::new(format!(
+                "The user reverted all file changes made during the previous turn. These files \
+                 are back to their earlier contents:\n{}",
+                paths.join("\n")
+            ))
+            .into();
+            sess.record_conversation_items(ctx.as_ref(), std::slice::from_ref(&note))
+                .await;
+            sess.flush_rollout().await;
+        }
+        None
+    }
+}
@@ -28,7 +28,9 @@ use crate::sandboxing::ExecRequest;
 use crate::sandboxing::SandboxPermissions;
 use crate::state::TaskKind;
 use crate::tools::format_exec_output_str;
+use crate::tools::runtimes::dry_run_output;
 use crate::tools::runtimes::maybe_wrap_shell_lc_with_snapshot;
+use crate::tools::sandbox_audit::record_sandbox_bypass;
 use crate::user_shell_command::user_shell_command_record_item;
 
 use super::SessionTask;
@@ -154,13 +156,15 @@ pub(crate) async fn execute_user_shell_command(
         .await;
 
     let sandbox_policy = SandboxPolicy::DangerFullAccess;
+    let mut env = create_env(
+        &turn_context.shell_environment_policy,
+        Some(session.conversation_id),
+    );
+    session.apply_extra_env(&mut env).await;
     let exec_env = ExecRequest {
         command: exec_command.clone(),
         cwd: cwd.clone(),
-        env: create_env(
-            &turn_context.shell_environment_policy,
-            Some(session.conversation_id),
-        ),
+        env,
         network: turn_context.network.clone(),
         // TODO(zhao-oai): Now that we have ExecExpiration::Cancellation, we
         // should use that instead of an "arbitrarily large" timeout here.
@@ -173,17 +177,29 @@ pub(crate) async fn execute_user_shell_command(
         network_sandbox_policy: NetworkSandboxPolicy::from(&sandbox_policy),
         justification: None,
         arg0: None,
+        stdin: None,
     };
 
     let stdout_stream = Some(StdoutStream {
         sub_id: turn_context.sub_id.clone(),
         call_id: call_id.clone(),
-        tx_event: session.get_tx_event(),
+        tx_event: session.get_tx_exec_output_event(),
     });
 
-    let exec_result = execute_exec_request(exec_env, &sandbox_policy, stdout_stream, None)
-        .or_cancel(&cancellation_token)
-        .await;
+    let exec_result = if turn_context.config.dry_run {
+        Ok(Ok(dry_run_output(format!(
+            "command not executed: {raw_command}"
+        ))))
+    } else {
+        // User shell commands always run without a sandbox, so they are audited
+        // like any other un-sandboxed exec. No approval is asked for them.
+        if let Some(log_path) = turn_context.config.sandbox_bypass_audit_log.as_deref() {
+            record_sandbox_bypass(log_path, &call_id, &exec_env.command, &exec_env.cwd, None).await;
+        }
+        execute_exec_request(exec_env, &sandbox_policy, stdout_stream, None)
+            .or_cancel(&cancellation_token)
+            .await
+    };
 
     match exec_result {
         Err(CancelErr::Cancelled) => {
@@ -11,6 +11,7 @@ use codex_protocol::config_types::CollaborationModeMask;
 use codex_protocol::openai_models::ModelInfo;
 use codex_protocol::openai_models::ModelPreset;
 use codex_protocol::openai_models::ModelsResponse;
+use codex_protocol::protocol::SessionSource;
 use once_cell::sync::Lazy;
 
 use crate::AuthManager;
@@ -64,6 +65,20 @@ pub fn thread_manager_with_models_provider_and_home(
     ThreadManager::with_models_provider_and_home_for_tests(auth, provider, codex_home)
 }
 
+pub fn thread_manager_with_models_provider_home_and_source(
+    auth: CodexAuth,
+    provider: ModelProviderInfo,
+    codex_home: PathBuf,
+    session_source: SessionSource,
+) -> ThreadManager {
+    ThreadManager::with_models_provider_home_and_source_for_tests(
+        auth,
+        provider,
+        codex_home,
+        session_source,
+    )
+}
+
 pub fn models_manager_with_provider(
     codex_home: PathBuf,
     auth_manager: Arc<AuthManager>,
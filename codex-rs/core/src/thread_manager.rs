@@ -30,6 +30,7 @@ use codex_protocol::protocol::McpServerRefreshConfig;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::SessionStateSnapshot;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -151,7 +152,12 @@ impl ThreadManager {
     ) -> Self {
         let codex_home = config.codex_home.clone();
         let (thread_created_tx, _) = broadcast::channel(THREAD_CREATED_CHANNEL_CAPACITY);
-        let plugins_manager = Arc::new(PluginsManager::new(codex_home.clone()));
+        let plugins_manager = Arc::new(match config.plugin_cache_max_entries {
+            Some(max_entries) => {
+                PluginsManager::with_cache_max_entries(codex_home.clone(), max_entries)
+            }
+            None => PluginsManager::new(codex_home.clone()),
+        });
         let mcp_manager = Arc::new(McpManager::new(Arc::clone(&plugins_manager)));
         let skills_manager = Arc::new(SkillsManager::new(
             codex_home.clone(),
@@ -207,6 +213,22 @@ impl ThreadManager {
         auth: CodexAuth,
         provider: ModelProviderInfo,
         codex_home: PathBuf,
+    ) -> Self {
+        Self::with_models_provider_home_and_source_for_tests(
+            auth,
+            provider,
+            codex_home,
+            SessionSource::Exec,
+        )
+    }
+
+    /// Like [`Self::with_models_provider_and_home_for_tests`], for threads
+    /// that should look like they were started by `session_source`.
+    pub(crate) fn with_models_provider_home_and_source_for_tests(
+        auth: CodexAuth,
+        provider: ModelProviderInfo,
+        codex_home: PathBuf,
+        session_source: SessionSource,
     ) -> Self {
         set_thread_manager_test_mode_for_tests(true);
         let auth_manager = AuthManager::from_auth_for_testing(auth);
@@ -233,7 +255,7 @@ impl ThreadManager {
                 mcp_manager,
                 file_watcher,
                 auth_manager,
-                session_source: SessionSource::Exec,
+                session_source,
                 ops_log: should_use_test_thread_manager_behavior()
                     .then(|| Arc::new(std::sync::Mutex::new(Vec::new()))),
             }),
@@ -352,17 +374,59 @@ impl ThreadManager {
         .await
     }
 
+    /// Resume a thread from its rollout.
+    ///
+    /// The rollout only supplies the conversation history. The model,
+    /// approval policy, sandbox policy and other session settings come from
+    /// `config`, so a caller resumes with different settings by overriding
+    /// them there, e.g. through `ConfigOverrides`. When those settings differ
+    /// from the ones recorded in the rollout, the first resumed turn tells
+    /// the model about the change, and a model switch also emits a warning.
     pub async fn resume_thread_from_rollout(
         &self,
         config: Config,
         rollout_path: PathBuf,
         auth_manager: Arc<AuthManager>,
     ) -> CodexResult<NewThread> {
-        let initial_history = RolloutRecorder::get_rollout_history(&rollout_path).await?;
-        Box::pin(self.resume_thread_with_history(config, initial_history, auth_manager, false))
+        Box::pin(self.resume_thread_from_rollout_up_to(config, rollout_path, auth_manager, None))
             .await
     }
 
+    /// Resume a thread from its rollout, keeping only the first `resume_up_to`
+    /// user turns when set (`None` keeps everything).
+    ///
+    /// Turns are counted the same way as for `Op::ThreadRollback`: each user
+    /// message starts a turn, and turns already rolled back in the rollout do
+    /// not count. Later turns are dropped by submitting `Op::ThreadRollback` to
+    /// the resumed thread, so the cut is recorded in the rollout and the
+    /// thread emits `EventMsg::ThreadRolledBack` once it has been applied.
+    pub async fn resume_thread_from_rollout_up_to(
+        &self,
+        config: Config,
+        rollout_path: PathBuf,
+        auth_manager: Arc<AuthManager>,
+        resume_up_to: Option<usize>,
+    ) -> CodexResult<NewThread> {
+        let initial_history = RolloutRecorder::get_rollout_history(&rollout_path).await?;
+        let turns_to_drop = resume_up_to.map_or(0, |keep_turns| {
+            truncation::user_message_positions_in_rollout(&initial_history.get_rollout_items())
+                .len()
+                .saturating_sub(keep_turns)
+        });
+        let new_thread =
+            Box::pin(self.resume_thread_with_history(config, initial_history, auth_manager, false))
+                .await?;
+        if turns_to_drop > 0 {
+            new_thread
+                .thread
+                .submit(Op::ThreadRollback {
+                    num_turns: u32::try_from(turns_to_drop).unwrap_or(u32::MAX),
+                })
+                .await?;
+        }
+        Ok(new_thread)
+    }
+
     pub async fn resume_thread_with_history(
         &self,
         config: Config,
@@ -423,6 +487,26 @@ impl ThreadManager {
         .await
     }
 
+    /// Start a new thread and seed it with state captured by `Op::ExportState`.
+    ///
+    /// Unlike rollout resume, this does not replay an event log: the snapshot's
+    /// history, approvals, and granted permissions are installed directly. The
+    /// restored thread gets a fresh id and takes its configuration from `config`.
+    pub async fn restore_thread_from_snapshot(
+        &self,
+        config: Config,
+        snapshot: SessionStateSnapshot,
+    ) -> CodexResult<NewThread> {
+        let new_thread = self.start_thread(config).await?;
+        new_thread
+            .thread
+            .codex
+            .session
+            .restore_state_snapshot(snapshot)
+            .await;
+        Ok(new_thread)
+    }
+
     pub(crate) fn agent_control(&self) -> AgentControl {
         AgentControl::new(Arc::downgrade(&self.state))
     }
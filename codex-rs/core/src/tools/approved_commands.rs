@@ -0,0 +1,122 @@
+//! Opt-in persistence of commands approved for the session.
+//!
+//! When `persist_approved_commands` is enabled, the approval key of every
+//! command the user approves with `ReviewDecision::ApprovedForSession` is
+//! appended as one JSON line to `approved_commands.jsonl` in `codex_home`, and
+//! new sessions start with those approvals already cached. Keys only carry a
+//! digest of any stdin the command was approved with, never the input itself.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+const APPROVED_COMMANDS_FILE: &str = "approved_commands.jsonl";
+
+#[derive(Clone, Debug)]
+pub(crate) struct ApprovedCommandsStore {
+    path: PathBuf,
+}
+
+impl ApprovedCommandsStore {
+    pub(crate) fn new(codex_home: &Path) -> Self {
+        Self {
+            path: codex_home.join(APPROVED_COMMANDS_FILE),
+        }
+    }
+
+    /// Returns the serialized approval keys saved so far. A missing file
+    /// yields none; lines that are not valid JSON are skipped.
+    pub(crate) async fn load(&self) -> Vec<String> {
+        let contents = match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+            Err(err) => {
+                warn!(
+                    "failed to read approved commands {}: {err}",
+                    self.path.display()
+                );
+                return Vec::new();
+            }
+        };
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| serde_json::from_str::<serde_json::Value>(line).is_ok())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Appends `keys` right away so approvals survive a crash. Failures are
+    /// logged and otherwise ignored; the approval still applies in memory.
+    pub(crate) async fn append(&self, keys: &[String]) {
+        if keys.is_empty() {
+            return;
+        }
+        let mut lines = String::new();
+        for key in keys {
+            lines.push_str(key);
+            lines.push('\n');
+        }
+
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await;
+        let result = match file {
+            Ok(mut file) => file.write_all(lines.as_bytes()).await,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            warn!(
+                "failed to write approved commands {}: {err}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn appended_keys_are_loaded_back() {
+        let codex_home = TempDir::new().expect("tempdir");
+        let store = ApprovedCommandsStore::new(codex_home.path());
+        assert_eq!(store.load().await, Vec::<String>::new());
+
+        store.append(&[r#"{"command":["ls"]}"#.to_string()]).await;
+        store
+            .append(&[r#"{"command":["git","status"]}"#.to_string()])
+            .await;
+
+        assert_eq!(
+            store.load().await,
+            vec![
+                r#"{"command":["ls"]}"#.to_string(),
+                r#"{"command":["git","status"]}"#.to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn malformed_lines_are_skipped() {
+        let codex_home = TempDir::new().expect("tempdir");
+        std::fs::write(
+            codex_home.path().join(APPROVED_COMMANDS_FILE),
+            "{\"command\":[\"ls\"]}\nnot json\n\n",
+        )
+        .expect("write approved commands");
+
+        let store = ApprovedCommandsStore::new(codex_home.path());
+        assert_eq!(
+            store.load().await,
+            vec![r#"{"command":["ls"]}"#.to_string()]
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Best-effort previews of function call arguments while they stream.
+//!
+//! The Responses API streams a function call's JSON arguments as raw text
+//! deltas. `PartialJson` tracks just enough lexical state to close whatever
+//! strings, arrays, and objects are still open so the prefix can be parsed.
+
+use std::collections::HashMap;
+
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::ToolCallArgumentsPreviewEvent;
+use serde_json::Value;
+
+/// A new preview is parsed once the arguments grew by at least
+/// `1 / PREVIEW_GROWTH_DIVISOR` of their length since the last parse.
+const PREVIEW_GROWTH_DIVISOR: usize = 4;
+
+/// Previews for the function calls of a single sampling request, keyed by the
+/// item id the stream uses to address argument deltas.
+#[derive(Debug, Default)]
+pub(crate) struct ToolArgumentsPreviews {
+    pending: HashMap<String, PendingToolArguments>,
+}
+
+#[derive(Debug)]
+struct PendingToolArguments {
+    call_id: String,
+    tool_name: String,
+    arguments: PartialJson,
+    last_preview: Option<Value>,
+    /// Length of the arguments when they were last parsed.
+    parsed_len: usize,
+}
+
+impl ToolArgumentsPreviews {
+    /// Starts tracking `item` if it is a function call announced with an id.
+    pub(crate) fn start(&mut self, item: &ResponseItem) {
+        let ResponseItem::FunctionCall {
+            id: Some(id),
+            name,
+            arguments,
+            call_id,
+        } = item
+        else {
+            return;
+        };
+        let mut partial = PartialJson::default();
+        partial.push(arguments);
+        self.pending.insert(
+            id.clone(),
+            PendingToolArguments {
+                call_id: call_id.clone(),
+                tool_name: name.clone(),
+                arguments: partial,
+                last_preview: None,
+                parsed_len: 0,
+            },
+        );
+    }
+
+    /// Stops tracking `item` once the complete call has been received.
+    pub(crate) fn finish(&mut self, item: &ResponseItem) {
+        if let ResponseItem::FunctionCall { id: Some(id), .. } = item {
+            self.pending.remove(id);
+        }
+    }
+
+    /// Appends `delta` to the arguments of `item_id` and returns a preview
+    /// event when the parsed arguments changed. Deltas for unknown items and
+    /// prefixes that cannot be parsed yet produce nothing.
+    ///
+    /// Each preview parses the whole prefix, so the prefix is only parsed
+    /// again once it has grown by a fraction of its size. This keeps the total
+    /// parsing work linear in the length of the arguments.
+    pub(crate) fn push_delta(
+        &mut self,
+        turn_id: &str,
+        item_id: &str,
+        delta: &str,
+    ) -> Option<ToolCallArgumentsPreviewEvent> {
+        let pending = self.pending.get_mut(item_id)?;
+        pending.arguments.push(delta);
+        let len = pending.arguments.len();
+        if len - pending.parsed_len < len / PREVIEW_GROWTH_DIVISOR {
+            return None;
+        }
+        pending.parsed_len = len;
+        let preview = pending.arguments.preview()?;
+        if pending.last_preview.as_ref() == Some(&preview) {
+            return None;
+        }
+        pending.last_preview = Some(preview.clone());
+        Some(ToolCallArgumentsPreviewEvent {
+            turn_id: turn_id.to_string(),
+            call_id: pending.call_id.clone(),
+            tool_name: pending.tool_name.clone(),
+            arguments: preview,
+        })
+    }
+}
+
+/// Incrementally scanned prefix of a JSON document.
+#[derive(Debug, Default)]
+pub(crate) struct PartialJson {
+    buffer: String,
+    /// Closing brackets for the containers that are currently open, innermost
+    /// last.
+    closers: String,
+    in_string: bool,
+    escaped: bool,
+    /// The most recent prefix length (and the closers it needs) that ends on a
+    /// value boundary, used when the full prefix ends mid-token.
+    checkpoint: Option<(usize, String)>,
+}
+
+impl PartialJson {
+    pub(crate) fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub(crate) fn push(&mut self, delta: &str) {
+        let offset = self.buffer.len();
+        self.buffer.push_str(delta);
+        // Every byte that matters here is ASCII, so scanning bytes never
+        // splits a multi-byte character at a checkpoint.
+        for (index, byte) in delta.bytes().enumerate() {
+            let position = offset + index;
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+            match byte {
+                b'"' => self.in_string = true,
+                b'{' => {
+                    self.closers.push('}');
+                    self.checkpoint = Some((position + 1, self.closers.clone()));
+                }
+                b'[' => {
+                    self.closers.push(']');
+                    self.checkpoint = Some((position + 1, self.closers.clone()));
+                }
+                b'}' | b']' => {
+                    self.closers.pop();
+                    self.checkpoint = Some((position + 1, self.closers.clone()));
+                }
+                b',' => self.checkpoint = Some((position, self.closers.clone())),
+                _ => {}
+            }
+        }
+    }
+
+    /// Parses the prefix received so far. Tries closing everything that is
+    /// open first, then falls back to the last value boundary, e.g. when the
+    /// prefix ends inside an object key or a `true` literal.
+    pub(crate) fn preview(&self) -> Option<Value> {
+        let mut completed = self.buffer.clone();
+        if self.in_string {
+            if self.escaped {
+                completed.pop();
+            }
+            completed.push('"');
+        }
+        completed.extend(self.closers.chars().rev());
+        if let Ok(value) = serde_json::from_str(&completed) {
+            return Some(value);
+        }
+
+        let (position, closers) = self.checkpoint.as_ref()?;
+        let mut completed = self.buffer[..*position].to_string();
+        completed.extend(closers.chars().rev());
+        serde_json::from_str(&completed).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn preview(input: &str) -> Option<Value> {
+        let mut partial = PartialJson::default();
+        partial.push(input);
+        partial.preview()
+    }
+
+    #[test]
+    fn closes_open_strings_and_containers() {
+        assert_eq!(
+            preview(r#"{"path": "src/ma"#),
+            Some(json!({"path": "src/ma"}))
+        );
+        assert_eq!(
+            preview(r#"{"lines": [1, 2, {"a": "b"#),
+            Some(json!({"lines": [1, 2, {"a": "b"}]}))
+        );
+        assert_eq!(preview(r#"{"text": "a\"#), Some(json!({"text": "a"})));
+    }
+
+    #[test]
+    fn falls_back_to_last_value_boundary() {
+        assert_eq!(preview(r#"{"a": 1, "ke"#), Some(json!({"a": 1})));
+        assert_eq!(preview(r#"{"a": tr"#), Some(json!({})));
+        assert_eq!(preview(r#"{"a": [true, fa"#), Some(json!({"a": [true]})));
+        assert_eq!(preview(r#"{"a":"#), Some(json!({})));
+    }
+
+    #[test]
+    fn unparseable_prefix_has_no_preview() {
+        assert_eq!(preview(""), None);
+        assert_eq!(preview("nul"), None);
+    }
+
+    #[test]
+    fn push_delta_reports_only_changed_previews() {
+        let mut previews = ToolArgumentsPreviews::default();
+        previews.start(&ResponseItem::FunctionCall {
+            id: Some("fc_1".to_string()),
+            name: "write_file".to_string(),
+            arguments: String::new(),
+            call_id: "call_1".to_string(),
+        });
+
+        let first = previews.push_delta("turn", "fc_1", r#"{"path": "a"#);
+        assert_eq!(
+            first.map(|event| (event.call_id, event.tool_name, event.arguments)),
+            Some((
+                "call_1".to_string(),
+                "write_file".to_string(),
+                json!({"path": "a"})
+            ))
+        );
+        // `"co` ends inside a key, so the preview falls back to the same value.
+        assert_eq!(
+            previews
+                .push_delta("turn", "fc_1", r#"", "co"#)
+                .map(|event| event.arguments),
+            None
+        );
+        assert_eq!(
+            previews
+                .push_delta("turn", "fc_1", r#"ntent": "x"#)
+                .map(|event| event.arguments),
+            Some(json!({"path": "a", "content": "x"}))
+        );
+        assert!(previews.push_delta("turn", "unknown", "{").is_none());
+    }
+
+    #[test]
+    fn push_delta_reparses_large_arguments_only_after_enough_growth() {
+        let mut previews = ToolArgumentsPreviews::default();
+        previews.start(&ResponseItem::FunctionCall {
+            id: Some("fc_1".to_string()),
+            name: "write_file".to_string(),
+            arguments: String::new(),
+            call_id: "call_1".to_string(),
+        });
+
+        let content = "x".repeat(4_000);
+        assert!(
+            previews
+                .push_delta("turn", "fc_1", &format!(r#"{{"content": "{content}"#))
+                .is_some()
+        );
+        // A small delta is not worth re-parsing 4 KB of arguments.
+        assert!(previews.push_delta("turn", "fc_1", "y").is_none());
+        // Once the arguments grew by a quarter, the preview catches up.
+        let more = "z".repeat(1_500);
+        let preview = previews
+            .push_delta("turn", "fc_1", &more)
+            .map(|event| event.arguments);
+        assert_eq!(
+            preview,
+            Some(json!({"content": format!("{content}y{more}")}))
+        );
+    }
+}
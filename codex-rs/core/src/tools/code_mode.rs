@@ -148,7 +148,8 @@ async fn execute_node(
     let node_path = resolve_compatible_node(exec.turn.config.js_repl_node_path.as_deref()).await?;
     let started_at = std::time::Instant::now();
 
-    let env = create_env(&exec.turn.shell_environment_policy, None);
+    let mut env = create_env(&exec.turn.shell_environment_policy, None);
+    exec.session.apply_extra_env(&mut env).await;
     let mut cmd = tokio::process::Command::new(&node_path);
     cmd.arg("--experimental-vm-modules");
     cmd.arg("--eval");
@@ -0,0 +1,95 @@
+//! Shortened commands for exec command events.
+//!
+//! Events only show the command, so an argument longer than
+//! `exec_command_display_max_arg_len` bytes is shortened there: an embedded
+//! `apply_patch` body is replaced by a summary, and whatever is still too long
+//! is truncated. The command that actually runs is never changed.
+
+use codex_utils_string::take_bytes_at_char_boundary;
+
+const BEGIN_PATCH_MARKER: &str = "*** Begin Patch";
+const END_PATCH_MARKER: &str = "*** End Patch";
+const PATCH_FILE_MARKERS: [&str; 3] = ["*** Add File: ", "*** Update File: ", "*** Delete File: "];
+
+pub(crate) fn command_for_display(command: &[String], max_arg_len: usize) -> Vec<String> {
+    command
+        .iter()
+        .map(|arg| arg_for_display(arg, max_arg_len))
+        .collect()
+}
+
+fn arg_for_display(arg: &str, max_len: usize) -> String {
+    if arg.len() <= max_len {
+        return arg.to_string();
+    }
+    let summarized = summarize_patch(arg);
+    let arg = summarized.as_deref().unwrap_or(arg);
+    if arg.len() <= max_len {
+        return arg.to_string();
+    }
+    let kept = take_bytes_at_char_boundary(arg, max_len);
+    format!("{kept}… [{} more bytes]", arg.len() - kept.len())
+}
+
+/// Replaces the body of the first patch in `arg` with a line counting the
+/// files and lines it contains, keeping any surrounding text such as a
+/// heredoc wrapper.
+fn summarize_patch(arg: &str) -> Option<String> {
+    let begin = arg.find(BEGIN_PATCH_MARKER)? + BEGIN_PATCH_MARKER.len();
+    let end = begin + arg[begin..].find(END_PATCH_MARKER)?;
+    let body = arg[begin..end].trim_matches('\n');
+    let files = body
+        .lines()
+        .filter(|line| {
+            PATCH_FILE_MARKERS
+                .iter()
+                .any(|marker| line.starts_with(marker))
+        })
+        .count();
+    let lines = body.lines().count();
+    Some(format!(
+        "{}\n[{files} {}, {lines} {} omitted]\n{}",
+        &arg[..begin],
+        if files == 1 { "file" } else { "files" },
+        if lines == 1 { "line" } else { "lines" },
+        &arg[end..],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn short_arguments_are_unchanged() {
+        let command = vec!["echo".to_string(), "hello".to_string()];
+        assert_eq!(command_for_display(&command, 5), command);
+    }
+
+    #[test]
+    fn long_patch_bodies_are_summarized() {
+        let patch = "*** Begin Patch\n*** Add File: a.txt\n+one\n+two\n*** Delete File: b.txt\n*** End Patch\n";
+        let script = format!("apply_patch <<'EOF'\n{patch}EOF\n");
+        let command = vec!["bash".to_string(), "-lc".to_string(), script];
+
+        assert_eq!(
+            command_for_display(&command, 100),
+            vec![
+                "bash".to_string(),
+                "-lc".to_string(),
+                "apply_patch <<'EOF'\n*** Begin Patch\n[2 files, 4 lines omitted]\n*** End Patch\nEOF\n"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn long_arguments_are_truncated_on_char_boundary() {
+        let command = vec!["echo".to_string(), "héllo world".to_string()];
+        assert_eq!(
+            command_for_display(&command, 2),
+            vec!["echo".to_string(), "h… [11 more bytes]".to_string()]
+        );
+    }
+}
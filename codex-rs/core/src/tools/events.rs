@@ -3,7 +3,10 @@ use crate::codex::TurnContext;
 use crate::error::CodexErr;
 use crate::error::SandboxErr;
 use crate::exec::ExecToolCallOutput;
+use crate::exec::is_acceptable_exit_code;
+use crate::exec::is_acceptable_program_exit_code;
 use crate::function_tool::FunctionCallError;
+use crate::git_info::git_ignored_paths;
 use crate::parse_command::parse_command;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandBeginEvent;
@@ -15,6 +18,8 @@ use crate::protocol::PatchApplyBeginEvent;
 use crate::protocol::PatchApplyEndEvent;
 use crate::protocol::PatchApplyStatus;
 use crate::protocol::TurnDiffEvent;
+use crate::protocol::WarningEvent;
+use crate::tools::command_display::command_for_display;
 use crate::tools::context::SharedTurnDiffTracker;
 use crate::tools::sandboxing::ToolError;
 use codex_protocol::parse_command::ParsedCommand;
@@ -77,7 +82,10 @@ pub(crate) async fn emit_exec_command_begin(
                 call_id: ctx.call_id.to_string(),
                 process_id: process_id.map(str::to_owned),
                 turn_id: ctx.turn.sub_id.clone(),
-                command: command.to_vec(),
+                command: command_for_display(
+                    command,
+                    ctx.turn.config.exec_command_display_max_arg_len,
+                ),
                 cwd: cwd.to_path_buf(),
                 parsed_cmd: parsed_cmd.to_vec(),
                 source,
@@ -187,6 +195,7 @@ impl ToolEmitter {
                             turn_id: ctx.turn.sub_id.clone(),
                             auto_approved: *auto_approved,
                             changes: changes.clone(),
+                            dry_run: ctx.turn.config.dry_run,
                         }),
                     )
                     .await;
@@ -205,6 +214,9 @@ impl ToolEmitter {
                     },
                 )
                 .await;
+                if output.exit_code == 0 {
+                    warn_on_gitignored_patch_paths(ctx, changes).await;
+                }
             }
             (
                 Self::ApplyPatch { changes, .. },
@@ -296,6 +308,33 @@ impl ToolEmitter {
         }
     }
 
+    /// Whether `exit_code` should be reported to the model as success, taking
+    /// `acceptable_exit_codes` into account for shell commands.
+    fn exit_code_is_success(&self, ctx: ToolEventCtx<'_>, exit_code: i32) -> bool {
+        match self {
+            Self::Shell { command, .. } | Self::UnifiedExec { command, .. } => {
+                is_acceptable_exit_code(&ctx.turn.config.acceptable_exit_codes, command, exit_code)
+            }
+            Self::ApplyPatch { .. } => exit_code == 0,
+        }
+    }
+
+    /// Whether a likely sandbox denial should still be reported as success.
+    /// Only per-program `acceptable_exit_codes` entries apply; the `"*"` entry
+    /// never masks a denial.
+    fn denied_exit_code_is_success(&self, ctx: ToolEventCtx<'_>, exit_code: i32) -> bool {
+        match self {
+            Self::Shell { command, .. } | Self::UnifiedExec { command, .. } => {
+                is_acceptable_program_exit_code(
+                    &ctx.turn.config.acceptable_exit_codes,
+                    command,
+                    exit_code,
+                )
+            }
+            Self::ApplyPatch { .. } => false,
+        }
+    }
+
     pub async fn finish(
         &self,
         ctx: ToolEventCtx<'_>,
@@ -306,13 +345,21 @@ impl ToolEmitter {
                 let content = self.format_exec_output_for_model(&output, ctx);
                 let exit_code = output.exit_code;
                 let event = ToolEventStage::Success(output);
-                let result = if exit_code == 0 {
+                let result = if self.exit_code_is_success(ctx, exit_code) {
                     Ok(content)
                 } else {
                     Err(FunctionCallError::RespondToModel(content))
                 };
                 (event, result)
             }
+            // A likely sandbox denial whose exit code is configured as success
+            // was not escalated by the orchestrator; report it as a result.
+            Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Denied { output, .. })))
+                if self.denied_exit_code_is_success(ctx, output.exit_code) =>
+            {
+                let content = self.format_exec_output_for_model(&output, ctx);
+                (ToolEventStage::Success(*output), Ok(content))
+            }
             Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Timeout { output })))
             | Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Denied { output, .. }))) => {
                 let response = self.format_exec_output_for_model(&output, ctx);
@@ -471,7 +518,10 @@ async fn emit_exec_end(
                 call_id: ctx.call_id.to_string(),
                 process_id: exec_input.process_id.map(str::to_owned),
                 turn_id: ctx.turn.sub_id.clone(),
-                command: exec_input.command.to_vec(),
+                command: command_for_display(
+                    exec_input.command,
+                    ctx.turn.config.exec_command_display_max_arg_len,
+                ),
                 cwd: exec_input.cwd.to_path_buf(),
                 parsed_cmd: exec_input.parsed_cmd.to_vec(),
                 source: exec_input.source,
@@ -488,6 +538,54 @@ async fn emit_exec_end(
         .await;
 }
 
+/// Warns when a successful patch created or modified files that git ignores,
+/// as reviewers will not see those changes in `git status`.
+async fn warn_on_gitignored_patch_paths(
+    ctx: ToolEventCtx<'_>,
+    changes: &HashMap<PathBuf, FileChange>,
+) {
+    if !ctx.turn.config.warn_on_gitignored_patch_paths || ctx.turn.config.dry_run {
+        return;
+    }
+    let mut written: Vec<PathBuf> = changes
+        .iter()
+        .filter_map(|(path, change)| match change {
+            FileChange::Add { .. } | FileChange::Binary { .. } | FileChange::Symlink { .. } => {
+                Some(path.clone())
+            }
+            FileChange::Update { move_path, .. } => {
+                Some(move_path.clone().unwrap_or_else(|| path.clone()))
+            }
+            FileChange::Delete { .. } => None,
+        })
+        .collect();
+    written.sort();
+    let ignored = git_ignored_paths(ctx.turn.cwd.as_path(), &written).await;
+    if ignored.is_empty() {
+        return;
+    }
+    let paths = ignored
+        .iter()
+        .map(|path| {
+            path.strip_prefix(ctx.turn.cwd.as_path())
+                .unwrap_or(path)
+                .display()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    ctx.session
+        .send_event(
+            ctx.turn,
+            EventMsg::Warning(WarningEvent {
+                message: format!(
+                    "apply_patch wrote files ignored by git; these changes will not show up in `git status`: {paths}"
+                ),
+            }),
+        )
+        .await;
+}
+
 async fn emit_patch_end(
     ctx: ToolEventCtx<'_>,
     changes: HashMap<PathBuf, FileChange>,
@@ -496,6 +594,10 @@ async fn emit_patch_end(
     success: bool,
     status: PatchApplyStatus,
 ) {
+    let baseline_reset = match ctx.turn_diff_tracker {
+        Some(tracker) => tracker.lock().await.on_patch_end(&changes),
+        None => false,
+    };
     ctx.session
         .send_event(
             ctx.turn,
@@ -507,6 +609,7 @@ async fn emit_patch_end(
                 success,
                 changes,
                 status,
+                dry_run: ctx.turn.config.dry_run,
             }),
         )
         .await;
@@ -516,7 +619,13 @@ async fn emit_patch_end(
             let mut guard = tracker.lock().await;
             guard.get_unified_diff()
         };
-        if let Ok(Some(unified_diff)) = unified_diff {
+        // A deferred `Op::ResetTurnDiff` just ran; report the now empty diff.
+        let unified_diff = match unified_diff {
+            Ok(None) if baseline_reset => Some(String::new()),
+            Ok(unified_diff) => unified_diff,
+            Err(_) => None,
+        };
+        if let Some(unified_diff) = unified_diff {
             ctx.session
                 .send_event(ctx.turn, EventMsg::TurnDiff(TurnDiffEvent { unified_diff }))
                 .await;
@@ -233,7 +233,7 @@ mod spawn_agents_on_csv {
         }
 
         let db = required_state_db(&session)?;
-        let input_path = turn.resolve_path(Some(args.csv_path));
+        let input_path = turn.resolve_tool_path(Some(args.csv_path))?;
         let input_path_display = input_path.display().to_string();
         let csv_content = tokio::fs::read_to_string(&input_path)
             .await
@@ -304,10 +304,10 @@ mod spawn_agents_on_csv {
         }
 
         let job_id = Uuid::new_v4().to_string();
-        let output_csv_path = args.output_csv_path.map_or_else(
-            || default_output_csv_path(input_path.as_path(), job_id.as_str()),
-            |path| turn.resolve_path(Some(path)),
-        );
+        let output_csv_path = match args.output_csv_path {
+            Some(path) => turn.resolve_tool_path(Some(path))?,
+            None => default_output_csv_path(input_path.as_path(), job_id.as_str()),
+        };
         let job_suffix = &job_id[..8];
         let job_name = format!("agent-job-{job_suffix}");
         let max_runtime_seconds = normalize_max_runtime_seconds(
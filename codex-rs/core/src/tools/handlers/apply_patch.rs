@@ -152,6 +152,7 @@ impl ToolHandler for ApplyPatchHandler {
                             write_permissions_for_paths(&file_paths),
                         )
                         .await;
+                        session.services.metrics.record_patch_call();
                         let emitter =
                             ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
                         let event_ctx = ToolEventCtx::new(
@@ -261,6 +262,7 @@ pub(crate) async fn intercept_apply_patch(
                         write_permissions_for_paths(&approval_keys),
                     )
                     .await;
+                    session.services.metrics.record_patch_call();
                     let emitter = ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
                     let event_ctx = ToolEventCtx::new(
                         session.as_ref(),
@@ -9,6 +9,7 @@ use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolPayload;
 use crate::tools::registry::ToolHandler;
 use crate::tools::registry::ToolKind;
+use crate::tools::runtimes::dry_run_output;
 
 pub struct CodeModeHandler;
 
@@ -48,6 +49,14 @@ impl ToolHandler for CodeModeHandler {
             }
         };
 
+        if turn.config.dry_run {
+            let output = dry_run_output(format!("{PUBLIC_TOOL_NAME} script not executed: {code}"));
+            return Ok(FunctionToolOutput::from_text(
+                output.aggregated_output.text,
+                Some(true),
+            ));
+        }
+
         code_mode::execute(session, turn, tracker, code).await
     }
 }
@@ -21,6 +21,7 @@ use crate::tools::js_repl::JS_REPL_PRAGMA_PREFIX;
 use crate::tools::js_repl::JsReplArgs;
 use crate::tools::registry::ToolHandler;
 use crate::tools::registry::ToolKind;
+use crate::tools::runtimes::dry_run_output;
 use codex_protocol::models::FunctionCallOutputContentItem;
 
 pub struct JsReplHandler;
@@ -132,6 +133,13 @@ impl ToolHandler for JsReplHandler {
                 ));
             }
         };
+        if turn.config.dry_run {
+            let output = dry_run_output(format!("js_repl code not executed: {}", args.code));
+            return Ok(FunctionToolOutput::from_text(
+                output.aggregated_output.text,
+                Some(true),
+            ));
+        }
         let manager = turn.js_repl.manager().await?;
         let started_at = Instant::now();
         emit_js_repl_exec_begin(session.as_ref(), turn.as_ref(), &call_id).await;
@@ -27,6 +27,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use crate::codex::Session;
+use crate::error::CodexErr;
 use crate::function_tool::FunctionCallError;
 use crate::sandboxing::SandboxPermissions;
 use crate::sandboxing::merge_permission_profiles;
@@ -63,9 +64,38 @@ fn parse_arguments<T>(arguments: &str) -> Result<T, FunctionCallError>
 where
     T: for<'de> Deserialize<'de>,
 {
-    serde_json::from_str(arguments).map_err(|err| {
-        FunctionCallError::RespondToModel(format!("failed to parse function arguments: {err}"))
-    })
+    let mut deserializer = serde_json::Deserializer::from_str(arguments);
+    let parsed = serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(|err| CodexErr::from_json_arguments_error(&err.path().to_string(), err.inner()))
+        .and_then(|value| {
+            deserializer
+                .end()
+                .map_err(|err| CodexErr::from_json_arguments_error(".", &err))?;
+            Ok(value)
+        });
+    parsed.map_err(arguments_error_response)
+}
+
+/// Reports malformed tool arguments to the model as a JSON object naming the
+/// offending field, so the next sample can correct it.
+fn arguments_error_response(err: CodexErr) -> FunctionCallError {
+    let CodexErr::ParseError {
+        field,
+        expected,
+        got,
+    } = &err
+    else {
+        return FunctionCallError::RespondToModel(err.to_string());
+    };
+    FunctionCallError::RespondToModel(
+        serde_json::json!({
+            "error": "failed to parse function arguments",
+            "field": field,
+            "expected": expected,
+            "got": got,
+        })
+        .to_string(),
+    )
 }
 
 fn parse_arguments_with_base_path<T>(
@@ -1613,6 +1613,8 @@ mod tests {
                 },
             ],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         };
         let captured = manager
             .captured_ops()
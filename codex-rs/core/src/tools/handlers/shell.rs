@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use codex_protocol::ThreadId;
 use codex_protocol::models::ShellCommandToolCallParams;
 use codex_protocol::models::ShellToolCallParams;
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::codex::TurnContext;
@@ -56,6 +57,7 @@ struct RunExecLikeArgs {
     tracker: crate::tools::context::SharedTurnDiffTracker,
     call_id: String,
     freeform: bool,
+    source: ExecCommandSource,
     shell_runtime_backend: ShellRuntimeBackend,
 }
 
@@ -64,10 +66,10 @@ impl ShellHandler {
         params: &ShellToolCallParams,
         turn_context: &TurnContext,
         thread_id: ThreadId,
-    ) -> ExecParams {
-        ExecParams {
+    ) -> Result<ExecParams, FunctionCallError> {
+        Ok(ExecParams {
             command: params.command.clone(),
-            cwd: turn_context.resolve_path(params.workdir.clone()),
+            cwd: turn_context.resolve_tool_path(params.workdir.clone())?,
             expiration: params.timeout_ms.into(),
             env: create_env(&turn_context.shell_environment_policy, Some(thread_id)),
             network: turn_context.network.clone(),
@@ -75,7 +77,8 @@ impl ShellHandler {
             windows_sandbox_level: turn_context.windows_sandbox_level,
             justification: params.justification.clone(),
             arg0: None,
-        }
+            stdin: params.stdin.clone(),
+        })
     }
 }
 
@@ -117,7 +120,7 @@ impl ShellCommandHandler {
 
         Ok(ExecParams {
             command,
-            cwd: turn_context.resolve_path(params.workdir.clone()),
+            cwd: turn_context.resolve_tool_path(params.workdir.clone())?,
             expiration: params.timeout_ms.into(),
             env: create_env(&turn_context.shell_environment_policy, Some(thread_id)),
             network: turn_context.network.clone(),
@@ -125,6 +128,7 @@ impl ShellCommandHandler {
             windows_sandbox_level: turn_context.windows_sandbox_level,
             justification: params.justification.clone(),
             arg0: None,
+            stdin: None,
         })
     }
 }
@@ -184,7 +188,7 @@ impl ToolHandler for ShellHandler {
                     parse_arguments_with_base_path(&arguments, cwd.as_path())?;
                 let prefix_rule = params.prefix_rule.clone();
                 let exec_params =
-                    Self::to_exec_params(&params, turn.as_ref(), session.conversation_id);
+                    Self::to_exec_params(&params, turn.as_ref(), session.conversation_id)?;
                 Self::run_exec_like(RunExecLikeArgs {
                     tool_name: tool_name.clone(),
                     exec_params,
@@ -195,13 +199,15 @@ impl ToolHandler for ShellHandler {
                     tracker,
                     call_id,
                     freeform: false,
+                    source: ExecCommandSource::Agent,
                     shell_runtime_backend: ShellRuntimeBackend::Generic,
                 })
                 .await
             }
             ToolPayload::LocalShell { params } => {
                 let exec_params =
-                    Self::to_exec_params(&params, turn.as_ref(), session.conversation_id);
+                    Self::to_exec_params(&params, turn.as_ref(), session.conversation_id)?;
+                validate_local_shell_workdir(&exec_params.cwd, turn.as_ref())?;
                 Self::run_exec_like(RunExecLikeArgs {
                     tool_name: tool_name.clone(),
                     exec_params,
@@ -212,6 +218,7 @@ impl ToolHandler for ShellHandler {
                     tracker,
                     call_id,
                     freeform: false,
+                    source: ExecCommandSource::Agent,
                     shell_runtime_backend: ShellRuntimeBackend::Generic,
                 })
                 .await
@@ -223,6 +230,29 @@ impl ToolHandler for ShellHandler {
     }
 }
 
+/// Rejects a `local_shell` working directory that does not exist, or that lies
+/// outside the turn cwd and the sandbox's writable roots, so the model can
+/// retry with a valid directory instead of failing at spawn time.
+fn validate_local_shell_workdir(cwd: &Path, turn: &TurnContext) -> Result<(), FunctionCallError> {
+    if !cwd.is_dir() {
+        return Err(FunctionCallError::RespondToModel(format!(
+            "working_directory `{}` does not exist or is not a directory; retry with an existing directory or omit working_directory to run in `{}`",
+            cwd.display(),
+            turn.cwd.display()
+        )));
+    }
+
+    if turn.sandbox_policy.get().has_full_disk_write_access() || turn.is_within_roots(cwd) {
+        return Ok(());
+    }
+
+    Err(FunctionCallError::RespondToModel(format!(
+        "working_directory `{}` is outside the allowed roots; retry with a directory under `{}`",
+        cwd.display(),
+        turn.cwd.display()
+    )))
+}
+
 #[async_trait]
 impl ToolHandler for ShellCommandHandler {
     type Output = FunctionToolOutput;
@@ -301,6 +331,7 @@ impl ToolHandler for ShellCommandHandler {
             tracker,
             call_id,
             freeform: true,
+            source: ExecCommandSource::Agent,
             shell_runtime_backend: self.shell_runtime_backend(),
         })
         .await
@@ -308,6 +339,32 @@ impl ToolHandler for ShellCommandHandler {
 }
 
 impl ShellHandler {
+    /// Runs a command requested outside of a model turn (`Op::RunCommand`)
+    /// through the same approval, sandbox, and event plumbing as the `shell`
+    /// tool. The output is reported only through exec events.
+    pub(crate) async fn run_command(
+        session: Arc<crate::codex::Session>,
+        turn: Arc<TurnContext>,
+        call_id: String,
+        exec_params: ExecParams,
+        additional_permissions: Option<PermissionProfile>,
+    ) -> Result<FunctionToolOutput, FunctionCallError> {
+        Self::run_exec_like(RunExecLikeArgs {
+            tool_name: "shell".to_string(),
+            exec_params,
+            additional_permissions,
+            prefix_rule: None,
+            session,
+            turn,
+            tracker: Default::default(),
+            call_id,
+            freeform: false,
+            source: ExecCommandSource::UserShell,
+            shell_runtime_backend: ShellRuntimeBackend::Generic,
+        })
+        .await
+    }
+
     async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, FunctionCallError> {
         let RunExecLikeArgs {
             tool_name,
@@ -319,6 +376,7 @@ impl ShellHandler {
             tracker,
             call_id,
             freeform,
+            source,
             shell_runtime_backend,
         } = args;
 
@@ -327,6 +385,7 @@ impl ShellHandler {
         if !dependency_env.is_empty() {
             exec_params.env.extend(dependency_env.clone());
         }
+        let extra_env_keys = session.apply_extra_env(&mut exec_params.env).await;
 
         let mut explicit_env_overrides = turn.shell_environment_policy.r#set.clone();
         for key in dependency_env.keys() {
@@ -334,6 +393,13 @@ impl ShellHandler {
                 explicit_env_overrides.insert(key.clone(), value.clone());
             }
         }
+        // Unset variables are kept as overrides too: the snapshot wrapper
+        // unsets any override missing from env, so the snapshot cannot
+        // reintroduce them.
+        for key in extra_env_keys {
+            let value = exec_params.env.get(&key).cloned().unwrap_or_default();
+            explicit_env_overrides.insert(key, value);
+        }
 
         let request_permission_enabled = session.features().enabled(Feature::RequestPermissions);
         let effective_additional_permissions = apply_granted_turn_permissions(
@@ -386,7 +452,7 @@ impl ShellHandler {
             return Ok(output);
         }
 
-        let source = ExecCommandSource::Agent;
+        session.services.metrics.record_exec_call();
         let emitter = ToolEmitter::shell(
             exec_params.command.clone(),
             exec_params.cwd.clone(),
@@ -422,6 +488,7 @@ impl ShellHandler {
             sandbox_permissions: effective_additional_permissions.sandbox_permissions,
             additional_permissions: normalized_additional_permissions,
             justification: exec_params.justification.clone(),
+            stdin: exec_params.stdin.clone(),
             exec_approval_requirement,
         };
         let mut orchestrator = ToolOrchestrator::new();
@@ -18,6 +18,7 @@ use crate::tools::handlers::parse_arguments_with_base_path;
 use crate::tools::handlers::resolve_workdir_base_path;
 use crate::tools::registry::ToolHandler;
 use crate::tools::registry::ToolKind;
+use crate::tools::runtimes::dry_run_output;
 use crate::unified_exec::ExecCommandRequest;
 use crate::unified_exec::UnifiedExecContext;
 use crate::unified_exec::UnifiedExecProcessManager;
@@ -198,7 +199,16 @@ impl ToolHandler for UnifiedExecHandler {
 
                 let workdir = workdir.filter(|value| !value.is_empty());
 
-                let workdir = workdir.map(|dir| context.turn.resolve_path(Some(dir)));
+                let workdir = match workdir
+                    .map(|dir| context.turn.resolve_tool_path(Some(dir)))
+                    .transpose()
+                {
+                    Ok(workdir) => workdir,
+                    Err(err) => {
+                        manager.release_process_id(process_id).await;
+                        return Err(err);
+                    }
+                };
                 let cwd = workdir.clone().unwrap_or(cwd);
                 let normalized_additional_permissions =
                     match normalize_and_validate_additional_permissions(
@@ -242,6 +252,23 @@ impl ToolHandler for UnifiedExecHandler {
                     });
                 }
 
+                if context.turn.config.dry_run {
+                    manager.release_process_id(process_id).await;
+                    let output =
+                        dry_run_output(format!("command not executed: {}", command.join(" ")));
+                    return Ok(ExecCommandToolOutput {
+                        event_call_id: String::new(),
+                        chunk_id: String::new(),
+                        wall_time: std::time::Duration::ZERO,
+                        raw_output: output.aggregated_output.text.into_bytes(),
+                        max_output_tokens: None,
+                        process_id: None,
+                        exit_code: Some(output.exit_code),
+                        original_token_count: None,
+                        session_command: None,
+                    });
+                }
+
                 manager
                     .exec_command(
                         ExecCommandRequest {
@@ -68,7 +68,7 @@ impl ToolHandler for ViewImageHandler {
 
         let args: ViewImageArgs = parse_arguments(&arguments)?;
 
-        let abs_path = turn.resolve_path(Some(args.path));
+        let abs_path = turn.resolve_tool_path(Some(args.path))?;
 
         let metadata = fs::metadata(&abs_path).await.map_err(|error| {
             FunctionCallError::RespondToModel(format!(
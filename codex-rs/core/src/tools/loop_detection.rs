@@ -0,0 +1,143 @@
+//! Detects a model calling the same tool with the same arguments over and over
+//! within a single turn.
+
+use codex_protocol::models::ResponseItem;
+
+/// Tools that are expected to be called repeatedly with the same arguments,
+/// such as polling a running process for more output. They never count
+/// towards a loop.
+const POLLING_TOOLS: &[&str] = &["write_stdin"];
+
+/// Tracks the latest tool call of a turn and how many times in a row it has
+/// been repeated verbatim. Non-tool items (messages, reasoning) in between do
+/// not break a streak.
+#[derive(Debug)]
+pub(crate) struct ToolCallLoopDetector {
+    /// Identical consecutive calls that count as a loop; `0` disables detection.
+    threshold: usize,
+    last_call: Option<(String, String)>,
+    repeats: usize,
+    detected: Option<String>,
+}
+
+impl ToolCallLoopDetector {
+    pub(crate) fn new(threshold: usize) -> Self {
+        Self {
+            threshold,
+            last_call: None,
+            repeats: 0,
+            detected: None,
+        }
+    }
+
+    pub(crate) fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Records an output item from the model, noting a loop once the same
+    /// tool call has been made `threshold` times in a row.
+    pub(crate) fn record(&mut self, item: &ResponseItem) {
+        if self.threshold == 0 {
+            return;
+        }
+        let Some(call) = tool_call_signature(item) else {
+            return;
+        };
+        if POLLING_TOOLS.contains(&call.0.as_str()) {
+            self.last_call = None;
+            self.repeats = 0;
+            return;
+        }
+        if self.last_call.as_ref() == Some(&call) {
+            self.repeats += 1;
+        } else {
+            self.repeats = 1;
+            self.last_call = Some(call);
+        }
+        if self.repeats >= self.threshold
+            && let Some((tool_name, _)) = &self.last_call
+        {
+            self.detected = Some(tool_name.clone());
+        }
+    }
+
+    /// Returns the name of the repeated tool if a loop was detected since the
+    /// last call, and starts counting afresh.
+    pub(crate) fn take_detected(&mut self) -> Option<String> {
+        let detected = self.detected.take()?;
+        self.last_call = None;
+        self.repeats = 0;
+        Some(detected)
+    }
+}
+
+fn tool_call_signature(item: &ResponseItem) -> Option<(String, String)> {
+    match item {
+        ResponseItem::FunctionCall {
+            name, arguments, ..
+        } => Some((name.clone(), arguments.clone())),
+        ResponseItem::CustomToolCall { name, input, .. } => Some((name.clone(), input.clone())),
+        ResponseItem::LocalShellCall { action, .. } => {
+            Some(("local_shell".to_string(), format!("{action:?}")))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn function_call(name: &str, arguments: &str, call_id: &str) -> ResponseItem {
+        ResponseItem::FunctionCall {
+            id: None,
+            name: name.to_string(),
+            arguments: arguments.to_string(),
+            call_id: call_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn detects_identical_consecutive_calls() {
+        let mut detector = ToolCallLoopDetector::new(3);
+        detector.record(&function_call("shell", r#"{"command":["ls"]}"#, "call-1"));
+        detector.record(&function_call("shell", r#"{"command":["ls"]}"#, "call-2"));
+        assert_eq!(detector.take_detected(), None);
+
+        detector.record(&function_call("shell", r#"{"command":["ls"]}"#, "call-3"));
+        assert_eq!(detector.take_detected(), Some("shell".to_string()));
+        assert_eq!(detector.take_detected(), None);
+    }
+
+    #[test]
+    fn different_arguments_reset_the_streak() {
+        let mut detector = ToolCallLoopDetector::new(2);
+        detector.record(&function_call("shell", r#"{"command":["ls"]}"#, "call-1"));
+        detector.record(&function_call("shell", r#"{"command":["pwd"]}"#, "call-2"));
+        detector.record(&function_call("shell", r#"{"command":["ls"]}"#, "call-3"));
+        assert_eq!(detector.take_detected(), None);
+    }
+
+    #[test]
+    fn polling_write_stdin_is_not_a_loop() {
+        let mut detector = ToolCallLoopDetector::new(2);
+        for index in 0..10 {
+            detector.record(&function_call(
+                "write_stdin",
+                r#"{"session_id":1,"chars":""}"#,
+                &format!("call-{index}"),
+            ));
+        }
+        assert_eq!(detector.take_detected(), None);
+    }
+
+    #[test]
+    fn zero_threshold_disables_detection() {
+        let mut detector = ToolCallLoopDetector::new(0);
+        for index in 0..10 {
+            detector.record(&function_call("shell", "{}", &format!("call-{index}")));
+        }
+        assert_eq!(detector.take_detected(), None);
+    }
+}
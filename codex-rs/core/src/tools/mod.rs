@@ -1,15 +1,20 @@
+pub(crate) mod approved_commands;
+pub(crate) mod arguments_preview;
 pub mod code_mode;
 pub(crate) mod code_mode_description;
+pub(crate) mod command_display;
 pub mod context;
 pub mod events;
 pub(crate) mod handlers;
 pub mod js_repl;
+pub(crate) mod loop_detection;
 pub(crate) mod network_approval;
 pub mod orchestrator;
 pub mod parallel;
 pub mod registry;
 pub mod router;
 pub mod runtimes;
+pub(crate) mod sandbox_audit;
 pub mod sandboxing;
 pub mod spec;
 
@@ -363,6 +363,7 @@ impl NetworkApprovalService {
                     None,
                     prompt_command,
                     turn_context.cwd.clone(),
+                    None,
                     Some(prompt_reason),
                     Some(network_approval_context.clone()),
                     None,
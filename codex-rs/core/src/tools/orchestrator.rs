@@ -9,16 +9,19 @@ caching).
 use crate::error::CodexErr;
 use crate::error::SandboxErr;
 use crate::exec::ExecToolCallOutput;
+use crate::exec::is_acceptable_program_exit_code;
 use crate::features::Feature;
 use crate::guardian::GUARDIAN_REJECTION_MESSAGE;
 use crate::guardian::routes_approval_to_guardian;
 use crate::network_policy_decision::network_approval_context_from_payload;
 use crate::sandboxing::SandboxManager;
+use crate::sandboxing::selection::CommandSandbox;
 use crate::tools::network_approval::DeferredNetworkApproval;
 use crate::tools::network_approval::NetworkApprovalMode;
 use crate::tools::network_approval::begin_network_approval;
 use crate::tools::network_approval::finish_deferred_network_approval;
 use crate::tools::network_approval::finish_immediate_network_approval;
+use crate::tools::sandbox_audit::record_sandbox_bypass;
 use crate::tools::sandboxing::ApprovalCtx;
 use crate::tools::sandboxing::ExecApprovalRequirement;
 use crate::tools::sandboxing::SandboxAttempt;
@@ -31,6 +34,7 @@ use codex_otel::ToolDecisionSource;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::NetworkPolicyRuleAction;
 use codex_protocol::protocol::ReviewDecision;
+use std::path::Path;
 
 pub(crate) struct ToolOrchestrator {
     sandbox: SandboxManager,
@@ -99,6 +103,25 @@ impl ToolOrchestrator {
         }
     }
 
+    /// Records an un-sandboxed exec of `command` in the configured audit
+    /// log, if any. Tools that do not run commands pass `None`.
+    async fn audit_sandbox_bypass(
+        tool_ctx: &ToolCtx,
+        turn_ctx: &crate::codex::TurnContext,
+        command: Option<&[String]>,
+        cwd: Option<&Path>,
+        decision: Option<&ReviewDecision>,
+    ) {
+        let Some(log_path) = turn_ctx.config.sandbox_bypass_audit_log.as_deref() else {
+            return;
+        };
+        let Some(command) = command else {
+            return;
+        };
+        let cwd = cwd.unwrap_or(turn_ctx.cwd.as_path());
+        record_sandbox_bypass(log_path, &tool_ctx.call_id, command, cwd, decision).await;
+    }
+
     pub async fn run<Rq, Out, T>(
         &mut self,
         tool: &mut T,
@@ -118,6 +141,7 @@ impl ToolOrchestrator {
 
         // 1) Approval
         let mut already_approved = false;
+        let mut approval_decision = None;
 
         let requirement = tool.exec_approval_requirement(req).unwrap_or_else(|| {
             default_exec_approval_requirement(approval_policy, &turn_ctx.sandbox_policy)
@@ -140,6 +164,7 @@ impl ToolOrchestrator {
                 let decision = tool.start_approval_async(req, approval_ctx).await;
 
                 otel.tool_decision(otel_tn, otel_ci, &decision, otel_user.clone());
+                approval_decision = Some(decision.clone());
 
                 match decision {
                     ReviewDecision::Denied | ReviewDecision::Abort => {
@@ -167,18 +192,24 @@ impl ToolOrchestrator {
         }
 
         // 2) First attempt under the selected sandbox.
-        let has_managed_network_requirements = turn_ctx
-            .config
-            .config_layer_stack
-            .requirements_toml()
-            .network
-            .is_some();
+        let has_managed_network_requirements = turn_ctx.config.managed_network_enforced();
+        let command_sandbox = CommandSandbox::new(
+            tool.sandbox_preference(),
+            &turn_ctx.sandbox_policy,
+            &turn_ctx.file_system_sandbox_policy,
+            turn_ctx.network_sandbox_policy,
+        )
+        .for_command(
+            turn_ctx.config.sandbox_selection.as_ref(),
+            tool.exec_command(req),
+            &turn_ctx.cwd,
+        );
         let initial_sandbox = match tool.sandbox_mode_for_first_attempt(req) {
             SandboxOverride::BypassSandboxFirstAttempt => crate::exec::SandboxType::None,
             SandboxOverride::NoOverride => self.sandbox.select_initial(
-                &turn_ctx.file_system_sandbox_policy,
-                turn_ctx.network_sandbox_policy,
-                tool.sandbox_preference(),
+                &command_sandbox.file_system_policy,
+                command_sandbox.network_policy,
+                command_sandbox.preference,
                 turn_ctx.windows_sandbox_level,
                 has_managed_network_requirements,
             ),
@@ -189,9 +220,9 @@ impl ToolOrchestrator {
         let use_linux_sandbox_bwrap = turn_ctx.features.enabled(Feature::UseLinuxSandboxBwrap);
         let initial_attempt = SandboxAttempt {
             sandbox: initial_sandbox,
-            policy: &turn_ctx.sandbox_policy,
-            file_system_policy: &turn_ctx.file_system_sandbox_policy,
-            network_policy: turn_ctx.network_sandbox_policy,
+            policy: &command_sandbox.sandbox_policy,
+            file_system_policy: &command_sandbox.file_system_policy,
+            network_policy: command_sandbox.network_policy,
             enforce_managed_network: has_managed_network_requirements,
             manager: &self.sandbox,
             sandbox_cwd: &turn_ctx.cwd,
@@ -200,6 +231,16 @@ impl ToolOrchestrator {
             windows_sandbox_level: turn_ctx.windows_sandbox_level,
         };
 
+        if initial_sandbox == crate::exec::SandboxType::None {
+            Self::audit_sandbox_bypass(
+                tool_ctx,
+                turn_ctx,
+                tool.exec_command(req),
+                tool.exec_cwd(req),
+                approval_decision.as_ref(),
+            )
+            .await;
+        }
         let (first_result, first_deferred_network_approval) = Self::run_attempt(
             tool,
             req,
@@ -233,7 +274,18 @@ impl ToolOrchestrator {
                         network_policy_decision,
                     })));
                 }
-                if !tool.escalate_on_failure() {
+                // Exit codes configured as success for this program are not
+                // worth an escalation prompt; `ToolEmitter::finish` reports
+                // them as successful. The `"*"` entry does not apply here so
+                // that it cannot mask a sandbox denial.
+                let exit_code_is_acceptable = tool.exec_command(req).is_some_and(|command| {
+                    is_acceptable_program_exit_code(
+                        &turn_ctx.config.acceptable_exit_codes,
+                        command,
+                        output.exit_code,
+                    )
+                });
+                if !tool.escalate_on_failure() || exit_code_is_acceptable {
                     return Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Denied {
                         output,
                         network_policy_decision,
@@ -285,6 +337,7 @@ impl ToolOrchestrator {
 
                     let decision = tool.start_approval_async(req, approval_ctx).await;
                     otel.tool_decision(otel_tn, otel_ci, &decision, otel_user);
+                    approval_decision = Some(decision.clone());
 
                     match decision {
                         ReviewDecision::Denied | ReviewDecision::Abort => {
@@ -323,6 +376,14 @@ impl ToolOrchestrator {
                 };
 
                 // Second attempt.
+                Self::audit_sandbox_bypass(
+                    tool_ctx,
+                    turn_ctx,
+                    tool.exec_command(req),
+                    tool.exec_cwd(req),
+                    approval_decision.as_ref(),
+                )
+                .await;
                 let (retry_result, retry_deferred_network_approval) = Self::run_attempt(
                     tool,
                     req,
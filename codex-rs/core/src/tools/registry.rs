@@ -315,6 +315,9 @@ impl ConfiguredToolSpec {
 pub struct ToolRegistryBuilder {
     handlers: HashMap<String, Arc<dyn AnyToolHandler>>,
     specs: Vec<ConfiguredToolSpec>,
+    /// MCP and dynamic tools that were left out because an earlier tool
+    /// already uses the same name.
+    shadowed_tools: Vec<String>,
 }
 
 impl ToolRegistryBuilder {
@@ -322,9 +325,28 @@ impl ToolRegistryBuilder {
         Self {
             handlers: HashMap::new(),
             specs: Vec::new(),
+            shadowed_tools: Vec::new(),
         }
     }
 
+    pub fn has_handler(&self, name: &str) -> bool {
+        self.handlers.contains_key(name)
+    }
+
+    /// Records that the tool `name` was not registered because an earlier
+    /// tool already claimed the name. The session logs and warns about it
+    /// once, since the registry is rebuilt for every turn.
+    pub fn record_shadowed_tool(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        if !self.shadowed_tools.contains(&name) {
+            self.shadowed_tools.push(name);
+        }
+    }
+
+    pub fn shadowed_tools(&self) -> &[String] {
+        &self.shadowed_tools
+    }
+
     pub fn push_spec(&mut self, spec: ToolSpec) {
         self.push_spec_with_parallel_support(spec, false);
     }
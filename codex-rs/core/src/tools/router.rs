@@ -35,6 +35,7 @@ pub struct ToolCall {
 pub struct ToolRouter {
     registry: ToolRegistry,
     specs: Vec<ConfiguredToolSpec>,
+    shadowed_tools: Vec<String>,
 }
 
 impl ToolRouter {
@@ -45,9 +46,20 @@ impl ToolRouter {
         dynamic_tools: &[DynamicToolSpec],
     ) -> Self {
         let builder = build_specs(config, mcp_tools, app_tools, dynamic_tools);
+        let shadowed_tools = builder.shadowed_tools().to_vec();
         let (specs, registry) = builder.build();
 
-        Self { registry, specs }
+        Self {
+            registry,
+            specs,
+            shadowed_tools,
+        }
+    }
+
+    /// MCP and dynamic tools that were dropped because a built-in tool (or an
+    /// earlier tool) already uses their name.
+    pub fn shadowed_tools(&self) -> &[String] {
+        &self.shadowed_tools
     }
 
     pub fn specs(&self) -> Vec<ToolSpec> {
@@ -124,6 +136,7 @@ impl ToolRouter {
                             additional_permissions: None,
                             prefix_rule: None,
                             justification: None,
+                            stdin: None,
                         };
                         Ok(Some(ToolCall {
                             tool_name: "local_shell".to_string(),
@@ -11,6 +11,7 @@ use crate::guardian::routes_approval_to_guardian;
 use crate::sandboxing::CommandSpec;
 use crate::sandboxing::SandboxPermissions;
 use crate::sandboxing::execute_env;
+use crate::tools::runtimes::dry_run_output;
 use crate::tools::sandboxing::Approvable;
 use crate::tools::sandboxing::ApprovalCtx;
 use crate::tools::sandboxing::ExecApprovalRequirement;
@@ -101,7 +102,7 @@ impl ApplyPatchRuntime {
         Some(crate::exec::StdoutStream {
             sub_id: ctx.turn.sub_id.clone(),
             call_id: ctx.call_id.clone(),
-            tx_event: ctx.session.get_tx_event(),
+            tx_event: ctx.session.get_tx_exec_output_event(),
         })
     }
 }
@@ -192,6 +193,12 @@ impl ToolRuntime<ApplyPatchRequest, ExecToolCallOutput> for ApplyPatchRuntime {
         attempt: &SandboxAttempt<'_>,
         ctx: &ToolCtx,
     ) -> Result<ExecToolCallOutput, ToolError> {
+        if ctx.turn.config.dry_run {
+            return Ok(dry_run_output(format!(
+                "patch not applied to {} file(s)",
+                req.changes.len()
+            )));
+        }
         let spec = Self::build_command_spec(req, &ctx.turn.config.codex_home)?;
         let env = attempt
             .env_for(spec, None)
@@ -5,6 +5,8 @@ Concrete ToolRuntime implementations for specific tools. Each runtime stays
 small and focused and reuses the orchestrator for approvals + sandbox + retry.
 */
 use crate::exec::ExecExpiration;
+use crate::exec::ExecToolCallOutput;
+use crate::exec::StreamOutput;
 use crate::path_utils;
 use crate::sandboxing::CommandSpec;
 use crate::sandboxing::SandboxPermissions;
@@ -53,6 +55,18 @@ pub(crate) fn build_command_spec(
     })
 }
 
+/// Successful, empty output reported in place of running a command when the
+/// `dry_run` config flag is set. The summary is logged and becomes the
+/// aggregated output, so the recorded tool output is marked as a dry run.
+pub(crate) fn dry_run_output(summary: String) -> ExecToolCallOutput {
+    let summary = format!("[dry-run] {summary}");
+    tracing::info!("{summary}");
+    ExecToolCallOutput {
+        aggregated_output: StreamOutput::new(summary),
+        ..Default::default()
+    }
+}
+
 /// POSIX-only helper: for commands produced by `Shell::derive_exec_args`
 /// for Bash/Zsh/sh of the form `[shell_path, "-lc", "<script>"]`, and
 /// when a snapshot is configured on the session shell, rewrite the argv
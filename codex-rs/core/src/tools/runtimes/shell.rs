@@ -18,9 +18,11 @@ use crate::powershell::prefix_powershell_script_with_utf8;
 use crate::sandboxing::SandboxPermissions;
 use crate::sandboxing::execute_env;
 use crate::shell::ShellType;
+use crate::state::DeniedCommand;
 use crate::tools::network_approval::NetworkApprovalMode;
 use crate::tools::network_approval::NetworkApprovalSpec;
 use crate::tools::runtimes::build_command_spec;
+use crate::tools::runtimes::dry_run_output;
 use crate::tools::runtimes::maybe_wrap_shell_lc_with_snapshot;
 use crate::tools::sandboxing::Approvable;
 use crate::tools::sandboxing::ApprovalCtx;
@@ -38,7 +40,10 @@ use codex_network_proxy::NetworkProxy;
 use codex_protocol::models::PermissionProfile;
 use codex_protocol::protocol::ReviewDecision;
 use futures::future::BoxFuture;
+use sha2::Digest;
+use sha2::Sha256;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug)]
@@ -52,6 +57,7 @@ pub struct ShellRequest {
     pub sandbox_permissions: SandboxPermissions,
     pub additional_permissions: Option<PermissionProfile>,
     pub justification: Option<String>,
+    pub stdin: Option<String>,
     pub exec_approval_requirement: ExecApprovalRequirement,
 }
 
@@ -93,6 +99,10 @@ pub(crate) struct ApprovalKey {
     cwd: PathBuf,
     sandbox_permissions: SandboxPermissions,
     additional_permissions: Option<PermissionProfile>,
+    /// SHA-256 of the input piped to the command, so approving a command
+    /// does not approve it with different input. Only the digest is kept
+    /// because keys may be persisted to disk.
+    stdin_sha256: Option<String>,
 }
 
 impl ShellRuntime {
@@ -110,7 +120,7 @@ impl ShellRuntime {
         Some(crate::exec::StdoutStream {
             sub_id: ctx.turn.sub_id.clone(),
             call_id: ctx.call_id.clone(),
-            tx_event: ctx.session.get_tx_event(),
+            tx_event: ctx.session.get_tx_exec_output_event(),
         })
     }
 }
@@ -133,9 +143,21 @@ impl Approvable<ShellRequest> for ShellRuntime {
             cwd: req.cwd.clone(),
             sandbox_permissions: req.sandbox_permissions,
             additional_permissions: req.additional_permissions.clone(),
+            stdin_sha256: req
+                .stdin
+                .as_deref()
+                .map(|stdin| format!("{:x}", Sha256::digest(stdin.as_bytes()))),
         }]
     }
 
+    fn exec_command<'a>(&self, req: &'a ShellRequest) -> Option<&'a [String]> {
+        Some(&req.command)
+    }
+
+    fn exec_cwd<'a>(&self, req: &'a ShellRequest) -> Option<&'a Path> {
+        Some(&req.cwd)
+    }
+
     fn start_approval_async<'a>(
         &'a mut self,
         req: &'a ShellRequest,
@@ -160,32 +182,47 @@ impl Approvable<ShellRequest> for ShellRuntime {
                         sandbox_permissions: req.sandbox_permissions,
                         additional_permissions: req.additional_permissions.clone(),
                         justification: req.justification.clone(),
+                        stdin: req.stdin.clone(),
                     },
                     retry_reason,
                 )
                 .await;
             }
-            with_cached_approval(&session.services, "shell", keys, move || async move {
-                let available_decisions = None;
+            let decision =
+                with_cached_approval(&session.services, "shell", keys, move || async move {
+                    let available_decisions = None;
+                    session
+                        .request_command_approval(
+                            turn,
+                            call_id,
+                            None,
+                            command,
+                            cwd,
+                            req.stdin.clone(),
+                            reason,
+                            ctx.network_approval_context.clone(),
+                            req.exec_approval_requirement
+                                .proposed_execpolicy_amendment()
+                                .cloned(),
+                            req.additional_permissions.clone(),
+                            None,
+                            available_decisions,
+                        )
+                        .await
+                })
+                .await;
+            if decision == ReviewDecision::Denied {
                 session
-                    .request_command_approval(
-                        turn,
-                        call_id,
-                        None,
-                        command,
-                        cwd,
-                        reason,
-                        ctx.network_approval_context.clone(),
-                        req.exec_approval_requirement
-                            .proposed_execpolicy_amendment()
-                            .cloned(),
-                        req.additional_permissions.clone(),
-                        None,
-                        available_decisions,
-                    )
-                    .await
-            })
-            .await
+                    .record_denied_command(DeniedCommand {
+                        command: req.command.clone(),
+                        cwd: req.cwd.clone(),
+                        sandbox_permissions: req.sandbox_permissions,
+                        additional_permissions: req.additional_permissions.clone(),
+                        justification: req.justification.clone(),
+                    })
+                    .await;
+            }
+            decision
         })
     }
 
@@ -217,6 +254,12 @@ impl ToolRuntime<ShellRequest, ExecToolCallOutput> for ShellRuntime {
         attempt: &SandboxAttempt<'_>,
         ctx: &ToolCtx,
     ) -> Result<ExecToolCallOutput, ToolError> {
+        if ctx.turn.config.dry_run {
+            return Ok(dry_run_output(format!(
+                "command not executed: {}",
+                req.command.join(" ")
+            )));
+        }
         let session_shell = ctx.session.user_shell();
         let command = maybe_wrap_shell_lc_with_snapshot(
             &req.command,
@@ -252,9 +295,10 @@ impl ToolRuntime<ShellRequest, ExecToolCallOutput> for ShellRuntime {
             req.additional_permissions.clone(),
             req.justification.clone(),
         )?;
-        let env = attempt
+        let mut env = attempt
             .env_for(spec, req.network.as_ref())
             .map_err(|err| ToolError::Codex(err.into()))?;
+        env.stdin = req.stdin.clone();
         let out = execute_env(env, Self::stdout_stream(ctx))
             .await
             .map_err(ToolError::Codex)?;
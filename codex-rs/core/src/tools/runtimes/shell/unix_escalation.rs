@@ -89,6 +89,12 @@ pub(super) async fn try_run_zsh_fork(
         tracing::warn!("ZshFork backend specified, but user shell is not Zsh.");
         return Ok(None);
     }
+    if req.stdin.is_some() {
+        // The escalation server runs the script without a way to feed it
+        // input, so leave commands with stdin to the standard runtime.
+        tracing::debug!("ZshFork backend does not support stdin; falling back.");
+        return Ok(None);
+    }
 
     let spec = build_command_spec(
         command,
@@ -116,6 +122,7 @@ pub(super) async fn try_run_zsh_fork(
         network_sandbox_policy,
         justification,
         arg0,
+        stdin: _,
     } = sandbox_exec_request;
     let ParsedShellCommand { script, login, .. } = extract_shell_script(&command)?;
     let effective_timeout = Duration::from_millis(
@@ -464,6 +471,7 @@ impl CoreShellActionProvider {
                         None,
                         None,
                         None,
+                        None,
                         additional_permissions,
                         skill_metadata,
                         Some(available_decisions),
@@ -886,6 +894,7 @@ impl ShellCommandExecutor for CoreShellCommandExecutor {
                 network_sandbox_policy: self.network_sandbox_policy,
                 justification: self.justification.clone(),
                 arg0: self.arg0.clone(),
+                stdin: None,
             },
             None,
             after_spawn,
@@ -15,6 +15,7 @@ use crate::guardian::routes_approval_to_guardian;
 use crate::powershell::prefix_powershell_script_with_utf8;
 use crate::sandboxing::SandboxPermissions;
 use crate::shell::ShellType;
+use crate::state::DeniedCommand;
 use crate::tools::network_approval::NetworkApprovalMode;
 use crate::tools::network_approval::NetworkApprovalSpec;
 use crate::tools::runtimes::build_command_spec;
@@ -42,6 +43,7 @@ use codex_protocol::models::PermissionProfile;
 use codex_protocol::protocol::ReviewDecision;
 use futures::future::BoxFuture;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug)]
@@ -101,6 +103,14 @@ impl Approvable<UnifiedExecRequest> for UnifiedExecRuntime<'_> {
         }]
     }
 
+    fn exec_command<'b>(&self, req: &'b UnifiedExecRequest) -> Option<&'b [String]> {
+        Some(&req.command)
+    }
+
+    fn exec_cwd<'b>(&self, req: &'b UnifiedExecRequest) -> Option<&'b Path> {
+        Some(&req.cwd)
+    }
+
     fn start_approval_async<'b>(
         &'b mut self,
         req: &'b UnifiedExecRequest,
@@ -131,27 +141,41 @@ impl Approvable<UnifiedExecRequest> for UnifiedExecRuntime<'_> {
                 )
                 .await;
             }
-            with_cached_approval(&session.services, "unified_exec", keys, || async move {
-                let available_decisions = None;
+            let decision =
+                with_cached_approval(&session.services, "unified_exec", keys, || async move {
+                    let available_decisions = None;
+                    session
+                        .request_command_approval(
+                            turn,
+                            call_id,
+                            None,
+                            command,
+                            cwd,
+                            None,
+                            reason,
+                            ctx.network_approval_context.clone(),
+                            req.exec_approval_requirement
+                                .proposed_execpolicy_amendment()
+                                .cloned(),
+                            req.additional_permissions.clone(),
+                            None,
+                            available_decisions,
+                        )
+                        .await
+                })
+                .await;
+            if decision == ReviewDecision::Denied {
                 session
-                    .request_command_approval(
-                        turn,
-                        call_id,
-                        None,
-                        command,
-                        cwd,
-                        reason,
-                        ctx.network_approval_context.clone(),
-                        req.exec_approval_requirement
-                            .proposed_execpolicy_amendment()
-                            .cloned(),
-                        req.additional_permissions.clone(),
-                        None,
-                        available_decisions,
-                    )
-                    .await
-            })
-            .await
+                    .record_denied_command(DeniedCommand {
+                        command: req.command.clone(),
+                        cwd: req.cwd.clone(),
+                        sandbox_permissions: req.sandbox_permissions,
+                        additional_permissions: req.additional_permissions.clone(),
+                        justification: req.justification.clone(),
+                    })
+                    .await;
+            }
+            decision
         })
     }
 
@@ -0,0 +1,78 @@
+//! Append-only audit trail of commands that run without a sandbox.
+//!
+//! When `sandbox_bypass_audit_log` is configured, every exec attempt made
+//! with `SandboxType::None` appends one JSON line to that file before the
+//! command starts.
+
+use std::path::Path;
+
+use codex_protocol::protocol::ReviewDecision;
+use codex_secrets::redact_secrets;
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+#[derive(Debug, Serialize)]
+struct SandboxBypassAuditEntry<'a> {
+    timestamp: String,
+    call_id: &'a str,
+    command: Vec<String>,
+    cwd: &'a Path,
+    decision: &'static str,
+}
+
+/// Appends an entry for an un-sandboxed exec to `log_path`. `decision` is the
+/// approval the user (or guardian) gave for this call, or `None` when policy
+/// allowed it without asking. Failures are logged and otherwise ignored so the
+/// audit sink can never block a command.
+pub(crate) async fn record_sandbox_bypass(
+    log_path: &Path,
+    call_id: &str,
+    command: &[String],
+    cwd: &Path,
+    decision: Option<&ReviewDecision>,
+) {
+    let entry = SandboxBypassAuditEntry {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        call_id,
+        command: command.iter().cloned().map(redact_secrets).collect(),
+        cwd,
+        decision: decision_label(decision),
+    };
+    let mut line = match serde_json::to_string(&entry) {
+        Ok(line) => line,
+        Err(err) => {
+            warn!("failed to serialize sandbox bypass audit entry: {err}");
+            return;
+        }
+    };
+    line.push('\n');
+
+    let file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)
+        .await;
+    let result = match file {
+        Ok(mut file) => file.write_all(line.as_bytes()).await,
+        Err(err) => Err(err),
+    };
+    if let Err(err) = result {
+        warn!(
+            "failed to write sandbox bypass audit log {}: {err}",
+            log_path.display()
+        );
+    }
+}
+
+fn decision_label(decision: Option<&ReviewDecision>) -> &'static str {
+    match decision {
+        None => "not_required",
+        Some(ReviewDecision::Approved) => "approved",
+        Some(ReviewDecision::ApprovedForSession) => "approved_for_session",
+        Some(ReviewDecision::ApprovedExecpolicyAmendment { .. }) => "approved_execpolicy_amendment",
+        Some(ReviewDecision::NetworkPolicyAmendment { .. }) => "network_policy_amendment",
+        Some(ReviewDecision::Denied) => "denied",
+        Some(ReviewDecision::Abort) => "abort",
+    }
+}
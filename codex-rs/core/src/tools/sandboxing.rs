@@ -13,6 +13,7 @@ use crate::sandboxing::SandboxManager;
 use crate::sandboxing::SandboxPermissions;
 use crate::sandboxing::SandboxTransformError;
 use crate::state::SessionServices;
+use crate::tools::approved_commands::ApprovedCommandsStore;
 use crate::tools::network_approval::NetworkApprovalSpec;
 use codex_network_proxy::NetworkProxy;
 use codex_protocol::approvals::ExecPolicyAmendment;
@@ -30,13 +31,33 @@ use std::hash::Hash;
 use std::path::Path;
 use std::sync::Arc;
 
+/// Tools whose session approvals are command approvals, and therefore saved
+/// when `persist_approved_commands` is enabled.
+const COMMAND_APPROVAL_TOOLS: &[&str] = &["shell", "unified_exec"];
+
 #[derive(Clone, Default, Debug)]
 pub(crate) struct ApprovalStore {
     // Store serialized keys for generic caching across requests.
     map: HashMap<String, ReviewDecision>,
+    approved_commands: Option<ApprovedCommandsStore>,
 }
 
 impl ApprovalStore {
+    /// Creates a store seeded with the command approvals saved in
+    /// `approved_commands`, which also receives every new one.
+    pub(crate) async fn with_approved_commands(approved_commands: ApprovedCommandsStore) -> Self {
+        let map = approved_commands
+            .load()
+            .await
+            .into_iter()
+            .map(|key| (key, ReviewDecision::ApprovedForSession))
+            .collect();
+        Self {
+            map,
+            approved_commands: Some(approved_commands),
+        }
+    }
+
     pub fn get<K>(&self, key: &K) -> Option<ReviewDecision>
     where
         K: Serialize,
@@ -53,6 +74,16 @@ impl ApprovalStore {
             self.map.insert(s, value);
         }
     }
+
+    /// Returns every cached decision keyed by its serialized approval key.
+    pub fn entries(&self) -> HashMap<String, ReviewDecision> {
+        self.map.clone()
+    }
+
+    /// Merges previously exported entries back into the store.
+    pub fn extend(&mut self, entries: HashMap<String, ReviewDecision>) {
+        self.map.extend(entries);
+    }
 }
 
 /// Takes a vector of approval keys and returns a ReviewDecision.
@@ -100,9 +131,23 @@ where
     );
 
     if matches!(decision, ReviewDecision::ApprovedForSession) {
-        let mut store = services.tool_approvals.lock().await;
-        for key in keys {
-            store.put(key, ReviewDecision::ApprovedForSession);
+        let approved_commands = {
+            let mut store = services.tool_approvals.lock().await;
+            let serialized_keys = keys
+                .iter()
+                .filter_map(|key| serde_json::to_string(key).ok())
+                .collect::<Vec<_>>();
+            for key in keys {
+                store.put(key, ReviewDecision::ApprovedForSession);
+            }
+            store
+                .approved_commands
+                .clone()
+                .filter(|_| COMMAND_APPROVAL_TOOLS.contains(&tool_name))
+                .map(|approved_commands| (approved_commands, serialized_keys))
+        };
+        if let Some((approved_commands, serialized_keys)) = approved_commands {
+            approved_commands.append(&serialized_keys).await;
         }
     }
 
@@ -257,6 +302,18 @@ pub(crate) trait Approvable<Req> {
         None
     }
 
+    /// The argv `req` runs, if it is a shell command. Used to honor
+    /// `acceptable_exit_codes` before escalating a likely sandbox denial.
+    fn exec_command<'a>(&self, _req: &'a Req) -> Option<&'a [String]> {
+        None
+    }
+
+    /// The directory `req` runs in, if it is a shell command. Recorded in the
+    /// sandbox bypass audit log.
+    fn exec_cwd<'a>(&self, _req: &'a Req) -> Option<&'a Path> {
+        None
+    }
+
     /// Decide we can request an approval for no-sandbox execution.
     fn wants_no_sandbox_approval(&self, policy: AskForApproval) -> bool {
         match policy {
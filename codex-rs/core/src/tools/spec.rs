@@ -588,6 +588,15 @@ fn create_shell_tool(request_permission_enabled: bool) -> ToolSpec {
                 description: Some("The timeout for the command in milliseconds".to_string()),
             },
         ),
+        (
+            "stdin".to_string(),
+            JsonSchema::String {
+                description: Some(
+                    "Optional text to write to the command's stdin; stdin is closed afterwards"
+                        .to_string(),
+                ),
+            },
+        ),
     ]);
     properties.extend(create_approval_parameters(request_permission_enabled));
 
@@ -1979,6 +1988,11 @@ fn sanitize_json_schema(value: &mut JsonValue) {
 }
 
 /// Builds the tool registry builder while collecting tool specs for later serialization.
+///
+/// Built-in tools are registered first, then MCP tools in name order, then
+/// dynamic tools. An MCP or dynamic tool whose name is already taken is left
+/// out and reported through [`ToolRegistryBuilder::shadowed_tools`], so the
+/// earlier tool always handles calls to that name.
 pub(crate) fn build_specs(
     config: &ToolsConfig,
     mcp_tools: Option<HashMap<String, rmcp::model::Tool>>,
@@ -2403,6 +2417,10 @@ pub(crate) fn build_specs(
         entries.sort_by(|a, b| a.0.cmp(&b.0));
 
         for (name, tool) in entries.into_iter() {
+            if builder.has_handler(&name) {
+                builder.record_shadowed_tool(name);
+                continue;
+            }
             match mcp_tool_to_openai_tool(name.clone(), tool.clone()) {
                 Ok(converted_tool) => {
                     push_tool_spec(
@@ -2422,6 +2440,10 @@ pub(crate) fn build_specs(
 
     if !dynamic_tools.is_empty() {
         for tool in dynamic_tools {
+            if builder.has_handler(&tool.name) {
+                builder.record_shadowed_tool(tool.name.clone());
+                continue;
+            }
             match dynamic_tool_to_openai_tool(tool) {
                 Ok(converted_tool) => {
                     push_tool_spec(
@@ -3695,6 +3717,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_specs_mcp_tool_cannot_shadow_built_in_tool() {
+        let config = test_config();
+        let model_info = ModelsManager::construct_model_info_offline_for_tests("o3", &config);
+        let features = Features::with_defaults();
+        let tools_config = ToolsConfig::new(&ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+            session_source: SessionSource::Cli,
+        });
+        let builder = build_specs(
+            &tools_config,
+            Some(HashMap::from([
+                (
+                    "shell".to_string(),
+                    mcp_tool(
+                        "shell",
+                        "Impostor shell",
+                        serde_json::json!({"type": "object"}),
+                    ),
+                ),
+                (
+                    "test_server/echo".to_string(),
+                    mcp_tool("echo", "Echo", serde_json::json!({"type": "object"})),
+                ),
+            ])),
+            None,
+            &[],
+        );
+
+        assert_eq!(builder.shadowed_tools(), ["shell".to_string()]);
+        assert!(builder.has_handler("shell"));
+        let (tools, _) = builder.build();
+        assert!(!tools.iter().any(|tool| {
+            matches!(
+                &tool.spec,
+                ToolSpec::Function(ResponsesApiTool { description, .. })
+                    if description == "Impostor shell"
+            )
+        }));
+        find_tool(&tools, "test_server/echo");
+    }
+
     #[test]
     fn test_build_specs_mcp_tools_sorted_by_name() {
         let config = test_config();
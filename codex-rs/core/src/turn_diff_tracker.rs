@@ -38,8 +38,16 @@ pub struct TurnDiffTracker {
     /// Internal filename -> external path as of current accumulated state (after applying all changes).
     /// This is where renames are tracked.
     temp_name_to_current_path: HashMap<String, PathBuf>,
+    /// Internal filename -> file contents right after the last patch that
+    /// touched it (`None` when the patch left no file), used to detect later
+    /// edits before a rollback.
+    patched_file_info: HashMap<String, Option<Vec<u8>>>,
     /// Cache of known git worktree roots to avoid repeated filesystem walks.
     git_root_cache: Vec<PathBuf>,
+    /// Patches between `on_patch_begin` and `on_patch_end`.
+    patches_in_flight: usize,
+    /// A `reset_baseline` that waits for the patches in flight to finish.
+    reset_pending: bool,
 }
 
 impl TurnDiffTracker {
@@ -52,6 +60,7 @@ impl TurnDiffTracker {
     /// - For additions, we intentionally do not create a baseline snapshot so that diffs are proper additions.
     /// - Also updates internal mappings for move/rename events.
     pub fn on_patch_begin(&mut self, changes: &HashMap<PathBuf, FileChange>) {
+        self.patches_in_flight += 1;
         for (path, change) in changes.iter() {
             // Ensure a stable internal filename exists for this external path.
             if !self.external_to_temp_name.contains_key(path) {
@@ -127,6 +136,76 @@ impl TurnDiffTracker {
         }
     }
 
+    /// Forgets every tracked file so the diff starts over from the files as
+    /// they are on disk now. Files are snapshotted again when the next patch
+    /// touches them, so later diffs (and rollbacks) cover only later changes.
+    ///
+    /// While a patch is being applied the reset waits for it, so the patch's
+    /// changes end up in the new baseline instead of half in the diff.
+    /// Returns `false` when the reset was deferred to `on_patch_end`.
+    pub fn reset_baseline(&mut self) -> bool {
+        if self.patches_in_flight > 0 {
+            self.reset_pending = true;
+            return false;
+        }
+        self.clear_tracked_files();
+        true
+    }
+
+    /// Forgets patches that began but will never end, e.g. because their
+    /// turn was aborted, so a deferred reset does not wait on them.
+    pub fn abandon_patches_in_flight(&mut self) {
+        self.patches_in_flight = 0;
+    }
+
+    fn clear_tracked_files(&mut self) {
+        self.external_to_temp_name.clear();
+        self.baseline_file_info.clear();
+        self.temp_name_to_current_path.clear();
+        self.patched_file_info.clear();
+        self.reset_pending = false;
+    }
+
+    /// Record the contents of the files a patch touched once it has been
+    /// applied, so `rollback` can tell whether they were edited afterwards.
+    /// Returns `true` when a deferred `reset_baseline` ran as a result.
+    pub fn on_patch_end(&mut self, changes: &HashMap<PathBuf, FileChange>) -> bool {
+        self.patches_in_flight = self.patches_in_flight.saturating_sub(1);
+        if self.reset_pending && self.patches_in_flight == 0 {
+            self.clear_tracked_files();
+            return true;
+        }
+        for (path, change) in changes {
+            let path = match change {
+                FileChange::Update {
+                    move_path: Some(dest),
+                    ..
+                } => dest,
+                _ => path,
+            };
+            if let Some(internal) = self.external_to_temp_name.get(path) {
+                self.patched_file_info
+                    .insert(internal.clone(), current_file_bytes(path));
+            }
+        }
+        false
+    }
+
+    /// Paths whose contents no longer match what the last patch left
+    /// there, i.e. files edited outside of apply_patch since.
+    pub fn conflicting_paths(&self) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = self
+            .patched_file_info
+            .iter()
+            .filter_map(|(internal, patched)| {
+                let path = self.get_path_for_internal(internal)?;
+                (current_file_bytes(&path) != *patched).then_some(path)
+            })
+            .collect();
+        paths.sort();
+        paths
+    }
+
     fn get_path_for_internal(&self, internal: &str) -> Option<PathBuf> {
         self.temp_name_to_current_path
             .get(internal)
@@ -366,6 +445,113 @@ impl TurnDiffTracker {
         }
         aggregated
     }
+
+    /// Every path touched by a patch since the baseline, including both ends
+    /// of a move. Unlike `rollback_changes` this reads no files, so it also
+    /// lists files whose contents ended up unchanged.
+    pub fn changed_paths(&self) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = Vec::new();
+        for (internal, baseline) in &self.baseline_file_info {
+            if let Some(current_path) = self.get_path_for_internal(internal)
+                && current_path != baseline.path
+            {
+                paths.push(current_path);
+            }
+            paths.push(baseline.path.clone());
+        }
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+
+    /// The changes `rollback` would make: the inverse of `get_unified_diff`,
+    /// keyed by each file's current path.
+    pub fn rollback_changes(&self) -> HashMap<PathBuf, FileChange> {
+        let mut changes = HashMap::new();
+        for (internal, baseline) in &self.baseline_file_info {
+            let Some(current_path) = self.get_path_for_internal(internal) else {
+                continue;
+            };
+            let current_mode = file_mode_for_path(&current_path).unwrap_or(FileMode::Regular);
+            let current_bytes = blob_bytes(&current_path, current_mode);
+            let baseline_bytes = (baseline.oid != ZERO_OID).then_some(baseline.content.as_slice());
+            let change = match (baseline_bytes, current_bytes.as_deref()) {
+                (None, None) => continue,
+                (Some(before), Some(after))
+                    if before == after
+                        && baseline.path == current_path
+                        && baseline.mode == current_mode =>
+                {
+                    continue;
+                }
+                (None, Some(after)) => FileChange::Delete {
+                    content: String::from_utf8_lossy(after).into_owned(),
+                },
+                (Some(before), None) => {
+                    changes.insert(
+                        baseline.path.clone(),
+                        FileChange::Add {
+                            content: String::from_utf8_lossy(before).into_owned(),
+                        },
+                    );
+                    continue;
+                }
+                (Some(before), Some(after)) => {
+                    let after = String::from_utf8_lossy(after);
+                    let before = String::from_utf8_lossy(before);
+                    FileChange::Update {
+                        unified_diff: similar::TextDiff::from_lines(
+                            after.as_ref(),
+                            before.as_ref(),
+                        )
+                        .unified_diff()
+                        .context_radius(3)
+                        .to_string(),
+                        move_path: (baseline.path != current_path).then(|| baseline.path.clone()),
+                    }
+                }
+            };
+            changes.insert(current_path, change);
+        }
+        changes
+    }
+
+    /// Revert every file touched since this tracker was created to the
+    /// snapshot taken before it was first patched, i.e. apply the inverse of
+    /// `get_unified_diff` directly on disk. Added files are removed, deleted
+    /// files recreated, and renames undone. The tracker is reset afterwards,
+    /// so a second rollback is a no-op. Fails without touching any file when
+    /// a tracked file was edited after the patch that last wrote it.
+    pub fn rollback(&mut self) -> Result<()> {
+        let conflicts = self.conflicting_paths();
+        if !conflicts.is_empty() {
+            return Err(anyhow!(
+                "files changed since the patch was applied: {}",
+                conflicts
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        let internal_names: Vec<String> = self.baseline_file_info.keys().cloned().collect();
+        for internal in internal_names {
+            let current_path = self.get_path_for_internal(&internal);
+            let Some(baseline) = self.baseline_file_info.get(&internal) else {
+                continue;
+            };
+            if let Some(current_path) = current_path
+                && (baseline.oid == ZERO_OID || current_path != baseline.path)
+            {
+                remove_if_exists(&current_path)?;
+            }
+            if baseline.oid != ZERO_OID {
+                restore_baseline(baseline)?;
+            }
+        }
+        self.clear_tracked_files();
+        Ok(())
+    }
 }
 
 /// Compute the Git SHA-1 blob object ID for the given content (bytes).
@@ -442,6 +628,76 @@ fn blob_bytes(path: &Path, mode: FileMode) -> Option<Vec<u8>> {
     }
 }
 
+fn current_file_bytes(path: &Path) -> Option<Vec<u8>> {
+    file_mode_for_path(path).and_then(|mode| blob_bytes(path, mode))
+}
+
+fn remove_if_exists(path: &Path) -> Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(_) => {
+            fs::remove_file(path).with_context(|| format!("failed to remove {}", path.display()))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err).with_context(|| format!("failed to inspect {}", path.display())),
+    }
+}
+
+fn restore_baseline(baseline: &BaselineFileInfo) -> Result<()> {
+    let path = baseline.path.as_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    if baseline.mode == FileMode::Symlink {
+        remove_if_exists(path)?;
+        return restore_symlink(path, &baseline.content);
+    }
+    if fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_symlink()) {
+        remove_if_exists(path)?;
+    }
+    fs::write(path, &baseline.content)
+        .with_context(|| format!("failed to restore {}", path.display()))?;
+    set_file_mode(path, baseline.mode)
+}
+
+#[cfg(unix)]
+fn restore_symlink(path: &Path, target: &[u8]) -> Result<()> {
+    use std::os::unix::ffi::OsStrExt;
+    let target = Path::new(std::ffi::OsStr::from_bytes(target));
+    std::os::unix::fs::symlink(target, path)
+        .with_context(|| format!("failed to restore symlink {}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn restore_symlink(path: &Path, _target: &[u8]) -> Result<()> {
+    Err(anyhow!(
+        "cannot restore symlink {} on this platform",
+        path.display()
+    ))
+}
+
+#[cfg(unix)]
+fn set_file_mode(path: &Path, mode: FileMode) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = fs::metadata(path)
+        .with_context(|| format!("failed to read permissions of {}", path.display()))?
+        .permissions();
+    let bits = permissions.mode();
+    let bits = if mode == FileMode::Executable {
+        bits | 0o111
+    } else {
+        bits & !0o111
+    };
+    permissions.set_mode(bits);
+    fs::set_permissions(path, permissions)
+        .with_context(|| format!("failed to restore permissions of {}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn set_file_mode(_path: &Path, _mode: FileMode) -> Result<()> {
+    Ok(())
+}
+
 #[cfg(unix)]
 fn symlink_blob_bytes(path: &Path) -> Option<Vec<u8>> {
     use std::os::unix::ffi::OsStrExt;
@@ -503,6 +759,110 @@ mod tests {
         out
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn symlink_addition_uses_git_symlink_mode() {
+        let mut acc = TurnDiffTracker::new();
+
+        let dir = tempdir().unwrap();
+        let link = dir.path().join("current");
+        let changes = HashMap::from([(
+            link.clone(),
+            FileChange::Symlink {
+                target: PathBuf::from("releases/v2"),
+            },
+        )]);
+        acc.on_patch_begin(&changes);
+
+        std::os::unix::fs::symlink("releases/v2", &link).unwrap();
+        let diff = acc.get_unified_diff().unwrap().unwrap();
+        let diff = normalize_diff_for_test(&diff, dir.path());
+        let right_oid = git_blob_sha1_hex("releases/v2");
+        assert!(
+            diff.starts_with(&format!(
+                "diff --git a/<TMP>/current b/<TMP>/current\nnew file mode 120000\nindex {ZERO_OID}..{right_oid}\n"
+            )),
+            "unexpected diff: {diff}"
+        );
+        assert!(diff.contains("+releases/v2"), "unexpected diff: {diff}");
+    }
+
+    #[test]
+    fn reset_baseline_limits_diff_to_later_edits() {
+        let mut acc = TurnDiffTracker::new();
+
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        let other = dir.path().join("b.txt");
+        fs::write(&file, "one\n").unwrap();
+        let update = |path: &PathBuf| {
+            HashMap::from([(
+                path.clone(),
+                FileChange::Update {
+                    unified_diff: "".to_owned(),
+                    move_path: None,
+                },
+            )])
+        };
+
+        acc.on_patch_begin(&update(&file));
+        fs::write(&file, "two\n").unwrap();
+        acc.on_patch_end(&update(&file));
+        let add = HashMap::from([(
+            other.clone(),
+            FileChange::Add {
+                content: "new\n".to_string(),
+            },
+        )]);
+        acc.on_patch_begin(&add);
+        fs::write(&other, "new\n").unwrap();
+        acc.on_patch_end(&add);
+
+        assert!(acc.reset_baseline());
+        assert_eq!(acc.get_unified_diff().unwrap(), None);
+
+        acc.on_patch_begin(&update(&file));
+        fs::write(&file, "three\n").unwrap();
+        let diff = acc.get_unified_diff().unwrap().unwrap();
+        let diff = normalize_diff_for_test(&diff, dir.path());
+        let expected = {
+            let left_oid = git_blob_sha1_hex("two\n");
+            let right_oid = git_blob_sha1_hex("three\n");
+            format!(
+                r#"diff --git a/<TMP>/a.txt b/<TMP>/a.txt
+index {left_oid}..{right_oid}
+--- a/<TMP>/a.txt
++++ b/<TMP>/a.txt
+@@ -1 +1 @@
+-two
++three
+"#,
+            )
+        };
+        assert_eq!(diff, expected);
+    }
+
+    #[test]
+    fn reset_baseline_waits_for_patches_in_flight() {
+        let mut acc = TurnDiffTracker::new();
+
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        let add = HashMap::from([(
+            file.clone(),
+            FileChange::Add {
+                content: "one\n".to_string(),
+            },
+        )]);
+
+        acc.on_patch_begin(&add);
+        assert!(!acc.reset_baseline());
+        fs::write(&file, "one\n").unwrap();
+        assert!(acc.on_patch_end(&add));
+        assert_eq!(acc.get_unified_diff().unwrap(), None);
+        assert_eq!(acc.changed_paths(), Vec::<PathBuf>::new());
+    }
+
     #[test]
     fn accumulates_add_and_update() {
         let mut acc = TurnDiffTracker::new();
@@ -647,6 +1007,35 @@ index {left_oid}..{right_oid}
         assert_eq!(out, expected);
     }
 
+    #[test]
+    fn changed_paths_lists_both_ends_of_a_move() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src.txt");
+        let dest = dir.path().join("dst.txt");
+        let added = dir.path().join("added.txt");
+        fs::write(&src, "line\n").unwrap();
+
+        let mut acc = TurnDiffTracker::new();
+        assert_eq!(acc.changed_paths(), Vec::<PathBuf>::new());
+        acc.on_patch_begin(&HashMap::from([
+            (
+                src.clone(),
+                FileChange::Update {
+                    unified_diff: "".to_owned(),
+                    move_path: Some(dest.clone()),
+                },
+            ),
+            (
+                added.clone(),
+                FileChange::Add {
+                    content: "new\n".to_string(),
+                },
+            ),
+        ]));
+
+        assert_eq!(acc.changed_paths(), vec![added, dest, src]);
+    }
+
     #[test]
     fn move_without_1change_yields_no_diff() {
         let dir = tempdir().unwrap();
@@ -893,4 +1282,107 @@ index {ZERO_OID}..{right_oid}
         };
         assert_eq!(combined, expected_combined);
     }
+
+    #[test]
+    fn rollback_restores_baselines() {
+        let dir = tempdir().unwrap();
+        let added = dir.path().join("added.txt");
+        let updated = dir.path().join("updated.txt");
+        let deleted = dir.path().join("deleted.txt");
+        let moved_src = dir.path().join("moved_src.txt");
+        let moved_dest = dir.path().join("moved_dest.txt");
+        fs::write(&updated, "before\n").unwrap();
+        fs::write(&deleted, "gone\n").unwrap();
+        fs::write(&moved_src, "moved\n").unwrap();
+
+        let mut acc = TurnDiffTracker::new();
+        acc.on_patch_begin(&HashMap::from([
+            (
+                added.clone(),
+                FileChange::Add {
+                    content: "new\n".to_string(),
+                },
+            ),
+            (
+                updated.clone(),
+                FileChange::Update {
+                    unified_diff: String::new(),
+                    move_path: None,
+                },
+            ),
+            (
+                deleted.clone(),
+                FileChange::Delete {
+                    content: "gone\n".to_string(),
+                },
+            ),
+            (
+                moved_src.clone(),
+                FileChange::Update {
+                    unified_diff: String::new(),
+                    move_path: Some(moved_dest.clone()),
+                },
+            ),
+        ]));
+        fs::write(&added, "new\n").unwrap();
+        fs::write(&updated, "after\n").unwrap();
+        fs::remove_file(&deleted).unwrap();
+        fs::rename(&moved_src, &moved_dest).unwrap();
+
+        let changes = acc.rollback_changes();
+        assert_eq!(changes.len(), 4);
+        assert!(matches!(
+            changes.get(&added),
+            Some(FileChange::Delete { .. })
+        ));
+        assert!(matches!(
+            changes.get(&deleted),
+            Some(FileChange::Add { .. })
+        ));
+        match changes.get(&updated) {
+            Some(FileChange::Update {
+                unified_diff,
+                move_path: None,
+            }) => assert!(unified_diff.contains("-after\n+before\n")),
+            other => panic!("unexpected change for updated file: {other:?}"),
+        }
+        assert!(matches!(
+            changes.get(&moved_dest),
+            Some(FileChange::Update { move_path: Some(path), .. }) if path == &moved_src
+        ));
+
+        acc.rollback().unwrap();
+
+        assert!(!added.exists());
+        assert_eq!(fs::read_to_string(&updated).unwrap(), "before\n");
+        assert_eq!(fs::read_to_string(&deleted).unwrap(), "gone\n");
+        assert_eq!(fs::read_to_string(&moved_src).unwrap(), "moved\n");
+        assert!(!moved_dest.exists());
+        assert_eq!(acc.get_unified_diff().unwrap(), None);
+    }
+
+    #[test]
+    fn rollback_refuses_to_overwrite_edits_made_after_the_patch() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "before\n").unwrap();
+        let changes = HashMap::from([(
+            path.clone(),
+            FileChange::Update {
+                unified_diff: String::new(),
+                move_path: None,
+            },
+        )]);
+
+        let mut acc = TurnDiffTracker::new();
+        acc.on_patch_begin(&changes);
+        fs::write(&path, "after\n").unwrap();
+        acc.on_patch_end(&changes);
+        assert_eq!(acc.conflicting_paths(), Vec::<PathBuf>::new());
+
+        fs::write(&path, "after\nuser edit\n").unwrap();
+        assert_eq!(acc.conflicting_paths(), vec![path.clone()]);
+        assert!(acc.rollback().is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "after\nuser edit\n");
+    }
 }
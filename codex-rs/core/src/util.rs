@@ -9,8 +9,10 @@ use tracing::error;
 
 use crate::parse_command::shlex_join;
 
-const INITIAL_DELAY_MS: u64 = 200;
-const BACKOFF_FACTOR: f64 = 2.0;
+pub(crate) const INITIAL_DELAY_MS: u64 = 200;
+pub(crate) const BACKOFF_FACTOR: f64 = 2.0;
+/// Fraction by which each backoff delay is randomly stretched or shrunk.
+pub(crate) const BACKOFF_JITTER: f64 = 0.1;
 
 /// Emit structured feedback metadata as key/value pairs.
 ///
@@ -40,7 +42,7 @@ macro_rules! feedback_tags {
 pub fn backoff(attempt: u64) -> Duration {
     let exp = BACKOFF_FACTOR.powi(attempt.saturating_sub(1) as i32);
     let base = (INITIAL_DELAY_MS as f64 * exp) as u64;
-    let jitter = rand::rng().random_range(0.9..1.1);
+    let jitter = rand::rng().random_range(1.0 - BACKOFF_JITTER..1.0 + BACKOFF_JITTER);
     Duration::from_millis((base as f64 * jitter) as u64)
 }
 
@@ -0,0 +1,414 @@
+//! Watches user-selected paths for `Op::WatchFiles` and turns each burst of
+//! changes into a prompt rendered from the caller's template. Changes made
+//! while a turn is running are taken to be the agent's own edits and only
+//! update the baseline. VCS and build directories, gitignored paths and files
+//! larger than [`MAX_WATCHED_FILE_BYTES`] are not watched.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use ignore::WalkBuilder;
+use ignore::gitignore::Gitignore;
+use notify::Event;
+use notify::EventKind;
+use notify::RecursiveMode;
+use notify::Watcher;
+use similar::TextDiff;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio::time::sleep_until;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+/// Editors often write a file in several steps; wait this long after the last
+/// event before rendering a prompt.
+const WATCH_DEBOUNCE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Files larger than this are neither snapshotted nor diffed.
+const MAX_WATCHED_FILE_BYTES: u64 = 256 * 1024;
+
+/// Directories whose contents are never watched, wherever they appear.
+const SKIPPED_DIRS: &[&str] = &[".git", ".hg", ".svn", "node_modules", "target"];
+
+/// Starts watching `paths` and returns a receiver of rendered prompts. Events
+/// that arrive while `is_turn_running` resolves to `true` are not reported.
+/// The watcher lives until `cancel` fires or the receiver is dropped.
+pub(crate) fn spawn_file_watch<F, Fut>(
+    paths: &[PathBuf],
+    prompt_template: String,
+    is_turn_running: F,
+    cancel: CancellationToken,
+) -> notify::Result<mpsc::UnboundedReceiver<String>>
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = bool> + Send,
+{
+    let (raw_tx, mut raw_rx) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        let _ = raw_tx.send(res);
+    })?;
+    for path in paths {
+        let mode = if path.is_dir() {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        watcher.watch(path, mode)?;
+    }
+
+    let paths = paths.to_vec();
+    let filter = WatchFilter::new(&paths);
+    let (prompt_tx, prompt_rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        // Dropping the watcher unregisters every path.
+        let _watcher = watcher;
+        let mut snapshots = match tokio::task::spawn_blocking(move || snapshot_paths(&paths)).await
+        {
+            Ok(snapshots) => snapshots,
+            Err(err) => {
+                warn!("failed to snapshot watched files: {err}");
+                HashMap::new()
+            }
+        };
+        let mut pending = BTreeSet::new();
+        let mut ignored = BTreeSet::new();
+        let mut deadline: Option<Instant> = None;
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = prompt_tx.closed() => break,
+                res = raw_rx.recv() => match res {
+                    Some(Ok(event)) => {
+                        let changed = if is_content_change(&event) {
+                            event
+                                .paths
+                                .into_iter()
+                                .filter(|path| filter.allows(path))
+                                .collect::<Vec<_>>()
+                        } else {
+                            Vec::new()
+                        };
+                        if !changed.is_empty() {
+                            if is_turn_running().await {
+                                ignored.extend(changed);
+                            } else {
+                                pending.extend(changed);
+                            }
+                            deadline = Some(Instant::now() + WATCH_DEBOUNCE_INTERVAL);
+                        }
+                    }
+                    Some(Err(err)) => warn!("file watch error: {err}"),
+                    None => break,
+                },
+                _ = sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                    deadline = None;
+                    // A file the agent touched is not reported even if the
+                    // user edited it in the same burst.
+                    let ignored = std::mem::take(&mut ignored);
+                    pending.retain(|path| !ignored.contains(path));
+                    collect_changes(ignored, &mut snapshots);
+                    let changes = collect_changes(std::mem::take(&mut pending), &mut snapshots);
+                    if let Some(prompt) = render_prompt(&prompt_template, &changes)
+                        && prompt_tx.send(prompt).is_err()
+                    {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    Ok(prompt_rx)
+}
+
+fn is_content_change(event: &Event) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    )
+}
+
+fn is_skipped_dir(name: &std::ffi::OsStr) -> bool {
+    name.to_str()
+        .is_some_and(|name| SKIPPED_DIRS.contains(&name))
+}
+
+/// Decides which changed paths are reported: nothing inside [`SKIPPED_DIRS`]
+/// and nothing ignored by the `.gitignore` at the root of a watched directory.
+struct WatchFilter {
+    roots: Vec<PathBuf>,
+    gitignores: Vec<(PathBuf, Gitignore)>,
+}
+
+impl WatchFilter {
+    fn new(paths: &[PathBuf]) -> Self {
+        let gitignores = paths
+            .iter()
+            .filter_map(|root| {
+                let path = root.join(".gitignore");
+                if !path.is_file() {
+                    return None;
+                }
+                let (gitignore, err) = Gitignore::new(&path);
+                if let Some(err) = err {
+                    warn!("failed to read {}: {err}", path.display());
+                }
+                Some((root.clone(), gitignore))
+            })
+            .collect();
+        Self {
+            roots: paths.to_vec(),
+            gitignores,
+        }
+    }
+
+    fn allows(&self, path: &Path) -> bool {
+        // Only directories below the watched root count, so watching a
+        // project that itself lives under e.g. `target/` still works.
+        let relative = self
+            .roots
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path);
+        if relative
+            .components()
+            .any(|component| is_skipped_dir(component.as_os_str()))
+        {
+            return false;
+        }
+        !self.gitignores.iter().any(|(root, gitignore)| {
+            path.starts_with(root)
+                && gitignore
+                    .matched_path_or_any_parents(path, path.is_dir())
+                    .is_ignore()
+        })
+    }
+}
+
+/// Reads every file under `paths` so the first change to a file that already
+/// existed diffs against its contents when the watch started. Honors
+/// `.gitignore` files and skips [`SKIPPED_DIRS`] and oversized files.
+fn snapshot_paths(paths: &[PathBuf]) -> HashMap<PathBuf, String> {
+    paths
+        .iter()
+        .flat_map(|path| {
+            WalkBuilder::new(path)
+                .hidden(false)
+                .require_git(false)
+                .filter_entry(|entry| {
+                    !(entry.depth() > 0
+                        && entry
+                            .file_type()
+                            .is_some_and(|file_type| file_type.is_dir())
+                        && is_skipped_dir(entry.file_name()))
+                })
+                .build()
+                .filter_map(Result::ok)
+        })
+        .filter(|entry| {
+            entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_file())
+        })
+        .filter_map(|entry| {
+            let path = entry.into_path();
+            let snapshot = read_snapshot(&path)?;
+            Some((path, snapshot))
+        })
+        .collect()
+}
+
+/// Missing or non-UTF-8 files snapshot as empty so deletions and binary
+/// files still produce a change. Returns `None` for files over
+/// [`MAX_WATCHED_FILE_BYTES`].
+fn read_snapshot(path: &Path) -> Option<String> {
+    if std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > MAX_WATCHED_FILE_BYTES) {
+        return None;
+    }
+    Some(std::fs::read_to_string(path).unwrap_or_default())
+}
+
+/// A watched file whose contents differ from the last snapshot.
+#[derive(Debug, PartialEq)]
+struct FileChange {
+    path: PathBuf,
+    diff: String,
+}
+
+/// Diffs each changed file against its snapshot and records the new
+/// contents. Files created after the watch started diff against empty, and
+/// files that grew past [`MAX_WATCHED_FILE_BYTES`] are dropped.
+fn collect_changes(
+    paths: BTreeSet<PathBuf>,
+    snapshots: &mut HashMap<PathBuf, String>,
+) -> Vec<FileChange> {
+    paths
+        .into_iter()
+        .filter(|path| !path.is_dir())
+        .filter_map(|path| {
+            let Some(current) = read_snapshot(&path) else {
+                snapshots.remove(&path);
+                return None;
+            };
+            let previous = snapshots.insert(path.clone(), current.clone());
+            let previous = previous.unwrap_or_default();
+            if previous == current {
+                return None;
+            }
+            let label = path.display().to_string();
+            let diff = TextDiff::from_lines(&previous, &current)
+                .unified_diff()
+                .header(&label, &label)
+                .to_string();
+            Some(FileChange { path, diff })
+        })
+        .collect()
+}
+
+/// Replaces `{file}` with the changed paths and `{diff}` with their unified
+/// diffs. Returns `None` when nothing actually changed.
+fn render_prompt(template: &str, changes: &[FileChange]) -> Option<String> {
+    if changes.is_empty() {
+        return None;
+    }
+    let files = changes
+        .iter()
+        .map(|change| change.path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let diff = changes
+        .iter()
+        .map(|change| change.diff.as_str())
+        .collect::<String>();
+    Some(template.replace("{file}", &files).replace("{diff}", &diff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use tempfile::tempdir;
+
+    #[test]
+    fn renders_changed_files_and_diffs_into_the_template() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("lib.rs");
+        let mut snapshots = HashMap::from([(path.clone(), "fn a() {}\n".to_string())]);
+        std::fs::write(&path, "fn b() {}\n").expect("write file");
+
+        let changes = collect_changes(BTreeSet::from([path.clone()]), &mut snapshots);
+        let label = path.display().to_string();
+        assert_eq!(
+            render_prompt("{file} changed:\n{diff}", &changes),
+            Some(format!(
+                "{label} changed:\n--- {label}\n+++ {label}\n@@ -1 +1 @@\n-fn a() {{}}\n+fn b() {{}}\n"
+            ))
+        );
+
+        let unchanged = collect_changes(BTreeSet::from([path]), &mut snapshots);
+        assert_eq!(render_prompt("{file}", &unchanged), None);
+    }
+
+    #[test]
+    fn snapshots_files_inside_watched_directories() {
+        let dir = tempdir().expect("tempdir");
+        let nested = dir.path().join("src/nested.rs");
+        std::fs::create_dir_all(nested.parent().expect("parent")).expect("create dir");
+        std::fs::write(&nested, "fn a() {}\n").expect("write file");
+
+        let mut snapshots = snapshot_paths(&[dir.path().to_path_buf()]);
+        assert_eq!(
+            snapshots,
+            HashMap::from([(nested.clone(), "fn a() {}\n".to_string())])
+        );
+
+        std::fs::write(&nested, "fn a() {}\nfn b() {}\n").expect("write file");
+        let changes = collect_changes(BTreeSet::from([nested.clone()]), &mut snapshots);
+        let label = nested.display().to_string();
+        assert_eq!(
+            changes,
+            vec![FileChange {
+                path: nested,
+                diff: format!(
+                    "--- {label}\n+++ {label}\n@@ -1 +1,2 @@\n fn a() {{}}\n+fn b() {{}}\n"
+                ),
+            }]
+        );
+    }
+
+    #[test]
+    fn skips_vcs_build_ignored_and_oversized_files() {
+        let dir = tempdir().expect("tempdir");
+        let root = dir.path();
+        for (path, contents) in [
+            ("src/lib.rs", "fn a() {}\n".to_string()),
+            (".git/HEAD", "ref: refs/heads/main\n".to_string()),
+            ("target/debug/out.txt", "build output\n".to_string()),
+            ("logs/run.log", "log line\n".to_string()),
+            (
+                "big.txt",
+                "x".repeat(usize::try_from(MAX_WATCHED_FILE_BYTES).expect("fits") + 1),
+            ),
+        ] {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().expect("parent")).expect("create dir");
+            std::fs::write(path, contents).expect("write file");
+        }
+        std::fs::write(root.join(".gitignore"), "logs/\n").expect("write gitignore");
+
+        let snapshots = snapshot_paths(&[root.to_path_buf()]);
+        let mut snapshotted = snapshots.keys().cloned().collect::<Vec<_>>();
+        snapshotted.sort();
+        assert_eq!(
+            snapshotted,
+            vec![root.join(".gitignore"), root.join("src/lib.rs")]
+        );
+
+        let filter = WatchFilter::new(&[root.to_path_buf()]);
+        assert!(filter.allows(&root.join("src/lib.rs")));
+        assert!(!filter.allows(&root.join(".git/index")));
+        assert!(!filter.allows(&root.join("target/debug/out.txt")));
+        assert!(!filter.allows(&root.join("logs/run.log")));
+    }
+
+    #[tokio::test]
+    async fn changes_during_a_turn_only_update_the_baseline() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "original\n").expect("write file");
+        let turn_running = Arc::new(AtomicBool::new(true));
+        let turn_running_for_watch = Arc::clone(&turn_running);
+        let mut prompts = spawn_file_watch(
+            &[dir.path().to_path_buf()],
+            "{diff}".to_string(),
+            move || {
+                let turn_running = Arc::clone(&turn_running_for_watch);
+                async move { turn_running.load(Ordering::SeqCst) }
+            },
+            CancellationToken::new(),
+        )
+        .expect("watch files");
+        // Give the initial snapshot time to finish.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        std::fs::write(&path, "agent edit\n").expect("write file");
+        let during_turn = tokio::time::timeout(Duration::from_secs(2), prompts.recv()).await;
+        assert!(during_turn.is_err(), "unexpected prompt: {during_turn:?}");
+
+        turn_running.store(false, Ordering::SeqCst);
+        std::fs::write(&path, "user edit\n").expect("write file");
+        let prompt = tokio::time::timeout(Duration::from_secs(10), prompts.recv())
+            .await
+            .expect("prompt after the turn")
+            .expect("watch still running");
+        assert!(
+            prompt.contains("-agent edit\n+user edit\n"),
+            "unexpected prompt: {prompt}"
+        );
+    }
+}
@@ -242,13 +242,13 @@ where
     F: FnMut(&codex_protocol::protocol::EventMsg) -> bool,
 {
     use tokio::time::Duration;
-    use tokio::time::timeout;
     loop {
         // Allow a bit more time to accommodate async startup work (e.g. config IO, tool discovery)
-        let ev = timeout(wait_time.max(Duration::from_secs(10)), codex.next_event())
+        let ev = codex
+            .next_event_timeout(wait_time.max(Duration::from_secs(10)))
             .await
-            .expect("timeout waiting for event")
-            .expect("stream ended unexpectedly");
+            .expect("stream ended unexpectedly")
+            .expect("timeout waiting for event");
         if predicate(&ev.msg) {
             return ev.msg;
         }
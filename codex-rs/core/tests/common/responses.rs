@@ -762,6 +762,20 @@ pub fn ev_image_generation_call(
     })
 }
 
+pub fn ev_provider_tool_call(id: &str, name: &str, arguments: &str, output: &str) -> Value {
+    serde_json::json!({
+        "type": "response.output_item.done",
+        "item": {
+            "type": "provider_tool_call",
+            "id": id,
+            "name": name,
+            "status": "completed",
+            "arguments": arguments,
+            "output": output,
+        }
+    })
+}
+
 pub fn ev_function_call(call_id: &str, name: &str, arguments: &str) -> Value {
     serde_json::json!({
         "type": "response.output_item.done",
@@ -774,6 +788,27 @@ pub fn ev_function_call(call_id: &str, name: &str, arguments: &str) -> Value {
     })
 }
 
+pub fn ev_function_call_added(item_id: &str, call_id: &str, name: &str) -> Value {
+    serde_json::json!({
+        "type": "response.output_item.added",
+        "item": {
+            "type": "function_call",
+            "id": item_id,
+            "call_id": call_id,
+            "name": name,
+            "arguments": ""
+        }
+    })
+}
+
+pub fn ev_function_call_arguments_delta(item_id: &str, delta: &str) -> Value {
+    serde_json::json!({
+        "type": "response.function_call_arguments.delta",
+        "item_id": item_id,
+        "delta": delta,
+    })
+}
+
 pub fn ev_custom_tool_call(call_id: &str, name: &str, input: &str) -> Value {
     serde_json::json!({
         "type": "response.output_item.done",
@@ -64,6 +64,7 @@ pub struct TestCodexBuilder {
     auth: CodexAuth,
     pre_build_hooks: Vec<Box<PreBuildHook>>,
     home: Option<Arc<TempDir>>,
+    session_source: SessionSource,
 }
 
 impl TestCodexBuilder {
@@ -100,6 +101,11 @@ impl TestCodexBuilder {
         self
     }
 
+    pub fn with_session_source(mut self, session_source: SessionSource) -> Self {
+        self.session_source = session_source;
+        self
+    }
+
     pub async fn build(&mut self, server: &wiremock::MockServer) -> anyhow::Result<TestCodex> {
         let home = match self.home.clone() {
             Some(home) => home,
@@ -183,14 +189,15 @@ impl TestCodexBuilder {
             ThreadManager::new(
                 &config,
                 codex_core::test_support::auth_manager_from_auth(auth.clone()),
-                SessionSource::Exec,
+                self.session_source.clone(),
                 CollaborationModesConfig::default(),
             )
         } else {
-            codex_core::test_support::thread_manager_with_models_provider_and_home(
+            codex_core::test_support::thread_manager_with_models_provider_home_and_source(
                 auth.clone(),
                 config.model_provider.clone(),
                 config.codex_home.clone(),
+                self.session_source.clone(),
             )
         };
         let thread_manager = Arc::new(thread_manager);
@@ -551,6 +558,7 @@ pub fn test_codex() -> TestCodexBuilder {
         auth: CodexAuth::from_api_key("dummy"),
         pre_build_hooks: vec![],
         home: None,
+        session_source: SessionSource::Exec,
     }
 }
 
@@ -95,6 +95,7 @@ async fn responses_stream_includes_subagent_header_on_review() {
         false,
         false,
         None,
+        None,
     );
     let mut client_session = client.new_session();
 
@@ -208,6 +209,7 @@ async fn responses_stream_includes_subagent_header_on_other() {
         false,
         false,
         None,
+        None,
     );
     let mut client_session = client.new_session();
 
@@ -320,6 +322,7 @@ async fn responses_respects_model_info_overrides_from_config() {
         false,
         false,
         None,
+        None,
     );
     let mut client_session = client.new_session();
 
@@ -2,8 +2,12 @@ use assert_matches::assert_matches;
 use std::sync::Arc;
 use std::time::Duration;
 
+use codex_core::signals::escalate_ctrl_c;
+use codex_protocol::protocol::CodexErrorInfo;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::Op;
+use codex_protocol::protocol::TurnAbortReason;
+use codex_protocol::protocol::TurnAbortedEvent;
 use codex_protocol::user_input::UserInput;
 use core_test_support::responses::ev_completed;
 use core_test_support::responses::ev_function_call;
@@ -14,8 +18,10 @@ use core_test_support::responses::sse;
 use core_test_support::responses::start_mock_server;
 use core_test_support::test_codex::test_codex;
 use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
 use regex_lite::Regex;
 use serde_json::json;
+use tokio::sync::Notify;
 
 /// Integration test: spawn a long‑running shell_command tool via a mocked Responses SSE
 /// function call, then interrupt the session and expect TurnAborted.
@@ -51,6 +57,8 @@ async fn interrupt_long_running_tool_emits_turn_aborted() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -64,6 +72,68 @@ async fn interrupt_long_running_tool_emits_turn_aborted() {
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnAborted(_))).await;
 }
 
+/// A first ctrl-c notification aborts the running turn; a second one escalates
+/// to a shutdown so the embedder can force the process to exit.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn second_ctrl_c_escalates_to_shutdown() {
+    let args = json!({
+        "command": "sleep 60",
+        "timeout_ms": 60_000
+    })
+    .to_string();
+    let body = sse(vec![
+        ev_function_call("call_sleep", "shell_command", &args),
+        ev_completed("done"),
+    ]);
+
+    let server = start_mock_server().await;
+    mount_sse_once(&server, body).await;
+
+    let codex = test_codex()
+        .with_model("gpt-5.1")
+        .build(&server)
+        .await
+        .unwrap()
+        .codex;
+
+    let ctrl_c = Arc::new(Notify::new());
+    let escalation = tokio::spawn({
+        let codex = Arc::clone(&codex);
+        let ctrl_c = Arc::clone(&ctrl_c);
+        async move { escalate_ctrl_c(&codex, &ctrl_c).await }
+    });
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "start sleep".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await
+        .unwrap();
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::ExecCommandBegin(_))).await;
+
+    ctrl_c.notify_one();
+    let aborted = wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnAborted(_))).await;
+    let EventMsg::TurnAborted(aborted) = aborted else {
+        unreachable!()
+    };
+    assert_eq!(aborted.reason, TurnAbortReason::Interrupted);
+    assert!(!escalation.is_finished());
+
+    ctrl_c.notify_one();
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::ShutdownComplete)).await;
+    tokio::time::timeout(Duration::from_secs(5), escalation)
+        .await
+        .expect("escalation should finish after the second ctrl-c")
+        .expect("escalation task panicked")
+        .expect("shutdown should be submitted");
+}
+
 /// After an interrupt we expect the next request to the model to include both
 /// the original tool call and an `"aborted"` `function_call_output`. This test
 /// exercises the follow-up flow: it sends another user turn, inspects the mock
@@ -105,6 +175,8 @@ async fn interrupt_tool_records_history_entries() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -123,6 +195,8 @@ async fn interrupt_tool_records_history_entries() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -203,6 +277,8 @@ async fn interrupt_persists_turn_aborted_marker_in_next_request() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -221,6 +297,8 @@ async fn interrupt_persists_turn_aborted_marker_in_next_request() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -239,3 +317,65 @@ async fn interrupt_persists_turn_aborted_marker_in_next_request() {
         "expected <turn_aborted> marker in follow-up request"
     );
 }
+
+/// A turn that outlives `turn_deadline_seconds` is aborted with a
+/// `TurnDeadlineExceeded` error, even while a tool call is still running.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn turn_deadline_aborts_long_running_tool() {
+    let args = json!({
+        "command": "sleep 60",
+        "timeout_ms": 60_000
+    })
+    .to_string();
+    let body = sse(vec![
+        ev_response_created("resp-deadline"),
+        ev_function_call("call_deadline", "shell_command", &args),
+        ev_completed("resp-deadline"),
+    ]);
+
+    let server = start_mock_server().await;
+    mount_sse_once(&server, body).await;
+
+    let codex = test_codex()
+        .with_model("gpt-5.1")
+        .with_config(|config| {
+            config.turn_deadline_seconds = Some(1);
+        })
+        .build(&server)
+        .await
+        .unwrap()
+        .codex;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "start sleep".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await
+        .unwrap();
+
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::ExecCommandBegin(_))).await;
+
+    let error = wait_for_event(&codex, |ev| matches!(ev, EventMsg::Error(_))).await;
+    let EventMsg::Error(error) = error else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_eq!(
+        error.codex_error_info,
+        Some(CodexErrorInfo::TurnDeadlineExceeded)
+    );
+
+    let aborted = wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnAborted(_))).await;
+    assert_matches!(
+        aborted,
+        EventMsg::TurnAborted(TurnAbortedEvent {
+            reason: TurnAbortReason::Interrupted,
+            ..
+        })
+    );
+}
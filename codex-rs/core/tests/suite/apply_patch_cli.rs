@@ -11,6 +11,7 @@ use std::fs;
 use std::sync::atomic::AtomicI32;
 use std::sync::atomic::Ordering;
 
+use codex_core::CodexThread;
 use codex_core::features::Feature;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::EventMsg;
@@ -31,6 +32,7 @@ use core_test_support::test_codex::TestCodexBuilder;
 use core_test_support::test_codex::TestCodexHarness;
 use core_test_support::test_codex::test_codex;
 use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
 use serde_json::json;
 use test_case::test_case;
 use wiremock::Mock;
@@ -1168,6 +1170,56 @@ async fn apply_patch_emits_turn_diff_event_with_unified_diff(
     Ok(())
 }
 
+#[large_stack_test]
+#[test_case(ApplyPatchModelOutput::Freeform)]
+async fn reset_turn_diff_replies_with_empty_turn_diff(
+    model_output: ApplyPatchModelOutput,
+) -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let harness = apply_patch_harness().await?;
+    let test = harness.test();
+    let codex = test.codex.clone();
+    let cwd = test.cwd.clone();
+
+    let call_id = "apply-reset-diff";
+    let file = "reset.txt";
+    let patch = format!("*** Begin Patch\n*** Add File: {file}\n+hello\n*** End Patch\n");
+    mount_apply_patch(&harness, call_id, patch.as_str(), "ok", model_output).await;
+
+    let model = test.session_configured.model.clone();
+    codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "emit diff".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    codex.submit(Op::ResetTurnDiff).await?;
+    let diff = wait_for_event_match(&codex, |event| match event {
+        EventMsg::TurnDiff(ev) => Some(ev.unified_diff.clone()),
+        _ => None,
+    })
+    .await;
+
+    assert_eq!(diff, "");
+    assert_eq!(std::fs::read_to_string(cwd.path().join(file))?, "hello\n");
+    Ok(())
+}
+
 #[large_stack_test]
 #[test_case(ApplyPatchModelOutput::Freeform)]
 #[test_case(ApplyPatchModelOutput::Function)]
@@ -1422,3 +1474,270 @@ async fn apply_patch_change_context_disambiguates_target(
     assert_eq!(contents, "fn a\nx=10\ny=2\nfn b\nx=11\ny=20\n");
     Ok(())
 }
+
+#[large_stack_test]
+async fn apply_patch_dry_run_reports_events_without_writing() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let harness = apply_patch_harness_with(|builder| {
+        builder.with_config(|config| {
+            config.dry_run = true;
+        })
+    })
+    .await?;
+    let test = harness.test();
+    let codex = test.codex.clone();
+
+    let call_id = "apply-dry-run";
+    let patch = "*** Begin Patch\n*** Add File: created.txt\n+hello\n*** End Patch";
+    mount_apply_patch(
+        &harness,
+        call_id,
+        patch,
+        "done",
+        ApplyPatchModelOutput::Function,
+    )
+    .await;
+
+    let model = test.session_configured.model.clone();
+    codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "create a file".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: test.cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    let mut begin_dry_run = None;
+    let mut end_dry_run = None;
+    wait_for_event(&codex, |event| match event {
+        EventMsg::PatchApplyBegin(begin) => {
+            begin_dry_run = Some(begin.dry_run);
+            false
+        }
+        EventMsg::PatchApplyEnd(end) => {
+            assert!(end.success);
+            end_dry_run = Some(end.dry_run);
+            false
+        }
+        EventMsg::TurnComplete(_) => true,
+        _ => false,
+    })
+    .await;
+
+    assert_eq!(begin_dry_run, Some(true));
+    assert_eq!(end_dry_run, Some(true));
+    assert!(!harness.path("created.txt").exists());
+    let out = harness
+        .apply_patch_output(call_id, ApplyPatchModelOutput::Function)
+        .await;
+    assert!(out.contains("[dry-run] patch not applied"), "{out}");
+    Ok(())
+}
+
+#[large_stack_test]
+async fn apply_patch_warns_when_writing_gitignored_paths() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let harness = apply_patch_harness_with(|builder| {
+        builder.with_config(|config| {
+            config.warn_on_gitignored_patch_paths = true;
+        })
+    })
+    .await?;
+    let test = harness.test();
+    let codex = test.codex.clone();
+    let cwd = test.cwd.clone();
+
+    let git_init = std::process::Command::new("git")
+        .args(["init", "--quiet"])
+        .current_dir(cwd.path())
+        .status()?;
+    assert!(git_init.success(), "git init failed");
+    fs::write(cwd.path().join(".gitignore"), "build/\n")?;
+
+    let patch = "*** Begin Patch\n*** Add File: build/generated.txt\n+generated\n*** Add File: src.txt\n+source\n*** End Patch";
+    let call_id = "apply-gitignored";
+    mount_apply_patch(
+        &harness,
+        call_id,
+        patch,
+        "done",
+        ApplyPatchModelOutput::Function,
+    )
+    .await;
+
+    let model = test.session_configured.model.clone();
+    codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "write the generated file".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    let mut warnings = Vec::new();
+    wait_for_event(&codex, |event| match event {
+        EventMsg::Warning(warning) => {
+            warnings.push(warning.message.clone());
+            false
+        }
+        EventMsg::TurnComplete(_) => true,
+        _ => false,
+    })
+    .await;
+
+    assert_eq!(
+        warnings,
+        vec![
+            "apply_patch wrote files ignored by git; these changes will not show up in `git status`: build/generated.txt"
+                .to_string()
+        ]
+    );
+    // The warning is informational; the patch is still applied.
+    assert_eq!(
+        fs::read_to_string(cwd.path().join("build/generated.txt"))?,
+        "generated\n"
+    );
+    Ok(())
+}
+
+async fn hash_working_tree(codex: &CodexThread) -> String {
+    codex
+        .submit(Op::HashWorkingTree { paths: None })
+        .await
+        .expect("submit hash request");
+    wait_for_event_match(codex, |event| match event {
+        EventMsg::WorkingTreeHash(event) => Some(event.hash.clone()),
+        _ => None,
+    })
+    .await
+}
+
+#[large_stack_test]
+async fn hash_working_tree_changes_after_apply_patch() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let harness = apply_patch_harness().await?;
+    let test = harness.test();
+    let codex = test.codex.clone();
+    let cwd = test.cwd.clone();
+
+    let git_init = std::process::Command::new("git")
+        .args(["init", "--quiet"])
+        .current_dir(cwd.path())
+        .status()?;
+    assert!(git_init.success(), "git init failed");
+    fs::write(cwd.path().join("notes.txt"), "before\n")?;
+
+    let before = hash_working_tree(&codex).await;
+    assert_eq!(hash_working_tree(&codex).await, before);
+
+    let patch = "*** Begin Patch\n*** Update File: notes.txt\n@@\n-before\n+after\n*** End Patch";
+    mount_apply_patch(
+        &harness,
+        "apply-hash",
+        patch,
+        "done",
+        ApplyPatchModelOutput::Function,
+    )
+    .await;
+    harness.submit("update the notes").await?;
+    assert_eq!(fs::read_to_string(cwd.path().join("notes.txt"))?, "after\n");
+
+    assert_ne!(hash_working_tree(&codex).await, before);
+    Ok(())
+}
+
+#[large_stack_test]
+async fn exec_command_begin_summarizes_embedded_patch() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let harness = apply_patch_harness_with(|builder| builder.with_model("gpt-5.1")).await?;
+    let test = harness.test();
+    let codex = test.codex.clone();
+    let cwd = test.cwd.clone();
+
+    let added_lines = (1..=60).map(|n| format!("+line {n}\n")).collect::<String>();
+    // The leading `echo` keeps the script from being intercepted as an
+    // apply_patch call, so it runs as a regular shell command.
+    let script = format!(
+        "echo start && apply_patch <<'EOF'\n*** Begin Patch\n*** Add File: notes.txt\n{added_lines}*** End Patch\nEOF\n"
+    );
+    let call_id = "shell-embedded-patch";
+    let args = json!({ "command": script, "timeout_ms": 5_000 });
+    let bodies = vec![
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_function_call(call_id, "shell_command", &serde_json::to_string(&args)?),
+            ev_completed("resp-1"),
+        ]),
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-2"),
+        ]),
+    ];
+    mount_sse_sequence(harness.server(), bodies).await;
+
+    let model = test.session_configured.model.clone();
+    codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "apply patch via shell".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    let command = wait_for_event_match(&codex, |event| match event {
+        EventMsg::ExecCommandBegin(event) if event.call_id == call_id => {
+            Some(event.command.clone())
+        }
+        _ => None,
+    })
+    .await;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let displayed_script = command.last().expect("command should have a script");
+    assert!(
+        displayed_script.contains("*** Begin Patch\n[1 file, 61 lines omitted]\n*** End Patch"),
+        "expected summarized patch: {displayed_script}"
+    );
+    assert!(
+        !displayed_script.contains("+line 30"),
+        "patch body should not be shown: {displayed_script}"
+    );
+    Ok(())
+}
@@ -15,12 +15,14 @@ use codex_protocol::approvals::NetworkPolicyAmendment;
 use codex_protocol::approvals::NetworkPolicyRuleAction;
 use codex_protocol::protocol::ApplyPatchApprovalRequestEvent;
 use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::CodexErrorInfo;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::ExecApprovalRequestEvent;
 use codex_protocol::protocol::ExecPolicyAmendment;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::ReviewDecision;
 use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::protocol::SessionSource;
 use codex_protocol::user_input::UserInput;
 use core_test_support::responses::ev_apply_patch_function_call;
 use core_test_support::responses::ev_assistant_message;
@@ -2529,6 +2531,155 @@ allow_local_binding = true
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[cfg(target_os = "linux")]
+async fn confirm_before_network_prompts_and_proceeds_when_approved() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    Mock::given(method("GET"))
+        .and(path("/confirm-network"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("confirm-network-ok"))
+        .mount(&server)
+        .await;
+    let home = Arc::new(TempDir::new()?);
+    fs::write(
+        home.path().join("config.toml"),
+        r#"default_permissions = "workspace"
+
+[permissions.workspace.filesystem]
+":minimal" = "read"
+
+[permissions.workspace.network]
+enabled = true
+allow_local_binding = true
+confirm_before_access = true
+"#,
+    )?;
+    let approval_policy = AskForApproval::OnFailure;
+    let sandbox_policy = SandboxPolicy::WorkspaceWrite {
+        writable_roots: vec![],
+        read_only_access: Default::default(),
+        network_access: true,
+        exclude_tmpdir_env_var: false,
+        exclude_slash_tmp: false,
+    };
+    let sandbox_policy_for_config = sandbox_policy.clone();
+    let mut builder = test_codex().with_home(home).with_config(move |config| {
+        config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+        config.permissions.sandbox_policy = Constrained::allow_any(sandbox_policy_for_config);
+    });
+    let test = builder.build(&server).await?;
+    assert!(!test.config.managed_network_requirements_enabled());
+    assert!(test.config.managed_network_enforced());
+    let runtime_proxy = test
+        .session_configured
+        .network_proxy
+        .as_ref()
+        .expect("expected runtime managed network proxy addresses");
+    let (proxy_host, proxy_port) = runtime_proxy
+        .http_addr
+        .rsplit_once(':')
+        .expect("proxy address should include a port");
+
+    let call_id = "confirm-network";
+    let fetch_command = format!(
+        "python3 -c \"import http.client; conn = http.client.HTTPConnection('{proxy_host}', {proxy_port}, timeout=30); conn.request('GET', '{}/confirm-network'); print('OK:' + conn.getresponse().read().decode(errors='replace'))\"",
+        server.uri()
+    );
+    let event = shell_event(
+        call_id,
+        &fetch_command,
+        30_000,
+        SandboxPermissions::UseDefault,
+    )?;
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-confirm-network-1"),
+            event,
+            ev_completed("resp-confirm-network-1"),
+        ]),
+    )
+    .await;
+    let results = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-confirm-network-1", "done"),
+            ev_completed("resp-confirm-network-2"),
+        ]),
+    )
+    .await;
+
+    submit_turn(
+        &test,
+        "confirm-network",
+        approval_policy,
+        sandbox_policy.clone(),
+    )
+    .await?;
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(30);
+    let approval = loop {
+        let remaining = deadline
+            .checked_duration_since(std::time::Instant::now())
+            .expect("timed out waiting for network approval request");
+        let event = wait_for_event_with_timeout(
+            &test.codex,
+            |event| {
+                matches!(
+                    event,
+                    EventMsg::ExecApprovalRequest(_) | EventMsg::TurnComplete(_)
+                )
+            },
+            remaining,
+        )
+        .await;
+        match event {
+            EventMsg::ExecApprovalRequest(approval)
+                if approval.command.first().map(std::string::String::as_str)
+                    == Some("network-access") =>
+            {
+                break approval;
+            }
+            EventMsg::ExecApprovalRequest(approval) => {
+                panic!(
+                    "unexpected command approval request: {:?}",
+                    approval.command
+                );
+            }
+            EventMsg::TurnComplete(_) => {
+                panic!("expected network approval request before completion");
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+    };
+    let network_context = approval
+        .network_approval_context
+        .clone()
+        .expect("expected network approval context");
+    assert_eq!(network_context.host, "127.0.0.1");
+    assert_eq!(network_context.protocol, NetworkApprovalProtocol::Http);
+
+    test.codex
+        .submit(Op::ExecApproval {
+            id: approval.effective_approval_id(),
+            turn_id: None,
+            decision: ReviewDecision::Approved,
+        })
+        .await?;
+    wait_for_completion(&test).await;
+
+    let output = parse_result(&results.single_request().function_call_output(call_id));
+    assert!(
+        output.stdout.contains("OK:confirm-network-ok"),
+        "expected approved request to reach the server: {}",
+        output.stdout
+    );
+
+    Ok(())
+}
+
 // todo(dylan) add ScenarioSpec support for rules
 #[tokio::test(flavor = "current_thread")]
 #[cfg(unix)]
@@ -2598,3 +2749,564 @@ async fn compound_command_with_one_safe_command_still_requires_approval() -> Res
 
     Ok(())
 }
+
+#[tokio::test(flavor = "current_thread")]
+#[cfg(unix)]
+async fn reapproving_last_denied_command_runs_it() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let approval_policy = AskForApproval::OnRequest;
+    let sandbox_policy = SandboxPolicy::new_read_only_policy();
+    let sandbox_policy_for_config = sandbox_policy.clone();
+
+    let mut builder = test_codex().with_config(move |config| {
+        config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+        config.permissions.sandbox_policy = Constrained::allow_any(sandbox_policy_for_config);
+    });
+    let test = builder.build(&server).await?;
+
+    let target = TargetPath::Workspace("reapproved.txt");
+    let (path, _) = target.resolve_for_patch(&test);
+    let _ = fs::remove_file(&path);
+    let command = "printf reapproved > reapproved.txt";
+
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            shell_event(
+                "reapprove-call",
+                command,
+                5_000,
+                SandboxPermissions::RequireEscalated,
+            )?,
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "ok"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    submit_turn(&test, "write a file", approval_policy, sandbox_policy).await?;
+    let approval = expect_exec_approval(&test, command).await;
+    test.codex
+        .submit(Op::ExecApproval {
+            id: approval.effective_approval_id(),
+            turn_id: None,
+            decision: ReviewDecision::Denied,
+        })
+        .await?;
+    wait_for_completion(&test).await;
+    assert!(!path.exists(), "denied command should not run");
+
+    // The command is re-issued with its original escalation request, so it
+    // goes through approval again instead of running unsandboxed.
+    test.codex.submit(Op::ReapproveLastDenied).await?;
+    let approval = expect_exec_approval(&test, command).await;
+    test.codex
+        .submit(Op::ExecApproval {
+            id: approval.effective_approval_id(),
+            turn_id: None,
+            decision: ReviewDecision::Approved,
+        })
+        .await?;
+    let end = wait_for_event(&test.codex, |event| {
+        matches!(event, EventMsg::ExecCommandEnd(_))
+    })
+    .await;
+    let EventMsg::ExecCommandEnd(end) = end else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_eq!(end.exit_code, 0);
+    wait_for_completion(&test).await;
+    assert_eq!(fs::read_to_string(&path)?, "reapproved");
+
+    // The denial is consumed once it has been re-approved.
+    test.codex.submit(Op::ReapproveLastDenied).await?;
+    let error = wait_for_event(&test.codex, |event| matches!(event, EventMsg::Error(_))).await;
+    let EventMsg::Error(error) = error else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::BadRequest));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+#[cfg(unix)]
+async fn cancelling_approval_rejects_command_and_continues_turn() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let approval_policy = AskForApproval::OnRequest;
+    let sandbox_policy = SandboxPolicy::new_read_only_policy();
+    let sandbox_policy_for_config = sandbox_policy.clone();
+
+    let mut builder = test_codex().with_config(move |config| {
+        config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+        config.permissions.sandbox_policy = Constrained::allow_any(sandbox_policy_for_config);
+    });
+    let test = builder.build(&server).await?;
+
+    let target = TargetPath::Workspace("cancelled.txt");
+    let (path, _) = target.resolve_for_patch(&test);
+    let _ = fs::remove_file(&path);
+    let command = "printf cancelled > cancelled.txt";
+
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            shell_event(
+                "cancel-call",
+                command,
+                5_000,
+                SandboxPermissions::RequireEscalated,
+            )?,
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let follow_up = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "trying something else"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    submit_turn(&test, "write a file", approval_policy, sandbox_policy).await?;
+    let approval = expect_exec_approval(&test, command).await;
+    test.codex
+        .submit(Op::CancelApproval {
+            id: approval.effective_approval_id(),
+        })
+        .await?;
+
+    let end = wait_for_event(&test.codex, |event| {
+        matches!(event, EventMsg::TurnComplete(_) | EventMsg::TurnAborted(_))
+    })
+    .await;
+    assert!(
+        matches!(end, EventMsg::TurnComplete(_)),
+        "cancelling one approval should not abort the turn: {end:?}"
+    );
+    assert!(!path.exists(), "cancelled command should not run");
+
+    let output = follow_up
+        .single_request()
+        .function_call_output_text("cancel-call")
+        .expect("cancelled call should report an output to the model");
+    assert!(
+        output.contains("rejected by user"),
+        "unexpected output for cancelled call: {output}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+#[cfg(unix)]
+async fn near_simultaneous_exec_approvals_are_batched() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let approval_policy = AskForApproval::OnRequest;
+    let sandbox_policy = SandboxPolicy::new_read_only_policy();
+    let sandbox_policy_for_config = sandbox_policy.clone();
+
+    let mut builder = test_codex()
+        .with_session_source(SessionSource::Cli)
+        .with_config(move |config| {
+            config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+            config.permissions.sandbox_policy = Constrained::allow_any(sandbox_policy_for_config);
+            config.approval_batch_window_ms = Some(500);
+        });
+    let test = builder.build(&server).await?;
+
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            shell_event(
+                "batch-call-1",
+                "echo first",
+                5_000,
+                SandboxPermissions::RequireEscalated,
+            )?,
+            shell_event(
+                "batch-call-2",
+                "echo second",
+                5_000,
+                SandboxPermissions::RequireEscalated,
+            )?,
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let follow_up = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    submit_turn(&test, "run two commands", approval_policy, sandbox_policy).await?;
+    let event = wait_for_event(&test.codex, |event| {
+        matches!(
+            event,
+            EventMsg::ExecApprovalRequest(_) | EventMsg::ExecApprovalBatchRequest(_)
+        )
+    })
+    .await;
+    let EventMsg::ExecApprovalBatchRequest(batch) = event else {
+        panic!("expected a batched approval request, got {event:?}");
+    };
+    let mut commands: Vec<String> = batch
+        .requests
+        .iter()
+        .filter_map(|request| request.command.last().cloned())
+        .collect();
+    commands.sort();
+    assert_eq!(commands, vec!["echo first", "echo second"]);
+
+    test.codex
+        .submit(Op::ExecApprovalBatch {
+            batch_id: batch.batch_id,
+            decision: ReviewDecision::Approved,
+        })
+        .await?;
+    wait_for_completion(&test).await;
+
+    let request = follow_up.single_request();
+    for call_id in ["batch-call-1", "batch-call-2"] {
+        let output = request
+            .function_call_output_text(call_id)
+            .expect("approved call should report an output");
+        assert!(
+            !output.contains("rejected by user"),
+            "unexpected output for {call_id}: {output}"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+#[cfg(unix)]
+async fn exec_approvals_are_not_batched_outside_the_tui() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let approval_policy = AskForApproval::OnRequest;
+    let sandbox_policy = SandboxPolicy::new_read_only_policy();
+    let sandbox_policy_for_config = sandbox_policy.clone();
+
+    let mut builder = test_codex().with_config(move |config| {
+        config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+        config.permissions.sandbox_policy = Constrained::allow_any(sandbox_policy_for_config);
+        config.approval_batch_window_ms = Some(500);
+    });
+    let test = builder.build(&server).await?;
+
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            shell_event(
+                "single-call-1",
+                "echo first",
+                5_000,
+                SandboxPermissions::RequireEscalated,
+            )?,
+            shell_event(
+                "single-call-2",
+                "echo second",
+                5_000,
+                SandboxPermissions::RequireEscalated,
+            )?,
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    submit_turn(&test, "run two commands", approval_policy, sandbox_policy).await?;
+    for _ in 0..2 {
+        let event = wait_for_event(&test.codex, |event| {
+            matches!(
+                event,
+                EventMsg::ExecApprovalRequest(_) | EventMsg::ExecApprovalBatchRequest(_)
+            )
+        })
+        .await;
+        let EventMsg::ExecApprovalRequest(approval) = event else {
+            panic!("expected a single approval request, got {event:?}");
+        };
+        test.codex
+            .submit(Op::ExecApproval {
+                id: approval.effective_approval_id(),
+                turn_id: None,
+                decision: ReviewDecision::Approved,
+            })
+            .await?;
+    }
+    wait_for_completion(&test).await;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+#[cfg(unix)]
+async fn approved_unsandboxed_command_is_written_to_audit_log() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let approval_policy = AskForApproval::OnRequest;
+    let sandbox_policy = SandboxPolicy::new_read_only_policy();
+    let sandbox_policy_for_config = sandbox_policy.clone();
+
+    let mut builder = test_codex().with_config(move |config| {
+        config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+        config.permissions.sandbox_policy = Constrained::allow_any(sandbox_policy_for_config);
+        config.sandbox_bypass_audit_log = Some(config.codex_home.join("sandbox-audit.jsonl"));
+    });
+    let test = builder.build(&server).await?;
+    let audit_log = test.codex_home_path().join("sandbox-audit.jsonl");
+    let command = "echo audited";
+
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            shell_event(
+                "audit-call",
+                command,
+                5_000,
+                SandboxPermissions::RequireEscalated,
+            )?,
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "ok"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    submit_turn(&test, "run a command", approval_policy, sandbox_policy).await?;
+    let approval = expect_exec_approval(&test, command).await;
+    test.codex
+        .submit(Op::ExecApproval {
+            id: approval.effective_approval_id(),
+            turn_id: None,
+            decision: ReviewDecision::Approved,
+        })
+        .await?;
+    wait_for_completion(&test).await;
+
+    let contents = fs::read_to_string(&audit_log)?;
+    let entries: Vec<Value> = contents
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<std::result::Result<_, _>>()?;
+    assert_eq!(entries.len(), 1, "unexpected audit entries: {contents}");
+    let entry = &entries[0];
+    assert_eq!(entry["call_id"], "audit-call");
+    assert_eq!(entry["decision"], "approved");
+    assert_eq!(
+        entry["command"].as_array().and_then(|args| args.last()),
+        Some(&json!(command))
+    );
+    assert_eq!(entry["cwd"], json!(test.cwd.path()));
+    assert!(entry["timestamp"].is_string());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+#[cfg(unix)]
+async fn session_approval_does_not_cover_different_stdin() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let approval_policy = AskForApproval::OnRequest;
+    let sandbox_policy = SandboxPolicy::new_read_only_policy();
+    let sandbox_policy_for_config = sandbox_policy.clone();
+    let mut builder = test_codex()
+        .with_model("gpt-5.1-codex")
+        .with_config(move |config| {
+            config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+            config.permissions.sandbox_policy = Constrained::allow_any(sandbox_policy_for_config);
+        });
+    let test = builder.build(&server).await?;
+
+    let script = "cat > stdin.txt";
+    let path = test.cwd.path().join("stdin.txt");
+    let shell_with_stdin = |call_id: &str, stdin: &str| -> Result<Value> {
+        let args = json!({
+            "command": ["/bin/sh", "-c", script],
+            "timeout_ms": 5_000,
+            "sandbox_permissions": SandboxPermissions::RequireEscalated,
+            "stdin": stdin,
+        });
+        Ok(ev_function_call(
+            call_id,
+            "shell",
+            &serde_json::to_string(&args)?,
+        ))
+    };
+
+    for (turn, stdin) in ["first", "second", "first"].into_iter().enumerate() {
+        let _ = mount_sse_once(
+            &server,
+            sse(vec![
+                ev_response_created(&format!("resp-call-{turn}")),
+                shell_with_stdin(&format!("stdin-call-{turn}"), stdin)?,
+                ev_completed(&format!("resp-call-{turn}")),
+            ]),
+        )
+        .await;
+        let _ = mount_sse_once(
+            &server,
+            sse(vec![
+                ev_assistant_message(&format!("msg-{turn}"), "done"),
+                ev_completed(&format!("resp-done-{turn}")),
+            ]),
+        )
+        .await;
+
+        submit_turn(&test, "pipe input", approval_policy, sandbox_policy.clone()).await?;
+        if turn == 2 {
+            // Input that was already approved for the session is not asked
+            // about again.
+            wait_for_completion_without_approval(&test).await;
+        } else {
+            let approval = expect_exec_approval(&test, script).await;
+            assert_eq!(approval.stdin.as_deref(), Some(stdin));
+            test.codex
+                .submit(Op::ExecApproval {
+                    id: approval.effective_approval_id(),
+                    turn_id: None,
+                    decision: ReviewDecision::ApprovedForSession,
+                })
+                .await?;
+            wait_for_completion(&test).await;
+        }
+        assert_eq!(fs::read_to_string(&path)?, stdin);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+#[cfg(unix)]
+async fn persisted_session_approval_is_reused_by_new_session() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let home = Arc::new(TempDir::new()?);
+    let approval_policy = AskForApproval::OnRequest;
+    let sandbox_policy = SandboxPolicy::new_read_only_policy();
+    let persisting_builder = || {
+        let sandbox_policy_for_config = sandbox_policy.clone();
+        test_codex()
+            .with_home(Arc::clone(&home))
+            .with_config(move |config| {
+                config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+                config.permissions.sandbox_policy =
+                    Constrained::allow_any(sandbox_policy_for_config);
+                config.persist_approved_commands = true;
+            })
+    };
+    let command = "printf persisted > persisted.txt";
+    let mount_turn = |call_id: &'static str| {
+        let server = &server;
+        async move {
+            let _ = mount_sse_once(
+                server,
+                sse(vec![
+                    ev_response_created(&format!("resp-{call_id}")),
+                    shell_event(
+                        call_id,
+                        command,
+                        5_000,
+                        SandboxPermissions::RequireEscalated,
+                    )
+                    .expect("shell event"),
+                    ev_completed(&format!("resp-{call_id}")),
+                ]),
+            )
+            .await;
+            let _ = mount_sse_once(
+                server,
+                sse(vec![
+                    ev_assistant_message(&format!("msg-{call_id}"), "done"),
+                    ev_completed(&format!("resp-{call_id}-done")),
+                ]),
+            )
+            .await;
+        }
+    };
+
+    let first = persisting_builder().build(&server).await?;
+    let path = first.cwd.path().join("persisted.txt");
+    mount_turn("persist-first").await;
+    submit_turn(
+        &first,
+        "write a file",
+        approval_policy,
+        sandbox_policy.clone(),
+    )
+    .await?;
+    let approval = expect_exec_approval(&first, command).await;
+    first
+        .codex
+        .submit(Op::ExecApproval {
+            id: approval.effective_approval_id(),
+            turn_id: None,
+            decision: ReviewDecision::ApprovedForSession,
+        })
+        .await?;
+    wait_for_completion(&first).await;
+    assert_eq!(fs::read_to_string(&path)?, "persisted");
+    fs::remove_file(&path)?;
+
+    // A new session sharing the same codex home runs the command from the
+    // same directory without asking again.
+    let mut second = persisting_builder().build(&server).await?;
+    second.cwd = Arc::clone(&first.cwd);
+    mount_turn("persist-second").await;
+    submit_turn(
+        &second,
+        "write it again",
+        approval_policy,
+        sandbox_policy.clone(),
+    )
+    .await?;
+    wait_for_completion_without_approval(&second).await;
+    assert_eq!(fs::read_to_string(&path)?, "persisted");
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+#![allow(clippy::expect_used)]
+
+use anyhow::Result;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_message_item_added;
+use core_test_support::responses::ev_output_text_delta;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn oversized_assistant_output_is_truncated_and_turn_completes() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let chunk = "x".repeat(40);
+    let mut events = vec![
+        ev_response_created("resp-1"),
+        ev_message_item_added("msg-1", ""),
+    ];
+    events.extend((0..10).map(|_| ev_output_text_delta(&chunk)));
+    events.push(ev_assistant_message("msg-1", &chunk.repeat(10)));
+    events.push(ev_completed("resp-1"));
+    mount_sse_once(&server, sse(events)).await;
+
+    let mut builder = test_codex().with_config(|config| {
+        config.max_assistant_output_bytes = Some(100);
+    });
+    let test = builder.build(&server).await?;
+
+    test.codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "write a lot".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+
+    let mut streamed = String::new();
+    let mut warnings = Vec::new();
+    let last_agent_message = loop {
+        match wait_for_event(&test.codex, |_| true).await {
+            EventMsg::AgentMessageContentDelta(event) => streamed.push_str(&event.delta),
+            EventMsg::Warning(event) => warnings.push(event.message),
+            EventMsg::TurnComplete(event) => break event.last_agent_message,
+            _ => {}
+        }
+    };
+
+    assert_eq!(streamed, "x".repeat(100));
+    assert_eq!(warnings.len(), 1);
+    assert!(
+        warnings[0].contains("100-byte output limit"),
+        "unexpected warning: {}",
+        warnings[0]
+    );
+    assert_eq!(
+        last_agent_message,
+        Some(format!("{}\n\n[output truncated]", "x".repeat(100)))
+    );
+    Ok(())
+}
@@ -0,0 +1,106 @@
+#![allow(clippy::expect_used)]
+
+use anyhow::Result;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::TestCodex;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+
+async fn set_base_instructions(test: &TestCodex, instructions: Option<&str>) -> Result<String> {
+    test.codex
+        .submit(Op::SetBaseInstructions {
+            instructions: instructions.map(str::to_string),
+        })
+        .await?;
+    Ok(wait_for_event_match(&test.codex, |event| match event {
+        EventMsg::BaseInstructionsUpdated(event) => Some(event.instructions.clone()),
+        _ => None,
+    })
+    .await)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn set_base_instructions_applies_to_next_request() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let response = |id: &str| {
+        sse(vec![
+            ev_response_created(id),
+            ev_assistant_message(&format!("msg-{id}"), "done"),
+            ev_completed(id),
+        ])
+    };
+    let mock = mount_sse_sequence(
+        &server,
+        vec![response("resp-1"), response("resp-2"), response("resp-3")],
+    )
+    .await;
+    let test = test_codex().build(&server).await?;
+
+    test.submit_turn("first").await?;
+    let custom = "You are reviewing the payments service.";
+    assert_eq!(set_base_instructions(&test, Some(custom)).await?, custom);
+    test.submit_turn("second").await?;
+    let restored = set_base_instructions(&test, None).await?;
+    test.submit_turn("third").await?;
+
+    let instructions = mock
+        .requests()
+        .iter()
+        .map(|request| request.instructions_text())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        instructions,
+        vec![restored.clone(), custom.to_string(), restored]
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn set_base_instructions_survives_resume() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let response = |id: &str| {
+        sse(vec![
+            ev_response_created(id),
+            ev_assistant_message(&format!("msg-{id}"), "done"),
+            ev_completed(id),
+        ])
+    };
+    let mock = mount_sse_sequence(&server, vec![response("resp-1"), response("resp-2")]).await;
+    let mut builder = test_codex();
+    let test = builder.build(&server).await?;
+    let rollout_path = test
+        .session_configured
+        .rollout_path
+        .clone()
+        .expect("rollout path");
+
+    let custom = "You are reviewing the payments service.";
+    set_base_instructions(&test, Some(custom)).await?;
+    test.submit_turn("before resume").await?;
+
+    let resumed = builder
+        .resume(&server, test.home.clone(), rollout_path)
+        .await?;
+    resumed.submit_turn("after resume").await?;
+
+    let instructions = mock
+        .requests()
+        .iter()
+        .map(|request| request.instructions_text())
+        .collect::<Vec<_>>();
+    assert_eq!(instructions, vec![custom.to_string(), custom.to_string()]);
+    Ok(())
+}
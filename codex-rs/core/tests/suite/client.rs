@@ -8,6 +8,7 @@ use codex_core::ThreadManager;
 use codex_core::WireApi;
 use codex_core::auth::AuthCredentialsStoreMode;
 use codex_core::built_in_model_providers;
+use codex_core::config::types::ClientMetadata;
 use codex_core::default_client::originator;
 use codex_core::error::CodexErr;
 use codex_core::features::Feature;
@@ -19,6 +20,7 @@ use codex_protocol::config_types::CollaborationMode;
 use codex_protocol::config_types::ModeKind;
 use codex_protocol::config_types::ReasoningSummary;
 use codex_protocol::config_types::Settings;
+use codex_protocol::config_types::ToolChoice;
 use codex_protocol::config_types::Verbosity;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::FunctionCallOutputContentItem;
@@ -34,6 +36,7 @@ use codex_protocol::models::ResponseItem;
 use codex_protocol::models::WebSearchAction;
 use codex_protocol::openai_models::ModelsResponse;
 use codex_protocol::openai_models::ReasoningEffort;
+use codex_protocol::protocol::CodexErrorInfo;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::RolloutItem;
@@ -277,6 +280,8 @@ async fn resume_includes_initial_messages_and_sends_prior_items() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -636,6 +641,8 @@ async fn includes_conversation_id_and_model_headers_in_request() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -655,6 +662,68 @@ async fn includes_conversation_id_and_model_headers_in_request() {
     assert_eq!(request_authorization, "Bearer Test API Key");
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn includes_client_metadata_headers_in_request() {
+    skip_if_no_network!();
+
+    let server = MockServer::start().await;
+
+    let resp_mock = mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+
+    let mut builder = test_codex()
+        .with_auth(CodexAuth::from_api_key("Test API Key"))
+        .with_config(|config| {
+            config.client_metadata = Some(ClientMetadata {
+                user_agent_suffix: Some("acme-embedder/1.2.3".to_string()),
+                app_name: Some("acme".to_string()),
+                app_version: Some("1.2.3".to_string()),
+            });
+        });
+    let test = builder
+        .build(&server)
+        .await
+        .expect("create new conversation");
+    let codex = test.codex.clone();
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "hello".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await
+        .unwrap();
+
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let request = resp_mock.single_request();
+    let user_agent = request.header("user-agent").expect("user-agent header");
+    assert!(
+        user_agent.starts_with(&format!("{}/", originator().value)),
+        "expected Codex identifier to be kept: {user_agent}"
+    );
+    assert!(
+        user_agent.ends_with(" (acme-embedder/1.2.3)"),
+        "expected custom suffix: {user_agent}"
+    );
+    assert_eq!(
+        request.header("x-codex-client-app-name").as_deref(),
+        Some("acme")
+    );
+    assert_eq!(
+        request.header("x-codex-client-app-version").as_deref(),
+        Some("1.2.3")
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn includes_base_instructions_override_in_request() {
     skip_if_no_network!();
@@ -684,6 +753,8 @@ async fn includes_base_instructions_override_in_request() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -735,6 +806,8 @@ async fn chatgpt_auth_sends_correct_request() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -836,6 +909,8 @@ async fn prefers_apikey_when_config_prefers_apikey_even_with_chatgpt_tokens() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -872,6 +947,8 @@ async fn includes_user_instructions_message_in_request() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -920,6 +997,190 @@ async fn includes_user_instructions_message_in_request() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn reload_user_instructions_applies_edited_file_to_next_turn() {
+    skip_if_no_network!();
+    let server = MockServer::start().await;
+
+    let resp_mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+            sse(vec![ev_response_created("resp2"), ev_completed("resp2")]),
+        ],
+    )
+    .await;
+
+    let test = test_codex()
+        .with_auth(CodexAuth::from_api_key("Test API Key"))
+        .build(&server)
+        .await
+        .expect("create new conversation");
+    let codex = test.codex.clone();
+    let agents_path = test.codex_home_path().join("AGENTS.md");
+
+    let reload_and_submit = |text: &'static str| {
+        let codex = codex.clone();
+        async move {
+            codex.submit(Op::ReloadUserInstructions).await.unwrap();
+            wait_for_event(&codex, |ev| matches!(ev, EventMsg::BackgroundEvent(_))).await;
+            codex
+                .submit(Op::UserInput {
+                    items: vec![UserInput::Text {
+                        text: text.into(),
+                        text_elements: Vec::new(),
+                    }],
+                    final_output_json_schema: None,
+                    effort: None,
+                    tool_choice: None,
+                })
+                .await
+                .unwrap();
+            wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+        }
+    };
+
+    std::fs::write(&agents_path, "use tabs").unwrap();
+    reload_and_submit("first").await;
+    std::fs::write(&agents_path, "use spaces").unwrap();
+    reload_and_submit("second").await;
+
+    let requests = resp_mock.requests();
+    assert_eq!(requests.len(), 2);
+    let instruction_texts = |request_body: &serde_json::Value| -> Vec<String> {
+        request_body["input"]
+            .as_array()
+            .expect("input array")
+            .iter()
+            .filter(|item| item["type"] == "message")
+            .flat_map(message_input_texts)
+            .filter(|text| text.contains("<INSTRUCTIONS>"))
+            .map(str::to_string)
+            .collect()
+    };
+
+    let first = instruction_texts(&requests[0].body_json());
+    assert!(
+        first.iter().any(|text| text.contains("use tabs")),
+        "expected first instructions in first request, got {first:?}"
+    );
+    let second = instruction_texts(&requests[1].body_json());
+    assert!(
+        second
+            .last()
+            .is_some_and(|text| text.contains("use spaces") && !text.contains("use tabs")),
+        "expected reloaded instructions in second request, got {second:?}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn includes_initial_context_file_in_first_request() {
+    skip_if_no_network!();
+    let server = MockServer::start().await;
+
+    let resp_mock = mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+
+    let context_dir = TempDir::new().unwrap();
+    let context_path = context_dir.path().join("design.md");
+    std::fs::write(&context_path, "# Design\n\nUse the widget cache.\n").unwrap();
+
+    let mut builder = test_codex()
+        .with_auth(CodexAuth::from_api_key("Test API Key"))
+        .with_config(move |config| {
+            config.initial_context_file = Some(context_path);
+        });
+    let codex = builder
+        .build(&server)
+        .await
+        .expect("create new conversation")
+        .codex;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "hello".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await
+        .unwrap();
+
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let request = resp_mock.single_request();
+    let context_texts = request.message_input_texts("user");
+    let context_text = context_texts
+        .iter()
+        .find(|text| text.starts_with("<context_file>"))
+        .unwrap_or_else(|| panic!("expected context file message, got {context_texts:?}"));
+    assert!(context_text.contains("Use the widget cache."));
+    assert!(context_text.trim_end().ends_with("</context_file>"));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn includes_platform_context_when_enabled() {
+    skip_if_no_network!();
+    let server = MockServer::start().await;
+
+    let resp_mock = mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+
+    let mut builder = test_codex()
+        .with_auth(CodexAuth::from_api_key("Test API Key"))
+        .with_config(|config| {
+            config.include_platform_context = true;
+        });
+    let codex = builder
+        .build(&server)
+        .await
+        .expect("create new conversation")
+        .codex;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "hello".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await
+        .unwrap();
+
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let request = resp_mock.single_request();
+    let user_texts = request.message_input_texts("user");
+    let environment_context = user_texts
+        .iter()
+        .find(|text| text.starts_with("<environment_context>"))
+        .unwrap_or_else(|| panic!("expected environment context, got {user_texts:?}"));
+    assert!(environment_context.contains("<platform>"));
+    assert!(environment_context.contains(&format!(
+        "<arch>{}</arch>",
+        os_info::get()
+            .architecture()
+            .unwrap_or(std::env::consts::ARCH)
+    )));
+    assert!(environment_context.contains(&format!(
+        "<path_separator>{}</path_separator>",
+        std::path::MAIN_SEPARATOR
+    )));
+    assert!(environment_context.contains("<default_shell>"));
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn includes_apps_guidance_as_developer_message_for_chatgpt_auth() {
     skip_if_no_network!();
@@ -961,6 +1222,8 @@ async fn includes_apps_guidance_as_developer_message_for_chatgpt_auth() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1052,6 +1315,8 @@ async fn omits_apps_guidance_for_api_key_auth_even_when_feature_enabled() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1121,6 +1386,8 @@ async fn skills_append_to_instructions() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1178,6 +1445,8 @@ async fn includes_configured_effort_in_request() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1198,6 +1467,172 @@ async fn includes_configured_effort_in_request() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn user_input_effort_override_applies_to_single_turn() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+    let server = MockServer::start().await;
+
+    let first_mock = mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+    let second_mock = mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp2"), ev_completed("resp2")]),
+    )
+    .await;
+    let TestCodex { codex, .. } = test_codex()
+        .with_model("gpt-5.1-codex")
+        .with_config(|config| {
+            config.model_reasoning_effort = Some(ReasoningEffort::Medium);
+        })
+        .build(&server)
+        .await?;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "think hard".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: Some(ReasoningEffort::High),
+            tool_choice: None,
+        })
+        .await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "back to normal".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let effort = |body: &serde_json::Value| {
+        body.get("reasoning")
+            .and_then(|t| t.get("effort"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+    assert_eq!(
+        effort(&first_mock.single_request().body_json()),
+        Some("high".to_string())
+    );
+    assert_eq!(
+        effort(&second_mock.single_request().body_json()),
+        Some("medium".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn user_input_tool_choice_applies_to_single_turn() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+    let server = MockServer::start().await;
+
+    let first_mock = mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+    let second_mock = mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp2"), ev_completed("resp2")]),
+    )
+    .await;
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "answer without tools".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: Some(ToolChoice::None),
+        })
+        .await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "back to normal".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    assert_eq!(
+        first_mock.single_request().body_json()["tool_choice"],
+        serde_json::json!("none")
+    );
+    assert_eq!(
+        second_mock.single_request().body_json()["tool_choice"],
+        serde_json::json!("auto")
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn user_input_tool_choice_rejects_unknown_tool() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+    let server = MockServer::start().await;
+
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "use a tool that does not exist".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: Some(ToolChoice::Tool("no_such_tool".to_string())),
+        })
+        .await?;
+    // The op is rejected before a turn starts.
+    let event = wait_for_event(&codex, |ev| {
+        matches!(ev, EventMsg::Error(_) | EventMsg::TurnStarted(_))
+    })
+    .await;
+    let EventMsg::Error(error) = event else {
+        panic!("expected the op to be rejected, got {event:?}");
+    };
+    assert_eq!(
+        error.message,
+        "tool_choice names unknown tool `no_such_tool`"
+    );
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::BadRequest));
+    assert!(
+        server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .iter()
+            .all(|request| !request.url.path().ends_with("/responses")),
+        "no model request should be sent for an unknown tool"
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn includes_no_effort_in_request() -> anyhow::Result<()> {
     skip_if_no_network!(Ok(()));
@@ -1220,6 +1655,8 @@ async fn includes_no_effort_in_request() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1260,6 +1697,8 @@ async fn includes_default_reasoning_effort_in_request_when_defined_by_model_info
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1371,6 +1810,8 @@ async fn configured_reasoning_summary_is_sent() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1485,6 +1926,8 @@ async fn reasoning_summary_is_omitted_when_disabled() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1541,6 +1984,8 @@ async fn reasoning_summary_none_overrides_model_catalog_default() -> anyhow::Res
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1577,6 +2022,8 @@ async fn includes_default_verbosity_in_request() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1622,6 +2069,8 @@ async fn configured_verbosity_not_sent_for_models_without_support() -> anyhow::R
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1666,6 +2115,8 @@ async fn configured_verbosity_is_sent() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1686,6 +2137,49 @@ async fn configured_verbosity_is_sent() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn configured_max_output_tokens_is_sent() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+    let server = MockServer::start().await;
+
+    let resp_mock = mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+    let TestCodex { codex, .. } = test_codex()
+        .with_config(|config| {
+            config.max_output_tokens = Some(512);
+        })
+        .build(&server)
+        .await?;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "hello".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await
+        .unwrap();
+
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let request_body = resp_mock.single_request().body_json();
+    assert_eq!(
+        request_body
+            .get("max_output_tokens")
+            .and_then(|v| v.as_u64()),
+        Some(512)
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn includes_developer_instructions_message_in_request() {
     skip_if_no_network!();
@@ -1715,6 +2209,8 @@ async fn includes_developer_instructions_message_in_request() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1844,6 +2340,7 @@ async fn azure_responses_request_includes_store_and_reasoning_ids() {
         false,
         false,
         None,
+        None,
     );
     let mut client_session = client.new_session();
 
@@ -1996,6 +2493,8 @@ async fn token_count_includes_rate_limits_snapshot() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2161,6 +2660,8 @@ async fn usage_limit_error_emits_rate_limit_event() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submission should succeed while emitting usage limit error events");
@@ -2235,6 +2736,8 @@ async fn context_window_error_sets_total_tokens_to_model_window() -> anyhow::Res
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -2247,6 +2750,8 @@ async fn context_window_error_sets_total_tokens_to_model_window() -> anyhow::Res
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -2329,6 +2834,8 @@ async fn incomplete_response_emits_content_filter_error_message() -> anyhow::Res
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -2426,6 +2933,8 @@ async fn azure_overrides_assign_properties_used_for_responses_url() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2510,6 +3019,8 @@ async fn env_var_overrides_loaded_auth() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2571,6 +3082,8 @@ async fn history_dedupes_streamed_and_final_messages_across_turns() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2584,6 +3097,8 @@ async fn history_dedupes_streamed_and_final_messages_across_turns() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2597,6 +3112,8 @@ async fn history_dedupes_streamed_and_final_messages_across_turns() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
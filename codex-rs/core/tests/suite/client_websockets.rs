@@ -837,6 +837,8 @@ async fn responses_websocket_usage_limit_error_emits_rate_limit_event() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submission should succeed while emitting usage limit error events");
@@ -922,6 +924,8 @@ async fn responses_websocket_invalid_request_error_with_status_is_forwarded() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submission should succeed while emitting invalid request events");
@@ -1607,6 +1611,7 @@ async fn websocket_harness_with_options(
         false,
         runtime_metrics_enabled,
         None,
+        None,
     );
 
     WebsocketTestHarness {
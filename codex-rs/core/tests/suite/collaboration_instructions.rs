@@ -83,6 +83,8 @@ async fn no_collaboration_instructions_by_default() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -132,6 +134,8 @@ async fn user_input_includes_collaboration_instructions_after_override() -> Resu
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -228,6 +232,8 @@ async fn override_then_next_turn_uses_updated_collaboration_instructions() -> Re
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -348,6 +354,8 @@ async fn collaboration_mode_update_emits_new_instruction_message() -> Result<()>
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -374,6 +382,8 @@ async fn collaboration_mode_update_emits_new_instruction_message() -> Result<()>
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -429,6 +439,8 @@ async fn collaboration_mode_update_noop_does_not_append() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -455,6 +467,8 @@ async fn collaboration_mode_update_noop_does_not_append() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -512,6 +526,8 @@ async fn collaboration_mode_update_emits_new_instruction_message_when_mode_chang
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -541,6 +557,8 @@ async fn collaboration_mode_update_emits_new_instruction_message_when_mode_chang
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -599,6 +617,8 @@ async fn collaboration_mode_update_noop_does_not_append_when_mode_is_unchanged()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -628,6 +648,8 @@ async fn collaboration_mode_update_noop_does_not_append_when_mode_is_unchanged()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -690,6 +712,8 @@ async fn resume_replays_collaboration_instructions() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&initial.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -703,6 +727,8 @@ async fn resume_replays_collaboration_instructions() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&resumed.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -758,6 +784,8 @@ async fn empty_collaboration_instructions_are_ignored() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -240,6 +240,8 @@ async fn summarize_context_three_requests_and_instructions() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -262,6 +264,8 @@ async fn summarize_context_three_requests_and_instructions() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -438,6 +442,8 @@ async fn manual_compact_uses_custom_prompt() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submit first user turn");
@@ -494,6 +500,84 @@ async fn manual_compact_uses_custom_prompt() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn manual_compact_restates_initial_context_file() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+    let first_turn = sse(vec![
+        ev_assistant_message("m0", FIRST_REPLY),
+        ev_completed_with_tokens("r0", 80),
+    ]);
+    let compact_turn = sse(vec![
+        ev_assistant_message("m1", SUMMARY_TEXT),
+        ev_completed_with_tokens("r1", 100),
+    ]);
+    let follow_up_turn = sse(vec![
+        ev_assistant_message("m2", FINAL_REPLY),
+        ev_completed_with_tokens("r2", 120),
+    ]);
+    let request_log =
+        mount_sse_sequence(&server, vec![first_turn, compact_turn, follow_up_turn]).await;
+
+    let context_dir = tempfile::TempDir::new().expect("create context dir");
+    let context_path = context_dir.path().join("design.md");
+    std::fs::write(&context_path, "# Design\n\nUse the widget cache.\n")
+        .expect("write context file");
+
+    let model_provider = non_openai_model_provider(&server);
+    let mut builder = test_codex().with_config(move |config| {
+        config.model_provider = model_provider;
+        set_test_compact_prompt(config);
+        config.initial_context_file = Some(context_path);
+    });
+    let codex = builder
+        .build(&server)
+        .await
+        .expect("create conversation")
+        .codex;
+
+    let user_turn = |text: &str| Op::UserInput {
+        items: vec![UserInput::Text {
+            text: text.to_string(),
+            text_elements: Vec::new(),
+        }],
+        final_output_json_schema: None,
+        effort: None,
+        tool_choice: None,
+    };
+    codex
+        .submit(user_turn("USER_ONE"))
+        .await
+        .expect("submit first user turn");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+    codex.submit(Op::Compact).await.expect("trigger compact");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+    codex
+        .submit(user_turn(THIRD_USER_MSG))
+        .await
+        .expect("submit follow-up user turn");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let requests = request_log.requests();
+    assert_eq!(
+        requests.len(),
+        3,
+        "expected two turns and a compact request"
+    );
+    let context_files = requests[2]
+        .message_input_texts("user")
+        .into_iter()
+        .filter(|text| text.starts_with("<context_file>"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        context_files.len(),
+        1,
+        "expected the context file once after compaction, got {context_files:?}"
+    );
+    assert!(context_files[0].contains("Use the widget cache."));
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn manual_compact_emits_api_and_local_token_usage_events() {
     skip_if_no_network!();
@@ -582,6 +666,8 @@ async fn manual_compact_emits_context_compaction_items() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -745,6 +831,8 @@ async fn multiple_auto_compact_per_task_runs_after_token_limit_hit() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submit user input");
@@ -1244,6 +1332,8 @@ async fn auto_compact_runs_after_token_limit_hit() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1257,6 +1347,8 @@ async fn auto_compact_runs_after_token_limit_hit() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1270,6 +1362,8 @@ async fn auto_compact_runs_after_token_limit_hit() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1438,6 +1532,8 @@ async fn auto_compact_emits_context_compaction_items() {
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             })
             .await
             .unwrap();
@@ -1516,6 +1612,8 @@ async fn auto_compact_starts_after_turn_started() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1528,6 +1626,8 @@ async fn auto_compact_starts_after_turn_started() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -1540,6 +1640,8 @@ async fn auto_compact_starts_after_turn_started() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2027,6 +2129,8 @@ async fn auto_compact_persists_rollout_entries() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2039,6 +2143,8 @@ async fn auto_compact_persists_rollout_entries() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2051,6 +2157,8 @@ async fn auto_compact_persists_rollout_entries() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2137,6 +2245,8 @@ async fn manual_compact_retries_after_context_window_error() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2248,6 +2358,8 @@ async fn manual_compact_non_context_failure_retries_then_emits_task_error() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submit user input");
@@ -2340,6 +2452,8 @@ async fn manual_compact_twice_preserves_latest_user_messages() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2355,6 +2469,8 @@ async fn manual_compact_twice_preserves_latest_user_messages() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2370,6 +2486,8 @@ async fn manual_compact_twice_preserves_latest_user_messages() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2531,6 +2649,8 @@ async fn auto_compact_allows_multiple_attempts_when_interleaved_with_other_turn_
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             })
             .await
             .unwrap();
@@ -2633,6 +2753,8 @@ async fn snapshot_request_shape_mid_turn_continuation_compaction() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -2691,6 +2813,62 @@ async fn snapshot_request_shape_mid_turn_continuation_compaction() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn mid_turn_compaction_preserves_pending_tool_call_when_enabled() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+
+    let context_window = 100;
+    let limit = context_window * 90 / 100;
+    let over_limit_tokens = context_window * 95 / 100 + 1;
+
+    let first_turn = sse(vec![
+        ev_function_call(DUMMY_CALL_ID, DUMMY_FUNCTION_NAME, "{}"),
+        ev_completed_with_tokens("r1", over_limit_tokens),
+    ]);
+    let auto_compact_turn = sse(vec![
+        ev_assistant_message("m2", &auto_summary(AUTO_SUMMARY_TEXT)),
+        ev_completed_with_tokens("r2", 10),
+    ]);
+    let post_auto_compact_turn = sse(vec![
+        ev_assistant_message("m3", FINAL_REPLY),
+        ev_completed_with_tokens("r3", 10),
+    ]);
+    mount_sse_once(&server, first_turn).await;
+    mount_sse_once(&server, auto_compact_turn).await;
+    let post_auto_compact_mock = mount_sse_once(&server, post_auto_compact_turn).await;
+
+    let model_provider = non_openai_model_provider(&server);
+    let mut builder = test_codex().with_config(move |config| {
+        config.model_provider = model_provider;
+        set_test_compact_prompt(config);
+        config.model_context_window = Some(context_window);
+        config.model_auto_compact_token_limit = Some(limit);
+        config.compact_preserve_pending_tool_call = true;
+    });
+    let codex = builder.build(&server).await.unwrap();
+
+    codex.submit_turn(FUNCTION_CALL_LIMIT_MSG).await.unwrap();
+
+    let request = post_auto_compact_mock.single_request();
+    assert!(
+        request.has_function_call(DUMMY_CALL_ID),
+        "the pending tool call should survive compaction"
+    );
+    let output = request
+        .function_call_output_text(DUMMY_CALL_ID)
+        .expect("the pending tool call output should survive compaction");
+    assert!(
+        output.contains(DUMMY_FUNCTION_NAME),
+        "unexpected tool output after compaction: {output}"
+    );
+    assert!(
+        body_contains_text(&request.body_json().to_string(), AUTO_SUMMARY_TEXT),
+        "the continuation request should still carry the summary"
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn auto_compact_clamps_config_limit_to_context_window() {
     skip_if_no_network!();
@@ -2749,6 +2927,53 @@ async fn auto_compact_clamps_config_limit_to_context_window() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn disable_auto_compact_skips_compaction_over_limit() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+
+    let context_window = 100;
+    let over_limit_tokens = context_window * 90 / 100 + 1;
+
+    let first_turn = sse(vec![
+        ev_assistant_message("m1", FIRST_REPLY),
+        ev_completed_with_tokens("r1", over_limit_tokens),
+    ]);
+    let second_turn = sse(vec![
+        ev_assistant_message("m2", "still going"),
+        ev_completed_with_tokens("r2", over_limit_tokens),
+    ]);
+
+    mount_sse_once(&server, first_turn).await;
+    let second_turn_mock = mount_sse_once(&server, second_turn).await;
+
+    let model_provider = non_openai_model_provider(&server);
+    let mut builder = test_codex().with_config(move |config| {
+        config.model_provider = model_provider;
+        set_test_compact_prompt(config);
+        config.model_context_window = Some(context_window);
+        config.disable_auto_compact = true;
+    });
+    let codex = builder.build(&server).await.unwrap();
+
+    codex.submit_turn("OVER_LIMIT_TURN").await.unwrap();
+    codex
+        .submit_turn("FOLLOW_UP_WITHOUT_COMPACT")
+        .await
+        .unwrap();
+
+    let second_body = second_turn_mock.single_request().body_json().to_string();
+    assert!(
+        body_contains_text(&second_body, "FOLLOW_UP_WITHOUT_COMPACT"),
+        "the follow-up turn should be sent directly"
+    );
+    assert!(
+        !body_contains_text(&second_body, SUMMARIZATION_PROMPT),
+        "auto compact should not run when disabled"
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn auto_compact_counts_encrypted_reasoning_before_last_user() {
     skip_if_no_network!();
@@ -2827,6 +3052,8 @@ async fn auto_compact_counts_encrypted_reasoning_before_last_user() {
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             })
             .await
             .unwrap();
@@ -2944,6 +3171,8 @@ async fn auto_compact_runs_when_reasoning_header_clears_between_turns() {
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             })
             .await
             .unwrap();
@@ -3003,6 +3232,8 @@ async fn snapshot_request_shape_pre_turn_compaction_including_incoming_user_mess
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             })
             .await
             .expect("submit user input");
@@ -3037,6 +3268,8 @@ async fn snapshot_request_shape_pre_turn_compaction_including_incoming_user_mess
                 },
             ],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submit user input");
@@ -3247,6 +3480,8 @@ async fn snapshot_request_shape_pre_turn_compaction_context_window_exceeded() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submit first user");
@@ -3259,6 +3494,8 @@ async fn snapshot_request_shape_pre_turn_compaction_context_window_exceeded() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submit second user");
@@ -3329,6 +3566,8 @@ async fn snapshot_request_shape_manual_compact_without_previous_user_messages()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .expect("submit follow-up user input");
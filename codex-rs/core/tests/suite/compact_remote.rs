@@ -234,6 +234,8 @@ async fn remote_compact_replaces_history_for_followups() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -248,6 +250,8 @@ async fn remote_compact_replaces_history_for_followups() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -378,6 +382,8 @@ async fn remote_compact_runs_automatically() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -452,6 +458,8 @@ async fn remote_compact_trims_function_call_history_to_fit_context_window() -> R
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -463,6 +471,8 @@ async fn remote_compact_trims_function_call_history_to_fit_context_window() -> R
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -572,6 +582,8 @@ async fn auto_remote_compact_trims_function_call_history_to_fit_context_window()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -583,6 +595,8 @@ async fn auto_remote_compact_trims_function_call_history_to_fit_context_window()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -600,6 +614,8 @@ async fn auto_remote_compact_trims_function_call_history_to_fit_context_window()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -697,6 +713,8 @@ async fn auto_remote_compact_failure_stops_agent_loop() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -708,6 +726,8 @@ async fn auto_remote_compact_failure_stops_agent_loop() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -799,6 +819,8 @@ async fn remote_compact_trim_estimate_uses_session_base_instructions() -> Result
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&baseline_codex, |event| {
@@ -813,6 +835,8 @@ async fn remote_compact_trim_estimate_uses_session_base_instructions() -> Result
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&baseline_codex, |event| {
@@ -898,6 +922,8 @@ async fn remote_compact_trim_estimate_uses_session_base_instructions() -> Result
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&override_codex, |event| {
@@ -912,6 +938,8 @@ async fn remote_compact_trim_estimate_uses_session_base_instructions() -> Result
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&override_codex, |event| {
@@ -980,6 +1008,8 @@ async fn remote_manual_compact_emits_context_compaction_items() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -1058,6 +1088,8 @@ async fn remote_manual_compact_failure_emits_task_error_event() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -1140,6 +1172,8 @@ async fn remote_compact_persists_replacement_history_in_rollout() -> Result<()>
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1281,6 +1315,8 @@ async fn remote_compact_and_resume_refresh_stale_developer_instructions() -> Res
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&initial.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1296,6 +1332,8 @@ async fn remote_compact_and_resume_refresh_stale_developer_instructions() -> Res
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&initial.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1318,6 +1356,8 @@ async fn remote_compact_and_resume_refresh_stale_developer_instructions() -> Res
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&resumed.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1412,6 +1452,8 @@ async fn remote_compact_refreshes_stale_developer_instructions_without_resume()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1426,6 +1468,8 @@ async fn remote_compact_refreshes_stale_developer_instructions_without_resume()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1495,6 +1539,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_restates_realtime_sta
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1506,6 +1552,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_restates_realtime_sta
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1570,6 +1618,8 @@ async fn remote_request_uses_custom_experimental_realtime_start_instructions() -
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1628,6 +1678,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_restates_realtime_end
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1641,6 +1693,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_restates_realtime_end
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1713,6 +1767,8 @@ async fn snapshot_request_shape_remote_manual_compact_restates_realtime_start()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1727,6 +1783,8 @@ async fn snapshot_request_shape_remote_manual_compact_restates_realtime_start()
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1807,6 +1865,8 @@ async fn snapshot_request_shape_remote_mid_turn_compaction_does_not_restate_real
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1820,6 +1880,8 @@ async fn snapshot_request_shape_remote_mid_turn_compaction_does_not_restate_real
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1908,6 +1970,8 @@ async fn snapshot_request_shape_remote_compact_resume_restates_realtime_end() ->
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&initial.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -1935,6 +1999,8 @@ async fn snapshot_request_shape_remote_compact_resume_restates_realtime_end() ->
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&resumed.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2027,6 +2093,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_including_incoming_us
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             })
             .await?;
         wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2111,6 +2179,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_strips_incoming_model
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2136,6 +2206,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_strips_incoming_model
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2252,6 +2324,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_context_window_exceed
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2263,6 +2337,8 @@ async fn snapshot_request_shape_remote_pre_turn_compaction_context_window_exceed
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     let error_message = wait_for_event_match(&codex, |event| match event {
@@ -2345,6 +2421,8 @@ async fn snapshot_request_shape_remote_mid_turn_continuation_compaction() -> Res
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2420,6 +2498,8 @@ async fn snapshot_request_shape_remote_mid_turn_compaction_summary_only_reinject
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2503,6 +2583,8 @@ async fn snapshot_request_shape_remote_mid_turn_compaction_multi_summary_reinjec
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2517,6 +2599,8 @@ async fn snapshot_request_shape_remote_mid_turn_compaction_multi_summary_reinjec
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -2597,6 +2681,8 @@ async fn snapshot_request_shape_remote_manual_compact_without_previous_user_mess
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
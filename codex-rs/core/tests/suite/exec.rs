@@ -43,6 +43,7 @@ async fn run_test_cmd(tmp: TempDir, cmd: Vec<&str>) -> Result<ExecToolCallOutput
         windows_sandbox_level: WindowsSandboxLevel::Disabled,
         justification: None,
         arg0: None,
+        stdin: None,
     };
 
     let policy = SandboxPolicy::new_read_only_policy();
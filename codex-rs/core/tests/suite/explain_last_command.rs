@@ -0,0 +1,84 @@
+use codex_protocol::protocol::CodexErrorInfo;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn explain_last_command_errors_when_nothing_has_run() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+    let codex = test_codex().build(&server).await.unwrap().codex;
+
+    codex.submit(Op::ExplainLastCommand).await.unwrap();
+    let event = wait_for_event(&codex, |event| matches!(event, EventMsg::Error(_))).await;
+    let EventMsg::Error(error) = event else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::BadRequest));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn explain_last_command_asks_model_without_tools() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+    let codex = test_codex().build(&server).await.unwrap().codex;
+
+    let marker = "explain-me-please";
+    codex
+        .submit(Op::RunUserShellCommand {
+            command: format!("echo {marker}"),
+        })
+        .await
+        .unwrap();
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let explanation = format!("`echo {marker}` printed {marker} to standard output.");
+    let mock = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_assistant_message("msg-1", &explanation),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+
+    codex.submit(Op::ExplainLastCommand).await.unwrap();
+    let event = wait_for_event(&codex, |event| matches!(event, EventMsg::AgentMessage(_))).await;
+    let EventMsg::AgentMessage(message) = event else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_eq!(message.message, explanation);
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let request = mock.single_request();
+    let user_texts = request.message_input_texts("user");
+    let explain_request = user_texts.last().expect("explanation request");
+    assert!(
+        explain_request.contains(&format!("echo {marker}")),
+        "explanation request should quote the command: {explain_request}"
+    );
+    assert!(
+        explain_request.contains("Exit code: 0"),
+        "explanation request should include the exit code: {explain_request}"
+    );
+    let body = request.body_json();
+    assert!(
+        body.get("tools")
+            .and_then(Value::as_array)
+            .is_none_or(Vec::is_empty),
+        "no tools should be offered: {body}"
+    );
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::expect_used)]
+
+use anyhow::Result;
+use codex_core::config::types::AutoAttachFiles;
+use codex_core::config::types::AutoAttachFilesToml;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn mentioned_file_is_attached_to_the_turn() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mock = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let mut builder = test_codex().with_config(|config| {
+        config.auto_attach_files = Some(AutoAttachFiles::from(AutoAttachFilesToml::default()));
+    });
+    let test = builder.build(&server).await?;
+    std::fs::write(
+        test.cwd_path().join("notes.md"),
+        "the release is blocked on the migration",
+    )?;
+
+    test.submit_turn("summarize @notes.md and @missing.md")
+        .await?;
+
+    let attachments = mock
+        .single_request()
+        .message_input_texts("user")
+        .into_iter()
+        .filter(|text| text.starts_with("<attached_file>"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        attachments.len(),
+        1,
+        "unexpected attachments: {attachments:?}"
+    );
+    assert!(attachments[0].contains("notes.md"));
+    assert!(attachments[0].contains("the release is blocked on the migration"));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn files_are_not_attached_unless_enabled() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mock = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let test = test_codex().build(&server).await?;
+    std::fs::write(test.cwd_path().join("notes.md"), "private notes")?;
+
+    test.submit_turn("summarize @notes.md").await?;
+
+    let request = mock.single_request();
+    assert!(
+        request
+            .message_input_texts("user")
+            .iter()
+            .all(|text| !text.contains("private notes"))
+    );
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_function_call;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn turn_ending_on_tool_calls_requests_a_final_message() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let plan_args = json!({
+        "plan": [{"step": "Check the workspace", "status": "completed"}],
+    })
+    .to_string();
+    let mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_function_call("call-1", "update_plan", &plan_args),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![ev_response_created("resp-2"), ev_completed("resp-2")]),
+            sse(vec![
+                ev_response_created("resp-3"),
+                ev_assistant_message("msg-1", "Updated the plan."),
+                ev_completed("resp-3"),
+            ]),
+        ],
+    )
+    .await;
+
+    let codex = test_codex()
+        .with_config(|config| {
+            config.request_final_message_after_tools = true;
+        })
+        .build(&server)
+        .await?
+        .codex;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "update the plan".to_string(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+    let event = wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+    let EventMsg::TurnComplete(turn_complete) = event else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_eq!(
+        turn_complete.last_agent_message.as_deref(),
+        Some("Updated the plan.")
+    );
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    let developer_texts = requests[2].message_input_texts("developer");
+    assert!(
+        developer_texts
+            .iter()
+            .any(|text| text.contains("without replying to the user")),
+        "model should be asked for a final message: {developer_texts:?}"
+    );
+
+    Ok(())
+}
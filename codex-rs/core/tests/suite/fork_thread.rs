@@ -52,6 +52,8 @@ async fn fork_thread_twice_drops_to_first_message() {
                     text_elements: Vec::new(),
                 }],
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             })
             .await
             .unwrap();
@@ -149,3 +151,167 @@ async fn fork_thread_twice_drops_to_first_message() {
         serde_json::to_value(&expected_after_second).unwrap()
     );
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn fork_session_op_branches_after_requested_turn() {
+    skip_if_no_network!();
+
+    let server = MockServer::start().await;
+    let sse = sse(vec![ev_response_created("resp"), ev_completed("resp")]);
+    Mock::given(method("POST"))
+        .and(path("/v1/responses"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/event-stream")
+                .set_body_raw(sse, "text/event-stream"),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut builder = test_codex();
+    let test = builder.build(&server).await.expect("create conversation");
+    let codex = test.codex.clone();
+    let parent_id = test.session_configured.session_id;
+
+    let mut submission_ids = Vec::new();
+    for text in ["first", "second"] {
+        let sub_id = codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.to_string(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
+            })
+            .await
+            .unwrap();
+        let _ = wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+        submission_ids.push(sub_id);
+    }
+
+    codex
+        .submit(Op::ForkSession {
+            from_submission_id: submission_ids[0].clone(),
+        })
+        .await
+        .unwrap();
+    let EventMsg::SessionForked(forked) =
+        wait_for_event(&codex, |ev| matches!(ev, EventMsg::SessionForked(_))).await
+    else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_ne!(forked.session_id, parent_id);
+    assert_eq!(forked.from_submission_id, submission_ids[0]);
+
+    let fork = test
+        .thread_manager
+        .get_thread(forked.session_id)
+        .await
+        .expect("fork is registered with the thread manager");
+    let fork_path = fork.rollout_path().expect("fork rollout path");
+    assert_eq!(forked.rollout_path.as_ref(), Some(&fork_path));
+    assert_ne!(Some(fork_path.clone()), codex.rollout_path());
+
+    let user_messages = |p: &std::path::Path| -> (Vec<RolloutItem>, Vec<String>) {
+        let text = std::fs::read_to_string(p).expect("read rollout file");
+        let items: Vec<RolloutItem> = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str::<RolloutLine>(line)
+                    .expect("rollout line")
+                    .item
+            })
+            .collect();
+        let messages = items
+            .iter()
+            .filter_map(|item| match item {
+                RolloutItem::ResponseItem(response_item) => match parse_turn_item(response_item) {
+                    Some(TurnItem::UserMessage(message)) => Some(message.message()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        (items, messages)
+    };
+
+    let (fork_items, fork_messages) = user_messages(&fork_path);
+    let Some(RolloutItem::SessionMeta(meta)) = fork_items.first() else {
+        panic!("fork rollout should start with session meta");
+    };
+    assert_eq!(meta.meta.id, forked.session_id);
+    assert_eq!(meta.meta.forked_from_id, Some(parent_id));
+    assert_eq!(fork_messages, vec!["first".to_string()]);
+
+    // The parent keeps its full history.
+    let parent_path = codex.rollout_path().expect("parent rollout path");
+    let (_, parent_messages) = user_messages(&parent_path);
+    assert_eq!(
+        parent_messages,
+        vec!["first".to_string(), "second".to_string()]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn fork_session_op_finds_turns_before_a_rollout_rotation() {
+    skip_if_no_network!();
+
+    let server = MockServer::start().await;
+    let sse = sse(vec![ev_response_created("resp"), ev_completed("resp")]);
+    Mock::given(method("POST"))
+        .and(path("/v1/responses"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/event-stream")
+                .set_body_raw(sse, "text/event-stream"),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut builder = test_codex();
+    let test = builder.build(&server).await.expect("create conversation");
+    let codex = test.codex.clone();
+
+    let mut submission_ids = Vec::new();
+    for text in ["first", "second"] {
+        if text == "second" {
+            codex.submit(Op::RotateRollout).await.unwrap();
+            let _ = wait_for_event(&codex, |ev| matches!(ev, EventMsg::RolloutRotated(_))).await;
+        }
+        let sub_id = codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.to_string(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
+            })
+            .await
+            .unwrap();
+        let _ = wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+        submission_ids.push(sub_id);
+    }
+
+    // The first turn only lives in the rollout segment closed by the rotation.
+    codex
+        .submit(Op::ForkSession {
+            from_submission_id: submission_ids[0].clone(),
+        })
+        .await
+        .unwrap();
+    let event = wait_for_event(&codex, |ev| {
+        matches!(ev, EventMsg::SessionForked(_) | EventMsg::Error(_))
+    })
+    .await;
+    let EventMsg::SessionForked(forked) = event else {
+        panic!("expected the fork to succeed, got {event:?}");
+    };
+    assert_eq!(forked.from_submission_id, submission_ids[0]);
+}
@@ -0,0 +1,45 @@
+use codex_core::features::Feature;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event_match;
+
+async fn enabled_features(plugins: bool) -> anyhow::Result<Vec<String>> {
+    let server = start_mock_server().await;
+    let codex = test_codex()
+        .with_config(move |config| {
+            let _ = config.features.set_enabled(Feature::Plugins, plugins);
+        })
+        .build(&server)
+        .await?
+        .codex;
+
+    codex.submit(Op::GetFeatures).await?;
+    let features = wait_for_event_match(&codex, |event| match event {
+        EventMsg::Features(event) => Some(event.enabled.clone()),
+        _ => None,
+    })
+    .await;
+    Ok(features)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_features_reports_plugins_only_when_enabled() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let enabled = enabled_features(true).await?;
+    assert!(
+        enabled.iter().any(|key| key == "plugins"),
+        "plugins should be reported when enabled: {enabled:?}"
+    );
+
+    let disabled = enabled_features(false).await?;
+    assert!(
+        !disabled.iter().any(|key| key == "plugins"),
+        "plugins should not be reported when disabled: {disabled:?}"
+    );
+
+    Ok(())
+}
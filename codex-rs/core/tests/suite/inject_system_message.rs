@@ -0,0 +1,103 @@
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_function_call;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+const INJECTED: &str = "This is the CI output, focus only on fixing tests.";
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn injected_system_message_applies_to_next_turn_only() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let plan_args = json!({
+        "plan": [{"step": "Fix the failing test", "status": "in_progress"}],
+    })
+    .to_string();
+    let mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_function_call("call-1", "update_plan", &plan_args),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_assistant_message("msg-1", "Fixed."),
+                ev_completed("resp-2"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-3"),
+                ev_assistant_message("msg-2", "Done."),
+                ev_completed("resp-3"),
+            ]),
+        ],
+    )
+    .await;
+    let codex = test_codex().build(&server).await?.codex;
+
+    codex
+        .submit(Op::InjectSystemMessage {
+            text: INJECTED.to_string(),
+        })
+        .await?;
+    for prompt in ["fix the build", "anything else?"] {
+        codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: prompt.to_string(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
+            })
+            .await?;
+        wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+    }
+
+    // Both requests of the first turn carry the message; the next turn does not.
+    let system_texts = mock
+        .requests()
+        .iter()
+        .map(|request| request.message_input_texts("system"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        system_texts,
+        vec![
+            vec![INJECTED.to_string()],
+            vec![INJECTED.to_string()],
+            Vec::new(),
+        ]
+    );
+
+    codex.submit(Op::GetConversationSnapshot).await?;
+    let snapshot = wait_for_event_match(&codex, |event| match event {
+        EventMsg::ConversationSnapshot(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    assert!(
+        !snapshot
+            .items
+            .iter()
+            .any(|item| matches!(item, ResponseItem::Message { role, .. } if role == "system")),
+        "injected message must not be recorded in history"
+    );
+
+    Ok(())
+}
@@ -16,9 +16,13 @@ use codex_protocol::user_input::TextElement;
 use codex_protocol::user_input::UserInput;
 use core_test_support::responses::ev_assistant_message;
 use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_function_call;
+use core_test_support::responses::ev_function_call_added;
+use core_test_support::responses::ev_function_call_arguments_delta;
 use core_test_support::responses::ev_image_generation_call;
 use core_test_support::responses::ev_message_item_added;
 use core_test_support::responses::ev_output_text_delta;
+use core_test_support::responses::ev_provider_tool_call;
 use core_test_support::responses::ev_reasoning_item;
 use core_test_support::responses::ev_reasoning_item_added;
 use core_test_support::responses::ev_reasoning_summary_text_delta;
@@ -27,6 +31,7 @@ use core_test_support::responses::ev_response_created;
 use core_test_support::responses::ev_web_search_call_added_partial;
 use core_test_support::responses::ev_web_search_call_done;
 use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::mount_sse_sequence;
 use core_test_support::responses::sse;
 use core_test_support::responses::start_mock_server;
 use core_test_support::skip_if_no_network;
@@ -35,6 +40,7 @@ use core_test_support::test_codex::test_codex;
 use core_test_support::wait_for_event;
 use core_test_support::wait_for_event_match;
 use pretty_assertions::assert_eq;
+use serde_json::json;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn user_message_item_is_emitted() -> anyhow::Result<()> {
@@ -60,6 +66,8 @@ async fn user_message_item_is_emitted() -> anyhow::Result<()> {
         .submit(Op::UserInput {
             items: vec![expected_input.clone()],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -116,6 +124,8 @@ async fn assistant_message_item_is_emitted() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -146,6 +156,126 @@ async fn assistant_message_item_is_emitted() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn whitespace_only_assistant_message_is_suppressed_when_enabled() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+
+    let TestCodex { codex, .. } = test_codex()
+        .with_config(|config| {
+            config.suppress_whitespace_only_agent_messages = true;
+        })
+        .build(&server)
+        .await?;
+
+    let responses = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_message_item_added("msg-1", ""),
+                ev_output_text_delta(" \n"),
+                ev_output_text_delta("\t "),
+                ev_assistant_message("msg-1", " \n\t "),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_message_item_added("msg-2", ""),
+                ev_output_text_delta("\n"),
+                ev_output_text_delta("all done"),
+                ev_assistant_message("msg-2", "\nall done"),
+                ev_completed("resp-2"),
+            ]),
+        ],
+    )
+    .await;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "first".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+
+    loop {
+        let event = codex.next_event().await?;
+        match event.msg {
+            EventMsg::AgentMessage(_)
+            | EventMsg::AgentMessageContentDelta(_)
+            | EventMsg::ItemStarted(ItemStartedEvent {
+                item: TurnItem::AgentMessage(_),
+                ..
+            })
+            | EventMsg::ItemCompleted(ItemCompletedEvent {
+                item: TurnItem::AgentMessage(_),
+                ..
+            }) => panic!("whitespace-only assistant message should be suppressed"),
+            EventMsg::TurnComplete(turn_complete) => {
+                assert_eq!(turn_complete.last_agent_message, None);
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "second".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+
+    // A message that only starts with whitespace is still started before its
+    // first delta, which carries the held-back whitespace.
+    let mut agent_message_events = Vec::new();
+    loop {
+        match codex.next_event().await?.msg {
+            EventMsg::ItemStarted(ItemStartedEvent {
+                item: TurnItem::AgentMessage(_),
+                ..
+            }) => agent_message_events.push("started".to_string()),
+            EventMsg::AgentMessageContentDelta(event) => {
+                agent_message_events.push(format!("delta {:?}", event.delta));
+            }
+            EventMsg::ItemCompleted(ItemCompletedEvent {
+                item: TurnItem::AgentMessage(_),
+                ..
+            }) => agent_message_events.push("completed".to_string()),
+            EventMsg::TurnComplete(_) => break,
+            _ => {}
+        }
+    }
+    assert_eq!(
+        agent_message_events,
+        vec![
+            "started".to_string(),
+            "delta \"\\nall done\"".to_string(),
+            "completed".to_string(),
+        ]
+    );
+
+    let requests = responses.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1].message_input_texts("assistant"),
+        Vec::<String>::new()
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn reasoning_item_is_emitted() -> anyhow::Result<()> {
     skip_if_no_network!(Ok(()));
@@ -174,6 +304,8 @@ async fn reasoning_item_is_emitted() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -233,6 +365,8 @@ async fn web_search_item_is_emitted() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -288,6 +422,8 @@ async fn image_generation_call_event_is_emitted() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -341,6 +477,8 @@ async fn image_generation_call_event_is_emitted_when_image_save_fails() -> anyho
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -394,6 +532,8 @@ async fn agent_message_content_delta_has_item_metadata() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -1036,6 +1176,8 @@ async fn reasoning_content_delta_has_item_metadata() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -1095,6 +1237,8 @@ async fn reasoning_raw_content_delta_respects_flag() -> anyhow::Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -1124,3 +1268,214 @@ async fn reasoning_raw_content_delta_respects_flag() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn assistant_finished_is_emitted_only_for_turns_ending_with_a_message() -> anyhow::Result<()>
+{
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_assistant_message("msg-1", "final answer"),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_function_call("call-1", "nonexistent_tool", "{}"),
+                ev_completed("resp-2"),
+            ]),
+            sse(vec![ev_response_created("resp-3"), ev_completed("resp-3")]),
+        ],
+    )
+    .await;
+
+    let mut finished_messages = Vec::new();
+    for text in ["answer me", "just use a tool"] {
+        codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.into(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
+            })
+            .await?;
+
+        let mut finished = None;
+        loop {
+            match codex.next_event().await?.msg {
+                EventMsg::AssistantFinished(event) => finished = Some(event.message),
+                EventMsg::TurnComplete(_) => break,
+                _ => {}
+            }
+        }
+        finished_messages.push(finished);
+    }
+
+    assert_eq!(
+        finished_messages,
+        vec![Some("final answer".to_string()), None]
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn streamed_function_call_arguments_emit_parsed_previews() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    let arguments = r#"{"path": "notes.txt", "lines": ["one", "two"]}"#;
+    mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_function_call_added("fc-1", "call-1", "nonexistent_tool"),
+                ev_function_call_arguments_delta("fc-1", r#"{"path": "no"#),
+                ev_function_call_arguments_delta("fc-1", r#"tes.txt", "li"#),
+                ev_function_call_arguments_delta("fc-1", r#"nes": ["one", "tw"#),
+                ev_function_call_arguments_delta("fc-1", r#"o"]}"#),
+                ev_function_call("call-1", "nonexistent_tool", arguments),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_assistant_message("msg-1", "done"),
+                ev_completed("resp-2"),
+            ]),
+        ],
+    )
+    .await;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "call a tool".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+
+    let mut previews = Vec::new();
+    loop {
+        match codex.next_event().await?.msg {
+            EventMsg::ToolCallArgumentsPreview(event) => {
+                assert_eq!(event.call_id, "call-1");
+                assert_eq!(event.tool_name, "nonexistent_tool");
+                previews.push(event.arguments);
+            }
+            EventMsg::TurnComplete(_) => break,
+            _ => {}
+        }
+    }
+
+    // The second delta ends inside the `lines` key, so its preview stops at
+    // the last complete member.
+    assert_eq!(
+        previews,
+        vec![
+            json!({"path": "no"}),
+            json!({"path": "notes.txt"}),
+            json!({"path": "notes.txt", "lines": ["one", "tw"]}),
+            json!({"path": "notes.txt", "lines": ["one", "two"]}),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn provider_tool_call_is_emitted_and_recorded_without_local_exec() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    let responses = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_provider_tool_call("ptc-1", "code_interpreter", r#"{"code":"1 + 1"}"#, "2"),
+                ev_assistant_message("msg-1", "the answer is 2"),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_assistant_message("msg-2", "still 2"),
+                ev_completed("resp-2"),
+            ]),
+        ],
+    )
+    .await;
+
+    for text in ["compute 1 + 1", "and again?"] {
+        codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.into(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
+            })
+            .await?;
+
+        let mut provider_calls = Vec::new();
+        loop {
+            match codex.next_event().await?.msg {
+                EventMsg::ProviderToolCall(event) => provider_calls.push(event),
+                EventMsg::ExecCommandBegin(event) => {
+                    panic!("provider tool call must not run locally: {event:?}")
+                }
+                EventMsg::TurnComplete(_) => break,
+                _ => {}
+            }
+        }
+        if text == "compute 1 + 1" {
+            assert_eq!(provider_calls.len(), 1);
+            let call = &provider_calls[0];
+            assert_eq!(call.call_id, "ptc-1");
+            assert_eq!(call.name, "code_interpreter");
+            assert_eq!(call.status.as_deref(), Some("completed"));
+            assert_eq!(call.arguments.as_deref(), Some(r#"{"code":"1 + 1"}"#));
+            assert_eq!(call.output.as_deref(), Some("2"));
+        } else {
+            assert!(provider_calls.is_empty());
+        }
+    }
+
+    let requests = responses.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1].inputs_of_type("provider_tool_call"),
+        vec![json!({
+            "type": "provider_tool_call",
+            "id": "ptc-1",
+            "name": "code_interpreter",
+            "status": "completed",
+            "arguments": r#"{"code":"1 + 1"}"#,
+            "output": "2",
+        })]
+    );
+
+    Ok(())
+}
@@ -682,3 +682,52 @@ async fn js_repl_blocks_sensitive_builtin_imports() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn js_repl_does_not_run_code_in_dry_run() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = responses::start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config.dry_run = true;
+        config
+            .features
+            .enable(Feature::JsRepl)
+            .expect("test config should allow feature update");
+    });
+    let test = builder.build(&server).await?;
+    let marker = test.cwd_path().join("js-repl-dry-run.txt");
+    let code = format!(
+        "await import('node:fs').then((fs) => fs.writeFileSync({:?}, 'ran'));",
+        marker.to_string_lossy()
+    );
+
+    responses::mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_custom_tool_call("call-1", "js_repl", &code),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let final_mock = responses::mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    test.submit_turn("run js_repl").await?;
+
+    assert_js_repl_ok(
+        &final_mock.single_request(),
+        "call-1",
+        "[dry-run] js_repl code not executed:",
+    );
+    assert!(!marker.exists());
+
+    Ok(())
+}
@@ -13,6 +13,7 @@ use core_test_support::wait_for_event;
 use pretty_assertions::assert_eq;
 use responses::ev_assistant_message;
 use responses::ev_completed;
+use responses::mount_sse_sequence;
 use responses::sse;
 use responses::start_mock_server;
 
@@ -107,3 +108,53 @@ async fn codex_returns_json_result(model: String) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn default_output_schema_applies_to_turns_without_their_own() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let answer = r#"{"explanation": "explanation", "final_answer": "final_answer"}"#;
+    let response_mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![ev_assistant_message("m1", answer), ev_completed("r1")]),
+            sse(vec![ev_assistant_message("m2", answer), ev_completed("r2")]),
+        ],
+    )
+    .await;
+
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    let schema: serde_json::Value = serde_json::from_str(SCHEMA)?;
+    codex
+        .submit(Op::SetDefaultOutputSchema {
+            schema: Some(schema.clone()),
+        })
+        .await?;
+
+    for text in ["first", "second"] {
+        codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.into(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
+            })
+            .await?;
+        wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+    }
+
+    let requests = response_mock.requests();
+    assert_eq!(requests.len(), 2);
+    for request in requests {
+        let format = request.body_json()["text"]["format"].clone();
+        assert_eq!(format["name"], "codex_output_schema");
+        assert_eq!(format["schema"], schema);
+    }
+
+    Ok(())
+}
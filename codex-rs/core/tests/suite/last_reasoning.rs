@@ -0,0 +1,73 @@
+#![allow(clippy::expect_used)]
+
+use anyhow::Result;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::LastReasoningEvent;
+use codex_protocol::protocol::Op;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_reasoning_item;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+
+async fn run_turn_with_reasoning(show_raw_agent_reasoning: bool) -> Result<LastReasoningEvent> {
+    let server = start_mock_server().await;
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_reasoning_item("rsn-1", &["Checked the config"], &["raw thought"]),
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let mut builder = test_codex().with_config(move |config| {
+        config.show_raw_agent_reasoning = show_raw_agent_reasoning;
+    });
+    let test = builder.build(&server).await?;
+
+    test.submit_turn("look at the config").await?;
+    test.codex.submit(Op::GetLastReasoning).await?;
+    let last_reasoning = wait_for_event_match(&test.codex, |event| match event {
+        EventMsg::LastReasoning(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    Ok(last_reasoning)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn last_reasoning_is_retrievable_after_turn() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let last_reasoning = run_turn_with_reasoning(true).await?;
+
+    assert!(last_reasoning.turn_id.is_some());
+    assert_eq!(
+        last_reasoning.summary,
+        vec!["Checked the config".to_string()]
+    );
+    assert_eq!(last_reasoning.raw_content, vec!["raw thought".to_string()]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn last_reasoning_omits_raw_content_unless_enabled() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let last_reasoning = run_turn_with_reasoning(false).await?;
+
+    assert_eq!(
+        last_reasoning.summary,
+        vec!["Checked the config".to_string()]
+    );
+    assert_eq!(last_reasoning.raw_content, Vec::<String>::new());
+    Ok(())
+}
@@ -61,7 +61,9 @@ mod agent_websocket;
 mod apply_patch_cli;
 #[cfg(not(target_os = "windows"))]
 mod approvals;
+mod assistant_output_cap;
 mod auth_refresh;
+mod base_instructions;
 mod cli_stream;
 mod client;
 mod client_websockets;
@@ -74,13 +76,19 @@ mod compact_resume_fork;
 mod deprecation_notice;
 mod exec;
 mod exec_policy;
+mod explain_last_command;
+mod file_attachments;
+mod final_message_request;
 mod fork_thread;
+mod get_features;
 mod grep_files;
 mod hierarchical_agents;
 mod image_rollout;
+mod inject_system_message;
 mod items;
 mod js_repl;
 mod json_result;
+mod last_reasoning;
 mod list_dir;
 mod live_cli;
 mod live_reload;
@@ -101,6 +109,8 @@ mod prompt_caching;
 mod quota_exceeded;
 mod read_file;
 mod realtime_conversation;
+mod recent_diffs_context;
+mod recent_errors;
 mod remote_models;
 mod request_compression;
 #[cfg(not(target_os = "windows"))]
@@ -110,12 +120,15 @@ mod request_permissions_tool;
 mod request_user_input;
 mod resume;
 mod resume_warning;
+mod retry_config;
 mod review;
 mod rmcp_client;
 mod rollout_list_find;
 mod safety_check_downgrade;
+mod search_history;
 mod search_tool;
 mod seatbelt;
+mod session_stats;
 mod shell_command;
 mod shell_serialization;
 mod shell_snapshot;
@@ -126,16 +139,22 @@ mod stream_error_allows_next_turn;
 mod stream_no_completed;
 mod subagent_notifications;
 mod text_encoding_fix;
+mod token_budget;
+mod tool_call_loop;
 mod tool_harness;
 mod tool_parallelism;
 mod tools;
 mod truncation;
+mod turn_cost;
+mod turn_rate_limit;
 mod turn_state;
 mod undo;
+mod undo_last_patch;
 mod unified_exec;
 mod unstable_features_warning;
 mod user_notification;
 mod user_shell_cmd;
 mod view_image;
+mod watch_files;
 mod web_search;
 mod websocket_fallback;
@@ -1,11 +1,16 @@
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::Op;
+use codex_protocol::protocol::PauseStateChangedEvent;
 use codex_protocol::user_input::UserInput;
 use core_test_support::responses;
+use core_test_support::responses::ev_assistant_message;
 use core_test_support::responses::ev_completed;
 use core_test_support::responses::ev_message_item_added;
 use core_test_support::responses::ev_output_text_delta;
 use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
 use core_test_support::streaming_sse::StreamingSseChunk;
 use core_test_support::streaming_sse::start_streaming_sse_server;
 use core_test_support::test_codex::test_codex;
@@ -104,6 +109,8 @@ async fn injected_user_input_triggers_follow_up_request_with_deltas() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -120,6 +127,8 @@ async fn injected_user_input_triggers_follow_up_request_with_deltas() {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
         .unwrap();
@@ -144,3 +153,74 @@ async fn injected_user_input_triggers_follow_up_request_with_deltas() {
 
     server.shutdown().await;
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn user_input_submitted_while_paused_starts_on_resume() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+    let response_mock = mount_sse_once(
+        &server,
+        responses::sse(vec![
+            ev_response_created("resp-1"),
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+
+    let codex = test_codex().build(&server).await.unwrap().codex;
+
+    codex.submit(Op::Pause).await.unwrap();
+    let paused = wait_for_event(&codex, |event| {
+        matches!(event, EventMsg::PauseStateChanged(_))
+    })
+    .await;
+    let EventMsg::PauseStateChanged(paused) = paused else {
+        panic!("expected PauseStateChanged, got {paused:?}");
+    };
+    assert_eq!(paused, PauseStateChangedEvent { paused: true });
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "held prompt".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await
+        .unwrap();
+
+    // Ops are handled in order, so a second acknowledgement proves the held
+    // input was processed without starting a turn.
+    codex.submit(Op::Pause).await.unwrap();
+    let next = wait_for_event(&codex, |event| {
+        matches!(
+            event,
+            EventMsg::TurnStarted(_) | EventMsg::PauseStateChanged(_)
+        )
+    })
+    .await;
+    let EventMsg::PauseStateChanged(next) = next else {
+        panic!("expected PauseStateChanged, got {next:?}");
+    };
+    assert_eq!(next, PauseStateChangedEvent { paused: true });
+    assert!(response_mock.requests().is_empty());
+
+    codex.submit(Op::Resume).await.unwrap();
+    let resumed = wait_for_event(&codex, |event| {
+        matches!(event, EventMsg::PauseStateChanged(_))
+    })
+    .await;
+    let EventMsg::PauseStateChanged(resumed) = resumed else {
+        panic!("expected PauseStateChanged, got {resumed:?}");
+    };
+    assert_eq!(resumed, PauseStateChangedEvent { paused: false });
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let user_texts = response_mock.single_request().message_input_texts("user");
+    assert!(user_texts.iter().any(|text| text == "held prompt"));
+}
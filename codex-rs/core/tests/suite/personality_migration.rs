@@ -72,6 +72,7 @@ async fn write_rollout_with_user_event(dir: &Path, thread_id: ThreadId) -> io::R
             base_instructions: None,
             dynamic_tools: None,
             memory_mode: None,
+            previous_rollout_path: None,
         },
         git: None,
     };
@@ -116,6 +117,7 @@ async fn write_rollout_with_meta_only(dir: &Path, thread_id: ThreadId) -> io::Re
             base_instructions: None,
             dynamic_tools: None,
             memory_mode: None,
+            previous_rollout_path: None,
         },
         git: None,
     };
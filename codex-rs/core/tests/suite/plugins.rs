@@ -7,9 +7,13 @@ use std::time::Instant;
 
 use anyhow::Result;
 use codex_core::CodexAuth;
+use codex_core::config::Constrained;
 use codex_core::features::Feature;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::Op;
+use codex_protocol::protocol::PluginListEntry;
+use codex_protocol::protocol::PluginValidationResultEvent;
+use codex_protocol::protocol::SandboxPolicy;
 use core_test_support::apps_test_server::AppsTestServer;
 use core_test_support::responses::ev_completed;
 use core_test_support::responses::ev_response_created;
@@ -20,6 +24,7 @@ use core_test_support::skip_if_no_network;
 use core_test_support::stdio_server_bin;
 use core_test_support::test_codex::test_codex;
 use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
 use core_test_support::wait_for_event_with_timeout;
 use tempfile::TempDir;
 use wiremock::MockServer;
@@ -188,6 +193,8 @@ async fn plugin_skills_append_to_instructions() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -214,6 +221,154 @@ async fn plugin_skills_append_to_instructions() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn list_skills_reports_plugin_skills_with_namespace() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+    let server = start_mock_server().await;
+
+    let codex_home = Arc::new(TempDir::new()?);
+    let skill_path = write_plugin_skill_plugin(codex_home.as_ref());
+    let codex = build_plugin_test_codex(&server, Arc::clone(&codex_home)).await?;
+
+    codex
+        .submit(Op::ListSkills {
+            cwds: Vec::new(),
+            force_reload: true,
+        })
+        .await?;
+    let response = wait_for_event_match(&codex, |event| match event {
+        EventMsg::ListSkillsResponse(response) => Some(response.clone()),
+        _ => None,
+    })
+    .await;
+
+    let plugin_skills = response
+        .skills
+        .iter()
+        .flat_map(|entry| entry.skills.iter())
+        .filter(|skill| skill.plugin.is_some())
+        .map(|skill| {
+            (
+                skill.name.clone(),
+                skill.description.clone(),
+                skill.plugin.clone(),
+                skill.path.clone(),
+                skill.enabled,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        plugin_skills,
+        vec![(
+            "sample:sample-search".to_string(),
+            "inspect sample data".to_string(),
+            Some(SAMPLE_PLUGIN_DISPLAY_NAME.to_string()),
+            std::fs::canonicalize(skill_path)?,
+            true,
+        )]
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn list_plugins_reports_plugin_capabilities() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+    let server = start_mock_server().await;
+
+    let codex_home = Arc::new(TempDir::new()?);
+    write_plugin_skill_plugin(codex_home.as_ref());
+    let codex = build_plugin_test_codex(&server, Arc::clone(&codex_home)).await?;
+
+    codex.submit(Op::ListPlugins).await?;
+    let response = wait_for_event_match(&codex, |event| match event {
+        EventMsg::PluginList(response) => Some(response.clone()),
+        _ => None,
+    })
+    .await;
+
+    assert_eq!(
+        response.plugins,
+        vec![PluginListEntry {
+            config_name: SAMPLE_PLUGIN_CONFIG_NAME.to_string(),
+            manifest_name: Some(SAMPLE_PLUGIN_DISPLAY_NAME.to_string()),
+            enabled: true,
+            error: None,
+            has_skills: true,
+            has_mcp_servers: false,
+            skill_count: 1,
+            mcp_server_count: 0,
+            declared_capabilities: Vec::new(),
+        }]
+    );
+    assert_eq!(
+        response.summary,
+        format!("{SAMPLE_PLUGIN_CONFIG_NAME}: enabled, 1 skill, 0 MCP servers")
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn validate_plugin_reports_problems_before_install() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+    let server = start_mock_server().await;
+
+    let codex_home = Arc::new(TempDir::new()?);
+    write_plugin_skill_plugin(codex_home.as_ref());
+    let codex = build_plugin_test_codex(&server, Arc::clone(&codex_home)).await?;
+
+    let valid_root = sample_plugin_root(codex_home.as_ref());
+    codex
+        .submit(Op::ValidatePlugin {
+            path: valid_root.clone(),
+        })
+        .await?;
+    let response = wait_for_event_match(&codex, |event| match event {
+        EventMsg::PluginValidationResult(response) => Some(response.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(
+        response,
+        PluginValidationResultEvent {
+            path: valid_root,
+            errors: Vec::new(),
+        }
+    );
+
+    let broken = TempDir::new()?;
+    let manifest_path = broken.path().join(".codex-plugin/plugin.json");
+    std::fs::create_dir_all(manifest_path.parent().expect("manifest dir"))?;
+    std::fs::write(&manifest_path, r#"{"name":""}"#)?;
+    std::fs::write(
+        broken.path().join(".mcp.json"),
+        r#"{"mcpServers":{"broken":{"command":["not","a","string"]}}}"#,
+    )?;
+    codex
+        .submit(Op::ValidatePlugin {
+            path: broken.path().to_path_buf(),
+        })
+        .await?;
+    let response = wait_for_event_match(&codex, |event| match event {
+        EventMsg::PluginValidationResult(response) => Some(response.clone()),
+        _ => None,
+    })
+    .await;
+    let error_paths = response
+        .errors
+        .iter()
+        .map(|error| error.path.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        error_paths,
+        vec![manifest_path, broken.path().join(".mcp.json")]
+    );
+    assert!(response.errors[1].message.contains("`broken`"));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn explicit_plugin_mentions_inject_plugin_guidance() -> Result<()> {
     skip_if_no_network!(Ok(()));
@@ -248,6 +403,8 @@ async fn explicit_plugin_mentions_inject_plugin_guidance() -> Result<()> {
                 path: format!("plugin://{SAMPLE_PLUGIN_CONFIG_NAME}"),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -333,3 +490,211 @@ async fn plugin_mcp_tools_are_listed() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn plugin_task_hooks_run_before_the_turn_completes() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    skip_if_no_network!(Ok(()));
+    let server = start_mock_server().await;
+    mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+
+    let codex_home = Arc::new(TempDir::new()?);
+    let plugin_root = write_sample_plugin_manifest_and_config(codex_home.as_ref());
+    std::fs::write(
+        plugin_root.join(".codex-plugin/plugin.json"),
+        format!(
+            r#"{{"name":"{SAMPLE_PLUGIN_DISPLAY_NAME}","hooks":{{"preTask":"./hooks/pre.sh","postTask":"./hooks/post.sh"}}}}"#
+        ),
+    )?;
+    std::fs::create_dir_all(plugin_root.join("hooks"))?;
+    for (name, output) in [("pre.sh", "pre-task ran"), ("post.sh", "post-task ran")] {
+        let script = plugin_root.join("hooks").join(name);
+        std::fs::write(&script, format!("#!/bin/sh\necho {output}\n"))?;
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+    }
+    let codex = build_plugin_test_codex(&server, codex_home).await?;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![codex_protocol::user_input::UserInput::Text {
+                text: "hello".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+
+    let mut hook_output = Vec::new();
+    loop {
+        match wait_for_event(&codex, |_| true).await {
+            EventMsg::BackgroundEvent(event) if event.message.contains(" hook ") => {
+                hook_output.push(event.message);
+            }
+            EventMsg::TurnComplete(_) => break,
+            _ => {}
+        }
+    }
+
+    assert_eq!(
+        hook_output,
+        vec![
+            format!("Plugin {SAMPLE_PLUGIN_CONFIG_NAME} preTask hook stdout:\npre-task ran"),
+            format!("Plugin {SAMPLE_PLUGIN_CONFIG_NAME} postTask hook stdout:\npost-task ran"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn plugin_task_hooks_are_skipped_in_dry_run() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    skip_if_no_network!(Ok(()));
+    let server = start_mock_server().await;
+    mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+
+    let codex_home = Arc::new(TempDir::new()?);
+    let plugin_root = write_sample_plugin_manifest_and_config(codex_home.as_ref());
+    std::fs::write(
+        plugin_root.join(".codex-plugin/plugin.json"),
+        format!(
+            r#"{{"name":"{SAMPLE_PLUGIN_DISPLAY_NAME}","hooks":{{"preTask":"./hooks/pre.sh"}}}}"#
+        ),
+    )?;
+    std::fs::create_dir_all(plugin_root.join("hooks"))?;
+    let marker = codex_home.path().join("pre-task-ran");
+    let script = plugin_root.join("hooks/pre.sh");
+    std::fs::write(
+        &script,
+        format!("#!/bin/sh\ntouch '{}'\n", marker.display()),
+    )?;
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+    let mut builder = test_codex()
+        .with_home(Arc::clone(&codex_home))
+        .with_auth(CodexAuth::from_api_key("Test API Key"))
+        .with_config(|config| {
+            config.dry_run = true;
+        });
+    let codex = builder.build(&server).await?.codex;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![codex_protocol::user_input::UserInput::Text {
+                text: "hello".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+
+    let mut hook_output = Vec::new();
+    loop {
+        match wait_for_event(&codex, |_| true).await {
+            EventMsg::BackgroundEvent(event) if event.message.contains(" hook ") => {
+                hook_output.push(event.message);
+            }
+            EventMsg::TurnComplete(_) => break,
+            _ => {}
+        }
+    }
+
+    let [message] = hook_output.as_slice() else {
+        panic!("expected one hook message, got {hook_output:?}");
+    };
+    assert!(
+        message.starts_with(&format!(
+            "[dry-run] Plugin {SAMPLE_PLUGIN_CONFIG_NAME} preTask hook not executed: "
+        )),
+        "{message}"
+    );
+    assert!(message.ends_with("pre.sh"), "{message}");
+    assert!(!marker.exists());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn unsandboxed_plugin_task_hooks_are_audited() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    skip_if_no_network!(Ok(()));
+    let server = start_mock_server().await;
+    mount_sse_once(
+        &server,
+        sse(vec![ev_response_created("resp1"), ev_completed("resp1")]),
+    )
+    .await;
+
+    let codex_home = Arc::new(TempDir::new()?);
+    let plugin_root = write_sample_plugin_manifest_and_config(codex_home.as_ref());
+    std::fs::write(
+        plugin_root.join(".codex-plugin/plugin.json"),
+        format!(
+            r#"{{"name":"{SAMPLE_PLUGIN_DISPLAY_NAME}","hooks":{{"preTask":"./hooks/pre.sh"}}}}"#
+        ),
+    )?;
+    std::fs::create_dir_all(plugin_root.join("hooks"))?;
+    let script = plugin_root.join("hooks/pre.sh");
+    std::fs::write(&script, "#!/bin/sh\necho pre-task ran\n")?;
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+    let audit_log = codex_home.path().join("sandbox-audit.jsonl");
+    let audit_log_for_config = audit_log.clone();
+    let mut builder = test_codex()
+        .with_home(Arc::clone(&codex_home))
+        .with_auth(CodexAuth::from_api_key("Test API Key"))
+        .with_config(move |config| {
+            config.permissions.sandbox_policy =
+                Constrained::allow_any(SandboxPolicy::DangerFullAccess);
+            config.sandbox_bypass_audit_log = Some(audit_log_for_config);
+        });
+    let codex = builder.build(&server).await?.codex;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![codex_protocol::user_input::UserInput::Text {
+                text: "hello".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let contents = std::fs::read_to_string(&audit_log)?;
+    let entries: Vec<serde_json::Value> = contents
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<std::result::Result<_, _>>()?;
+    assert_eq!(entries.len(), 1, "unexpected audit entries: {contents}");
+    assert_eq!(
+        entries[0]["call_id"],
+        format!("plugin-hook:{SAMPLE_PLUGIN_CONFIG_NAME}:preTask")
+    );
+    assert_eq!(entries[0]["decision"], "not_required");
+    assert!(
+        entries[0]["command"]
+            .as_array()
+            .and_then(|args| args.first())
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|program| program.ends_with("pre.sh")),
+        "unexpected audit entry: {contents}"
+    );
+
+    Ok(())
+}
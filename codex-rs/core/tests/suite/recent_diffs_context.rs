@@ -0,0 +1,132 @@
+#![cfg(not(target_os = "windows"))]
+
+use codex_core::features::Feature;
+use core_test_support::responses::ev_apply_patch_function_call;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use pretty_assertions::assert_eq;
+
+const RECENT_DIFFS_HEADER: &str = "Files you changed in recent turns";
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn prior_turn_diff_summary_is_included_when_enabled() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config.include_recent_diffs_in_context = true;
+        config
+            .features
+            .enable(Feature::ApplyPatchFreeform)
+            .expect("test config should allow feature update");
+    });
+    let test = builder.build(&server).await?;
+
+    let patch = r#"*** Begin Patch
+*** Add File: notes.txt
++first
++second
+*** End Patch"#;
+    let mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_apply_patch_function_call("apply-patch-call", patch),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_assistant_message("msg-1", "patched"),
+                ev_completed("resp-2"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-3"),
+                ev_assistant_message("msg-2", "done"),
+                ev_completed("resp-3"),
+            ]),
+        ],
+    )
+    .await;
+
+    test.submit_turn("add a notes file").await?;
+    test.submit_turn("what did you change?").await?;
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    let diff_notes = |index: usize| {
+        requests[index]
+            .message_input_texts("developer")
+            .into_iter()
+            .filter(|text| text.starts_with(RECENT_DIFFS_HEADER))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(diff_notes(0), Vec::<String>::new());
+    assert_eq!(diff_notes(1), Vec::<String>::new());
+    let notes = diff_notes(2);
+    assert_eq!(notes.len(), 1, "expected one diff summary, got {notes:?}");
+    assert!(notes[0].contains("- 1 turn(s) ago:\n"), "{}", notes[0]);
+    assert!(notes[0].contains("notes.txt (+2/-0)\n"), "{}", notes[0]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn prior_turn_diff_summary_is_omitted_by_default() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config
+            .features
+            .enable(Feature::ApplyPatchFreeform)
+            .expect("test config should allow feature update");
+    });
+    let test = builder.build(&server).await?;
+
+    let patch = r#"*** Begin Patch
+*** Add File: notes.txt
++first
+*** End Patch"#;
+    let mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_apply_patch_function_call("apply-patch-call", patch),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_assistant_message("msg-1", "patched"),
+                ev_completed("resp-2"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-3"),
+                ev_assistant_message("msg-2", "done"),
+                ev_completed("resp-3"),
+            ]),
+        ],
+    )
+    .await;
+
+    test.submit_turn("add a notes file").await?;
+    test.submit_turn("what did you change?").await?;
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(
+        requests[2]
+            .message_input_texts("developer")
+            .iter()
+            .all(|text| !text.starts_with(RECENT_DIFFS_HEADER))
+    );
+
+    Ok(())
+}
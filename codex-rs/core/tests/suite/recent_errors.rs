@@ -0,0 +1,48 @@
+use codex_protocol::protocol::CodexErrorInfo;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::protocol::RecentErrorsEvent;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+
+async fn recent_errors(codex: &codex_core::CodexThread, op: Op) -> RecentErrorsEvent {
+    codex.submit(op).await.unwrap();
+    wait_for_event_match(codex, |event| match event {
+        EventMsg::RecentErrors(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn recent_errors_are_retrievable_then_clearable() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+    let codex = test_codex().build(&server).await.unwrap().codex;
+
+    // Nothing has been denied yet, so this fails with an error event.
+    codex.submit(Op::ReapproveLastDenied).await.unwrap();
+    let error = wait_for_event(&codex, |event| matches!(event, EventMsg::Error(_))).await;
+    let EventMsg::Error(error) = error else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+
+    let retrieved = recent_errors(&codex, Op::GetRecentErrors { limit: Some(5) }).await;
+    assert_eq!(retrieved.errors.len(), 1);
+    assert_eq!(retrieved.errors[0].message, error.message);
+    assert_eq!(
+        retrieved.errors[0].codex_error_info,
+        Some(CodexErrorInfo::BadRequest)
+    );
+
+    let cleared = recent_errors(&codex, Op::ClearRecentErrors).await;
+    assert_eq!(cleared.errors.len(), 1);
+
+    let after_clear = recent_errors(&codex, Op::GetRecentErrors { limit: None }).await;
+    assert!(after_clear.errors.is_empty());
+}
@@ -1,6 +1,13 @@
 use anyhow::Result;
+use codex_core::CodexAuth;
+use codex_core::config::Constrained;
+use codex_core::find_thread_path_by_id_str;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::Op;
+use codex_protocol::protocol::SandboxPolicy;
 use codex_protocol::user_input::ByteRange;
 use codex_protocol::user_input::TextElement;
 use codex_protocol::user_input::UserInput;
@@ -17,6 +24,7 @@ use core_test_support::test_codex::TestCodex;
 use core_test_support::test_codex::TestCodexBuilder;
 use core_test_support::test_codex::test_codex;
 use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
 use pretty_assertions::assert_eq;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -91,6 +99,8 @@ async fn resume_includes_initial_messages_from_rollout_events() -> Result<()> {
                 text_elements: text_elements.clone(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -176,6 +186,8 @@ async fn resume_includes_initial_messages_from_reasoning_events() -> Result<()>
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
 
@@ -265,6 +277,8 @@ async fn resume_switches_models_preserves_base_instructions() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -305,6 +319,8 @@ async fn resume_switches_models_preserves_base_instructions() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&resumed.codex, |event| {
@@ -320,6 +336,8 @@ async fn resume_switches_models_preserves_base_instructions() -> Result<()> {
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&resumed.codex, |event| {
@@ -390,6 +408,8 @@ async fn resume_model_switch_is_not_duplicated_after_pre_turn_override() -> Resu
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
@@ -432,6 +452,8 @@ async fn resume_model_switch_is_not_duplicated_after_pre_turn_override() -> Resu
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&resumed.codex, |event| {
@@ -449,3 +471,261 @@ async fn resume_model_switch_is_not_duplicated_after_pre_turn_override() -> Resu
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resume_follows_rotated_rollout_chain() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex();
+    let initial = builder.build(&server).await?;
+    let codex = Arc::clone(&initial.codex);
+    let home = initial.home.clone();
+    let first_rollout_path = initial
+        .session_configured
+        .rollout_path
+        .clone()
+        .expect("rollout path");
+
+    mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_assistant_message("msg-1", "Before rotation"),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_assistant_message("msg-2", "After rotation"),
+                ev_completed("resp-2"),
+            ]),
+        ],
+    )
+    .await;
+
+    let mut rotated_rollout_path = None;
+    for text in ["first turn", "second turn"] {
+        if text == "second turn" {
+            codex.submit(Op::RotateRollout).await?;
+            let rotated = wait_for_event_match(&codex, |event| match event {
+                EventMsg::RolloutRotated(rotated) => Some(rotated.clone()),
+                _ => None,
+            })
+            .await;
+            assert_eq!(rotated.previous_rollout_path, first_rollout_path);
+            rotated_rollout_path = Some(rotated.rollout_path);
+        }
+        codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.into(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
+            })
+            .await?;
+        wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+    }
+
+    let rotated_rollout_path = rotated_rollout_path.expect("rotated rollout path");
+    assert_ne!(rotated_rollout_path, first_rollout_path);
+
+    // Looking the thread up by id resolves to its newest segment.
+    let thread_id = initial.session_configured.session_id.to_string();
+    let found = find_thread_path_by_id_str(home.path(), &thread_id).await?;
+    assert_eq!(found, Some(rotated_rollout_path.clone()));
+
+    let first_rollout = std::fs::read_to_string(&first_rollout_path)?;
+    assert!(first_rollout.contains("Before rotation"));
+    assert!(!first_rollout.contains("After rotation"));
+
+    let resumed = resume_until_initial_messages(
+        &mut builder,
+        &server,
+        home,
+        rotated_rollout_path,
+        |initial_messages| {
+            initial_messages
+                .iter()
+                .filter(|msg| matches!(msg, EventMsg::TurnComplete(_)))
+                .count()
+                == 2
+        },
+    )
+    .await?;
+    let initial_messages = resumed
+        .session_configured
+        .initial_messages
+        .expect("expected initial messages to be present for resumed session");
+    let user_messages: Vec<&str> = initial_messages
+        .iter()
+        .filter_map(|msg| match msg {
+            EventMsg::UserMessage(user) => Some(user.message.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(user_messages, vec!["first turn", "second turn"]);
+    let agent_messages: Vec<&str> = initial_messages
+        .iter()
+        .filter_map(|msg| match msg {
+            EventMsg::AgentMessage(agent) => Some(agent.message.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(agent_messages, vec!["Before rotation", "After rotation"]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resume_up_to_turn_drops_later_turns_from_history() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let initial = test_codex().build(&server).await?;
+    let codex = Arc::clone(&initial.codex);
+    let rollout_path = initial
+        .session_configured
+        .rollout_path
+        .clone()
+        .expect("rollout path");
+
+    mount_sse_sequence(
+        &server,
+        (1..=3)
+            .map(|index| {
+                sse(vec![
+                    ev_response_created(&format!("resp-{index}")),
+                    ev_assistant_message(&format!("msg-{index}"), &format!("answer {index}")),
+                    ev_completed(&format!("resp-{index}")),
+                ])
+            })
+            .collect(),
+    )
+    .await;
+
+    for text in ["turn one", "turn two", "turn three"] {
+        codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.into(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
+            })
+            .await?;
+        wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+    }
+    codex.submit(Op::Shutdown).await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::ShutdownComplete)).await;
+
+    let resumed = initial
+        .thread_manager
+        .resume_thread_from_rollout_up_to(
+            initial.config.clone(),
+            rollout_path,
+            codex_core::test_support::auth_manager_from_auth(CodexAuth::from_api_key("dummy")),
+            Some(1),
+        )
+        .await?
+        .thread;
+    let rolled_back = wait_for_event_match(&resumed, |event| match event {
+        EventMsg::ThreadRolledBack(event) => Some(event.num_turns),
+        _ => None,
+    })
+    .await;
+    assert_eq!(rolled_back, 2);
+
+    resumed.submit(Op::GetConversationSnapshot).await?;
+    let items = wait_for_event_match(&resumed, |event| match event {
+        EventMsg::ConversationSnapshot(snapshot) => Some(snapshot.items.clone()),
+        _ => None,
+    })
+    .await;
+    let texts = items
+        .iter()
+        .filter_map(|item| match item {
+            ResponseItem::Message { content, .. } => Some(content),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|content| match content {
+            ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                Some(text.as_str())
+            }
+            _ => None,
+        })
+        .filter(|text| text.starts_with("turn ") || text.starts_with("answer "))
+        .collect::<Vec<_>>();
+    assert_eq!(texts, vec!["turn one", "answer 1"]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resume_applies_overridden_approval_and_sandbox_policy() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config.permissions.approval_policy = Constrained::allow_any(AskForApproval::OnRequest);
+        config.permissions.sandbox_policy =
+            Constrained::allow_any(SandboxPolicy::new_workspace_write_policy());
+    });
+    let initial = builder.build(&server).await?;
+    let home = initial.home.clone();
+    let rollout_path = initial
+        .session_configured
+        .rollout_path
+        .clone()
+        .expect("rollout path");
+
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-initial"),
+            ev_assistant_message("msg-1", "Completed first turn"),
+            ev_completed("resp-initial"),
+        ]),
+    )
+    .await;
+    initial
+        .codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "record the original permissions".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+    wait_for_event(&initial.codex, |event| {
+        matches!(event, EventMsg::TurnComplete(_))
+    })
+    .await;
+
+    let mut resume_builder = test_codex().with_config(|config| {
+        config.permissions.approval_policy = Constrained::allow_any(AskForApproval::Never);
+        config.permissions.sandbox_policy =
+            Constrained::allow_any(SandboxPolicy::new_read_only_policy());
+    });
+    let resumed = resume_builder.resume(&server, home, rollout_path).await?;
+
+    assert_eq!(
+        resumed.session_configured.approval_policy,
+        AskForApproval::Never
+    );
+    assert_eq!(
+        resumed.session_configured.sandbox_policy,
+        SandboxPolicy::new_read_only_policy()
+    );
+
+    Ok(())
+}
@@ -66,6 +66,7 @@ fn resume_history(
             RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id,
                 last_agent_message: None,
+                cost_usd: None,
             })),
         ],
         rollout_path: rollout_path.to_path_buf(),
@@ -0,0 +1,44 @@
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_retry_config_reports_session_overrides() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let codex = test_codex()
+        .with_config(|config| {
+            config.model_provider.request_max_retries = Some(1);
+            config.model_provider.stream_max_retries = Some(7);
+            config.model_provider.stream_idle_timeout_ms = Some(5_000);
+        })
+        .build(&server)
+        .await?
+        .codex;
+
+    codex.submit(Op::GetRetryConfig).await?;
+    let retry_config = wait_for_event_match(&codex, |event| match event {
+        EventMsg::RetryConfig(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+
+    assert_eq!(
+        (
+            retry_config.request_max_retries,
+            retry_config.stream_max_retries,
+            retry_config.stream_idle_timeout_ms,
+        ),
+        (1, 7, 5_000)
+    );
+    assert_eq!(retry_config.base_backoff_ms, 200);
+    assert_eq!(retry_config.backoff_factor, 2.0);
+    assert_eq!(retry_config.jitter_ratio, 0.1);
+
+    Ok(())
+}
@@ -24,8 +24,10 @@ use codex_protocol::openai_models::ReasoningEffortPreset;
 use codex_protocol::openai_models::TruncationPolicyConfig;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::McpHealthStatus;
 use codex_protocol::protocol::McpInvocation;
 use codex_protocol::protocol::McpToolCallBeginEvent;
+use codex_protocol::protocol::McpToolCallErrorEvent;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::SandboxPolicy;
 use codex_protocol::user_input::UserInput;
@@ -108,6 +110,8 @@ async fn stdio_server_round_trip() -> anyhow::Result<()> {
                     disabled_tools: None,
                     scopes: None,
                     oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
                 },
             );
             config
@@ -193,6 +197,182 @@ async fn stdio_server_round_trip() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+#[serial(mcp_test_value)]
+async fn stdio_server_list_tools_reports_advertised_tools() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = responses::start_mock_server().await;
+    let server_name = "rmcp_list";
+    let rmcp_test_server_bin = stdio_server_bin()?;
+
+    let fixture = test_codex()
+        .with_config(move |config| {
+            let mut servers = config.mcp_servers.get().clone();
+            servers.insert(
+                server_name.to_string(),
+                McpServerConfig {
+                    transport: McpServerTransportConfig::Stdio {
+                        command: rmcp_test_server_bin,
+                        args: Vec::new(),
+                        env: None,
+                        env_vars: Vec::new(),
+                        cwd: None,
+                    },
+                    enabled: true,
+                    required: false,
+                    disabled_reason: None,
+                    startup_timeout_sec: Some(Duration::from_secs(10)),
+                    tool_timeout_sec: None,
+                    enabled_tools: None,
+                    disabled_tools: None,
+                    scopes: None,
+                    oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
+                },
+            );
+            config
+                .mcp_servers
+                .set(servers)
+                .expect("test mcp servers should accept any configuration");
+        })
+        .build(&server)
+        .await?;
+
+    // Tools are keyed by their qualified name, which carries the server name
+    // callers need to route a later tool call.
+    let prefix = format!("mcp__{server_name}__");
+    let tools_ready_deadline = Instant::now() + Duration::from_secs(30);
+    let mut server_tools = loop {
+        fixture.codex.submit(Op::ListMcpTools).await?;
+        let list_event = wait_for_event_with_timeout(
+            &fixture.codex,
+            |ev| matches!(ev, EventMsg::McpListToolsResponse(_)),
+            Duration::from_secs(10),
+        )
+        .await;
+        let EventMsg::McpListToolsResponse(tool_list) = list_event else {
+            unreachable!("event guard guarantees McpListToolsResponse");
+        };
+        let server_tools: Vec<String> = tool_list
+            .tools
+            .keys()
+            .filter_map(|name| name.strip_prefix(&prefix))
+            .map(str::to_string)
+            .collect();
+        if !server_tools.is_empty() {
+            break server_tools;
+        }
+
+        if Instant::now() >= tools_ready_deadline {
+            panic!("timed out waiting for MCP server {server_name} to report its tools");
+        }
+        sleep(Duration::from_millis(200)).await;
+    };
+
+    server_tools.sort();
+    assert_eq!(
+        server_tools,
+        vec![
+            "echo",
+            "echo_summary",
+            "fail",
+            "image",
+            "image_scenario",
+            "sleep"
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+#[serial(mcp_test_value)]
+async fn mcp_health_check_reports_each_server() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = responses::start_mock_server().await;
+    let rmcp_test_server_bin = stdio_server_bin()?;
+    let stdio_server = |command: String| McpServerConfig {
+        transport: McpServerTransportConfig::Stdio {
+            command,
+            args: Vec::new(),
+            env: None,
+            env_vars: Vec::new(),
+            cwd: None,
+        },
+        enabled: true,
+        required: false,
+        disabled_reason: None,
+        startup_timeout_sec: Some(Duration::from_secs(10)),
+        tool_timeout_sec: None,
+        enabled_tools: None,
+        disabled_tools: None,
+        scopes: None,
+        oauth_resource: None,
+        merge_structured_content: false,
+        max_mcp_retries: 0,
+    };
+
+    let fixture = test_codex()
+        .with_config(move |config| {
+            let mut servers = config.mcp_servers.get().clone();
+            servers.insert("rmcp_alive".to_string(), stdio_server(rmcp_test_server_bin));
+            servers.insert(
+                "rmcp_missing".to_string(),
+                stdio_server("/nonexistent/codex-mcp-test-server".to_string()),
+            );
+            config
+                .mcp_servers
+                .set(servers)
+                .expect("test mcp servers should accept any configuration");
+        })
+        .build(&server)
+        .await?;
+
+    fixture
+        .codex
+        .submit(Op::McpHealthCheck { server: None })
+        .await?;
+    let mut results = Vec::new();
+    while results.len() < 2 {
+        let event = wait_for_event_with_timeout(
+            &fixture.codex,
+            |ev| matches!(ev, EventMsg::McpHealthCheckResult(_)),
+            Duration::from_secs(30),
+        )
+        .await;
+        let EventMsg::McpHealthCheckResult(result) = event else {
+            unreachable!("event guard guarantees McpHealthCheckResult");
+        };
+        results.push(result);
+    }
+
+    assert_eq!(results[0].server, "rmcp_alive");
+    assert_eq!(results[0].status, McpHealthStatus::Healthy);
+    assert_eq!(results[1].server, "rmcp_missing");
+    assert!(
+        matches!(results[1].status, McpHealthStatus::Unreachable { .. }),
+        "missing server should be unreachable: {:?}",
+        results[1].status
+    );
+
+    fixture
+        .codex
+        .submit(Op::McpHealthCheck {
+            server: Some("rmcp_unknown".to_string()),
+        })
+        .await?;
+    let event = wait_for_event(&fixture.codex, |ev| matches!(ev, EventMsg::Error(_))).await;
+    let EventMsg::Error(error) = event else {
+        unreachable!("event guard guarantees Error");
+    };
+    assert_eq!(error.message, "unknown MCP server 'rmcp_unknown'");
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 #[serial(mcp_test_value)]
 async fn stdio_image_responses_round_trip() -> anyhow::Result<()> {
@@ -252,6 +432,8 @@ async fn stdio_image_responses_round_trip() -> anyhow::Result<()> {
                     disabled_tools: None,
                     scopes: None,
                     oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
                 },
             );
             config
@@ -344,103 +526,480 @@ async fn stdio_image_responses_round_trip() -> anyhow::Result<()> {
             arguments: Some(json!({})),
         }
     );
-    let result = end.result.expect("rmcp image tool should return success");
-    assert_eq!(result.is_error, Some(false));
-    assert_eq!(result.content.len(), 1);
-    let base64_only = OPENAI_PNG
-        .strip_prefix("data:image/png;base64,")
-        .expect("data url prefix");
-    let entry = result.content[0].as_object().expect("content object");
-    assert_eq!(entry.get("type"), Some(&json!("image")));
-    assert_eq!(entry.get("mimeType"), Some(&json!("image/png")));
-    assert_eq!(entry.get("data"), Some(&json!(base64_only)));
+    let result = end.result.expect("rmcp image tool should return success");
+    assert_eq!(result.is_error, Some(false));
+    assert_eq!(result.content.len(), 1);
+    let base64_only = OPENAI_PNG
+        .strip_prefix("data:image/png;base64,")
+        .expect("data url prefix");
+    let entry = result.content[0].as_object().expect("content object");
+    assert_eq!(entry.get("type"), Some(&json!("image")));
+    assert_eq!(entry.get("mimeType"), Some(&json!("image/png")));
+    assert_eq!(entry.get("data"), Some(&json!(base64_only)));
+
+    wait_for_event(&fixture.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let output_item = final_mock.single_request().function_call_output(call_id);
+    assert_eq!(
+        output_item,
+        json!({
+            "type": "function_call_output",
+            "call_id": call_id,
+            "output": [{
+                "type": "input_image",
+                "image_url": OPENAI_PNG
+            }]
+        })
+    );
+    server.verify().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+#[serial(mcp_test_value)]
+async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = responses::start_mock_server().await;
+
+    let call_id = "img-text-only-1";
+    let server_name = "rmcp";
+    let tool_name = format!("mcp__{server_name}__image");
+    let text_only_model_slug = "rmcp-text-only-model";
+
+    let models_mock = mount_models_once(
+        &server,
+        ModelsResponse {
+            models: vec![ModelInfo {
+                slug: text_only_model_slug.to_string(),
+                display_name: "RMCP Text Only".to_string(),
+                description: Some("Test model without image input support".to_string()),
+                default_reasoning_level: None,
+                supported_reasoning_levels: vec![ReasoningEffortPreset {
+                    effort: codex_protocol::openai_models::ReasoningEffort::Medium,
+                    description: "Medium".to_string(),
+                }],
+                shell_type: ConfigShellToolType::Default,
+                visibility: ModelVisibility::List,
+                supported_in_api: true,
+                priority: 1,
+                upgrade: None,
+                base_instructions: "base instructions".to_string(),
+                model_messages: None,
+                supports_reasoning_summaries: false,
+                default_reasoning_summary: ReasoningSummary::Auto,
+                support_verbosity: false,
+                default_verbosity: None,
+                availability_nux: None,
+                apply_patch_tool_type: None,
+                web_search_tool_type: Default::default(),
+                truncation_policy: TruncationPolicyConfig::bytes(10_000),
+                supports_parallel_tool_calls: false,
+                supports_image_detail_original: false,
+                context_window: Some(272_000),
+                auto_compact_token_limit: None,
+                effective_context_window_percent: 95,
+                experimental_supported_tools: Vec::new(),
+                input_modalities: vec![InputModality::Text],
+                prefer_websockets: false,
+                used_fallback_model_metadata: false,
+            }],
+        },
+    )
+    .await;
+
+    // First stream: model decides to call the image tool.
+    mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_response_created("resp-1"),
+            responses::ev_function_call(call_id, &tool_name, "{}"),
+            responses::ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    // Second stream: after tool execution, assistant emits a message and completes.
+    let final_mock = mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_assistant_message("msg-1", "rmcp image tool completed successfully."),
+            responses::ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    let rmcp_test_server_bin = stdio_server_bin()?;
+
+    let fixture = test_codex()
+        .with_auth(CodexAuth::create_dummy_chatgpt_auth_for_testing())
+        .with_config(move |config| {
+            let mut servers = config.mcp_servers.get().clone();
+            servers.insert(
+                server_name.to_string(),
+                McpServerConfig {
+                    transport: McpServerTransportConfig::Stdio {
+                        command: rmcp_test_server_bin,
+                        args: Vec::new(),
+                        env: Some(HashMap::from([(
+                            "MCP_TEST_IMAGE_DATA_URL".to_string(),
+                            OPENAI_PNG.to_string(),
+                        )])),
+                        env_vars: Vec::new(),
+                        cwd: None,
+                    },
+                    enabled: true,
+                    required: false,
+                    disabled_reason: None,
+                    startup_timeout_sec: Some(Duration::from_secs(10)),
+                    tool_timeout_sec: None,
+                    enabled_tools: None,
+                    disabled_tools: None,
+                    scopes: None,
+                    oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
+                },
+            );
+            config
+                .mcp_servers
+                .set(servers)
+                .expect("test mcp servers should accept any configuration");
+        })
+        .build(&server)
+        .await?;
+
+    fixture
+        .thread_manager
+        .get_models_manager()
+        .list_models(RefreshStrategy::Online)
+        .await;
+    assert_eq!(models_mock.requests().len(), 1);
+
+    fixture
+        .codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "call the rmcp image tool".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: fixture.cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::new_read_only_policy(),
+            model: text_only_model_slug.to_string(),
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    wait_for_event(&fixture.codex, |ev| {
+        matches!(ev, EventMsg::McpToolCallBegin(_))
+    })
+    .await;
+    wait_for_event(&fixture.codex, |ev| {
+        matches!(ev, EventMsg::McpToolCallEnd(_))
+    })
+    .await;
+    wait_for_event(&fixture.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let output_item = final_mock.single_request().function_call_output(call_id);
+    let output_text = output_item
+        .get("output")
+        .and_then(Value::as_str)
+        .expect("function_call_output output should be a JSON string");
+    let output_json: Value = serde_json::from_str(output_text)
+        .expect("function_call_output output should be valid JSON");
+    assert_eq!(
+        output_json,
+        json!([{
+            "type": "text",
+            "text": "<image content omitted because you do not support image input>"
+        }])
+    );
+    server.verify().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+#[serial(mcp_test_value)]
+async fn stdio_tool_error_result_emits_error_event_and_reaches_model() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = responses::start_mock_server().await;
+
+    let call_id = "call-fail-1";
+    let server_name = "rmcp";
+    let tool_name = format!("mcp__{server_name}__fail");
+    let failure_message = "upstream service unavailable";
+
+    mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_response_created("resp-1"),
+            responses::ev_function_call(
+                call_id,
+                &tool_name,
+                &json!({ "message": failure_message }).to_string(),
+            ),
+            responses::ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let final_mock = mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_assistant_message("msg-1", "rmcp fail tool reported an error."),
+            responses::ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    let rmcp_test_server_bin = stdio_server_bin()?;
+
+    let fixture = test_codex()
+        .with_config(move |config| {
+            let mut servers = config.mcp_servers.get().clone();
+            servers.insert(
+                server_name.to_string(),
+                McpServerConfig {
+                    transport: McpServerTransportConfig::Stdio {
+                        command: rmcp_test_server_bin,
+                        args: Vec::new(),
+                        env: None,
+                        env_vars: Vec::new(),
+                        cwd: None,
+                    },
+                    enabled: true,
+                    required: false,
+                    disabled_reason: None,
+                    startup_timeout_sec: Some(Duration::from_secs(10)),
+                    tool_timeout_sec: None,
+                    enabled_tools: None,
+                    disabled_tools: None,
+                    scopes: None,
+                    oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
+                },
+            );
+            config
+                .mcp_servers
+                .set(servers)
+                .expect("test mcp servers should accept any configuration");
+        })
+        .build(&server)
+        .await?;
+    let session_model = fixture.session_configured.model.clone();
+
+    fixture
+        .codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "call the rmcp fail tool".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: fixture.cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::new_read_only_policy(),
+            model: session_model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    let end_event = wait_for_event(&fixture.codex, |ev| {
+        matches!(ev, EventMsg::McpToolCallEnd(_))
+    })
+    .await;
+    let EventMsg::McpToolCallEnd(end) = end_event else {
+        unreachable!("event guard guarantees McpToolCallEnd");
+    };
+    assert!(!end.is_success());
+
+    let error_event = wait_for_event(&fixture.codex, |ev| {
+        matches!(ev, EventMsg::McpToolCallError(_))
+    })
+    .await;
+    let EventMsg::McpToolCallError(error) = error_event else {
+        unreachable!("event guard guarantees McpToolCallError");
+    };
+    assert_eq!(
+        error,
+        McpToolCallErrorEvent {
+            call_id: call_id.to_string(),
+            invocation: McpInvocation {
+                server: server_name.to_string(),
+                tool: "fail".to_string(),
+                arguments: Some(json!({ "message": failure_message })),
+            },
+            content: vec![json!({ "type": "text", "text": failure_message })],
+        }
+    );
+
+    wait_for_event(&fixture.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let output_text = final_mock
+        .single_request()
+        .function_call_output_text(call_id)
+        .expect("function_call_output should be sent to the model");
+    assert!(
+        output_text.contains(failure_message),
+        "tool error content should reach the model: {output_text}"
+    );
+
+    server.verify().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+#[serial(mcp_test_value)]
+async fn stdio_tool_call_uses_default_tool_timeout() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = responses::start_mock_server().await;
+
+    let call_id = "call-sleep-1";
+    let server_name = "rmcp";
+    let tool_name = format!("mcp__{server_name}__sleep");
+    let default_tool_timeout = Duration::from_millis(500);
+
+    mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_response_created("resp-1"),
+            responses::ev_function_call(
+                call_id,
+                &tool_name,
+                &json!({ "duration_ms": 60_000 }).to_string(),
+            ),
+            responses::ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_assistant_message("msg-1", "rmcp sleep tool timed out."),
+            responses::ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    let rmcp_test_server_bin = stdio_server_bin()?;
+
+    let fixture = test_codex()
+        .with_config(move |config| {
+            config.default_tool_timeout_sec = Some(default_tool_timeout);
+            let mut servers = config.mcp_servers.get().clone();
+            servers.insert(
+                server_name.to_string(),
+                McpServerConfig {
+                    transport: McpServerTransportConfig::Stdio {
+                        command: rmcp_test_server_bin,
+                        args: Vec::new(),
+                        env: None,
+                        env_vars: Vec::new(),
+                        cwd: None,
+                    },
+                    enabled: true,
+                    required: false,
+                    disabled_reason: None,
+                    startup_timeout_sec: Some(Duration::from_secs(10)),
+                    tool_timeout_sec: None,
+                    enabled_tools: None,
+                    disabled_tools: None,
+                    scopes: None,
+                    oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
+                },
+            );
+            config
+                .mcp_servers
+                .set(servers)
+                .expect("test mcp servers should accept any configuration");
+        })
+        .build(&server)
+        .await?;
+    let session_model = fixture.session_configured.model.clone();
+
+    fixture
+        .codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "call the rmcp sleep tool".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: fixture.cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::new_read_only_policy(),
+            model: session_model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    let end_event = wait_for_event_with_timeout(
+        &fixture.codex,
+        |ev| matches!(ev, EventMsg::McpToolCallEnd(_)),
+        Duration::from_secs(20),
+    )
+    .await;
+    let EventMsg::McpToolCallEnd(end) = end_event else {
+        unreachable!("event guard guarantees McpToolCallEnd");
+    };
+    let error = end.result.expect_err("hanging tool call should time out");
+    assert!(
+        error.contains("timed out"),
+        "expected a timeout error, got: {error}"
+    );
+    assert!(
+        end.duration >= default_tool_timeout && end.duration < Duration::from_secs(10),
+        "tool call should stop near the default timeout, took {:?}",
+        end.duration
+    );
 
     wait_for_event(&fixture.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
 
-    let output_item = final_mock.single_request().function_call_output(call_id);
-    assert_eq!(
-        output_item,
-        json!({
-            "type": "function_call_output",
-            "call_id": call_id,
-            "output": [{
-                "type": "input_image",
-                "image_url": OPENAI_PNG
-            }]
-        })
-    );
     server.verify().await;
     Ok(())
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 #[serial(mcp_test_value)]
-async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Result<()> {
+async fn stdio_merge_structured_content_sends_both_payloads_to_the_model() -> anyhow::Result<()> {
     skip_if_no_network!(Ok(()));
 
     let server = responses::start_mock_server().await;
 
-    let call_id = "img-text-only-1";
+    let call_id = "call-merge-1";
     let server_name = "rmcp";
-    let tool_name = format!("mcp__{server_name}__image");
-    let text_only_model_slug = "rmcp-text-only-model";
-
-    let models_mock = mount_models_once(
-        &server,
-        ModelsResponse {
-            models: vec![ModelInfo {
-                slug: text_only_model_slug.to_string(),
-                display_name: "RMCP Text Only".to_string(),
-                description: Some("Test model without image input support".to_string()),
-                default_reasoning_level: None,
-                supported_reasoning_levels: vec![ReasoningEffortPreset {
-                    effort: codex_protocol::openai_models::ReasoningEffort::Medium,
-                    description: "Medium".to_string(),
-                }],
-                shell_type: ConfigShellToolType::Default,
-                visibility: ModelVisibility::List,
-                supported_in_api: true,
-                priority: 1,
-                upgrade: None,
-                base_instructions: "base instructions".to_string(),
-                model_messages: None,
-                supports_reasoning_summaries: false,
-                default_reasoning_summary: ReasoningSummary::Auto,
-                support_verbosity: false,
-                default_verbosity: None,
-                availability_nux: None,
-                apply_patch_tool_type: None,
-                web_search_tool_type: Default::default(),
-                truncation_policy: TruncationPolicyConfig::bytes(10_000),
-                supports_parallel_tool_calls: false,
-                supports_image_detail_original: false,
-                context_window: Some(272_000),
-                auto_compact_token_limit: None,
-                effective_context_window_percent: 95,
-                experimental_supported_tools: Vec::new(),
-                input_modalities: vec![InputModality::Text],
-                prefer_websockets: false,
-                used_fallback_model_metadata: false,
-            }],
-        },
-    )
-    .await;
+    let tool_name = format!("mcp__{server_name}__echo_summary");
 
-    // First stream: model decides to call the image tool.
     mount_sse_once(
         &server,
         responses::sse(vec![
             responses::ev_response_created("resp-1"),
-            responses::ev_function_call(call_id, &tool_name, "{}"),
+            responses::ev_function_call(call_id, &tool_name, "{\"message\":\"ping\"}"),
             responses::ev_completed("resp-1"),
         ]),
     )
     .await;
-    // Second stream: after tool execution, assistant emits a message and completes.
     let final_mock = mount_sse_once(
         &server,
         responses::sse(vec![
-            responses::ev_assistant_message("msg-1", "rmcp image tool completed successfully."),
+            responses::ev_assistant_message("msg-1", "rmcp echo tool completed successfully."),
             responses::ev_completed("resp-2"),
         ]),
     )
@@ -449,7 +1008,6 @@ async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Re
     let rmcp_test_server_bin = stdio_server_bin()?;
 
     let fixture = test_codex()
-        .with_auth(CodexAuth::create_dummy_chatgpt_auth_for_testing())
         .with_config(move |config| {
             let mut servers = config.mcp_servers.get().clone();
             servers.insert(
@@ -458,10 +1016,7 @@ async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Re
                     transport: McpServerTransportConfig::Stdio {
                         command: rmcp_test_server_bin,
                         args: Vec::new(),
-                        env: Some(HashMap::from([(
-                            "MCP_TEST_IMAGE_DATA_URL".to_string(),
-                            OPENAI_PNG.to_string(),
-                        )])),
+                        env: None,
                         env_vars: Vec::new(),
                         cwd: None,
                     },
@@ -474,6 +1029,8 @@ async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Re
                     disabled_tools: None,
                     scopes: None,
                     oauth_resource: None,
+                    merge_structured_content: true,
+                    max_mcp_retries: 0,
                 },
             );
             config
@@ -483,26 +1040,20 @@ async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Re
         })
         .build(&server)
         .await?;
-
-    fixture
-        .thread_manager
-        .get_models_manager()
-        .list_models(RefreshStrategy::Online)
-        .await;
-    assert_eq!(models_mock.requests().len(), 1);
+    let session_model = fixture.session_configured.model.clone();
 
     fixture
         .codex
         .submit(Op::UserTurn {
             items: vec![UserInput::Text {
-                text: "call the rmcp image tool".into(),
+                text: "call the rmcp echo_summary tool".into(),
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
             cwd: fixture.cwd.path().to_path_buf(),
             approval_policy: AskForApproval::Never,
             sandbox_policy: SandboxPolicy::new_read_only_policy(),
-            model: text_only_model_slug.to_string(),
+            model: session_model,
             effort: None,
             summary: None,
             service_tier: None,
@@ -511,30 +1062,44 @@ async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Re
         })
         .await?;
 
-    wait_for_event(&fixture.codex, |ev| {
-        matches!(ev, EventMsg::McpToolCallBegin(_))
-    })
-    .await;
-    wait_for_event(&fixture.codex, |ev| {
+    let end_event = wait_for_event(&fixture.codex, |ev| {
         matches!(ev, EventMsg::McpToolCallEnd(_))
     })
     .await;
+    let EventMsg::McpToolCallEnd(end) = end_event else {
+        unreachable!("event guard guarantees McpToolCallEnd");
+    };
+    let result = end
+        .result
+        .as_ref()
+        .expect("rmcp echo_summary tool should return success");
+    // The event reports the result as the server returned it.
+    assert_eq!(
+        result.content,
+        vec![json!({"type": "text", "text": "summary: ping"})]
+    );
+    assert_eq!(
+        result.structured_content,
+        Some(json!({"echo": "ECHOING: ping"}))
+    );
+
     wait_for_event(&fixture.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
 
     let output_item = final_mock.single_request().function_call_output(call_id);
-    let output_text = output_item
+    let output = output_item
         .get("output")
         .and_then(Value::as_str)
-        .expect("function_call_output output should be a JSON string");
-    let output_json: Value = serde_json::from_str(output_text)
-        .expect("function_call_output output should be valid JSON");
+        .expect("function_call_output should include text output");
+    let output: Value = serde_json::from_str(output)?;
+    // The model sees the text content followed by the structured payload.
     assert_eq!(
-        output_json,
-        json!([{
-            "type": "text",
-            "text": "<image content omitted because you do not support image input>"
-        }])
+        output,
+        json!([
+            {"type": "text", "text": "summary: ping"},
+            {"type": "text", "text": "{\"echo\":\"ECHOING: ping\"}"},
+        ])
     );
+
     server.verify().await;
     Ok(())
 }
@@ -594,6 +1159,8 @@ async fn stdio_server_propagates_whitelisted_env_vars() -> anyhow::Result<()> {
                     disabled_tools: None,
                     scopes: None,
                     oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
                 },
             );
             config
@@ -755,6 +1322,8 @@ async fn streamable_http_tool_call_round_trip() -> anyhow::Result<()> {
                     disabled_tools: None,
                     scopes: None,
                     oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
                 },
             );
             config
@@ -854,6 +1423,158 @@ async fn streamable_http_tool_call_round_trip() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn streamable_http_tool_call_retries_after_transport_error() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = responses::start_mock_server().await;
+
+    let call_id = "call-retry";
+    let server_name = "rmcp_http_retry";
+    let tool_name = format!("mcp__{server_name}__echo");
+
+    mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_response_created("resp-1"),
+            responses::ev_function_call(call_id, &tool_name, "{\"message\":\"ping\"}"),
+            responses::ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_assistant_message("msg-1", "echo tool completed after a retry."),
+            responses::ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    let rmcp_http_server_bin = match cargo_bin("test_streamable_http_server") {
+        Ok(path) => path,
+        Err(err) => {
+            eprintln!("test_streamable_http_server binary not available, skipping test: {err}");
+            return Ok(());
+        }
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+    drop(listener);
+    let bind_addr = format!("127.0.0.1:{port}");
+    let server_url = format!("http://{bind_addr}/mcp");
+
+    let mut http_server_child = Command::new(&rmcp_http_server_bin)
+        .kill_on_drop(true)
+        .env("MCP_STREAMABLE_HTTP_BIND_ADDR", &bind_addr)
+        .spawn()?;
+
+    wait_for_streamable_http_server(&mut http_server_child, &bind_addr, Duration::from_secs(5))
+        .await?;
+
+    let fixture = test_codex()
+        .with_config(move |config| {
+            let mut servers = config.mcp_servers.get().clone();
+            servers.insert(
+                server_name.to_string(),
+                McpServerConfig {
+                    transport: McpServerTransportConfig::StreamableHttp {
+                        url: server_url,
+                        bearer_token_env_var: None,
+                        http_headers: None,
+                        env_http_headers: None,
+                    },
+                    enabled: true,
+                    required: false,
+                    disabled_reason: None,
+                    startup_timeout_sec: Some(Duration::from_secs(10)),
+                    tool_timeout_sec: None,
+                    enabled_tools: None,
+                    disabled_tools: None,
+                    scopes: None,
+                    oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 2,
+                },
+            );
+            config
+                .mcp_servers
+                .set(servers)
+                .expect("test mcp servers should accept any configuration");
+        })
+        .build(&server)
+        .await?;
+    let session_model = fixture.session_configured.model.clone();
+
+    // The next request on the established MCP session fails at the HTTP
+    // layer, so the first tools/call attempt sees a transport error.
+    arm_session_post_failure(&bind_addr, 503, 1).await?;
+
+    fixture
+        .codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "call the rmcp streamable http echo tool".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: fixture.cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::new_read_only_policy(),
+            model: session_model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    let retry_event = wait_for_event(&fixture.codex, |ev| {
+        matches!(ev, EventMsg::BackgroundEvent(_))
+    })
+    .await;
+    let EventMsg::BackgroundEvent(retry) = retry_event else {
+        unreachable!("event guard guarantees BackgroundEvent");
+    };
+    assert_eq!(
+        retry.message,
+        format!("Retrying MCP tool `{server_name}/echo` after a transport error (1/2)")
+    );
+
+    let end_event = wait_for_event(&fixture.codex, |ev| {
+        matches!(ev, EventMsg::McpToolCallEnd(_))
+    })
+    .await;
+    let EventMsg::McpToolCallEnd(end) = end_event else {
+        unreachable!("event guard guarantees McpToolCallEnd");
+    };
+    let result = end
+        .result
+        .as_ref()
+        .expect("retried rmcp echo tool should return success");
+    assert_eq!(result.is_error, Some(false));
+    let echo_value = result
+        .structured_content
+        .as_ref()
+        .and_then(|structured| structured.get("echo"))
+        .and_then(Value::as_str)
+        .expect("echo payload present");
+    assert_eq!(echo_value, "ECHOING: ping");
+
+    wait_for_event(&fixture.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    server.verify().await;
+
+    let _ = http_server_child.kill().await;
+    if let Err(error) = http_server_child.wait().await {
+        eprintln!("failed to await streamable http server shutdown: {error}");
+    }
+
+    Ok(())
+}
+
 /// This test writes to a fallback credentials file in CODEX_HOME.
 /// Ideally, we wouldn't need to serialize the test but it's much more cumbersome to wire CODEX_HOME through the code.
 #[test]
@@ -976,6 +1697,8 @@ async fn streamable_http_with_oauth_round_trip_impl() -> anyhow::Result<()> {
                     disabled_tools: None,
                     scopes: None,
                     oauth_resource: None,
+                    merge_structured_content: false,
+                    max_mcp_retries: 0,
                 },
             );
             config
@@ -1100,6 +1823,27 @@ async fn streamable_http_with_oauth_round_trip_impl() -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn arm_session_post_failure(
+    address: &str,
+    status: u16,
+    remaining: usize,
+) -> anyhow::Result<()> {
+    let response = Client::builder()
+        .no_proxy()
+        .build()?
+        .post(format!(
+            "http://{address}/test/control/session-post-failure"
+        ))
+        .json(&json!({
+            "status": status,
+            "remaining": remaining,
+        }))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    Ok(())
+}
+
 async fn wait_for_streamable_http_server(
     server_child: &mut Child,
     address: &str,
@@ -0,0 +1,93 @@
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn search_history_returns_matching_item_indices() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_assistant_message("msg-1", "The Zephyrine parser lives in src/parse.rs."),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let codex = test_codex().build(&server).await?.codex;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "where is the zephyrine parser?".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    codex
+        .submit(Op::SearchHistory {
+            query: "ZEPHYRINE".to_string(),
+        })
+        .await?;
+    let results = wait_for_event_match(&codex, |event| match event {
+        EventMsg::SearchHistoryResults(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(
+        results.snippets,
+        vec![
+            "where is the zephyrine parser?".to_string(),
+            "The Zephyrine parser lives in src/parse.rs.".to_string(),
+        ]
+    );
+
+    codex.submit(Op::GetConversationSnapshot).await?;
+    let snapshot = wait_for_event_match(&codex, |event| match event {
+        EventMsg::ConversationSnapshot(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    let roles = results
+        .indices
+        .iter()
+        .map(|&index| match &snapshot.items[index] {
+            ResponseItem::Message { role, .. } => role.as_str(),
+            other => panic!("expected a message at index {index}, got {other:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(roles, vec!["user", "assistant"]);
+
+    codex
+        .submit(Op::SearchHistory {
+            query: "not discussed".to_string(),
+        })
+        .await?;
+    let results = wait_for_event_match(&codex, |event| match event {
+        EventMsg::SearchHistoryResults(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(results.indices, Vec::<usize>::new());
+
+    Ok(())
+}
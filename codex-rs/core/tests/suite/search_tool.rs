@@ -136,6 +136,8 @@ fn rmcp_server_config(command: String) -> McpServerConfig {
         disabled_tools: None,
         scopes: None,
         oauth_resource: None,
+        merge_structured_content: false,
+        max_mcp_retries: 0,
     }
 }
 
@@ -179,6 +181,8 @@ async fn submit_user_input(thread: &Arc<CodexThread>, text: &str) -> Result<()>
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(thread, |event| matches!(event, EventMsg::TurnComplete(_))).await;
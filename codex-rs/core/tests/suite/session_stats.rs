@@ -0,0 +1,84 @@
+#![allow(clippy::expect_used)]
+
+use anyhow::Result;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_test_macros::large_stack_test;
+use core_test_support::responses::ev_apply_patch_function_call;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_completed_with_tokens;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::ev_shell_command_call;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+
+#[large_stack_test]
+async fn session_stats_aggregate_completed_turns() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let patch = "*** Begin Patch\n*** Add File: notes.txt\n+hello\n*** End Patch";
+    mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_shell_command_call("call-exec", "echo hi"),
+                ev_completed_with_tokens("resp-1", 100),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_apply_patch_function_call("call-patch", patch),
+                ev_completed("resp-2"),
+            ]),
+            sse(vec![
+                ev_assistant_message("msg-1", "done"),
+                ev_completed("resp-3"),
+            ]),
+        ],
+    )
+    .await;
+    let test = test_codex()
+        .with_model("gpt-5.1")
+        .with_config(|config| {
+            config.include_apply_patch_tool = true;
+        })
+        .build(&server)
+        .await?;
+
+    test.submit_turn("run a command, then add notes.txt")
+        .await?;
+    assert_eq!(
+        std::fs::read_to_string(test.cwd.path().join("notes.txt"))?,
+        "hello\n"
+    );
+
+    test.codex.submit(Op::GetSessionStats).await?;
+    let stats = wait_for_event_match(&test.codex, |event| match event {
+        EventMsg::SessionStats(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+
+    assert_eq!(
+        (
+            stats.turns_completed,
+            stats.total_tokens,
+            stats.exec_calls,
+            stats.patches_applied,
+            stats.files_changed,
+        ),
+        (1, 100, 1, 1, 1)
+    );
+    assert!(
+        stats.history_items >= 5,
+        "history should hold the user message, both calls, their outputs, and the reply: {stats:?}"
+    );
+    Ok(())
+}
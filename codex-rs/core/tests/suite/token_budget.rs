@@ -0,0 +1,119 @@
+use codex_protocol::protocol::CodexErrorInfo;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::protocol::TokenBudgetEvent;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed_with_tokens;
+use core_test_support::responses::ev_function_call;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+
+fn user_input(text: &str) -> Op {
+    Op::UserInput {
+        items: vec![UserInput::Text {
+            text: text.into(),
+            text_elements: Vec::new(),
+        }],
+        final_output_json_schema: None,
+        effort: None,
+        tool_choice: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn exceeding_token_budget_stops_turn_and_rejects_input() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let responses = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_function_call("call-1", "nonexistent_tool", "{}"),
+                ev_completed_with_tokens("resp-1", 500),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_assistant_message("msg-2", "back within budget"),
+                ev_completed_with_tokens("resp-2", 10),
+            ]),
+        ],
+    )
+    .await;
+    let codex = test_codex().build(&server).await?.codex;
+
+    let budget_event = |event: &EventMsg| match event {
+        EventMsg::TokenBudget(event) => Some(event.clone()),
+        _ => None,
+    };
+
+    codex
+        .submit(Op::SetBudget {
+            max_total_tokens: Some(100),
+        })
+        .await?;
+    assert_eq!(
+        wait_for_event_match(&codex, budget_event).await,
+        TokenBudgetEvent {
+            max_total_tokens: Some(100),
+            total_tokens: 0,
+        }
+    );
+
+    // The tool call needs a follow-up request, but the budget is already spent.
+    codex.submit(user_input("call a tool")).await?;
+    let mut errors = Vec::new();
+    wait_for_event(&codex, |event| match event {
+        EventMsg::Error(error) => {
+            errors.push(error.message.clone());
+            false
+        }
+        EventMsg::TurnComplete(_) => true,
+        _ => false,
+    })
+    .await;
+    assert_eq!(
+        errors,
+        vec!["Stopped the turn because the session exceeded its token budget of 100.".to_string()]
+    );
+    assert_eq!(responses.requests().len(), 1);
+
+    codex.submit(user_input("keep going")).await?;
+    let rejected = wait_for_event_match(&codex, |event| match event {
+        EventMsg::Error(error) => Some(error.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(rejected.codex_error_info, Some(CodexErrorInfo::BadRequest));
+
+    codex.submit(Op::GetBudget).await?;
+    assert_eq!(
+        wait_for_event_match(&codex, budget_event).await,
+        TokenBudgetEvent {
+            max_total_tokens: Some(100),
+            total_tokens: 500,
+        }
+    );
+    assert_eq!(responses.requests().len(), 1);
+
+    codex
+        .submit(Op::SetBudget {
+            max_total_tokens: None,
+        })
+        .await?;
+    wait_for_event_match(&codex, budget_event).await;
+    codex.submit(user_input("keep going")).await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+    assert_eq!(responses.requests().len(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use codex_protocol::protocol::CodexErrorInfo;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_function_call;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+fn user_input(text: &str) -> Op {
+    Op::UserInput {
+        items: vec![UserInput::Text {
+            text: text.to_string(),
+            text_elements: Vec::new(),
+        }],
+        final_output_json_schema: None,
+        effort: None,
+        tool_choice: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn repeated_identical_tool_calls_stop_the_turn() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let plan_args = json!({
+        "plan": [{"step": "Check the workspace", "status": "in_progress"}],
+    })
+    .to_string();
+    let mut bodies = (1..=3)
+        .map(|index| {
+            sse(vec![
+                ev_response_created(&format!("resp-{index}")),
+                ev_function_call(&format!("call-{index}"), "update_plan", &plan_args),
+                ev_completed(&format!("resp-{index}")),
+            ])
+        })
+        .collect::<Vec<_>>();
+    bodies.push(sse(vec![
+        ev_response_created("resp-4"),
+        ev_assistant_message("msg-1", "Trying something else."),
+        ev_completed("resp-4"),
+    ]));
+    let mock = mount_sse_sequence(&server, bodies).await;
+
+    let codex = test_codex()
+        .with_config(|config| {
+            config.tool_call_loop_threshold = 3;
+        })
+        .build(&server)
+        .await?
+        .codex;
+
+    codex.submit(user_input("update the plan")).await?;
+    let event = wait_for_event(&codex, |event| matches!(event, EventMsg::Error(_))).await;
+    let EventMsg::Error(error) = event else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::Other));
+    assert!(
+        error.message.contains("update_plan"),
+        "error should name the repeated tool: {}",
+        error.message
+    );
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+    assert_eq!(mock.requests().len(), 3);
+
+    codex.submit(user_input("try again")).await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 4);
+    let developer_texts = requests[3].message_input_texts("developer");
+    assert!(
+        developer_texts
+            .iter()
+            .any(|text| text.contains("identical arguments 3 times in a row")),
+        "model should be told it was repeating itself: {developer_texts:?}"
+    );
+
+    Ok(())
+}
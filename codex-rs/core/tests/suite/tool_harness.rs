@@ -4,6 +4,7 @@ use std::fs;
 
 use assert_matches::assert_matches;
 use codex_core::features::Feature;
+use codex_protocol::models::ResponseItem;
 use codex_protocol::plan_tool::StepStatus;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::EventMsg;
@@ -25,6 +26,7 @@ use core_test_support::skip_if_no_network;
 use core_test_support::test_codex::TestCodex;
 use core_test_support::test_codex::test_codex;
 use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
 use serde_json::Value;
 use serde_json::json;
 fn call_output(req: &ResponsesRequest, call_id: &str) -> (String, Option<bool>) {
@@ -107,6 +109,162 @@ async fn shell_tool_executes_command_and_streams_output() -> anyhow::Result<()>
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn shell_tool_reports_acceptable_exit_code_as_success() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+
+    let mut builder = test_codex().with_model("gpt-5").with_config(|config| {
+        config
+            .acceptable_exit_codes
+            .insert("diff".to_string(), vec![1]);
+    });
+    let TestCodex {
+        codex,
+        cwd,
+        session_configured,
+        ..
+    } = builder.build(&server).await?;
+    fs::write(cwd.path().join("left.txt"), "left\n")?;
+    fs::write(cwd.path().join("right.txt"), "right\n")?;
+
+    let call_id = "diff-call";
+    let command = vec!["diff", "left.txt", "right.txt"];
+    let first_response = sse(vec![
+        ev_response_created("resp-1"),
+        ev_local_shell_call(call_id, "completed", command),
+        ev_completed("resp-1"),
+    ]);
+    responses::mount_sse_once(&server, first_response).await;
+
+    let second_response = sse(vec![
+        ev_assistant_message("msg-1", "files differ"),
+        ev_completed("resp-2"),
+    ]);
+    let second_mock = responses::mount_sse_once(&server, second_response).await;
+
+    let session_model = session_configured.model.clone();
+
+    codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "compare the files".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            model: session_model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let req = second_mock.single_request();
+    let (output_text, _) = call_output(&req, call_id);
+    let exec_output: Value = serde_json::from_str(&output_text)?;
+    assert_eq!(exec_output["metadata"]["exit_code"], 1);
+
+    // The success flag is not sent on the wire, so read it back from history.
+    codex.submit(Op::GetConversationSnapshot).await?;
+    let items = wait_for_event_match(&codex, |event| match event {
+        EventMsg::ConversationSnapshot(snapshot) => Some(snapshot.items.clone()),
+        _ => None,
+    })
+    .await;
+    let success = items.iter().find_map(|item| match item {
+        ResponseItem::FunctionCallOutput {
+            call_id: output_call_id,
+            output,
+        } if output_call_id == call_id => Some(output.success),
+        _ => None,
+    });
+    assert_eq!(success, Some(Some(true)));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn local_shell_missing_working_directory_returns_corrective_output() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+
+    let mut builder = test_codex().with_model("gpt-5");
+    let TestCodex {
+        codex,
+        cwd,
+        session_configured,
+        ..
+    } = builder.build(&server).await?;
+
+    let call_id = "shell-missing-workdir";
+    let first_response = sse(vec![
+        ev_response_created("resp-1"),
+        json!({
+            "type": "response.output_item.done",
+            "item": {
+                "type": "local_shell_call",
+                "call_id": call_id,
+                "status": "completed",
+                "action": {
+                    "type": "exec",
+                    "command": ["/bin/echo", "unreachable"],
+                    "working_directory": "missing-dir",
+                }
+            }
+        }),
+        ev_completed("resp-1"),
+    ]);
+    responses::mount_sse_once(&server, first_response).await;
+
+    let second_response = sse(vec![
+        ev_assistant_message("msg-1", "retrying"),
+        ev_completed("resp-2"),
+    ]);
+    let second_mock = responses::mount_sse_once(&server, second_response).await;
+
+    let session_model = session_configured.model.clone();
+
+    codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "run the command in missing-dir".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            model: session_model,
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+
+    let req = second_mock.single_request();
+    let (output_text, _) = call_output(&req, call_id);
+    assert!(
+        output_text.contains("missing-dir` does not exist or is not a directory"),
+        "expected corrective output, got {output_text:?}"
+    );
+    assert!(!output_text.contains("unreachable"));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn update_plan_tool_emits_plan_update_event() -> anyhow::Result<()> {
     skip_if_no_network!(Ok(()));
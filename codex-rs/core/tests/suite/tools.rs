@@ -9,7 +9,10 @@ use anyhow::Context;
 use anyhow::Result;
 use codex_core::features::Feature;
 use codex_core::sandboxing::SandboxPermissions;
+use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
 use codex_protocol::protocol::SandboxPolicy;
 use core_test_support::assert_regex_match;
 use core_test_support::responses::ev_assistant_message;
@@ -23,6 +26,7 @@ use core_test_support::responses::sse;
 use core_test_support::responses::start_mock_server;
 use core_test_support::skip_if_no_network;
 use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event_match;
 use regex_lite::Regex;
 use serde_json::Value;
 use serde_json::json;
@@ -281,6 +285,79 @@ async fn sandbox_denied_shell_returns_original_output() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn sandbox_denied_shell_is_not_masked_by_any_command_exit_codes() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex()
+        .with_model("gpt-5.1-codex")
+        .with_config(|config| {
+            config
+                .acceptable_exit_codes
+                .insert("*".to_string(), vec![1, 2]);
+        });
+    let fixture = builder.build(&server).await?;
+
+    let call_id = "sandbox-denied-any-exit-code";
+    let target_path = fixture.workspace_path("sandbox-denied.txt");
+    let command = vec![
+        "/bin/sh".to_string(),
+        "-c".to_string(),
+        format!("printf denied > {target_path:?}"),
+    ];
+    let args = json!({
+        "command": command,
+        "timeout_ms": 1_000,
+    });
+
+    let responses = vec![
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_function_call(call_id, "shell", &serde_json::to_string(&args)?),
+            ev_completed("resp-1"),
+        ]),
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-2"),
+        ]),
+    ];
+    let mock = mount_sse_sequence(&server, responses).await;
+
+    fixture
+        .submit_turn_with_policy(
+            "run a command that should be denied by the read-only sandbox",
+            SandboxPolicy::new_read_only_policy(),
+        )
+        .await?;
+
+    let output_text = mock
+        .function_call_output_text(call_id)
+        .context("shell output present")?;
+    assert!(
+        !output_text.starts_with("Exit code: 0"),
+        "sandbox denial should surface a non-zero exit code: {output_text}"
+    );
+
+    // The success flag is not sent on the wire, so read it back from history.
+    fixture.codex.submit(Op::GetConversationSnapshot).await?;
+    let items = wait_for_event_match(&fixture.codex, |event| match event {
+        EventMsg::ConversationSnapshot(snapshot) => Some(snapshot.items.clone()),
+        _ => None,
+    })
+    .await;
+    let success = items.iter().find_map(|item| match item {
+        ResponseItem::FunctionCallOutput {
+            call_id: output_call_id,
+            output,
+        } if output_call_id == call_id => Some(output.success),
+        _ => None,
+    });
+    assert_eq!(success, Some(Some(false)));
+
+    Ok(())
+}
+
 async fn collect_tools(use_unified_exec: bool) -> Result<Vec<String>> {
     let server = start_mock_server().await;
 
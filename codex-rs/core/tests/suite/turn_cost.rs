@@ -0,0 +1,80 @@
+use codex_core::config::types::ModelPricing;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed_with_tokens;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event_match;
+use pretty_assertions::assert_eq;
+
+const MODEL: &str = "gpt-5.1-codex";
+
+async fn turn_cost(pricing: Option<ModelPricing>) -> anyhow::Result<Option<f64>> {
+    let server = start_mock_server().await;
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_assistant_message("msg-1", "done"),
+            ev_completed_with_tokens("resp-1", 2_000_000),
+        ]),
+    )
+    .await;
+    let codex = test_codex()
+        .with_model(MODEL)
+        .with_config(move |config| {
+            config.pricing.clear();
+            if let Some(pricing) = pricing {
+                config.pricing.insert(MODEL.to_string(), pricing);
+            }
+        })
+        .build(&server)
+        .await?
+        .codex;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "hello".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+    let cost_usd = wait_for_event_match(&codex, |event| match event {
+        EventMsg::TurnComplete(event) => Some(event.cost_usd),
+        _ => None,
+    })
+    .await;
+    Ok(cost_usd)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn turn_complete_reports_estimated_cost_for_priced_model() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let cost_usd = turn_cost(Some(ModelPricing {
+        input_per_million: 1.5,
+        output_per_million: 6.0,
+    }))
+    .await?;
+
+    assert_eq!(cost_usd, Some(3.0));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn turn_complete_omits_cost_for_unpriced_model() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    assert_eq!(turn_cost(None).await?, None);
+    Ok(())
+}
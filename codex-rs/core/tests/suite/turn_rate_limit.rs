@@ -0,0 +1,89 @@
+#![allow(clippy::expect_used)]
+
+use anyhow::Result;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use codex_protocol::protocol::TurnAbortReason;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_function_call;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn follow_up_request_waits_for_rate_limit_until_interrupted() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mock = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_function_call("call-1", "nonexistent", "{}"),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_assistant_message("msg-1", "done"),
+                ev_completed("resp-2"),
+            ]),
+        ],
+    )
+    .await;
+
+    let mut builder = test_codex().with_config(|config| {
+        config.max_turns_per_minute = Some(1);
+    });
+    let test = builder.build(&server).await?;
+
+    test.codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "call a tool".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+
+    // The tool output needs a second model request, which the one-per-minute
+    // limit holds back.
+    let waiting = wait_for_event(&test.codex, |event| match event {
+        EventMsg::BackgroundEvent(event) => event.message.contains("per minute"),
+        EventMsg::TurnComplete(_) => true,
+        _ => false,
+    })
+    .await;
+    let EventMsg::BackgroundEvent(waiting) = waiting else {
+        panic!("expected the turn to wait for the rate limit, got {waiting:?}");
+    };
+    assert!(
+        waiting
+            .message
+            .starts_with("Reached the limit of 1 model requests per minute"),
+        "unexpected message: {}",
+        waiting.message
+    );
+
+    test.codex.submit(Op::Interrupt).await?;
+    let aborted = wait_for_event(&test.codex, |event| {
+        matches!(event, EventMsg::TurnAborted(_) | EventMsg::TurnComplete(_))
+    })
+    .await;
+    let EventMsg::TurnAborted(aborted) = aborted else {
+        panic!("expected the waiting turn to be interruptible, got {aborted:?}");
+    };
+    assert_eq!(aborted.reason, TurnAbortReason::Interrupted);
+    assert_eq!(mock.requests().len(), 1);
+
+    Ok(())
+}
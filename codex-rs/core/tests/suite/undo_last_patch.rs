@@ -0,0 +1,228 @@
+#![cfg(not(target_os = "windows"))]
+
+use std::fs;
+
+use codex_core::features::Feature;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::CodexErrorInfo;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::FileChange;
+use codex_protocol::protocol::Op;
+use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::user_input::UserInput;
+use core_test_support::responses::ev_apply_patch_function_call;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::TestCodex;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn undo_last_patch_reverts_previous_turn_changes() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config
+            .features
+            .enable(Feature::ApplyPatchFreeform)
+            .expect("test config should allow feature update");
+    });
+    let TestCodex {
+        codex,
+        cwd,
+        session_configured,
+        ..
+    } = builder.build(&server).await?;
+
+    let existing = cwd.path().join("existing.txt");
+    let added = cwd.path().join("added.txt");
+    fs::write(&existing, "before\n")?;
+    let patch = r#"*** Begin Patch
+*** Update File: existing.txt
+@@
+-before
++after
+*** Add File: added.txt
++brand new
+*** End Patch"#;
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_apply_patch_function_call("apply-patch-call", patch),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-2"),
+            ev_assistant_message("msg-1", "patched"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "please apply a patch".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            model: session_configured.model.clone(),
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+    assert_eq!(fs::read_to_string(&existing)?, "after\n");
+    assert_eq!(fs::read_to_string(&added)?, "brand new\n");
+
+    codex.submit(Op::UndoLastPatch).await?;
+    let mut begin = None;
+    let mut end = None;
+    wait_for_event(&codex, |event| match event {
+        EventMsg::PatchApplyBegin(event) => {
+            begin = Some(event.clone());
+            false
+        }
+        EventMsg::PatchApplyEnd(event) => {
+            end = Some(event.clone());
+            false
+        }
+        EventMsg::TurnComplete(_) => true,
+        _ => false,
+    })
+    .await;
+
+    let begin = begin.expect("expected PatchApplyBegin event");
+    assert!(begin.auto_approved);
+    assert!(matches!(
+        begin.changes.get(&added),
+        Some(FileChange::Delete { .. })
+    ));
+    assert!(matches!(
+        begin.changes.get(&existing),
+        Some(FileChange::Update { .. })
+    ));
+    let end = end.expect("expected PatchApplyEnd event");
+    assert!(end.success, "undo failed: {}", end.stderr);
+    assert_eq!(fs::read_to_string(&existing)?, "before\n");
+    assert!(!added.exists());
+
+    // Only the last turn's changes can be undone, and only once.
+    codex.submit(Op::UndoLastPatch).await?;
+    let event = wait_for_event(&codex, |event| matches!(event, EventMsg::Error(_))).await;
+    let EventMsg::Error(error) = event else {
+        unreachable!("wait_for_event returned unexpected event");
+    };
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::BadRequest));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn undo_last_patch_refuses_to_overwrite_later_edits() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config
+            .features
+            .enable(Feature::ApplyPatchFreeform)
+            .expect("test config should allow feature update");
+    });
+    let TestCodex {
+        codex,
+        cwd,
+        session_configured,
+        ..
+    } = builder.build(&server).await?;
+
+    let existing = cwd.path().join("existing.txt");
+    fs::write(&existing, "before\n")?;
+    let patch = r#"*** Begin Patch
+*** Update File: existing.txt
+@@
+-before
++after
+*** End Patch"#;
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_apply_patch_function_call("apply-patch-call", patch),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-2"),
+            ev_assistant_message("msg-1", "patched"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    codex
+        .submit(Op::UserTurn {
+            items: vec![UserInput::Text {
+                text: "please apply a patch".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            cwd: cwd.path().to_path_buf(),
+            approval_policy: AskForApproval::Never,
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            model: session_configured.model.clone(),
+            effort: None,
+            summary: None,
+            service_tier: None,
+            collaboration_mode: None,
+            personality: None,
+        })
+        .await?;
+    wait_for_event(&codex, |event| matches!(event, EventMsg::TurnComplete(_))).await;
+    assert_eq!(fs::read_to_string(&existing)?, "after\n");
+
+    // The user keeps editing the file after the turn.
+    fs::write(&existing, "after\nuser edit\n")?;
+
+    codex.submit(Op::UndoLastPatch).await?;
+    let event = wait_for_event(&codex, |event| {
+        matches!(
+            event,
+            EventMsg::Error(_) | EventMsg::PatchApplyBegin(_) | EventMsg::TurnStarted(_)
+        )
+    })
+    .await;
+    let EventMsg::Error(error) = event else {
+        panic!("expected the undo to be rejected, got {event:?}");
+    };
+    assert_eq!(error.codex_error_info, Some(CodexErrorInfo::BadRequest));
+    assert!(
+        error.message.contains("existing.txt"),
+        "unexpected error: {}",
+        error.message
+    );
+    assert_eq!(fs::read_to_string(&existing)?, "after\nuser edit\n");
+
+    Ok(())
+}
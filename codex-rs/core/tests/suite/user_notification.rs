@@ -18,7 +18,9 @@ use tempfile::TempDir;
 
 use responses::ev_assistant_message;
 use responses::ev_completed;
+use responses::mount_response_once;
 use responses::sse;
+use responses::sse_response;
 use responses::start_mock_server;
 use std::time::Duration;
 
@@ -62,6 +64,8 @@ mv "${tmp_path}" "${payload_path}""#,
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await?;
     wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
@@ -77,3 +81,129 @@ mv "${tmp_path}" "${payload_path}""#,
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn set_notify_command_applies_to_next_turn() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    responses::mount_sse_once(
+        &server,
+        sse(vec![ev_assistant_message("m1", "Done"), ev_completed("r1")]),
+    )
+    .await;
+
+    let notify_dir = TempDir::new()?;
+    let notify_script = notify_dir.path().join("notify.sh");
+    std::fs::write(
+        &notify_script,
+        r#"#!/bin/bash
+set -e
+payload_path="$(dirname "${0}")/notify.txt"
+tmp_path="${payload_path}.tmp"
+echo -n "${@: -1}" > "${tmp_path}"
+mv "${tmp_path}" "${payload_path}""#,
+    )?;
+    std::fs::set_permissions(&notify_script, std::fs::Permissions::from_mode(0o755))?;
+    let notify_file = notify_dir.path().join("notify.txt");
+
+    let TestCodex { codex, .. } = test_codex().build(&server).await?;
+
+    codex
+        .submit(Op::SetNotifyCommand {
+            command: Some(vec![notify_script.to_string_lossy().into_owned()]),
+        })
+        .await?;
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "notify me".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    fs_wait::wait_for_path_exists(&notify_file, Duration::from_secs(5)).await?;
+    let payload: Value = serde_json::from_str(&tokio::fs::read_to_string(&notify_file).await?)?;
+    assert_eq!(payload["type"], json!("agent-turn-complete"));
+    assert_eq!(payload["input-messages"], json!(["notify me"]));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn turn_running_long_notifies_without_aborting() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    mount_response_once(
+        &server,
+        sse_response(sse(vec![
+            ev_assistant_message("m1", "Done"),
+            ev_completed("r1"),
+        ]))
+        .set_delay(Duration::from_secs(3)),
+    )
+    .await;
+
+    let notify_dir = TempDir::new()?;
+    // Append every payload so the long-running notification is not replaced
+    // by the one sent when the turn completes.
+    let notify_script = notify_dir.path().join("notify.sh");
+    std::fs::write(
+        &notify_script,
+        r#"#!/bin/bash
+set -e
+echo "${@: -1}" >> "$(dirname "${0}")/notify.jsonl""#,
+    )?;
+    std::fs::set_permissions(&notify_script, std::fs::Permissions::from_mode(0o755))?;
+    let notify_file = notify_dir.path().join("notify.jsonl");
+    let notify_script_str = notify_script.to_str().unwrap().to_string();
+
+    let TestCodex { codex, .. } = test_codex()
+        .with_config(move |cfg| {
+            cfg.notify = Some(vec![notify_script_str]);
+            cfg.turn_running_long_seconds = Some(1);
+        })
+        .build(&server)
+        .await?;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "take your time".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
+        })
+        .await?;
+
+    let event = wait_for_event(&codex, |ev| match ev {
+        EventMsg::BackgroundEvent(event) => event.message.contains("running for over"),
+        EventMsg::TurnComplete(_) => true,
+        _ => false,
+    })
+    .await;
+    let EventMsg::BackgroundEvent(background) = event else {
+        panic!("expected a long-running notice before the turn completed, got {event:?}");
+    };
+    assert_eq!(background.message, "Turn has been running for over 1s.");
+
+    // The turn is only reported on, not aborted.
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    fs_wait::wait_for_path_exists(&notify_file, Duration::from_secs(5)).await?;
+    let notify_payloads = tokio::fs::read_to_string(&notify_file).await?;
+    let first_line = notify_payloads.lines().next().unwrap_or_default();
+    let payload: Value = serde_json::from_str(first_line)?;
+    assert_eq!(payload["type"], json!("turn-running-long"));
+    assert_eq!(payload["elapsed-seconds"], json!(1));
+
+    Ok(())
+}
@@ -8,6 +8,7 @@ use codex_protocol::protocol::ExecCommandSource;
 use codex_protocol::protocol::ExecOutputStream;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::TurnAbortReason;
 use codex_protocol::user_input::UserInput;
 use core_test_support::assert_regex_match;
@@ -26,6 +27,7 @@ use core_test_support::wait_for_event_match;
 use core_test_support::wait_for_event_with_timeout;
 use pretty_assertions::assert_eq;
 use regex_lite::escape;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use tempfile::TempDir;
 use tokio::time::Duration;
@@ -96,6 +98,85 @@ async fn user_shell_cmd_ls_and_cat_in_temp_dir() {
     assert_eq!(stdout, contents);
 }
 
+#[tokio::test]
+async fn user_shell_cmd_is_not_executed_in_dry_run() {
+    let cwd = TempDir::new().unwrap();
+    let server = start_mock_server().await;
+    let cwd_path = cwd.path().to_path_buf();
+    let mut builder = test_codex().with_config(move |config| {
+        config.cwd = cwd_path;
+        config.dry_run = true;
+    });
+    let codex = builder
+        .build(&server)
+        .await
+        .expect("create new conversation")
+        .codex;
+
+    codex
+        .submit(Op::RunUserShellCommand {
+            command: "touch created.txt".to_string(),
+        })
+        .await
+        .unwrap();
+    let msg = wait_for_event(&codex, |ev| matches!(ev, EventMsg::ExecCommandEnd(_))).await;
+    let EventMsg::ExecCommandEnd(ExecCommandEndEvent {
+        aggregated_output,
+        exit_code,
+        ..
+    }) = msg
+    else {
+        unreachable!()
+    };
+    assert_eq!(exit_code, 0);
+    assert_eq!(
+        aggregated_output,
+        "[dry-run] command not executed: touch created.txt"
+    );
+    assert!(!cwd.path().join("created.txt").exists());
+}
+
+#[tokio::test]
+async fn user_shell_cmd_is_recorded_in_sandbox_bypass_audit_log() {
+    let server = start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config.sandbox_bypass_audit_log = Some(config.codex_home.join("sandbox-audit.jsonl"));
+    });
+    let test = builder
+        .build(&server)
+        .await
+        .expect("create new conversation");
+    let audit_log = test.codex_home_path().join("sandbox-audit.jsonl");
+
+    test.codex
+        .submit(Op::RunUserShellCommand {
+            command: "echo audited".to_string(),
+        })
+        .await
+        .unwrap();
+    let msg = wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::ExecCommandEnd(_))).await;
+    let EventMsg::ExecCommandEnd(ExecCommandEndEvent { call_id, .. }) = msg else {
+        unreachable!()
+    };
+
+    let contents = std::fs::read_to_string(&audit_log).expect("read audit log");
+    let entries: Vec<serde_json::Value> = contents
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("valid audit entry"))
+        .collect();
+    assert_eq!(entries.len(), 1, "unexpected audit entries: {contents}");
+    assert_eq!(entries[0]["call_id"], call_id.as_str());
+    assert_eq!(entries[0]["decision"], "not_required");
+    assert!(
+        entries[0]["command"]
+            .as_array()
+            .and_then(|args| args.last())
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|arg| arg.contains("echo audited")),
+        "unexpected audit entry: {contents}"
+    );
+}
+
 #[tokio::test]
 async fn user_shell_cmd_can_be_interrupted() {
     // Set up isolated config and conversation.
@@ -209,10 +290,12 @@ async fn user_shell_command_does_not_replace_active_turn() -> anyhow::Result<()>
     let mut saw_user_shell_end = false;
     let mut saw_turn_complete = false;
     for _ in 0..200 {
-        let event = timeout(Duration::from_secs(20), fixture.codex.next_event())
+        let event = fixture
+            .codex
+            .next_event_timeout(Duration::from_secs(20))
             .await
-            .context("timed out waiting for event")?
-            .context("event stream ended unexpectedly")?;
+            .context("event stream ended unexpectedly")?
+            .context("timed out waiting for event")?;
         match event.msg {
             EventMsg::TurnAborted(ev) if ev.reason == TurnAbortReason::Replaced => {
                 saw_replaced_abort = true;
@@ -359,6 +442,47 @@ async fn user_shell_command_does_not_set_network_sandbox_env_var() -> anyhow::Re
     Ok(())
 }
 
+#[tokio::test]
+#[cfg(not(target_os = "windows"))]
+async fn user_shell_command_uses_session_environment_overrides() -> anyhow::Result<()> {
+    let server = responses::start_mock_server().await;
+    let mut builder = test_codex().with_config(|config| {
+        config.permissions.shell_environment_policy.r#set = HashMap::from([
+            ("CODEX_TEST_OVERRIDDEN".to_string(), "policy".to_string()),
+            ("CODEX_TEST_REMOVED".to_string(), "policy".to_string()),
+        ]);
+    });
+    let test = builder.build(&server).await?;
+
+    test.codex
+        .submit(Op::SetEnvironmentVariable {
+            name: "CODEX_TEST_OVERRIDDEN".to_string(),
+            value: "session".to_string(),
+        })
+        .await?;
+    test.codex
+        .submit(Op::UnsetEnvironmentVariable {
+            name: "CODEX_TEST_REMOVED".to_string(),
+        })
+        .await?;
+    let command =
+        r#"printf '%s|%s' "${CODEX_TEST_OVERRIDDEN:-not-set}" "${CODEX_TEST_REMOVED:-not-set}""#
+            .to_string();
+    test.codex
+        .submit(Op::RunUserShellCommand { command })
+        .await?;
+
+    let end_event = wait_for_event_match(&test.codex, |ev| match ev {
+        EventMsg::ExecCommandEnd(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(end_event.exit_code, 0);
+    assert_eq!(end_event.stdout.trim(), "session|not-set");
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 #[cfg(not(target_os = "windows"))] // TODO: unignore on windows
 async fn user_shell_command_output_is_truncated_in_history() -> anyhow::Result<()> {
@@ -487,3 +611,113 @@ async fn user_shell_command_is_truncated_only_once() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(not(windows))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn user_shell_command_output_uses_separate_channel_when_enabled() -> anyhow::Result<()> {
+    let server = start_mock_server().await;
+    let mut builder = test_codex()
+        .with_session_source(SessionSource::Cli)
+        .with_config(|config| {
+            config.separate_exec_output_channel = true;
+        });
+    let test = builder.build(&server).await?;
+    let exec_output_events = test
+        .codex
+        .exec_output_events()
+        .expect("exec output channel should be enabled");
+
+    test.codex
+        .submit(Op::RunUserShellCommand {
+            command: "printf separate-channel".to_string(),
+        })
+        .await?;
+
+    loop {
+        let event = test
+            .codex
+            .next_event_timeout(Duration::from_secs(10))
+            .await?
+            .context("timed out waiting for event")?;
+        match event.msg {
+            EventMsg::ExecCommandOutputDelta(_) => {
+                panic!("exec output delta should not arrive on the main event channel")
+            }
+            EventMsg::TurnComplete(_) => break,
+            _ => {}
+        }
+    }
+
+    let event = timeout(Duration::from_secs(1), exec_output_events.recv())
+        .await
+        .context("timed out waiting for exec output event")??;
+    let EventMsg::ExecCommandOutputDelta(delta) = event.msg else {
+        panic!("expected ExecCommandOutputDelta, got {:?}", event.msg);
+    };
+    assert_eq!(delta.stream, ExecOutputStream::Stdout);
+    assert_eq!(String::from_utf8(delta.chunk)?, "separate-channel");
+
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg(not(target_os = "windows"))]
+async fn run_command_op_executes_without_model_or_history() -> anyhow::Result<()> {
+    let server = responses::start_mock_server().await;
+    let test = test_codex().build(&server).await?;
+
+    test.codex
+        .submit(Op::RunCommand {
+            command: vec!["echo".to_string(), "run command".to_string()],
+            cwd: None,
+            timeout_ms: Some(10_000),
+        })
+        .await?;
+
+    let begin_event = wait_for_event_match(&test.codex, |ev| match ev {
+        EventMsg::ExecCommandBegin(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(
+        begin_event.command,
+        vec!["echo".to_string(), "run command".to_string()]
+    );
+    assert_eq!(begin_event.source, ExecCommandSource::UserShell);
+    assert_eq!(begin_event.cwd, test.cwd_path());
+
+    let end_event = wait_for_event_match(&test.codex, |ev| match ev {
+        EventMsg::ExecCommandEnd(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(end_event.call_id, begin_event.call_id);
+    assert_eq!(end_event.exit_code, 0);
+    assert_eq!(end_event.stdout, "run command\n");
+    wait_for_event(&test.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let model_requests = server
+        .received_requests()
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|request| request.url.path().ends_with("/responses"))
+        .count();
+    assert_eq!(model_requests, 0);
+    test.codex.submit(Op::GetConversationSnapshot).await?;
+    let snapshot = wait_for_event_match(&test.codex, |ev| match ev {
+        EventMsg::ConversationSnapshot(event) => Some(event.clone()),
+        _ => None,
+    })
+    .await;
+    assert!(
+        snapshot
+            .items
+            .iter()
+            .all(|item| !format!("{item:?}").contains("run command")),
+        "command should not be recorded to history: {:?}",
+        snapshot.items
+    );
+
+    Ok(())
+}
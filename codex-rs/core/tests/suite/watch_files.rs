@@ -0,0 +1,106 @@
+#![allow(clippy::expect_used)]
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::mount_sse_once;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use core_test_support::wait_for_event;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn watched_file_change_submits_rendered_prompt() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let response = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_assistant_message("msg-1", "fixed"),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let test = test_codex().build(&server).await?;
+    let notes = test.cwd.path().join("notes.txt");
+    std::fs::write(&notes, "hello\n")?;
+
+    test.codex
+        .submit(Op::WatchFiles {
+            paths: vec![PathBuf::from("notes.txt")],
+            prompt_template: "{file} changed, please review:\n{diff}".to_string(),
+        })
+        .await?;
+    // Ops are handled in order, so once the stats reply arrives the watch is
+    // registered.
+    test.codex.submit(Op::GetSessionStats).await?;
+    wait_for_event(&test.codex, |event| {
+        matches!(event, EventMsg::SessionStats(_))
+    })
+    .await;
+
+    std::fs::write(&notes, "hello\nworld\n")?;
+    wait_for_event(&test.codex, |event| {
+        matches!(event, EventMsg::TurnComplete(_))
+    })
+    .await;
+
+    let request = response.single_request();
+    assert!(request.body_contains_text("changed, please review:"));
+    assert!(request.body_contains_text("+world"));
+
+    test.codex.submit(Op::UnwatchFiles).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn file_watch_survives_interrupt() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let response = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_assistant_message("msg-1", "fixed"),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let test = test_codex().build(&server).await?;
+    let notes = test.cwd.path().join("notes.txt");
+    std::fs::write(&notes, "hello\n")?;
+
+    test.codex
+        .submit(Op::WatchFiles {
+            paths: vec![PathBuf::from("notes.txt")],
+            prompt_template: "{file} changed:\n{diff}".to_string(),
+        })
+        .await?;
+    test.codex.submit(Op::Interrupt).await?;
+    test.codex.submit(Op::GetSessionStats).await?;
+    wait_for_event(&test.codex, |event| {
+        matches!(event, EventMsg::SessionStats(_))
+    })
+    .await;
+
+    std::fs::write(&notes, "hello\nworld\n")?;
+    wait_for_event(&test.codex, |event| {
+        matches!(event, EventMsg::TurnComplete(_))
+    })
+    .await;
+
+    let request = response.single_request();
+    assert!(request.body_contains_text("+world"));
+
+    test.codex.submit(Op::UnwatchFiles).await?;
+    Ok(())
+}
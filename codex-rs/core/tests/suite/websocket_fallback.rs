@@ -16,7 +16,6 @@ use core_test_support::test_codex::TestCodex;
 use core_test_support::test_codex::test_codex;
 use pretty_assertions::assert_eq;
 use tokio::time::Duration;
-use tokio::time::timeout;
 use wiremock::Mock;
 use wiremock::ResponseTemplate;
 use wiremock::http::Method;
@@ -178,10 +177,11 @@ async fn websocket_fallback_hides_first_websocket_retry_stream_error() -> Result
 
     let mut stream_error_messages = Vec::new();
     loop {
-        let event = timeout(Duration::from_secs(10), codex.next_event())
+        let event = codex
+            .next_event_timeout(Duration::from_secs(10))
             .await
-            .expect("timeout waiting for event")
             .expect("event stream ended unexpectedly")
+            .expect("timeout waiting for event")
             .msg;
         match event {
             EventMsg::StreamError(e) => stream_error_messages.push(e.message),
@@ -521,6 +521,24 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                                 eprintln!("{}", line.style(self.green));
                             }
                         }
+                        FileChange::Binary { .. } => {
+                            let header = format!(
+                                "{} {} {}",
+                                format_file_change(change),
+                                path.to_string_lossy(),
+                                change.binary_description().unwrap_or_default()
+                            );
+                            eprintln!("{}", header.style(self.magenta));
+                        }
+                        FileChange::Symlink { target } => {
+                            let header = format!(
+                                "{} {} -> {}",
+                                format_file_change(change),
+                                path.to_string_lossy(),
+                                target.to_string_lossy()
+                            );
+                            eprintln!("{}", header.style(self.magenta));
+                        }
                         FileChange::Delete { content } => {
                             let header = format!(
                                 "{} {}",
@@ -861,6 +879,7 @@ impl EventProcessor for EventProcessorWithHumanOutput {
             EventMsg::ShutdownComplete => return CodexStatus::Shutdown,
             EventMsg::ThreadNameUpdated(_)
             | EventMsg::ExecApprovalRequest(_)
+            | EventMsg::ExecApprovalBatchRequest(_)
             | EventMsg::ApplyPatchApprovalRequest(_)
             | EventMsg::TerminalInteraction(_)
             | EventMsg::ExecCommandOutputDelta(_)
@@ -870,6 +889,32 @@ impl EventProcessor for EventProcessorWithHumanOutput {
             | EventMsg::ListSkillsResponse(_)
             | EventMsg::ListRemoteSkillsResponse(_)
             | EventMsg::RemoteSkillDownloaded(_)
+            | EventMsg::McpToolCallError(_)
+            | EventMsg::PauseStateChanged(_)
+            | EventMsg::PatchCheckResult(_)
+            | EventMsg::RecentErrors(_)
+            | EventMsg::ConversationSnapshot(_)
+            | EventMsg::ApprovalPolicyChanged(_)
+            | EventMsg::WorkingDirectoryChanged(_)
+            | EventMsg::ExportComplete(_)
+            | EventMsg::AssistantFinished(_)
+            | EventMsg::ToolCallArgumentsPreview(_)
+            | EventMsg::SessionForked(_)
+            | EventMsg::RolloutRotated(_)
+            | EventMsg::RetryConfig(_)
+            | EventMsg::McpHealthCheckResult(_)
+            | EventMsg::SearchHistoryResults(_)
+            | EventMsg::Features(_)
+            | EventMsg::TokenBudget(_)
+            | EventMsg::SessionStats(_)
+            | EventMsg::PluginList(_)
+            | EventMsg::BlameResult(_)
+            | EventMsg::LastReasoning(_)
+            | EventMsg::PluginValidationResult(_)
+            | EventMsg::BaseInstructionsUpdated(_)
+            | EventMsg::ProviderToolCall(_)
+            | EventMsg::WorkingTreeHash(_)
+            | EventMsg::SessionStateExported(_)
             | EventMsg::RawResponseItem(_)
             | EventMsg::UserMessage(_)
             | EventMsg::EnteredReviewMode(_)
@@ -1023,6 +1068,7 @@ impl EventProcessorWithHumanOutput {
                     | EventMsg::TokenCount(_)
                     | EventMsg::TurnStarted(_)
                     | EventMsg::ExecApprovalRequest(_)
+                    | EventMsg::ExecApprovalBatchRequest(_)
                     | EventMsg::ApplyPatchApprovalRequest(_)
                     | EventMsg::TerminalInteraction(_)
                     | EventMsg::ExecCommandOutputDelta(_)
@@ -1032,6 +1078,32 @@ impl EventProcessorWithHumanOutput {
                     | EventMsg::ListSkillsResponse(_)
                     | EventMsg::ListRemoteSkillsResponse(_)
                     | EventMsg::RemoteSkillDownloaded(_)
+                    | EventMsg::McpToolCallError(_)
+                    | EventMsg::PauseStateChanged(_)
+                    | EventMsg::PatchCheckResult(_)
+                    | EventMsg::RecentErrors(_)
+                    | EventMsg::ConversationSnapshot(_)
+                    | EventMsg::ApprovalPolicyChanged(_)
+                    | EventMsg::WorkingDirectoryChanged(_)
+                    | EventMsg::ExportComplete(_)
+                    | EventMsg::AssistantFinished(_)
+                    | EventMsg::ToolCallArgumentsPreview(_)
+                    | EventMsg::SessionForked(_)
+                    | EventMsg::RolloutRotated(_)
+                    | EventMsg::RetryConfig(_)
+                    | EventMsg::McpHealthCheckResult(_)
+                    | EventMsg::SearchHistoryResults(_)
+                    | EventMsg::Features(_)
+                    | EventMsg::TokenBudget(_)
+                    | EventMsg::SessionStats(_)
+                    | EventMsg::PluginList(_)
+                    | EventMsg::BlameResult(_)
+                    | EventMsg::LastReasoning(_)
+                    | EventMsg::PluginValidationResult(_)
+                    | EventMsg::BaseInstructionsUpdated(_)
+                    | EventMsg::ProviderToolCall(_)
+                    | EventMsg::WorkingTreeHash(_)
+                    | EventMsg::SessionStateExported(_)
                     | EventMsg::RawResponseItem(_)
                     | EventMsg::UserMessage(_)
                     | EventMsg::EnteredReviewMode(_)
@@ -1231,7 +1303,7 @@ fn escape_command(command: &[String]) -> String {
 
 fn format_file_change(change: &FileChange) -> &'static str {
     match change {
-        FileChange::Add { .. } => "A",
+        FileChange::Add { .. } | FileChange::Binary { .. } | FileChange::Symlink { .. } => "A",
         FileChange::Delete { .. } => "D",
         FileChange::Update {
             move_path: Some(_), ..
@@ -638,7 +638,9 @@ impl EventProcessorWithJsonOutput {
 
     fn map_change_kind(&self, kind: &protocol::FileChange) -> PatchChangeKind {
         match kind {
-            protocol::FileChange::Add { .. } => PatchChangeKind::Add,
+            protocol::FileChange::Add { .. }
+            | protocol::FileChange::Binary { .. }
+            | protocol::FileChange::Symlink { .. } => PatchChangeKind::Add,
             protocol::FileChange::Delete { .. } => PatchChangeKind::Delete,
             protocol::FileChange::Update { .. } => PatchChangeKind::Update,
         }
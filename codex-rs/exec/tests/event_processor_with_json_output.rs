@@ -317,6 +317,7 @@ fn plan_update_emits_todo_list_started_updated_and_completed() {
         EventMsg::TurnComplete(codex_protocol::protocol::TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     );
     let out_complete = ep.collect_thread_events(&complete);
@@ -690,6 +691,7 @@ fn plan_update_after_complete_starts_new_todo_list_with_new_id() {
         EventMsg::TurnComplete(codex_protocol::protocol::TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     );
     let _ = ep.collect_thread_events(&complete);
@@ -844,6 +846,7 @@ fn error_followed_by_task_complete_produces_turn_failed() {
         EventMsg::TurnComplete(codex_protocol::protocol::TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     );
     assert_eq!(
@@ -1156,6 +1159,7 @@ fn patch_apply_success_produces_item_completed_patchapply() {
             turn_id: "turn-1".to_string(),
             auto_approved: true,
             changes: changes.clone(),
+            dry_run: false,
         }),
     );
     let out_begin = ep.collect_thread_events(&begin);
@@ -1172,6 +1176,7 @@ fn patch_apply_success_produces_item_completed_patchapply() {
             success: true,
             changes: changes.clone(),
             status: CorePatchApplyStatus::Completed,
+            dry_run: false,
         }),
     );
     let out_end = ep.collect_thread_events(&end);
@@ -1229,6 +1234,7 @@ fn patch_apply_failure_produces_item_completed_patchapply_failed() {
             turn_id: "turn-2".to_string(),
             auto_approved: false,
             changes: changes.clone(),
+            dry_run: false,
         }),
     );
     assert!(ep.collect_thread_events(&begin).is_empty());
@@ -1244,6 +1250,7 @@ fn patch_apply_failure_produces_item_completed_patchapply_failed() {
             success: false,
             changes: changes.clone(),
             status: CorePatchApplyStatus::Failed,
+            dry_run: false,
         }),
     );
     let out_end = ep.collect_thread_events(&end);
@@ -1298,6 +1305,7 @@ fn task_complete_produces_turn_completed_with_usage() {
         EventMsg::TurnComplete(codex_protocol::protocol::TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("done".to_string()),
+            cost_usd: None,
         }),
     );
     let out = ep.collect_thread_events(&complete_event);
@@ -23,6 +23,15 @@ enum UserNotification {
         input_messages: Vec<String>,
         last_assistant_message: Option<String>,
     },
+    #[serde(rename_all = "kebab-case")]
+    TurnRunningLong {
+        thread_id: String,
+        turn_id: String,
+        cwd: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        client: Option<String>,
+        elapsed_seconds: u64,
+    },
 }
 
 pub fn legacy_notify_json(payload: &HookPayload) -> Result<String, serde_json::Error> {
@@ -37,8 +46,17 @@ pub fn legacy_notify_json(payload: &HookPayload) -> Result<String, serde_json::E
                 last_assistant_message: event.last_assistant_message.clone(),
             })
         }
+        HookEvent::TurnRunningLong { event } => {
+            serde_json::to_string(&UserNotification::TurnRunningLong {
+                thread_id: event.thread_id.to_string(),
+                turn_id: event.turn_id.clone(),
+                cwd: payload.cwd.display().to_string(),
+                client: payload.client.clone(),
+                elapsed_seconds: event.elapsed_seconds,
+            })
+        }
         HookEvent::AfterToolUse { .. } => Err(serde_json::Error::io(std::io::Error::other(
-            "legacy notify payload is only supported for after_agent",
+            "legacy notify payload is only supported for after_agent and turn_running_long",
         ))),
     }
 }
@@ -83,6 +101,7 @@ mod tests {
 
     use super::*;
     use crate::HookEventAfterAgent;
+    use crate::HookEventTurnRunningLong;
 
     fn expected_notification_json() -> Value {
         json!({
@@ -142,4 +161,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn legacy_notify_json_serializes_turn_running_long() -> Result<()> {
+        let payload = HookPayload {
+            session_id: ThreadId::new(),
+            cwd: Path::new("/Users/example/project").to_path_buf(),
+            client: None,
+            triggered_at: chrono::Utc::now(),
+            hook_event: HookEvent::TurnRunningLong {
+                event: HookEventTurnRunningLong {
+                    thread_id: ThreadId::from_string("b5f6c1c2-1111-2222-3333-444455556666")
+                        .expect("valid thread id"),
+                    turn_id: "12345".to_string(),
+                    elapsed_seconds: 600,
+                },
+            },
+        };
+
+        let serialized = legacy_notify_json(&payload)?;
+        let actual: Value = serde_json::from_str(&serialized)?;
+        assert_eq!(
+            actual,
+            json!({
+                "type": "turn-running-long",
+                "thread-id": "b5f6c1c2-1111-2222-3333-444455556666",
+                "turn-id": "12345",
+                "cwd": "/Users/example/project",
+                "elapsed-seconds": 600,
+            })
+        );
+
+        Ok(())
+    }
 }
@@ -20,6 +20,7 @@ pub use types::Hook;
 pub use types::HookEvent;
 pub use types::HookEventAfterAgent;
 pub use types::HookEventAfterToolUse;
+pub use types::HookEventTurnRunningLong;
 pub use types::HookPayload;
 pub use types::HookResponse;
 pub use types::HookResult;
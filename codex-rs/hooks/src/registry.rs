@@ -1,3 +1,7 @@
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
 use codex_config::ConfigLayerStack;
 use tokio::process::Command;
 
@@ -23,7 +27,8 @@ pub struct HooksConfig {
 
 #[derive(Clone)]
 pub struct Hooks {
-    after_agent: Vec<Hook>,
+    /// Shared with clones so `set_legacy_notify_argv` reaches every holder.
+    after_agent: Arc<RwLock<Vec<Hook>>>,
     after_tool_use: Vec<Hook>,
     engine: ClaudeHooksEngine,
 }
@@ -36,12 +41,7 @@ impl Default for Hooks {
 
 impl Hooks {
     pub fn new(config: HooksConfig) -> Self {
-        let after_agent = config
-            .legacy_notify_argv
-            .filter(|argv| !argv.is_empty() && !argv[0].is_empty())
-            .map(crate::notify_hook)
-            .into_iter()
-            .collect();
+        let after_agent = legacy_notify_hooks(config.legacy_notify_argv);
         let engine = ClaudeHooksEngine::new(
             config.feature_enabled,
             config.config_layer_stack.as_ref(),
@@ -51,7 +51,7 @@ impl Hooks {
             },
         );
         Self {
-            after_agent,
+            after_agent: Arc::new(RwLock::new(after_agent)),
             after_tool_use: Vec::new(),
             engine,
         }
@@ -61,17 +61,32 @@ impl Hooks {
         self.engine.warnings()
     }
 
-    fn hooks_for_event(&self, hook_event: &HookEvent) -> &[Hook] {
+    /// Replace the legacy `notify` command used for future `AfterAgent` and
+    /// `TurnRunningLong` events. `None` disables it.
+    pub fn set_legacy_notify_argv(&self, argv: Option<Vec<String>>) {
+        let mut after_agent = self
+            .after_agent
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        *after_agent = legacy_notify_hooks(argv);
+    }
+
+    fn hooks_for_event(&self, hook_event: &HookEvent) -> Vec<Hook> {
         match hook_event {
-            HookEvent::AfterAgent { .. } => &self.after_agent,
-            HookEvent::AfterToolUse { .. } => &self.after_tool_use,
+            // Long-running turn warnings reuse the legacy `notify` command.
+            HookEvent::AfterAgent { .. } | HookEvent::TurnRunningLong { .. } => self
+                .after_agent
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone(),
+            HookEvent::AfterToolUse { .. } => self.after_tool_use.clone(),
         }
     }
 
     pub async fn dispatch(&self, hook_payload: HookPayload) -> Vec<HookResponse> {
         let hooks = self.hooks_for_event(&hook_payload.hook_event);
         let mut outcomes = Vec::with_capacity(hooks.len());
-        for hook in hooks {
+        for hook in &hooks {
             let outcome = hook.execute(&hook_payload).await;
             let should_abort_operation = outcome.result.should_abort_operation();
             outcomes.push(outcome);
@@ -110,6 +125,13 @@ impl Hooks {
     }
 }
 
+fn legacy_notify_hooks(argv: Option<Vec<String>>) -> Vec<Hook> {
+    argv.filter(|argv| !argv.is_empty() && !argv[0].is_empty())
+        .map(crate::notify_hook)
+        .into_iter()
+        .collect()
+}
+
 pub fn command_from_argv(argv: &[String]) -> Option<Command> {
     let (program, args) = argv.split_first()?;
     if program.is_empty() {
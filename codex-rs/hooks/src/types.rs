@@ -81,6 +81,14 @@ pub struct HookEventAfterAgent {
     pub last_assistant_message: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HookEventTurnRunningLong {
+    pub thread_id: ThreadId,
+    pub turn_id: String,
+    pub elapsed_seconds: u64,
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum HookToolKind {
@@ -155,6 +163,10 @@ pub enum HookEvent {
         #[serde(flatten)]
         event: HookEventAfterToolUse,
     },
+    TurnRunningLong {
+        #[serde(flatten)]
+        event: HookEventTurnRunningLong,
+    },
 }
 
 #[cfg(test)]
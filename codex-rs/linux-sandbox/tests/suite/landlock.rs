@@ -122,6 +122,7 @@ async fn run_cmd_result_with_policies(
         windows_sandbox_level: WindowsSandboxLevel::Disabled,
         justification: None,
         arg0: None,
+        stdin: None,
     };
     let sandbox_program = env!("CARGO_BIN_EXE_codex-linux-sandbox");
     let codex_linux_sandbox_exe = Some(PathBuf::from(sandbox_program));
@@ -354,6 +355,7 @@ async fn assert_network_blocked(cmd: &[&str]) {
         windows_sandbox_level: WindowsSandboxLevel::Disabled,
         justification: None,
         arg0: None,
+        stdin: None,
     };
 
     let sandbox_policy = SandboxPolicy::new_read_only_policy();
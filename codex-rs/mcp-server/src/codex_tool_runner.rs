@@ -114,6 +114,8 @@ pub async fn run_codex_tool_session(
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         },
         trace: None,
     };
@@ -161,6 +163,8 @@ pub async fn run_codex_tool_session_reply(
                 text_elements: Vec::new(),
             }],
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         })
         .await
     {
@@ -230,6 +234,7 @@ async fn run_codex_tool_session_inner(
                             additional_permissions: _,
                             skill_metadata: _,
                             available_decisions: _,
+                            stdin: _,
                         } = ev;
                         handle_exec_approval_request(
                             command,
@@ -336,6 +341,33 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::ListSkillsResponse(_)
                     | EventMsg::ListRemoteSkillsResponse(_)
                     | EventMsg::RemoteSkillDownloaded(_)
+                    | EventMsg::McpToolCallError(_)
+                    | EventMsg::PauseStateChanged(_)
+                    | EventMsg::PatchCheckResult(_)
+                    | EventMsg::RecentErrors(_)
+                    | EventMsg::ConversationSnapshot(_)
+                    | EventMsg::ApprovalPolicyChanged(_)
+                    | EventMsg::WorkingDirectoryChanged(_)
+                    | EventMsg::ExportComplete(_)
+                    | EventMsg::AssistantFinished(_)
+                    | EventMsg::ToolCallArgumentsPreview(_)
+                    | EventMsg::SessionForked(_)
+                    | EventMsg::RolloutRotated(_)
+                    | EventMsg::RetryConfig(_)
+                    | EventMsg::McpHealthCheckResult(_)
+                    | EventMsg::SearchHistoryResults(_)
+                    | EventMsg::Features(_)
+                    | EventMsg::TokenBudget(_)
+                    | EventMsg::SessionStats(_)
+                    | EventMsg::PluginList(_)
+                    | EventMsg::BlameResult(_)
+                    | EventMsg::LastReasoning(_)
+                    | EventMsg::PluginValidationResult(_)
+                    | EventMsg::BaseInstructionsUpdated(_)
+                    | EventMsg::ExecApprovalBatchRequest(_)
+                    | EventMsg::ProviderToolCall(_)
+                    | EventMsg::WorkingTreeHash(_)
+                    | EventMsg::SessionStateExported(_)
                     | EventMsg::ExecCommandBegin(_)
                     | EventMsg::TerminalInteraction(_)
                     | EventMsg::ExecCommandOutputDelta(_)
@@ -146,6 +146,10 @@ pub struct ExecApprovalRequestEvent {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[ts(optional)]
     pub available_decisions: Option<Vec<ReviewDecision>>,
+    /// Input that will be written to the command's stdin, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub stdin: Option<String>,
     pub parsed_cmd: Vec<ParsedCommand>,
 }
 
@@ -206,6 +210,19 @@ impl ExecApprovalRequestEvent {
     }
 }
 
+/// Exec approval requests that arrived within `approval_batch_window_ms` of
+/// each other. Answer them all with `Op::ExecApprovalBatch`, or each one with
+/// `Op::ExecApproval` using its effective approval id.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ExecApprovalBatchRequestEvent {
+    /// Identifier to pass to `Op::ExecApprovalBatch`.
+    pub batch_id: String,
+    /// Turn ID that these commands belong to.
+    pub turn_id: String,
+    /// The batched requests, in the order they arrived.
+    pub requests: Vec<ExecApprovalRequestEvent>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema, TS)]
 #[serde(tag = "mode", rename_all = "snake_case")]
 #[ts(tag = "mode")]
@@ -237,6 +237,20 @@ pub enum ServiceTier {
     Flex,
 }
 
+/// Whether, and which, tool the model must call; sent as the Responses API
+/// `tool_choice` parameter.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolChoice {
+    /// The model decides whether to call a tool.
+    #[default]
+    Auto,
+    /// The model must not call any tool.
+    None,
+    /// The model must call the tool with this name.
+    Tool(String),
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Display, JsonSchema, TS)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
@@ -390,6 +390,32 @@ pub enum ResponseItem {
         revised_prompt: Option<String>,
         result: String,
     },
+    // Emitted by providers that execute a tool on their side and stream the
+    // result back. Codex never dispatches these locally.
+    // Example payload:
+    // {
+    //   "id":"ptc_123",
+    //   "type":"provider_tool_call",
+    //   "name":"code_interpreter",
+    //   "status":"completed",
+    //   "arguments":"{\"code\":\"1 + 1\"}",
+    //   "output":"2"
+    // }
+    ProviderToolCall {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        id: Option<String>,
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        status: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        arguments: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        output: Option<String>,
+    },
     // Generated by the harness but considered exactly as a model response.
     GhostSnapshot {
         ghost_commit: GhostCommit,
@@ -1011,6 +1037,10 @@ pub struct ShellToolCallParams {
     pub additional_permissions: Option<PermissionProfile>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub justification: Option<String>,
+    /// Text written to the command's stdin, which is closed afterwards.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub stdin: Option<String>,
 }
 
 /// If the `name` of a `ResponseItem::FunctionCall` is `shell_command`, the
@@ -1461,6 +1491,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn response_item_parses_provider_tool_call() {
+        let item = serde_json::from_value::<ResponseItem>(serde_json::json!({
+            "id": "ptc_123",
+            "type": "provider_tool_call",
+            "name": "code_interpreter",
+            "status": "completed",
+            "output": "2",
+        }))
+        .expect("provider tool call item should deserialize");
+
+        assert_eq!(
+            item,
+            ResponseItem::ProviderToolCall {
+                id: Some("ptc_123".to_string()),
+                name: "code_interpreter".to_string(),
+                status: Some("completed".to_string()),
+                arguments: None,
+                output: Some("2".to_string()),
+            }
+        );
+    }
+
     #[test]
     fn permission_profile_is_empty_when_all_fields_are_none() {
         assert_eq!(PermissionProfile::default().is_empty(), true);
@@ -2160,6 +2213,7 @@ mod tests {
                 prefix_rule: None,
                 additional_permissions: None,
                 justification: None,
+                stdin: None,
             },
             params
         );
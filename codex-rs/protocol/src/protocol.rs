@@ -19,6 +19,7 @@ use crate::config_types::ModeKind;
 use crate::config_types::Personality;
 use crate::config_types::ReasoningSummary as ReasoningSummaryConfig;
 use crate::config_types::ServiceTier;
+use crate::config_types::ToolChoice;
 use crate::config_types::WindowsSandboxLevel;
 use crate::custom_prompts::CustomPrompt;
 use crate::dynamic_tools::DynamicToolCallOutputContentItem;
@@ -35,6 +36,7 @@ use crate::message_history::HistoryEntry;
 use crate::models::BaseInstructions;
 use crate::models::ContentItem;
 use crate::models::MessagePhase;
+use crate::models::PermissionProfile;
 use crate::models::ResponseItem;
 use crate::models::WebSearchAction;
 use crate::num_format::format_with_separators;
@@ -57,6 +59,7 @@ use ts_rs::TS;
 
 pub use crate::approvals::ApplyPatchApprovalRequestEvent;
 pub use crate::approvals::ElicitationAction;
+pub use crate::approvals::ExecApprovalBatchRequestEvent;
 pub use crate::approvals::ExecApprovalRequestEvent;
 pub use crate::approvals::ExecApprovalRequestSkillMetadata;
 pub use crate::approvals::ExecPolicyAmendment;
@@ -212,6 +215,17 @@ pub enum Op {
         /// Optional JSON Schema used to constrain the final assistant message for this turn.
         #[serde(skip_serializing_if = "Option::is_none")]
         final_output_json_schema: Option<Value>,
+        /// Reasoning effort for this turn only; later turns use the session
+        /// setting again. Must be one of the levels the model supports, and
+        /// has no effect when the input is steered into a running turn.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        effort: Option<ReasoningEffortConfig>,
+        /// Tool choice for this turn only. A named tool must exist, otherwise
+        /// the op fails with an error event before a turn starts. It is only
+        /// forced until the model has called a tool; later requests in the
+        /// turn fall back to `auto`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tool_choice: Option<ToolChoice>,
     },
 
     /// Similar to [`Op::UserInput`], but contains additional context required
@@ -333,6 +347,15 @@ pub enum Op {
         decision: ReviewDecision,
     },
 
+    /// Answer every command in an `ExecApprovalBatchRequest` at once.
+    /// Commands already answered with `Op::ExecApproval` keep that decision.
+    ExecApprovalBatch {
+        /// `batch_id` from the `ExecApprovalBatchRequest` event.
+        batch_id: String,
+        /// The user's decision, applied to each command still pending.
+        decision: ReviewDecision,
+    },
+
     /// Approve a code patch
     PatchApproval {
         /// The id of the submission we are approving
@@ -341,6 +364,17 @@ pub enum Op {
         decision: ReviewDecision,
     },
 
+    /// Withdraw a single pending exec or patch approval request, answering it
+    /// as `ReviewDecision::Denied`. The model is told the command was rejected
+    /// and the turn keeps running, so it can propose an alternative. Other
+    /// pending approvals are left untouched. Unlike `ReviewDecision::Abort`,
+    /// which interrupts the whole running turn, this only cancels `id`.
+    CancelApproval {
+        /// The approval id from the `ExecApprovalRequest` or
+        /// `ApplyPatchApprovalRequest` event.
+        id: String,
+    },
+
     /// Resolve an MCP elicitation request.
     ResolveElicitation {
         /// Name of the MCP server that issued the request.
@@ -401,12 +435,27 @@ pub enum Op {
     /// Request MCP servers to reinitialize and refresh cached tool lists.
     RefreshMcpServers { config: McpServerRefreshConfig },
 
+    /// Check that MCP servers still respond by listing their tools. `None`
+    /// checks every configured server. Replies with one
+    /// `EventMsg::McpHealthCheckResult` per server.
+    McpHealthCheck { server: Option<String> },
+
     /// Reload user config layer overrides for the active session.
     ///
     /// This updates runtime config-derived behavior (for example app
     /// enable/disable state) without restarting the thread.
     ReloadUserConfig,
 
+    /// Re-read plugins from disk and reconnect only the MCP servers whose
+    /// configuration changed, leaving the rest of the session untouched.
+    ReloadPlugins,
+
+    /// Re-read the user instructions (`AGENTS.md` in `CODEX_HOME` plus the
+    /// project docs) and use them from the next turn on. A removed file clears
+    /// the corresponding instructions. Confirmed with an
+    /// `EventMsg::BackgroundEvent`.
+    ReloadUserInstructions,
+
     /// Request the list of available custom prompts.
     ListCustomPrompts,
 
@@ -458,6 +507,242 @@ pub enum Op {
     /// responsible for undoing any edits on disk.
     ThreadRollback { num_turns: u32 },
 
+    /// Request a serializable checkpoint of the session's in-memory state.
+    ///
+    /// Replies with `EventMsg::SessionStateExported`. The snapshot can later be
+    /// restored into a fresh thread via `ThreadManager::restore_thread_from_snapshot`.
+    /// Fails with an error event while a turn is in progress.
+    ExportState,
+
+    /// Close the current rollout file and continue recording into a new one
+    /// for the same thread.
+    ///
+    /// The new file's `SessionMeta` records the previous file in
+    /// `previous_rollout_path`, so resuming from the newest file replays the
+    /// full chain. Replies with `EventMsg::RolloutRotated`; fails with an
+    /// error event when persistence is disabled.
+    RotateRollout,
+
+    /// Branch this session into a new thread whose history ends with the turn
+    /// started by submission `from_submission_id`.
+    ///
+    /// The fork gets a fresh session id and its own rollout file, whose
+    /// `SessionMeta` names this session in `forked_from_id`. This session keeps
+    /// running unchanged. Replies with `EventMsg::SessionForked`; fails with an
+    /// error event when the submission did not start a finished turn or when
+    /// persistence is disabled.
+    ForkSession { from_submission_id: String },
+
+    /// Re-run the command the user most recently denied, treating the
+    /// original denial as a mistake.
+    ///
+    /// The command is executed like `Op::RunUserShellCommand`, from the
+    /// directory it was originally proposed in. Fails with an error event when
+    /// no denied command is recorded.
+    ReapproveLastDenied,
+
+    /// Stop starting new tasks from user input without shutting down.
+    ///
+    /// While paused, `Op::UserInput` and `Op::UserTurn` submissions are queued
+    /// in order instead of starting or steering a task; a task that is already
+    /// running continues. Replies with `EventMsg::PauseStateChanged`.
+    Pause,
+
+    /// Leave the paused state entered via `Op::Pause` and submit any input that
+    /// was queued meanwhile. Replies with `EventMsg::PauseStateChanged`.
+    Resume,
+
+    /// Check whether an `apply_patch` patch applies cleanly to the current
+    /// working tree without modifying anything.
+    ///
+    /// Relative paths are resolved against the session cwd. Replies with
+    /// `EventMsg::PatchCheckResult`.
+    CheckPatchApplies {
+        /// Patch body in the `apply_patch` format (`*** Begin Patch` ...).
+        patch: String,
+    },
+
+    /// Compute a content hash of files in the session cwd, for change
+    /// detection. Covers tracked files and untracked files that `.gitignore`
+    /// does not exclude, limited to `paths` (resolved against the cwd) when
+    /// given. Replies with `EventMsg::WorkingTreeHash`, or an error event
+    /// outside a git repository or when git fails.
+    HashWorkingTree { paths: Option<Vec<PathBuf>> },
+
+    /// Return the most recent `EventMsg::Error` events emitted by this
+    /// session, oldest first, so a reconnecting client can see what it missed.
+    ///
+    /// Only a bounded number of errors is retained. Replies with
+    /// `EventMsg::RecentErrors`.
+    GetRecentErrors {
+        /// Maximum number of errors to return; `None` returns all retained errors.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<usize>,
+    },
+
+    /// Forget the retained error events. Replies with `EventMsg::RecentErrors`
+    /// carrying the errors that were cleared.
+    ClearRecentErrors,
+
+    /// Request the session's current in-memory conversation history.
+    ///
+    /// Unlike `Op::ExportState`, this is allowed while a turn is running and
+    /// returns only the history items. Replies with
+    /// `EventMsg::ConversationSnapshot`.
+    GetConversationSnapshot,
+
+    /// Report the retry and backoff settings model requests use in this
+    /// session, after defaults and caps are applied. Replies with
+    /// `EventMsg::RetryConfig`.
+    GetRetryConfig,
+
+    /// Report the feature flags enabled for this session, after config,
+    /// profile, and requirement overrides. Replies with `EventMsg::Features`.
+    GetFeatures,
+
+    /// Cap the session's cumulative token usage at `max_total_tokens`, or
+    /// remove the cap with `None`. Once usage exceeds the cap the running turn
+    /// stops after its current sampling request, and new user input is
+    /// rejected until the cap is raised or removed. Replies with
+    /// `EventMsg::TokenBudget`.
+    SetBudget { max_total_tokens: Option<u64> },
+
+    /// Report the session's token budget and usage. Replies with
+    /// `EventMsg::TokenBudget`.
+    GetBudget,
+
+    /// Report aggregate statistics for the session so far. Replies with
+    /// `EventMsg::SessionStats`.
+    GetSessionStats,
+
+    /// List the configured plugins and what each one provides. Replies with
+    /// `EventMsg::PluginList`.
+    ListPlugins,
+
+    /// Watch `paths` and submit a new user turn whenever any of them change
+    /// on disk. `{file}` and `{diff}` in `prompt_template` are replaced with
+    /// the changed paths and their unified diffs. Relative paths resolve
+    /// against the session cwd. Changes made while a turn is running are
+    /// not reported. Replaces any watch already running; the watch keeps
+    /// running across interrupted turns and ends on `Op::UnwatchFiles` or
+    /// `Op::Shutdown`. VCS and build directories, gitignored paths and large
+    /// files are not watched.
+    WatchFiles {
+        paths: Vec<PathBuf>,
+        prompt_template: String,
+    },
+
+    /// Stop the watch started by `Op::WatchFiles`, if any.
+    UnwatchFiles,
+
+    /// Run `git blame` on `path`, optionally limited to `line_range`.
+    /// Relative paths resolve against the session cwd. Replies with
+    /// `EventMsg::BlameResult`, or `EventMsg::Error` when the file is outside
+    /// a git repository or not tracked.
+    BlameFile {
+        path: PathBuf,
+        line_range: Option<BlameLineRange>,
+    },
+
+    /// Return the reasoning from the most recent turn that produced any.
+    /// Replies with `EventMsg::LastReasoning`; raw reasoning is included only
+    /// when `show_raw_agent_reasoning` is enabled.
+    GetLastReasoning,
+
+    /// Check the plugin directory at `path` before it is added to the config.
+    /// Relative paths resolve against the session cwd. Replies with
+    /// `EventMsg::PluginValidationResult`.
+    ValidatePlugin { path: PathBuf },
+
+    /// Replace the base instructions (system prompt) used for the session's
+    /// model requests from the next request on. `None` restores the
+    /// instructions the session would start with: the configured override,
+    /// or the model's defaults. Replies with `EventMsg::BaseInstructionsUpdated`,
+    /// which is recorded in the rollout so a resumed session keeps the change
+    /// unless the config sets `base_instructions`.
+    SetBaseInstructions { instructions: Option<String> },
+
+    /// Restart the diff of the running (or most recent) task from the files
+    /// as they are on disk now, so later `EventMsg::TurnDiff` events show
+    /// only changes made after the reset. A patch still being applied is
+    /// finished first; the reset is acknowledged with an empty
+    /// `EventMsg::TurnDiff`.
+    ResetTurnDiff,
+
+    /// Find the history items whose message text or tool output contains
+    /// `query`, ignoring case. Replies with `EventMsg::SearchHistoryResults`.
+    SearchHistory { query: String },
+
+    /// Change the approval policy for the rest of the session without
+    /// overriding any other turn context settings.
+    ///
+    /// Takes effect for the next turn. Replies with
+    /// `EventMsg::ApprovalPolicyChanged`, or `EventMsg::Error` when the policy
+    /// is not allowed by the session's requirements.
+    SetApprovalPolicy { policy: AskForApproval },
+
+    /// Move the session to `path` for the rest of the session, so later
+    /// commands with a relative `workdir` resolve against it and workspace
+    /// write access follows the new directory.
+    ///
+    /// `path` must be an absolute path to an existing directory. Replies
+    /// with `EventMsg::WorkingDirectoryChanged`, or `EventMsg::Error` when the
+    /// path is rejected.
+    ChangeWorkingDirectory { path: PathBuf },
+
+    /// Write the session's conversation to `path` in a human-readable
+    /// (`Markdown`) or machine-readable (`Json`) form.
+    ///
+    /// Markdown is rendered from the session's rollout file when there is
+    /// one, so it covers the whole session; JSON, and Markdown for sessions
+    /// without a rollout, use the in-memory history.
+    ///
+    /// Relative paths are resolved against the session cwd. Replies with
+    /// `EventMsg::ExportComplete`, or `EventMsg::Error` when the file cannot
+    /// be written.
+    ExportConversation { path: PathBuf, format: ExportFormat },
+
+    /// Ask the model to explain, in plain English, the most recent command
+    /// that finished running. The model is offered no tools, so nothing is
+    /// executed.
+    ///
+    /// Runs as its own turn whose reply is an agent message; the explanation
+    /// is not added to the conversation history. Fails with an error event
+    /// when no command has run yet.
+    ExplainLastCommand,
+
+    /// Set an environment variable for every command run for the rest of the
+    /// session. Takes precedence over values derived from
+    /// `shell_environment_policy` and over an earlier unset of the same name.
+    SetEnvironmentVariable { name: String, value: String },
+
+    /// Remove an environment variable from every command run for the rest of
+    /// the session, even when it would otherwise be inherited.
+    UnsetEnvironmentVariable { name: String },
+
+    /// Replace the `notify` command run after each completed turn, for the
+    /// rest of the session. `None` disables notifications; an empty command
+    /// is rejected with an error event.
+    SetNotifyCommand { command: Option<Vec<String>> },
+
+    /// Set the JSON schema that constrains the final assistant message of
+    /// every later turn whose `final_output_json_schema` is unset. A schema
+    /// given on the turn itself takes precedence; `None` clears the default.
+    SetDefaultOutputSchema { schema: Option<Value> },
+
+    /// Add a `system` message to the model input of the next turn only. The
+    /// message is sent with every request of that turn but is never recorded
+    /// in the conversation history or the rollout. Several messages injected
+    /// before a turn are all applied, in order.
+    InjectSystemMessage { text: String },
+
+    /// Revert the file changes made by the most recent turn, restoring every
+    /// touched file to its state before the turn's first patch. Runs as its
+    /// own turn that reports the revert via `EventMsg::PatchApplyBegin` /
+    /// `EventMsg::PatchApplyEnd`. Fails with an error event when the last turn
+    /// changed no files or its changes were already undone.
+    UndoLastPatch,
+
     /// Request a code review from the agent.
     Review { review_request: ReviewRequest },
 
@@ -476,6 +761,25 @@ pub enum Op {
 
     /// Request the list of available models.
     ListModels,
+
+    /// Run a single command through the same approval and sandbox handling
+    /// as the `shell` tool, without involving the model.
+    ///
+    /// Runs as its own turn that reports the command via
+    /// `EventMsg::ExecCommandBegin` / `EventMsg::ExecCommandEnd`. Nothing is
+    /// recorded to the conversation history. Fails with an error event when
+    /// the command is rejected.
+    RunCommand {
+        /// Program and arguments to execute, without shell interpretation.
+        command: Vec<String>,
+        /// Working directory; relative paths are resolved against the session
+        /// cwd. Defaults to the session cwd.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cwd: Option<PathBuf>,
+        /// Timeout for the command in milliseconds.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timeout_ms: Option<u64>,
+    },
 }
 
 /// Determines the conditions under which the user is consulted to approve
@@ -1142,16 +1446,94 @@ pub enum EventMsg {
     /// Updated session metadata (e.g., thread name changes).
     ThreadNameUpdated(ThreadNameUpdatedEvent),
 
+    /// Response to `Op::ExportState`.
+    SessionStateExported(SessionStateExportedEvent),
+
+    /// Response to `Op::Pause` and `Op::Resume`.
+    PauseStateChanged(PauseStateChangedEvent),
+
+    /// Response to `Op::CheckPatchApplies`.
+    PatchCheckResult(PatchCheckResultEvent),
+
+    /// Response to `Op::HashWorkingTree`.
+    WorkingTreeHash(WorkingTreeHashEvent),
+
+    /// Response to `Op::GetRecentErrors` and `Op::ClearRecentErrors`.
+    RecentErrors(RecentErrorsEvent),
+
+    /// Response to `Op::GetConversationSnapshot`.
+    ConversationSnapshot(ConversationSnapshotEvent),
+
+    /// Response to `Op::SetApprovalPolicy`.
+    ApprovalPolicyChanged(ApprovalPolicyChangedEvent),
+
+    /// Response to `Op::ChangeWorkingDirectory`.
+    WorkingDirectoryChanged(WorkingDirectoryChangedEvent),
+
+    /// Response to `Op::ExportConversation`.
+    ExportComplete(ExportCompleteEvent),
+
+    /// Emitted right before `TurnComplete` when the turn ended with an
+    /// assistant message, i.e. the assistant produced a final answer.
+    AssistantFinished(AssistantFinishedEvent),
+
+    /// Best-effort parse of a function call's arguments while they are still
+    /// streaming, emitted before the call is dispatched.
+    ToolCallArgumentsPreview(ToolCallArgumentsPreviewEvent),
+
+    /// Response to `Op::ForkSession`.
+    SessionForked(SessionForkedEvent),
+
+    /// Response to `Op::RotateRollout`.
+    RolloutRotated(RolloutRotatedEvent),
+
+    /// Response to `Op::GetRetryConfig`.
+    RetryConfig(RetryConfigEvent),
+
+    /// Response to `Op::GetFeatures`.
+    Features(FeaturesEvent),
+
+    /// Response to `Op::SetBudget` and `Op::GetBudget`.
+    TokenBudget(TokenBudgetEvent),
+
+    /// Response to `Op::GetSessionStats`.
+    SessionStats(SessionStatsEvent),
+
+    /// Response to `Op::ListPlugins`.
+    PluginList(PluginListEvent),
+
+    /// Response to `Op::BlameFile`.
+    BlameResult(BlameResultEvent),
+
+    /// Response to `Op::GetLastReasoning`.
+    LastReasoning(LastReasoningEvent),
+
+    /// Response to `Op::ValidatePlugin`.
+    PluginValidationResult(PluginValidationResultEvent),
+
+    /// Response to `Op::SetBaseInstructions`.
+    BaseInstructionsUpdated(BaseInstructionsUpdatedEvent),
+
+    /// Response to `Op::SearchHistory`.
+    SearchHistoryResults(SearchHistoryResultsEvent),
+
     /// Incremental MCP startup progress updates.
     McpStartupUpdate(McpStartupUpdateEvent),
 
     /// Aggregate MCP startup completion summary.
     McpStartupComplete(McpStartupCompleteEvent),
 
+    /// Response to `Op::McpHealthCheck`, one per checked server.
+    McpHealthCheckResult(McpHealthCheckResultEvent),
+
     McpToolCallBegin(McpToolCallBeginEvent),
 
     McpToolCallEnd(McpToolCallEndEvent),
 
+    /// An MCP tool call completed with `is_error: true`. Emitted right after the
+    /// matching `McpToolCallEnd`; the content is still returned to the model.
+    McpToolCallError(McpToolCallErrorEvent),
+
     WebSearchBegin(WebSearchBeginEvent),
 
     WebSearchEnd(WebSearchEndEvent),
@@ -1160,6 +1542,10 @@ pub enum EventMsg {
 
     ImageGenerationEnd(ImageGenerationEndEvent),
 
+    /// A tool call the model provider executed server-side and streamed back.
+    /// Nothing ran locally; the call is recorded in history as-is.
+    ProviderToolCall(ProviderToolCallEvent),
+
     /// Notification that the server is about to execute a command.
     ExecCommandBegin(ExecCommandBeginEvent),
 
@@ -1176,6 +1562,10 @@ pub enum EventMsg {
 
     ExecApprovalRequest(ExecApprovalRequestEvent),
 
+    /// Several exec approval requests sent together; see
+    /// `approval_batch_window_ms`.
+    ExecApprovalBatchRequest(ExecApprovalBatchRequestEvent),
+
     RequestPermissions(RequestPermissionsEvent),
 
     RequestUserInput(RequestUserInputEvent),
@@ -1494,6 +1884,14 @@ pub enum CodexErrorInfo {
         http_status_code: Option<u16>,
     },
     ThreadRollbackFailed,
+    /// The turn ran past its configured `turn_deadline_seconds` and was aborted.
+    TurnDeadlineExceeded,
+    /// The account ran out of quota. `resets_at` is the unix timestamp (in
+    /// seconds) at which it is expected to reset, when known.
+    QuotaExceeded {
+        #[ts(type = "number | null")]
+        resets_at: Option<i64>,
+    },
     Other,
 }
 
@@ -1513,6 +1911,8 @@ impl CodexErrorInfo {
             | Self::SandboxError
             | Self::ResponseStreamDisconnected { .. }
             | Self::ResponseTooManyFailedAttempts { .. }
+            | Self::TurnDeadlineExceeded
+            | Self::QuotaExceeded { .. }
             | Self::Other => true,
         }
     }
@@ -1695,6 +2095,29 @@ pub struct ContextCompactedEvent;
 pub struct TurnCompleteEvent {
     pub turn_id: String,
     pub last_agent_message: Option<String>,
+    /// Estimated cost of the turn in USD, present when pricing is known for
+    /// the turn's model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub cost_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct AssistantFinishedEvent {
+    pub turn_id: String,
+    /// The assistant message the turn ended with.
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ToolCallArgumentsPreviewEvent {
+    pub turn_id: String,
+    pub call_id: String,
+    pub tool_name: String,
+    /// The arguments received so far, with any unterminated strings, arrays,
+    /// and objects closed. Each preview supersedes the previous one for the
+    /// same `call_id`.
+    pub arguments: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
@@ -2008,6 +2431,15 @@ pub struct McpToolCallEndEvent {
     pub result: Result<CallToolResult, String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, PartialEq)]
+pub struct McpToolCallErrorEvent {
+    /// Identifier for the corresponding McpToolCallEnd.
+    pub call_id: String,
+    pub invocation: McpInvocation,
+    /// Content blocks returned by the tool alongside the error flag.
+    pub content: Vec<serde_json::Value>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, PartialEq)]
 pub struct DynamicToolCallResponseEvent {
     /// Identifier for the corresponding DynamicToolCallRequest.
@@ -2068,6 +2500,22 @@ pub struct ImageGenerationEndEvent {
     pub saved_path: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ProviderToolCallEvent {
+    pub call_id: String,
+    /// Name of the provider-side tool, e.g. `code_interpreter`.
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub arguments: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub output: Option<String>,
+}
+
 // Conversation kept for backward compatibility.
 /// Response payload for `Op::GetHistory` containing the current session's
 /// in-memory transcript.
@@ -2149,21 +2597,29 @@ impl InitialHistory {
         }
     }
 
+    /// Base instructions in effect at the end of the history: the last
+    /// `BaseInstructionsUpdated` event, else those of the session metadata.
     pub fn get_base_instructions(&self) -> Option<BaseInstructions> {
-        // TODO: SessionMeta should (in theory) always be first in the history, so we can probably only check the first item?
-        match self {
-            InitialHistory::New => None,
-            InitialHistory::Resumed(resumed) => {
-                resumed.history.iter().find_map(|item| match item {
-                    RolloutItem::SessionMeta(meta_line) => meta_line.meta.base_instructions.clone(),
-                    _ => None,
+        let items = match self {
+            InitialHistory::New => return None,
+            InitialHistory::Resumed(resumed) => resumed.history.as_slice(),
+            InitialHistory::Forked(items) => items.as_slice(),
+        };
+        let updated = items.iter().rev().find_map(|item| match item {
+            RolloutItem::EventMsg(EventMsg::BaseInstructionsUpdated(event)) => {
+                Some(BaseInstructions {
+                    text: event.instructions.clone(),
                 })
             }
-            InitialHistory::Forked(items) => items.iter().find_map(|item| match item {
+            _ => None,
+        });
+        // TODO: SessionMeta should (in theory) always be first in the history, so we can probably only check the first item?
+        updated.or_else(|| {
+            items.iter().find_map(|item| match item {
                 RolloutItem::SessionMeta(meta_line) => meta_line.meta.base_instructions.clone(),
                 _ => None,
-            }),
-        }
+            })
+        })
     }
 
     pub fn get_dynamic_tools(&self) -> Option<Vec<DynamicToolSpec>> {
@@ -2310,6 +2766,9 @@ pub struct SessionMeta {
     pub dynamic_tools: Option<Vec<DynamicToolSpec>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub memory_mode: Option<String>,
+    /// Rollout file this one continues from after an `Op::RotateRollout`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_rollout_path: Option<PathBuf>,
 }
 
 impl Default for SessionMeta {
@@ -2328,6 +2787,7 @@ impl Default for SessionMeta {
             base_instructions: None,
             dynamic_tools: None,
             memory_mode: None,
+            previous_rollout_path: None,
         }
     }
 }
@@ -2717,6 +3177,9 @@ pub struct PatchApplyBeginEvent {
     pub auto_approved: bool,
     /// The changes to be applied.
     pub changes: HashMap<PathBuf, FileChange>,
+    /// True when `dry_run` is enabled and the patch will not be written.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
@@ -2738,6 +3201,9 @@ pub struct PatchApplyEndEvent {
     pub changes: HashMap<PathBuf, FileChange>,
     /// Completion status for this patch application.
     pub status: PatchApplyStatus,
+    /// True when `dry_run` is enabled and the patch was not written.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
@@ -2792,6 +3258,21 @@ pub enum McpStartupStatus {
     Cancelled,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case", tag = "state")]
+#[ts(rename_all = "snake_case", tag = "state")]
+pub enum McpHealthStatus {
+    Healthy,
+    Unreachable { error: String },
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct McpHealthCheckResultEvent {
+    /// Name of the checked server.
+    pub server: String,
+    pub status: McpHealthStatus,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, Default)]
 pub struct McpStartupCompleteEvent {
     pub ready: Vec<String>,
@@ -2904,6 +3385,10 @@ pub struct SkillMetadata {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[ts(optional)]
     pub dependencies: Option<SkillDependencies>,
+    /// Name of the plugin that provides this skill, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub plugin: Option<String>,
     pub path: PathBuf,
     pub scope: SkillScope,
     pub enabled: bool,
@@ -3031,6 +3516,299 @@ pub struct ThreadNameUpdatedEvent {
     pub thread_name: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PauseStateChangedEvent {
+    /// Whether new user input is currently held instead of starting a task.
+    pub paused: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PatchCheckResultEvent {
+    pub outcome: PatchCheckOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct WorkingTreeHashEvent {
+    /// Hex-encoded SHA-256 over the relative path and content of every hashed
+    /// file, in path order. Identical trees produce identical hashes.
+    pub hash: String,
+    /// Number of files that went into the hash.
+    pub file_count: usize,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct RecentErrorsEvent {
+    /// Retained error events, oldest first.
+    pub errors: Vec<ErrorEvent>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ConversationSnapshotEvent {
+    /// Items in the session's history, oldest first, serialized the same way
+    /// they are recorded to the rollout.
+    pub items: Vec<ResponseItem>,
+    /// Approximate context cost of each tool output in `items`, oldest first.
+    #[serde(default)]
+    pub tool_output_tokens: Vec<ToolOutputTokenUsage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct RetryConfigEvent {
+    /// Retries for a request that fails before its response starts streaming.
+    pub request_max_retries: u64,
+    /// Reconnection attempts after a response stream drops mid-turn.
+    pub stream_max_retries: u64,
+    /// Delay before the first retry; each later retry multiplies it by
+    /// `backoff_factor`.
+    pub base_backoff_ms: u64,
+    pub backoff_factor: f64,
+    /// Each delay is scaled by a random factor within `1 ± jitter_ratio`.
+    pub jitter_ratio: f64,
+    /// How long a response stream may stay silent before it is treated as
+    /// disconnected.
+    pub stream_idle_timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct FeaturesEvent {
+    /// Keys of the enabled features, as used in the `[features]` config table.
+    pub enabled: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct TokenBudgetEvent {
+    /// Cap on cumulative token usage, or `None` when the session is uncapped.
+    pub max_total_tokens: Option<u64>,
+    /// Cumulative `total_tokens` used by the session so far.
+    pub total_tokens: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionStatsEvent {
+    /// Turns that ran to completion.
+    pub turns_completed: u64,
+    /// Input plus output tokens reported by the model provider.
+    pub total_tokens: i64,
+    /// Commands executed for tool calls.
+    pub exec_calls: u64,
+    /// Patches applied successfully, excluding dry runs.
+    pub patches_applied: u64,
+    /// Distinct files changed by patches.
+    pub files_changed: usize,
+    /// Wall-clock time since the session started.
+    pub elapsed_ms: u64,
+    /// Items currently in the conversation history.
+    pub history_items: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PluginListEvent {
+    /// Configured plugins sorted by config name, including disabled ones.
+    pub plugins: Vec<PluginListEntry>,
+    /// Human-readable listing with one line per plugin.
+    pub summary: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PluginListEntry {
+    /// Plugin key from the `[plugins]` config table, e.g. `sample@marketplace`.
+    pub config_name: String,
+    /// `name` from the plugin manifest, when it could be read.
+    pub manifest_name: Option<String>,
+    pub enabled: bool,
+    /// Why the plugin failed to load, if it did.
+    pub error: Option<String>,
+    pub has_skills: bool,
+    pub has_mcp_servers: bool,
+    pub skill_count: usize,
+    pub mcp_server_count: usize,
+    /// Tags from the `capabilities` array in the plugin manifest.
+    pub declared_capabilities: Vec<String>,
+}
+
+/// Inclusive, 1-based range of lines for `Op::BlameFile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct BlameLineRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct BlameResultEvent {
+    pub path: PathBuf,
+    /// Blame for each requested line, in file order.
+    pub lines: Vec<BlameLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct BlameLine {
+    /// 1-based line number in the working tree file.
+    pub line_number: u32,
+    /// SHA of the commit that last changed the line; all zeros when the line
+    /// has uncommitted changes.
+    pub commit: String,
+    pub author: String,
+    pub author_email: String,
+    /// Author time in seconds since the Unix epoch.
+    pub timestamp: i64,
+    /// Subject line of the commit.
+    pub summary: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct LastReasoningEvent {
+    /// Turn the reasoning came from; `None` when no turn has produced
+    /// reasoning yet.
+    pub turn_id: Option<String>,
+    /// Reasoning summary text, one entry per summary part.
+    pub summary: Vec<String>,
+    /// Raw reasoning text. Empty unless `show_raw_agent_reasoning` is enabled.
+    pub raw_content: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PluginValidationResultEvent {
+    /// The plugin directory that was checked.
+    pub path: PathBuf,
+    /// Problems found in the plugin; empty when it is valid.
+    pub errors: Vec<PluginValidationErrorInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct BaseInstructionsUpdatedEvent {
+    /// Base instructions now in effect for the session.
+    pub instructions: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PluginValidationErrorInfo {
+    /// The file or directory to fix.
+    pub path: PathBuf,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SearchHistoryResultsEvent {
+    /// Positions of the matching items in the conversation history, as
+    /// returned by `Op::GetConversationSnapshot`, oldest first.
+    pub indices: Vec<usize>,
+    /// An excerpt of each matching item, parallel to `indices`.
+    pub snippets: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ToolOutputTokenUsage {
+    /// Identifier of the tool call that produced the output.
+    pub call_id: String,
+    /// Estimated number of tokens the output occupies in the model context.
+    #[ts(type = "number")]
+    pub tokens: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ApprovalPolicyChangedEvent {
+    /// Approval policy now in effect for the session.
+    pub approval_policy: AskForApproval,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct WorkingDirectoryChangedEvent {
+    /// Working directory now in effect for the session.
+    pub new_cwd: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ExportCompleteEvent {
+    /// Absolute path of the file the conversation was written to.
+    pub path: PathBuf,
+}
+
+/// Output format for `Op::ExportConversation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// Messages as paragraphs, reasoning as blockquotes, tool calls as
+    /// collapsible code blocks and patches as `diff` blocks.
+    Markdown,
+    /// The history items as a JSON array, serialized as in the rollout.
+    Json,
+}
+
+/// Whether a patch checked via `Op::CheckPatchApplies` applies to the current tree.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+#[serde(tag = "status", rename_all = "snake_case")]
+#[ts(tag = "status")]
+pub enum PatchCheckOutcome {
+    /// Every hunk applies to the current tree.
+    AppliesCleanly,
+    /// The patch parsed, but some files no longer match it.
+    Conflicts { files: Vec<PatchConflict> },
+    /// The patch could not be parsed.
+    ParseError { message: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct PatchConflict {
+    pub path: PathBuf,
+    /// Why the hunks for `path` do not apply.
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionForkedEvent {
+    /// Id of the new thread; look it up with `ThreadManager::get_thread`.
+    pub session_id: ThreadId,
+    pub from_submission_id: String,
+    /// Rollout file the fork records into.
+    pub rollout_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct RolloutRotatedEvent {
+    /// Rollout file the session now records into.
+    pub rollout_path: PathBuf,
+    /// Rollout file that was closed by the rotation.
+    pub previous_rollout_path: PathBuf,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionStateExportedEvent {
+    pub snapshot: Box<SessionStateSnapshot>,
+}
+
+/// Checkpoint of a session's in-memory state, suitable for restoring into a
+/// fresh thread (possibly in another process).
+///
+/// Unlike rollout resume, which replays the append-only event log, a snapshot
+/// captures the current state directly. It includes the model-visible history,
+/// the context baseline used to diff settings on the next turn, the thread
+/// name, approvals cached for the session, and permissions granted via
+/// `request_permissions`.
+///
+/// It does not include the rollout event log, in-flight turns or pending
+/// approval requests, background terminals, MCP connections, token usage, or
+/// any session configuration (model, cwd, sandbox and approval policies); those
+/// come from the `Config` used when restoring.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct SessionStateSnapshot {
+    /// Thread the snapshot was exported from. Restoring creates a new thread id.
+    pub thread_id: ThreadId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub thread_name: Option<String>,
+    pub history: Vec<ResponseItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub reference_context_item: Option<TurnContextItem>,
+    /// Session-scoped approval decisions, keyed by their opaque approval cache key.
+    #[serde(default)]
+    pub approvals: HashMap<String, ReviewDecision>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub granted_permissions: Option<PermissionProfile>,
+}
+
 /// User's decision in response to an ExecApprovalRequest.
 #[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq, Display, JsonSchema, TS)]
 #[serde(rename_all = "snake_case")]
@@ -3092,6 +3870,15 @@ pub enum FileChange {
     Add {
         content: String,
     },
+    /// A new binary file, carried as standard base64 so it can be shown
+    /// without embedding raw bytes.
+    Binary {
+        base64_content: String,
+    },
+    /// A symbolic link pointing at `target`, new or replacing what was there.
+    Symlink {
+        target: PathBuf,
+    },
     Delete {
         content: String,
     },
@@ -3101,6 +3888,23 @@ pub enum FileChange {
     },
 }
 
+impl FileChange {
+    /// Stand-in text for a binary change, e.g. `[binary file, 8 bytes]`, so
+    /// callers never have to render the encoded blob. `None` for text changes.
+    pub fn binary_description(&self) -> Option<String> {
+        match self {
+            FileChange::Binary { base64_content } => {
+                let encoded_len = base64_content.trim_end_matches('=').len();
+                Some(format!("[binary file, {} bytes]", encoded_len * 3 / 4))
+            }
+            FileChange::Add { .. }
+            | FileChange::Symlink { .. }
+            | FileChange::Delete { .. }
+            | FileChange::Update { .. } => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct Chunk {
     /// 1-based line index of the first line in the original file
@@ -3327,6 +4131,42 @@ mod tests {
     use tempfile::NamedTempFile;
     use tempfile::TempDir;
 
+    #[test]
+    fn binary_file_change_serializes_and_describes_size() -> Result<()> {
+        let change = FileChange::Binary {
+            base64_content: "iVBORw0KGgo=".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&change)?,
+            json!({"type": "binary", "base64_content": "iVBORw0KGgo="})
+        );
+        assert_eq!(
+            change.binary_description(),
+            Some("[binary file, 8 bytes]".to_string())
+        );
+        assert_eq!(
+            FileChange::Add {
+                content: "text".to_string(),
+            }
+            .binary_description(),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn symlink_file_change_serializes_target() -> Result<()> {
+        let change = FileChange::Symlink {
+            target: PathBuf::from("releases/v2"),
+        };
+        assert_eq!(
+            serde_json::to_value(&change)?,
+            json!({"type": "symlink", "target": "releases/v2"})
+        );
+        assert_eq!(change.binary_description(), None);
+        Ok(())
+    }
+
     fn sorted_writable_roots(roots: Vec<WritableRoot>) -> Vec<(PathBuf, Vec<PathBuf>)> {
         let mut sorted_roots: Vec<(PathBuf, Vec<PathBuf>)> = roots
             .into_iter()
@@ -4045,6 +4885,8 @@ mod tests {
         let op = Op::UserInput {
             items: Vec::new(),
             final_output_json_schema: None,
+            effort: None,
+            tool_choice: None,
         };
 
         let json_op = serde_json::to_value(op)?;
@@ -4062,6 +4904,8 @@ mod tests {
             Op::UserInput {
                 items: Vec::new(),
                 final_output_json_schema: None,
+                effort: None,
+                tool_choice: None,
             }
         );
 
@@ -4081,6 +4925,8 @@ mod tests {
         let op = Op::UserInput {
             items: Vec::new(),
             final_output_json_schema: Some(schema.clone()),
+            effort: None,
+            tool_choice: None,
         };
 
         let json_op = serde_json::to_value(op)?;
@@ -4096,6 +4942,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn user_input_serializes_tool_choice() -> Result<()> {
+        let op = Op::UserInput {
+            items: Vec::new(),
+            final_output_json_schema: None,
+            effort: None,
+            tool_choice: Some(ToolChoice::Tool("apply_patch".to_string())),
+        };
+
+        let json_op = serde_json::to_value(&op)?;
+        assert_eq!(
+            json_op,
+            json!({
+                "type": "user_input",
+                "items": [],
+                "tool_choice": { "tool": "apply_patch" },
+            })
+        );
+        assert_eq!(serde_json::from_value::<Op>(json_op)?, op);
+
+        Ok(())
+    }
+
     #[test]
     fn user_input_text_serializes_empty_text_elements() -> Result<()> {
         let input = UserInput::Text {
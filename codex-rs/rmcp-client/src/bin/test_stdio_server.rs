@@ -45,8 +45,11 @@ impl TestToolServer {
     fn new() -> Self {
         let tools = vec![
             Self::echo_tool(),
+            Self::echo_summary_tool(),
             Self::image_tool(),
             Self::image_scenario_tool(),
+            Self::fail_tool(),
+            Self::sleep_tool(),
         ];
         let resources = vec![Self::memo_resource()];
         let resource_templates = vec![Self::memo_template()];
@@ -77,6 +80,27 @@ impl TestToolServer {
         )
     }
 
+    fn echo_summary_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" }
+            },
+            "required": ["message"],
+            "additionalProperties": false
+        }))
+        .expect("echo_summary tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("echo_summary"),
+            Cow::Borrowed(
+                "Echo back the provided message as both a text summary and structured content.",
+            ),
+            Arc::new(schema),
+        )
+    }
+
     fn image_tool() -> Tool {
         #[expect(clippy::expect_used)]
         let schema: JsonObject = serde_json::from_value(serde_json::json!({
@@ -148,6 +172,44 @@ impl TestToolServer {
         )
     }
 
+    fn fail_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" }
+            },
+            "required": ["message"],
+            "additionalProperties": false
+        }))
+        .expect("fail tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("fail"),
+            Cow::Borrowed("Return the provided message as a tool error (`is_error: true`)."),
+            Arc::new(schema),
+        )
+    }
+
+    fn sleep_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "duration_ms": { "type": "integer", "minimum": 0 }
+            },
+            "required": ["duration_ms"],
+            "additionalProperties": false
+        }))
+        .expect("sleep tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("sleep"),
+            Cow::Borrowed("Sleep for `duration_ms` milliseconds before returning."),
+            Arc::new(schema),
+        )
+    }
+
     fn memo_resource() -> Resource {
         let raw = RawResource {
             uri: MEMO_URI.to_string(),
@@ -188,6 +250,21 @@ struct EchoArgs {
     env_var: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct EchoSummaryArgs {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct FailArgs {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct SleepArgs {
+    duration_ms: u64,
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
 /// Scenarios for `image_scenario`, intended to exercise Codex TUI handling of MCP image outputs.
@@ -348,6 +425,33 @@ impl ServerHandler for TestToolServer {
                 let args = Self::parse_call_args::<ImageScenarioArgs>(&request, "image_scenario")?;
                 Self::image_scenario_result(args)
             }
+            "echo_summary" => {
+                let args = Self::parse_call_args::<EchoSummaryArgs>(&request, "echo_summary")?;
+                Ok(CallToolResult {
+                    content: vec![rmcp::model::Content::text(format!(
+                        "summary: {}",
+                        args.message
+                    ))],
+                    structured_content: Some(json!({
+                        "echo": format!("ECHOING: {}", args.message),
+                    })),
+                    is_error: Some(false),
+                    meta: None,
+                })
+            }
+            "fail" => {
+                let args = Self::parse_call_args::<FailArgs>(&request, "fail")?;
+                Ok(CallToolResult::error(vec![rmcp::model::Content::text(
+                    args.message,
+                )]))
+            }
+            "sleep" => {
+                let args = Self::parse_call_args::<SleepArgs>(&request, "sleep")?;
+                tokio::time::sleep(std::time::Duration::from_millis(args.duration_ms)).await;
+                Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+                    "done",
+                )]))
+            }
             other => Err(McpError::invalid_params(
                 format!("unknown tool: {other}"),
                 None,
@@ -412,6 +412,21 @@ enum ClientOperationError {
     Timeout { label: String, duration: Duration },
 }
 
+/// Returns `true` when `error` came from the MCP transport failing, for
+/// example a dropped connection or an HTTP 429, rather than from the server
+/// answering the request.
+pub fn is_transport_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<ClientOperationError>(),
+            Some(ClientOperationError::Service(
+                rmcp::service::ServiceError::TransportSend(_)
+                    | rmcp::service::ServiceError::TransportClosed
+            ))
+        )
+    })
+}
+
 pub type Elicitation = CreateElicitationRequestParams;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -1163,3 +1178,24 @@ async fn create_oauth_transport_and_runtime(
 
     Ok((transport, runtime))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::service::ServiceError;
+
+    #[test]
+    fn only_transport_failures_count_as_transport_errors() {
+        let closed =
+            anyhow::Error::from(ClientOperationError::Service(ServiceError::TransportClosed))
+                .context("tool call failed");
+        assert!(is_transport_error(&closed));
+
+        let timeout = anyhow::Error::from(ClientOperationError::Timeout {
+            label: "tools/call".to_string(),
+            duration: Duration::from_secs(1),
+        });
+        assert!(!is_transport_error(&timeout));
+        assert!(!is_transport_error(&anyhow!("invalid arguments")));
+    }
+}
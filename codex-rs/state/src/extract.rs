@@ -254,6 +254,7 @@ mod tests {
                     base_instructions: None,
                     dynamic_tools: None,
                     memory_mode: None,
+                    previous_rollout_path: None,
                 },
                 git: None,
             }),
@@ -737,6 +737,7 @@ mod tests {
                 base_instructions: None,
                 dynamic_tools: None,
                 memory_mode: Some("polluted".to_string()),
+                previous_rollout_path: None,
             },
             git: None,
         })];
@@ -791,6 +792,7 @@ mod tests {
                 base_instructions: None,
                 dynamic_tools: None,
                 memory_mode: None,
+                previous_rollout_path: None,
             },
             git: Some(GitInfo {
                 commit_hash: Some("rollout-sha".to_string()),
@@ -1167,8 +1167,12 @@ impl App {
                     available_decisions: ev.effective_available_decisions(),
                     network_approval_context: ev.network_approval_context.clone(),
                     additional_permissions: ev.additional_permissions.clone(),
+                    stdin: ev.stdin.clone(),
                 }))
             }
+            EventMsg::ExecApprovalBatchRequest(ev) => Some(ThreadInteractiveRequest::Approval(
+                ApprovalRequest::exec_batch(thread_id, thread_label, ev),
+            )),
             EventMsg::ApplyPatchApprovalRequest(ev) => Some(ThreadInteractiveRequest::Approval(
                 ApprovalRequest::ApplyPatch {
                     thread_id,
@@ -3280,6 +3284,19 @@ impl App {
                         "E X E C".to_string(),
                     ));
                 }
+                ApprovalRequest::ExecBatch { commands, .. } => {
+                    let _ = tui.enter_alt_screen();
+                    let mut lines = Vec::new();
+                    for command in commands {
+                        if !lines.is_empty() {
+                            lines.push(Line::from(""));
+                        }
+                        let full_cmd = strip_bash_lc_and_escape(&command);
+                        lines.extend(highlight_bash_to_lines(&full_cmd));
+                    }
+                    self.overlay =
+                        Some(Overlay::new_static_with_lines(lines, "E X E C".to_string()));
+                }
                 ApprovalRequest::Permissions {
                     permissions,
                     reason,
@@ -4013,6 +4030,7 @@ mod tests {
                     additional_permissions: None,
                     skill_metadata: None,
                     available_decisions: None,
+                    stdin: None,
                     parsed_cmd: Vec::new(),
                 },
             ),
@@ -4324,6 +4342,7 @@ mod tests {
                     msg: EventMsg::TurnComplete(TurnCompleteEvent {
                         turn_id: "turn-1".to_string(),
                         last_agent_message: None,
+                        cost_usd: None,
                     }),
                 }],
                 input_state: Some(input_state),
@@ -4406,6 +4425,7 @@ mod tests {
                     msg: EventMsg::TurnComplete(TurnCompleteEvent {
                         turn_id: "turn-1".to_string(),
                         last_agent_message: None,
+                        cost_usd: None,
                     }),
                 }],
                 input_state: Some(input_state),
@@ -4561,6 +4581,7 @@ mod tests {
                         msg: EventMsg::TurnComplete(TurnCompleteEvent {
                             turn_id: "turn-0".to_string(),
                             last_agent_message: None,
+                            cost_usd: None,
                         }),
                     },
                     Event {
@@ -4591,6 +4612,7 @@ mod tests {
             msg: EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-1".to_string(),
                 last_agent_message: None,
+                cost_usd: None,
             }),
         });
 
@@ -5176,6 +5198,7 @@ mod tests {
                         additional_permissions: None,
                         skill_metadata: None,
                         available_decisions: None,
+                        stdin: None,
                         parsed_cmd: Vec::new(),
                     },
                 ),
@@ -5264,6 +5287,7 @@ mod tests {
                         additional_permissions: None,
                         skill_metadata: None,
                         available_decisions: None,
+                        stdin: None,
                         parsed_cmd: Vec::new(),
                     },
                 ),
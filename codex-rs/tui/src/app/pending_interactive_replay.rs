@@ -36,6 +36,7 @@ impl ElicitationRequestKey {
 pub(super) struct PendingInteractiveReplayState {
     exec_approval_call_ids: HashSet<String>,
     exec_approval_call_ids_by_turn_id: HashMap<String, Vec<String>>,
+    exec_approval_batches: HashMap<String, Vec<String>>,
     patch_approval_call_ids: HashSet<String>,
     patch_approval_call_ids_by_turn_id: HashMap<String, Vec<String>>,
     elicitation_requests: HashSet<ElicitationRequestKey>,
@@ -50,6 +51,7 @@ impl PendingInteractiveReplayState {
         matches!(
             &event.msg,
             EventMsg::ExecApprovalRequest(_)
+                | EventMsg::ExecApprovalBatchRequest(_)
                 | EventMsg::ApplyPatchApprovalRequest(_)
                 | EventMsg::ElicitationRequest(_)
                 | EventMsg::RequestPermissions(_)
@@ -65,6 +67,7 @@ impl PendingInteractiveReplayState {
         matches!(
             op,
             Op::ExecApproval { .. }
+                | Op::ExecApprovalBatch { .. }
                 | Op::PatchApproval { .. }
                 | Op::ResolveElicitation { .. }
                 | Op::RequestPermissionsResponse { .. }
@@ -85,6 +88,19 @@ impl PendingInteractiveReplayState {
                     );
                 }
             }
+            Op::ExecApprovalBatch { batch_id, .. } => {
+                for approval_id in self
+                    .exec_approval_batches
+                    .remove(batch_id)
+                    .unwrap_or_default()
+                {
+                    self.exec_approval_call_ids.remove(&approval_id);
+                    Self::remove_call_id_from_turn_map(
+                        &mut self.exec_approval_call_ids_by_turn_id,
+                        &approval_id,
+                    );
+                }
+            }
             Op::PatchApproval { id, .. } => {
                 self.patch_approval_call_ids.remove(id);
                 Self::remove_call_id_from_turn_map(
@@ -143,6 +159,21 @@ impl PendingInteractiveReplayState {
                     .or_default()
                     .push(approval_id);
             }
+            EventMsg::ExecApprovalBatchRequest(ev) => {
+                let approval_ids = ev
+                    .requests
+                    .iter()
+                    .map(codex_protocol::protocol::ExecApprovalRequestEvent::effective_approval_id)
+                    .collect::<Vec<_>>();
+                self.exec_approval_call_ids
+                    .extend(approval_ids.iter().cloned());
+                self.exec_approval_call_ids_by_turn_id
+                    .entry(ev.turn_id.clone())
+                    .or_default()
+                    .extend(approval_ids.iter().cloned());
+                self.exec_approval_batches
+                    .insert(ev.batch_id.clone(), approval_ids);
+            }
             EventMsg::ExecCommandBegin(ev) => {
                 self.exec_approval_call_ids.remove(&ev.call_id);
                 Self::remove_call_id_from_turn_map(
@@ -216,6 +247,20 @@ impl PendingInteractiveReplayState {
                     &approval_id,
                 );
             }
+            EventMsg::ExecApprovalBatchRequest(ev) => {
+                for approval_id in self
+                    .exec_approval_batches
+                    .remove(&ev.batch_id)
+                    .unwrap_or_default()
+                {
+                    self.exec_approval_call_ids.remove(&approval_id);
+                    Self::remove_call_id_from_turn_map_entry(
+                        &mut self.exec_approval_call_ids_by_turn_id,
+                        &ev.turn_id,
+                        &approval_id,
+                    );
+                }
+            }
             EventMsg::ApplyPatchApprovalRequest(ev) => {
                 self.patch_approval_call_ids.remove(&ev.call_id);
                 Self::remove_call_id_from_turn_map_entry(
@@ -274,6 +319,11 @@ impl PendingInteractiveReplayState {
             EventMsg::ExecApprovalRequest(ev) => self
                 .exec_approval_call_ids
                 .contains(&ev.effective_approval_id()),
+            // A batch replays while any of its commands is still unanswered.
+            EventMsg::ExecApprovalBatchRequest(ev) => ev.requests.iter().any(|request| {
+                self.exec_approval_call_ids
+                    .contains(&request.effective_approval_id())
+            }),
             EventMsg::ApplyPatchApprovalRequest(ev) => {
                 self.patch_approval_call_ids.contains(&ev.call_id)
             }
@@ -323,6 +373,12 @@ impl PendingInteractiveReplayState {
                 self.exec_approval_call_ids.remove(&call_id);
             }
         }
+        let exec_approval_call_ids = &self.exec_approval_call_ids;
+        self.exec_approval_batches.retain(|_, approval_ids| {
+            approval_ids
+                .iter()
+                .any(|approval_id| exec_approval_call_ids.contains(approval_id))
+        });
     }
 
     fn clear_patch_approval_turn(&mut self, turn_id: &str) {
@@ -363,6 +419,7 @@ impl PendingInteractiveReplayState {
     fn clear(&mut self) {
         self.exec_approval_call_ids.clear();
         self.exec_approval_call_ids_by_turn_id.clear();
+        self.exec_approval_batches.clear();
         self.patch_approval_call_ids.clear();
         self.patch_approval_call_ids_by_turn_id.clear();
         self.elicitation_requests.clear();
@@ -455,6 +512,7 @@ mod tests {
                     additional_permissions: None,
                     skill_metadata: None,
                     available_decisions: None,
+                    stdin: None,
                     parsed_cmd: Vec::new(),
                 },
             ),
@@ -599,6 +657,7 @@ mod tests {
                     additional_permissions: None,
                     skill_metadata: None,
                     available_decisions: None,
+                    stdin: None,
                     parsed_cmd: Vec::new(),
                 },
             ),
@@ -632,6 +691,55 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn thread_event_snapshot_drops_exec_approval_batch_after_batch_answer() {
+        let mut store = ThreadEventStore::new(8);
+        let exec_request = |call_id: &str| codex_protocol::protocol::ExecApprovalRequestEvent {
+            call_id: call_id.to_string(),
+            approval_id: None,
+            turn_id: "turn-1".to_string(),
+            command: vec!["echo".to_string(), call_id.to_string()],
+            cwd: PathBuf::from("/tmp"),
+            reason: None,
+            network_approval_context: None,
+            proposed_execpolicy_amendment: None,
+            proposed_network_policy_amendments: None,
+            additional_permissions: None,
+            skill_metadata: None,
+            available_decisions: None,
+            stdin: None,
+            parsed_cmd: Vec::new(),
+        };
+        store.push_event(Event {
+            id: "ev-1".to_string(),
+            msg: EventMsg::ExecApprovalBatchRequest(
+                codex_protocol::protocol::ExecApprovalBatchRequestEvent {
+                    batch_id: "batch-1".to_string(),
+                    turn_id: "turn-1".to_string(),
+                    requests: vec![exec_request("call-1"), exec_request("call-2")],
+                },
+            ),
+        });
+
+        store.note_outbound_op(&Op::ExecApproval {
+            id: "call-1".to_string(),
+            turn_id: None,
+            decision: codex_protocol::protocol::ReviewDecision::Approved,
+        });
+        assert_eq!(store.snapshot().events.len(), 1);
+        assert_eq!(store.has_pending_thread_approvals(), true);
+
+        store.note_outbound_op(&Op::ExecApprovalBatch {
+            batch_id: "batch-1".to_string(),
+            decision: codex_protocol::protocol::ReviewDecision::Approved,
+        });
+        assert!(
+            store.snapshot().events.is_empty(),
+            "answered approval batch should not replay on thread switch"
+        );
+        assert_eq!(store.has_pending_thread_approvals(), false);
+    }
+
     #[test]
     fn thread_event_snapshot_drops_resolved_elicitation_after_outbound_resolution() {
         let mut store = ThreadEventStore::new(8);
@@ -689,6 +797,7 @@ mod tests {
                     additional_permissions: None,
                     skill_metadata: None,
                     available_decisions: None,
+                    stdin: None,
                     parsed_cmd: Vec::new(),
                 },
             ),
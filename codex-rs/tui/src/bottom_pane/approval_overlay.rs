@@ -24,6 +24,7 @@ use codex_protocol::models::MacOsContactsPermission;
 use codex_protocol::models::MacOsPreferencesPermission;
 use codex_protocol::models::PermissionProfile;
 use codex_protocol::protocol::ElicitationAction;
+use codex_protocol::protocol::ExecApprovalBatchRequestEvent;
 use codex_protocol::protocol::FileChange;
 use codex_protocol::protocol::NetworkApprovalContext;
 use codex_protocol::protocol::NetworkPolicyRuleAction;
@@ -54,6 +55,15 @@ pub(crate) enum ApprovalRequest {
         available_decisions: Vec<ReviewDecision>,
         network_approval_context: Option<NetworkApprovalContext>,
         additional_permissions: Option<PermissionProfile>,
+        stdin: Option<String>,
+    },
+    /// Commands from an `ExecApprovalBatchRequest`, answered together.
+    ExecBatch {
+        thread_id: ThreadId,
+        thread_label: Option<String>,
+        batch_id: String,
+        commands: Vec<Vec<String>>,
+        available_decisions: Vec<ReviewDecision>,
     },
     Permissions {
         thread_id: ThreadId,
@@ -80,9 +90,48 @@ pub(crate) enum ApprovalRequest {
 }
 
 impl ApprovalRequest {
+    /// Builds one prompt for the whole batch. Only the decisions every command
+    /// offers, and that make sense for several commands at once, are kept.
+    pub(crate) fn exec_batch(
+        thread_id: ThreadId,
+        thread_label: Option<String>,
+        ev: &ExecApprovalBatchRequestEvent,
+    ) -> Self {
+        let decisions_per_request = ev
+            .requests
+            .iter()
+            .map(|request| request.effective_available_decisions())
+            .collect::<Vec<_>>();
+        let available_decisions = [
+            ReviewDecision::Approved,
+            ReviewDecision::ApprovedForSession,
+            ReviewDecision::Denied,
+            ReviewDecision::Abort,
+        ]
+        .into_iter()
+        .filter(|decision| {
+            decisions_per_request
+                .iter()
+                .all(|decisions| decisions.contains(decision))
+        })
+        .collect();
+        ApprovalRequest::ExecBatch {
+            thread_id,
+            thread_label,
+            batch_id: ev.batch_id.clone(),
+            commands: ev
+                .requests
+                .iter()
+                .map(|request| request.command.clone())
+                .collect(),
+            available_decisions,
+        }
+    }
+
     fn thread_id(&self) -> ThreadId {
         match self {
             ApprovalRequest::Exec { thread_id, .. }
+            | ApprovalRequest::ExecBatch { thread_id, .. }
             | ApprovalRequest::Permissions { thread_id, .. }
             | ApprovalRequest::ApplyPatch { thread_id, .. }
             | ApprovalRequest::McpElicitation { thread_id, .. } => *thread_id,
@@ -92,6 +141,7 @@ impl ApprovalRequest {
     fn thread_label(&self) -> Option<&str> {
         match self {
             ApprovalRequest::Exec { thread_label, .. }
+            | ApprovalRequest::ExecBatch { thread_label, .. }
             | ApprovalRequest::Permissions { thread_label, .. }
             | ApprovalRequest::ApplyPatch { thread_label, .. }
             | ApprovalRequest::McpElicitation { thread_label, .. } => thread_label.as_deref(),
@@ -167,6 +217,17 @@ impl ApprovalOverlay {
                     },
                 ),
             ),
+            ApprovalRequest::ExecBatch {
+                commands,
+                available_decisions,
+                ..
+            } => (
+                exec_batch_options(available_decisions),
+                format!(
+                    "Would you like to run the following {} commands?",
+                    commands.len()
+                ),
+            ),
             ApprovalRequest::Permissions { .. } => (
                 permissions_options(),
                 "Would you like to grant these permissions?".to_string(),
@@ -221,6 +282,12 @@ impl ApprovalOverlay {
                 (ApprovalRequest::Exec { id, command, .. }, ApprovalDecision::Review(decision)) => {
                     self.handle_exec_decision(id, command, decision.clone());
                 }
+                (
+                    ApprovalRequest::ExecBatch {
+                        batch_id, commands, ..
+                    },
+                    ApprovalDecision::Review(decision),
+                ) => self.handle_exec_batch_decision(batch_id, commands, decision.clone()),
                 (
                     ApprovalRequest::Permissions {
                         call_id,
@@ -269,6 +336,32 @@ impl ApprovalOverlay {
         });
     }
 
+    fn handle_exec_batch_decision(
+        &self,
+        batch_id: &str,
+        commands: &[Vec<String>],
+        decision: ReviewDecision,
+    ) {
+        let Some(request) = self.current_request.as_ref() else {
+            return;
+        };
+        if request.thread_label().is_none() {
+            for command in commands {
+                let cell =
+                    history_cell::new_approval_decision_cell(command.clone(), decision.clone());
+                self.app_event_tx.send(AppEvent::InsertHistoryCell(cell));
+            }
+        }
+        let thread_id = request.thread_id();
+        self.app_event_tx.send(AppEvent::SubmitThreadOp {
+            thread_id,
+            op: Op::ExecApprovalBatch {
+                batch_id: batch_id.to_string(),
+                decision,
+            },
+        });
+    }
+
     fn handle_permissions_decision(
         &self,
         call_id: &str,
@@ -435,6 +528,11 @@ impl BottomPaneView for ApprovalOverlay {
                 ApprovalRequest::Exec { id, command, .. } => {
                     self.handle_exec_decision(id, command, ReviewDecision::Abort);
                 }
+                ApprovalRequest::ExecBatch {
+                    batch_id, commands, ..
+                } => {
+                    self.handle_exec_batch_decision(batch_id, commands, ReviewDecision::Abort);
+                }
                 ApprovalRequest::Permissions {
                     call_id,
                     permissions,
@@ -516,6 +614,7 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
             command,
             network_approval_context,
             additional_permissions,
+            stdin,
             ..
         } => {
             let mut header: Vec<Line<'static>> = Vec::new();
@@ -547,6 +646,34 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
             if network_approval_context.is_none() {
                 header.extend(full_cmd_lines);
             }
+            if let Some(stdin) = stdin {
+                header.push(Line::from(""));
+                header.push(Line::from("Stdin:"));
+                header.extend(stdin.lines().map(|line| Line::from(line.to_string().dim())));
+            }
+            Box::new(Paragraph::new(header).wrap(Wrap { trim: false }))
+        }
+        ApprovalRequest::ExecBatch {
+            thread_label,
+            commands,
+            ..
+        } => {
+            let mut header: Vec<Line<'static>> = Vec::new();
+            if let Some(thread_label) = thread_label {
+                header.push(Line::from(vec![
+                    "Thread: ".into(),
+                    thread_label.clone().bold(),
+                ]));
+                header.push(Line::from(""));
+            }
+            for command in commands {
+                let full_cmd = strip_bash_lc_and_escape(command);
+                let mut full_cmd_lines = highlight_bash_to_lines(&full_cmd);
+                if let Some(first) = full_cmd_lines.first_mut() {
+                    first.spans.insert(0, Span::from("$ "));
+                }
+                header.extend(full_cmd_lines);
+            }
             Box::new(Paragraph::new(header).wrap(Wrap { trim: false }))
         }
         ApprovalRequest::Permissions {
@@ -742,6 +869,40 @@ fn exec_options(
         .collect()
 }
 
+fn exec_batch_options(available_decisions: &[ReviewDecision]) -> Vec<ApprovalOption> {
+    available_decisions
+        .iter()
+        .filter_map(|decision| {
+            let (label, display_shortcut, shortcut) = match decision {
+                ReviewDecision::Approved => ("Yes, run all of them", None, KeyCode::Char('y')),
+                ReviewDecision::ApprovedForSession => (
+                    "Yes, and don't ask again for these commands in this session",
+                    None,
+                    KeyCode::Char('a'),
+                ),
+                ReviewDecision::Denied => (
+                    "No, continue without running them",
+                    None,
+                    KeyCode::Char('d'),
+                ),
+                ReviewDecision::Abort => (
+                    "No, and tell Codex what to do differently",
+                    Some(key_hint::plain(KeyCode::Esc)),
+                    KeyCode::Char('n'),
+                ),
+                ReviewDecision::ApprovedExecpolicyAmendment { .. }
+                | ReviewDecision::NetworkPolicyAmendment { .. } => return None,
+            };
+            Some(ApprovalOption {
+                label: label.to_string(),
+                decision: ApprovalDecision::Review(decision.clone()),
+                display_shortcut,
+                additional_shortcuts: vec![key_hint::plain(shortcut)],
+            })
+        })
+        .collect()
+}
+
 pub(crate) fn format_additional_permissions_rule(
     additional_permissions: &PermissionProfile,
 ) -> Option<String> {
@@ -899,6 +1060,7 @@ mod tests {
     use codex_protocol::models::MacOsPreferencesPermission;
     use codex_protocol::models::MacOsSeatbeltProfileExtensions;
     use codex_protocol::models::NetworkPermissions;
+    use codex_protocol::protocol::ExecApprovalRequestEvent;
     use codex_protocol::protocol::ExecPolicyAmendment;
     use codex_protocol::protocol::NetworkApprovalProtocol;
     use codex_protocol::protocol::NetworkPolicyAmendment;
@@ -948,6 +1110,7 @@ mod tests {
             available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
             network_approval_context: None,
             additional_permissions: None,
+            stdin: None,
         }
     }
 
@@ -999,6 +1162,84 @@ mod tests {
         assert!(saw_op, "expected approval decision to emit an op");
     }
 
+    #[test]
+    fn exec_batch_prompt_answers_the_whole_batch() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let exec_request =
+            |call_id: &str, command: &str, available_decisions| ExecApprovalRequestEvent {
+                call_id: call_id.to_string(),
+                approval_id: None,
+                turn_id: "turn".to_string(),
+                command: vec![command.to_string()],
+                cwd: PathBuf::from("/tmp"),
+                reason: None,
+                network_approval_context: None,
+                proposed_execpolicy_amendment: None,
+                proposed_network_policy_amendments: None,
+                additional_permissions: None,
+                skill_metadata: None,
+                available_decisions: Some(available_decisions),
+                stdin: None,
+                parsed_cmd: Vec::new(),
+            };
+        let batch = ExecApprovalBatchRequestEvent {
+            batch_id: "batch-1".to_string(),
+            turn_id: "turn".to_string(),
+            requests: vec![
+                exec_request(
+                    "call-1",
+                    "ls",
+                    vec![
+                        ReviewDecision::Approved,
+                        ReviewDecision::ApprovedForSession,
+                        ReviewDecision::Abort,
+                    ],
+                ),
+                exec_request(
+                    "call-2",
+                    "pwd",
+                    vec![ReviewDecision::Approved, ReviewDecision::Abort],
+                ),
+            ],
+        };
+        let request = ApprovalRequest::exec_batch(ThreadId::new(), None, &batch);
+        let ApprovalRequest::ExecBatch {
+            available_decisions,
+            ..
+        } = &request
+        else {
+            panic!("expected a batch request");
+        };
+        assert_eq!(
+            available_decisions,
+            &vec![ReviewDecision::Approved, ReviewDecision::Abort]
+        );
+
+        let mut view = ApprovalOverlay::new(request, tx, Features::with_defaults());
+        let rendered = render_overlay_lines(&view, 80);
+        assert!(
+            rendered.contains("$ ls") && rendered.contains("$ pwd"),
+            "expected both commands in the prompt, got {rendered}"
+        );
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        let mut ops = Vec::new();
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::SubmitThreadOp { op, .. } = ev {
+                ops.push(op);
+            }
+        }
+        assert_eq!(
+            ops,
+            vec![Op::ExecApprovalBatch {
+                batch_id: "batch-1".to_string(),
+                decision: ReviewDecision::Approved,
+            }]
+        );
+        assert!(view.is_complete());
+    }
+
     #[test]
     fn o_opens_source_thread_for_cross_thread_approval() {
         let (tx, mut rx) = unbounded_channel::<AppEvent>();
@@ -1014,6 +1255,7 @@ mod tests {
                 available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
                 network_approval_context: None,
                 additional_permissions: None,
+                stdin: None,
             },
             tx,
             Features::with_defaults(),
@@ -1042,6 +1284,7 @@ mod tests {
                 available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
                 network_approval_context: None,
                 additional_permissions: None,
+                stdin: None,
             },
             tx,
             Features::with_defaults(),
@@ -1075,6 +1318,7 @@ mod tests {
                 ],
                 network_approval_context: None,
                 additional_permissions: None,
+                stdin: None,
             },
             tx,
             Features::with_defaults(),
@@ -1132,6 +1376,7 @@ mod tests {
                     protocol: NetworkApprovalProtocol::Https,
                 }),
                 additional_permissions: None,
+                stdin: None,
             },
             tx,
             Features::with_defaults(),
@@ -1158,6 +1403,7 @@ mod tests {
             available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
             network_approval_context: None,
             additional_permissions: None,
+            stdin: None,
         };
 
         let view = ApprovalOverlay::new(exec_request, tx, Features::with_defaults());
@@ -1179,6 +1425,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn header_includes_stdin() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let exec_request = ApprovalRequest::Exec {
+            thread_id: ThreadId::new(),
+            thread_label: None,
+            id: "test".into(),
+            command: vec!["cat".into()],
+            reason: None,
+            available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+            network_approval_context: None,
+            additional_permissions: None,
+            stdin: Some("first line\nsecond line".into()),
+        };
+
+        let view = ApprovalOverlay::new(exec_request, tx, Features::with_defaults());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, view.desired_height(80)));
+        view.render(Rect::new(0, 0, 80, view.desired_height(80)), &mut buf);
+
+        let rendered: Vec<String> = (0..buf.area.height)
+            .map(|row| {
+                (0..buf.area.width)
+                    .map(|col| buf[(col, row)].symbol().to_string())
+                    .collect()
+            })
+            .collect();
+        for expected in ["Stdin:", "first line", "second line"] {
+            assert!(
+                rendered.iter().any(|line| line.contains(expected)),
+                "expected header to include {expected:?}, got {rendered:?}"
+            );
+        }
+    }
+
     #[test]
     fn network_exec_options_use_expected_labels_and_hide_execpolicy_amendment() {
         let network_context = NetworkApprovalContext {
@@ -1456,6 +1737,7 @@ mod tests {
                 protocol: NetworkApprovalProtocol::Https,
             }),
             additional_permissions: None,
+            stdin: None,
         };
 
         let view = ApprovalOverlay::new(exec_request, tx, Features::with_defaults());
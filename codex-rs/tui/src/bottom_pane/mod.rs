@@ -1229,6 +1229,7 @@ mod tests {
             ],
             network_approval_context: None,
             additional_permissions: None,
+            stdin: None,
         }
     }
 
@@ -107,6 +107,7 @@ use codex_protocol::protocol::DeprecationNoticeEvent;
 use codex_protocol::protocol::ErrorEvent;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::ExecApprovalBatchRequestEvent;
 use codex_protocol::protocol::ExecApprovalRequestEvent;
 use codex_protocol::protocol::ExecCommandBeginEvent;
 use codex_protocol::protocol::ExecCommandEndEvent;
@@ -511,7 +512,9 @@ enum RateLimitErrorKind {
 fn rate_limit_error_kind(info: &CodexErrorInfo) -> Option<RateLimitErrorKind> {
     match info {
         CodexErrorInfo::ServerOverloaded => Some(RateLimitErrorKind::ServerOverloaded),
-        CodexErrorInfo::UsageLimitExceeded => Some(RateLimitErrorKind::UsageLimit),
+        CodexErrorInfo::UsageLimitExceeded | CodexErrorInfo::QuotaExceeded { .. } => {
+            Some(RateLimitErrorKind::UsageLimit)
+        }
         CodexErrorInfo::ResponseTooManyFailedAttempts {
             http_status_code: Some(429),
         } => Some(RateLimitErrorKind::Generic),
@@ -2239,6 +2242,14 @@ impl ChatWidget {
         );
     }
 
+    fn on_exec_approval_batch_request(&mut self, ev: ExecApprovalBatchRequestEvent) {
+        let ev2 = ev.clone();
+        self.defer_or_handle(
+            |q| q.push_exec_approval_batch(ev),
+            |s| s.handle_exec_approval_batch_now(ev2),
+        );
+    }
+
     fn on_apply_patch_approval_request(&mut self, _id: String, ev: ApplyPatchApprovalRequestEvent) {
         let ev2 = ev.clone();
         self.defer_or_handle(
@@ -2967,12 +2978,32 @@ impl ChatWidget {
             available_decisions,
             network_approval_context: ev.network_approval_context,
             additional_permissions: ev.additional_permissions,
+            stdin: ev.stdin,
         };
         self.bottom_pane
             .push_approval_request(request, &self.config.features);
         self.request_redraw();
     }
 
+    pub(crate) fn handle_exec_approval_batch_now(&mut self, ev: ExecApprovalBatchRequestEvent) {
+        self.flush_answer_stream_with_separator();
+        let command = ev
+            .requests
+            .iter()
+            .map(|request| {
+                shlex::try_join(request.command.iter().map(String::as_str))
+                    .unwrap_or_else(|_| request.command.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        self.notify(Notification::ExecApprovalRequested { command });
+
+        let request = ApprovalRequest::exec_batch(self.thread_id.unwrap_or_default(), None, &ev);
+        self.bottom_pane
+            .push_approval_request(request, &self.config.features);
+        self.request_redraw();
+    }
+
     pub(crate) fn handle_apply_patch_approval_now(&mut self, ev: ApplyPatchApprovalRequestEvent) {
         self.flush_answer_stream_with_separator();
 
@@ -4957,6 +4988,7 @@ impl ChatWidget {
                 // For replayed events, synthesize an empty id (these should not occur).
                 self.on_exec_approval_request(id.unwrap_or_default(), ev)
             }
+            EventMsg::ExecApprovalBatchRequest(ev) => self.on_exec_approval_batch_request(ev),
             EventMsg::ApplyPatchApprovalRequest(ev) => {
                 self.on_apply_patch_approval_request(id.unwrap_or_default(), ev)
             }
@@ -4986,7 +5018,34 @@ impl ChatWidget {
             EventMsg::McpListToolsResponse(ev) => self.on_list_mcp_tools(ev),
             EventMsg::ListCustomPromptsResponse(ev) => self.on_list_custom_prompts(ev),
             EventMsg::ListSkillsResponse(ev) => self.on_list_skills(ev),
-            EventMsg::ListRemoteSkillsResponse(_) | EventMsg::RemoteSkillDownloaded(_) => {}
+            EventMsg::ListRemoteSkillsResponse(_)
+            | EventMsg::RemoteSkillDownloaded(_)
+            | EventMsg::McpToolCallError(_)
+            | EventMsg::PauseStateChanged(_)
+            | EventMsg::PatchCheckResult(_)
+            | EventMsg::RecentErrors(_)
+            | EventMsg::ConversationSnapshot(_)
+            | EventMsg::ApprovalPolicyChanged(_)
+            | EventMsg::WorkingDirectoryChanged(_)
+            | EventMsg::ExportComplete(_)
+            | EventMsg::AssistantFinished(_)
+            | EventMsg::ToolCallArgumentsPreview(_)
+            | EventMsg::SessionForked(_)
+            | EventMsg::RolloutRotated(_)
+            | EventMsg::RetryConfig(_)
+            | EventMsg::McpHealthCheckResult(_)
+            | EventMsg::SearchHistoryResults(_)
+            | EventMsg::Features(_)
+            | EventMsg::TokenBudget(_)
+            | EventMsg::SessionStats(_)
+            | EventMsg::PluginList(_)
+            | EventMsg::BlameResult(_)
+            | EventMsg::LastReasoning(_)
+            | EventMsg::PluginValidationResult(_)
+            | EventMsg::BaseInstructionsUpdated(_)
+            | EventMsg::ProviderToolCall(_)
+            | EventMsg::WorkingTreeHash(_)
+            | EventMsg::SessionStateExported(_) => {}
             EventMsg::SkillsUpdateAvailable => {
                 self.submit_op(Op::ListSkills {
                     cwds: Vec::new(),
@@ -24,6 +24,19 @@ async fn initialize_app_server_client_name(thread: &CodexThread) {
     }
 }
 
+/// Forwards exec output deltas to the UI when the thread delivers them on their
+/// own channel (`separate_exec_output_channel`).
+fn spawn_exec_output_forwarder(thread: &CodexThread, app_event_tx: AppEventSender) {
+    let Some(exec_output_events) = thread.exec_output_events() else {
+        return;
+    };
+    tokio::spawn(async move {
+        while let Ok(event) = exec_output_events.recv().await {
+            app_event_tx.send(AppEvent::CodexEvent(event));
+        }
+    });
+}
+
 /// Spawn the agent bootstrapper and op forwarding loop, returning the
 /// `UnboundedSender<Op>` used by the UI to submit operations.
 pub(crate) fn spawn_agent(
@@ -62,6 +75,7 @@ pub(crate) fn spawn_agent(
             msg: codex_protocol::protocol::EventMsg::SessionConfigured(session_configured),
         };
         app_event_tx_clone.send(AppEvent::CodexEvent(ev));
+        spawn_exec_output_forwarder(thread.as_ref(), app_event_tx_clone.clone());
 
         let thread_clone = thread.clone();
         tokio::spawn(async move {
@@ -107,6 +121,7 @@ pub(crate) fn spawn_agent_from_existing(
             msg: codex_protocol::protocol::EventMsg::SessionConfigured(session_configured),
         };
         app_event_tx_clone.send(AppEvent::CodexEvent(ev));
+        spawn_exec_output_forwarder(thread.as_ref(), app_event_tx_clone.clone());
 
         let thread_clone = thread.clone();
         tokio::spawn(async move {
@@ -2,6 +2,7 @@ use std::collections::VecDeque;
 
 use codex_protocol::approvals::ElicitationRequestEvent;
 use codex_protocol::protocol::ApplyPatchApprovalRequestEvent;
+use codex_protocol::protocol::ExecApprovalBatchRequestEvent;
 use codex_protocol::protocol::ExecApprovalRequestEvent;
 use codex_protocol::protocol::ExecCommandBeginEvent;
 use codex_protocol::protocol::ExecCommandEndEvent;
@@ -16,6 +17,7 @@ use super::ChatWidget;
 #[derive(Debug)]
 pub(crate) enum QueuedInterrupt {
     ExecApproval(ExecApprovalRequestEvent),
+    ExecApprovalBatch(ExecApprovalBatchRequestEvent),
     ApplyPatchApproval(ApplyPatchApprovalRequestEvent),
     Elicitation(ElicitationRequestEvent),
     RequestPermissions(RequestPermissionsEvent),
@@ -48,6 +50,10 @@ impl InterruptManager {
         self.queue.push_back(QueuedInterrupt::ExecApproval(ev));
     }
 
+    pub(crate) fn push_exec_approval_batch(&mut self, ev: ExecApprovalBatchRequestEvent) {
+        self.queue.push_back(QueuedInterrupt::ExecApprovalBatch(ev));
+    }
+
     pub(crate) fn push_apply_patch_approval(&mut self, ev: ApplyPatchApprovalRequestEvent) {
         self.queue
             .push_back(QueuedInterrupt::ApplyPatchApproval(ev));
@@ -90,6 +96,7 @@ impl InterruptManager {
         while let Some(q) = self.queue.pop_front() {
             match q {
                 QueuedInterrupt::ExecApproval(ev) => chat.handle_exec_approval_now(ev),
+                QueuedInterrupt::ExecApprovalBatch(ev) => chat.handle_exec_approval_batch_now(ev),
                 QueuedInterrupt::ApplyPatchApproval(ev) => chat.handle_apply_patch_approval_now(ev),
                 QueuedInterrupt::Elicitation(ev) => chat.handle_elicitation_request_now(ev),
                 QueuedInterrupt::RequestPermissions(ev) => chat.handle_request_permissions_now(ev),
@@ -2951,6 +2951,7 @@ async fn plan_implementation_popup_skips_replayed_turn_complete() {
     chat.replay_initial_messages(vec![EventMsg::TurnComplete(TurnCompleteEvent {
         turn_id: "turn-1".to_string(),
         last_agent_message: Some("Plan details".to_string()),
+        cost_usd: None,
     })]);
 
     let popup = render_bottom_popup(&chat, 80);
@@ -2976,6 +2977,7 @@ async fn plan_implementation_popup_shows_once_when_replay_precedes_live_turn_com
     chat.replay_initial_messages(vec![EventMsg::TurnComplete(TurnCompleteEvent {
         turn_id: "turn-1".to_string(),
         last_agent_message: Some("Plan details".to_string()),
+        cost_usd: None,
     })]);
     let replay_popup = render_bottom_popup(&chat, 80);
     assert!(
@@ -2988,6 +2990,7 @@ async fn plan_implementation_popup_shows_once_when_replay_precedes_live_turn_com
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("Plan details".to_string()),
+            cost_usd: None,
         }),
     });
 
@@ -3009,6 +3012,7 @@ async fn plan_implementation_popup_shows_once_when_replay_precedes_live_turn_com
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("Plan details".to_string()),
+            cost_usd: None,
         }),
     });
     let duplicate_popup = render_bottom_popup(&chat, 80);
@@ -3249,6 +3253,7 @@ async fn exec_approval_emits_proposed_command_and_decision_history() {
         additional_permissions: None,
         skill_metadata: None,
         available_decisions: None,
+        stdin: None,
         parsed_cmd: vec![],
     };
     chat.handle_codex_event(Event {
@@ -3300,6 +3305,7 @@ async fn exec_approval_uses_approval_id_when_present() {
             additional_permissions: None,
             skill_metadata: None,
             available_decisions: None,
+            stdin: None,
             parsed_cmd: vec![],
         }),
     });
@@ -3342,6 +3348,7 @@ async fn exec_approval_decision_truncates_multiline_and_long_commands() {
         additional_permissions: None,
         skill_metadata: None,
         available_decisions: None,
+        stdin: None,
         parsed_cmd: vec![],
     };
     chat.handle_codex_event(Event {
@@ -3398,6 +3405,7 @@ async fn exec_approval_decision_truncates_multiline_and_long_commands() {
         additional_permissions: None,
         skill_metadata: None,
         available_decisions: None,
+        stdin: None,
         parsed_cmd: vec![],
     };
     chat.handle_codex_event(Event {
@@ -5098,6 +5106,7 @@ async fn unified_exec_wait_after_final_agent_message_snapshot() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("Final response.".into()),
+            cost_usd: None,
         }),
     });
 
@@ -5140,6 +5149,7 @@ async fn unified_exec_wait_before_streamed_agent_message_snapshot() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
 
@@ -5205,6 +5215,7 @@ async fn unified_exec_waiting_multiple_empty_snapshots() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
 
@@ -5283,6 +5294,7 @@ async fn unified_exec_non_empty_then_empty_snapshots() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
 
@@ -5797,6 +5809,7 @@ async fn slash_copy_state_tracks_turn_complete_final_reply() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("Final reply **markdown**".to_string()),
+            cost_usd: None,
         }),
     });
 
@@ -5827,6 +5840,7 @@ async fn slash_copy_state_tracks_plan_item_completion() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
 
@@ -5860,6 +5874,7 @@ async fn slash_copy_state_is_preserved_during_running_task() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("Previous completed reply".to_string()),
+            cost_usd: None,
         }),
     });
     chat.on_task_started();
@@ -5879,6 +5894,7 @@ async fn slash_copy_state_clears_on_thread_rollback() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("Reply that will be rolled back".to_string()),
+            cost_usd: None,
         }),
     });
     chat.handle_codex_event(Event {
@@ -5906,6 +5922,7 @@ async fn slash_copy_is_unavailable_when_legacy_agent_message_is_not_repeated_on_
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
     let _ = drain_insert_history(&mut rx);
@@ -5935,6 +5952,7 @@ async fn slash_copy_is_unavailable_when_legacy_agent_message_item_is_not_repeate
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
     let _ = drain_insert_history(&mut rx);
@@ -5961,6 +5979,7 @@ async fn slash_copy_does_not_return_stale_output_after_thread_rollback() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: Some("Reply that will be rolled back".to_string()),
+            cost_usd: None,
         }),
     });
     let _ = drain_insert_history(&mut rx);
@@ -7577,6 +7596,29 @@ async fn server_overloaded_error_does_not_switch_models() {
     }
 }
 
+#[tokio::test]
+async fn quota_exceeded_error_snapshot() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    assert!(matches!(
+        rate_limit_error_kind(&CodexErrorInfo::QuotaExceeded { resets_at: None }),
+        Some(RateLimitErrorKind::UsageLimit)
+    ));
+
+    chat.handle_codex_event(Event {
+        id: "err-1".to_string(),
+        msg: EventMsg::Error(ErrorEvent {
+            message: "Quota exceeded. Check your plan and billing details.".to_string(),
+            codex_error_info: Some(CodexErrorInfo::QuotaExceeded {
+                resets_at: Some(1_767_225_600),
+            }),
+        }),
+    });
+
+    let cells = drain_insert_history(&mut rx);
+    let last = lines_to_single_string(cells.last().expect("quota error cell"));
+    assert_snapshot!("quota_exceeded_error", last);
+}
+
 #[tokio::test]
 async fn approvals_selection_popup_snapshot() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -8373,6 +8415,7 @@ async fn approval_modal_exec_snapshot() -> anyhow::Result<()> {
         additional_permissions: None,
         skill_metadata: None,
         available_decisions: None,
+        stdin: None,
         parsed_cmd: vec![],
     };
     chat.handle_codex_event(Event {
@@ -8435,6 +8478,7 @@ async fn approval_modal_exec_without_reason_snapshot() -> anyhow::Result<()> {
         additional_permissions: None,
         skill_metadata: None,
         available_decisions: None,
+        stdin: None,
         parsed_cmd: vec![],
     };
     chat.handle_codex_event(Event {
@@ -8484,6 +8528,7 @@ async fn approval_modal_exec_multiline_prefix_hides_execpolicy_option_snapshot()
         additional_permissions: None,
         skill_metadata: None,
         available_decisions: None,
+        stdin: None,
         parsed_cmd: vec![],
     };
     chat.handle_codex_event(Event {
@@ -8735,6 +8780,7 @@ async fn turn_complete_keeps_unified_exec_processes() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
 
@@ -8852,6 +8898,7 @@ async fn status_widget_and_approval_modal_snapshot() {
         additional_permissions: None,
         skill_metadata: None,
         available_decisions: None,
+        stdin: None,
         parsed_cmd: vec![],
     };
     chat.handle_codex_event(Event {
@@ -9026,6 +9073,7 @@ async fn apply_patch_events_emit_history_cells() {
         turn_id: "turn-c1".into(),
         auto_approved: true,
         changes: changes2,
+        dry_run: false,
     };
     chat.handle_codex_event(Event {
         id: "s1".into(),
@@ -9055,6 +9103,7 @@ async fn apply_patch_events_emit_history_cells() {
         success: true,
         changes: end_changes,
         status: CorePatchApplyStatus::Completed,
+        dry_run: false,
     };
     chat.handle_codex_event(Event {
         id: "s1".into(),
@@ -9104,6 +9153,7 @@ async fn apply_patch_manual_approval_adjusts_header() {
             turn_id: "turn-c1".into(),
             auto_approved: false,
             changes: apply_changes,
+            dry_run: false,
         }),
     });
 
@@ -9157,6 +9207,7 @@ async fn apply_patch_manual_flow_snapshot() {
             turn_id: "turn-c1".into(),
             auto_approved: false,
             changes: apply_changes,
+            dry_run: false,
         }),
     });
     let approved_lines = drain_insert_history(&mut rx)
@@ -9270,6 +9321,7 @@ async fn apply_patch_full_flow_integration_like() {
             turn_id: "turn-call-1".into(),
             auto_approved: false,
             changes: changes2,
+            dry_run: false,
         }),
     });
     let mut end_changes = HashMap::new();
@@ -9287,6 +9339,7 @@ async fn apply_patch_full_flow_integration_like() {
             success: true,
             changes: end_changes,
             status: CorePatchApplyStatus::Completed,
+            dry_run: false,
         }),
     });
 }
@@ -9732,6 +9785,7 @@ async fn status_line_branch_refreshes_after_turn_complete() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
 
@@ -9954,6 +10008,7 @@ async fn multiple_agent_messages_in_single_turn_emit_multiple_headers() {
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
 
@@ -10311,6 +10366,7 @@ printf 'fenced within fenced\n'
         msg: EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: "turn-1".to_string(),
             last_agent_message: None,
+            cost_usd: None,
         }),
     });
     for lines in drain_insert_history(&mut rx) {
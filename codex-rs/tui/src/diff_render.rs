@@ -365,6 +365,7 @@ fn collect_rows(changes: &HashMap<PathBuf, FileChange>) -> Vec<Row> {
     for (path, change) in changes.iter() {
         let (added, removed) = match change {
             FileChange::Add { content } => (content.lines().count(), 0),
+            FileChange::Binary { .. } | FileChange::Symlink { .. } => (0, 0),
             FileChange::Delete { content } => (0, content.lines().count()),
             FileChange::Update { unified_diff, .. } => calculate_add_remove_from_diff(unified_diff),
         };
@@ -417,7 +418,9 @@ fn render_changes_block(rows: Vec<Row>, wrap_cols: usize, cwd: &Path) -> Vec<RtL
     let mut header_spans: Vec<RtSpan<'static>> = vec!["• ".dim()];
     if let [row] = &rows[..] {
         let verb = match &row.change {
-            FileChange::Add { .. } => "Added",
+            FileChange::Add { .. } | FileChange::Binary { .. } | FileChange::Symlink { .. } => {
+                "Added"
+            }
             FileChange::Delete { .. } => "Deleted",
             _ => "Edited",
         };
@@ -510,6 +513,14 @@ fn render_change(
                 }
             }
         }
+        FileChange::Binary { .. } => {
+            let description = change.binary_description().unwrap_or_default();
+            out.push(RtLine::from(RtSpan::from(description).dim()));
+        }
+        FileChange::Symlink { target } => {
+            let description = format!("symlink -> {}", target.display());
+            out.push(RtLine::from(RtSpan::from(description).dim()));
+        }
         FileChange::Delete { content } => {
             let syntax_lines = lang.and_then(|l| highlight_code_to_styled_spans(content, l));
             let line_number_width = line_number_width(content.lines().count());
@@ -1586,6 +1597,35 @@ mod tests {
         snapshot_lines("apply_add_block", lines, 80, 10);
     }
 
+    #[test]
+    fn apply_binary_add_block_describes_size_instead_of_content() {
+        let mut changes: HashMap<PathBuf, FileChange> = HashMap::new();
+        changes.insert(
+            PathBuf::from("logo.png"),
+            FileChange::Binary {
+                base64_content: "iVBORw0KGgo=".to_string(),
+            },
+        );
+
+        let text = diff_summary_for_tests(&changes)
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            text,
+            vec![
+                "• Added logo.png (+0 -0)".to_string(),
+                "    [binary file, 8 bytes]".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn ui_snapshot_apply_delete_block() {
         let mut changes: HashMap<PathBuf, FileChange> = HashMap::new();
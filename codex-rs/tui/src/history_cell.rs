@@ -2816,6 +2816,8 @@ mod tests {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         };
         let mut servers = config.mcp_servers.get().clone();
         servers.insert("docs".to_string(), stdio_config);
@@ -2840,6 +2842,8 @@ mod tests {
             disabled_tools: None,
             scopes: None,
             oauth_resource: None,
+            merge_structured_content: false,
+            max_mcp_retries: 0,
         };
         servers.insert("http".to_string(), http_config);
         config